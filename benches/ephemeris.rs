@@ -0,0 +1,92 @@
+// benches/ephemeris.rs
+
+use apollo::{coordinate, lunar, nutation, sun, Accuracy};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const JD: f64 = 2448908.5;
+
+fn bench_nutation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("nutation");
+    group.bench_function("low", |b| {
+        b.iter(|| nutation::nutation(black_box(JD), Accuracy::Low))
+    });
+    group.bench_function("high", |b| {
+        b.iter(|| nutation::nutation(black_box(JD), Accuracy::High))
+    });
+    group.finish();
+}
+
+fn bench_solar_position(c: &mut Criterion) {
+    let mut group = c.benchmark_group("solar_position");
+    group.bench_function("low", |b| {
+        b.iter(|| sun::geocent_ecl_pos(black_box(JD), Accuracy::Low))
+    });
+    group.bench_function("high", |b| {
+        b.iter(|| sun::geocent_ecl_pos(black_box(JD), Accuracy::High))
+    });
+    group.finish();
+}
+
+fn bench_lunar_position(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lunar_position");
+    group.bench_function("low", |b| {
+        b.iter(|| lunar::geocent_ecl_pos(black_box(JD), Accuracy::Low))
+    });
+    group.bench_function("high", |b| {
+        b.iter(|| lunar::geocent_ecl_pos(black_box(JD), Accuracy::High))
+    });
+    group.finish();
+}
+
+fn bench_coordinate_transforms(c: &mut Criterion) {
+    let asc = 116.328942_f64.to_radians();
+    let dec = 28.026183_f64.to_radians();
+    let oblq_eclip = 23.4392911_f64.to_radians();
+    let hour_angle = 64.352133_f64.to_radians();
+    let observer_lat = 38.921389_f64.to_radians();
+
+    let mut group = c.benchmark_group("coordinate_transforms");
+    group.bench_function("ecliptic_from_eq", |b| {
+        b.iter(|| {
+            (
+                coordinate::ecliptic_long_from_eq(black_box(asc), black_box(dec), oblq_eclip),
+                coordinate::ecliptic_lat_from_eq(black_box(asc), black_box(dec), oblq_eclip),
+            )
+        })
+    });
+    group.bench_function("horizontal_from_eq", |b| {
+        b.iter(|| {
+            (
+                coordinate::azimuth_from_eq(black_box(hour_angle), black_box(dec), observer_lat),
+                coordinate::altitude_from_eq(black_box(hour_angle), black_box(dec), observer_lat),
+            )
+        })
+    });
+    group.bench_function("horizontal_from_eq_precomputed", |b| {
+        let observer_lat = coordinate::PrecomputedLatitude::new(observer_lat);
+        b.iter(|| {
+            (
+                coordinate::azimuth_from_eq(black_box(hour_angle), black_box(dec), observer_lat),
+                coordinate::altitude_from_eq(black_box(hour_angle), black_box(dec), observer_lat),
+            )
+        })
+    });
+    group.bench_function("galactic_from_eq", |b| {
+        b.iter(|| {
+            (
+                coordinate::galactic_long_from_eq(black_box(asc), black_box(dec)),
+                coordinate::galactic_lat_from_eq(black_box(asc), black_box(dec)),
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_nutation,
+    bench_solar_position,
+    bench_lunar_position,
+    bench_coordinate_transforms
+);
+criterion_main!(benches);
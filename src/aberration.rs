@@ -0,0 +1,104 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------ABERRATION-----------!
+
+use crate::angle;
+use crate::au;
+use crate::planet::earth;
+
+/**
+Computes the annual aberration in ecliptic coordinates
+
+# Returns
+
+(aberr_long, aberr_lat)
+
+* aberr_long: Aberration in longitude | in radians
+* aberr_lat : Aberration in latitude | in radians
+
+This is the aberration due to the Earth's orbital motion, including
+the term for the eccentricity of the orbit (Meeus, Astronomical
+Algorithms, equation 23.2). Added to the geometric direction of a
+star, or to the light-time corrected direction of a planet, it gives
+the apparent direction.
+
+# Arguments
+
+* long    : Ecliptic longitude of the body | in radians
+* lat     : Ecliptic latitude of the body | in radians
+* sun_long: True geometric longitude of the Sun | in radians
+* jd      : Julian (Ephemeris) day
+**/
+pub fn annual(long: f64, lat: f64, sun_long: f64, jd: f64) -> (f64, f64) {
+    let k = angle::deg_dmas(0, 0, 20.49552).to_radians();
+    let e = earth::orbital_eccentricity(jd);
+    let perihelion = earth::long_of_perihelion(jd);
+
+    (
+        (-k * (sun_long - long).cos() + e * k * (perihelion - long).cos()) / lat.cos(),
+        -k * lat.sin() * ((sun_long - long).sin() - e * (perihelion - long).sin()),
+    )
+}
+
+/**
+Applies the planetary aberration to a geometric position
+
+# Returns
+
+* apparent: Apparent geocentric position, rectangular | in AU
+
+The planetary aberration is the sum of the light-time correction,
+for the motion of the body, and the annual aberration, for the motion
+of the Earth. To the first order it takes back the geometric position
+along the relative velocity by the light-time: `position −
+velocity · distance / c`.
+
+# Arguments
+
+* position: Geometric geocentric position of the body at the time of
+            observation, rectangular | in AU
+* velocity: Velocity of the body relative to the Earth, rectangular
+            | in AU per day
+* distance: Earth-body distance | in AU
+**/
+pub fn planetary(position: &[f64; 3], velocity: &[f64; 3], distance: f64) -> [f64; 3] {
+    let light_time = light_time(distance);
+
+    std::array::from_fn(|i| position[i] - velocity[i] * light_time)
+}
+
+/**
+Computes the light-time for a distance
+
+# Returns
+
+* light_time: Light-time | in days
+
+# Arguments
+
+* distance: Distance | in AU
+**/
+#[inline]
+pub fn light_time(distance: f64) -> f64 {
+    au::light_time_days(distance)
+}
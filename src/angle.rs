@@ -182,3 +182,74 @@ pub fn limit_twoPI(angl: f64) -> f64 {
         limited_angl
     }
 }
+
+/*
+Computes the equivalent angle in (-π, π] radian range
+
+# Arguments
+
+* angl: Angle | in radians
+*/
+#[inline]
+pub fn limit_pi(angl: f64) -> f64 {
+    let limited_angl = limit_twoPI(angl);
+
+    if limited_angl > std::f64::consts::PI {
+        limited_angl - TWO_PI
+    } else {
+        limited_angl
+    }
+}
+
+/*
+Clamps an angle to the [-π/2, π/2] radian range of a declination or
+latitude
+
+# Returns
+
+* angl: The angle, clamped to [-π/2, π/2] | in radians
+
+Angles beyond ±π/2 by less than 1e-9 radians, as left by rounding
+errors, are clamped. Angles further out of range, or NaN, are an
+error.
+
+# Arguments
+
+* angl: Angle | in radians
+*/
+#[inline]
+pub fn limit_half_pi(angl: f64) -> Result<f64, &'static str> {
+    let half_pi = std::f64::consts::FRAC_PI_2;
+
+    if angl.abs() <= half_pi {
+        Ok(angl)
+    } else if angl.abs() <= half_pi + 1e-9 {
+        Ok(half_pi.copysign(angl))
+    } else {
+        Err("Angle out of the range [-π/2, π/2]")
+    }
+}
+
+/*
+Computes the equivalent time in [0, 24) hour range
+
+# Arguments
+
+* hours: Time or hour angle | in hours
+*/
+#[inline]
+pub fn limit_24(hours: f64) -> f64 {
+    let limited_hours = hours % 24.0;
+    let limited_hours = if limited_hours < 0.0 {
+        limited_hours + 24.0
+    } else {
+        limited_hours
+    };
+
+    // Adding 24 to a tiny negative remainder can round to 24
+    if limited_hours >= 24.0 {
+        0.0
+    } else {
+        limited_hours
+    }
+}
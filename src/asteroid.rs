@@ -27,6 +27,10 @@ Computes the diameter of an asteroid
 
 * diameter: Diameter of the asteroid | in meters
 
+The relation is log₁₀ D = 3.12 − H/5 − 0.217147 ln A, with D in
+kilometers; its last term takes the natural logarithm of the albedo
+A, 0.217147 being 1 / (2 ln 10).
+
 # Arguments
 
 * abs_mag: Absolute magnitude of the asteroid
@@ -35,7 +39,83 @@ Computes the diameter of an asteroid
 
 #[inline]
 pub fn asteroid_diameter(abs_mag: f64, albedo: f64) -> f64 {
-    1000.0 * 10_f64.powf(3.12 - abs_mag / 5.0 - 0.217147 * albedo.log10())
+    1000.0 * 10_f64.powf(3.12 - abs_mag / 5.0 - 0.217147 * albedo.ln())
+}
+
+/*
+Computes the absolute magnitude of an asteroid
+
+# Returns
+
+* abs_mag: Absolute magnitude H of the asteroid
+
+This is the inverse of asteroid_diameter().
+
+# Arguments
+
+* diameter: Diameter of the asteroid | in meters
+* albedo  : Geometric albedo of the asteroid
+*/
+
+#[inline]
+pub fn asteroid_abs_mag(diameter: f64, albedo: f64) -> f64 {
+    5.0 * (3.12 - (diameter / 1000.0).log10() - 0.217147 * albedo.ln())
+}
+
+/*
+Computes the geometric albedo of an asteroid
+
+# Returns
+
+* albedo: Geometric albedo of the asteroid
+
+This is the inverse of asteroid_diameter().
+
+# Arguments
+
+* abs_mag : Absolute magnitude H of the asteroid
+* diameter: Diameter of the asteroid | in meters
+*/
+
+#[inline]
+pub fn asteroid_albedo(abs_mag: f64, diameter: f64) -> f64 {
+    ((3.12 - abs_mag / 5.0 - (diameter / 1000.0).log10()) / 0.217147).exp()
+}
+
+/*
+Computes the apparent magnitude of an asteroid
+
+# Returns
+
+* apparent_mag: Apparent magnitude of the asteroid
+
+The (H, G) magnitude system adopted by the IAU in 1985 is used, as
+in Meeus, Astronomical Algorithms, equation 33.14. The Minor Planet
+Center publishes H and G with the orbital elements; G is 0.15 if
+unknown.
+
+# Arguments
+
+* abs_mag            : Absolute magnitude H of the asteroid
+* slope_param        : Slope parameter G of the asteroid
+* asteroid_sun_dist  : Asteroid-Sun distance | in AU
+* asteroid_earth_dist: Asteroid-Earth distance | in AU
+* phase_angle        : Sun-asteroid-Earth angle | in radians
+*/
+
+pub fn asteroid_apparent_mag(
+    abs_mag: f64,
+    slope_param: f64,
+    asteroid_sun_dist: f64,
+    asteroid_earth_dist: f64,
+    phase_angle: f64,
+) -> f64 {
+    let tan_half = (phase_angle / 2.0).tan();
+    let phi_1 = (-3.33 * tan_half.powf(0.63)).exp();
+    let phi_2 = (-1.87 * tan_half.powf(1.22)).exp();
+
+    abs_mag + 5.0 * (asteroid_sun_dist * asteroid_earth_dist).log10()
+        - 2.5 * ((1.0 - slope_param) * phi_1 + slope_param * phi_2).log10()
 }
 
 /*
@@ -0,0 +1,153 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------ASTRONOMICAL UNIT-----------!
+
+/// Kilometers per astronomical unit, IAU 2012 Resolution B2
+pub const AU_KM: f64 = 149597870.7;
+
+/// Light-time for unit distance | in minutes per AU
+pub const LIGHT_TIME_MINUTES: f64 = 8.316746;
+
+/// Light-time for unit distance | in days per AU
+pub const LIGHT_TIME_DAYS: f64 = 0.0057755183;
+
+/// Represents a unit of distance
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Unit {
+    /// Astronomical unit, written `AU`
+    AU,
+    /// Kilometer, written `km`
+    Km,
+    /// Light-minute, written `lmin`
+    LightMinute,
+}
+
+impl Unit {
+    /// Returns the symbol of the unit
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::AU => "AU",
+            Unit::Km => "km",
+            Unit::LightMinute => "lmin",
+        }
+    }
+
+    /// Returns the length of the unit | in AU
+    pub fn in_au(&self) -> f64 {
+        match self {
+            Unit::AU => 1.0,
+            Unit::Km => 1.0 / AU_KM,
+            Unit::LightMinute => 1.0 / LIGHT_TIME_MINUTES,
+        }
+    }
+}
+
+/**
+Converts a distance from AU to kilometers
+
+# Arguments
+
+* distance: Distance | in AU
+**/
+#[inline]
+pub fn km_from_au(distance: f64) -> f64 {
+    distance * AU_KM
+}
+
+/**
+Converts a distance from kilometers to AU
+
+# Arguments
+
+* distance: Distance | in kilometers
+**/
+#[inline]
+pub fn au_from_km(distance: f64) -> f64 {
+    distance / AU_KM
+}
+
+/**
+Computes the light-time for a distance
+
+# Returns
+
+* light_time: Light-time | in days
+
+# Arguments
+
+* distance: Distance | in AU
+**/
+#[inline]
+pub fn light_time_days(distance: f64) -> f64 {
+    LIGHT_TIME_DAYS * distance
+}
+
+/**
+Parses a distance with a unit
+
+# Returns
+
+* distance: The distance | in AU
+
+The number may be followed by whitespace and one of the symbols of
+`Unit`, matched without regard to case. A bare number is taken to
+be in AU.
+
+# Arguments
+
+* s: The text, such as `"1.52 AU"` or `"384400 km"`
+**/
+pub fn parse_distance(s: &str) -> Result<f64, &'static str> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| c.is_ascii_alphabetic() && c != 'e' && c != 'E')
+        .unwrap_or(s.len());
+    let (number, symbol) = s.split_at(split);
+
+    let unit = match symbol.trim().to_ascii_lowercase().as_str() {
+        "" | "au" => Unit::AU,
+        "km" => Unit::Km,
+        "lmin" => Unit::LightMinute,
+        _ => return Err("Unknown unit of distance"),
+    };
+    let value: f64 = number.trim().parse().map_err(|_| "Invalid number")?;
+
+    Ok(value * unit.in_au())
+}
+
+/**
+Formats a distance in a unit
+
+# Returns
+
+* text: The distance followed by a space and the symbol of the unit
+
+# Arguments
+
+* distance: Distance | in AU
+* unit    : Unit to format the distance in
+* decimals: Number of decimal places
+**/
+pub fn format_distance(distance: f64, unit: Unit, decimals: usize) -> String {
+    format!("{:.*} {}", decimals, distance / unit.in_au(), unit.symbol())
+}
@@ -0,0 +1,205 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------COMMAND LINE-----------!
+
+//! Command line front end to the common computations
+//!
+//! ```text
+//! cargo run --features cli --bin apollo-cli -- sunrise 42.36 71.06 2024-06-20 -4
+//! ```
+
+use apollo::angle;
+use apollo::coordinate;
+use apollo::ephemeris;
+use apollo::lunar;
+use apollo::sun;
+use apollo::time;
+use apollo::transit;
+use apollo::Accuracy;
+use std::process;
+
+const USAGE: &str = "\
+Usage: apollo-cli <command> [arguments]
+
+Commands:
+  jd <date-time>                      Julian day of a date and time
+  sunrise <lat> <long> <date> [<tz>]  Sunrise, solar transit and sunset
+  moonphase <date-time>               Illuminated fraction of the Moon
+  planet-position <body> <date-time>  Apparent place of the Sun, the
+                                      Moon or Mars
+
+Dates are written YYYY-MM-DD and dates and times
+YYYY-MM-DDTHH:MM[:SS], in UT; the date of sunrise is in the time zone
+<tz>, in hours east of Greenwich. Latitudes and longitudes are in
+degrees, with longitudes positive westward.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(output) => println!("{}", output),
+        Err(message) => {
+            eprintln!("apollo-cli: {}\n\n{}", message, USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<String, String> {
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    match args.as_slice() {
+        ["jd", date_time] => {
+            let date_time = parse_date_time(date_time, 0.0)?;
+            Ok(format!("{:.6}", date_time.to_julian_day()))
+        }
+        ["sunrise", lat, long, date] => sunrise(lat, long, date, "0"),
+        ["sunrise", lat, long, date, time_zone] => sunrise(lat, long, date, time_zone),
+        ["moonphase", date_time] => moon_phase(date_time),
+        ["planet-position", body, date_time] => planet_position(body, date_time),
+        ["help"] | ["--help"] | ["-h"] => Ok(USAGE.to_string()),
+        [] => Err("No command given".to_string()),
+        _ => Err(format!("Unknown command or arguments: {}", args.join(" "))),
+    }
+}
+
+fn sunrise(lat: &str, long: &str, date: &str, time_zone: &str) -> Result<String, String> {
+    let time_zone = parse_number(time_zone, "time zone")?;
+    let observer = coordinate::Observer::new(
+        parse_number(long, "longitude")?.to_radians(),
+        parse_number(lat, "latitude")?.to_radians(),
+        0.0,
+    )
+    .time_zone(time_zone);
+    let date = parse_date_time(date, time_zone)?.to_date();
+
+    let events = transit::sun_rise_set(&observer, &date);
+    let format_event = |event: Option<time::ZonedInstant>| match event {
+        Some(zoned) => match zoned.local_date_time() {
+            Ok(t) => format!(
+                "{:02}:{:02}:{:02}",
+                t.day_of_month.hr,
+                t.day_of_month.min,
+                t.day_of_month.sec.floor()
+            ),
+            Err(_) => "-".to_string(),
+        },
+        None => "-".to_string(),
+    };
+
+    Ok(format!(
+        "rise    {}\ntransit {}\nset     {}",
+        format_event(events.rise),
+        format_event(events.transit),
+        format_event(events.set)
+    ))
+}
+
+fn moon_phase(date_time: &str) -> Result<String, String> {
+    let jd = time::Instant::from_ut(parse_date_time(date_time, 0.0)?.to_julian_day()).jd_tt;
+
+    let phase_angle = lunar::phase_angle(jd, Accuracy::High);
+    let (moon, _) = lunar::apparent_ecl_pos(jd, Accuracy::High);
+    let (sun, _) = sun::apparent_ecl_pos(jd, Accuracy::High);
+    let waxing = angle::limit_twoPI(moon.long - sun.long) < std::f64::consts::PI;
+
+    Ok(format!(
+        "illuminated {:.1}%\nphase angle {:.2}°\n{}",
+        100.0 * lunar::illuminated_fraction(phase_angle),
+        phase_angle.to_degrees(),
+        if waxing { "waxing" } else { "waning" }
+    ))
+}
+
+fn planet_position(body: &str, date_time: &str) -> Result<String, String> {
+    let body = match body.to_ascii_lowercase().as_str() {
+        "sun" => ephemeris::Body::Sun,
+        "moon" => ephemeris::Body::Moon,
+        "mars" => ephemeris::Body::Mars,
+        _ => return Err(format!("Unknown body: {}", body)),
+    };
+    let jd = time::Instant::from_ut(parse_date_time(date_time, 0.0)?.to_julian_day()).jd_tt;
+    let (eq_point, dist) = ephemeris::apparent_eq_pos(&body, jd, Accuracy::High);
+
+    let (hour, min, sec) = angle::hms_deg(eq_point.asc.to_degrees());
+    let dec = eq_point.dec.to_degrees();
+    let (deg, arcmin, arcsec) = angle::dms_deg(dec.abs());
+
+    Ok(format!(
+        "R.A.  {:02}h{:02}m{:05.2}s\nDec.  {}{:02}°{:02}′{:04.1}″\ndist  {:.8} AU",
+        hour,
+        min,
+        sec,
+        if dec < 0.0 { '-' } else { '+' },
+        deg,
+        arcmin,
+        arcsec,
+        dist
+    ))
+}
+
+// Parses YYYY-MM-DD or YYYY-MM-DDTHH:MM[:SS] in a time zone
+fn parse_date_time(s: &str, time_zone: f64) -> Result<time::DateTime, String> {
+    let invalid = || format!("Invalid date: {}", s);
+    let (date, clock) = s.split_once('T').unwrap_or((s, "0:0"));
+
+    let date: Vec<&str> = date.split('-').collect();
+    let clock: Vec<&str> = clock.split(':').collect();
+    if date.len() != 3 || !(2..=3).contains(&clock.len()) {
+        return Err(invalid());
+    }
+
+    let year: i16 = date[0].parse().map_err(|_| invalid())?;
+    let month: u8 = date[1].parse().map_err(|_| invalid())?;
+    let day: u8 = date[2].parse().map_err(|_| invalid())?;
+    let hr: u8 = clock[0].parse().map_err(|_| invalid())?;
+    let min: u8 = clock[1].parse().map_err(|_| invalid())?;
+    let sec: f64 = match clock.get(2) {
+        Some(sec) => sec.parse().map_err(|_| invalid())?,
+        None => 0.0,
+    };
+    if !(1..=31).contains(&day) || hr > 23 || min > 59 || !(0.0..60.0).contains(&sec) {
+        return Err(invalid());
+    }
+
+    Ok(time::DateTime {
+        year,
+        month: time::Month::try_from(month).map_err(|e| e.to_string())?,
+        day_of_month: time::DayOfMonth {
+            day,
+            hr,
+            min,
+            sec,
+            time_zone,
+        },
+        cal_type: if (year, month, day) < (1582, 10, 15) {
+            time::CalType::Julian
+        } else {
+            time::CalType::Gregorian
+        },
+    })
+}
+
+fn parse_number(s: &str, name: &str) -> Result<f64, String> {
+    s.parse().map_err(|_| format!("Invalid {}: {}", name, s))
+}
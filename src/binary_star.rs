@@ -63,7 +63,6 @@ pub fn mean_annomaly(n: f64, t: f64, T: f64) -> f64 {
 /// # Returns
 ///
 /// The radius vector of the binary star.
-
 pub fn radius_vector(a: f64, e: f64, ecc_anom: f64) -> f64 {
     a * (1.0 - e * ecc_anom.cos())
 }
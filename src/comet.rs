@@ -0,0 +1,81 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------COMET-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::phase;
+use std::f64::consts::PI;
+
+/**
+Computes the apparent magnitude of a comet
+
+# Returns
+
+* mag: Apparent total magnitude of the comet
+
+The standard law m = H + 5 log Δ + 2.5 n log r is used. For a
+typical comet n lies between 2 and 6, often taken as 4.
+
+# Arguments
+
+* abs_mag         : Absolute magnitude H of the comet
+* activity_index  : Photometric index n, describing how steeply the
+                    brightness grows towards the Sun
+* comet_sun_dist  : Comet-Sun distance r | in AU
+* comet_earth_dist: Comet-Earth distance Δ | in AU
+**/
+pub fn apparent_magnitude(
+    abs_mag: f64,
+    activity_index: f64,
+    comet_sun_dist: f64,
+    comet_earth_dist: f64,
+) -> f64 {
+    abs_mag + 5.0 * comet_earth_dist.log10() + 2.5 * activity_index * comet_sun_dist.log10()
+}
+
+/**
+Computes the position angle of the tail of a comet
+
+# Returns
+
+* tail_pos_angle: Position angle of the antisolar direction at the
+                  comet, measured eastwards from the north | in radians
+
+The tail of a comet points away from the Sun, so its projection on
+the sky is approximately along this direction. Dust tails curve
+back along the orbit and may deviate from it.
+
+# Arguments
+
+* comet_eq_point: Equatorial point of the comet | in radians
+* sun_eq_point  : Equatorial point of the Sun | in radians
+**/
+pub fn tail_position_angle(
+    comet_eq_point: &coordinate::EqPoint,
+    sun_eq_point: &coordinate::EqPoint,
+) -> f64 {
+    let sun_pos_angle = phase::bright_limb_pos_angle(comet_eq_point, sun_eq_point);
+
+    angle::limit_twoPI(sun_pos_angle + PI)
+}
@@ -0,0 +1,115 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------CONFIGURATION-----------!
+
+//! Process-wide defaults for the convenience functions
+//!
+//! Applications can set a default observer, ΔT model, refraction
+//! conditions and accuracy tier once, instead of passing them to every
+//! call. Functions taking these as parameters ignore the defaults, so
+//! libraries built on this crate stay independent of them.
+//!
+//! Beyond the convenience functions, the ΔT model is the default for
+//! the conversions between UT and TT that don't take a model, such as
+//! [time::tt_from_ut()](../time/fn.tt_from_ut.html); the `_with_model`
+//! variants, such as
+//! [time::tt_from_ut_with_model()](../time/fn.tt_from_ut_with_model.html)
+//! and
+//! [time::ut_from_tt_with_model()](../time/fn.ut_from_tt_with_model.html),
+//! ignore it. The refraction conditions are the default of
+//! [sun::golden_hour()](../sun/fn.golden_hour.html) and
+//! [sun::blue_hour()](../sun/fn.blue_hour.html).
+
+use crate::coordinate;
+use crate::time;
+use crate::Accuracy;
+use std::sync::RwLock;
+
+/// Represents the defaults of the convenience functions
+#[derive(Copy, Clone, Debug)]
+pub struct Config {
+    /// Default observer, `None` until set
+    pub observer: Option<coordinate::Observer>,
+    /// ΔT model
+    pub delta_t: time::DeltaTModel,
+    /// Local pressure, for refraction | in millibars
+    pub pressure: f64,
+    /// Local temperature, for refraction | in kelvins
+    pub temp: f64,
+    /// Accuracy tier
+    pub accuracy: Accuracy,
+}
+
+impl Config {
+    /// The defaults in force until changed: no observer, ΔT from
+    /// [time::delta_t()](../time/fn.delta_t.html), the standard
+    /// refraction conditions of 1010 mbar and 283 K, and
+    /// `Accuracy::High`
+    pub const DEFAULT: Config = Config {
+        observer: None,
+        delta_t: time::DeltaTModel::EspenakMeeus,
+        pressure: 1010.0,
+        temp: 283.0,
+        accuracy: Accuracy::High,
+    };
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config::DEFAULT
+    }
+}
+
+static CONFIG: RwLock<Config> = RwLock::new(Config::DEFAULT);
+
+/// Returns the current defaults
+pub fn get() -> Config {
+    *CONFIG.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Replaces the defaults
+pub fn set(config: Config) {
+    *CONFIG.write().unwrap_or_else(|e| e.into_inner()) = config;
+}
+
+/**
+Changes some of the defaults
+
+# Arguments
+
+* change: Function changing the defaults in place
+
+```
+apollo::config::update(|config| config.temp = 268.0);
+assert_eq!(apollo::config::get().temp, 268.0);
+# apollo::config::reset();
+```
+**/
+pub fn update<F: FnOnce(&mut Config)>(change: F) {
+    change(&mut CONFIG.write().unwrap_or_else(|e| e.into_inner()));
+}
+
+/// Restores [Config::DEFAULT](./struct.Config.html#associatedconstant.DEFAULT)
+pub fn reset() {
+    set(Config::DEFAULT);
+}
@@ -23,9 +23,12 @@ THE SOFTWARE.
 // !--------Transfer Coordinate---------!
 
 use crate::angle;
+use crate::atmospheric;
+use crate::sphere;
+use num_traits::Float;
 
 /// Represents a point on the geographical surface of the Earth
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct GeographPoint {
     /// Geographical longitude
     pub long: f64,
@@ -34,28 +37,206 @@ pub struct GeographPoint {
 }
 
 impl GeographPoint {
+    pub fn new(long: f64, lat: f64) -> GeographPoint {
+        GeographPoint { long, lat }
+    }
+
     pub fn angular_sep(&self, other_point: &GeographPoint) -> f64 {
         angle::angular_sep(self.long, self.lat, other_point.long, other_point.lat)
     }
 }
 
+/// Represents an observer on the Earth's surface
+#[derive(Copy, Clone, Debug)]
+pub struct Observer {
+    /// Geographical longitude, positive westward
+    pub long: f64,
+    /// Geographical latitude
+    pub lat: f64,
+    /// Height above sea level | in meters
+    pub height: f64,
+    /// Time zone | in decimal hours
+    ///
+    /// Example: Pacific Time Zone is -8.0
+    pub time_zone: f64,
+}
+
+impl Observer {
+    pub fn new(long: f64, lat: f64, height: f64) -> Observer {
+        Observer {
+            long,
+            lat,
+            height,
+            time_zone: 0.0,
+        }
+    }
+
+    /// Sets the time zone | in decimal hours
+    pub fn time_zone(mut self, time_zone: f64) -> Observer {
+        self.time_zone = time_zone;
+        self
+    }
+
+    pub fn geograph_point(&self) -> GeographPoint {
+        GeographPoint::new(self.long, self.lat)
+    }
+}
+
 /// Represents a point in the equatorial coordinate system
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct EqPoint {
     /// Right ascension
     pub asc: f64,
     /// Declination
     pub dec: f64,
+    /// Equinox the point is referred to, `None` if unknown | as a
+    /// Julian (Ephemeris) day
+    pub equinox: Option<f64>,
 }
 
 impl EqPoint {
+    /// Returns a point whose equinox is unknown | in radians
+    pub fn new(asc: f64, dec: f64) -> EqPoint {
+        EqPoint {
+            asc,
+            dec,
+            equinox: None,
+        }
+    }
+
+    /// Returns a point referred to the equinox of a Julian
+    /// (Ephemeris) day | in radians
+    pub fn with_equinox(asc: f64, dec: f64, equinox: f64) -> EqPoint {
+        EqPoint {
+            asc,
+            dec,
+            equinox: Some(equinox),
+        }
+    }
+
+    /**
+    Computes the angular separation from another point
+
+    # Returns
+
+    * angular_sep: Angular separation | in radians
+
+    If both points carry an equinox and they differ, the other point
+    is first precessed to the equinox of this one, with
+    [precession::precess_eq()](../precession/fn.precess_eq.html).
+    Points whose equinox is unknown are compared as they are.
+
+    # Arguments
+
+    * other_point: The other point
+    **/
     pub fn angular_sep(&self, other_point: &EqPoint) -> f64 {
+        let other_point = match (self.equinox, other_point.equinox) {
+            (Some(to), Some(from)) if to != from => {
+                crate::precession::precess_eq(other_point, from, to)
+            }
+            _ => *other_point,
+        };
+
         angle::angular_sep(self.asc, self.dec, other_point.asc, other_point.dec)
     }
 }
 
+/// Represents a point in the hour angle coordinate system, the
+/// equatorial system turning with the observer's meridian
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HaPoint {
+    /// Local hour angle, measured westward from the meridian, in
+    /// (−π, π]
+    pub hour_angle: f64,
+    /// Declination
+    pub dec: f64,
+}
+
+impl HaPoint {
+    /**
+    Returns the hour angle point of an equatorial point
+
+    # Returns
+
+    * ha_point: The hour angle point
+
+    # Arguments
+
+    * eq_point      : Equatorial point | in radians
+    * local_sidereal: Local sidereal time | in radians
+    **/
+    pub fn from_eq(eq_point: &EqPoint, local_sidereal: f64) -> HaPoint {
+        HaPoint {
+            hour_angle: angle::limit_pi(local_sidereal - eq_point.asc),
+            dec: eq_point.dec,
+        }
+    }
+
+    /**
+    Returns the equatorial point of the hour angle point
+
+    # Returns
+
+    * eq_point: Equatorial point, with the right ascension in
+                [0, 2π) | in radians
+
+    # Arguments
+
+    * local_sidereal: Local sidereal time | in radians
+    **/
+    pub fn to_eq(&self, local_sidereal: f64) -> EqPoint {
+        EqPoint::new(
+            angle::limit_twoPI(local_sidereal - self.hour_angle),
+            self.dec,
+        )
+    }
+
+    /**
+    Computes local horizontal coordinates
+
+    # Returns
+
+    (az, alt)
+
+    * az: Azimuth, measured westward from the south | in radians
+    * alt: Altitude | in radians
+
+    # Arguments
+
+    * observer_lat: Observer's geographical latitude | in radians
+    **/
+    pub fn horizontal(&self, observer_lat: f64) -> (f64, f64) {
+        (
+            azimuth_from_eq(self.hour_angle, self.dec, observer_lat),
+            altitude_from_eq(self.hour_angle, self.dec, observer_lat),
+        )
+    }
+
+    // Topocentric hour angle point from the geocentric one, Meeus,
+    // Astronomical Algorithms, equations 40.2 and 40.3
+    pub(crate) fn topocent(
+        &self,
+        sin_parallax: f64,
+        observer_lat: f64,
+        observer_height: f64,
+    ) -> HaPoint {
+        let (rho_sin_phi, rho_cos_phi) =
+            crate::planet::earth::rho_sin_cos_phi(observer_lat, observer_height);
+
+        let (sin_dec, cos_dec) = self.dec.sin_cos();
+        let denominator = cos_dec - rho_cos_phi * sin_parallax * self.hour_angle.cos();
+        let d_asc = (-rho_cos_phi * sin_parallax * self.hour_angle.sin()).atan2(denominator);
+
+        HaPoint {
+            hour_angle: angle::limit_pi(self.hour_angle - d_asc),
+            dec: ((sin_dec - rho_sin_phi * sin_parallax) * d_asc.cos()).atan2(denominator),
+        }
+    }
+}
+
 /// Represents a point in the ecliptic coordinate system
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct EclPoint {
     /// Ecliptic longitude
     pub long: f64,
@@ -69,6 +250,404 @@ impl EclPoint {
     }
 }
 
+/// Represents a point in a rectangular coordinate system
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RectPoint {
+    /// X coordinate, towards longitude 0 on the fundamental plane
+    pub x: f64,
+    /// Y coordinate, towards longitude 90° on the fundamental plane
+    pub y: f64,
+    /// Z coordinate, towards the pole
+    pub z: f64,
+}
+
+impl RectPoint {
+    pub fn new(x: f64, y: f64, z: f64) -> RectPoint {
+        RectPoint { x, y, z }
+    }
+
+    /// Returns the point at a distance in the direction of a
+    /// longitude and latitude | in radians
+    pub fn from_spherical(long: f64, lat: f64, dist: f64) -> RectPoint {
+        let [x, y, z] = sphere::rectangular(long, lat);
+
+        RectPoint {
+            x: x * dist,
+            y: y * dist,
+            z: z * dist,
+        }
+    }
+
+    /// Returns (long, lat, dist) of the point, the longitude in
+    /// 0 - 2π | in radians
+    pub fn to_spherical(&self) -> (f64, f64, f64) {
+        let (long, lat) = sphere::spherical(&[self.x, self.y, self.z]);
+
+        (long, lat, self.dist())
+    }
+
+    /// Returns the distance from the origin
+    pub fn dist(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+}
+
+/// Represents a rotation of a rectangular coordinate frame
+///
+/// The matrix takes the coordinates of a point in the original frame
+/// to its coordinates in the rotated frame. Rotations compose with
+/// `*`, the right-hand one being applied first.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RotationMatrix {
+    /// Elements of the matrix, row by row
+    pub m: [[f64; 3]; 3],
+}
+
+impl RotationMatrix {
+    /// Returns the identity rotation
+    pub fn identity() -> RotationMatrix {
+        RotationMatrix {
+            m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// Returns the rotation of the frame about the x axis by an
+    /// angle | in radians
+    pub fn about_x(angl: f64) -> RotationMatrix {
+        let (sin, cos) = angl.sin_cos();
+
+        RotationMatrix {
+            m: [[1.0, 0.0, 0.0], [0.0, cos, sin], [0.0, -sin, cos]],
+        }
+    }
+
+    /// Returns the rotation of the frame about the y axis by an
+    /// angle | in radians
+    pub fn about_y(angl: f64) -> RotationMatrix {
+        let (sin, cos) = angl.sin_cos();
+
+        RotationMatrix {
+            m: [[cos, 0.0, -sin], [0.0, 1.0, 0.0], [sin, 0.0, cos]],
+        }
+    }
+
+    /// Returns the rotation of the frame about the z axis by an
+    /// angle | in radians
+    pub fn about_z(angl: f64) -> RotationMatrix {
+        let (sin, cos) = angl.sin_cos();
+
+        RotationMatrix {
+            m: [[cos, sin, 0.0], [-sin, cos, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// Returns the inverse rotation, which is the transpose
+    pub fn transpose(&self) -> RotationMatrix {
+        RotationMatrix {
+            m: std::array::from_fn(|i| std::array::from_fn(|j| self.m[j][i])),
+        }
+    }
+}
+
+impl std::ops::Mul for RotationMatrix {
+    type Output = RotationMatrix;
+
+    fn mul(self, other: RotationMatrix) -> RotationMatrix {
+        RotationMatrix {
+            m: std::array::from_fn(|i| {
+                std::array::from_fn(|j| (0..3).map(|k| self.m[i][k] * other.m[k][j]).sum())
+            }),
+        }
+    }
+}
+
+impl std::ops::Mul<RectPoint> for RotationMatrix {
+    type Output = RectPoint;
+
+    fn mul(self, p: RectPoint) -> RectPoint {
+        let [x, y, z] = self.m.map(|row| row[0] * p.x + row[1] * p.y + row[2] * p.z);
+
+        RectPoint { x, y, z }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<RectPoint> for nalgebra::Vector3<f64> {
+    fn from(p: RectPoint) -> Self {
+        nalgebra::Vector3::new(p.x, p.y, p.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f64>> for RectPoint {
+    fn from(v: nalgebra::Vector3<f64>) -> Self {
+        RectPoint::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<RotationMatrix> for nalgebra::Rotation3<f64> {
+    fn from(r: RotationMatrix) -> Self {
+        nalgebra::Rotation3::from_matrix_unchecked(nalgebra::Matrix3::from_fn(|i, j| r.m[i][j]))
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Rotation3<f64>> for RotationMatrix {
+    fn from(r: nalgebra::Rotation3<f64>) -> Self {
+        RotationMatrix {
+            m: std::array::from_fn(|i| std::array::from_fn(|j| r[(i, j)])),
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl std::ops::Mul<nalgebra::Rotation3<f64>> for RotationMatrix {
+    type Output = RotationMatrix;
+
+    fn mul(self, other: nalgebra::Rotation3<f64>) -> RotationMatrix {
+        self * RotationMatrix::from(other)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl std::ops::Mul<RotationMatrix> for nalgebra::Rotation3<f64> {
+    type Output = RotationMatrix;
+
+    fn mul(self, other: RotationMatrix) -> RotationMatrix {
+        RotationMatrix::from(self) * other
+    }
+}
+
+// Altitude below which no refraction is applied | in degrees
+const MIN_REFRACTION_ALT: f64 = -2.0;
+
+/// Represents a point in the local horizontal coordinate system, as
+/// observed through the atmosphere
+///
+/// Both the true altitude and the refracted one are kept, so that a
+/// chain of transformations never applies refraction twice, or
+/// forgets it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ObservedPoint {
+    /// Azimuth, measured westward from the south | in radians
+    pub az: f64,
+    /// True altitude, not corrected for refraction | in radians
+    pub true_alt: f64,
+    /// Apparent altitude, corrected for refraction | in radians
+    pub refracted_alt: f64,
+}
+
+impl ObservedPoint {
+    /**
+    Returns an observed point from the true altitude
+
+    # Returns
+
+    * observed_point: The observed point
+
+    No refraction is applied more than 2° below the horizon.
+
+    # Arguments
+
+    * az      : Azimuth, measured westward from the south | in radians
+    * true_alt: True altitude | in radians
+    * pressure: Local pressure | in millibars
+    * temp    : Local temperature | in kelvins
+    **/
+    pub fn from_true_alt(az: f64, true_alt: f64, pressure: f64, temp: f64) -> ObservedPoint {
+        let refraction = if true_alt < MIN_REFRACTION_ALT.to_radians() {
+            0.0
+        } else {
+            atmospheric::refrac_true_alt(true_alt)
+                * atmospheric::refrac_pressure(pressure)
+                * atmospheric::refrac_temp(temp)
+        };
+
+        ObservedPoint {
+            az,
+            true_alt,
+            refracted_alt: true_alt + refraction,
+        }
+    }
+
+    /**
+    Returns an observed point from the refracted altitude
+
+    # Returns
+
+    * observed_point: The observed point
+
+    No refraction is removed more than 2° below the horizon.
+
+    # Arguments
+
+    * az           : Azimuth, measured westward from the south | in
+                     radians
+    * refracted_alt: Apparent altitude, as measured | in radians
+    * pressure     : Local pressure | in millibars
+    * temp         : Local temperature | in kelvins
+    **/
+    pub fn from_refracted_alt(
+        az: f64,
+        refracted_alt: f64,
+        pressure: f64,
+        temp: f64,
+    ) -> ObservedPoint {
+        let refraction = if refracted_alt < MIN_REFRACTION_ALT.to_radians() {
+            0.0
+        } else {
+            atmospheric::refrac_apparent_altitude(refracted_alt)
+                * atmospheric::refrac_pressure(pressure)
+                * atmospheric::refrac_temp(temp)
+        };
+
+        ObservedPoint {
+            az,
+            true_alt: refracted_alt - refraction,
+            refracted_alt,
+        }
+    }
+
+    /// Returns the refraction, the refracted altitude less the true
+    /// one | in radians
+    pub fn refraction(&self) -> f64 {
+        self.refracted_alt - self.true_alt
+    }
+
+    /// Returns the true zenith distance | in radians
+    pub fn true_zenith_distance(&self) -> f64 {
+        zenith_distance(self.true_alt)
+    }
+
+    /// Returns the apparent zenith distance | in radians
+    pub fn refracted_zenith_distance(&self) -> f64 {
+        zenith_distance(self.refracted_alt)
+    }
+
+    /**
+    Computes the airmass along the line of sight
+
+    # Returns
+
+    * airmass: Relative optical path length through the atmosphere,
+               1 at the zenith, or `None` below the horizon
+
+    The formula of Kasten and Young (1989) is used, on the apparent
+    zenith distance; it gives about 38 at the horizon.
+    **/
+    pub fn airmass(&self) -> Option<f64> {
+        if self.refracted_alt < 0.0 {
+            return None;
+        }
+
+        let zenith_dist = self.refracted_zenith_distance();
+
+        Some(
+            1.0 / (zenith_dist.cos()
+                + 0.50572 * (96.07995 - zenith_dist.to_degrees()).powf(-1.6364)),
+        )
+    }
+}
+
+/// Represents an angle whose sine and cosine are at hand
+///
+/// The conversion functions take the obliquity of the ecliptic and the
+/// observer's latitude as any `SinCos`: a plain angle, whose sine and
+/// cosine are computed on each call, or a
+/// [PrecomputedObliquity](./struct.PrecomputedObliquity.html) or
+/// [PrecomputedLatitude](./struct.PrecomputedLatitude.html), which
+/// computes them once for converting many points.
+pub trait SinCos<F: Float>: Copy {
+    /// Returns the angle | in radians
+    fn angle(&self) -> F;
+    /// Returns the sine of the angle
+    fn sine(&self) -> F;
+    /// Returns the cosine of the angle
+    fn cosine(&self) -> F;
+}
+
+impl<F: Float> SinCos<F> for F {
+    fn angle(&self) -> F {
+        *self
+    }
+
+    fn sine(&self) -> F {
+        self.sin()
+    }
+
+    fn cosine(&self) -> F {
+        self.cos()
+    }
+}
+
+/// Represents the obliquity of the ecliptic with its sine and cosine
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PrecomputedObliquity<F> {
+    oblq_eclip: F,
+    sin: F,
+    cos: F,
+}
+
+impl<F: Float> PrecomputedObliquity<F> {
+    /// Precomputes the sine and cosine of the obliquity of the
+    /// ecliptic | in radians
+    pub fn new(oblq_eclip: F) -> PrecomputedObliquity<F> {
+        let (sin, cos) = oblq_eclip.sin_cos();
+        PrecomputedObliquity {
+            oblq_eclip,
+            sin,
+            cos,
+        }
+    }
+}
+
+impl<F: Float> SinCos<F> for PrecomputedObliquity<F> {
+    fn angle(&self) -> F {
+        self.oblq_eclip
+    }
+
+    fn sine(&self) -> F {
+        self.sin
+    }
+
+    fn cosine(&self) -> F {
+        self.cos
+    }
+}
+
+/// Represents an observer's geographical latitude with its sine and
+/// cosine
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PrecomputedLatitude<F> {
+    lat: F,
+    sin: F,
+    cos: F,
+}
+
+impl<F: Float> PrecomputedLatitude<F> {
+    /// Precomputes the sine and cosine of an observer's geographical
+    /// latitude | in radians
+    pub fn new(lat: F) -> PrecomputedLatitude<F> {
+        let (sin, cos) = lat.sin_cos();
+        PrecomputedLatitude { lat, sin, cos }
+    }
+}
+
+impl<F: Float> SinCos<F> for PrecomputedLatitude<F> {
+    fn angle(&self) -> F {
+        self.lat
+    }
+
+    fn sine(&self) -> F {
+        self.sin
+    }
+
+    fn cosine(&self) -> F {
+        self.cos
+    }
+}
+
 /**
 Computes the hour angle from geographical longitude and Greenwich
 sidereal time
@@ -84,7 +663,7 @@ sidereal time
 * asc: Right ascension | in radians
 **/
 #[inline]
-pub fn hour_angle_from_long(green_sidereal: f64, observer_long: f64, asc: f64) -> f64 {
+pub fn hour_angle_from_long<F: Float>(green_sidereal: F, observer_long: F, asc: F) -> F {
     green_sidereal - observer_long - asc
 }
 
@@ -102,7 +681,7 @@ ascension
 * asc: Right ascension | in radians
 **/
 #[inline]
-pub fn hour_angle_from_sidereal(local_sidereal: f64, asc: f64) -> f64 {
+pub fn hour_angle_from_sidereal<F: Float>(local_sidereal: F, asc: F) -> F {
     local_sidereal - asc
 }
 
@@ -121,8 +700,8 @@ Computes the ecliptic longitude from equatorial coordinates
                     nutation, then true obliquity. If not, then
                     mean obliquity. | in radians
 **/
-pub fn ecliptic_long_from_eq(asc: f64, dec: f64, oblq_eclip: f64) -> f64 {
-    (asc.sin() * oblq_eclip.cos() + dec.tan() * oblq_eclip.sin()).atan2(asc.cos())
+pub fn ecliptic_long_from_eq<F: Float, O: SinCos<F>>(asc: F, dec: F, oblq_eclip: O) -> F {
+    (asc.sin() * oblq_eclip.cosine() + dec.tan() * oblq_eclip.sine()).atan2(asc.cos())
 }
 
 /**
@@ -140,8 +719,8 @@ Computes the ecliptic latitude from equatorial coordinates
                     nutation, then true obliquity. If not, then
                     mean obliquity. | in radians
 **/
-pub fn ecliptic_lat_from_eq(asc: f64, dec: f64, oblq_eclip: f64) -> f64 {
-    (dec.sin() * oblq_eclip.cos() - dec.cos() * oblq_eclip.sin() * asc.sin()).asin()
+pub fn ecliptic_lat_from_eq<F: Float, O: SinCos<F>>(asc: F, dec: F, oblq_eclip: O) -> F {
+    (dec.sin() * oblq_eclip.cosine() - dec.cos() * oblq_eclip.sine() * asc.sin()).asin()
 }
 
 /**
@@ -166,8 +745,8 @@ Computes ecliptic coordinates from equatorial coordinates
 macro_rules! ecliptic_from_eq {
     ($asc: expr, $dec: expr, $oblq_eclip: expr) => {{
         (
-            apollo::coordinate::ecliptic_long_from_eq($asc, $dec, $oblq_eclip),
-            apollo::coordinate::ecliptic_lat_from_eq($asc, $dec, $oblq_eclip),
+            $crate::coordinate::ecliptic_long_from_eq($asc, $dec, $oblq_eclip),
+            $crate::coordinate::ecliptic_lat_from_eq($asc, $dec, $oblq_eclip),
         )
     }};
 }
@@ -187,8 +766,8 @@ Computes the right ascension from ecliptic coordinates
                     for nutation, then true obliquity. If not, then
                     mean obliquity. | in radians
 **/
-pub fn asc_from_ecliptic(ecl_long: f64, ecl_lat: f64, oblq_eclip: f64) -> f64 {
-    (ecl_long.sin() * oblq_eclip.cos() - ecl_lat.tan() * oblq_eclip.sin()).atan2(ecl_long.cos())
+pub fn asc_from_ecliptic<F: Float, O: SinCos<F>>(ecl_long: F, ecl_lat: F, oblq_eclip: O) -> F {
+    (ecl_long.sin() * oblq_eclip.cosine() - ecl_lat.tan() * oblq_eclip.sine()).atan2(ecl_long.cos())
 }
 
 /**
@@ -206,8 +785,9 @@ Computes the declination from ecliptic coordinates
                     for nutation, then true obliquity. If not, then
                     mean obliquity. | in radians
 **/
-pub fn dec_from_ecliptic(ecl_long: f64, ecl_lat: f64, oblq_eclip: f64) -> f64 {
-    (ecl_lat.sin() * oblq_eclip.cos() + ecl_lat.cos() * oblq_eclip.sin() * ecl_long.sin()).asin()
+pub fn dec_from_ecliptic<F: Float, O: SinCos<F>>(ecl_long: F, ecl_lat: F, oblq_eclip: O) -> F {
+    (ecl_lat.sin() * oblq_eclip.cosine() + ecl_lat.cos() * oblq_eclip.sine() * ecl_long.sin())
+        .asin()
 }
 
 /**
@@ -232,8 +812,8 @@ Computes equatorial coordinates from ecliptic coordinates
 macro_rules! eq_from_ecliptic {
     ($ecl_long: expr, $ecl_lat: expr, $oblq_eclip: expr) => {{
         (
-            apollo::coordinate::asc_from_ecliptic($ecl_long, $ecl_lat, $oblq_eclip),
-            apollo::coordinate::dec_from_ecliptic($ecl_long, $ecl_lat, $oblq_eclip),
+            $crate::coordinate::asc_from_ecliptic($ecl_long, $ecl_lat, $oblq_eclip),
+            $crate::coordinate::dec_from_ecliptic($ecl_long, $ecl_lat, $oblq_eclip),
         )
     }};
 }
@@ -243,7 +823,11 @@ Computes the azimuth from equatorial coordinates
 
 # Returns
 
-* az: Azimuth | in radians
+* az: Azimuth, measured westward from the south | in radians
+
+This is the convention of the Meeus book, used throughout this crate.
+[azimuth_north_from_eq()](./fn.azimuth_north_from_eq.html) gives the
+azimuth measured eastward from the north.
 
 # Arguments
 
@@ -251,10 +835,81 @@ Computes the azimuth from equatorial coordinates
 * dec: Declination | in radians
 * observer_lat: Observer's geographical latitude | in radians
 **/
-pub fn azimuth_from_eq(hour_angle: f64, dec: f64, observer_lat: f64) -> f64 {
+pub fn azimuth_from_eq<F: Float, L: SinCos<F>>(hour_angle: F, dec: F, observer_lat: L) -> F {
     hour_angle
         .sin()
-        .atan2(hour_angle.cos() * observer_lat.sin() - dec.tan() * observer_lat.cos())
+        .atan2(hour_angle.cos() * observer_lat.sine() - dec.tan() * observer_lat.cosine())
+}
+
+/**
+Computes the azimuth from equatorial coordinates, measured eastward
+from the north
+
+# Returns
+
+* az: Azimuth, measured eastward from the north, 0 - 2π | in radians
+
+# Arguments
+
+* hour_angle: Hour angle | in radians
+* dec: Declination | in radians
+* observer_lat: Observer's geographical latitude | in radians
+**/
+pub fn azimuth_north_from_eq<F: Float, L: SinCos<F>>(hour_angle: F, dec: F, observer_lat: L) -> F {
+    azimuth_north_from_south(azimuth_from_eq(hour_angle, dec, observer_lat))
+}
+
+/**
+Converts an azimuth measured westward from the south to one measured
+eastward from the north
+
+# Returns
+
+* az_north: Azimuth, measured eastward from the north, 0 - 2π
+            | in radians
+
+# Arguments
+
+* az_south: Azimuth, measured westward from the south | in radians
+**/
+pub fn azimuth_north_from_south<F: Float>(az_south: F) -> F {
+    limit_two_pi(az_south + F::from(std::f64::consts::PI).unwrap())
+}
+
+/**
+Converts an azimuth measured eastward from the north to one measured
+westward from the south
+
+# Returns
+
+* az_south: Azimuth, measured westward from the south, 0 - 2π
+            | in radians
+
+# Arguments
+
+* az_north: Azimuth, measured eastward from the north | in radians
+**/
+pub fn azimuth_south_from_north<F: Float>(az_north: F) -> F {
+    limit_two_pi(az_north - F::from(std::f64::consts::PI).unwrap())
+}
+
+// Equivalent angle in the [0, 2π) range, computed in the precision of
+// the angle
+fn limit_two_pi<F: Float>(angl: F) -> F {
+    let two_pi = F::from(angle::TWO_PI).unwrap();
+    let limited_angl = angl % two_pi;
+    let limited_angl = if limited_angl < F::zero() {
+        limited_angl + two_pi
+    } else {
+        limited_angl
+    };
+
+    // Adding 2π to a tiny negative remainder can round to 2π
+    if limited_angl >= two_pi {
+        F::zero()
+    } else {
+        limited_angl
+    }
 }
 
 /**
@@ -270,8 +925,8 @@ Computes the altitude from equatorial coordinates
 * dec: Declination | in radians
 * observer_lat: Observer's geographical latitude | in radians
 **/
-pub fn altitude_from_eq(hour_angle: f64, dec: f64, observer_lat: f64) -> f64 {
-    (observer_lat.sin() * dec.sin() + observer_lat.cos() * dec.cos() * hour_angle.cos()).asin()
+pub fn altitude_from_eq<F: Float, L: SinCos<F>>(hour_angle: F, dec: F, observer_lat: L) -> F {
+    (observer_lat.sine() * dec.sin() + observer_lat.cosine() * dec.cos() * hour_angle.cos()).asin()
 }
 
 /**
@@ -281,7 +936,7 @@ Computes local horizontal coordinates from equatorial coordinates
 
 (az, alt)
 
-* az: Azimuth | in radians
+* az: Azimuth, measured westward from the south | in radians
 * alt: Altitude | in radians
 
 # Arguments
@@ -294,12 +949,60 @@ Computes local horizontal coordinates from equatorial coordinates
 macro_rules! horizontal_from_eq {
     ($hour_angle: expr, $dec: expr, $observer_lat: expr) => {{
         (
-            apollo::coordinate::azimuth_from_eq($hour_angle, $dec, $observer_lat),
-            apollo::coordinate::altitude_from_eq($hour_angle, $dec, $observer_lat),
+            $crate::coordinate::azimuth_from_eq($hour_angle, $dec, $observer_lat),
+            $crate::coordinate::altitude_from_eq($hour_angle, $dec, $observer_lat),
         )
     }};
 }
 
+/**
+Computes local horizontal coordinates from ecliptic coordinates
+
+# Returns
+
+(az, alt)
+
+* az: Azimuth, measured westward from the south | in radians
+* alt: Altitude | in radians
+
+The direction is rotated from the ecliptic frame to the horizontal
+frame in one go, without forming the right ascension and declination.
+
+# Arguments
+
+* ecl_long: Ecliptic longitude | in radians
+* ecl_lat: Ecliptic latitude | in radians
+* oblq_eclip: Obliquity of the ecliptic | in radians
+* local_sidereal: Local sidereal time | in radians
+* observer_lat: Observer's geographical latitude | in radians
+**/
+pub fn horizontal_from_ecliptic<F: Float, O: SinCos<F>, L: SinCos<F>>(
+    ecl_long: F,
+    ecl_lat: F,
+    oblq_eclip: O,
+    local_sidereal: F,
+    observer_lat: L,
+) -> (F, F) {
+    let (sin_long, cos_long) = ecl_long.sin_cos();
+    let (sin_lat, cos_lat) = ecl_lat.sin_cos();
+    let (sin_sidereal, cos_sidereal) = local_sidereal.sin_cos();
+
+    // Direction cosines in the equatorial frame
+    let x = cos_lat * cos_long;
+    let y = cos_lat * sin_long * oblq_eclip.cosine() - sin_lat * oblq_eclip.sine();
+    let z = cos_lat * sin_long * oblq_eclip.sine() + sin_lat * oblq_eclip.cosine();
+
+    // Direction cosines in the hour angle frame, cos H cos δ and
+    // sin H cos δ
+    let x_hour = x * cos_sidereal + y * sin_sidereal;
+    let y_hour = x * sin_sidereal - y * cos_sidereal;
+
+    let az = y_hour.atan2(x_hour * observer_lat.sine() - z * observer_lat.cosine());
+    let alt = (z * observer_lat.sine() + x_hour * observer_lat.cosine()).asin();
+
+    (az, alt)
+}
+
 /**
 Computes the hour angle from local horizontal coordinates
 
@@ -309,13 +1012,13 @@ Computes the hour angle from local horizontal coordinates
 
 # Arguments
 
-* az: Azimuth | in radians
+* az: Azimuth, measured westward from the south | in radians
 * alt: Altitude | in radians
 * observer_lat: Observer's geographical latitude | in radians
 **/
-pub fn hour_angle_from_horizontal(az: f64, alt: f64, observer_lat: f64) -> f64 {
+pub fn hour_angle_from_horizontal<F: Float, L: SinCos<F>>(az: F, alt: F, observer_lat: L) -> F {
     az.sin()
-        .atan2(az.cos() * observer_lat.sin() + alt.tan() * observer_lat.cos())
+        .atan2(az.cos() * observer_lat.sine() + alt.tan() * observer_lat.cosine())
 }
 
 /**
@@ -327,12 +1030,120 @@ Computes the declination from local horizontal coordinates
 
 # Arguments
 
-* az: Azimuth | in radians
+* az: Azimuth, measured westward from the south | in radians
 * alt: Altitude | in radians
 * observer_lat: Observer's geographical latitude | in radians
 **/
-pub fn dec_from_horizontal(az: f64, alt: f64, observer_lat: f64) -> f64 {
-    (observer_lat.sin() * alt.sin() - observer_lat.cos() * az.cos() * az.cos()).asin()
+pub fn dec_from_horizontal<F: Float, L: SinCos<F>>(az: F, alt: F, observer_lat: L) -> F {
+    (observer_lat.sine() * alt.sin() - observer_lat.cosine() * alt.cos() * az.cos()).asin()
+}
+
+/**
+Computes the zenith distance from the altitude
+
+# Returns
+
+* zenith_dist: Zenith distance | in radians
+
+# Arguments
+
+* alt: Altitude | in radians
+**/
+#[inline]
+pub fn zenith_distance<F: Float>(alt: F) -> F {
+    F::from(std::f64::consts::FRAC_PI_2).unwrap() - alt
+}
+
+/**
+Computes the altitude from the zenith distance
+
+# Returns
+
+* alt: Altitude | in radians
+
+# Arguments
+
+* zenith_dist: Zenith distance | in radians
+**/
+#[inline]
+pub fn altitude_from_zenith_distance<F: Float>(zenith_dist: F) -> F {
+    F::from(std::f64::consts::FRAC_PI_2).unwrap() - zenith_dist
+}
+
+/**
+Computes the hour angle at which a body reaches an altitude
+
+# Returns
+
+* hour_angle: Hour angle at which the body reaches the altitude west
+              of the meridian, in [0, π], or `None` if it stays above
+              or below it | in radians
+
+The body reaches the altitude east of the meridian at −`hour_angle`,
+so `hour_angle` is the semi-diurnal arc for the altitude.
+
+# Arguments
+
+* alt: Altitude | in radians
+* dec: Declination | in radians
+* observer_lat: Observer's geographical latitude | in radians
+**/
+pub fn hour_angle_at_altitude<F: Float, L: SinCos<F>>(
+    alt: F,
+    dec: F,
+    observer_lat: L,
+) -> Option<F> {
+    let cos_hour_angle =
+        (alt.sin() - observer_lat.sine() * dec.sin()) / (observer_lat.cosine() * dec.cos());
+
+    if cos_hour_angle.abs() <= F::one() {
+        Some(cos_hour_angle.acos())
+    } else {
+        None
+    }
+}
+
+/**
+Computes the parallactic angle
+
+# Returns
+
+* parallactic_angle: Angle between the directions of the zenith and
+                     of the north celestial pole at a point | in
+                     radians
+
+# Arguments
+
+* hour_angle: Hour angle | in radians
+* dec: Declination | in radians
+* observer_lat: Observer's geographical latitude | in radians
+**/
+pub fn parallactic_angle<F: Float, L: SinCos<F>>(hour_angle: F, dec: F, observer_lat: L) -> F {
+    // Both arguments of atan2 are multiplied by cos φ, which is positive
+    (hour_angle.sin() * observer_lat.cosine()).atan2(
+        observer_lat.sine() * dec.cos() - observer_lat.cosine() * dec.sin() * hour_angle.cos(),
+    )
+}
+
+/**
+Computes the rate of field rotation for an alt-azimuth mount
+
+# Returns
+
+* field_rotation_rate: Rate of change of the parallactic angle, the
+                       rate at which a derotator must turn | in
+                       radians per second
+
+# Arguments
+
+* az: Azimuth, measured westward from the south | in radians
+* alt: Altitude | in radians
+* observer_lat: Observer's geographical latitude | in radians
+**/
+pub fn field_rotation_rate<F: Float, L: SinCos<F>>(az: F, alt: F, observer_lat: L) -> F {
+    let earth_rotation = F::from(crate::planet::earth::ROTATIONAL_ANGULAR_VELOCITY).unwrap();
+
+    earth_rotation * observer_lat.cosine() * az.cos() / alt.cos()
 }
 
 /**
@@ -401,8 +1212,8 @@ standard equinox of B1950.0.
 macro_rules! galactic_from_eq {
     ($asc: expr, $dec: expr) => {{
         (
-            apollo::coordinate::galactic_long_from_eq($asc, $dec),
-            apollo::coordinate::galactic_lat_from_eq($asc, $dec),
+            $crate::coordinate::galactic_long_from_eq($asc, $dec),
+            $crate::coordinate::galactic_lat_from_eq($asc, $dec),
         )
     }};
 }
@@ -473,8 +1284,96 @@ equinox of  B1950.0.
 macro_rules! eq_from_galactic {
     ($gal_long: expr, $gal_lat: expr) => {{
         (
-            apollo::coordinate::asc_from_galactic($gal_long, $gal_lat),
-            apollo::coordinate::dec_from_galactic($gal_long, $gal_lat),
+            $crate::coordinate::asc_from_galactic($gal_long, $gal_lat),
+            $crate::coordinate::dec_from_galactic($gal_long, $gal_lat),
         )
     }};
 }
+
+/**
+Computes ecliptic coordinates directly from galactic coordinates
+
+# Returns
+
+(ecl_long, ecl_lat)
+
+* ecl_long: Ecliptic longitude | in radians
+* ecl_lat : Ecliptic latitude | in radians
+
+The rotations from galactic to equatorial and from equatorial to
+ecliptic coordinates are composed into a single rotation, so no
+intermediate right ascension and declination are computed. The
+ecliptic coordinates returned here are referred to the standard
+equinox of B1950.0.
+
+# Arguments
+
+* gal_long  : Galactic longitude | in radians
+* gal_lat   : Galactic latitude | in radians
+* oblq_eclip: Obliquity of the ecliptic of B1950.0, 23°26′44.84″
+              | in radians
+**/
+pub fn ecliptic_from_galactic(gal_long: f64, gal_lat: f64, oblq_eclip: f64) -> (f64, f64) {
+    let m = galactic_to_ecliptic_matrix(oblq_eclip);
+    let g = sphere::rectangular(gal_long, gal_lat);
+
+    let e: [f64; 3] = std::array::from_fn(|i| m[i][0] * g[0] + m[i][1] * g[1] + m[i][2] * g[2]);
+
+    sphere::spherical(&e)
+}
+
+/**
+Computes galactic coordinates directly from ecliptic coordinates
+
+# Returns
+
+(gal_long, gal_lat)
+
+* gal_long: Galactic longitude | in radians
+* gal_lat : Galactic latitude | in radians
+
+This is the inverse of
+[ecliptic_from_galactic()](./fn.ecliptic_from_galactic.html). The
+ecliptic coordinates passed are assumed to be referred to the
+standard equinox of B1950.0.
+
+# Arguments
+
+* ecl_long  : Ecliptic longitude | in radians
+* ecl_lat   : Ecliptic latitude | in radians
+* oblq_eclip: Obliquity of the ecliptic of B1950.0, 23°26′44.84″
+              | in radians
+**/
+pub fn galactic_from_ecliptic(ecl_long: f64, ecl_lat: f64, oblq_eclip: f64) -> (f64, f64) {
+    let m = galactic_to_ecliptic_matrix(oblq_eclip);
+    let e = sphere::rectangular(ecl_long, ecl_lat);
+
+    // The inverse of a rotation is its transpose
+    let g: [f64; 3] = std::array::from_fn(|i| m[0][i] * e[0] + m[1][i] * e[1] + m[2][i] * e[2]);
+
+    sphere::spherical(&g)
+}
+
+// Rotation from galactic to B1950.0 ecliptic rectangular coordinates.
+// The columns of the galactic-to-equatorial rotation are the
+// equatorial directions of the galactic axes, which are then rotated
+// about the equinox direction by the obliquity.
+fn galactic_to_ecliptic_matrix(oblq_eclip: f64) -> [[f64; 3]; 3] {
+    let half_pi = std::f64::consts::FRAC_PI_2;
+    let axes = [(0.0, 0.0), (half_pi, 0.0), (0.0, half_pi)].map(|(gal_long, gal_lat)| {
+        sphere::rectangular(
+            asc_from_galactic(gal_long, gal_lat),
+            dec_from_galactic(gal_long, gal_lat),
+        )
+    });
+
+    let (sin_oblq, cos_oblq) = oblq_eclip.sin_cos();
+    let mut m = [[0.0; 3]; 3];
+    for (j, axis) in axes.iter().enumerate() {
+        m[0][j] = axis[0];
+        m[1][j] = axis[1] * cos_oblq + axis[2] * sin_oblq;
+        m[2][j] = -axis[1] * sin_oblq + axis[2] * cos_oblq;
+    }
+
+    m
+}
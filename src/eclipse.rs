@@ -0,0 +1,716 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------ECLIPSE-----------!
+
+use crate::angle;
+use crate::au;
+use crate::config;
+use crate::coordinate;
+use crate::ephemeris;
+use crate::lunar;
+use crate::search;
+use crate::sun;
+use crate::time;
+use crate::Accuracy;
+use crate::Measurement;
+
+// Half-width of the time window searched for the greatest local
+// phase of a solar eclipse, and the sampling step | in days
+const LOCAL_WINDOW: f64 = 3.0 / 24.0;
+const LOCAL_STEP: f64 = 2.0 / 1440.0;
+
+// Sampling step of the search for the first and fourth contacts, and
+// tolerance of the contact times | in days
+const CONTACT_STEP: f64 = 5.0 / 1440.0;
+const CONTACT_TOLERANCE: f64 = 0.5 / 86400.0;
+
+// Enlargement of the shadow by the Earth's atmosphere, after Danjon
+const SHADOW_ENLARGEMENT: f64 = 1.01;
+
+// Standard deviation of the enlargement of the shadow, which varies
+// from eclipse to eclipse with the state of the Earth's atmosphere
+const SHADOW_ENLARGEMENT_SIGMA: f64 = 0.005;
+
+/// Represents the Earth's shadow at the distance of the Moon
+#[derive(Copy, Clone, Debug)]
+pub struct EarthShadow {
+    /// Angular radius of the umbra | in radians
+    pub umbra_radius: f64,
+    /// Angular radius of the penumbra | in radians
+    pub penumbra_radius: f64,
+    /// Geocentric equatorial point of the center of the shadow, the
+    /// anti-solar point | in radians
+    pub center: coordinate::EqPoint,
+    /// Earth-Moon distance | in kilometers
+    pub earth_moon_dist: f64,
+}
+
+/**
+Computes the Earth's shadow at the distance of the Moon
+
+# Returns
+
+* earth_shadow: Angular radii of the umbra and penumbra as seen from
+                the center of the Earth, and the anti-solar point
+
+The radii are `1.01π + π☉ ∓ s☉`, π being the Moon's horizontal
+parallax, and π☉ and s☉ the Sun's horizontal parallax and
+semidiameter. The factor 1.01 accounts for the enlargement of the
+shadow by the Earth's atmosphere, after A. Danjon.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn earth_shadow(jd: f64) -> EarthShadow {
+    shadow(jd, Accuracy::High)
+}
+
+/**
+Computes the radii of the Earth's shadow at the distance of the Moon,
+with their uncertainties
+
+# Returns
+
+(umbra_radius, penumbra_radius)
+
+* umbra_radius   : Angular radius of the umbra and its standard
+                   uncertainty | in radians
+* penumbra_radius: Angular radius of the penumbra and its standard
+                   uncertainty | in radians
+
+The radii are those of [earth_shadow()](./fn.earth_shadow.html). The
+enlargement of the shadow by the Earth's atmosphere varies from
+eclipse to eclipse; an uncertainty of 0.005 in Danjon's factor of
+1.01, about 17″, is propagated to both radii. It dominates the
+uncertainty of the umbral and penumbral magnitudes of a lunar eclipse.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn earth_shadow_radii_with_uncertainty(jd: f64) -> (Measurement, Measurement) {
+    let shadow = shadow(jd, Accuracy::High);
+    let sigma = SHADOW_ENLARGEMENT_SIGMA * lunar::horizontal_parallax(shadow.earth_moon_dist);
+
+    (
+        Measurement {
+            value: shadow.umbra_radius,
+            sigma,
+        },
+        Measurement {
+            value: shadow.penumbra_radius,
+            sigma,
+        },
+    )
+}
+
+/// Represents the kind of an eclipse
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EclipseKind {
+    /// Solar eclipse, at a new Moon
+    Solar,
+    /// Lunar eclipse, at a full Moon
+    Lunar,
+}
+
+/// Represents the Saros and Inex series of an eclipse
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SarosInex {
+    /// Kind of the eclipse
+    pub kind: EclipseKind,
+    /// Saros series number
+    pub saros: i64,
+    /// Inex series number
+    pub inex: i64,
+    /// Position of the eclipse within its Saros series, from 1
+    pub member: u32,
+    /// Number of eclipses in the Saros series
+    pub members: u32,
+}
+
+// Reference eclipses (kind, Meeus lunation, Saros, Inex): the total
+// solar eclipse of 2024 April 8 and the total lunar eclipse of 2025
+// March 14
+const SOLAR_REFERENCE: (f64, i64, i64) = (300.0, 139, 50);
+const LUNAR_REFERENCE: (f64, i64, i64) = (311.5, 123, 50);
+
+/**
+Identifies the Saros and Inex series of an eclipse
+
+# Returns
+
+* saros_inex: Kind of the eclipse, its Saros and Inex numbers, and its
+              position within the Saros series
+
+Consecutive eclipses of a Saros series are 223 lunations apart, and
+of an Inex series 358 lunations apart. Saros series are numbered as
+in the catalogs of F. Espenak, in which the solar eclipse of 2024
+April 8 belongs to Saros 139 and the lunar eclipse of 2025 March 14 to
+Saros 123. Inex series are numbered so that both of those eclipses
+belong to Inex 50. The members of the Saros series are found by
+testing each syzygy 223 lunations apart for an eclipse, which holds
+within the span of the lunar theory, a few thousand years from 2000.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day of the eclipse, within a day of the
+      syzygy
+**/
+pub fn saros_series(jd: f64) -> Result<SarosInex, &'static str> {
+    let k = (2.0 * (jd - lunar::LUNATION_0) / lunar::SYNODIC_MONTH).round() / 2.0;
+    if (lunar::phase(k) - jd).abs() > 1.0 {
+        return Err("The time is not within a day of a new or full Moon");
+    }
+
+    let (kind, (ref_k, ref_saros, ref_inex)) = if k.rem_euclid(1.0) == 0.0 {
+        (EclipseKind::Solar, SOLAR_REFERENCE)
+    } else {
+        (EclipseKind::Lunar, LUNAR_REFERENCE)
+    };
+    if !is_eclipse(kind, k) {
+        return Err("No eclipse happens at the nearest new or full Moon");
+    }
+
+    // k = ref_k + 223 (inex - ref_inex) + 358 (saros - ref_saros), and
+    // 38 is the inverse of 358 modulo 223
+    let delta_k = (k - ref_k) as i64;
+    let delta_saros = (38 * delta_k).rem_euclid(223);
+    let delta_saros = if ref_saros + delta_saros > 180 {
+        delta_saros - 223
+    } else {
+        delta_saros
+    };
+    let delta_inex = (delta_k - 358 * delta_saros) / 223;
+
+    let count = |step: f64| {
+        let mut n = 0;
+        while is_eclipse(kind, k + step * (n + 1) as f64) {
+            n += 1;
+        }
+        n
+    };
+    let before = count(-223.0);
+    let after = count(223.0);
+
+    Ok(SarosInex {
+        kind,
+        saros: ref_saros + delta_saros,
+        inex: ref_inex + delta_inex,
+        member: before + 1,
+        members: before + after + 1,
+    })
+}
+
+// Whether an eclipse happens at the syzygy of lunation k. A solar
+// eclipse is seen somewhere on the Earth when the Moon comes within
+// π − π☉ + s☉ + s of the Sun, and a penumbral lunar eclipse happens
+// when it comes within the penumbra.
+fn is_eclipse(kind: EclipseKind, k: f64) -> bool {
+    let syzygy = lunar::phase(k);
+
+    let margin = |jd: f64| {
+        let (moon, earth_moon_dist) =
+            ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, jd, Accuracy::Low);
+        let earth_moon_dist = earth_moon_dist * au::AU_KM;
+        let moon_semidiameter = lunar::semidiameter(earth_moon_dist);
+
+        match kind {
+            EclipseKind::Solar => {
+                let (sun, earth_sun_dist) =
+                    ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jd, Accuracy::Low);
+                let sun_parallax = sun::horizontal_parallax(earth_sun_dist);
+                let sun_semidiameter = sun::semidiameter(earth_sun_dist);
+
+                moon.angular_sep(&sun)
+                    - (lunar::horizontal_parallax(earth_moon_dist) - sun_parallax
+                        + sun_semidiameter
+                        + moon_semidiameter)
+            }
+            EclipseKind::Lunar => {
+                let shadow = shadow(jd, Accuracy::Low);
+                moon.angular_sep(&shadow.center) - shadow.penumbra_radius - moon_semidiameter
+            }
+        }
+    };
+
+    // Golden-section search for the closest approach near the syzygy
+    let ratio = (5_f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = (syzygy - 0.25, syzygy + 0.25);
+    while b - a > 1.0 / 1440.0 {
+        let c = b - ratio * (b - a);
+        let d = a + ratio * (b - a);
+        if margin(c) < margin(d) {
+            b = d;
+        } else {
+            a = c;
+        }
+    }
+
+    margin((a + b) / 2.0) < 0.0
+}
+
+fn shadow(jd: f64, accuracy: Accuracy) -> EarthShadow {
+    let (sun, earth_sun_dist) = ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jd, accuracy);
+    let (_, earth_moon_dist) = ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, jd, accuracy);
+    let earth_moon_dist = earth_moon_dist * au::AU_KM;
+
+    let moon_parallax = lunar::horizontal_parallax(earth_moon_dist);
+    let sun_parallax = sun::horizontal_parallax(earth_sun_dist);
+    let sun_semidiameter = sun::semidiameter(earth_sun_dist);
+
+    let shadow = SHADOW_ENLARGEMENT * moon_parallax + sun_parallax;
+
+    EarthShadow {
+        umbra_radius: shadow - sun_semidiameter,
+        penumbra_radius: shadow + sun_semidiameter,
+        center: coordinate::EqPoint {
+            asc: angle::limit_twoPI(sun.asc + std::f64::consts::PI),
+            dec: -sun.dec,
+            equinox: sun.equinox,
+        },
+        earth_moon_dist,
+    }
+}
+
+/// Represents a grid of geographical points
+#[derive(Copy, Clone, Debug)]
+pub struct GridSpec {
+    /// Latitude of the first row | in radians
+    pub lat_start: f64,
+    /// Latitude of the last row | in radians
+    pub lat_end: f64,
+    /// Number of rows
+    pub n_lat: usize,
+    /// Longitude of the first column, positive westward | in radians
+    pub long_start: f64,
+    /// Longitude of the last column, positive westward | in radians
+    pub long_end: f64,
+    /// Number of columns
+    pub n_long: usize,
+}
+
+/// Represents the greatest phase of a solar eclipse at a point
+#[derive(Copy, Clone, Debug)]
+pub struct LocalMagnitude {
+    /// Geographical point | in radians
+    pub geograph_point: coordinate::GeographPoint,
+    /// Greatest magnitude, the fraction of the Sun's diameter covered
+    /// by the Moon, or 0.0 if the eclipse isn't seen
+    pub magnitude: f64,
+    /// Obscuration at the greatest magnitude, the fraction of the
+    /// Sun's disk covered by the Moon | range: 0.0 - 1.0
+    pub obscuration: f64,
+}
+
+/**
+Maps the greatest local magnitude of a solar eclipse over a grid
+
+# Returns
+
+* local_magnitudes: Greatest magnitude and obscuration at each point
+                    of the grid, row by row from `lat_start`, each
+                    row from `long_start`
+
+The topocentric Sun and Moon are sampled every two minutes within
+three hours of `jd_max`, counting only samples with the Sun above the
+horizon, and the greatest magnitude is interpolated between the
+samples. The topocentric semidiameter of the Moon is corrected for
+its augmentation. With the `rayon` feature, the points are evaluated
+in parallel. ΔT, which sets the rotation of the Earth under the
+shadow, is taken from the model set in the
+[config](../config/index.html) module.
+
+# Arguments
+
+* jd_max   : Julian (Ephemeris) day of the greatest eclipse
+* grid_spec: The grid
+**/
+pub fn map_magnitude(jd_max: f64, grid_spec: &GridSpec) -> Vec<LocalMagnitude> {
+    map_magnitude_with_model(jd_max, grid_spec, &config::get().delta_t)
+}
+
+/**
+Maps the greatest local magnitude of a solar eclipse over a grid
+with a given ΔT model
+
+# Returns
+
+* local_magnitudes: Greatest magnitude and obscuration at each point
+                    of the grid, as in
+                    [map_magnitude()](./fn.map_magnitude.html)
+
+The path of an eclipse on the Earth shifts in longitude with ΔT, by
+about 15″ per second of ΔT, which matters for ancient eclipses.
+
+# Arguments
+
+* jd_max   : Julian (Ephemeris) day of the greatest eclipse
+* grid_spec: The grid
+* model    : ΔT model
+**/
+pub fn map_magnitude_with_model(
+    jd_max: f64,
+    grid_spec: &GridSpec,
+    model: &time::DeltaTModel,
+) -> Vec<LocalMagnitude> {
+    let n_samples = (2.0 * LOCAL_WINDOW / LOCAL_STEP).round() as usize + 1;
+    let samples: Vec<EclipseSample> = (0..n_samples)
+        .map(|i| EclipseSample::new(jd_max - LOCAL_WINDOW + i as f64 * LOCAL_STEP, model))
+        .collect();
+
+    let step = |start: f64, end: f64, n: usize| {
+        if n > 1 {
+            (end - start) / (n - 1) as f64
+        } else {
+            0.0
+        }
+    };
+    let lat_step = step(grid_spec.lat_start, grid_spec.lat_end, grid_spec.n_lat);
+    let long_step = step(grid_spec.long_start, grid_spec.long_end, grid_spec.n_long);
+
+    let points: Vec<coordinate::GeographPoint> = (0..grid_spec.n_lat)
+        .flat_map(|i| {
+            (0..grid_spec.n_long).map(move |j| {
+                coordinate::GeographPoint::new(
+                    grid_spec.long_start + j as f64 * long_step,
+                    grid_spec.lat_start + i as f64 * lat_step,
+                )
+            })
+        })
+        .collect();
+
+    map_points(&points, |point| greatest_local_phase(&samples, point))
+}
+
+/// Represents the local circumstances of a solar eclipse
+#[derive(Copy, Clone, Debug)]
+pub struct LocalCircumstances {
+    /// First contact, the beginning of the partial eclipse
+    pub c1: time::Instant,
+    /// Second contact, the beginning of the total or annular eclipse,
+    /// `None` for a partial eclipse
+    pub c2: Option<time::Instant>,
+    /// Third contact, the end of the total or annular eclipse, `None`
+    /// for a partial eclipse
+    pub c3: Option<time::Instant>,
+    /// Fourth contact, the end of the partial eclipse
+    pub c4: time::Instant,
+    /// Greatest eclipse
+    pub max: time::Instant,
+    /// Magnitude at the greatest eclipse, the fraction of the Sun's
+    /// diameter covered by the Moon
+    pub magnitude: f64,
+    /// Obscuration at the greatest eclipse, the fraction of the Sun's
+    /// disk covered by the Moon | range: 0.0 - 1.0
+    pub obscuration: f64,
+    /// Altitude of the Sun at the greatest eclipse, not corrected for
+    /// atmospheric refraction | in radians
+    pub sun_alt: f64,
+}
+
+/**
+Computes the local circumstances of a solar eclipse
+
+# Returns
+
+* local_circumstances: Contact times, and the magnitude, obscuration
+                       and Sun's altitude at the greatest eclipse
+
+The contacts are those of the topocentric disks of the Sun and Moon,
+within three hours of `jd_max`, and are accurate to a few seconds;
+the profile of the lunar limb is ignored. An eclipse in progress at
+sunrise or sunset keeps the contacts that happen with the Sun below
+the horizon, so `sun_alt` should be checked. An error is returned if
+the disks don't overlap as seen from the observer's place, or if the
+Sun stays below the horizon throughout. ΔT, on which the contacts
+depend through the rotation of the Earth, is taken from the model set
+in the [config](../config/index.html) module.
+
+# Arguments
+
+* observer: The observer
+* jd_max  : Julian (Ephemeris) day of the greatest eclipse, or of the
+            new Moon
+**/
+pub fn local_circumstances(
+    observer: &coordinate::Observer,
+    jd_max: f64,
+) -> Result<LocalCircumstances, &'static str> {
+    local_circumstances_with_model(observer, jd_max, &config::get().delta_t)
+}
+
+/**
+Computes the local circumstances of a solar eclipse with a given ΔT
+model
+
+# Returns
+
+* local_circumstances: Contact times, and the magnitude, obscuration
+                       and Sun's altitude at the greatest eclipse, as
+                       in [local_circumstances()](./fn.local_circumstances.html)
+
+Both the TT and the UT of the contacts depend on the model.
+
+# Arguments
+
+* observer: The observer
+* jd_max  : Julian (Ephemeris) day of the greatest eclipse, or of the
+            new Moon
+* model   : ΔT model
+**/
+pub fn local_circumstances_with_model(
+    observer: &coordinate::Observer,
+    jd_max: f64,
+    model: &time::DeltaTModel,
+) -> Result<LocalCircumstances, &'static str> {
+    let point = coordinate::GeographPoint::new(observer.long, observer.lat);
+    let disks_at = |jd: f64| EclipseSample::new(jd, model).local_disks(&point, observer.height);
+    let instant = |jd: f64| time::Instant::from_tt_with_model(jd, model);
+
+    let (c1, c4) = *search::intervals(
+        jd_max - LOCAL_WINDOW,
+        jd_max + LOCAL_WINDOW,
+        CONTACT_STEP,
+        CONTACT_TOLERANCE,
+        |jd| disks_at(jd).overlap(),
+    )?
+    .first()
+    .ok_or("No eclipse is seen from this place")?;
+
+    let sun_up = |jd: f64| disks_at(jd).sun_alt > 0.0;
+    if search::intervals(c1, c4, CONTACT_STEP, CONTACT_TOLERANCE, sun_up)?.is_empty() {
+        return Err("The Sun is below the horizon during the eclipse");
+    }
+
+    // A grazing eclipse may touch the limb at a single instant
+    let max = if c4 > c1 {
+        search::minimize(c1, c4, c4 - c1, CONTACT_TOLERANCE, |jd| {
+            -disks_at(jd).magnitude()
+        })?
+        .0
+    } else {
+        c1
+    };
+    let disks = disks_at(max);
+
+    let (c2, c3) = if disks.central() {
+        let contact = |start: f64, end: f64| {
+            search::changes(start, end, end - start, CONTACT_TOLERANCE, |jd| {
+                disks_at(jd).central()
+            })
+            .ok()?
+            .first()
+            .map(|&(jd, _)| instant(jd))
+        };
+        (contact(c1, max), contact(max, c4))
+    } else {
+        (None, None)
+    };
+
+    Ok(LocalCircumstances {
+        c1: instant(c1),
+        c2,
+        c3,
+        c4: instant(c4),
+        max: instant(max),
+        magnitude: disks.magnitude(),
+        obscuration: disks.obscuration(),
+        sun_alt: disks.sun_alt,
+    })
+}
+
+// Geocentric positions of the Sun and Moon at an instant, with the
+// Greenwich apparent sidereal time under a ΔT model
+struct EclipseSample {
+    sidereal: f64,
+    sun: coordinate::EqPoint,
+    earth_sun_dist: f64,
+    moon: coordinate::EqPoint,
+    earth_moon_dist: f64,
+}
+
+impl EclipseSample {
+    fn new(jd: f64, model: &time::DeltaTModel) -> EclipseSample {
+        let (sun, earth_sun_dist) =
+            ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jd, Accuracy::High);
+        let (moon, earth_moon_dist) =
+            ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, jd, Accuracy::High);
+
+        EclipseSample {
+            sidereal: time::apparent_sidereal_from_jd(time::ut_from_tt_with_model(jd, model)),
+            sun,
+            earth_sun_dist,
+            moon,
+            earth_moon_dist: earth_moon_dist * au::AU_KM,
+        }
+    }
+
+    // Disks of the Sun and Moon seen from a point
+    fn local_disks(&self, point: &coordinate::GeographPoint, height: f64) -> LocalDisks {
+        let local_sidereal = self.sidereal - point.long;
+
+        let sun_parallax = sun::horizontal_parallax(self.earth_sun_dist);
+        let sun = coordinate::HaPoint::from_eq(&self.sun, local_sidereal).topocent(
+            sun_parallax.sin(),
+            point.lat,
+            height,
+        );
+        let (_, sun_alt) = sun.horizontal(point.lat);
+
+        let sin_moon_parallax = lunar::horizontal_parallax(self.earth_moon_dist).sin();
+        let moon = coordinate::HaPoint::from_eq(&self.moon, local_sidereal).topocent(
+            sin_moon_parallax,
+            point.lat,
+            height,
+        );
+        let (_, moon_alt) = moon.horizontal(point.lat);
+
+        LocalDisks {
+            sun_alt,
+            sun_radius: sun::semidiameter(self.earth_sun_dist),
+            moon_radius: lunar::semidiameter(self.earth_moon_dist)
+                / (1.0 - sin_moon_parallax * moon_alt.sin()),
+            sep: angle::angular_sep(sun.hour_angle, sun.dec, moon.hour_angle, moon.dec),
+        }
+    }
+
+    // Magnitude and obscuration seen from a point on the ellipsoid,
+    // or None if the Sun is below the horizon
+    fn local_phase(&self, point: &coordinate::GeographPoint) -> Option<(f64, f64)> {
+        let disks = self.local_disks(point, 0.0);
+        if disks.sun_alt < 0.0 {
+            return None;
+        }
+
+        Some((disks.magnitude().max(0.0), disks.obscuration()))
+    }
+}
+
+// Topocentric disks of the Sun and Moon, with the Sun's altitude
+struct LocalDisks {
+    sun_alt: f64,
+    sun_radius: f64,
+    moon_radius: f64,
+    sep: f64,
+}
+
+impl LocalDisks {
+    // Magnitude, negative outside the eclipse
+    fn magnitude(&self) -> f64 {
+        (self.sun_radius + self.moon_radius - self.sep) / (2.0 * self.sun_radius)
+    }
+
+    fn obscuration(&self) -> f64 {
+        obscuration(self.sun_radius, self.moon_radius, self.sep)
+    }
+
+    // Whether the disks overlap, between the first and fourth contacts
+    fn overlap(&self) -> bool {
+        self.sep < self.sun_radius + self.moon_radius
+    }
+
+    // Whether one disk lies within the other, between the second and
+    // third contacts
+    fn central(&self) -> bool {
+        self.sep < (self.moon_radius - self.sun_radius).abs()
+    }
+}
+
+// Greatest phase among the samples, refined by a parabola through the
+// greatest magnitude and its neighbors
+fn greatest_local_phase(
+    samples: &[EclipseSample],
+    point: &coordinate::GeographPoint,
+) -> LocalMagnitude {
+    let phases: Vec<Option<(f64, f64)>> = samples
+        .iter()
+        .map(|sample| sample.local_phase(point))
+        .collect();
+    let magnitude_at = |i: usize| phases[i].map_or(0.0, |(magnitude, _)| magnitude);
+
+    let greatest = (0..phases.len()).max_by(|&a, &b| magnitude_at(a).total_cmp(&magnitude_at(b)));
+    let (magnitude, obscuration) = match greatest {
+        Some(i) if magnitude_at(i) > 0.0 => {
+            let (magnitude, obscuration) = phases[i].unwrap_or((0.0, 0.0));
+            if i > 0 && i + 1 < phases.len() && phases[i - 1].is_some() && phases[i + 1].is_some() {
+                let (y_1, y_2, y_3) = (magnitude_at(i - 1), magnitude, magnitude_at(i + 1));
+                let curvature = y_1 - 2.0 * y_2 + y_3;
+                let peak = if curvature < 0.0 {
+                    y_2 - (y_3 - y_1) * (y_3 - y_1) / (8.0 * curvature)
+                } else {
+                    y_2
+                };
+                (peak, obscuration)
+            } else {
+                (magnitude, obscuration)
+            }
+        }
+        _ => (0.0, 0.0),
+    };
+
+    LocalMagnitude {
+        geograph_point: *point,
+        magnitude,
+        obscuration,
+    }
+}
+
+// Fraction of the area of a disk of radius r_1 covered by a disk of
+// radius r_2 whose center is d away
+fn obscuration(r_1: f64, r_2: f64, d: f64) -> f64 {
+    if d >= r_1 + r_2 {
+        return 0.0;
+    }
+    if d <= (r_1 - r_2).abs() {
+        return (r_2.min(r_1) / r_1).powi(2);
+    }
+
+    let angle_1 = ((d * d + r_1 * r_1 - r_2 * r_2) / (2.0 * d * r_1))
+        .clamp(-1.0, 1.0)
+        .acos();
+    let angle_2 = ((d * d + r_2 * r_2 - r_1 * r_1) / (2.0 * d * r_2))
+        .clamp(-1.0, 1.0)
+        .acos();
+    let kite = ((-d + r_1 + r_2) * (d + r_1 - r_2) * (d - r_1 + r_2) * (d + r_1 + r_2)).sqrt();
+
+    (r_1 * r_1 * angle_1 + r_2 * r_2 * angle_2 - kite / 2.0) / (std::f64::consts::PI * r_1 * r_1)
+}
+
+#[cfg(feature = "rayon")]
+fn map_points<F>(points: &[coordinate::GeographPoint], f: F) -> Vec<LocalMagnitude>
+where
+    F: Fn(&coordinate::GeographPoint) -> LocalMagnitude + Sync + Send,
+{
+    use rayon::prelude::*;
+
+    points.par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn map_points<F>(points: &[coordinate::GeographPoint], f: F) -> Vec<LocalMagnitude>
+where
+    F: Fn(&coordinate::GeographPoint) -> LocalMagnitude,
+{
+    points.iter().map(f).collect()
+}
@@ -20,10 +20,10 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 THE SOFTWARE.
 */
 
-
 use crate::angle;
-use std::f64::consts:PI;
+use crate::math::polynomial;
 use crate::time;
+use std::f64::consts::PI;
 
 /*
 Computes the mean obliquity of the ecliptic using
@@ -43,23 +43,35 @@ for 10000 years before and after 2000 AD.
 */
 
 pub fn mean_obliquity_laskar(JD: f64) -> f64 {
-    let u = time::julian_century(JD);
-    Horner_eval!(
-        u,
-        angle::deg_dmas(23, 26, 21.448),
-       -angle::deg_dmas(0,  0,  4680.93),
-       -angle::deg_dmas(0,  0,  1.55),
-        angle::deg_dmas(0,  0,  1999.25),
-       -angle::deg_dmas(0,  0,  51.38),
-       -angle::deg_dmas(0,  0,  249.67),
-       -angle::deg_dmas(0,  0,  39.05),
-        angle::deg_dmas(0,  0,  7.12),
-        angle::deg_dmas(0,  0,  27.87),
-        angle::deg_dmas(0,  0,  5.79),
-        angle::deg_dmas(0,  0,  2.45)
-    ).to_radians()
+    let u = time::julian_century(JD) / 100.0;
+    (LASKAR.eval(u) / 3600.0).to_radians()
+}
+
+/*
+Computes the rate of change of the mean obliquity of the ecliptic,
+the derivative of J. Laskar's formula
+
+# Returns
+
+* `mn_oblq_rate`: Rate of change of the mean obliquity of the
+                  ecliptic *| in radians per day*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+*/
+
+pub fn mean_obliquity_rate(JD: f64) -> f64 {
+    let u = time::julian_century(JD) / 100.0;
+    (LASKAR.eval_derivative(u) / 3600.0 / 3652500.0).to_radians()
 }
 
+// J. Laskar's formula for the mean obliquity of the ecliptic, in
+// units of 10000 Julian years from J2000.0 | in arcseconds
+const LASKAR: polynomial::Polynomial = polynomial::Polynomial::new(&[
+    84381.448, -4680.93, -1.55, 1999.25, -51.38, -249.67, -39.05, 7.12, 27.87, 5.79, 2.45,
+]);
+
 /*
 Computes the mean obliquity of the ecliptic using
 the IAU formula
@@ -78,16 +90,40 @@ The error in `mn_oblq` reaches 1 arcsecond over a period of
 */
 
 pub fn mean_obliquity_IAU(JD: f64) -> f64 {
-    let u = time::julian_century(JD) / 100.00;
-    Horner_eval!(
+    let u = time::julian_century(JD);
+    polynomial::horner(
+        &[
+            angle::deg_dmas(23, 26, 21.448),
+           -angle::deg_dmas(0,  0,  46.815),
+           -angle::deg_dmas(0,  0,  0.00059),
+            angle::deg_dmas(0,  0,  0.001813),
+        ],
         u,
-        angle::deg_dmas(23, 26, 21.448),
-       -angle::deg_dmas(0,  0,  46.815),
-       -angle::deg_dmas(0,  0,  0.00059),
-        angle::deg_dmas(0,  0,  0.001813)
     ).to_radians()
 }
 
+/// Represents a formula for the mean obliquity of the ecliptic
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ObliquityModel {
+    /// J. Laskar's formula, of
+    /// [mean_obliquity_laskar()](./fn.mean_obliquity_laskar.html)
+    Laskar,
+    /// The IAU formula, of
+    /// [mean_obliquity_IAU()](./fn.mean_obliquity_IAU.html)
+    IAU,
+}
+
+impl ObliquityModel {
+    /// Computes the mean obliquity of the ecliptic with the formula
+    /// | in radians
+    pub fn mean_obliquity(&self, JD: f64) -> f64 {
+        match self {
+            ObliquityModel::Laskar => mean_obliquity_laskar(JD),
+            ObliquityModel::IAU => mean_obliquity_IAU(JD),
+        }
+    }
+}
+
 /*
 Computes the longitudes of the two ecliptic points on
 a horizon on Earth
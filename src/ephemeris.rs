@@ -0,0 +1,400 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------EPHEMERIS-----------!
+
+use crate::angle;
+use crate::au;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::lunar;
+use crate::nutation;
+use crate::planet;
+use crate::sphere;
+use crate::sun;
+use crate::time;
+use crate::Accuracy;
+use std::collections::HashMap;
+
+/// Represents a body whose geocentric position can be computed
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Body {
+    /// The Sun
+    Sun,
+    /// The Moon
+    Moon,
+    /// Mars
+    Mars,
+}
+
+/**
+Computes the apparent geocentric ecliptic position of a body
+
+# Returns
+
+(ecl_point, dist)
+
+* ecl_point: Apparent geocentric ecliptic point of the body,
+             referred to the true equinox of the date | in radians
+* dist     : Earth-body distance | in AU
+
+# Arguments
+
+* body    : The body
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn apparent_ecl_pos(body: &Body, jd: f64, accuracy: Accuracy) -> (coordinate::EclPoint, f64) {
+    match body {
+        Body::Sun => sun::apparent_ecl_pos(jd, accuracy),
+        Body::Moon => {
+            let (ecl_point, earth_moon_dist) = lunar::apparent_ecl_pos(jd, accuracy);
+            (ecl_point, earth_moon_dist / au::AU_KM)
+        }
+        Body::Mars => planet::geocent_apparent_ecl_pos(&planet::Planet::Mars, jd, accuracy)
+            .expect("Internal error in ephemeris::apparent_ecl_pos"),
+    }
+}
+
+/**
+Computes the apparent geocentric equatorial position of a body
+
+# Returns
+
+(eq_point, dist)
+
+* eq_point: Apparent geocentric equatorial point of the body,
+            referred to the true equinox of the date | in radians
+* dist    : Earth-body distance | in AU
+
+# Arguments
+
+* body    : The body
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn apparent_eq_pos(body: &Body, jd: f64, accuracy: Accuracy) -> (coordinate::EqPoint, f64) {
+    let (ecl_point, dist) = apparent_ecl_pos(body, jd, accuracy);
+
+    let (_, nut_in_oblq) = nutation::nutation(jd, accuracy);
+    let oblq_eclip = ecliptic::mean_obliquity_laskar(jd) + nut_in_oblq;
+
+    let asc = coordinate::asc_from_ecliptic(ecl_point.long, ecl_point.lat, oblq_eclip);
+    let dec = coordinate::dec_from_ecliptic(ecl_point.long, ecl_point.lat, oblq_eclip);
+
+    (
+        coordinate::EqPoint::with_equinox(angle::limit_twoPI(asc), dec, jd),
+        dist,
+    )
+}
+
+// Topocentric apparent hour angle point of a body for an observer,
+// and the local apparent sidereal time | in radians
+pub(crate) fn topocent_ha_pos(
+    body: &Body,
+    observer: &coordinate::Observer,
+    instant: &time::Instant,
+    accuracy: Accuracy,
+) -> (coordinate::HaPoint, f64) {
+    let (eq_point, dist) = apparent_eq_pos(body, instant.jd_tt, accuracy);
+    // sin π = sin π₀ / Δ, π₀ being the horizontal parallax at 1 AU
+    let sin_parallax = sun::horizontal_parallax(1.0).sin() / dist;
+
+    let local_sidereal = time::apparent_sidereal_from_jd(instant.jd_ut) - observer.long;
+    let ha_point = coordinate::HaPoint::from_eq(&eq_point, local_sidereal).topocent(
+        sin_parallax,
+        observer.lat,
+        observer.height,
+    );
+
+    (ha_point, local_sidereal)
+}
+
+/// An iterator over the positions of a body at regular intervals
+///
+/// Created by [range()](./fn.range.html).
+#[derive(Debug)]
+pub struct Range {
+    body: Body,
+    start_jd: f64,
+    step_days: f64,
+    index: usize,
+    len: usize,
+    accuracy: Accuracy,
+}
+
+impl Range {
+    /// Sets the accuracy tier of the positions; `Accuracy::High` by default
+    pub fn accuracy(mut self, accuracy: Accuracy) -> Range {
+        self.accuracy = accuracy;
+        self
+    }
+}
+
+impl Iterator for Range {
+    /// (jd, eq_point, dist), as given by
+    /// [apparent_eq_pos()](./fn.apparent_eq_pos.html)
+    type Item = (f64, coordinate::EqPoint, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let jd = self.start_jd + (self.index as f64) * self.step_days;
+        self.index += 1;
+
+        let (eq_point, dist) = apparent_eq_pos(&self.body, jd, self.accuracy);
+        Some((jd, eq_point, dist))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.len - self.index;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for Range {}
+
+/**
+Returns an iterator over the apparent positions of a body
+
+The iterator yields `(jd, eq_point, dist)` for every `step_days`
+from `start_jd` up to and including `end_jd`. See
+[apparent_eq_pos()](./fn.apparent_eq_pos.html) for the meaning of
+`eq_point` and `dist`. An error is returned unless `step_days` is
+positive.
+
+# Arguments
+
+* body     : The body
+* start_jd : Julian (Ephemeris) day of the first position
+* end_jd   : Julian (Ephemeris) day after which the iterator stops
+* step_days: Interval between positions | in days. *Must be positive.*
+**/
+pub fn range(
+    body: Body,
+    start_jd: f64,
+    end_jd: f64,
+    step_days: f64,
+) -> Result<Range, &'static str> {
+    if step_days.is_nan() || step_days <= 0.0 {
+        return Err("The step between positions must be positive");
+    }
+
+    // Tolerates rounding in (end_jd - start_jd) / step_days
+    let len = if end_jd < start_jd {
+        0
+    } else {
+        ((end_jd - start_jd) / step_days + 1e-9).floor() as usize + 1
+    };
+
+    Ok(Range {
+        body,
+        start_jd,
+        step_days,
+        index: 0,
+        len,
+        accuracy: Accuracy::High,
+    })
+}
+
+/// A cache of the apparent positions of a body
+///
+/// Positions are computed with
+/// [apparent_eq_pos()](./fn.apparent_eq_pos.html) at nodes spaced
+/// `step_days` apart, each once, and epochs between two nodes are
+/// served by cubic Hermite interpolation on the geocentric
+/// rectangular coordinates and their rates.
+#[derive(Debug)]
+pub struct Cache {
+    body: Body,
+    step_days: f64,
+    accuracy: Accuracy,
+    nodes: HashMap<i64, Node>,
+}
+
+// Geocentric equatorial rectangular coordinates of a body and their
+// rates | in AU and AU per day
+#[derive(Copy, Clone, Debug)]
+struct Node {
+    pos: [f64; 3],
+    vel: [f64; 3],
+}
+
+// Half interval of the central differences for the rates | in days
+const RATE_HALF_STEP: f64 = 1.0 / 1440.0;
+
+impl Cache {
+    /**
+    Returns an empty cache
+
+    An error is returned unless `step_days` is positive.
+
+    # Arguments
+
+    * body     : The body
+    * step_days: Interval between nodes | in days. *Must be
+                 positive.* Half a day suits the Moon, and several
+                 days the Sun and planets.
+    **/
+    pub fn new(body: Body, step_days: f64) -> Result<Cache, &'static str> {
+        if step_days.is_nan() || step_days <= 0.0 {
+            return Err("The step between nodes must be positive");
+        }
+
+        Ok(Cache {
+            body,
+            step_days,
+            accuracy: Accuracy::High,
+            nodes: HashMap::new(),
+        })
+    }
+
+    /// Sets the accuracy tier of the positions; `Accuracy::High` by
+    /// default. The cached nodes are discarded.
+    pub fn accuracy(mut self, accuracy: Accuracy) -> Cache {
+        self.accuracy = accuracy;
+        self.nodes.clear();
+        self
+    }
+
+    /**
+    Computes the apparent geocentric equatorial position of the body
+
+    # Returns
+
+    (eq_point, dist)
+
+    * eq_point: Apparent geocentric equatorial point of the body,
+                referred to the true equinox of the date | in radians
+    * dist    : Earth-body distance | in AU
+
+    # Arguments
+
+    * jd: Julian (Ephemeris) day
+    **/
+    pub fn apparent_eq_pos(&mut self, jd: f64) -> (coordinate::EqPoint, f64) {
+        let (index, t) = self.interval(jd);
+        let (node_0, node_1) = (self.node(index), self.node(index + 1));
+        let h = self.step_days;
+
+        let h00 = (1.0 + 2.0 * t) * (1.0 - t) * (1.0 - t);
+        let h10 = t * (1.0 - t) * (1.0 - t);
+        let h01 = t * t * (3.0 - 2.0 * t);
+        let h11 = t * t * (t - 1.0);
+        let pos: [f64; 3] = std::array::from_fn(|i| {
+            h00 * node_0.pos[i]
+                + h10 * h * node_0.vel[i]
+                + h01 * node_1.pos[i]
+                + h11 * h * node_1.vel[i]
+        });
+
+        let (asc, dec) = sphere::spherical(&pos);
+
+        (
+            coordinate::EqPoint::with_equinox(asc, dec, jd),
+            sphere::dot(&pos, &pos).sqrt(),
+        )
+    }
+
+    /**
+    Estimates the error of an interpolated position
+
+    # Returns
+
+    * error: Estimated bound of the angular error of
+             [Cache::apparent_eq_pos()](#method.apparent_eq_pos) at `jd`
+             | in radians
+
+    The error of cubic Hermite interpolation is at most h⁴ |r⁗| / 384
+    over an interval of length h. The fourth derivative r⁗ is taken
+    from the change of the third derivative of the interpolating
+    cubics between the interval and its neighbours, and the bound is
+    doubled as a margin. The estimate fails for a body whose motion
+    changes abruptly over a few steps.
+
+    # Arguments
+
+    * jd: Julian (Ephemeris) day
+    **/
+    pub fn error_bound(&mut self, jd: f64) -> f64 {
+        let (index, _) = self.interval(jd);
+        let third_derivs: Vec<[f64; 3]> = (index - 1..=index + 1)
+            .map(|i| self.third_deriv(i))
+            .collect();
+
+        let h = self.step_days;
+        let fourth_deriv = third_derivs
+            .windows(2)
+            .map(|w| {
+                let d: [f64; 3] = std::array::from_fn(|i| (w[1][i] - w[0][i]) / h);
+                sphere::dot(&d, &d).sqrt()
+            })
+            .fold(0.0, f64::max);
+
+        let (_, dist) = self.apparent_eq_pos(jd);
+
+        2.0 * h.powi(4) * fourth_deriv / 384.0 / dist
+    }
+
+    // Index of the node starting the interval holding jd, and the
+    // fraction of the interval elapsed
+    fn interval(&self, jd: f64) -> (i64, f64) {
+        let n = jd / self.step_days;
+        let index = n.floor();
+
+        (index as i64, n - index)
+    }
+
+    // Third derivative of the interpolating cubic over an interval,
+    // constant over it | in AU per day³
+    fn third_deriv(&mut self, index: i64) -> [f64; 3] {
+        let (node_0, node_1) = (self.node(index), self.node(index + 1));
+        let h = self.step_days;
+
+        std::array::from_fn(|i| {
+            (12.0 * (node_0.pos[i] - node_1.pos[i]) + 6.0 * h * (node_0.vel[i] + node_1.vel[i]))
+                / (h * h * h)
+        })
+    }
+
+    fn node(&mut self, index: i64) -> Node {
+        let (body, accuracy) = (self.body, self.accuracy);
+        let jd = index as f64 * self.step_days;
+
+        *self.nodes.entry(index).or_insert_with(|| {
+            let pos = |jd: f64| {
+                let (eq_point, dist) = apparent_eq_pos(&body, jd, accuracy);
+                sphere::rectangular(eq_point.asc, eq_point.dec).map(|x| x * dist)
+            };
+
+            let before = pos(jd - RATE_HALF_STEP);
+            let after = pos(jd + RATE_HALF_STEP);
+
+            Node {
+                pos: pos(jd),
+                vel: std::array::from_fn(|i| (after[i] - before[i]) / (2.0 * RATE_HALF_STEP)),
+            }
+        })
+    }
+}
@@ -0,0 +1,258 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------REFERENCE FRAMES-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::nutation;
+use crate::precession;
+use crate::time;
+use crate::Accuracy;
+
+// Julian (Ephemeris) day of the B1950.0 epoch
+const B1950: f64 = 2433282.4235;
+
+// Frame bias of the FK5 J2000.0 frame relative to the ICRS, IERS
+// Conventions (2003), chapter 5: dα0, ξ0 and η0 | in milliarcseconds
+const BIAS_ASC: f64 = -14.6;
+const BIAS_XI: f64 = -16.617;
+const BIAS_ETA: f64 = -6.8192;
+
+/// Represents a celestial reference frame
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Frame {
+    /// International Celestial Reference System
+    Icrs,
+    /// Mean equator and equinox of J2000.0, FK5 system
+    Fk5J2000,
+    /// True equator and equinox of a date, holding its Julian
+    /// (Ephemeris) day
+    EquinoxOfDate(f64),
+    /// Mean equator and equinox of B1950.0
+    B1950,
+    /// Galactic coordinates, with the pole and origin of the IAU 1958
+    /// definition
+    Galactic,
+}
+
+/// Represents a direction on the sky, tagged with its reference frame
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SkyCoord {
+    /// Reference frame of the coordinates
+    pub frame: Frame,
+    /// Right ascension, or galactic longitude | in radians
+    pub long: f64,
+    /// Declination, or galactic latitude | in radians
+    pub lat: f64,
+}
+
+impl SkyCoord {
+    /**
+    Returns a direction tagged with its reference frame
+
+    # Arguments
+
+    * frame: Reference frame of the coordinates
+    * long : Right ascension, or galactic longitude in the galactic
+             frame | in radians
+    * lat  : Declination, or galactic latitude in the galactic frame
+             | in radians
+    **/
+    pub fn new(frame: Frame, long: f64, lat: f64) -> SkyCoord {
+        SkyCoord { frame, long, lat }
+    }
+
+    /// Returns the coordinates of an equatorial point referred to an
+    /// equatorial frame
+    pub fn from_eq(frame: Frame, eq_point: &coordinate::EqPoint) -> SkyCoord {
+        SkyCoord::new(frame, eq_point.asc, eq_point.dec)
+    }
+
+    /// Returns the coordinates as an equatorial point, or `None` in
+    /// the galactic frame
+    pub fn eq_point(&self) -> Option<coordinate::EqPoint> {
+        match self.frame {
+            Frame::Galactic => None,
+            Frame::Icrs => Some(coordinate::EqPoint::new(self.long, self.lat)),
+            Frame::Fk5J2000 => Some(coordinate::EqPoint::with_equinox(
+                self.long,
+                self.lat,
+                time::J2000,
+            )),
+            Frame::EquinoxOfDate(jd) => {
+                Some(coordinate::EqPoint::with_equinox(self.long, self.lat, jd))
+            }
+            Frame::B1950 => Some(coordinate::EqPoint::with_equinox(
+                self.long, self.lat, B1950,
+            )),
+        }
+    }
+
+    /**
+    Transforms the coordinates to another frame
+
+    # Returns
+
+    * sky_coord: The same direction referred to `frame`, with the
+                 longitude in [0, 2π)
+
+    The transformations pass through the FK5 J2000.0 frame. Precession
+    follows Meeus, Astronomical Algorithms, chapter 21, and the
+    difference between the FK4 and FK5 systems, under 1″ away from the
+    poles, is neglected for B1950.0. The equinox of a date is reached
+    by precession and then nutation.
+
+    # Arguments
+
+    * frame   : Target frame
+    * accuracy: Accuracy tier of the nutation
+    **/
+    pub fn transform_to(&self, frame: Frame, accuracy: Accuracy) -> SkyCoord {
+        if frame == self.frame {
+            return *self;
+        }
+
+        let (asc, dec) = to_fk5_j2000(self, accuracy);
+        let (long, lat) = from_fk5_j2000(asc, dec, frame, accuracy);
+
+        SkyCoord::new(frame, angle::limit_twoPI(long), lat)
+    }
+
+    /**
+    Computes the angular separation from another direction
+
+    # Returns
+
+    * angular_sep: Angular separation | in radians
+
+    The other direction is first transformed to the frame of this one.
+
+    # Arguments
+
+    * other   : The other direction
+    * accuracy: Accuracy tier of the nutation, for the equinox of a
+                date
+    **/
+    pub fn angular_sep(&self, other: &SkyCoord, accuracy: Accuracy) -> f64 {
+        let other = other.transform_to(self.frame, accuracy);
+
+        angle::angular_sep(self.long, self.lat, other.long, other.lat)
+    }
+}
+
+// (asc, dec) of a direction in the FK5 J2000.0 frame
+fn to_fk5_j2000(sky_coord: &SkyCoord, accuracy: Accuracy) -> (f64, f64) {
+    let eq_point = coordinate::EqPoint::new;
+
+    let fk5 = match sky_coord.frame {
+        Frame::Fk5J2000 => eq_point(sky_coord.long, sky_coord.lat),
+        Frame::Icrs => {
+            let p = coordinate::RectPoint::from_spherical(sky_coord.long, sky_coord.lat, 1.0);
+            let (asc, dec, _) = (frame_bias() * p).to_spherical();
+            eq_point(asc, dec)
+        }
+        Frame::EquinoxOfDate(jd) => {
+            let (asc, dec) = remove_nutation(sky_coord.long, sky_coord.lat, jd, accuracy);
+            precession::precess_eq(&eq_point(asc, dec), jd, time::J2000)
+        }
+        Frame::B1950 => {
+            precession::precess_eq(&eq_point(sky_coord.long, sky_coord.lat), B1950, time::J2000)
+        }
+        Frame::Galactic => {
+            let asc = coordinate::asc_from_galactic(sky_coord.long, sky_coord.lat);
+            let dec = coordinate::dec_from_galactic(sky_coord.long, sky_coord.lat);
+            precession::precess_eq(&eq_point(asc, dec), B1950, time::J2000)
+        }
+    };
+
+    (fk5.asc, fk5.dec)
+}
+
+// (long, lat) in a frame of a direction in the FK5 J2000.0 frame
+fn from_fk5_j2000(asc: f64, dec: f64, frame: Frame, accuracy: Accuracy) -> (f64, f64) {
+    let fk5 = coordinate::EqPoint::with_equinox(asc, dec, time::J2000);
+
+    match frame {
+        Frame::Fk5J2000 => (asc, dec),
+        Frame::Icrs => {
+            let p = coordinate::RectPoint::from_spherical(asc, dec, 1.0);
+            let (asc, dec, _) = (frame_bias().transpose() * p).to_spherical();
+            (asc, dec)
+        }
+        Frame::EquinoxOfDate(jd) => {
+            let mean = precession::precess_eq(&fk5, time::J2000, jd);
+            add_nutation(mean.asc, mean.dec, jd, accuracy)
+        }
+        Frame::B1950 => {
+            let b1950 = precession::precess_eq(&fk5, time::J2000, B1950);
+            (b1950.asc, b1950.dec)
+        }
+        Frame::Galactic => {
+            let b1950 = precession::precess_eq(&fk5, time::J2000, B1950);
+            (
+                coordinate::galactic_long_from_eq(b1950.asc, b1950.dec),
+                coordinate::galactic_lat_from_eq(b1950.asc, b1950.dec),
+            )
+        }
+    }
+}
+
+// Rotation from the ICRS to the FK5 J2000.0 frame
+fn frame_bias() -> coordinate::RotationMatrix {
+    let mas = |x: f64| angle::deg_dmas(0, 0, x / 1000.0).to_radians();
+
+    coordinate::RotationMatrix::about_x(-mas(BIAS_ETA))
+        * coordinate::RotationMatrix::about_y(mas(BIAS_XI))
+        * coordinate::RotationMatrix::about_z(mas(BIAS_ASC))
+}
+
+// True (asc, dec) of date from the mean ones, through the ecliptic
+fn add_nutation(asc: f64, dec: f64, jd: f64, accuracy: Accuracy) -> (f64, f64) {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(jd, accuracy);
+    let mean_oblq = ecliptic::mean_obliquity_laskar(jd);
+    let true_oblq = mean_oblq + nut_in_oblq;
+
+    let long = coordinate::ecliptic_long_from_eq(asc, dec, mean_oblq) + nut_in_long;
+    let lat = coordinate::ecliptic_lat_from_eq(asc, dec, mean_oblq);
+
+    (
+        coordinate::asc_from_ecliptic(long, lat, true_oblq),
+        coordinate::dec_from_ecliptic(long, lat, true_oblq),
+    )
+}
+
+// Mean (asc, dec) of date from the true ones
+fn remove_nutation(asc: f64, dec: f64, jd: f64, accuracy: Accuracy) -> (f64, f64) {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(jd, accuracy);
+    let mean_oblq = ecliptic::mean_obliquity_laskar(jd);
+    let true_oblq = mean_oblq + nut_in_oblq;
+
+    let long = coordinate::ecliptic_long_from_eq(asc, dec, true_oblq) - nut_in_long;
+    let lat = coordinate::ecliptic_lat_from_eq(asc, dec, true_oblq);
+
+    (
+        coordinate::asc_from_ecliptic(long, lat, mean_oblq),
+        coordinate::dec_from_ecliptic(long, lat, mean_oblq),
+    )
+}
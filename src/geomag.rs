@@ -0,0 +1,486 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------GEOMAGNETISM-----------!
+
+use crate::coordinate;
+use crate::planet::earth;
+
+/// Reference radius of the IGRF spherical-harmonic expansion | in kilometers
+pub const IGRF_REFERENCE_RADIUS: f64 = 6371.2;
+
+/// Epoch of the IGRF-13 main field coefficients | as a decimal year
+pub const IGRF_EPOCH: f64 = 2020.0;
+
+/// Last decimal year for which the IGRF-13 secular variation is valid
+pub const IGRF_VALID_UNTIL: f64 = 2025.0;
+
+/// Maximum degree of the spherical-harmonic expansion evaluated
+pub const MAX_DEGREE: usize = 13;
+
+// IGRF-13 Schmidt semi-normalized coefficients for epoch 2020.0, to
+// degree 13. The secular variation is only given to degree 8, and is
+// zero for the higher degrees. Each row is [n, m, g, h, g_dot, h_dot]
+// with the field in nT and the secular variation in nT/yr.
+const IGRF_13: [[f64; 6]; 104] = [
+    [1.0, 0.0, -29404.8, 0.0, 5.7, 0.0],
+    [1.0, 1.0, -1450.9, 4652.5, 7.4, -25.9],
+    [2.0, 0.0, -2499.6, 0.0, -11.0, 0.0],
+    [2.0, 1.0, 2982.0, -2991.6, -7.0, -30.2],
+    [2.0, 2.0, 1677.0, -734.6, -2.1, -22.4],
+    [3.0, 0.0, 1363.2, 0.0, 2.2, 0.0],
+    [3.0, 1.0, -2381.2, -82.1, -5.9, 6.0],
+    [3.0, 2.0, 1236.2, 241.9, 3.1, -1.1],
+    [3.0, 3.0, 525.7, -543.4, -12.0, 0.5],
+    [4.0, 0.0, 903.0, 0.0, -1.2, 0.0],
+    [4.0, 1.0, 809.5, 281.9, -1.6, -0.1],
+    [4.0, 2.0, 86.3, -158.4, -5.9, 6.5],
+    [4.0, 3.0, -309.4, 199.7, 5.2, 3.6],
+    [4.0, 4.0, 48.0, -349.7, -5.1, -5.0],
+    [5.0, 0.0, -234.3, 0.0, -0.3, 0.0],
+    [5.0, 1.0, 363.2, 47.7, 0.5, 0.0],
+    [5.0, 2.0, 187.8, 208.3, -0.6, 2.5],
+    [5.0, 3.0, -140.7, -121.2, 0.2, -0.6],
+    [5.0, 4.0, -151.2, 32.3, 1.3, 3.0],
+    [5.0, 5.0, 13.5, 98.9, 0.9, 0.3],
+    [6.0, 0.0, 66.0, 0.0, -0.5, 0.0],
+    [6.0, 1.0, 65.5, -19.1, -0.3, 0.0],
+    [6.0, 2.0, 72.9, 25.1, 0.4, -1.6],
+    [6.0, 3.0, -121.5, 52.8, 1.3, -1.3],
+    [6.0, 4.0, -36.2, -64.5, -1.4, 0.8],
+    [6.0, 5.0, 13.5, 8.9, 0.0, 0.0],
+    [6.0, 6.0, -64.7, 68.1, 0.9, 1.0],
+    [7.0, 0.0, 80.6, 0.0, -0.1, 0.0],
+    [7.0, 1.0, -76.7, -51.5, -0.2, 0.6],
+    [7.0, 2.0, -8.2, -16.9, 0.0, 0.6],
+    [7.0, 3.0, 56.5, 2.2, 0.7, -0.8],
+    [7.0, 4.0, 15.8, 23.5, 0.1, -0.2],
+    [7.0, 5.0, 6.4, -2.2, -0.5, -1.1],
+    [7.0, 6.0, -7.2, -27.2, -0.8, 0.1],
+    [7.0, 7.0, 9.8, -1.8, 0.8, 0.3],
+    [8.0, 0.0, 23.7, 0.0, 0.0, 0.0],
+    [8.0, 1.0, 9.7, 8.4, 0.1, -0.2],
+    [8.0, 2.0, -17.6, -15.3, -0.1, 0.6],
+    [8.0, 3.0, -0.5, 12.8, 0.4, -0.2],
+    [8.0, 4.0, -21.1, -11.7, -0.1, 0.5],
+    [8.0, 5.0, 15.3, 14.9, 0.4, -0.3],
+    [8.0, 6.0, 13.7, 3.6, 0.3, -0.4],
+    [8.0, 7.0, -16.5, -6.9, -0.1, -0.5],
+    [8.0, 8.0, -0.3, 2.8, 0.4, 0.0],
+    [9.0, 0.0, 5.0, 0.0, 0.0, 0.0],
+    [9.0, 1.0, 8.4, -23.4, 0.0, 0.0],
+    [9.0, 2.0, 2.9, 11.0, 0.0, 0.0],
+    [9.0, 3.0, -1.5, 9.8, 0.0, 0.0],
+    [9.0, 4.0, -1.1, -5.1, 0.0, 0.0],
+    [9.0, 5.0, -13.2, -6.3, 0.0, 0.0],
+    [9.0, 6.0, 1.1, 7.8, 0.0, 0.0],
+    [9.0, 7.0, 8.8, 0.4, 0.0, 0.0],
+    [9.0, 8.0, -9.3, -1.4, 0.0, 0.0],
+    [9.0, 9.0, -11.9, 9.6, 0.0, 0.0],
+    [10.0, 0.0, -1.9, 0.0, 0.0, 0.0],
+    [10.0, 1.0, -6.2, 3.4, 0.0, 0.0],
+    [10.0, 2.0, -0.1, -0.2, 0.0, 0.0],
+    [10.0, 3.0, 1.7, 3.6, 0.0, 0.0],
+    [10.0, 4.0, -0.9, 4.8, 0.0, 0.0],
+    [10.0, 5.0, 0.7, -8.6, 0.0, 0.0],
+    [10.0, 6.0, -0.9, -0.1, 0.0, 0.0],
+    [10.0, 7.0, 1.9, -4.3, 0.0, 0.0],
+    [10.0, 8.0, 1.4, -3.4, 0.0, 0.0],
+    [10.0, 9.0, -2.4, -0.1, 0.0, 0.0],
+    [10.0, 10.0, -3.8, -8.8, 0.0, 0.0],
+    [11.0, 0.0, 3.0, 0.0, 0.0, 0.0],
+    [11.0, 1.0, -1.4, 0.0, 0.0, 0.0],
+    [11.0, 2.0, -2.5, 2.5, 0.0, 0.0],
+    [11.0, 3.0, 2.3, -0.6, 0.0, 0.0],
+    [11.0, 4.0, -0.9, -0.4, 0.0, 0.0],
+    [11.0, 5.0, 0.3, 0.6, 0.0, 0.0],
+    [11.0, 6.0, -0.7, -0.2, 0.0, 0.0],
+    [11.0, 7.0, -0.1, -1.7, 0.0, 0.0],
+    [11.0, 8.0, 1.4, -1.6, 0.0, 0.0],
+    [11.0, 9.0, -0.6, -3.0, 0.0, 0.0],
+    [11.0, 10.0, 0.2, -2.0, 0.0, 0.0],
+    [11.0, 11.0, 3.1, -2.6, 0.0, 0.0],
+    [12.0, 0.0, -2.0, 0.0, 0.0, 0.0],
+    [12.0, 1.0, -0.1, -1.2, 0.0, 0.0],
+    [12.0, 2.0, 0.5, 0.5, 0.0, 0.0],
+    [12.0, 3.0, 1.3, 1.4, 0.0, 0.0],
+    [12.0, 4.0, -1.2, -1.8, 0.0, 0.0],
+    [12.0, 5.0, 0.7, 0.1, 0.0, 0.0],
+    [12.0, 6.0, 0.3, 0.8, 0.0, 0.0],
+    [12.0, 7.0, 0.5, -0.2, 0.0, 0.0],
+    [12.0, 8.0, -0.3, 0.6, 0.0, 0.0],
+    [12.0, 9.0, -0.5, 0.2, 0.0, 0.0],
+    [12.0, 10.0, 0.1, -0.9, 0.0, 0.0],
+    [12.0, 11.0, -1.1, 0.0, 0.0, 0.0],
+    [12.0, 12.0, -0.3, 0.5, 0.0, 0.0],
+    [13.0, 0.0, 0.1, 0.0, 0.0, 0.0],
+    [13.0, 1.0, -0.9, -0.9, 0.0, 0.0],
+    [13.0, 2.0, 0.5, 0.6, 0.0, 0.0],
+    [13.0, 3.0, 0.7, 1.4, 0.0, 0.0],
+    [13.0, 4.0, -0.3, -0.4, 0.0, 0.0],
+    [13.0, 5.0, 0.8, -1.3, 0.0, 0.0],
+    [13.0, 6.0, 0.0, -0.1, 0.0, 0.0],
+    [13.0, 7.0, 0.8, 0.3, 0.0, 0.0],
+    [13.0, 8.0, 0.0, -0.1, 0.0, 0.0],
+    [13.0, 9.0, 0.4, 0.5, 0.0, 0.0],
+    [13.0, 10.0, 0.1, 0.5, 0.0, 0.0],
+    [13.0, 11.0, 0.5, -0.4, 0.0, 0.0],
+    [13.0, 12.0, -0.5, -0.4, 0.0, 0.0],
+    [13.0, 13.0, -0.4, -0.6, 0.0, 0.0],
+];
+
+/// Represents the geomagnetic field vector at a point
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MagneticField {
+    /// Northward component, X | in nanoteslas
+    pub north: f64,
+    /// Eastward component, Y | in nanoteslas
+    pub east: f64,
+    /// Downward (vertical) component, Z | in nanoteslas
+    pub down: f64,
+}
+
+impl MagneticField {
+    /// Returns the horizontal intensity H | in nanoteslas
+    pub fn horizontal_intensity(&self) -> f64 {
+        self.north.hypot(self.east)
+    }
+
+    /// Returns the total intensity F | in nanoteslas
+    pub fn total_intensity(&self) -> f64 {
+        self.horizontal_intensity().hypot(self.down)
+    }
+
+    /// Returns the magnetic declination, positive eastward | in radians
+    pub fn declination(&self) -> f64 {
+        self.east.atan2(self.north)
+    }
+
+    /// Returns the magnetic inclination (dip), positive downward | in radians
+    pub fn inclination(&self) -> f64 {
+        self.down.atan2(self.horizontal_intensity())
+    }
+}
+
+/**
+Computes the IGRF-13 geomagnetic field vector at a point
+
+# Returns
+
+`Err` if the date lies outside the validity interval of the
+model, i.e. before `IGRF_EPOCH` or after `IGRF_VALID_UNTIL`.
+
+The components are referred to the geodetic (geographical) frame
+of the observer. The expansion is evaluated to `MAX_DEGREE`, the
+full degree of the IGRF-13 main field.
+
+# Arguments
+
+* `point`       : Observer's geographical point | in radians
+* `height`      : Observer's height above the ellipsoid | in meters
+* `decimal_year`: Date of observation | as a decimal year
+**/
+pub fn magnetic_field(
+    point: &coordinate::GeographPoint,
+    height: f64,
+    decimal_year: f64,
+) -> Result<MagneticField, &'static str> {
+    if !(IGRF_EPOCH..=IGRF_VALID_UNTIL).contains(&decimal_year) {
+        return Err("Date lies outside the validity interval of IGRF-13");
+    }
+    let dt = decimal_year - IGRF_EPOCH;
+
+    // Geodetic to geocentric spherical coordinates
+    let a2 = earth::EQUATORIAL_RADIUS * earth::EQUATORIAL_RADIUS;
+    let b2 = earth::POLAR_RADIUS * earth::POLAR_RADIUS;
+    let h = height / 1000.0;
+    let ct = point.lat.sin();
+    let st = point.lat.cos();
+    let one = a2 * st * st;
+    let two = b2 * ct * ct;
+    let rho = (one + two).sqrt();
+    let r = (h * (h + 2.0 * rho) + (a2 * one + b2 * two) / (one + two)).sqrt();
+    let cd = (h + rho) / r;
+    let sd = (a2 - b2) / rho * ct * st / r;
+    let cos_theta = ct * cd - st * sd;
+    let sin_theta = st * cd + ct * sd;
+
+    // Longitude is positive westward in this library
+    let east_long = -point.long;
+
+    let (p, dp) = schmidt_legendre(cos_theta, sin_theta);
+
+    let ratio = IGRF_REFERENCE_RADIUS / r;
+    let mut x = 0.0;
+    let mut y = 0.0;
+    let mut z = 0.0;
+    for row in IGRF_13.iter() {
+        let n = row[0] as usize;
+        let m = row[1] as usize;
+        let g = row[2] + row[4] * dt;
+        let hh = row[3] + row[5] * dt;
+        let (sin_m, cos_m) = (m as f64 * east_long).sin_cos();
+        let rn = ratio.powi(n as i32 + 2);
+
+        x += rn * (g * cos_m + hh * sin_m) * dp[n][m];
+        y += rn * (m as f64) * (g * sin_m - hh * cos_m) * p[n][m];
+        z -= rn * (n as f64 + 1.0) * (g * cos_m + hh * sin_m) * p[n][m];
+    }
+    // The east component is singular at the geocentric poles
+    y = if sin_theta.abs() > 1e-10 {
+        y / sin_theta
+    } else {
+        0.0
+    };
+
+    // Rotate back to the geodetic frame
+    Ok(MagneticField {
+        north: x * cd + z * sd,
+        east: y,
+        down: z * cd - x * sd,
+    })
+}
+
+/**
+Computes the magnetic declination at a point
+
+# Returns
+
+`declination`: Angle from geographic north to magnetic north,
+positive eastward | in radians
+
+# Arguments
+
+* `point`       : Observer's geographical point | in radians
+* `decimal_year`: Date of observation | as a decimal year
+**/
+pub fn declination(
+    point: &coordinate::GeographPoint,
+    decimal_year: f64,
+) -> Result<f64, &'static str> {
+    Ok(magnetic_field(point, 0.0, decimal_year)?.declination())
+}
+
+/**
+Computes the magnetic inclination (dip) at a point
+
+# Returns
+
+`inclination`: Angle of the field below the horizontal, positive
+downward | in radians
+
+# Arguments
+
+* `point`       : Observer's geographical point | in radians
+* `decimal_year`: Date of observation | as a decimal year
+**/
+pub fn inclination(
+    point: &coordinate::GeographPoint,
+    decimal_year: f64,
+) -> Result<f64, &'static str> {
+    Ok(magnetic_field(point, 0.0, decimal_year)?.inclination())
+}
+
+/**
+Computes the location of the north geomagnetic pole
+
+# Returns
+
+The point where the axis of the centered dipole, given by the
+first-degree coefficients, pierces the northern hemisphere.
+
+# Arguments
+
+* `decimal_year`: Date | as a decimal year
+**/
+pub fn geomagnetic_north_pole(
+    decimal_year: f64,
+) -> Result<coordinate::GeographPoint, &'static str> {
+    let (g10, g11, h11) = dipole_coefficients(decimal_year)?;
+    let b0 = (g10 * g10 + g11 * g11 + h11 * h11).sqrt();
+
+    Ok(coordinate::GeographPoint {
+        long: h11.atan2(-g11),
+        lat: (-g10 / b0).asin(),
+    })
+}
+
+/**
+Computes the geomagnetic coordinates of a point
+
+# Returns
+
+`geomag_point`: Geomagnetic longitude (positive westward, like
+geographical longitude) and geomagnetic latitude in the centered
+dipole approximation
+
+# Arguments
+
+* `point`       : Geographical point | in radians
+* `decimal_year`: Date | as a decimal year
+**/
+pub fn geomagnetic_coords(
+    point: &coordinate::GeographPoint,
+    decimal_year: f64,
+) -> Result<coordinate::GeographPoint, &'static str> {
+    let pole = geomagnetic_north_pole(decimal_year)?;
+
+    // Rotate the pole of the dipole to the z axis
+    let d_long = pole.long - point.long;
+    let x = point.lat.cos() * d_long.cos() * pole.lat.sin() - point.lat.sin() * pole.lat.cos();
+    let y = point.lat.cos() * d_long.sin();
+    let z = point.lat.cos() * d_long.cos() * pole.lat.cos() + point.lat.sin() * pole.lat.sin();
+
+    let long = -y.atan2(x);
+    let lat = z.asin();
+
+    Ok(coordinate::GeographPoint { long, lat })
+}
+
+/**
+Computes the geomagnetic latitude of an observer
+
+# Returns
+
+`geomag_lat`: Geomagnetic latitude in the centered dipole
+approximation | in radians
+
+# Arguments
+
+* `point`       : Observer's geographical point | in radians
+* `decimal_year`: Date of observation | as a decimal year
+**/
+pub fn geomagnetic_lat(
+    point: &coordinate::GeographPoint,
+    decimal_year: f64,
+) -> Result<f64, &'static str> {
+    Ok(geomagnetic_coords(point, decimal_year)?.lat)
+}
+
+/**
+Computes the lowest geomagnetic latitude from which aurora is
+likely to be seen for a given planetary K index
+
+# Returns
+
+`Err` if `kp` lies outside 0 to 9.
+
+`geomag_lat`: Absolute geomagnetic latitude of the equatorward
+edge of the visibility region | in radians
+
+A linear fit to the equatorward boundary of the auroral oval,
+66.5 degrees at Kp 0 falling by about 2 degrees per unit of Kp,
+as used for aurora forecasts. Aurora overhead at this latitude can
+be seen low on the poleward horizon a few degrees further away.
+
+# Arguments
+
+* `kp`: Planetary K index, 0 to 9
+**/
+pub fn kp_visibility_lat(kp: f64) -> Result<f64, &'static str> {
+    if !(0.0..=9.0).contains(&kp) {
+        return Err("Kp index lies outside 0 to 9");
+    }
+
+    Ok((66.5 - 2.05 * kp).to_radians())
+}
+
+/**
+Checks whether aurora is likely to be visible to an observer
+
+# Returns
+
+`true` if the observer's absolute geomagnetic latitude is at or
+poleward of `kp_visibility_lat(kp)`, in either hemisphere.
+
+# Arguments
+
+* `point`       : Observer's geographical point | in radians
+* `decimal_year`: Date of observation | as a decimal year
+* `kp`          : Planetary K index, 0 to 9
+**/
+pub fn aurora_visible(
+    point: &coordinate::GeographPoint,
+    decimal_year: f64,
+    kp: f64,
+) -> Result<bool, &'static str> {
+    let threshold = kp_visibility_lat(kp)?;
+
+    Ok(geomagnetic_lat(point, decimal_year)?.abs() >= threshold)
+}
+
+fn dipole_coefficients(decimal_year: f64) -> Result<(f64, f64, f64), &'static str> {
+    if !(IGRF_EPOCH..=IGRF_VALID_UNTIL).contains(&decimal_year) {
+        return Err("Date lies outside the validity interval of IGRF-13");
+    }
+    let dt = decimal_year - IGRF_EPOCH;
+    let coeff = |row: &[f64; 6]| (row[2] + row[4] * dt, row[3] + row[5] * dt);
+    let (g10, _) = coeff(&IGRF_13[0]);
+    let (g11, h11) = coeff(&IGRF_13[1]);
+
+    Ok((g10, g11, h11))
+}
+
+// Schmidt semi-normalized associated Legendre functions P(n, m) of
+// cos(theta) and their derivatives with respect to theta
+type Legendre = [[f64; MAX_DEGREE + 1]; MAX_DEGREE + 1];
+
+fn schmidt_legendre(cos_theta: f64, sin_theta: f64) -> (Legendre, Legendre) {
+    let mut p = [[0.0; MAX_DEGREE + 1]; MAX_DEGREE + 1];
+    let mut dp = [[0.0; MAX_DEGREE + 1]; MAX_DEGREE + 1];
+    p[0][0] = 1.0;
+
+    // Gauss-normalized functions by recurrence
+    for n in 1..=MAX_DEGREE {
+        for m in 0..=n {
+            if n == m {
+                p[n][m] = sin_theta * p[n - 1][m - 1];
+                dp[n][m] = sin_theta * dp[n - 1][m - 1] + cos_theta * p[n - 1][m - 1];
+            } else if n == 1 {
+                p[n][m] = cos_theta * p[n - 1][m];
+                dp[n][m] = cos_theta * dp[n - 1][m] - sin_theta * p[n - 1][m];
+            } else {
+                let k = (((n - 1) * (n - 1)) as f64 - (m * m) as f64)
+                    / (((2 * n - 1) * (2 * n - 3)) as f64);
+                p[n][m] = cos_theta * p[n - 1][m] - k * p[n - 2][m];
+                dp[n][m] = cos_theta * dp[n - 1][m] - sin_theta * p[n - 1][m] - k * dp[n - 2][m];
+            }
+        }
+    }
+
+    // Conversion to Schmidt semi-normalization
+    let mut s = 1.0;
+    for n in 1..=MAX_DEGREE {
+        s *= (2 * n - 1) as f64 / n as f64;
+        let mut snm = s;
+        p[n][0] *= snm;
+        dp[n][0] *= snm;
+        for m in 1..=n {
+            let delta = if m == 1 { 2.0 } else { 1.0 };
+            snm *= ((n - m + 1) as f64 * delta / (n + m) as f64).sqrt();
+            p[n][m] *= snm;
+            dp[n][m] *= snm;
+        }
+    }
+
+    (p, dp)
+}
@@ -22,6 +22,8 @@ THE SOFTWARE.
 
 // !-----------INTERPOLATION-----------!
 
+use crate::math::polynomial;
+
 /*
 Interpolates an intermediate value of a function from three of it's
 given values
@@ -83,5 +85,5 @@ pub fn five_val(y1: f64, y2: f64, y3: f64, y4: f64, y5: f64, n: f64) -> f64 {
     let k = (j - h) / 12.0;
     let h_j_12 = (h + j) / 6.0;
 
-    y3 + Horner_eval!(n, 0.0, b + c - h_j_12, f - k, h_j_12, k) / 2.0
+    y3 + polynomial::horner(&[0.0, b + c - h_j_12, f - k, h_j_12, k], n) / 2.0
 }
@@ -20,15 +20,174 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 THE SOFTWARE.
 */
 
+#![allow(non_snake_case)]
+#![allow(clippy::doc_overindented_list_items)]
+
+pub mod aberration;
 pub mod angle;
 pub mod asteroid;
 pub mod atmospheric;
+pub mod au;
 pub mod binary_star;
+pub mod comet;
+pub mod config;
 pub mod coordinate;
+pub mod eclipse;
 pub mod ecliptic;
+pub mod ephemeris;
+pub mod frame;
+#[cfg(feature = "geomag")]
+pub mod geomag;
 pub mod interpol;
 pub mod lunar;
+pub mod math;
+pub mod navigation;
+pub mod nutation;
+pub mod orbit;
+pub mod phase;
+pub mod phenomena;
+pub mod planning;
+pub mod pointing;
+pub mod precession;
+pub mod prelude;
+#[cfg(feature = "output")]
+pub mod output;
+pub mod projection;
+pub mod reduce;
+pub mod ring;
+pub mod satellite;
+pub mod search;
+pub mod seasons;
+pub mod spectro;
+pub mod sphere;
 pub mod star;
+pub mod sun;
+#[cfg(feature = "testdata")]
+pub mod testdata;
 pub mod time;
+pub mod transit;
+#[cfg(feature = "uom")]
+pub mod units;
+pub mod validate;
+pub mod visibility;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod zodiac;
 
 pub mod planet;
+
+/// Floating point type accepted by the generic functions, `f32` or `f64`
+pub use num_traits::Float;
+
+/// Represents the accuracy tier of an expensive series evaluation
+///
+/// `Low` evaluates a truncated series, trading a few arcseconds of
+/// accuracy for speed. `High` evaluates the full series.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Accuracy {
+    /// Truncated series
+    Low,
+    /// Full series
+    High,
+}
+
+/// Represents a computed quantity with its standard uncertainty
+///
+/// The uncertainty is the standard deviation (1σ) of the quantity, as
+/// propagated from the documented uncertainties of the models used.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Measurement {
+    /// Value of the quantity
+    pub value: f64,
+    /// Standard uncertainty of the quantity, in the unit of the value
+    pub sigma: f64,
+}
+
+/**
+Computes the apparent position of the Sun at a civil date and time
+
+# Returns
+
+(eq_point, earth_sun_dist)
+
+* eq_point      : Apparent geocentric equatorial point of the Sun,
+                  referred to the true equinox of the date | in radians
+* earth_sun_dist: Earth-Sun distance | in AU
+
+The civil time is converted to TT with ΔT from
+[time::delta_t()](./time/fn.delta_t.html).
+
+# Arguments
+
+* date_time: Civil date and time
+* accuracy : Accuracy tier
+**/
+pub fn sun_position(date_time: &time::DateTime, accuracy: Accuracy) -> (coordinate::EqPoint, f64) {
+    let jd = time::Instant::from_ut(date_time.to_julian_day()).jd_tt;
+
+    ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jd, accuracy)
+}
+
+/**
+Computes the apparent position of the Moon at a civil date and time
+
+# Returns
+
+(eq_point, earth_moon_dist)
+
+* eq_point       : Apparent geocentric equatorial point of the Moon,
+                   referred to the true equinox of the date | in
+                   radians
+* earth_moon_dist: Earth-Moon distance | in kilometers
+
+The civil time is converted to TT with ΔT from
+[time::delta_t()](./time/fn.delta_t.html).
+
+# Arguments
+
+* date_time: Civil date and time
+* accuracy : Accuracy tier
+**/
+pub fn moon_position(date_time: &time::DateTime, accuracy: Accuracy) -> (coordinate::EqPoint, f64) {
+    let jd = time::Instant::from_ut(date_time.to_julian_day()).jd_tt;
+    let (eq_point, earth_moon_dist) =
+        ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, jd, accuracy);
+
+    (eq_point, earth_moon_dist * au::AU_KM)
+}
+
+/**
+Computes the observed position of a body at a civil date and time
+
+# Returns
+
+* observed_point: Topocentric horizontal point of the body, with its
+                  true and refracted altitudes
+
+The observer, the accuracy tier and the refraction conditions are
+the defaults of the [config](./config/index.html) module. An error
+is returned if no default observer is set.
+
+# Arguments
+
+* body     : The body
+* date_time: Civil date and time
+**/
+pub fn observed_position(
+    body: &ephemeris::Body,
+    date_time: &time::DateTime,
+) -> Result<coordinate::ObservedPoint, &'static str> {
+    let config = config::get();
+    let observer = config.observer.ok_or("No default observer is set")?;
+
+    let instant = time::Instant::from_ut(date_time.to_julian_day());
+    let (ha_point, _) = ephemeris::topocent_ha_pos(body, &observer, &instant, config.accuracy);
+    let (az, alt) = ha_point.horizontal(observer.lat);
+
+    Ok(coordinate::ObservedPoint::from_true_alt(
+        az,
+        alt,
+        config.pressure,
+        config.temp,
+    ))
+}
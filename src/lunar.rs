@@ -21,8 +21,210 @@ THE SOFTWARE.
 */
 
 use crate::angle;
+use crate::au;
 use crate::coordinate;
+use crate::ephemeris;
+use crate::math::polynomial::Polynomial;
+use crate::nutation;
+use crate::phase;
+use crate::sun;
 use crate::time;
+use crate::transit;
+use crate::Accuracy;
+
+// Periodic terms for the longitude and distance of the Moon
+// (Meeus, Astronomical Algorithms, table 47.A)
+//
+// (D, M, M', F, Σl, Σr)
+//
+// Σl is in units of 0.000001 degree, Σr in units of 0.001 kilometer.
+const LONG_DIST_TERMS: [(i8, i8, i8, i8, f64, f64); 60] = [
+    (0, 0, 1, 0, 6288774.0, -20905355.0),
+    (2, 0, -1, 0, 1274027.0, -3699111.0),
+    (2, 0, 0, 0, 658314.0, -2955968.0),
+    (0, 0, 2, 0, 213618.0, -569925.0),
+    (0, 1, 0, 0, -185116.0, 48888.0),
+    (0, 0, 0, 2, -114332.0, -3149.0),
+    (2, 0, -2, 0, 58793.0, 246158.0),
+    (2, -1, -1, 0, 57066.0, -152138.0),
+    (2, 0, 1, 0, 53322.0, -170733.0),
+    (2, -1, 0, 0, 45758.0, -204586.0),
+    (0, 1, -1, 0, -40923.0, -129620.0),
+    (1, 0, 0, 0, -34720.0, 108743.0),
+    (0, 1, 1, 0, -30383.0, 104755.0),
+    (2, 0, 0, -2, 15327.0, 10321.0),
+    (0, 0, 1, 2, -12528.0, 0.0),
+    (0, 0, 1, -2, 10980.0, 79661.0),
+    (4, 0, -1, 0, 10675.0, -34782.0),
+    (0, 0, 3, 0, 10034.0, -23210.0),
+    (4, 0, -2, 0, 8548.0, -21636.0),
+    (2, 1, -1, 0, -7888.0, 24208.0),
+    (2, 1, 0, 0, -6766.0, 30824.0),
+    (1, 0, -1, 0, -5163.0, -8379.0),
+    (1, 1, 0, 0, 4987.0, -16675.0),
+    (2, -1, 1, 0, 4036.0, -12831.0),
+    (2, 0, 2, 0, 3994.0, -10445.0),
+    (4, 0, 0, 0, 3861.0, -11650.0),
+    (2, 0, -3, 0, 3665.0, 14403.0),
+    (0, 1, -2, 0, -2689.0, -7003.0),
+    (2, 0, -1, 2, -2602.0, 0.0),
+    (2, -1, -2, 0, 2390.0, 10056.0),
+    (1, 0, 1, 0, -2348.0, 6322.0),
+    (2, -2, 0, 0, 2236.0, -9884.0),
+    (0, 1, 2, 0, -2120.0, 5751.0),
+    (0, 2, 0, 0, -2069.0, 0.0),
+    (2, -2, -1, 0, 2048.0, -4950.0),
+    (2, 0, 1, -2, -1773.0, 4130.0),
+    (2, 0, 0, 2, -1595.0, 0.0),
+    (4, -1, -1, 0, 1215.0, -3958.0),
+    (0, 0, 2, 2, -1110.0, 0.0),
+    (3, 0, -1, 0, -892.0, 3258.0),
+    (2, 1, 1, 0, -810.0, 2616.0),
+    (4, -1, -2, 0, 759.0, -1897.0),
+    (0, 2, -1, 0, -713.0, -2117.0),
+    (2, 2, -1, 0, -700.0, 2354.0),
+    (2, 1, -2, 0, 691.0, 0.0),
+    (2, -1, 0, -2, 596.0, 0.0),
+    (4, 0, 1, 0, 549.0, -1423.0),
+    (0, 0, 4, 0, 537.0, -1117.0),
+    (4, -1, 0, 0, 520.0, -1571.0),
+    (1, 0, -2, 0, -487.0, -1739.0),
+    (2, 1, 0, -2, -399.0, 0.0),
+    (0, 0, 2, -2, -381.0, -4421.0),
+    (1, 1, 1, 0, 351.0, 0.0),
+    (3, 0, -2, 0, -340.0, 0.0),
+    (4, 0, -3, 0, 330.0, 0.0),
+    (2, -1, 2, 0, 327.0, 0.0),
+    (0, 2, 1, 0, -323.0, 1165.0),
+    (1, 1, -1, 0, 299.0, 0.0),
+    (2, 0, 3, 0, 294.0, 0.0),
+    (2, 0, -1, -2, 0.0, 8752.0),
+];
+
+// Periodic terms for the latitude of the Moon
+// (Meeus, Astronomical Algorithms, table 47.B)
+//
+// (D, M, M', F, Σb)
+//
+// Σb is in units of 0.000001 degree.
+const LAT_TERMS: [(i8, i8, i8, i8, f64); 60] = [
+    (0, 0, 0, 1, 5128122.0),
+    (0, 0, 1, 1, 280602.0),
+    (0, 0, 1, -1, 277693.0),
+    (2, 0, 0, -1, 173237.0),
+    (2, 0, -1, 1, 55413.0),
+    (2, 0, -1, -1, 46271.0),
+    (2, 0, 0, 1, 32573.0),
+    (0, 0, 2, 1, 17198.0),
+    (2, 0, 1, -1, 9266.0),
+    (0, 0, 2, -1, 8822.0),
+    (2, -1, 0, -1, 8216.0),
+    (2, 0, -2, -1, 4324.0),
+    (2, 0, 1, 1, 4200.0),
+    (2, 1, 0, -1, -3359.0),
+    (2, -1, -1, 1, 2463.0),
+    (2, -1, 0, 1, 2211.0),
+    (2, -1, -1, -1, 2065.0),
+    (0, 1, -1, -1, -1870.0),
+    (4, 0, -1, -1, 1828.0),
+    (0, 1, 0, 1, -1794.0),
+    (0, 0, 0, 3, -1749.0),
+    (0, 1, -1, 1, -1565.0),
+    (1, 0, 0, 1, -1491.0),
+    (0, 1, 1, 1, -1475.0),
+    (0, 1, 1, -1, -1410.0),
+    (0, 1, 0, -1, -1344.0),
+    (1, 0, 0, -1, -1335.0),
+    (0, 0, 3, 1, 1107.0),
+    (4, 0, 0, -1, 1021.0),
+    (4, 0, -1, 1, 833.0),
+    (0, 0, 1, -3, 777.0),
+    (4, 0, -2, 1, 671.0),
+    (2, 0, 0, -3, 607.0),
+    (2, 0, 2, -1, 596.0),
+    (2, -1, 1, -1, 491.0),
+    (2, 0, -2, 1, -451.0),
+    (0, 0, 3, -1, 439.0),
+    (2, 0, 2, 1, 422.0),
+    (2, 0, -3, -1, 421.0),
+    (2, 1, -1, 1, -366.0),
+    (2, 1, 0, 1, -351.0),
+    (4, 0, 0, 1, 331.0),
+    (2, -1, 1, 1, 315.0),
+    (2, -2, 0, -1, 302.0),
+    (0, 0, 1, 3, -283.0),
+    (2, 1, 1, -1, -229.0),
+    (1, 1, 0, -1, 223.0),
+    (1, 1, 0, 1, 223.0),
+    (0, 1, -2, -1, -220.0),
+    (2, 1, -1, -1, -220.0),
+    (1, 0, 1, 1, -185.0),
+    (2, -1, -2, -1, 181.0),
+    (0, 1, 2, 1, -177.0),
+    (4, 0, -2, -1, 176.0),
+    (4, -1, -1, -1, 166.0),
+    (1, 0, 1, -1, -164.0),
+    (4, 0, 1, -1, 132.0),
+    (1, 0, -1, -1, -119.0),
+    (4, -1, 0, -1, 115.0),
+    (2, -2, 0, 1, 107.0),
+];
+
+// Coefficient below which periodic terms are skipped with `Accuracy::Low`
+const LOW_ACCURACY_CUTOFF: f64 = 1000.0;
+
+// Fundamental arguments of the lunar theory (Meeus, Astronomical
+// Algorithms, equations 47.1 to 47.5 and 47.7), polynomials in Julian
+// centuries from J2000.0 | in degrees
+const MEAN_LONG: Polynomial = Polynomial::new(&[
+    218.3164477,
+    481267.88123421,
+    -0.0015786,
+    1.0 / 538841.0,
+    -1.0 / 65194000.0,
+]);
+const MEAN_ELONG: Polynomial = Polynomial::new(&[
+    297.8501921,
+    445267.1114034,
+    -0.0018819,
+    1.0 / 545868.0,
+    -1.0 / 113065000.0,
+]);
+const SUN_MEAN_ANOM: Polynomial =
+    Polynomial::new(&[357.5291092, 35999.0502909, -0.0001536, 1.0 / 24490000.0]);
+const MEAN_ANOM: Polynomial = Polynomial::new(&[
+    134.9633964,
+    477198.8675055,
+    0.0087414,
+    1.0 / 69699.0,
+    -1.0 / 14712000.0,
+]);
+const ARG_OF_LAT: Polynomial = Polynomial::new(&[
+    93.2720950,
+    483202.0175233,
+    -0.0036539,
+    -1.0 / 3526000.0,
+    1.0 / 863310000.0,
+]);
+const MEAN_ASC_NODE: Polynomial = Polynomial::new(&[
+    125.0445479,
+    -1934.1362891,
+    0.0020754,
+    1.0 / 467441.0,
+    -1.0 / 60616000.0,
+]);
+const MEAN_PERIGEE: Polynomial = Polynomial::new(&[
+    83.3532465,
+    4069.0137287,
+    -0.0103200,
+    -1.0 / 80053.0,
+    1.0 / 18999000.0,
+]);
+
+// A fundamental argument at a Julian (Ephemeris) day | in radians
+fn fundamental_arg(poly: &Polynomial, jd: f64) -> f64 {
+    angle::limit_360(poly.eval(time::julian_century(jd))).to_radians()
+}
 
 /*
 Computes the equatorial horizontal parallax of the Moon
@@ -58,3 +260,820 @@ Computes the equatorial semidiameter of the Moon
 pub fn semidiameter(earth_moon_dist: f64) -> f64 {
     0.272481 * horizontal_parallax(earth_moon_dist).sin()
 }
+
+/**
+Computes the mean longitude of the Moon
+
+# Returns
+
+* mean_long: Mean longitude of the Moon, referred to the mean
+             equinox of the date | in radians
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn mean_long(jd: f64) -> f64 {
+    fundamental_arg(&MEAN_LONG, jd)
+}
+
+/**
+Computes the mean elongation of the Moon from the Sun (D)
+
+# Returns
+
+* mean_elong: Mean elongation of the Moon | in radians
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn mean_elong(jd: f64) -> f64 {
+    fundamental_arg(&MEAN_ELONG, jd)
+}
+
+/**
+Computes the mean anomaly of the Sun (M)
+
+# Returns
+
+* sun_mean_anom: Mean anomaly of the Sun | in radians
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn sun_mean_anom(jd: f64) -> f64 {
+    fundamental_arg(&SUN_MEAN_ANOM, jd)
+}
+
+/**
+Computes the mean anomaly of the Moon (M′)
+
+# Returns
+
+* mean_anom: Mean anomaly of the Moon | in radians
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn mean_anom(jd: f64) -> f64 {
+    fundamental_arg(&MEAN_ANOM, jd)
+}
+
+/**
+Computes the argument of latitude of the Moon (F)
+
+# Returns
+
+* arg_of_lat: Mean distance of the Moon from its ascending
+              node | in radians
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn arg_of_lat(jd: f64) -> f64 {
+    fundamental_arg(&ARG_OF_LAT, jd)
+}
+
+/**
+Computes the longitude of the mean ascending node of the Moon (Ω)
+
+# Returns
+
+* mean_asc_node: Longitude of the mean ascending node of the lunar
+                 orbit, referred to the mean equinox of the
+                 date | in radians
+
+The node regresses along the ecliptic with a period of 18.6 years.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn mean_asc_node(jd: f64) -> f64 {
+    fundamental_arg(&MEAN_ASC_NODE, jd)
+}
+
+/**
+Computes the longitude of the true ascending node of the Moon
+
+# Returns
+
+* true_asc_node: Longitude of the true ascending node of the lunar
+                 orbit, referred to the mean equinox of the
+                 date | in radians
+
+The true node oscillates by up to 1.9° around the
+[mean node](./fn.mean_asc_node.html), mostly with a period of half
+an eclipse year.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn true_asc_node(jd: f64) -> f64 {
+    let mean_elong = mean_elong(jd);
+    let sun_mean_anom = sun_mean_anom(jd);
+    let mean_anom = mean_anom(jd);
+    let arg_lat = arg_of_lat(jd);
+
+    let correction = -1.4979 * (2.0 * (mean_elong - arg_lat)).sin() - 0.15 * sun_mean_anom.sin()
+        + 0.1226 * (2.0 * mean_elong).sin()
+        + 0.1176 * (2.0 * arg_lat).sin()
+        - 0.0801 * (2.0 * (mean_anom - arg_lat)).sin();
+
+    angle::limit_twoPI(mean_asc_node(jd) + correction.to_radians())
+}
+
+/**
+Computes the longitude of the mean perigee of the Moon
+
+# Returns
+
+* mean_perigee: Longitude of the mean perigee of the lunar orbit,
+                referred to the mean equinox of the date | in radians
+
+The perigee advances along the orbit with a period of 8.85 years.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn mean_perigee(jd: f64) -> f64 {
+    fundamental_arg(&MEAN_PERIGEE, jd)
+}
+
+/**
+Computes the geocentric ecliptic position of the Moon
+
+# Returns
+
+(ecl_point, earth_moon_dist)
+
+* ecl_point      : Geocentric ecliptic point of the Moon | in radians
+* earth_moon_dist: Earth-Moon distance | in kilometers
+
+The position is referred to the mean equinox of the date. With
+`Accuracy::High`, the full truncated ELP-2000/82 series given by
+Meeus is used (about 10 arcseconds in longitude and 4 arcseconds in
+latitude). With `Accuracy::Low`, periodic terms smaller than 0.001
+degree are skipped, giving errors of about a minute of arc.
+
+# Arguments
+
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn geocent_ecl_pos(jd: f64, accuracy: Accuracy) -> (coordinate::EclPoint, f64) {
+    let t = time::julian_century(jd);
+
+    let mean_long = mean_long(jd);
+    let mean_elong = mean_elong(jd);
+    let sun_mean_anom = sun_mean_anom(jd);
+    let mean_anom = mean_anom(jd);
+    let arg_lat = arg_of_lat(jd);
+
+    let a1 = (119.75 + 131.849 * t).to_radians();
+    let a2 = (53.09 + 479264.29 * t).to_radians();
+    let a3 = (313.45 + 481266.484 * t).to_radians();
+    let e = 1.0 - t * (0.002516 + t * 0.0000074);
+
+    let eccen_factor = |m: i8| match m.abs() {
+        1 => e,
+        2 => e * e,
+        _ => 1.0,
+    };
+
+    let mut sum_long = 0.0;
+    let mut sum_dist = 0.0;
+    for &(d, m, m1, f, l, r) in LONG_DIST_TERMS.iter() {
+        let arg = (d as f64) * mean_elong
+            + (m as f64) * sun_mean_anom
+            + (m1 as f64) * mean_anom
+            + (f as f64) * arg_lat;
+        let k = eccen_factor(m);
+
+        if accuracy == Accuracy::High || l.abs() >= LOW_ACCURACY_CUTOFF {
+            sum_long += l * k * arg.sin();
+        }
+        if accuracy == Accuracy::High || r.abs() >= LOW_ACCURACY_CUTOFF {
+            sum_dist += r * k * arg.cos();
+        }
+    }
+
+    let mut sum_lat = 0.0;
+    for &(d, m, m1, f, b) in LAT_TERMS.iter() {
+        if accuracy == Accuracy::Low && b.abs() < LOW_ACCURACY_CUTOFF {
+            continue;
+        }
+        let arg = (d as f64) * mean_elong
+            + (m as f64) * sun_mean_anom
+            + (m1 as f64) * mean_anom
+            + (f as f64) * arg_lat;
+
+        sum_lat += b * eccen_factor(m) * arg.sin();
+    }
+
+    sum_long += 3958.0 * a1.sin() + 1962.0 * (mean_long - arg_lat).sin() + 318.0 * a2.sin();
+    sum_lat += -2235.0 * mean_long.sin()
+        + 382.0 * a3.sin()
+        + 175.0 * (a1 - arg_lat).sin()
+        + 175.0 * (a1 + arg_lat).sin()
+        + 127.0 * (mean_long - mean_anom).sin()
+        - 115.0 * (mean_long + mean_anom).sin();
+
+    (
+        coordinate::EclPoint {
+            long: angle::limit_twoPI(mean_long + (sum_long / 1000000.0).to_radians()),
+            lat: (sum_lat / 1000000.0).to_radians(),
+        },
+        385000.56 + sum_dist / 1000.0,
+    )
+}
+
+/**
+Computes the apparent geocentric ecliptic position of the Moon
+
+# Returns
+
+(ecl_point, earth_moon_dist)
+
+* ecl_point      : Apparent geocentric ecliptic point of the Moon,
+                   referred to the true equinox of the date | in radians
+* earth_moon_dist: Earth-Moon distance | in kilometers
+
+# Arguments
+
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier of the underlying series
+**/
+pub fn apparent_ecl_pos(jd: f64, accuracy: Accuracy) -> (coordinate::EclPoint, f64) {
+    let (ecl_point, earth_moon_dist) = geocent_ecl_pos(jd, accuracy);
+    let (nut_in_long, _) = nutation::nutation(jd, accuracy);
+
+    (
+        coordinate::EclPoint {
+            long: angle::limit_twoPI(ecl_point.long + nut_in_long),
+            lat: ecl_point.lat,
+        },
+        earth_moon_dist,
+    )
+}
+
+/**
+Computes the phase angle of the Moon
+
+# Returns
+
+* phase_angle: Phase angle of the Moon, the angle Sun-Moon-Earth |
+               in radians
+
+# Arguments
+
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier of the underlying series
+**/
+pub fn phase_angle(jd: f64, accuracy: Accuracy) -> f64 {
+    let (moon, earth_moon_dist) = apparent_ecl_pos(jd, accuracy);
+    let (sun, earth_sun_dist) = sun::apparent_ecl_pos(jd, accuracy);
+    let earth_sun_dist = earth_sun_dist * au::AU_KM;
+
+    let geocent_elong = (moon.lat.cos() * (moon.long - sun.long).cos()).acos();
+
+    (earth_sun_dist * geocent_elong.sin())
+        .atan2(earth_moon_dist - earth_sun_dist * geocent_elong.cos())
+}
+
+/**
+Computes the illuminated fraction of the Moon's disk
+
+# Returns
+
+* illum_frac: Illuminated fraction of the disk | range: 0.0 - 1.0
+
+# Arguments
+
+* phase_angle: Phase angle of the Moon | in radians
+**/
+#[inline]
+pub fn illuminated_fraction(phase_angle: f64) -> f64 {
+    phase::illuminated_fraction(phase_angle)
+}
+
+/**
+Computes the position angle of the Moon's bright limb
+
+# Returns
+
+* pos_angle: Position angle of the midpoint of the bright limb,
+             measured eastwards from the north | in radians
+
+# Arguments
+
+* moon_eq: Equatorial point of the Moon | in radians
+* sun_eq : Equatorial point of the Sun | in radians
+**/
+#[inline]
+pub fn bright_limb_position_angle(
+    moon_eq: &coordinate::EqPoint,
+    sun_eq: &coordinate::EqPoint,
+) -> f64 {
+    phase::bright_limb_pos_angle(moon_eq, sun_eq)
+}
+
+/**
+Computes the tilt of the lunar crescent for an observer
+
+# Returns
+
+* tilt: Position angle of the midpoint of the bright limb, measured
+        from the zenith towards the east | in radians
+
+This is the position angle of the bright limb less the parallactic
+angle, Meeus, Astronomical Algorithms, chapter 48. With a tilt near
+π the bright limb faces the horizon and the horns point up; near
+π/2 or 3π/2 the crescent stands upright.
+
+# Arguments
+
+* moon_eq     : Equatorial point of the Moon | in radians
+* sun_eq      : Equatorial point of the Sun | in radians
+* hour_angle  : Local hour angle of the Moon | in radians
+* observer_lat: Observer's geographical latitude | in radians
+**/
+pub fn crescent_tilt(
+    moon_eq: &coordinate::EqPoint,
+    sun_eq: &coordinate::EqPoint,
+    hour_angle: f64,
+    observer_lat: f64,
+) -> f64 {
+    angle::limit_twoPI(
+        bright_limb_position_angle(moon_eq, sun_eq)
+            - coordinate::parallactic_angle(hour_angle, moon_eq.dec, observer_lat),
+    )
+}
+
+/**
+Computes the relative brightness of earthshine on the Moon
+
+# Returns
+
+* earthshine: Brightness of the unlit part of the Moon, relative to
+              that under a full Earth | range: 0.0 - 1.0
+
+The Earth's phase angle seen from the Moon is taken as the
+supplement of the Moon's, and the Earth is treated as a Lambertian
+sphere. The model ignores the Earth's cloud cover and the lunar
+opposition surge, which make real earthshine vary by some tens of
+percent.
+
+# Arguments
+
+* phase_angle: Phase angle of the Moon | in radians
+**/
+pub fn earthshine(phase_angle: f64) -> f64 {
+    // Lambert phase function (sin α + (π - α) cos α) / π of the Earth,
+    // with α = π - i
+    let (sin_i, cos_i) = phase_angle.sin_cos();
+
+    ((sin_i - phase_angle * cos_i) / std::f64::consts::PI).clamp(0.0, 1.0)
+}
+
+/// Mean length of the synodic month | in days
+pub const SYNODIC_MONTH: f64 = 29.530588861;
+
+// Mean new Moon of lunation 0 of the Meeus book, 2000 January 6
+// (Meeus, Astronomical Algorithms, equation 49.1) | in JDE
+pub(crate) const LUNATION_0: f64 = 2451550.09766;
+
+/// Represents a numbering of lunations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LunationConvention {
+    /// Lunation 0 starts with the new Moon of 2000 January 6, as in
+    /// the Meeus book
+    Meeus,
+    /// Lunation 1 starts with the new Moon of 1923 January 17, as in
+    /// E. W. Brown's lunar tables
+    Brown,
+    /// Lunation 1 starts with the month of Muharram of the year 1 AH
+    Islamic,
+}
+
+/**
+Finds the last new Moon at or before a time
+
+# Returns
+
+* new_moon: Time of the new Moon | in Julian (Ephemeris) days
+
+The new Moon is the time at which the apparent geocentric longitudes
+of the Moon and the Sun are equal, found to about a second.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn previous_new_moon(jd: f64) -> f64 {
+    let mut k = ((jd - LUNATION_0) / SYNODIC_MONTH).floor() + 1.0;
+    while phase(k) > jd {
+        k -= 1.0;
+    }
+
+    phase(k)
+}
+
+/**
+Finds the first new Moon after a time
+
+# Returns
+
+* new_moon: Time of the new Moon | in Julian (Ephemeris) days
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn next_new_moon(jd: f64) -> f64 {
+    let mut k = ((jd - LUNATION_0) / SYNODIC_MONTH).floor();
+    while phase(k) <= jd {
+        k += 1.0;
+    }
+
+    phase(k)
+}
+
+/**
+Computes the age of the Moon
+
+# Returns
+
+* age: Time since the last new Moon | in days
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn age(jd: f64) -> f64 {
+    jd - previous_new_moon(jd)
+}
+
+/**
+Computes the number of the lunation in progress
+
+# Returns
+
+* lunation_number: Number of the lunation that started with the last
+                   new Moon at or before `jd`
+
+# Arguments
+
+* jd        : Julian (Ephemeris) day
+* convention: Numbering of the lunations
+**/
+pub fn lunation_number(jd: f64, convention: LunationConvention) -> i64 {
+    let k = ((previous_new_moon(jd) - LUNATION_0) / SYNODIC_MONTH).round() as i64;
+
+    match convention {
+        LunationConvention::Meeus => k,
+        LunationConvention::Brown => k + 953,
+        LunationConvention::Islamic => k + 17038,
+    }
+}
+
+// Time of a phase of lunation k, k being whole for the new Moon and
+// k + 0.5 for the full Moon, refined from the mean phase with Newton's
+// method on the elongation | in JDE
+pub(crate) fn phase(k: f64) -> f64 {
+    let mean_rate = angle::TWO_PI / SYNODIC_MONTH;
+    let elongation = angle::TWO_PI * k.rem_euclid(1.0);
+
+    let mut jd = LUNATION_0 + k * SYNODIC_MONTH;
+    for _ in 0..20 {
+        let (moon, _) = apparent_ecl_pos(jd, Accuracy::High);
+        let (sun, _) = sun::apparent_ecl_pos(jd, Accuracy::High);
+
+        let correction = angle::limit_pi(moon.long - sun.long - elongation) / mean_rate;
+        jd -= correction;
+        if correction.abs() < 1e-6 {
+            break;
+        }
+    }
+
+    jd
+}
+
+/// Represents a syzygy of the Sun and the Moon
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Syzygy {
+    /// New Moon, the Moon in conjunction with the Sun
+    NewMoon,
+    /// Full Moon, the Moon in opposition to the Sun
+    FullMoon,
+}
+
+/**
+Finds the first new or full Moon after a time
+
+# Returns
+
+(jd, syzygy)
+
+* jd    : Time of the syzygy | in Julian (Ephemeris) days
+* syzygy: The syzygy
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn next_syzygy(jd: f64) -> (f64, Syzygy) {
+    let mut k = (2.0 * (jd - LUNATION_0) / SYNODIC_MONTH).floor() / 2.0;
+    while phase(k) <= jd {
+        k += 0.5;
+    }
+
+    let syzygy = if k.rem_euclid(1.0) == 0.0 {
+        Syzygy::NewMoon
+    } else {
+        Syzygy::FullMoon
+    };
+
+    (phase(k), syzygy)
+}
+
+/// Represents a class of the tidal range
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TideClass {
+    /// Spring tide with the Moon near perigee, tidal index ≥ 1.10
+    PerigeanSpring,
+    /// Spring tide, tidal index ≥ 0.85
+    Spring,
+    /// Between spring and neap tides
+    Intermediate,
+    /// Neap tide, tidal index ≤ 0.50
+    Neap,
+}
+
+/// Represents the combined tide-raising force of the Moon and the Sun
+#[derive(Copy, Clone, Debug)]
+pub struct TidalForce {
+    /// Amplitude of the combined semidiurnal tide-raising force,
+    /// relative to that of a spring tide at the mean distances
+    pub index: f64,
+    /// Class of the tidal range
+    pub class: TideClass,
+}
+
+// Ratio of the solar to the lunar tide-raising force, at the mean
+// distances
+const SOLAR_LUNAR_TIDE_RATIO: f64 = 0.46;
+
+// Mean Earth-Moon distance | in kilometers
+const MEAN_EARTH_MOON_DIST: f64 = 385000.56;
+
+/**
+Computes the combined tide-raising force of the Moon and the Sun
+
+# Returns
+
+* tidal_force: Tidal index and class of the tidal range
+
+The tide-raising forces vary as the inverse cube of the distances of
+the bodies, and their semidiurnal tides add up at the syzygies and
+partly cancel at the quarters. This is an indicator of the
+astronomical forcing only; the tides at a port lag it by a day or
+two, and their size is set by local conditions.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn tidal_force(jd: f64) -> TidalForce {
+    let (moon, earth_moon_dist) = apparent_ecl_pos(jd, Accuracy::Low);
+    let (sun, earth_sun_dist) = sun::apparent_ecl_pos(jd, Accuracy::Low);
+
+    let lunar = (MEAN_EARTH_MOON_DIST / earth_moon_dist).powi(3);
+    let solar = SOLAR_LUNAR_TIDE_RATIO / earth_sun_dist.powi(3);
+    let elongation = moon.long - sun.long;
+
+    let amplitude =
+        (lunar * lunar + solar * solar + 2.0 * lunar * solar * (2.0 * elongation).cos()).sqrt();
+    let index = amplitude / (1.0 + SOLAR_LUNAR_TIDE_RATIO);
+
+    let class = if index >= 1.10 {
+        TideClass::PerigeanSpring
+    } else if index >= 0.85 {
+        TideClass::Spring
+    } else if index <= 0.50 {
+        TideClass::Neap
+    } else {
+        TideClass::Intermediate
+    };
+
+    TidalForce { index, class }
+}
+
+/// Represents a visibility class of B. D. Yallop's q-test
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum YallopClass {
+    /// q > +0.216, easily visible to the naked eye
+    A,
+    /// +0.216 ≥ q > −0.014, visible to the naked eye under perfect
+    /// conditions
+    B,
+    /// −0.014 ≥ q > −0.160, may need optical aid to find the crescent
+    /// before it can be seen with the naked eye
+    C,
+    /// −0.160 ≥ q > −0.232, will need optical aid to find the crescent
+    D,
+    /// −0.232 ≥ q > −0.293, not visible with a telescope
+    E,
+    /// q ≤ −0.293, not visible, below the Danjon limit
+    F,
+}
+
+/// Represents the visibility of the new crescent Moon on an evening
+#[derive(Copy, Clone, Debug)]
+pub struct CrescentVisibility {
+    /// Best time for the observation, 4/9 of the lag from sunset to
+    /// moonset after sunset
+    pub best_time: time::Instant,
+    /// Geocentric elongation of the Moon from the Sun | in radians
+    pub arcl: f64,
+    /// Geocentric difference in altitude of the Moon and the Sun
+    /// | in radians
+    pub arcv: f64,
+    /// Difference in azimuth of the Sun and the Moon | in radians
+    pub daz: f64,
+    /// Topocentric width of the crescent | in radians
+    pub width: f64,
+    /// Yallop's q value
+    pub q: f64,
+    /// Yallop's visibility class
+    pub class: YallopClass,
+}
+
+/**
+Computes the visibility of the new crescent Moon on the evening of a
+date
+
+# Returns
+
+* crescent_visibility: Visibility of the crescent at the best time,
+                       by B. D. Yallop's q-test (NAO Technical Note
+                       No. 69, 1997)
+
+The date is a calendar date in the observer's time zone, as in
+[transit::sun_rise_set()](../transit/fn.sun_rise_set.html). The
+classification makes sense only after the new Moon, and a Moon
+setting before the Sun is an error.
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn crescent_visibility(
+    observer: &coordinate::Observer,
+    date: &time::Date,
+) -> Result<CrescentVisibility, &'static str> {
+    let sunset = transit::sun_rise_set(observer, date)
+        .set
+        .ok_or("The Sun doesn't set on the date")?
+        .instant;
+    let moonset = transit::moon_rise_set(observer, date)
+        .set
+        .ok_or("The Moon doesn't set on the date")?
+        .instant;
+    if moonset.jd_ut < sunset.jd_ut {
+        return Err("The Moon sets before the Sun");
+    }
+
+    let best_time =
+        time::Instant::from_ut(sunset.jd_ut + 4.0 / 9.0 * (moonset.jd_ut - sunset.jd_ut));
+
+    let sidereal = time::apparent_sidereal_from_jd(best_time.jd_ut);
+    let horizontal = |eq_point: &coordinate::EqPoint| {
+        let hour_angle = coordinate::hour_angle_from_long(sidereal, observer.long, eq_point.asc);
+        (
+            coordinate::azimuth_from_eq(hour_angle, eq_point.dec, observer.lat),
+            coordinate::altitude_from_eq(hour_angle, eq_point.dec, observer.lat),
+        )
+    };
+
+    let (moon, dist) =
+        ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, best_time.jd_tt, Accuracy::High);
+    let (sun, _) =
+        ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, best_time.jd_tt, Accuracy::High);
+    let (moon_az, moon_alt) = horizontal(&moon);
+    let (sun_az, sun_alt) = horizontal(&sun);
+
+    let arcl = moon.angular_sep(&sun);
+    let arcv = moon_alt - sun_alt;
+
+    let parallax = horizontal_parallax(dist * au::AU_KM);
+    let topocent_semidiameter = 0.27245 * parallax * (1.0 + moon_alt.sin() * parallax.sin());
+    let width = topocent_semidiameter * (1.0 - arcl.cos());
+
+    let w = width.to_degrees() * 60.0;
+    let q =
+        (arcv.to_degrees() - (11.8371 - 6.3226 * w + 0.7319 * w * w - 0.1018 * w * w * w)) / 10.0;
+
+    let class = if q > 0.216 {
+        YallopClass::A
+    } else if q > -0.014 {
+        YallopClass::B
+    } else if q > -0.160 {
+        YallopClass::C
+    } else if q > -0.232 {
+        YallopClass::D
+    } else if q > -0.293 {
+        YallopClass::E
+    } else {
+        YallopClass::F
+    };
+
+    Ok(CrescentVisibility {
+        best_time,
+        arcl,
+        arcv,
+        daz: angle::limit_pi(sun_az - moon_az),
+        width,
+        q,
+        class,
+    })
+}
+
+/// Represents a moonrise or moonset
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HorizonEvent {
+    /// Time of the event
+    pub time: time::Instant,
+    /// Topocentric azimuth of the center of the Moon, measured
+    /// westwards from the south | in radians
+    pub az: f64,
+}
+
+/// Represents the moonrise and moonset on a date
+///
+/// An event that doesn't happen on the date is `None`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RiseSetAzimuth {
+    /// Moonrise
+    pub rise: Option<HorizonEvent>,
+    /// Moonset
+    pub set: Option<HorizonEvent>,
+}
+
+/**
+Computes the times and azimuths of moonrise and moonset on a date
+
+# Returns
+
+* rise_set_azimuth: Moonrise and moonset on the date
+
+The date is a calendar date in the observer's time zone, as in
+[transit::sun_rise_set()](../transit/fn.sun_rise_set.html). The
+times are those of
+[transit::moon_rise_set()](../transit/fn.moon_rise_set.html), for
+which the upper limb touches the refracted horizon, allowing for the
+parallax of the Moon. The azimuths are those of the topocentric
+Moon; as parallax and refraction act almost wholly in altitude, they
+differ from the geocentric azimuths by thousandths of a degree.
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn rise_set_azimuth(observer: &coordinate::Observer, date: &time::Date) -> RiseSetAzimuth {
+    let events = transit::moon_rise_set(observer, date);
+
+    let with_azimuth = |zoned: time::ZonedInstant| HorizonEvent {
+        time: zoned.instant,
+        az: topocent_azimuth(observer, &zoned.instant),
+    };
+
+    RiseSetAzimuth {
+        rise: events.rise.map(with_azimuth),
+        set: events.set.map(with_azimuth),
+    }
+}
+
+// Azimuth of the Moon corrected for parallax
+fn topocent_azimuth(observer: &coordinate::Observer, instant: &time::Instant) -> f64 {
+    let (eq_point, dist) =
+        ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, instant.jd_tt, Accuracy::High);
+    let local_sidereal = time::apparent_sidereal_from_jd(instant.jd_ut) - observer.long;
+
+    let ha_point = coordinate::HaPoint::from_eq(&eq_point, local_sidereal).topocent(
+        horizontal_parallax(dist * au::AU_KM).sin(),
+        observer.lat,
+        observer.height,
+    );
+    let (az, _) = ha_point.horizontal(observer.lat);
+
+    az
+}
@@ -0,0 +1,108 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------POLYNOMIAL-----------!
+
+/**
+Evaluates a polynomial with Horner's method
+
+# Returns
+
+* value: Value of the polynomial at `x`
+
+An empty slice of coefficients is the zero polynomial.
+
+# Arguments
+
+* coeffs: Coefficients of the polynomial in ascending order of power,
+          the constant term first
+* x     : Value of the variable
+**/
+pub fn horner(coeffs: &[f64], x: f64) -> f64 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(0.0, |value, &coeff| value * x + coeff)
+}
+
+/// Represents a polynomial in one variable
+///
+/// The coefficients are borrowed, so that polynomials with fixed
+/// coefficients can be `const` items.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Polynomial<'a> {
+    coeffs: &'a [f64],
+}
+
+impl<'a> Polynomial<'a> {
+    /// Creates a polynomial from its coefficients in ascending order of
+    /// power, the constant term first
+    pub const fn new(coeffs: &'a [f64]) -> Polynomial<'a> {
+        Polynomial { coeffs }
+    }
+
+    /// Returns the coefficients in ascending order of power
+    pub fn coeffs(&self) -> &'a [f64] {
+        self.coeffs
+    }
+
+    /// Returns the degree of the polynomial, `None` for the zero
+    /// polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.iter().rposition(|&coeff| coeff != 0.0)
+    }
+
+    /// Evaluates the polynomial at `x`
+    pub fn eval(&self, x: f64) -> f64 {
+        horner(self.coeffs, x)
+    }
+
+    /// Evaluates the derivative of the polynomial at `x`
+    pub fn eval_derivative(&self, x: f64) -> f64 {
+        self.eval_with_derivative(x).1
+    }
+
+    /**
+    Evaluates the polynomial and its derivative together
+
+    # Returns
+
+    (value, derivative)
+
+    * value     : Value of the polynomial at `x`
+    * derivative: Value of the derivative of the polynomial at `x`
+
+    Both are found in a single pass of Horner's method.
+
+    # Arguments
+
+    * x: Value of the variable
+    **/
+    pub fn eval_with_derivative(&self, x: f64) -> (f64, f64) {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold((0.0, 0.0), |(value, derivative), &coeff| {
+                (value * x + coeff, derivative * x + value)
+            })
+    }
+}
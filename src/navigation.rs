@@ -0,0 +1,387 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------CELESTIAL NAVIGATION-----------!
+
+// Distances on the Earth are angles at its center, one arcminute
+// being a nautical mile. Courses and azimuths are measured eastward
+// from the north, as at sea.
+
+use crate::angle;
+use crate::atmospheric;
+use crate::coordinate;
+use crate::ephemeris;
+use crate::sphere;
+use crate::time;
+use crate::Accuracy;
+
+/// Represents a line of position from a reduced sight
+#[derive(Copy, Clone, Debug)]
+pub struct LineOfPosition {
+    /// Altitude computed for the assumed position | in radians
+    pub computed_alt: f64,
+    /// Azimuth of the body from the assumed position, measured
+    /// eastward from the north | in radians
+    pub az: f64,
+    /// Intercept, observed minus computed altitude, positive towards
+    /// the body | in radians
+    pub intercept: f64,
+}
+
+/// Represents the hourly almanac quantities of a body
+#[derive(Copy, Clone, Debug)]
+pub struct AlmanacEntry {
+    /// Greenwich hour angle, 0 - 2π | in radians
+    pub gha: f64,
+    /// Declination | in radians
+    pub dec: f64,
+    /// Excess of the hourly change of the GHA over the adopted rate,
+    /// 14°19′ for the Moon and 15° for the other bodies | in radians
+    pub v: f64,
+    /// Hourly change of the declination | in radians
+    pub d: f64,
+}
+
+/**
+Computes the Greenwich hour angle of the first point of Aries
+
+# Returns
+
+* gha_aries: GHA Aries, 0 - 2π | in radians
+
+GHA Aries is the Greenwich apparent sidereal time.
+
+# Arguments
+
+* jd: Julian day, UT
+**/
+pub fn gha_aries(jd: f64) -> f64 {
+    angle::limit_twoPI(time::apparent_sidereal_from_jd(jd))
+}
+
+/**
+Computes the sidereal hour angle of a right ascension
+
+# Returns
+
+* sha: Sidereal hour angle, 0 - 2π | in radians
+
+# Arguments
+
+* asc: Right ascension | in radians
+**/
+pub fn sha(asc: f64) -> f64 {
+    angle::limit_twoPI(-asc)
+}
+
+/**
+Computes the Greenwich hour angle of a star from its sidereal hour
+angle
+
+# Returns
+
+* gha: Greenwich hour angle of the star, 0 - 2π | in radians
+
+# Arguments
+
+* sha: Sidereal hour angle of the star | in radians
+* jd : Julian day, UT
+**/
+pub fn gha_star(sha: f64, jd: f64) -> f64 {
+    angle::limit_twoPI(gha_aries(jd) + sha)
+}
+
+/**
+Computes the almanac quantities of a body
+
+# Returns
+
+* almanac_entry: GHA, declination and the hourly v and d values
+
+The apparent position of the body is used. `v` and `d` are taken
+from the change over the hour that follows `jd`, so that the GHA and
+declination at `n` minutes past it are `gha + (15° + v) · n/60`,
+`14°19′` for the Moon, and `dec + d · n/60`.
+
+# Arguments
+
+* body: The body
+* jd  : Julian day, UT
+**/
+pub fn gha(body: &ephemeris::Body, jd: f64) -> AlmanacEntry {
+    let position = |jd: f64| {
+        let (eq_point, _) = ephemeris::apparent_eq_pos(body, time::tt_from_ut(jd), Accuracy::High);
+        (
+            angle::limit_twoPI(gha_aries(jd) - eq_point.asc),
+            eq_point.dec,
+        )
+    };
+    let (gha, dec) = position(jd);
+    let (next_gha, next_dec) = position(jd + 1.0 / 24.0);
+
+    let adopted_rate = match body {
+        ephemeris::Body::Moon => angle::deg_dmas(14, 19, 0.0).to_radians(),
+        _ => 15_f64.to_radians(),
+    };
+
+    AlmanacEntry {
+        gha,
+        dec,
+        v: angle::limit_pi(next_gha - gha - adopted_rate),
+        d: next_dec - dec,
+    }
+}
+
+/**
+Computes the observed altitude from a sextant altitude
+
+# Returns
+
+* observed_alt: Observed altitude of the body | in radians
+
+The index error, the dip of the horizon and the mean refraction are
+removed. The semidiameter of the body, for a limb sight, and its
+parallax in altitude are left to be applied.
+
+# Arguments
+
+* sextant_alt: Altitude read off the sextant | in radians
+* index_error: Index error, positive if the sextant reads too high
+               | in radians
+* eye_height : Height of the eye above the sea | in meters
+**/
+pub fn observed_alt(sextant_alt: f64, index_error: f64, eye_height: f64) -> f64 {
+    let dip = (1.76 * eye_height.sqrt() / 60.0).to_radians();
+    let apparent_alt = sextant_alt - index_error - dip;
+
+    apparent_alt - atmospheric::refrac_apparent_altitude(apparent_alt)
+}
+
+/**
+Computes the parallax in altitude of a body
+
+# Returns
+
+* parallax: Parallax in altitude, to be added to the observed
+            altitude | in radians
+
+# Arguments
+
+* alt               : Altitude of the body | in radians
+* horizontal_parallax: Horizontal parallax of the body | in radians
+**/
+pub fn parallax_in_alt(alt: f64, horizontal_parallax: f64) -> f64 {
+    (horizontal_parallax.sin() * alt.cos()).asin()
+}
+
+/**
+Computes the geographical position of a body
+
+# Returns
+
+* gp: Point on the Earth with the body at its zenith
+
+# Arguments
+
+* gha: Greenwich hour angle of the body | in radians
+* dec: Declination of the body | in radians
+**/
+pub fn geograph_pos(gha: f64, dec: f64) -> coordinate::GeographPoint {
+    coordinate::GeographPoint::new(gha, dec)
+}
+
+/**
+Reduces a sight to a line of position
+
+# Returns
+
+* line_of_position: Computed altitude, azimuth and intercept
+
+The line of position runs perpendicular to the azimuth, at the
+intercept from the assumed position.
+
+# Arguments
+
+* gha         : Greenwich hour angle of the body | in radians
+* dec         : Declination of the body | in radians
+* assumed     : Assumed position, longitude positive westward
+* observed_alt: Observed altitude of the body | in radians
+**/
+pub fn sight_reduction(
+    gha: f64,
+    dec: f64,
+    assumed: &coordinate::GeographPoint,
+    observed_alt: f64,
+) -> LineOfPosition {
+    let local_hour_angle = gha - assumed.long;
+    let computed_alt = coordinate::altitude_from_eq(local_hour_angle, dec, assumed.lat);
+
+    LineOfPosition {
+        computed_alt,
+        az: coordinate::azimuth_north_from_eq(local_hour_angle, dec, assumed.lat),
+        intercept: observed_alt - computed_alt,
+    }
+}
+
+/**
+Computes the position reached by sailing a great circle
+
+# Returns
+
+* destination: Position reached, longitude positive westward
+
+# Arguments
+
+* start   : Starting position, longitude positive westward
+* course  : Initial course, measured eastward from the north
+            | in radians
+* distance: Distance sailed | in radians
+**/
+pub fn destination(
+    start: &coordinate::GeographPoint,
+    course: f64,
+    distance: f64,
+) -> coordinate::GeographPoint {
+    let (sin_lat, cos_lat) = start.lat.sin_cos();
+    let (sin_dist, cos_dist) = distance.sin_cos();
+
+    let lat = (sin_lat * cos_dist + cos_lat * sin_dist * course.cos()).asin();
+    let east = (course.sin() * sin_dist * cos_lat).atan2(cos_dist - sin_lat * lat.sin());
+
+    coordinate::GeographPoint::new(start.long - east, lat)
+}
+
+/**
+Computes a fix from two simultaneous sights
+
+# Returns
+
+* fix: Position of the observer, longitude positive westward
+
+The circles of equal altitude around the geographical positions of
+the bodies cross in two points; the one nearer the dead-reckoning
+position is returned. Bodies close together or opposite in the sky
+give a poor fix, or none.
+
+# Arguments
+
+* sights: (gp, observed_alt) of each sight, `gp` being the
+          geographical position of the body from
+          [geograph_pos()](./fn.geograph_pos.html) and `observed_alt`
+          in radians
+* dead_reckoning: Dead-reckoning position
+**/
+pub fn fix(
+    sights: &[(coordinate::GeographPoint, f64); 2],
+    dead_reckoning: &coordinate::GeographPoint,
+) -> Result<coordinate::GeographPoint, &'static str> {
+    let center_1 = sphere::rectangular(sights[0].0.long, sights[0].0.lat);
+    let center_2 = sphere::rectangular(sights[1].0.long, sights[1].0.lat);
+    let (cos_r_1, cos_r_2) = (sights[0].1.sin(), sights[1].1.sin());
+
+    let d = sphere::dot(&center_1, &center_2);
+    let normal = sphere::cross(&center_1, &center_2);
+    let normal_sq = sphere::dot(&normal, &normal);
+    if normal_sq < 1e-12 {
+        return Err("The bodies are too close together or opposite for a fix");
+    }
+
+    // The crossings are a·c1 + b·c2 ± t·(c1 × c2)
+    let a = (cos_r_1 - d * cos_r_2) / normal_sq;
+    let b = (cos_r_2 - d * cos_r_1) / normal_sq;
+    let t_sq = (1.0 - a * a - b * b - 2.0 * a * b * d) / normal_sq;
+    if t_sq < 0.0 {
+        return Err("The circles of equal altitude don't cross");
+    }
+    let t = t_sq.sqrt();
+
+    let dr = sphere::rectangular(dead_reckoning.long, dead_reckoning.lat);
+    let crossing = |t: f64| -> [f64; 3] {
+        std::array::from_fn(|i| a * center_1[i] + b * center_2[i] + t * normal[i])
+    };
+    let (p, q) = (crossing(t), crossing(-t));
+    let nearer = if sphere::dot(&p, &dr) >= sphere::dot(&q, &dr) {
+        p
+    } else {
+        q
+    };
+
+    let (long, lat) = sphere::spherical(&nearer);
+    Ok(coordinate::GeographPoint::new(angle::limit_pi(long), lat))
+}
+
+/**
+Computes a running fix from two sights taken at different times
+
+# Returns
+
+* fix: Position of the observer at the later sight, longitude
+       positive westward
+
+The circle of equal altitude of the earlier sight is carried along by
+the run between the sights, by the rotation of the Earth's sphere
+that takes the earlier dead-reckoning position along its great circle
+course, and is then crossed with the later sight as in
+[fix()](./fn.fix.html).
+
+# Arguments
+
+* earlier: (gp, observed_alt) of the earlier sight
+* later  : (gp, observed_alt) of the later sight
+* earlier_dead_reckoning: Dead-reckoning position at the earlier sight
+* course  : Initial great circle course made good between the
+            sights, measured eastward from the north | in radians
+* distance: Distance run between the sights | in radians
+**/
+pub fn running_fix(
+    earlier: &(coordinate::GeographPoint, f64),
+    later: &(coordinate::GeographPoint, f64),
+    earlier_dead_reckoning: &coordinate::GeographPoint,
+    course: f64,
+    distance: f64,
+) -> Result<coordinate::GeographPoint, &'static str> {
+    let dead_reckoning = destination(earlier_dead_reckoning, course, distance);
+
+    let start = sphere::rectangular(earlier_dead_reckoning.long, earlier_dead_reckoning.lat);
+    let end = sphere::rectangular(dead_reckoning.long, dead_reckoning.lat);
+    let axis = sphere::cross(&start, &end);
+    let axis_len = sphere::dot(&axis, &axis).sqrt();
+
+    let advanced_gp = if axis_len < 1e-15 {
+        earlier.0
+    } else {
+        // Rodrigues' rotation of the geographical position by the run
+        let k = axis.map(|x| x / axis_len);
+        let v = sphere::rectangular(earlier.0.long, earlier.0.lat);
+        let k_cross_v = sphere::cross(&k, &v);
+        let k_dot_v = sphere::dot(&k, &v);
+        let (sin_dist, cos_dist) = distance.sin_cos();
+        let rotated: [f64; 3] = std::array::from_fn(|i| {
+            v[i] * cos_dist + k_cross_v[i] * sin_dist + k[i] * k_dot_v * (1.0 - cos_dist)
+        });
+
+        let (long, lat) = sphere::spherical(&rotated);
+        coordinate::GeographPoint::new(long, lat)
+    };
+
+    fix(&[(advanced_gp, earlier.1), *later], &dead_reckoning)
+}
@@ -0,0 +1,336 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-------------NUTATION-------------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::math::polynomial::Polynomial;
+use crate::precession;
+use crate::time;
+use crate::Accuracy;
+
+// Periodic terms for the nutation in longitude and obliquity
+// (Meeus, Astronomical Algorithms, table 22.A)
+//
+// ([D, M, M', F, Ω], [ψ₀, ψ₁, ε₀, ε₁])
+//
+// The coefficients ψ₀ + ψ₁T of the sines and ε₀ + ε₁T of the cosines
+// are in units of 0.0001 arcseconds.
+const TERMS: [([i8; 5], [f64; 4]); 63] = [
+    ([0, 0, 0, 0, 1], [-171996.0, -174.2, 92025.0, 8.9]),
+    ([-2, 0, 0, 2, 2], [-13187.0, -1.6, 5736.0, -3.1]),
+    ([0, 0, 0, 2, 2], [-2274.0, -0.2, 977.0, -0.5]),
+    ([0, 0, 0, 0, 2], [2062.0, 0.2, -895.0, 0.5]),
+    ([0, 1, 0, 0, 0], [1426.0, -3.4, 54.0, -0.1]),
+    ([0, 0, 1, 0, 0], [712.0, 0.1, -7.0, 0.0]),
+    ([-2, 1, 0, 2, 2], [-517.0, 1.2, 224.0, -0.6]),
+    ([0, 0, 0, 2, 1], [-386.0, -0.4, 200.0, 0.0]),
+    ([0, 0, 1, 2, 2], [-301.0, 0.0, 129.0, -0.1]),
+    ([-2, -1, 0, 2, 2], [217.0, -0.5, -95.0, 0.3]),
+    ([-2, 0, 1, 0, 0], [-158.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 0, 2, 1], [129.0, 0.1, -70.0, 0.0]),
+    ([0, 0, -1, 2, 2], [123.0, 0.0, -53.0, 0.0]),
+    ([2, 0, 0, 0, 0], [63.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, 0, 1], [63.0, 0.1, -33.0, 0.0]),
+    ([2, 0, -1, 2, 2], [-59.0, 0.0, 26.0, 0.0]),
+    ([0, 0, -1, 0, 1], [-58.0, -0.1, 32.0, 0.0]),
+    ([0, 0, 1, 2, 1], [-51.0, 0.0, 27.0, 0.0]),
+    ([-2, 0, 2, 0, 0], [48.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -2, 2, 1], [46.0, 0.0, -24.0, 0.0]),
+    ([2, 0, 0, 2, 2], [-38.0, 0.0, 16.0, 0.0]),
+    ([0, 0, 2, 2, 2], [-31.0, 0.0, 13.0, 0.0]),
+    ([0, 0, 2, 0, 0], [29.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 1, 2, 2], [29.0, 0.0, -12.0, 0.0]),
+    ([0, 0, 0, 2, 0], [26.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 0, 2, 0], [-22.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -1, 2, 1], [21.0, 0.0, -10.0, 0.0]),
+    ([0, 2, 0, 0, 0], [17.0, -0.1, 0.0, 0.0]),
+    ([2, 0, -1, 0, 1], [16.0, 0.0, -8.0, 0.0]),
+    ([-2, 2, 0, 2, 2], [-16.0, 0.1, 7.0, 0.0]),
+    ([0, 1, 0, 0, 1], [-15.0, 0.0, 9.0, 0.0]),
+    ([-2, 0, 1, 0, 1], [-13.0, 0.0, 7.0, 0.0]),
+    ([0, -1, 0, 0, 1], [-12.0, 0.0, 6.0, 0.0]),
+    ([0, 0, 2, -2, 0], [11.0, 0.0, 0.0, 0.0]),
+    ([2, 0, -1, 2, 1], [-10.0, 0.0, 5.0, 0.0]),
+    ([2, 0, 1, 2, 2], [-8.0, 0.0, 3.0, 0.0]),
+    ([0, 1, 0, 2, 2], [7.0, 0.0, -3.0, 0.0]),
+    ([-2, 1, 1, 0, 0], [-7.0, 0.0, 0.0, 0.0]),
+    ([0, -1, 0, 2, 2], [-7.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 0, 2, 1], [-7.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 1, 0, 0], [6.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 2, 2, 2], [6.0, 0.0, -3.0, 0.0]),
+    ([-2, 0, 1, 2, 1], [6.0, 0.0, -3.0, 0.0]),
+    ([2, 0, -2, 0, 1], [-6.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 0, 0, 1], [-6.0, 0.0, 3.0, 0.0]),
+    ([0, -1, 1, 0, 0], [5.0, 0.0, 0.0, 0.0]),
+    ([-2, -1, 0, 2, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([-2, 0, 0, 0, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([0, 0, 2, 2, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([-2, 0, 2, 0, 1], [4.0, 0.0, 0.0, 0.0]),
+    ([-2, 1, 0, 2, 1], [4.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, -2, 0], [4.0, 0.0, 0.0, 0.0]),
+    ([-1, 0, 1, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([-2, 1, 0, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([1, 0, 0, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, 2, 0], [3.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -2, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([-1, -1, 1, 0, 0], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, 1, 1, 0, 0], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, -1, 1, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([2, -1, -1, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 3, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([2, -1, 0, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+];
+
+/**
+Computes the nutation in longitude and obliquity
+
+# Returns
+
+(nut_in_long, nut_in_oblq)
+
+* nut_in_long: Nutation in longitude | in radians
+* nut_in_oblq: Nutation in obliquity | in radians
+
+With `Accuracy::High`, the 63 periodic terms of the IAU 1980 theory
+are summed. With `Accuracy::Low`, only the four largest terms are
+used; the errors are then up to 0.5 arcseconds in longitude and 0.1
+arcseconds in obliquity.
+
+# Arguments
+
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn nutation(jd: f64, accuracy: Accuracy) -> (f64, f64) {
+    let (nut, _) = nutation_and_rate(jd, accuracy);
+    nut
+}
+
+/**
+Computes the rates of change of the nutation in longitude and
+obliquity
+
+# Returns
+
+(nut_in_long_rate, nut_in_oblq_rate)
+
+* nut_in_long_rate: Rate of change of the nutation in longitude | in
+                    radians per day
+* nut_in_oblq_rate: Rate of change of the nutation in obliquity | in
+                    radians per day
+
+The series of [nutation()](./fn.nutation.html) are differentiated
+term by term. The largest term, with the period of the Moon's node,
+changes by up to 0.006″ per day, and the semi-monthly terms by up to
+0.1″ per day.
+
+# Arguments
+
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn nutation_rate(jd: f64, accuracy: Accuracy) -> (f64, f64) {
+    let (_, rate) = nutation_and_rate(jd, accuracy);
+    rate
+}
+
+/**
+Computes the nutation matrix
+
+# Returns
+
+* matrix: Rotation from the mean equator and equinox of the date to
+          the true ones
+
+The matrix is R₁(−ε) R₃(−Δψ) R₁(ε₀), ε₀ and ε being the mean and true
+obliquities of the ecliptic. The nutation is computed with
+`Accuracy::High`.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn matrix(jd: f64) -> coordinate::RotationMatrix {
+    let (nut_in_long, nut_in_oblq) = nutation(jd, Accuracy::High);
+    let mean_oblq = ecliptic::mean_obliquity_laskar(jd);
+
+    coordinate::RotationMatrix::about_x(-(mean_oblq + nut_in_oblq))
+        * coordinate::RotationMatrix::about_z(-nut_in_long)
+        * coordinate::RotationMatrix::about_x(mean_oblq)
+}
+
+/**
+Computes the combined precession and nutation matrix
+
+# Returns
+
+* matrix: Rotation from the mean equator and equinox of J2000.0 to
+          the true equator and equinox of the date
+
+This is [matrix()](./fn.matrix.html) applied after
+[precession::matrix()](../precession/fn.matrix.html). Applied to
+rectangular FK5 coordinates, it gives the true place of the date.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn precession_nutation_matrix(jd: f64) -> coordinate::RotationMatrix {
+    matrix(jd) * precession::matrix(time::J2000, jd)
+}
+
+// Fundamental arguments of the nutation, polynomials in Julian
+// centuries from J2000.0 | in degrees
+const MOON_ASC_NODE: Polynomial =
+    Polynomial::new(&[125.04452, -1934.136261, 0.0020708, 1.0 / 450000.0]);
+const MEAN_ELONG: Polynomial =
+    Polynomial::new(&[297.85036, 445267.11148, -0.0019142, 1.0 / 189474.0]);
+const SUN_MEAN_ANOM: Polynomial =
+    Polynomial::new(&[357.52772, 35999.05034, -0.0001603, -1.0 / 300000.0]);
+const MOON_MEAN_ANOM: Polynomial =
+    Polynomial::new(&[134.96298, 477198.867398, 0.0086972, 1.0 / 56250.0]);
+const MOON_ARG_LAT: Polynomial =
+    Polynomial::new(&[93.27191, 483202.017538, -0.0036825, 1.0 / 327270.0]);
+const SUN_MEAN_LONG: Polynomial = Polynomial::new(&[280.4665, 36000.7698]);
+const MOON_MEAN_LONG: Polynomial = Polynomial::new(&[218.3165, 481267.8813]);
+
+// Nutation in longitude and obliquity | in radians, and their rates of
+// change | in radians per day
+fn nutation_and_rate(jd: f64, accuracy: Accuracy) -> ((f64, f64), (f64, f64)) {
+    let t = time::julian_century(jd);
+
+    // An argument and its rate of change | in radians, and radians per
+    // Julian century
+    let arg = |poly: &Polynomial| {
+        let (value, rate) = poly.eval_with_derivative(t);
+        (value.to_radians(), rate.to_radians())
+    };
+    let moon_asc_node = arg(&MOON_ASC_NODE);
+
+    // Sums of the terms (ψ, ε) and of their rates of change, in
+    // arcseconds and arcseconds per Julian century
+    let (nut, rate) = match accuracy {
+        Accuracy::Low => {
+            let sun_mean_long = arg(&SUN_MEAN_LONG);
+            let moon_mean_long = arg(&MOON_MEAN_LONG);
+
+            let mut nut = (0.0, 0.0);
+            let mut rate = (0.0, 0.0);
+            for &(psi, eps, (angl, angl_rate)) in &[
+                (-17.2, 9.2, moon_asc_node),
+                (-1.32, 0.57, (2.0 * sun_mean_long.0, 2.0 * sun_mean_long.1)),
+                (-0.23, 0.1, (2.0 * moon_mean_long.0, 2.0 * moon_mean_long.1)),
+                (0.21, -0.09, (2.0 * moon_asc_node.0, 2.0 * moon_asc_node.1)),
+            ] {
+                let (sin, cos) = angl.sin_cos();
+                nut.0 += psi * sin;
+                nut.1 += eps * cos;
+                rate.0 += psi * cos * angl_rate;
+                rate.1 -= eps * sin * angl_rate;
+            }
+
+            (nut, rate)
+        }
+        Accuracy::High => {
+            // D, M, M', F and Ω
+            let fundamental = [
+                arg(&MEAN_ELONG),
+                arg(&SUN_MEAN_ANOM),
+                arg(&MOON_MEAN_ANOM),
+                arg(&MOON_ARG_LAT),
+                moon_asc_node,
+            ];
+
+            let mut nut = (0.0, 0.0);
+            let mut rate = (0.0, 0.0);
+
+            for (multiples, [psi0, psi1, eps0, eps1]) in TERMS.iter() {
+                let (angl, angl_rate) = multiples.iter().zip(fundamental.iter()).fold(
+                    (0.0, 0.0),
+                    |(angl, angl_rate), (&multiple, &(value, value_rate))| {
+                        (
+                            angl + (multiple as f64) * value,
+                            angl_rate + (multiple as f64) * value_rate,
+                        )
+                    },
+                );
+                let (sin, cos) = angl.sin_cos();
+
+                let (psi, eps) = (psi0 + psi1 * t, eps0 + eps1 * t);
+                nut.0 += psi * sin;
+                nut.1 += eps * cos;
+                rate.0 += psi1 * sin + psi * cos * angl_rate;
+                rate.1 += eps1 * cos - eps * sin * angl_rate;
+            }
+
+            (
+                (nut.0 / 10000.0, nut.1 / 10000.0),
+                (rate.0 / 10000.0, rate.1 / 10000.0),
+            )
+        }
+    };
+
+    let radians = |arcsec: f64| angle::deg_dmas(0, 0, arcsec).to_radians();
+    (
+        (radians(nut.0), radians(nut.1)),
+        (radians(rate.0 / 36525.0), radians(rate.1 / 36525.0)),
+    )
+}
+
+/**
+Computes the nutation in right ascension and declination
+
+# Returns
+
+(nut_in_asc, nut_in_dec)
+
+* nut_in_asc: Nutation in right ascension | in radians
+* nut_in_dec: Nutation in declination | in radians
+
+These are the corrections to add to a mean place, referred to the
+mean equinox of the date, to refer it to the true equinox of the date
+(Meeus, Astronomical Algorithms, equation 23.1). The nutation is
+computed with `Accuracy::High`. The formulas aren't valid close to the
+celestial poles.
+
+# Arguments
+
+* asc: Right ascension, referred to the mean equinox of the
+       date | in radians
+* dec: Declination, referred to the mean equinox of the
+       date | in radians
+* jd : Julian (Ephemeris) day
+**/
+pub fn nutation_in_eq_coords(asc: f64, dec: f64, jd: f64) -> (f64, f64) {
+    let (nut_in_long, nut_in_oblq) = nutation(jd, Accuracy::High);
+    let (sin_oblq, cos_oblq) = (ecliptic::mean_obliquity_laskar(jd) + nut_in_oblq).sin_cos();
+    let (sin_asc, cos_asc) = asc.sin_cos();
+    let tan_dec = dec.tan();
+
+    (
+        (cos_oblq + sin_oblq * sin_asc * tan_dec) * nut_in_long - cos_asc * tan_dec * nut_in_oblq,
+        sin_oblq * cos_asc * nut_in_long + sin_asc * nut_in_oblq,
+    )
+}
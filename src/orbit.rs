@@ -0,0 +1,539 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------HELIOCENTRIC ORBITS-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::precession;
+use crate::search;
+use crate::sun;
+use crate::time;
+use crate::Accuracy;
+use std::f64::consts::PI;
+
+// Number of intervals of the grid of true anomalies sampled for the
+// MOID, and the tolerance of the refined true anomalies | in radians
+const MOID_GRID: usize = 360;
+const MOID_TOLERANCE: f64 = 1e-9;
+
+// Number of the least local minima of the grid refined for the MOID;
+// two conics have at most four
+const MOID_CANDIDATES: usize = 8;
+
+// Gaussian gravitational constant | in radians per day
+const GAUSS_GRAV: f64 = 0.01720209895;
+
+/// Heliocentric gravitational parameter, the square of the Gaussian
+/// gravitational constant | in AU³ per day²
+pub const SUN_GRAV_PARAM: f64 = GAUSS_GRAV * GAUSS_GRAV;
+
+// Eccentricities closer than this to 1 are treated as parabolic
+const PARABOLIC_MARGIN: f64 = 1e-9;
+
+// Sampling step of the search for close approaches | in days
+const APPROACH_STEP: f64 = 0.25;
+
+// Tolerance of the time of a close approach | in days
+const APPROACH_TOLERANCE: f64 = 1.0 / 86400.0;
+
+/// Represents the elements of a heliocentric orbit, referred to the
+/// mean ecliptic and equinox of J2000.0
+///
+/// Elliptic, parabolic and hyperbolic orbits are all described from
+/// the perihelion, as in the comet elements of the Minor Planet
+/// Center.
+#[derive(Copy, Clone, Debug)]
+pub struct OrbitalElements {
+    /// Perihelion distance | in AU
+    pub perih_dist: f64,
+    /// Eccentricity
+    pub ecc: f64,
+    /// Inclination | in radians
+    pub incl: f64,
+    /// Argument of the perihelion | in radians
+    pub arg_perih: f64,
+    /// Longitude of the ascending node | in radians
+    pub asc_node: f64,
+    /// Time of perihelion passage | in Julian (Ephemeris) days
+    pub perih_jd: f64,
+}
+
+impl OrbitalElements {
+    /**
+    Returns the elements of an elliptic orbit given by its mean anomaly
+    at an epoch, as in the asteroid elements of the Minor Planet
+    Center
+
+    # Arguments
+
+    * semimaj_axis: Semimajor axis | in AU
+    * ecc         : Eccentricity
+    * incl        : Inclination | in radians
+    * arg_perih   : Argument of the perihelion | in radians
+    * asc_node    : Longitude of the ascending node | in radians
+    * mean_anom   : Mean anomaly at the epoch | in radians
+    * epoch       : Julian (Ephemeris) day of the epoch
+    **/
+    pub fn from_mean_anom(
+        semimaj_axis: f64,
+        ecc: f64,
+        incl: f64,
+        arg_perih: f64,
+        asc_node: f64,
+        mean_anom: f64,
+        epoch: f64,
+    ) -> OrbitalElements {
+        let mean_motion = GAUSS_GRAV / semimaj_axis.powf(1.5);
+
+        OrbitalElements {
+            perih_dist: semimaj_axis * (1.0 - ecc),
+            ecc,
+            incl,
+            arg_perih,
+            asc_node,
+            perih_jd: epoch - angle::limit_pi(mean_anom) / mean_motion,
+        }
+    }
+}
+
+/**
+Computes the heliocentric rectangular coordinates of a body from its
+orbital elements
+
+# Returns
+
+* rect_point: Heliocentric rectangular ecliptic point, referred to
+              the mean ecliptic and equinox of J2000.0 | in AU
+
+The motion is unperturbed; Kepler's equation is solved for elliptic
+and hyperbolic orbits, and Barker's for parabolic ones.
+
+# Arguments
+
+* elements: Orbital elements of the body
+* jd      : Julian (Ephemeris) day
+**/
+pub fn heliocent_rect(elements: &OrbitalElements, jd: f64) -> coordinate::RectPoint {
+    let (true_anom, rad_vec) =
+        true_anom_and_rad_vec(elements, jd - elements.perih_jd, SUN_GRAV_PARAM);
+
+    rect_from_true_anom(elements, true_anom, rad_vec)
+}
+
+/**
+Computes the orbital elements of a body from its state vector
+
+# Returns
+
+* elements: Orbital elements of the body, with the time of perihelion
+            passage nearest to `jd`
+
+The elements are referred to the same frame as the state vector. An
+eccentricity within 1e-9 of 1 is taken as parabolic. A circular orbit
+has its perihelion put at the ascending node, and an orbit in the
+plane of the frame has its ascending node put on the x-axis.
+
+# Arguments
+
+* pos       : Position of the body relative to the central body | in AU
+* vel       : Velocity of the body | in AU per day
+* grav_param: Gravitational parameter of the central body, such as
+              [SUN_GRAV_PARAM](./constant.SUN_GRAV_PARAM.html) | in AU³ per day²
+* jd        : Julian (Ephemeris) day of the state vector
+**/
+pub fn elements_from_state(
+    pos: &coordinate::RectPoint,
+    vel: &coordinate::RectPoint,
+    grav_param: f64,
+    jd: f64,
+) -> OrbitalElements {
+    let rad_vec = pos.dist();
+    let speed_sqr = vel.x * vel.x + vel.y * vel.y + vel.z * vel.z;
+    let radial = pos.x * vel.x + pos.y * vel.y + pos.z * vel.z;
+
+    let ang_mom = cross(pos, vel);
+    let ang_mom_mag = ang_mom.dist();
+    let unit_normal = coordinate::RectPoint::new(
+        ang_mom.x / ang_mom_mag,
+        ang_mom.y / ang_mom_mag,
+        ang_mom.z / ang_mom_mag,
+    );
+
+    // Eccentricity vector, pointing to the perihelion
+    let radial_coeff = speed_sqr - grav_param / rad_vec;
+    let ecc_vec = coordinate::RectPoint::new(
+        (radial_coeff * pos.x - radial * vel.x) / grav_param,
+        (radial_coeff * pos.y - radial * vel.y) / grav_param,
+        (radial_coeff * pos.z - radial * vel.z) / grav_param,
+    );
+    let mut ecc = ecc_vec.dist();
+    if (ecc - 1.0).abs() < PARABOLIC_MARGIN {
+        ecc = 1.0;
+    }
+
+    let incl = unit_normal.z.clamp(-1.0, 1.0).acos();
+    let asc_node = if unit_normal.x.hypot(unit_normal.y) > 1e-12 {
+        angle::limit_twoPI(unit_normal.x.atan2(-unit_normal.y))
+    } else {
+        0.0
+    };
+
+    // Angles in the orbital plane, measured from the ascending node
+    let node_dir = coordinate::RectPoint::new(asc_node.cos(), asc_node.sin(), 0.0);
+    let angle_from_node = |v: &coordinate::RectPoint| {
+        let along = node_dir.x * v.x + node_dir.y * v.y + node_dir.z * v.z;
+        let c = cross(&node_dir, v);
+        let across = c.x * unit_normal.x + c.y * unit_normal.y + c.z * unit_normal.z;
+        across.atan2(along)
+    };
+    let arg_perih = if ecc > 1e-12 {
+        angle::limit_twoPI(angle_from_node(&ecc_vec))
+    } else {
+        0.0
+    };
+    let true_anom = angle::limit_pi(angle_from_node(pos) - arg_perih);
+
+    let semilat_rect = ang_mom_mag * ang_mom_mag / grav_param;
+    let perih_dist = semilat_rect / (1.0 + ecc);
+
+    let time_from_perih = if ecc == 1.0 {
+        // Barker's equation
+        let s = (true_anom / 2.0).tan();
+        (2.0 * perih_dist.powi(3) / grav_param).sqrt() * (s + s * s * s / 3.0)
+    } else {
+        let semimaj_axis = perih_dist / (1.0 - ecc).abs();
+        let mean_motion = (grav_param / semimaj_axis.powi(3)).sqrt();
+        let mean_anom = if ecc < 1.0 {
+            let ecc_anom =
+                2.0 * (((1.0 - ecc) / (1.0 + ecc)).sqrt() * (true_anom / 2.0).tan()).atan();
+            ecc_anom - ecc * ecc_anom.sin()
+        } else {
+            let hyp_anom =
+                2.0 * (((ecc - 1.0) / (ecc + 1.0)).sqrt() * (true_anom / 2.0).tan()).atanh();
+            ecc * hyp_anom.sinh() - hyp_anom
+        };
+        mean_anom / mean_motion
+    };
+
+    OrbitalElements {
+        perih_dist,
+        ecc,
+        incl,
+        arg_perih,
+        asc_node,
+        perih_jd: jd - time_from_perih,
+    }
+}
+
+/**
+Computes the state vector of a body from its orbital elements
+
+# Returns
+
+(pos, vel)
+
+* pos: Position of the body relative to the central body, in the
+       frame of the elements | in AU
+* vel: Velocity of the body | in AU per day
+
+With [SUN_GRAV_PARAM](./constant.SUN_GRAV_PARAM.html) the position is
+that of [heliocent_rect()](./fn.heliocent_rect.html).
+
+# Arguments
+
+* elements  : Orbital elements of the body
+* grav_param: Gravitational parameter of the central body | in AU³ per day²
+* jd        : Julian (Ephemeris) day
+**/
+pub fn state_from_elements(
+    elements: &OrbitalElements,
+    grav_param: f64,
+    jd: f64,
+) -> (coordinate::RectPoint, coordinate::RectPoint) {
+    let (true_anom, rad_vec) = true_anom_and_rad_vec(elements, jd - elements.perih_jd, grav_param);
+    let pos = rect_from_true_anom(elements, true_anom, rad_vec);
+
+    // Velocity from its components along the directions of the
+    // perihelion and of a true anomaly of 90 degrees
+    let semilat_rect = elements.perih_dist * (1.0 + elements.ecc);
+    let speed_unit = (grav_param / semilat_rect).sqrt();
+    let to_perih = rect_from_true_anom(elements, 0.0, 1.0);
+    let to_latus = rect_from_true_anom(elements, PI / 2.0, 1.0);
+    let (along_perih, along_latus) = (
+        -speed_unit * true_anom.sin(),
+        speed_unit * (elements.ecc + true_anom.cos()),
+    );
+    let vel = coordinate::RectPoint::new(
+        along_perih * to_perih.x + along_latus * to_latus.x,
+        along_perih * to_perih.y + along_latus * to_latus.y,
+        along_perih * to_perih.z + along_latus * to_latus.z,
+    );
+
+    (pos, vel)
+}
+
+/**
+Computes the minimum orbit intersection distance of two orbits
+
+# Returns
+
+* moid: Least distance between a point of one orbit and a point of
+        the other | in AU
+
+The distance is sampled over a grid of the true anomalies of both
+orbits, one degree apart, and the least local minima of the grid
+are refined with [search::minimize()](../search/fn.minimize.html). Open
+orbits are sampled out to 99.9 percent of their asymptotic true
+anomaly.
+
+# Arguments
+
+* elements_a: Orbital elements of the first body
+* elements_b: Orbital elements of the second body
+**/
+pub fn moid(elements_a: &OrbitalElements, elements_b: &OrbitalElements) -> f64 {
+    let (range_a, range_b) = (true_anom_range(elements_a), true_anom_range(elements_b));
+    let step_a = 2.0 * range_a / MOID_GRID as f64;
+    let step_b = 2.0 * range_b / MOID_GRID as f64;
+
+    let point_at = |elements: &OrbitalElements, true_anom: f64| {
+        let rad_vec =
+            elements.perih_dist * (1.0 + elements.ecc) / (1.0 + elements.ecc * true_anom.cos());
+        rect_from_true_anom(elements, true_anom, rad_vec)
+    };
+    let dist = |true_anom_a: f64, true_anom_b: f64| {
+        let (a, b) = (
+            point_at(elements_a, true_anom_a),
+            point_at(elements_b, true_anom_b),
+        );
+        coordinate::RectPoint::new(a.x - b.x, a.y - b.y, a.z - b.z).dist()
+    };
+
+    let points_b: Vec<coordinate::RectPoint> = (0..=MOID_GRID)
+        .map(|j| point_at(elements_b, -range_b + j as f64 * step_b))
+        .collect();
+    let grid: Vec<Vec<f64>> = (0..=MOID_GRID)
+        .map(|i| {
+            let a = point_at(elements_a, -range_a + i as f64 * step_a);
+            points_b
+                .iter()
+                .map(|b| coordinate::RectPoint::new(a.x - b.x, a.y - b.y, a.z - b.z).dist())
+                .collect()
+        })
+        .collect();
+
+    // Least local minima of the grid, each refined by nested searches
+    // over the cells around it
+    let mut local_minima: Vec<(usize, usize)> = (0..=MOID_GRID)
+        .flat_map(|i| (0..=MOID_GRID).map(move |j| (i, j)))
+        .filter(|&(i, j)| {
+            (i.saturating_sub(1)..=(i + 1).min(MOID_GRID)).all(|k| {
+                (j.saturating_sub(1)..=(j + 1).min(MOID_GRID)).all(|l| grid[k][l] >= grid[i][j])
+            })
+        })
+        .collect();
+    local_minima.sort_by(|&(i, j), &(k, l)| grid[i][j].total_cmp(&grid[k][l]));
+
+    local_minima
+        .iter()
+        .take(MOID_CANDIDATES)
+        .map(|&(i, j)| {
+            let center_a = -range_a + i as f64 * step_a;
+            let center_b = -range_b + j as f64 * step_b;
+            let refined = search::minimize(
+                center_a - step_a,
+                center_a + step_a,
+                step_a,
+                MOID_TOLERANCE,
+                |true_anom_a| {
+                    search::minimize(
+                        center_b - step_b,
+                        center_b + step_b,
+                        step_b,
+                        MOID_TOLERANCE,
+                        |true_anom_b| dist(true_anom_a, true_anom_b),
+                    )
+                    .map_or(f64::INFINITY, |(_, least)| least)
+                },
+            )
+            .map_or(f64::INFINITY, |(_, least)| least);
+
+            refined.min(grid[i][j])
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+// Heliocentric rectangular ecliptic point at a true anomaly and
+// radius vector
+fn rect_from_true_anom(
+    elements: &OrbitalElements,
+    true_anom: f64,
+    rad_vec: f64,
+) -> coordinate::RectPoint {
+    let arg_lat = true_anom + elements.arg_perih;
+    let (sin_node, cos_node) = elements.asc_node.sin_cos();
+    let (sin_incl, cos_incl) = elements.incl.sin_cos();
+    let (sin_lat, cos_lat) = arg_lat.sin_cos();
+
+    coordinate::RectPoint::new(
+        rad_vec * (cos_node * cos_lat - sin_node * sin_lat * cos_incl),
+        rad_vec * (sin_node * cos_lat + cos_node * sin_lat * cos_incl),
+        rad_vec * sin_lat * sin_incl,
+    )
+}
+
+/**
+Computes the geometric distance of a body from the Earth
+
+# Returns
+
+* earth_body_dist: Distance from the center of the Earth | in AU
+
+# Arguments
+
+* elements: Orbital elements of the body
+* jd      : Julian (Ephemeris) day
+**/
+pub fn geocent_dist(elements: &OrbitalElements, jd: f64) -> f64 {
+    let body = heliocent_rect(elements, jd);
+    let earth = earth_heliocent_rect(jd);
+
+    coordinate::RectPoint::new(body.x - earth.x, body.y - earth.y, body.z - earth.z).dist()
+}
+
+/**
+Finds the closest approach of a body to the Earth
+
+# Returns
+
+(jd, earth_body_dist)
+
+* jd             : Julian (Ephemeris) day of the closest approach
+* earth_body_dist: Distance from the center of the Earth at the
+                   closest approach | in AU
+
+The distance of [geocent_dist()](./fn.geocent_dist.html) is sampled
+every six hours and its least value refined with
+[search::minimize()](../search/fn.minimize.html). The time is
+accurate to about a second for the unperturbed orbit; the Earth's
+attraction, which bends the paths of very close approaches, is
+ignored. An approach at `jd_start` or `jd_end` means the body is
+closest at the edge of the range.
+
+# Arguments
+
+* elements: Orbital elements of the body
+* jd_start: Julian (Ephemeris) day of the start of the search
+* jd_end  : Julian (Ephemeris) day of the end of the search
+**/
+pub fn close_approach(elements: &OrbitalElements, jd_start: f64, jd_end: f64) -> (f64, f64) {
+    search::minimize(jd_start, jd_end, APPROACH_STEP, APPROACH_TOLERANCE, |jd| {
+        geocent_dist(elements, jd)
+    })
+    .expect("Internal error in orbit::close_approach")
+}
+
+// True anomaly and radius vector at a time from the perihelion, for
+// a gravitational parameter in AU³ per day²
+fn true_anom_and_rad_vec(
+    elements: &OrbitalElements,
+    time_from_perih: f64,
+    grav_param: f64,
+) -> (f64, f64) {
+    let (q, ecc) = (elements.perih_dist, elements.ecc);
+    let grav = grav_param.sqrt();
+
+    if (ecc - 1.0).abs() < PARABOLIC_MARGIN {
+        // Barker's equation, Meeus, Astronomical Algorithms, chapter 34
+        let w = 3.0 * grav / (2.0 * q * q * q).sqrt() * time_from_perih;
+        let g = w / 2.0;
+        let y = (g + (g * g + 1.0).sqrt()).cbrt();
+        let s = y - 1.0 / y;
+
+        return (2.0 * s.atan(), q * (1.0 + s * s));
+    }
+
+    let semimaj_axis = q / (1.0 - ecc).abs();
+    let mean_anom = grav / semimaj_axis.powf(1.5) * time_from_perih;
+
+    if ecc < 1.0 {
+        let mean_anom = angle::limit_pi(mean_anom);
+        let mut ecc_anom = if ecc > 0.8 {
+            PI.copysign(mean_anom)
+        } else {
+            mean_anom
+        };
+        for _ in 0..50 {
+            let correction =
+                (ecc_anom - ecc * ecc_anom.sin() - mean_anom) / (1.0 - ecc * ecc_anom.cos());
+            ecc_anom -= correction;
+            if correction.abs() < 1e-14 {
+                break;
+            }
+        }
+
+        let true_anom = 2.0 * (((1.0 + ecc) / (1.0 - ecc)).sqrt() * (ecc_anom / 2.0).tan()).atan();
+        (true_anom, semimaj_axis * (1.0 - ecc * ecc_anom.cos()))
+    } else {
+        let mut hyp_anom = (mean_anom / ecc).asinh();
+        for _ in 0..50 {
+            let correction =
+                (ecc * hyp_anom.sinh() - hyp_anom - mean_anom) / (ecc * hyp_anom.cosh() - 1.0);
+            hyp_anom -= correction;
+            if correction.abs() < 1e-14 {
+                break;
+            }
+        }
+
+        let true_anom = 2.0 * (((ecc + 1.0) / (ecc - 1.0)).sqrt() * (hyp_anom / 2.0).tanh()).atan();
+        (true_anom, semimaj_axis * (ecc * hyp_anom.cosh() - 1.0))
+    }
+}
+
+// Cross product of two vectors
+fn cross(a: &coordinate::RectPoint, b: &coordinate::RectPoint) -> coordinate::RectPoint {
+    coordinate::RectPoint::new(
+        a.y * b.z - a.z * b.y,
+        a.z * b.x - a.x * b.z,
+        a.x * b.y - a.y * b.x,
+    )
+}
+
+// Heliocentric rectangular ecliptic coordinates of the Earth, referred
+// to the mean ecliptic and equinox of J2000.0 | in AU
+fn earth_heliocent_rect(jd: f64) -> coordinate::RectPoint {
+    let (sun, rad_vec) = sun::geocent_ecl_pos(jd, Accuracy::High);
+    let sun = precession::precess_ecl(&sun, jd, time::J2000);
+
+    coordinate::RectPoint::from_spherical(sun.long + PI, -sun.lat, rad_vec)
+}
+
+// Largest true anomaly sampled on an orbit, short of the asymptote of
+// an open one | in radians
+fn true_anom_range(elements: &OrbitalElements) -> f64 {
+    if elements.ecc < 1.0 {
+        PI
+    } else {
+        0.999 * (-1.0 / elements.ecc).acos()
+    }
+}
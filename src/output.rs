@@ -0,0 +1,246 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------ALMANAC OUTPUT-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::time;
+use std::io;
+
+/// Represents a column of an almanac table
+///
+/// The column headers follow the names used by JPL Horizons for
+/// apparent positions, with the time scale shown as TT.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Column {
+    /// Calendar date and time, `Date__(TT)__HR:MN:SS`
+    Date,
+    /// Julian day, `Date_________JDTT`
+    JulianDay,
+    /// Apparent right ascension, `R.A._(a-app)`
+    Asc,
+    /// Apparent declination, `DEC_(a-app)`
+    Dec,
+    /// Distance in AU, `delta`
+    Dist,
+}
+
+impl Column {
+    /// Returns the header of the column
+    pub fn header(&self) -> &'static str {
+        match self {
+            Column::Date => "Date__(TT)__HR:MN:SS",
+            Column::JulianDay => "Date_________JDTT",
+            Column::Asc => "R.A._(a-app)",
+            Column::Dec => "DEC_(a-app)",
+            Column::Dist => "delta",
+        }
+    }
+}
+
+/// Represents the format of angles in an almanac table
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AngleFormat {
+    /// Right ascension as `HH MM SS.ff` and declination as
+    /// `sDD MM SS.f`
+    Sexagesimal,
+    /// Angles in degrees with decimals
+    Decimal,
+}
+
+/// Represents the layout of an almanac table
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Columns, in order
+    pub columns: Vec<Column>,
+    /// Format of the right ascension and declination
+    pub angle_format: AngleFormat,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            columns: vec![Column::Date, Column::Asc, Column::Dec, Column::Dist],
+            angle_format: AngleFormat::Sexagesimal,
+        }
+    }
+}
+
+/**
+Writes rows of an ephemeris as CSV
+
+The first line holds the column headers. An error of kind
+`InvalidInput` is returned if a date can't be written, as for a
+negative Julian day.
+
+# Arguments
+
+* writer : Destination of the CSV text
+* rows   : Rows `(jd, eq_point, dist)`, such as the ones yielded by
+           [ephemeris::range()](../ephemeris/fn.range.html)
+* options: Layout of the table
+**/
+pub fn write_csv<W, I>(writer: &mut W, rows: I, options: &Options) -> io::Result<()>
+where
+    W: io::Write,
+    I: IntoIterator<Item = (f64, coordinate::EqPoint, f64)>,
+{
+    let headers: Vec<&str> = options.columns.iter().map(|c| c.header()).collect();
+    writeln!(writer, "{}", headers.join(","))?;
+
+    for (jd, eq_point, dist) in rows {
+        let fields = options
+            .columns
+            .iter()
+            .map(|c| format_field(c, jd, &eq_point, dist, options.angle_format))
+            .collect::<io::Result<Vec<String>>>()?;
+        writeln!(writer, "{}", fields.join(","))?;
+    }
+
+    Ok(())
+}
+
+/**
+Writes rows of an ephemeris as JSON
+
+The rows are written as an array of objects keyed by the column
+headers. All values are written as strings, in the same format as
+in [write_csv()](./fn.write_csv.html), and the same errors are
+returned.
+
+# Arguments
+
+* writer : Destination of the JSON text
+* rows   : Rows `(jd, eq_point, dist)`, such as the ones yielded by
+           [ephemeris::range()](../ephemeris/fn.range.html)
+* options: Layout of the table
+**/
+pub fn write_json<W, I>(writer: &mut W, rows: I, options: &Options) -> io::Result<()>
+where
+    W: io::Write,
+    I: IntoIterator<Item = (f64, coordinate::EqPoint, f64)>,
+{
+    write!(writer, "[")?;
+
+    for (i, (jd, eq_point, dist)) in rows.into_iter().enumerate() {
+        if i > 0 {
+            write!(writer, ",")?;
+        }
+
+        let members = options
+            .columns
+            .iter()
+            .map(|c| {
+                let field = format_field(c, jd, &eq_point, dist, options.angle_format)?;
+                Ok(format!("\"{}\":\"{}\"", c.header(), field))
+            })
+            .collect::<io::Result<Vec<String>>>()?;
+        write!(writer, "{{{}}}", members.join(","))?;
+    }
+
+    writeln!(writer, "]")
+}
+
+fn format_field(
+    column: &Column,
+    jd: f64,
+    eq_point: &coordinate::EqPoint,
+    dist: f64,
+    angle_format: AngleFormat,
+) -> io::Result<String> {
+    Ok(match column {
+        Column::Date => format_date(jd)?,
+        Column::JulianDay => format!("{:.9}", jd),
+        Column::Asc => {
+            let asc = angle::limit_360(eq_point.asc.to_degrees());
+            match angle_format {
+                AngleFormat::Sexagesimal => format_sexagesimal(asc / 15.0, 2, false, Some(24)),
+                AngleFormat::Decimal => format!("{:.5}", asc),
+            }
+        }
+        Column::Dec => {
+            let dec = eq_point.dec.to_degrees();
+            match angle_format {
+                AngleFormat::Sexagesimal => format_sexagesimal(dec, 1, true, None),
+                AngleFormat::Decimal => format!("{:.5}", dec),
+            }
+        }
+        Column::Dist => format!("{:.14}", dist),
+    })
+}
+
+// Formats a value as `DD MM SS.f`, rounding it to `decimals` of a
+// second before splitting the fields, so that the seconds carry into
+// the minutes, and wrapping the leading field at `period` if any
+fn format_sexagesimal(value: f64, decimals: usize, signed: bool, period: Option<i64>) -> String {
+    let scale = 10_i64.pow(decimals as u32);
+    let mut total = (value.abs() * 3600.0 * scale as f64).round() as i64;
+    if let Some(period) = period {
+        total %= period * 3600 * scale;
+    }
+
+    let units = total / (3600 * scale);
+    let minutes = (total / (60 * scale)) % 60;
+    let seconds = (total % (60 * scale)) as f64 / scale as f64;
+
+    // A value rounding to zero is written unsigned
+    let sign = if value < 0.0 && total != 0 {
+        "-"
+    } else if signed {
+        "+"
+    } else {
+        ""
+    };
+
+    format!(
+        "{}{:02} {:02} {:0width$.decimals$}",
+        sign,
+        units,
+        minutes,
+        seconds,
+        width = decimals + 3,
+        decimals = decimals
+    )
+}
+
+fn format_date(jd: f64) -> io::Result<String> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    // Adding half a second rounds to the nearest second
+    let (year, month, decimal_day) = time::date_from_julian_day(jd + 0.5 / 86400.0)
+        .map_err(|msg| io::Error::new(io::ErrorKind::InvalidInput, msg))?;
+    let day = decimal_day.floor();
+    let secs = ((decimal_day - day) * 86400.0).floor() as i64;
+
+    Ok(format!(
+        "{}-{}-{:02} {:02}:{:02}:{:02}",
+        year,
+        MONTHS[(month - 1) as usize],
+        day as i64,
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60
+    ))
+}
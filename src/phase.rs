@@ -0,0 +1,116 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------PHASE-----------!
+
+use crate::angle;
+use crate::coordinate;
+
+/**
+Computes the phase angle of a body
+
+# Returns
+
+* phase_angle: Phase angle, the angle Sun-body-Earth | in radians
+
+# Arguments
+
+* body_sun_dist  : Body-Sun distance r
+* body_earth_dist: Body-Earth distance Δ
+* earth_sun_dist : Earth-Sun distance R
+
+All three distances must be in the same unit.
+**/
+pub fn phase_angle(body_sun_dist: f64, body_earth_dist: f64, earth_sun_dist: f64) -> f64 {
+    angle_opposite(body_sun_dist, body_earth_dist, earth_sun_dist)
+}
+
+/**
+Computes the elongation of a body
+
+# Returns
+
+* elongation: Elongation, the angle Sun-Earth-body | in radians
+
+# Arguments
+
+* body_sun_dist  : Body-Sun distance r
+* body_earth_dist: Body-Earth distance Δ
+* earth_sun_dist : Earth-Sun distance R
+
+All three distances must be in the same unit.
+**/
+pub fn elongation(body_sun_dist: f64, body_earth_dist: f64, earth_sun_dist: f64) -> f64 {
+    angle_opposite(earth_sun_dist, body_earth_dist, body_sun_dist)
+}
+
+/**
+Computes the illuminated fraction of the disk of a body
+
+# Returns
+
+* illum_frac: Illuminated fraction of the disk | range: 0.0 - 1.0
+
+# Arguments
+
+* phase_angle: Phase angle of the body | in radians
+**/
+#[inline]
+pub fn illuminated_fraction(phase_angle: f64) -> f64 {
+    (1.0 + phase_angle.cos()) / 2.0
+}
+
+/**
+Computes the position angle of the bright limb of a body
+
+# Returns
+
+* pos_angle: Position angle of the midpoint of the illuminated
+             limb, measured eastwards from the north | in radians
+
+This is the position angle of the direction towards the Sun, Meeus,
+Astronomical Algorithms, equation 48.5.
+
+# Arguments
+
+* body_eq_point: Equatorial point of the body | in radians
+* sun_eq_point : Equatorial point of the Sun | in radians
+**/
+pub fn bright_limb_pos_angle(
+    body_eq_point: &coordinate::EqPoint,
+    sun_eq_point: &coordinate::EqPoint,
+) -> f64 {
+    let d_asc = sun_eq_point.asc - body_eq_point.asc;
+
+    angle::limit_twoPI((sun_eq_point.dec.cos() * d_asc.sin()).atan2(
+        sun_eq_point.dec.sin() * body_eq_point.dec.cos()
+            - sun_eq_point.dec.cos() * body_eq_point.dec.sin() * d_asc.cos(),
+    ))
+}
+
+// Angle between the sides a and b of a plane triangle, opposite
+// to the side c, by the law of cosines
+fn angle_opposite(a: f64, b: f64, c: f64) -> f64 {
+    ((a * a + b * b - c * c) / (2.0 * a * b))
+        .clamp(-1.0, 1.0)
+        .acos()
+}
@@ -0,0 +1,394 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------PHENOMENA-----------!
+
+use crate::angle;
+use crate::au;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::ephemeris;
+use crate::lunar;
+use crate::navigation;
+use crate::nutation;
+use crate::search;
+use crate::sun;
+use crate::time;
+use crate::transit;
+use crate::Accuracy;
+use std::f64::consts::PI;
+
+// Semidiameter of Mars at 1 AU (Meeus, Astronomical Algorithms,
+// table 55.A) | in arcseconds
+const MARS_SEMIDIAMETER_1AU: f64 = 4.68;
+
+// Sampling step of the search for appulses | in days, and half the
+// interval over which the separation is differentiated | in days
+const APPULSE_STEP: f64 = 0.5;
+const APPULSE_HALF_INTERVAL: f64 = 1.0 / 1440.0;
+
+// Bodies other than the Sun, between which occultations are searched
+const OCCULTING_BODIES: [ephemeris::Body; 2] = [ephemeris::Body::Moon, ephemeris::Body::Mars];
+
+/// Represents the geometry of the zodiacal light cone for an observer
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ZodiacalLightCone {
+    /// Azimuth of the point where the ecliptic meets the horizon on
+    /// the side of the Sun, measured westwards from the south | in radians
+    pub az: f64,
+    /// Angle between the ecliptic and the horizon | in radians
+    pub inclination: f64,
+}
+
+/// Represents a least separation of two bodies on the sky
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Appulse {
+    /// Julian (Ephemeris) day of the least separation
+    pub jd: f64,
+    /// The nearer body
+    pub near_body: ephemeris::Body,
+    /// The farther body
+    pub far_body: ephemeris::Body,
+    /// Geocentric angular separation of the centers | in radians
+    pub angular_sep: f64,
+    /// Whether the disks overlap, the nearer body occulting the
+    /// farther one, as seen from the center of the Earth
+    pub occultation: bool,
+}
+
+/**
+Computes the antisolar point
+
+# Returns
+
+* `antisolar_point`: Apparent geocentric ecliptic point opposite the
+                     Sun, referred to the true equinox of the
+                     date | in radians
+
+The gegenschein is centered on this point.
+
+# Arguments
+
+* `jd`: Julian (Ephemeris) day
+**/
+pub fn antisolar_point(jd: f64) -> coordinate::EclPoint {
+    let (sun_point, _) = sun::apparent_ecl_pos(jd, Accuracy::Low);
+
+    coordinate::EclPoint {
+        long: angle::limit_twoPI(sun_point.long + PI),
+        lat: -sun_point.lat,
+    }
+}
+
+/**
+Computes the local horizontal position of the gegenschein
+
+# Returns
+
+(az, alt)
+
+* `az` : Azimuth of the antisolar point, measured westwards from the
+         south | in radians
+* `alt`: Altitude of the antisolar point, not corrected for
+         atmospheric refraction | in radians
+
+# Arguments
+
+* `jd`      : Julian day, UT
+* `observer`: The observer
+**/
+pub fn gegenschein_horizontal_pos(jd: f64, observer: &coordinate::Observer) -> (f64, f64) {
+    let jde = time::tt_from_ut(jd);
+    let ecl_point = antisolar_point(jde);
+    let oblq_eclip = true_obliquity(jde);
+
+    let asc = coordinate::asc_from_ecliptic(ecl_point.long, ecl_point.lat, oblq_eclip);
+    let dec = coordinate::dec_from_ecliptic(ecl_point.long, ecl_point.lat, oblq_eclip);
+    let hour_angle =
+        coordinate::hour_angle_from_long(time::apparent_sidereal_from_jd(jd), observer.long, asc);
+
+    (
+        coordinate::azimuth_from_eq(hour_angle, dec, observer.lat),
+        coordinate::altitude_from_eq(hour_angle, dec, observer.lat),
+    )
+}
+
+/**
+Computes where the zodiacal light cone rises from the horizon
+
+# Returns
+
+* `cone`: Azimuth of the base of the cone and its inclination to
+          the horizon
+
+The zodiacal light extends along the ecliptic from the Sun, so its
+base lies where the ecliptic crosses the horizon on the Sun's side.
+It is most conspicuous when the inclination is close to 90°, that
+is after evening twilight in spring and before morning twilight in
+autumn at mid-latitudes.
+
+# Arguments
+
+* `jd`      : Julian day, UT
+* `observer`: The observer
+**/
+pub fn zodiacal_light_cone(jd: f64, observer: &coordinate::Observer) -> ZodiacalLightCone {
+    let jde = time::tt_from_ut(jd);
+    let oblq_eclip = true_obliquity(jde);
+    let (sun_point, _) = sun::apparent_ecl_pos(jde, Accuracy::Low);
+    let loc_sidreal = angle::limit_twoPI(time::apparent_sidereal_from_jd(jd) - observer.long);
+
+    let (long_1, long_2) = ecliptic::eclip_points_on_hz(oblq_eclip, observer.lat, loc_sidreal);
+    let long = if angle::limit_pi(long_1 - sun_point.long).abs() <= PI / 2.0 {
+        long_1
+    } else {
+        long_2
+    };
+
+    let asc = coordinate::asc_from_ecliptic(long, 0.0, oblq_eclip);
+    let dec = coordinate::dec_from_ecliptic(long, 0.0, oblq_eclip);
+    let hour_angle =
+        coordinate::hour_angle_from_long(time::apparent_sidereal_from_jd(jd), observer.long, asc);
+
+    ZodiacalLightCone {
+        az: coordinate::azimuth_from_eq(hour_angle, dec, observer.lat),
+        inclination: ecliptic::angl_betwn_eclip_and_hz(oblq_eclip, observer.lat, loc_sidreal),
+    }
+}
+
+/**
+Computes the subsolar point
+
+# Returns
+
+* `subsolar_point`: Geographic point where the Sun is at the
+                    zenith, with the longitude positive westward |
+                    in radians
+
+# Arguments
+
+* `jd`: Julian day, UT
+**/
+pub fn subsolar_point(jd: f64) -> coordinate::GeographPoint {
+    sub_point(&ephemeris::Body::Sun, jd)
+}
+
+/**
+Computes the sublunar point
+
+# Returns
+
+* `sublunar_point`: Geographic point where the Moon is at the
+                    zenith, as seen from the center of the Earth,
+                    with the longitude positive westward | in radians
+
+# Arguments
+
+* `jd`: Julian day, UT
+**/
+pub fn sublunar_point(jd: f64) -> coordinate::GeographPoint {
+    sub_point(&ephemeris::Body::Moon, jd)
+}
+
+/**
+Computes the day/night terminator
+
+# Returns
+
+* `terminator`: `n_points` geographic points, with the longitude
+                positive westward, where the Sun is rising or
+                setting | in radians
+
+The Sun is at its standard altitude of −50′, as for
+[transit::sun_rise_set()](../transit/fn.sun_rise_set.html). The
+points run around the small circle centered on the
+[subsolar_point()](./fn.subsolar_point.html), starting due north of
+it and turning eastward, evenly spaced; the first point is not
+repeated at the end.
+
+# Arguments
+
+* `jd`      : Julian day, UT
+* `n_points`: Number of points
+**/
+pub fn terminator(jd: f64, n_points: usize) -> Vec<coordinate::GeographPoint> {
+    terminator_at_depression(jd, n_points, -transit::StandardAltitude::Sun.alt())
+}
+
+/**
+Computes the terminator for a depression of the Sun
+
+# Returns
+
+* `terminator`: `n_points` geographic points, with the longitude
+                positive westward, where the center of the Sun is
+                `depression` below the horizon | in radians
+
+Depressions of 6°, 12° and 18° trace the limits of civil, nautical
+and astronomical twilight. The points are ordered as for
+[terminator()](./fn.terminator.html).
+
+# Arguments
+
+* `jd`        : Julian day, UT
+* `n_points`  : Number of points
+* `depression`: Depression of the Sun below the horizon, negative
+                above it | in radians
+**/
+pub fn terminator_at_depression(
+    jd: f64,
+    n_points: usize,
+    depression: f64,
+) -> Vec<coordinate::GeographPoint> {
+    let subsolar = subsolar_point(jd);
+    let radius = PI / 2.0 + depression;
+
+    (0..n_points)
+        .map(|i| {
+            let course = 2.0 * PI * (i as f64) / (n_points as f64);
+            let point = navigation::destination(&subsolar, course, radius);
+
+            coordinate::GeographPoint::new(angle::limit_pi(point.long), point.lat)
+        })
+        .collect()
+}
+
+/**
+Finds the appulses of two bodies
+
+# Returns
+
+* appulses: Least geocentric separations of the two bodies below
+            `max_sep`, in increasing order of time
+
+The separation is sampled every half day, and each minimum is found
+to about a second. Minima closer together than half a day may be
+missed.
+
+# Arguments
+
+* body_1  : The first body
+* body_2  : The second body
+* jd_start: Julian (Ephemeris) day of the start of the search
+* jd_end  : Julian (Ephemeris) day of the end of the search
+* max_sep : Largest angular separation of the centers | in radians
+**/
+pub fn appulses(
+    body_1: &ephemeris::Body,
+    body_2: &ephemeris::Body,
+    jd_start: f64,
+    jd_end: f64,
+    max_sep: f64,
+) -> Vec<Appulse> {
+    let angular_sep = |jd: f64| {
+        let (eq_point_1, _) = ephemeris::apparent_eq_pos(body_1, jd, Accuracy::High);
+        let (eq_point_2, _) = ephemeris::apparent_eq_pos(body_2, jd, Accuracy::High);
+        eq_point_1.angular_sep(&eq_point_2)
+    };
+    let approaching =
+        |jd: f64| angular_sep(jd + APPULSE_HALF_INTERVAL) < angular_sep(jd - APPULSE_HALF_INTERVAL);
+
+    search::changes(jd_start, jd_end, APPULSE_STEP, 1.0 / 86400.0, approaching)
+        .expect("Internal error in phenomena::appulses")
+        .into_iter()
+        .filter(|&(_, approaching)| !approaching)
+        .filter_map(|(jd, _)| {
+            let (eq_point_1, dist_1) = ephemeris::apparent_eq_pos(body_1, jd, Accuracy::High);
+            let (eq_point_2, dist_2) = ephemeris::apparent_eq_pos(body_2, jd, Accuracy::High);
+            let angular_sep = eq_point_1.angular_sep(&eq_point_2);
+            if angular_sep > max_sep {
+                return None;
+            }
+
+            let (near_body, far_body) = if dist_1 < dist_2 {
+                (*body_1, *body_2)
+            } else {
+                (*body_2, *body_1)
+            };
+
+            Some(Appulse {
+                jd,
+                near_body,
+                far_body,
+                angular_sep,
+                occultation: angular_sep
+                    < semidiameter(body_1, dist_1) + semidiameter(body_2, dist_2),
+            })
+        })
+        .collect()
+}
+
+/**
+Finds the mutual appulses and occultations of the Moon and the planets
+
+# Returns
+
+* appulses: Appulses of every pair of bodies of
+            [ephemeris::Body](../ephemeris/enum.Body.html) other than
+            the Sun, with centers closer than `max_sep`, in increasing
+            order of time
+
+See [appulses()](./fn.appulses.html). Of the planets, apollo computes
+only the position of Mars, so for now these are the appulses and
+occultations of Mars by the Moon.
+
+# Arguments
+
+* jd_start: Julian (Ephemeris) day of the start of the search
+* jd_end  : Julian (Ephemeris) day of the end of the search
+* max_sep : Largest angular separation of the centers | in radians
+**/
+pub fn planetary_occultations(jd_start: f64, jd_end: f64, max_sep: f64) -> Vec<Appulse> {
+    let mut appulses_found = Vec::new();
+    for (i, body_1) in OCCULTING_BODIES.iter().enumerate() {
+        for body_2 in &OCCULTING_BODIES[i + 1..] {
+            appulses_found.extend(appulses(body_1, body_2, jd_start, jd_end, max_sep));
+        }
+    }
+
+    appulses_found.sort_by(|a, b| a.jd.total_cmp(&b.jd));
+    appulses_found
+}
+
+// Geocentric semidiameter of a body | in radians
+fn semidiameter(body: &ephemeris::Body, dist: f64) -> f64 {
+    match body {
+        ephemeris::Body::Sun => sun::semidiameter(dist),
+        ephemeris::Body::Moon => lunar::semidiameter(dist * au::AU_KM),
+        ephemeris::Body::Mars => angle::deg_dmas(0, 0, MARS_SEMIDIAMETER_1AU / dist).to_radians(),
+    }
+}
+
+// Geographic point where a body is at the zenith, on the meridian
+// whose local sidereal time equals the body's right ascension
+fn sub_point(body: &ephemeris::Body, jd: f64) -> coordinate::GeographPoint {
+    let (eq_point, _) = ephemeris::apparent_eq_pos(body, time::tt_from_ut(jd), Accuracy::High);
+    let long = time::apparent_sidereal_from_jd(jd) - eq_point.asc;
+
+    coordinate::GeographPoint::new(angle::limit_pi(long), eq_point.dec)
+}
+
+fn true_obliquity(jde: f64) -> f64 {
+    let (_, nut_in_oblq) = nutation::nutation(jde, Accuracy::Low);
+
+    ecliptic::mean_obliquity_laskar(jde) + nut_in_oblq
+}
@@ -1,2471 +1,2469 @@
-#[inline(always)]
-pub fn terms() -> Vec<Vec<Vec<[f64; 3]>>> {
-    vec![
-        vec![
-            vec![
-                [1.75347045673, 0.0, 0.0],
-                [0.03341656456, 4.66925680417, 6283.0758499914],
-                [0.00034894275, 4.62610241759, 12566.1516999828],
-                [3.417571e-05, 2.82886579606, 3.523118349],
-                [3.497056e-05, 2.74411800971, 5753.3848848968],
-                [3.135896e-05, 3.62767041758, 77713.7714681205],
-                [2.676218e-05, 4.41808351397, 7860.4193924392],
-                [2.342687e-05, 6.13516237631, 3930.2096962196],
-                [1.273166e-05, 2.03709655772, 529.6909650946],
-                [1.324292e-05, 0.74246356352, 11506.7697697936],
-                [9.01855e-06, 2.04505443513, 26.2983197998],
-                [1.199167e-05, 1.10962944315, 1577.3435424478],
-                [8.57223e-06, 3.50849156957, 398.1490034082],
-                [7.79786e-06, 1.17882652114, 5223.6939198022],
-                [9.9025e-06, 5.23268129594, 5884.9268465832],
-                [7.53141e-06, 2.53339053818, 5507.5532386674],
-                [5.05264e-06, 4.58292563052, 18849.2275499742],
-                [4.92379e-06, 4.20506639861, 775.522611324],
-                [3.56655e-06, 2.91954116867, 0.0673103028],
-                [2.84125e-06, 1.89869034186, 796.2980068164],
-                [2.4281e-06, 0.34481140906, 5486.777843175],
-                [3.17087e-06, 5.84901952218, 11790.6290886588],
-                [2.71039e-06, 0.31488607649, 10977.078804699],
-                [2.0616e-06, 4.80646606059, 2544.3144198834],
-                [2.05385e-06, 1.86947813692, 5573.1428014331],
-                [2.02261e-06, 2.45767795458, 6069.7767545534],
-                [1.26184e-06, 1.0830263021, 20.7753954924],
-                [1.55516e-06, 0.83306073807, 213.299095438],
-                [1.15132e-06, 0.64544911683, 0.9803210682],
-                [1.02851e-06, 0.63599846727, 4694.0029547076],
-                [1.01724e-06, 4.26679821365, 7.1135470008],
-                [9.9206e-07, 6.20992940258, 2146.1654164752],
-                [1.32212e-06, 3.41118275555, 2942.4634232916],
-                [9.7607e-07, 0.6810127227, 155.4203994342],
-                [8.5128e-07, 1.29870743025, 6275.9623029906],
-                [7.4651e-07, 1.75508916159, 5088.6288397668],
-                [1.01895e-06, 0.97569221824, 15720.8387848784],
-                [8.4711e-07, 3.67080093025, 71430.69561812909],
-                [7.3547e-07, 4.67926565481, 801.8209311238],
-                [7.3874e-07, 3.50319443167, 3154.6870848956],
-                [7.8756e-07, 3.03698313141, 12036.4607348882],
-                [7.9637e-07, 1.807913307, 17260.1546546904],
-                [8.5803e-07, 5.98322631256, 161000.6857376741],
-                [5.6963e-07, 2.78430398043, 6286.5989683404],
-                [6.1148e-07, 1.81839811024, 7084.8967811152],
-                [6.9627e-07, 0.83297596966, 9437.762934887],
-                [5.6116e-07, 4.38694880779, 14143.4952424306],
-                [6.2449e-07, 3.97763880587, 8827.3902698748],
-                [5.1145e-07, 0.28306864501, 5856.4776591154],
-                [5.5577e-07, 3.47006009062, 6279.5527316424],
-                [4.1036e-07, 5.36817351402, 8429.2412664666],
-                [5.1605e-07, 1.33282746983, 1748.016413067],
-                [5.1992e-07, 0.18914945834, 12139.5535091068],
-                [4.9e-07, 0.48735065033, 1194.4470102246],
-                [3.92e-07, 6.16832995016, 10447.3878396044],
-                [3.5566e-07, 1.77597314691, 6812.766815086],
-                [3.677e-07, 6.04133859347, 10213.285546211],
-                [3.6596e-07, 2.56955238628, 1059.3819301892],
-                [3.3291e-07, 0.59309499459, 17789.845619785],
-                [3.5954e-07, 1.70876111898, 2352.8661537718],
-                [4.0938e-07, 2.39850881707, 19651.048481098],
-                [3.0047e-07, 2.73975123935, 1349.8674096588],
-                [3.0412e-07, 0.44294464135, 83996.84731811189],
-                [2.3663e-07, 0.48473567763, 8031.0922630584],
-                [2.3574e-07, 2.06527720049, 3340.6124266998],
-                [2.1089e-07, 4.14825464101, 951.7184062506],
-                [2.4738e-07, 0.21484762138, 3.5904286518],
-                [2.5352e-07, 3.16470953405, 4690.4798363586],
-                [2.282e-07, 5.22197888032, 4705.7323075436],
-                [2.1419e-07, 1.42563735525, 16730.4636895958],
-                [2.1891e-07, 5.55594302562, 553.5694028424],
-                [1.7481e-07, 4.56052900359, 135.0650800354],
-                [1.9925e-07, 5.22208471269, 12168.0026965746],
-                [1.986e-07, 5.77470167653, 6309.3741697912],
-                [2.03e-07, 0.37133792946, 283.8593188652],
-                [1.4421e-07, 4.19315332546, 242.728603974],
-                [1.6225e-07, 5.98837722564, 11769.8536931664],
-                [1.5077e-07, 4.19567181073, 6256.7775301916],
-                [1.9124e-07, 3.82219996949, 23581.2581773176],
-                [1.8888e-07, 5.38626880969, 149854.4001348079],
-                [1.4346e-07, 3.72355084422, 38.0276726358],
-                [1.7898e-07, 2.21490735647, 13367.9726311066],
-                [1.2054e-07, 2.62229588349, 955.5997416086],
-                [1.1287e-07, 0.17739328092, 4164.311989613],
-                [1.3971e-07, 4.40138139996, 6681.2248533996],
-                [1.3621e-07, 1.88934471407, 7632.9432596502],
-                [1.2503e-07, 1.13052412208, 5.5229243074],
-                [1.0498e-07, 5.35909518669, 1592.5960136328],
-                [9.803e-08, 0.99947478995, 11371.7046897582],
-                [9.22e-08, 4.57138609781, 4292.3308329504],
-                [1.0327e-07, 6.19982566125, 6438.4962494256],
-                [1.2003e-07, 1.003514567, 632.7837393132],
-                [1.0827e-07, 0.32734520222, 103.0927742186],
-                [8.356e-08, 4.53902685948, 25132.3033999656],
-                [1.0005e-07, 6.0291496328, 5746.271337896],
-                [8.409e-08, 3.29946744189, 7234.794256242],
-                [8.006e-08, 5.82145271907, 28.4491874678],
-                [1.0523e-07, 0.93871805506, 11926.2544136688],
-                [7.686e-08, 3.12142363172, 7238.6755916],
-                [9.378e-08, 2.62414241032, 5760.4984318976],
-                [8.127e-08, 6.11228001785, 4732.0306273434],
-                [9.232e-08, 0.48343968736, 522.5774180938],
-                [9.802e-08, 5.24413991147, 27511.4678735372],
-                [7.871e-08, 0.99590177926, 5643.1785636774],
-                [8.123e-08, 6.2705301365, 426.598190876],
-                [9.048e-08, 5.33686335897, 6386.16862421],
-                [8.62e-08, 4.16538210888, 7058.5984613154],
-                [6.297e-08, 4.71724819317, 6836.6452528338],
-                [7.575e-08, 3.97382858911, 11499.6562227928],
-                [7.756e-08, 2.95729056763, 23013.5395395872],
-                [7.314e-08, 0.60652505806, 11513.8833167944],
-                [5.955e-08, 2.87641047971, 6283.14316029419],
-                [6.534e-08, 5.79072926033, 18073.7049386502],
-                [7.188e-08, 3.99831508699, 74.7815985673],
-                [7.346e-08, 4.38582365437, 316.3918696566],
-                [5.413e-08, 5.39199024641, 419.4846438752],
-                [5.127e-08, 2.36062848786, 10973.55568635],
-                [7.056e-08, 0.32258441903, 263.0839233728],
-                [6.625e-08, 3.66475158672, 17298.1823273262],
-                [6.762e-08, 5.91132535899, 90955.5516944961],
-                [4.938e-08, 5.73672165674, 9917.6968745098],
-                [5.547e-08, 2.45152597661, 12352.8526045448],
-                [5.958e-08, 3.32051344676, 6283.0085396886],
-                [4.471e-08, 2.06385999536, 7079.3738568078],
-                [6.153e-08, 1.45823331144, 233141.3144043615],
-                [4.348e-08, 4.4234217548, 5216.5803728014],
-                [6.123e-08, 1.07494905258, 19804.8272915828],
-                [4.488e-08, 3.6528503715, 206.1855484372],
-                [4.02e-08, 0.83995823171, 20.3553193988],
-                [5.188e-08, 4.06503864016, 6208.2942514241],
-                [5.307e-08, 0.38217636096, 31441.6775697568],
-                [3.785e-08, 2.34369213733, 3.881335358],
-                [4.497e-08, 3.27230796845, 11015.1064773348],
-                [4.132e-08, 0.92128915753, 3738.761430108],
-                [3.521e-08, 5.97844807108, 3894.1818295422],
-                [4.215e-08, 1.90601120623, 245.8316462294],
-                [3.701e-08, 5.03069397926, 536.8045120954],
-                [3.865e-08, 1.82634360607, 11856.2186514245],
-                [3.652e-08, 1.01838584934, 16200.7727245012],
-                [3.39e-08, 0.97785123922, 8635.9420037632],
-                [3.737e-08, 2.95380107829, 3128.3887650958],
-                [3.507e-08, 3.71291946325, 6290.1893969922],
-                [3.086e-08, 3.64646921512, 10.6366653498],
-                [3.397e-08, 1.10590684017, 14712.317116458],
-                [3.334e-08, 0.83684924911, 6496.3749454294],
-                [2.805e-08, 2.58504514144, 14314.1681130498],
-                [3.65e-08, 1.08344142571, 88860.05707098669],
-                [3.388e-08, 3.20185096055, 5120.6011455836],
-                [3.252e-08, 3.47859752062, 6133.5126528568],
-                [2.553e-08, 3.94869034189, 1990.745017041],
-                [3.52e-08, 2.05559692878, 244287.60000722768],
-                [2.565e-08, 1.560717849, 23543.23050468179],
-                [2.621e-08, 3.85639359951, 266.6070417218],
-                [2.955e-08, 3.39692949667, 9225.539273283],
-                [2.876e-08, 6.02635617464, 154717.6098876827],
-                [2.395e-08, 1.16131956403, 10984.1923516998],
-                [3.161e-08, 1.32798718453, 10873.9860304804],
-                [3.163e-08, 5.08946464629, 21228.3920235458],
-                [2.361e-08, 4.27212906992, 6040.3472460174],
-                [3.03e-08, 1.80209931347, 35371.8872659764],
-                [2.343e-08, 3.576898605, 10969.9652576982],
-                [2.618e-08, 2.57870156528, 22483.84857449259],
-                [2.113e-08, 3.71393780256, 65147.6197681377],
-                [2.019e-08, 0.81393923319, 170.6728706192],
-                [2.003e-08, 0.38091017375, 6172.869528772],
-                [2.506e-08, 3.74379142438, 10575.4066829418],
-                [2.381e-08, 0.10581361289, 7.046236698],
-                [1.949e-08, 4.86892513469, 36.0278666774],
-                [2.074e-08, 4.2279477457, 5650.2921106782],
-                [1.924e-08, 5.5946054986, 6282.0955289232],
-                [1.949e-08, 1.07002512703, 5230.807466803],
-                [1.988e-08, 5.19736046771, 6262.300454499],
-                [1.887e-08, 3.74365662683, 23.8784377478],
-                [1.787e-08, 1.25929682929, 12559.038152982],
-                [1.883e-08, 1.90364058477, 15.252471185],
-                [1.816e-08, 3.68083868442, 15110.4661198662],
-                [1.701e-08, 4.4110589538, 110.2063212194],
-                [1.99e-08, 3.93295788548, 6206.8097787158],
-                [2.103e-08, 0.75354917468, 13521.7514415914],
-                [1.774e-08, 0.48747535361, 1551.045222648],
-                [1.882e-08, 0.86684493432, 22003.9146348698],
-                [1.924e-08, 1.22898324132, 709.9330485583],
-                [2.009e-08, 4.6285092198, 6037.244203762],
-                [1.924e-08, 0.60231842508, 6284.0561710596],
-                [1.596e-08, 3.98332956992, 13916.0191096416],
-                [1.664e-08, 4.41939715469, 8662.240323563],
-                [1.971e-08, 1.04560500503, 18209.33026366019],
-                [1.942e-08, 4.31335979989, 6244.9428143536],
-                [1.476e-08, 0.93271367331, 2379.1644735716],
-                [1.81e-08, 0.49112137707, 1.4844727083],
-                [1.346e-08, 1.51574702235, 4136.9104335162],
-                [1.528e-08, 5.61835711404, 6127.6554505572],
-                [1.791e-08, 3.22187270126, 39302.096962196],
-                [1.747e-08, 3.05638656738, 18319.5365848796],
-                [1.431e-08, 4.51153808594, 20426.571092422],
-                [1.695e-08, 0.22047718414, 25158.6017197654],
-                [1.242e-08, 4.46665769933, 17256.6315363414],
-                [1.463e-08, 4.69242679213, 14945.3161735544],
-                [1.205e-08, 1.86912144659, 4590.910180489],
-                [1.192e-08, 2.74227166898, 12569.6748183318],
-                [1.222e-08, 5.18120087482, 5333.9002410216],
-                [1.39e-08, 5.42894648983, 143571.32428481648],
-                [1.473e-08, 1.70479245805, 11712.9553182308],
-                [1.362e-08, 2.61069503292, 6062.6632075526],
-                [1.148e-08, 6.0300180054, 3634.6210245184],
-                [1.198e-08, 5.15294130422, 10177.2576795336],
-                [1.266e-08, 0.11421493643, 18422.62935909819],
-                [1.411e-08, 1.09908857534, 3496.032826134],
-                [1.349e-08, 2.99805109633, 17654.7805397496],
-                [1.253e-08, 2.79850152848, 167283.7615876655],
-                [1.311e-08, 1.60942984879, 5481.2549188676],
-                [1.079e-08, 6.20304501787, 3.2863574178],
-                [1.181e-08, 1.20653776978, 131.5419616864],
-                [1.254e-08, 5.45103277798, 6076.8903015542],
-                [1.035e-08, 2.32142722747, 7342.4577801806],
-                [1.117e-08, 0.38838354256, 949.1756089698],
-                [9.66e-09, 3.18341890851, 11087.2851259184],
-                [1.171e-08, 3.39635049962, 12562.6285816338],
-                [1.121e-08, 0.72627490378, 220.4126424388],
-                [1.024e-08, 2.19378315386, 11403.676995575],
-                [8.88e-09, 3.91173199285, 4686.8894077068],
-                [9.1e-09, 1.98802695087, 735.8765135318],
-                [8.3e-09, 0.48984915507, 24072.9214697764],
-                [1.096e-08, 6.17377835617, 5436.9930152402],
-                [9.08e-09, 0.44959639433, 7477.522860216],
-                [9.74e-09, 1.52996238356, 9623.6882766912],
-                [8.4e-09, 1.79543266333, 5429.8794682394],
-                [7.78e-09, 6.17699177946, 38.1330356378],
-                [7.76e-09, 4.09855402433, 14.2270940016],
-                [1.068e-08, 4.64200173735, 43232.3066584156],
-                [9.54e-09, 1.49988435748, 1162.4747044078],
-                [9.07e-09, 0.86986870809, 10344.2950653858],
-                [9.31e-09, 4.06044689031, 28766.924424484],
-                [7.39e-09, 5.04368197372, 639.897286314],
-                [9.37e-09, 3.4688469896, 1589.0728952838],
-                [7.63e-09, 5.86304932998, 16858.4825329332],
-                [9.53e-09, 4.20801492835, 11190.377900137],
-                [7.08e-09, 1.7289998894, 13095.8426650774],
-                [9.69e-09, 1.64439522215, 29088.811415985],
-                [7.17e-09, 0.16688678895, 11.729352836],
-                [9.62e-09, 3.53092337542, 12416.5885028482],
-                [7.47e-09, 5.77866940346, 12592.4500197826],
-                [6.72e-09, 1.91095796194, 3.9321532631],
-                [6.71e-09, 5.46240843677, 18052.9295431578],
-                [6.75e-09, 6.28311558823, 4535.0594369244],
-                [6.84e-09, 0.3997501208, 5849.3641121146],
-                [7.99e-09, 0.29851185294, 12132.439962106],
-                [7.58e-09, 0.96370823331, 1052.2683831884],
-                [7.82e-09, 5.33878339919, 13517.8701062334],
-                [7.3e-09, 1.70106160291, 17267.26820169119],
-                [7.49e-09, 2.59599901875, 11609.8625440122],
-                [7.34e-09, 2.78417782952, 640.8776073822],
-                [6.88e-09, 5.15048287468, 16496.3613962024],
-                [7.7e-09, 1.62469589333, 4701.1165017084],
-                [6.33e-09, 2.20587893893, 25934.1243310894],
-                [7.6e-09, 4.21317219403, 377.3736079158],
-                [5.84e-09, 2.13420121623, 10557.5941608238],
-                [5.74e-09, 0.24250054587, 9779.1086761254],
-                [5.73e-09, 3.16435264609, 533.2140834436],
-                [6.85e-09, 3.19344289472, 12146.6670561076],
-                [6.75e-09, 0.96179233959, 10454.5013866052],
-                [6.48e-09, 1.46327342555, 6268.8487559898],
-                [5.89e-09, 2.50543543638, 3097.88382272579],
-                [5.51e-09, 5.28099026956, 9388.0059094152],
-                [6.96e-09, 3.65342150016, 4804.209275927],
-                [6.69e-09, 2.51030077026, 2388.8940204492],
-                [5.5e-09, 0.06883864342, 20199.094959633],
-                [6.29e-09, 4.13350995675, 45892.73043315699],
-                [6.78e-09, 6.09190163533, 135.62532501],
-                [5.93e-09, 1.50136257618, 226858.23855437007],
-                [5.42e-09, 3.58573645173, 6148.010769956],
-                [6.82e-09, 5.02203067788, 17253.04110768959],
-                [5.65e-09, 4.2930923861, 11933.3679606696],
-                [4.86e-09, 0.77746204893, 27.4015560968],
-                [5.03e-09, 0.58963565969, 15671.0817594066],
-                [6.16e-09, 4.06539884128, 227.476132789],
-                [5.83e-09, 6.12695541996, 18875.525869774],
-                [5.37e-09, 2.1505644098, 21954.15760939799],
-                [6.69e-09, 6.06986269566, 47162.5163546352],
-                [4.75e-09, 0.4034384211, 6915.8595893046],
-                [5.4e-09, 2.83444222174, 5326.7866940208],
-                [5.3e-09, 5.26359885263, 10988.808157535],
-                [5.82e-09, 3.24533095664, 153.7788104848],
-                [6.41e-09, 3.24711791371, 2107.0345075424],
-                [6.21e-09, 3.09698523779, 33019.0211122046],
-                [4.66e-09, 3.14982372198, 10440.2742926036],
-                [4.66e-09, 0.90708835657, 5966.6839803348],
-                [5.28e-09, 0.8192645447, 813.5502839598],
-                [6.03e-09, 3.81378921927, 316428.22867391503],
-                [5.59e-09, 1.81894804124, 17996.0311682222],
-                [4.37e-09, 2.28625594435, 6303.8512454838],
-                [5.18e-09, 4.86069178322, 20597.2439630412],
-                [4.24e-09, 6.23520018693, 6489.2613984286],
-                [5.18e-09, 6.17617826756, 0.2438174835],
-                [4.04e-09, 5.72804304258, 5642.1982426092],
-                [4.58e-09, 1.34117773915, 6287.0080032545],
-                [5.48e-09, 5.6845445832, 155427.542936241],
-                [5.47e-09, 1.03391472061, 3646.3503773544],
-                [4.28e-09, 4.69800981138, 846.0828347512],
-                [4.13e-09, 6.02520699406, 6279.4854213396],
-                [5.34e-09, 3.03030638223, 66567.48586525429],
-                [3.83e-09, 1.49056949125, 19800.9459562248],
-                [4.1e-09, 5.28319622279, 18451.07854656599],
-                [3.52e-09, 4.68891600359, 4907.3020501456],
-                [4.8e-09, 5.36572651091, 348.924420448],
-                [3.44e-09, 5.89157452896, 6546.1597733642],
-                [3.4e-09, 0.3755742644, 13119.72110282519],
-                [4.34e-09, 4.98417785901, 6702.5604938666],
-                [3.32e-09, 2.68902519126, 29296.6153895786],
-                [4.48e-09, 2.16478480251, 5905.7022420756],
-                [3.44e-09, 2.06546633735, 49.7570254718],
-                [3.15e-09, 1.24023811803, 4061.2192153944],
-                [3.24e-09, 2.30897526929, 5017.508371365],
-                [4.13e-09, 0.17171692962, 6286.6662786432],
-                [4.31e-09, 3.86601101393, 12489.8856287072],
-                [3.49e-09, 4.55372342974, 4933.2084403326],
-                [3.23e-09, 0.41971136084, 10770.8932562618],
-                [3.41e-09, 2.68612860807, 11.0457002639],
-                [3.16e-09, 3.52936906658, 17782.7320727842],
-                [3.15e-09, 5.63357264999, 568.8218740274],
-                [3.4e-09, 3.83571212349, 10660.6869350424],
-                [2.97e-09, 0.62691416712, 20995.3929664494],
-                [4.05e-09, 1.00085779471, 16460.33352952499],
-                [4.14e-09, 1.21998752076, 51092.7260508548],
-                [3.36e-09, 4.71465945226, 6179.9830757728],
-                [3.61e-09, 3.71227508354, 28237.2334593894],
-                [3.85e-09, 6.21925225757, 24356.7807886416],
-                [3.27e-09, 1.05606504715, 11919.140866668],
-                [3.27e-09, 6.14222420989, 6254.6266625236],
-                [2.68e-09, 2.47224339737, 664.75604513],
-                [2.69e-09, 1.86207884109, 23141.5583829246],
-                [3.45e-09, 0.93461290184, 6058.7310542895],
-                [2.96e-09, 4.5168755718, 6418.1409300268],
-                [3.53e-09, 4.50033653082, 36949.2308084242],
-                [2.6e-09, 4.04963546305, 6525.8044539654],
-                [2.98e-09, 2.20046722622, 156137.47598479927],
-                [2.53e-09, 3.49900838384, 29864.334027309],
-                [2.54e-09, 2.44901693835, 5331.3574437408],
-                [2.96e-09, 0.84347588787, 5729.506447149],
-                [2.98e-09, 1.29194706125, 22805.7355659936],
-                [2.41e-09, 2.00721280805, 16737.5772365966],
-                [3.11e-09, 1.23668016334, 6281.5913772831],
-                [2.4e-09, 2.51650377121, 6245.0481773556],
-                [3.32e-09, 3.55576945724, 7668.6374249425],
-                [2.64e-09, 4.44052061202, 12964.300703391],
-                [2.57e-09, 1.79654471948, 11080.1715789176],
-                [2.6e-09, 3.3307759842, 5888.4499649322],
-                [2.85e-09, 0.3088636143, 11823.1616394502],
-                [2.9e-09, 5.70141882483, 77.673770428],
-                [2.55e-09, 4.0093966444, 5881.4037282342],
-                [2.53e-09, 4.73318493678, 16723.350142595],
-                [2.28e-09, 0.95333661324, 5540.0857894588],
-                [3.19e-09, 1.38633229189, 163096.18036118348],
-                [2.24e-09, 1.65156322696, 10027.9031957292],
-                [2.26e-09, 0.34106460604, 17796.9591667858],
-                [2.36e-09, 4.19817431922, 19.66976089979],
-                [2.8e-09, 4.1408026897, 12539.853380183],
-                [2.75e-09, 5.50306930248, 32.5325507914],
-                [2.23e-09, 5.23334210294, 56.8983749356],
-                [2.17e-09, 6.08587881787, 6805.6532680852],
-                [2.8e-09, 4.52472044653, 6016.4688082696],
-                [2.27e-09, 5.06509843737, 6277.552925684],
-                [2.26e-09, 5.17755154305, 11720.0688652316],
-                [2.45e-09, 3.96486270306, 22.7752014508],
-                [2.2e-09, 4.7207808197, 6.62855890001],
-                [2.07e-09, 5.71701403951, 41.5507909848],
-                [2.04e-09, 3.9122741125, 2699.7348193176],
-                [2.09e-09, 0.86881969011, 6321.1035226272],
-                [2e-09, 2.11984445273, 4274.5183108324],
-                [2e-09, 5.39839888163, 6019.9919266186],
-                [2.09e-09, 5.67606291663, 11293.4706743556],
-                [2.52e-09, 1.64965729351, 9380.9596727172],
-                [2.75e-09, 5.04826903506, 73.297125859],
-                [2.08e-09, 1.88207277133, 11300.5842213564],
-                [2.72e-09, 0.74640926842, 1975.492545856],
-                [1.99e-09, 3.30836672397, 22743.4093795164],
-                [2.69e-09, 4.48560812155, 64471.99124174489],
-                [1.92e-09, 2.17464236325, 5863.5912061162],
-                [2.28e-09, 5.85373115869, 128.0188433374],
-                [2.61e-09, 2.64321183295, 55022.9357470744],
-                [2.2e-09, 5.75012110079, 29.429508536],
-                [1.87e-09, 4.03230554718, 467.9649903544],
-                [2e-09, 5.60556112058, 1066.49547719],
-                [2.31e-09, 1.09802712785, 12341.8069042809],
-                [1.99e-09, 0.295006252, 149.5631971346],
-                [2.49e-09, 5.10473210814, 7875.6718636242],
-                [2.08e-09, 0.93013835019, 14919.0178537546],
-                [1.79e-09, 0.87104393079, 12721.572099417],
-                [2.03e-09, 1.56920753653, 28286.9904848612],
-                [1.79e-09, 2.47036386443, 16062.1845261168],
-                [1.98e-09, 3.54061588502, 30.914125635],
-                [1.71e-09, 3.45356518113, 5327.4761083828],
-                [1.83e-09, 0.72325421604, 6272.0301497275],
-                [2.16e-09, 2.97174580686, 19402.7969528166],
-                [1.68e-09, 2.51550550242, 23937.856389741],
-                [1.95e-09, 0.09045393425, 156.4007205024],
-                [1.79e-09, 4.4947179809, 31415.379249957],
-                [2.16e-09, 0.42177594328, 23539.7073863328],
-                [1.89e-09, 0.37542530191, 9814.6041002912],
-                [2.18e-09, 2.36835880025, 16627.3709153772],
-                [1.66e-09, 4.23182968446, 16840.67001081519],
-                [2e-09, 2.02153258098, 16097.6799502826],
-                [1.69e-09, 0.91318727, 95.9792272178],
-                [2.11e-09, 5.73370637657, 151.8972810852],
-                [2.04e-09, 0.42643085174, 515.463871093],
-                [2.12e-09, 3.00233538977, 12043.574281889],
-                [1.92e-09, 5.46153589821, 6379.0550772092],
-                [1.65e-09, 1.38698167064, 4171.4255366138],
-                [1.6e-09, 6.23798383332, 202.2533951741],
-                [2.15e-09, 0.20889073407, 5621.8429232104],
-                [1.81e-09, 4.12439203622, 13341.6743113068],
-                [1.53e-09, 1.24460848836, 29826.3063546732],
-                [1.5e-09, 3.12999753018, 799.8211251654],
-                [1.75e-09, 4.55671604437, 239424.39025435288],
-                [1.92e-09, 1.33928820063, 394.6258850592],
-                [1.49e-09, 2.65697593276, 21.335640467],
-                [1.46e-09, 5.58021191726, 412.3710968744],
-                [1.56e-09, 3.75650175503, 12323.4230960088],
-                [1.43e-09, 3.75708566606, 58864.5439181463],
-                [1.43e-09, 3.28248547724, 29.8214381488],
-                [1.44e-09, 1.07862546598, 1265.5674786264],
-                [1.48e-09, 0.23389236655, 10021.8372800994],
-                [1.93e-09, 5.92751083086, 40879.4405046438],
-                [1.4e-09, 4.97612440269, 158.9435177832],
-                [1.48e-09, 2.61640453469, 17157.0618804718],
-                [1.41e-09, 3.66871308723, 26084.0218062162],
-                [1.47e-09, 5.09968173403, 661.232926781],
-                [1.46e-09, 4.96885605695, 57375.8019008462],
-                [1.42e-09, 0.78678347839, 12779.4507954208],
-                [1.34e-09, 4.79432636012, 111.1866422876],
-                [1.4e-09, 1.27748013377, 107.6635239386],
-                [1.69e-09, 2.74893543762, 26735.9452622132],
-                [1.65e-09, 3.95288000638, 6357.8574485587],
-                [1.83e-09, 5.43418358741, 369.6998159404],
-                [1.34e-09, 3.09132862833, 17.812522118],
-                [1.32e-09, 3.05633896779, 22490.9621214934],
-                [1.34e-09, 4.09472795832, 6599.467719648],
-                [1.81e-09, 4.22950689891, 966.9708774356],
-                [1.52e-09, 5.28885894415, 12669.2444742014],
-                [1.5e-09, 5.86819430908, 97238.62754448749],
-                [1.42e-09, 5.87266532526, 22476.73502749179],
-                [1.45e-09, 5.07330784304, 87.30820453981],
-                [1.33e-09, 5.65471067133, 31.9723058168],
-                [1.24e-09, 2.83326217072, 12566.2190102856],
-                [1.35e-09, 3.12861731644, 32217.2001810808],
-                [1.37e-09, 0.86487461904, 9924.8104215106],
-                [1.72e-09, 1.98369595114, 174242.4659640497],
-                [1.7e-09, 4.41115280254, 327574.51427678124],
-                [1.51e-09, 0.46542099527, 39609.6545831656],
-                [1.48e-09, 2.13439571118, 491.6632924588],
-                [1.53e-09, 3.78801830344, 17363.24742890899],
-                [1.65e-09, 5.31654110459, 16943.7627850338],
-                [1.65e-09, 4.06747587817, 58953.145443294],
-                [1.18e-09, 0.63846333239, 6.0659156298],
-                [1.59e-09, 0.86086959274, 221995.02880149524],
-                [1.19e-09, 5.96432932413, 1385.8952763362],
-                [1.14e-09, 5.16516114595, 25685.872802808],
-                [1.12e-09, 3.39403722178, 21393.5419698576],
-                [1.12e-09, 4.92889233335, 56.8032621698],
-                [1.19e-09, 2.40637635942, 18635.9284545362],
-                [1.15e-09, 0.23374479051, 418.9243989006],
-                [1.22e-09, 0.93575234049, 24492.40611365159],
-                [1.15e-09, 4.58880032176, 26709.6469424134],
-                [1.3e-09, 4.85539251, 22345.2603761082],
-                [1.4e-09, 1.09413073202, 44809.6502008634],
-                [1.12e-09, 6.05401806281, 433.7117378768],
-                [1.04e-09, 1.54931540602, 127.9515330346],
-                [1.05e-09, 4.82620858888, 33794.5437235286],
-                [1.02e-09, 4.12448497391, 15664.03552270859],
-                [1.07e-09, 4.67919356465, 77690.75950573849],
-                [1.18e-09, 4.5232017012, 19004.6479494084],
-                [1.07e-09, 5.71774478555, 77736.78343050249],
-                [1.43e-09, 1.81201813018, 4214.0690150848],
-                [1.25e-09, 1.14419195615, 625.6701923124],
-                [1.24e-09, 3.27736514057, 12566.08438968],
-                [1.1e-09, 1.08682570828, 2787.0430238574],
-                [1.05e-09, 1.78318141871, 18139.2945014159],
-                [1.02e-09, 4.75119578149, 12242.6462833254],
-                [1.37e-09, 1.43510636754, 86464.6133168312],
-                [1.01e-09, 4.91289409429, 401.6721217572],
-                [1.29e-09, 1.23567904485, 12029.3471878874],
-                [1.38e-09, 2.45654707999, 7576.560073574],
-                [1.03e-09, 0.40004073416, 90279.92316810328],
-                [1.08e-09, 0.9898977494, 5636.0650166766],
-                [1.17e-09, 5.17362872063, 34520.3093093808],
-                [1e-09, 3.95534628189, 5547.1993364596],
-                [9.8e-10, 1.28118280598, 21548.9623692918],
-                [9.7e-10, 3.34717130592, 16310.9790457206],
-                [9.8e-10, 4.37041908717, 34513.2630726828],
-                [1.25e-09, 2.7216443296, 24065.80792277559],
-                [1.02e-09, 0.66938025772, 10239.5838660108],
-                [1.19e-09, 1.21689479331, 1478.8665740644],
-                [9.4e-10, 1.99595224256, 13362.4497067992],
-                [9.4e-10, 4.30965982872, 26880.3198130326],
-                [9.5e-10, 2.89807657534, 34911.412076091],
-                [1.06e-09, 1.0015665359, 16522.6597160022],
-                [9.7e-10, 0.89642320201, 71980.63357473118],
-                [1.16e-09, 4.19967201116, 206.7007372966],
-                [9.9e-10, 1.37437847718, 1039.0266107904],
-                [1.26e-09, 3.21642544972, 305281.9430710488],
-                [9.4e-10, 0.6899787606, 7834.1210726394],
-                [9.4e-10, 5.58132218606, 3104.9300594238],
-                [9.5e-10, 3.0382374111, 8982.810669309],
-                [1.08e-09, 0.52696637156, 276.7457718644],
-                [1.24e-09, 3.43899862683, 172146.9713405403],
-                [1.02e-09, 1.04031728553, 95143.1329209781],
-                [1.04e-09, 3.39218586218, 290.972865866],
-                [1.1e-09, 3.68205877433, 22380.755800274],
-                [1.17e-09, 0.78475956902, 83286.91426955358],
-                [8.3e-10, 0.18241793425, 15141.390794312],
-                [8.9e-10, 4.45371820659, 792.7748884674],
-                [8.2e-10, 4.80703651241, 6819.8803620868],
-                [8.7e-10, 3.43122851097, 27707.5424942948],
-                [1.01e-09, 5.32081603011, 2301.58581590939],
-                [8.2e-10, 0.87060089842, 10241.2022911672],
-                [8.6e-10, 4.61919461931, 36147.4098773004],
-                [9.5e-10, 2.87032884659, 23020.65308658799],
-                [8.8e-10, 3.2113316569, 33326.5787331742],
-                [8e-10, 1.84900424847, 21424.4666443034],
-                [1.01e-09, 4.18796434479, 30666.1549584328],
-                [1.07e-09, 5.77864921649, 34115.1140692746],
-                [1.04e-09, 1.08739495962, 6288.5987742988],
-                [1.1e-09, 3.32898859416, 72140.6286666874],
-                [8.7e-10, 4.40657711727, 142.1786270362],
-                [1.09e-09, 1.94546030825, 24279.10701821359],
-                [8.7e-10, 4.32472045435, 742.9900605326],
-                [1.07e-09, 4.91580912547, 277.0349937414],
-                [8.8e-10, 2.10180220766, 26482.1708096244],
-                [8.6e-10, 4.01887374432, 12491.3701014155],
-                [1.06e-09, 5.49092372854, 62883.3551395136],
-                [8e-10, 6.19781316983, 6709.6740408674],
-                [8.8e-10, 2.09872810657, 238004.5241572363],
-                [8.3e-10, 4.90662164029, 51.28033786241],
-                [9.5e-10, 4.13387406591, 18216.443810661],
-                [7.8e-10, 6.0694939168, 148434.53403769128],
-                [7.9e-10, 3.03048221644, 838.9692877504],
-                [7.4e-10, 5.49813051211, 29026.48522950779],
-                [7.3e-10, 3.05008665738, 567.7186377304],
-                [8.4e-10, 0.46604373274, 45.1412196366],
-                [9.3e-10, 2.52267536308, 48739.859897083],
-                [7.6e-10, 1.76418124905, 41654.9631159678],
-                [6.7e-10, 5.77851227793, 6311.5250374592],
-                [6.2e-10, 3.32967880172, 15508.6151232744],
-                [7.9e-10, 5.59773841328, 71960.38658322369],
-                [5.7e-10, 3.90629505268, 5999.2165311262],
-                [6.1e-10, 0.05695043232, 7856.89627409019],
-                [6.1e-10, 5.63297958433, 7863.9425107882],
-                [6.5e-10, 3.72178394016, 12573.2652469836],
-                [5.7e-10, 4.18217219541, 26087.9031415742],
-                [6.6e-10, 3.92262333487, 69853.3520756813],
-                [5.3e-10, 5.51119362045, 77710.24834977149],
-                [5.3e-10, 4.88573986961, 77717.29458646949],
-                [6.2e-10, 2.88876342225, 9411.4646150872],
-                [5.1e-10, 1.12657183874, 82576.9812209953],
-                [4.5e-10, 2.95671076719, 24602.61243487099],
-                [4e-10, 5.55145719241, 12565.1713789146],
-                [3.9e-10, 1.20838190039, 18842.11400297339],
-                [4.5e-10, 3.18590558749, 45585.1728121874],
-                [4.9e-10, 2.44790934886, 13613.804277336],
-            ],
-            vec![
-                [6283.31966747491, 0.0, 0.0],
-                [0.00206058863, 2.67823455584, 6283.0758499914],
-                [4.30343e-05, 2.63512650414, 12566.1516999828],
-                [4.25264e-06, 1.59046980729, 3.523118349],
-                [1.08977e-06, 2.96618001993, 1577.3435424478],
-                [9.3478e-07, 2.59212835365, 18849.2275499742],
-                [1.19261e-06, 5.79557487799, 26.2983197998],
-                [7.2122e-07, 1.13846158196, 529.6909650946],
-                [6.7768e-07, 1.87472304791, 398.1490034082],
-                [6.7327e-07, 4.40918235168, 5507.5532386674],
-                [5.9027e-07, 2.8879703846, 5223.6939198022],
-                [5.5976e-07, 2.17471680261, 155.4203994342],
-                [4.5407e-07, 0.39803079805, 796.2980068164],
-                [3.6369e-07, 0.46624739835, 775.522611324],
-                [2.8958e-07, 2.64707383882, 7.1135470008],
-                [1.9097e-07, 1.84628332577, 5486.777843175],
-                [2.0844e-07, 5.34138275149, 0.9803210682],
-                [1.8508e-07, 4.96855124577, 213.299095438],
-                [1.6233e-07, 0.03216483047, 2544.3144198834],
-                [1.7293e-07, 2.99116864949, 6275.9623029906],
-                [1.5832e-07, 1.43049285325, 2146.1654164752],
-                [1.4615e-07, 1.20532366323, 10977.078804699],
-                [1.1877e-07, 3.25804815607, 5088.6288397668],
-                [1.1514e-07, 2.07502418155, 4694.0029547076],
-                [9.721e-08, 4.23925472239, 1349.8674096588],
-                [9.969e-08, 1.30262991097, 6286.5989683404],
-                [9.452e-08, 2.69957062864, 242.728603974],
-                [1.2461e-07, 2.83432285512, 1748.016413067],
-                [1.1808e-07, 5.2737979048, 1194.4470102246],
-                [8.577e-08, 5.64475868067, 951.7184062506],
-                [1.0641e-07, 0.76614199202, 553.5694028424],
-                [7.576e-08, 5.30062664886, 2352.8661537718],
-                [5.834e-08, 1.76649917904, 1059.3819301892],
-                [6.385e-08, 2.65033984967, 9437.762934887],
-                [5.223e-08, 5.66135767624, 71430.69561812909],
-                [5.305e-08, 0.90857521574, 3154.6870848956],
-                [6.101e-08, 4.66632584188, 4690.4798363586],
-                [4.33e-08, 0.24102555403, 6812.766815086],
-                [5.041e-08, 1.42490103709, 6438.4962494256],
-                [4.259e-08, 0.77355900599, 10447.3878396044],
-                [5.198e-08, 1.85353197345, 801.8209311238],
-                [3.744e-08, 2.00119516488, 8031.0922630584],
-                [3.558e-08, 2.42901552681, 14143.4952424306],
-                [3.372e-08, 3.86210700128, 1592.5960136328],
-                [3.374e-08, 0.88776219727, 12036.4607348882],
-                [3.175e-08, 3.18785710594, 4705.7323075436],
-                [3.221e-08, 0.61599835472, 8429.2412664666],
-                [4.132e-08, 5.23992859705, 7084.8967811152],
-                [2.97e-08, 6.07026318493, 4292.3308329504],
-                [2.9e-08, 2.32464208411, 20.3553193988],
-                [3.504e-08, 4.79975694359, 6279.5527316424],
-                [2.95e-08, 1.43108874817, 5746.271337896],
-                [2.697e-08, 4.80368225199, 7234.794256242],
-                [2.531e-08, 6.22290682655, 6836.6452528338],
-                [2.745e-08, 0.93466065396, 5760.4984318976],
-                [3.25e-08, 3.39954640038, 7632.9432596502],
-                [2.277e-08, 5.00277837672, 17789.845619785],
-                [2.075e-08, 3.95534978634, 10213.285546211],
-                [2.061e-08, 2.22411683077, 5856.4776591154],
-                [2.252e-08, 5.67166499885, 11499.6562227928],
-                [2.148e-08, 5.20184578235, 11513.8833167944],
-                [1.886e-08, 0.53198320577, 3340.6124266998],
-                [1.875e-08, 4.73511970207, 83996.84731811189],
-                [2.06e-08, 2.54987293999, 25132.3033999656],
-                [1.794e-08, 1.47435409831, 4164.311989613],
-                [1.778e-08, 3.02473091781, 5.5229243074],
-                [2.029e-08, 0.90960209983, 6256.7775301916],
-                [2.075e-08, 2.26767270157, 522.5774180938],
-                [1.772e-08, 3.02622802353, 5753.3848848968],
-                [1.569e-08, 6.12410242782, 5216.5803728014],
-                [1.59e-08, 4.63713748247, 3.2863574178],
-                [1.542e-08, 4.20004448567, 13367.9726311066],
-                [1.427e-08, 1.19088061711, 3894.1818295422],
-                [1.375e-08, 3.09301252193, 135.0650800354],
-                [1.359e-08, 4.24532506641, 426.598190876],
-                [1.34e-08, 5.76511818622, 6040.3472460174],
-                [1.284e-08, 3.08524663344, 5643.1785636774],
-                [1.25e-08, 3.07748157144, 11926.2544136688],
-                [1.551e-08, 3.07665451458, 6681.2248533996],
-                [1.268e-08, 2.09196018331, 6290.1893969922],
-                [1.144e-08, 3.24444699514, 12168.0026965746],
-                [1.248e-08, 3.44504937285, 536.8045120954],
-                [1.118e-08, 2.31829670425, 16730.4636895958],
-                [1.105e-08, 5.31966001019, 23.8784377478],
-                [1.051e-08, 3.75015946014, 7860.4193924392],
-                [1.025e-08, 2.44688534235, 1990.745017041],
-                [9.62e-09, 0.81771017882, 3.881335358],
-                [9.1e-09, 0.41727865299, 7079.3738568078],
-                [8.83e-09, 5.16833917651, 11790.6290886588],
-                [9.57e-09, 4.07673573735, 6127.6554505572],
-                [1.11e-08, 3.90096793825, 11506.7697697936],
-                [8.02e-09, 3.88778875582, 10973.55568635],
-                [7.8e-09, 2.39934293755, 1589.0728952838],
-                [7.58e-09, 1.30034364248, 103.0927742186],
-                [7.49e-09, 4.962758033, 6496.3749454294],
-                [7.65e-09, 3.36312388424, 36.0278666774],
-                [9.15e-09, 5.41543742089, 206.1855484372],
-                [7.76e-09, 2.57589093871, 11371.7046897582],
-                [7.72e-09, 3.98369209464, 955.5997416086],
-                [7.49e-09, 5.17890001805, 10969.9652576982],
-                [8.06e-09, 0.34218864254, 9917.6968745098],
-                [7.28e-09, 5.20962563787, 38.0276726358],
-                [6.85e-09, 2.77592961854, 20.7753954924],
-                [6.36e-09, 4.28242193632, 28.4491874678],
-                [6.08e-09, 5.63278508906, 10984.1923516998],
-                [7.04e-09, 5.60738823665, 3738.761430108],
-                [6.85e-09, 0.38876148682, 15.252471185],
-                [6.01e-09, 0.73489602442, 419.4846438752],
-                [7.16e-09, 2.65279791438, 6309.3741697912],
-                [5.84e-09, 5.54502568227, 17298.1823273262],
-                [6.5e-09, 1.13379656406, 7058.5984613154],
-                [6.88e-09, 2.59683891779, 3496.032826134],
-                [4.85e-09, 0.44467180946, 12352.8526045448],
-                [5.28e-09, 2.74936967681, 3930.2096962196],
-                [5.97e-09, 5.27668281777, 10575.4066829418],
-                [5.83e-09, 3.1892906781, 4732.0306273434],
-                [5.26e-09, 5.01697321546, 5884.9268465832],
-                [5.4e-09, 1.29175137075, 640.8776073822],
-                [4.73e-09, 5.4995330697, 5230.807466803],
-                [4.06e-09, 5.21248452189, 220.4126424388],
-                [3.95e-09, 1.87474483222, 16200.7727245012],
-                [3.7e-09, 3.84921354713, 18073.7049386502],
-                [3.67e-09, 0.88533542778, 6283.14316029419],
-                [3.79e-09, 0.37983009325, 10177.2576795336],
-                [3.56e-09, 3.84145204913, 11712.9553182308],
-                [3.74e-09, 5.01577520608, 7.046236698],
-                [3.81e-09, 4.30250406634, 6062.6632075526],
-                [4.71e-09, 0.86381834647, 6069.7767545534],
-                [3.67e-09, 1.32943839763, 6283.0085396886],
-                [4.6e-09, 5.19667219575, 6284.0561710596],
-                [3.33e-09, 5.54256205741, 4686.8894077068],
-                [3.41e-09, 4.36522989934, 7238.6755916],
-                [3.36e-09, 4.00205876835, 3097.88382272579],
-                [3.59e-09, 6.22679790284, 245.8316462294],
-                [3.07e-09, 2.35299010924, 170.6728706192],
-                [3.43e-09, 3.77164927143, 6076.8903015542],
-                [2.96e-09, 5.44152227481, 17260.1546546904],
-                [3.28e-09, 0.13837875384, 11015.1064773348],
-                [2.68e-09, 1.1390455063, 12569.6748183318],
-                [2.63e-09, 0.00538633678, 4136.9104335162],
-                [2.82e-09, 5.0439983748, 7477.522860216],
-                [2.88e-09, 3.13401177517, 12559.038152982],
-                [2.59e-09, 0.93882269387, 5642.1982426092],
-                [2.92e-09, 1.98420020514, 12132.439962106],
-                [2.47e-09, 3.84244798532, 5429.8794682394],
-                [2.45e-09, 5.70467521726, 65147.6197681377],
-                [2.41e-09, 0.99480969552, 3634.6210245184],
-                [2.46e-09, 3.06168069935, 110.2063212194],
-                [2.39e-09, 6.11855909114, 11856.2186514245],
-                [2.63e-09, 0.66348415419, 21228.3920235458],
-                [2.62e-09, 1.51070507866, 12146.6670561076],
-                [2.3e-09, 1.75927314884, 9779.1086761254],
-                [2.23e-09, 2.00967043606, 6172.869528772],
-                [2.46e-09, 1.10411690865, 6282.0955289232],
-                [2.21e-09, 3.03945240854, 8635.9420037632],
-                [2.14e-09, 4.03840869663, 14314.1681130498],
-                [2.36e-09, 5.4691507058, 13916.0191096416],
-                [2.24e-09, 4.68408089456, 24072.9214697764],
-                [2.12e-09, 2.13695625494, 5849.3641121146],
-                [2.07e-09, 3.07724246401, 11.729352836],
-                [2.07e-09, 6.10306282747, 23543.23050468179],
-                [2.66e-09, 1.00709566823, 2388.8940204492],
-                [2.17e-09, 6.27837036335, 17267.26820169119],
-                [2.04e-09, 2.34615348695, 266.6070417218],
-                [1.95e-09, 5.55015549753, 6133.5126528568],
-                [1.88e-09, 2.52667166175, 6525.8044539654],
-                [1.85e-09, 0.90960768344, 18319.5365848796],
-                [1.77e-09, 1.73429218289, 154717.6098876827],
-                [1.87e-09, 4.76483647432, 4535.0594369244],
-                [1.86e-09, 4.63080493407, 10440.2742926036],
-                [2.15e-09, 2.8125545456, 7342.4577801806],
-                [1.72e-09, 1.45551888559, 9225.539273283],
-                [1.62e-09, 3.30661909388, 639.897286314],
-                [1.68e-09, 2.17671416605, 27.4015560968],
-                [1.6e-09, 1.68164180475, 15110.4661198662],
-                [1.58e-09, 0.13519771874, 13095.8426650774],
-                [1.83e-09, 0.56281322071, 13517.8701062334],
-                [1.79e-09, 3.58450811616, 87.30820453981],
-                [1.52e-09, 2.84070476818, 5650.2921106782],
-                [1.82e-09, 0.44065530624, 17253.04110768959],
-                [1.6e-09, 5.95767264171, 4701.1165017084],
-                [1.42e-09, 1.4629013752, 11087.2851259184],
-                [1.42e-09, 2.04464036087, 20426.571092422],
-                [1.31e-09, 5.40912137746, 2699.7348193176],
-                [1.44e-09, 2.07312090485, 25158.6017197654],
-                [1.47e-09, 6.15106982168, 9623.6882766912],
-                [1.41e-09, 5.55739979498, 10454.5013866052],
-                [1.35e-09, 0.06098110407, 16723.350142595],
-                [1.24e-09, 5.81218025669, 17256.6315363414],
-                [1.24e-09, 2.36293551623, 4933.2084403326],
-                [1.26e-09, 3.47435905118, 22483.84857449259],
-                [1.59e-09, 5.63954754618, 5729.506447149],
-                [1.23e-09, 3.92815963256, 17996.0311682222],
-                [1.48e-09, 3.02509280598, 1551.045222648],
-                [1.2e-09, 5.91904349732, 6206.8097787158],
-                [1.34e-09, 3.11122937825, 21954.15760939799],
-                [1.19e-09, 5.5214112345, 709.9330485583],
-                [1.22e-09, 3.00813429479, 19800.9459562248],
-                [1.27e-09, 1.37618620001, 14945.3161735544],
-                [1.41e-09, 2.56889468729, 1052.2683831884],
-                [1.23e-09, 2.83671175442, 11919.140866668],
-                [1.18e-09, 0.81934438215, 5331.3574437408],
-                [1.51e-09, 2.68731829165, 11769.8536931664],
-                [1.19e-09, 5.08835797638, 5481.2549188676],
-                [1.53e-09, 2.46021790779, 11933.3679606696],
-                [1.08e-09, 1.04936452145, 11403.676995575],
-                [1.28e-09, 0.99794735107, 8827.3902698748],
-                [1.44e-09, 2.54869747042, 227.476132789],
-                [1.5e-09, 4.50631437136, 2379.1644735716],
-                [1.07e-09, 1.79272017026, 13119.72110282519],
-                [1.07e-09, 4.43556814486, 18422.62935909819],
-                [1.09e-09, 0.29269062317, 16737.5772365966],
-                [1.41e-09, 3.18979826258, 6262.300454499],
-                [1.22e-09, 4.23040027813, 29.429508536],
-                [1.11e-09, 5.16954029551, 17782.7320727842],
-                [1e-09, 3.52213872761, 18052.9295431578],
-                [1.08e-09, 1.08514212991, 16858.4825329332],
-                [1.06e-09, 1.9608524841, 74.7815985673],
-                [1.1e-09, 2.30582372873, 16460.33352952499],
-                [9.7e-10, 3.5091894021, 5333.9002410216],
-                [9.9e-10, 3.56417337974, 735.8765135318],
-                [9.4e-10, 5.01857894228, 3128.3887650958],
-                [9.7e-10, 1.65579893894, 533.2140834436],
-                [9.2e-10, 0.89217162285, 29296.6153895786],
-                [1.23e-09, 3.16062050433, 9380.9596727172],
-                [1.02e-09, 1.20493500565, 23020.65308658799],
-                [8.8e-10, 2.21296088224, 12721.572099417],
-                [8.9e-10, 1.5426472031, 20199.094959633],
-                [1.13e-09, 4.8332070787, 16496.3613962024],
-                [1.21e-09, 6.19860353182, 9388.0059094152],
-                [8.9e-10, 4.08082274765, 22805.7355659936],
-                [9.8e-10, 1.0918183283, 12043.574281889],
-                [8.6e-10, 1.13655027605, 143571.32428481648],
-                [8.8e-10, 5.96980472191, 107.6635239386],
-                [8.2e-10, 5.01340404594, 22003.9146348698],
-                [9.4e-10, 1.69615700473, 23006.42599258639],
-                [8.1e-10, 3.00657814365, 2118.7638603784],
-                [9.8e-10, 1.39215287161, 8662.240323563],
-                [7.7e-10, 3.3355519084, 15720.8387848784],
-                [8.2e-10, 5.86880116464, 2787.0430238574],
-                [7.6e-10, 5.67183650604, 14.2270940016],
-                [8.1e-10, 6.16619455699, 1039.0266107904],
-                [7.6e-10, 3.21449884756, 111.1866422876],
-                [7.8e-10, 1.37531518377, 21947.1113727],
-                [7.4e-10, 3.58814195051, 11609.8625440122],
-                [7.7e-10, 4.84846488388, 22743.4093795164],
-                [9e-10, 1.48869013606, 15671.0817594066],
-                [8.2e-10, 3.48618399109, 29088.811415985],
-                [6.9e-10, 3.55746476593, 4590.910180489],
-                [6.9e-10, 1.93625656075, 135.62532501],
-                [7e-10, 2.66548322237, 18875.525869774],
-                [6.9e-10, 5.41478093731, 26735.9452622132],
-                [7.9e-10, 5.15154513662, 12323.4230960088],
-                [9.4e-10, 3.62899392448, 77713.7714681205],
-                [7.8e-10, 4.17011182047, 1066.49547719],
-                [7.1e-10, 3.89435637865, 22779.4372461938],
-                [6.3e-10, 4.53968787714, 8982.810669309],
-                [6.9e-10, 0.96028230548, 14919.0178537546],
-                [7.6e-10, 3.29092216589, 2942.4634232916],
-                [6.3e-10, 4.09167842893, 16062.1845261168],
-                [6.5e-10, 3.34580407184, 51.28033786241],
-                [6.5e-10, 5.75757544877, 52670.0695933026],
-                [6.8e-10, 5.75884067555, 21424.4666443034],
-                [5.7e-10, 5.4512239985, 12592.4500197826],
-                [5.7e-10, 5.25043362558, 20995.3929664494],
-                [7.3e-10, 0.53299090807, 2301.58581590939],
-                [7e-10, 4.31243357502, 19402.7969528166],
-                [6.7e-10, 2.53852336668, 377.3736079158],
-                [5.6e-10, 3.20816844695, 24889.5747959916],
-                [5.3e-10, 3.17816599142, 18451.07854656599],
-                [5.3e-10, 3.61529270216, 77.673770428],
-                [5.3e-10, 0.45467549335, 30666.1549584328],
-                [6.1e-10, 0.14807288453, 23013.5395395872],
-                [5.1e-10, 3.32803972907, 56.8983749356],
-                [5.2e-10, 3.41177624177, 23141.5583829246],
-                [5.8e-10, 3.13638677202, 309.2783226558],
-                [7e-10, 2.50592323465, 31415.379249957],
-                [5.2e-10, 5.10673376738, 17796.9591667858],
-                [6.7e-10, 6.27917920454, 22345.2603761082],
-                [5e-10, 0.42577644151, 25685.872802808],
-                [4.8e-10, 0.70204553333, 1162.4747044078],
-                [6.6e-10, 3.64350022359, 15265.8865193004],
-                [5e-10, 5.7438291744, 19.66976089979],
-                [5e-10, 4.69825387775, 28237.2334593894],
-                [4.7e-10, 5.74015846442, 12139.5535091068],
-                [5.4e-10, 1.97301333704, 23581.2581773176],
-                [4.9e-10, 4.98223579027, 10021.8372800994],
-                [4.6e-10, 5.41431705539, 33019.0211122046],
-                [5.1e-10, 1.23882053879, 12539.853380183],
-                [4.6e-10, 2.41369976086, 98068.53671630539],
-                [4.4e-10, 0.80750593746, 167283.7615876655],
-                [4.5e-10, 4.39613584445, 433.7117378768],
-                [4.4e-10, 2.57358208785, 12964.300703391],
-                [4.6e-10, 0.26142733448, 11.0457002639],
-                [4.5e-10, 2.46230645202, 51868.2486621788],
-                [4.8e-10, 0.89551707131, 56600.2792895222],
-                [5.7e-10, 1.8641670701, 25287.7237993998],
-                [4.2e-10, 5.26377513431, 26084.0218062162],
-                [4.9e-10, 3.17757670611, 6303.8512454838],
-                [5.2e-10, 3.65266055509, 7872.1487452752],
-                [4e-10, 1.81891629936, 34596.3646546524],
-                [4.3e-10, 1.94164978061, 1903.4368125012],
-                [4.1e-10, 0.74461854136, 23937.856389741],
-                [4.8e-10, 6.26034008181, 28286.9904848612],
-                [4.5e-10, 5.4557501753, 60530.4889857418],
-                [4e-10, 2.92105728682, 21548.9623692918],
-                [4e-10, 0.04502010161, 38526.574350872],
-                [5.3e-10, 3.64791042082, 11925.2740926006],
-                [4.1e-10, 5.04048954693, 27832.0382192832],
-                [4.2e-10, 5.19292937193, 19004.6479494084],
-                [4e-10, 2.57120233428, 24356.7807886416],
-                [3.8e-10, 3.49190341464, 226858.23855437007],
-                [3.9e-10, 4.61184303844, 95.9792272178],
-                [4.3e-10, 2.20648228147, 13521.7514415914],
-                [4e-10, 5.83461945819, 16193.65917750039],
-                [4.5e-10, 3.73714372195, 7875.6718636242],
-                [4.3e-10, 1.14078465002, 49.7570254718],
-                [3.7e-10, 1.29390383811, 310.8407988684],
-                [3.8e-10, 0.9597092595, 664.75604513],
-                [3.7e-10, 4.27532649462, 6709.6740408674],
-                [3.8e-10, 2.20108541046, 28628.3362260996],
-                [3.9e-10, 0.85957361635, 16522.6597160022],
-                [4e-10, 4.35214003837, 48739.859897083],
-                [3.6e-10, 1.68167662194, 10344.2950653858],
-                [4e-10, 5.13217319067, 15664.03552270859],
-                [3.6e-10, 3.72187132496, 30774.5016425748],
-                [3.6e-10, 3.32158458257, 16207.886271502],
-                [4.5e-10, 3.94202418608, 10988.808157535],
-                [3.9e-10, 1.51948786199, 12029.3471878874],
-                [2.6e-10, 3.8768588318, 6262.7205305926],
-                [2.4e-10, 4.91804163466, 19651.048481098],
-                [2.3e-10, 0.29300197709, 13362.4497067992],
-                [2.1e-10, 3.18605672363, 6277.552925684],
-                [2.1e-10, 6.07546891132, 18139.2945014159],
-                [2.2e-10, 2.31199937177, 6303.4311693902],
-                [2.1e-10, 3.58418394393, 18209.33026366019],
-                [2.6e-10, 2.068012969, 12573.2652469836],
-                [2.1e-10, 1.56857722317, 13341.6743113068],
-                [2.4e-10, 5.72605158675, 29864.334027309],
-                [2.4e-10, 1.40237993205, 14712.317116458],
-                [2.5e-10, 5.71466092822, 25934.1243310894],
-            ],
-            vec![
-                [0.0005291887, 0.0, 0.0],
-                [8.719837e-05, 1.07209665242, 6283.0758499914],
-                [3.09125e-06, 0.86728818832, 12566.1516999828],
-                [2.7339e-07, 0.05297871691, 3.523118349],
-                [1.6334e-07, 5.18826691036, 26.2983197998],
-                [1.5752e-07, 3.6845788943, 155.4203994342],
-                [9.541e-08, 0.75742297675, 18849.2275499742],
-                [8.937e-08, 2.05705419118, 77713.7714681205],
-                [6.952e-08, 0.8267330541, 775.522611324],
-                [5.064e-08, 4.66284525271, 1577.3435424478],
-                [4.061e-08, 1.03057162962, 7.1135470008],
-                [3.463e-08, 5.14074632811, 796.2980068164],
-                [3.169e-08, 6.05291851171, 5507.5532386674],
-                [3.02e-08, 1.19246506441, 242.728603974],
-                [2.886e-08, 6.11652627155, 529.6909650946],
-                [3.81e-08, 3.4405080349, 5573.1428014331],
-                [2.714e-08, 0.30637881025, 398.1490034082],
-                [2.371e-08, 4.38118838167, 5223.6939198022],
-                [2.538e-08, 2.27992810679, 553.5694028424],
-                [2.079e-08, 3.75435330484, 0.9803210682],
-                [1.675e-08, 0.90216407959, 951.7184062506],
-                [1.534e-08, 5.75900462759, 1349.8674096588],
-                [1.224e-08, 2.97328088405, 2146.1654164752],
-                [1.449e-08, 4.3641591397, 1748.016413067],
-                [1.341e-08, 3.72061130861, 1194.4470102246],
-                [1.254e-08, 2.94846826628, 6438.4962494256],
-                [9.99e-09, 5.98640014468, 6286.5989683404],
-                [9.17e-09, 4.79788687522, 5088.6288397668],
-                [8.28e-09, 3.31321076572, 213.299095438],
-                [1.103e-08, 1.27104454479, 161000.6857376741],
-                [7.62e-09, 3.41582762988, 5486.777843175],
-                [1.044e-08, 0.60409577691, 3154.6870848956],
-                [8.87e-09, 5.23465144638, 7084.8967811152],
-                [6.45e-09, 1.60096192515, 2544.3144198834],
-                [6.81e-09, 3.43155669169, 4694.0029547076],
-                [6.05e-09, 2.47806340546, 10977.078804699],
-                [7.06e-09, 6.19393222575, 4690.4798363586],
-                [6.43e-09, 1.98042503148, 801.8209311238],
-                [5.02e-09, 1.44394375363, 6836.6452528338],
-                [4.9e-09, 2.34129524194, 1592.5960136328],
-                [4.58e-09, 1.30876448575, 4292.3308329504],
-                [4.31e-09, 0.03526421494, 7234.794256242],
-                [3.79e-09, 3.17030522615, 6309.3741697912],
-                [3.48e-09, 0.99049550009, 6040.3472460174],
-                [3.86e-09, 1.57019797263, 71430.69561812909],
-                [3.47e-09, 0.67013291338, 1059.3819301892],
-                [4.58e-09, 3.81499443681, 149854.4001348079],
-                [3.02e-09, 1.91760044838, 10447.3878396044],
-                [3.07e-09, 3.55343347416, 8031.0922630584],
-                [3.95e-09, 4.93701776616, 7632.9432596502],
-                [3.14e-09, 3.18093696547, 2352.8661537718],
-                [2.82e-09, 4.41936437052, 9437.762934887],
-                [2.76e-09, 2.71314254553, 3894.1818295422],
-                [2.98e-09, 2.5203747421, 6127.6554505572],
-                [2.3e-09, 1.37790215549, 4705.7323075436],
-                [2.52e-09, 0.55330133471, 6279.5527316424],
-                [2.55e-09, 5.26570187369, 6812.766815086],
-                [2.75e-09, 0.67264264272, 25132.3033999656],
-                [1.78e-09, 0.92820785174, 1990.745017041],
-                [2.21e-09, 0.63897368842, 6256.7775301916],
-                [1.55e-09, 0.77319790838, 14143.4952424306],
-                [1.5e-09, 2.40470465561, 426.598190876],
-                [1.96e-09, 6.06877865012, 640.8776073822],
-                [1.37e-09, 2.21679460145, 8429.2412664666],
-                [1.27e-09, 3.26094223174, 17789.845619785],
-                [1.28e-09, 5.47237279946, 12036.4607348882],
-                [1.22e-09, 2.16291082757, 10213.285546211],
-                [1.18e-09, 0.45789822268, 7058.5984613154],
-                [1.41e-09, 2.34932647403, 11506.7697697936],
-                [1e-09, 0.85621569847, 6290.1893969922],
-                [9.2e-10, 5.10587476002, 7079.3738568078],
-                [1.26e-09, 2.65428307012, 88860.05707098669],
-                [1.06e-09, 5.85646710022, 7860.4193924392],
-                [8.4e-10, 3.57457554262, 16730.4636895958],
-                [8.9e-10, 4.21433259618, 83996.84731811189],
-                [9.7e-10, 5.57938280855, 13367.9726311066],
-                [1.02e-09, 2.05853060226, 87.30820453981],
-                [8e-10, 4.73792651816, 11926.2544136688],
-                [8e-10, 5.41418965044, 10973.55568635],
-                [1.06e-09, 4.10978997399, 3496.032826134],
-                [1.02e-09, 3.62650006043, 244287.60000722768],
-                [7.5e-10, 4.89483161769, 5643.1785636774],
-                [8.7e-10, 0.42863750683, 11015.1064773348],
-                [6.9e-10, 1.8890876072, 10177.2576795336],
-                [8.9e-10, 1.35567273119, 6681.2248533996],
-                [6.6e-10, 0.99455837265, 6525.8044539654],
-                [6.7e-10, 5.5124099707, 3097.88382272579],
-                [7.6e-10, 2.72016814799, 4164.311989613],
-                [6.3e-10, 1.4434990254, 9917.6968745098],
-                [7.8e-10, 3.51469733747, 11856.2186514245],
-                [8.5e-10, 0.50956043858, 10575.4066829418],
-                [6.7e-10, 3.62043033405, 16496.3613962024],
-                [5.5e-10, 5.24637517308, 3340.6124266998],
-                [4.8e-10, 5.43966777314, 20426.571092422],
-                [6.4e-10, 5.79535817813, 2388.8940204492],
-                [4.6e-10, 5.43499966519, 6275.9623029906],
-                [5e-10, 3.86263598617, 5729.506447149],
-                [4.4e-10, 1.52269529228, 12168.0026965746],
-                [5.7e-10, 4.96352373486, 14945.3161735544],
-                [4.5e-10, 1.0086123016, 8635.9420037632],
-                [4.3e-10, 3.30685683359, 9779.1086761254],
-                [4.2e-10, 0.6348125893, 2699.7348193176],
-                [4.1e-10, 5.67996766641, 11712.9553182308],
-                [5.6e-10, 4.34024451468, 90955.5516944961],
-                [4.1e-10, 5.81722212845, 709.9330485583],
-                [5.3e-10, 6.17052087143, 233141.3144043615],
-                [3.7e-10, 3.12495025087, 16200.7727245012],
-                [3.5e-10, 5.76973458495, 12569.6748183318],
-                [3.7e-10, 0.31656444326, 24356.7807886416],
-                [3.5e-10, 0.96229051027, 17298.1823273262],
-                [3.3e-10, 5.23130355867, 5331.3574437408],
-                [3.5e-10, 0.62517020593, 25158.6017197654],
-                [3.5e-10, 0.80004512129, 13916.0191096416],
-                [3.7e-10, 2.89336088688, 12721.572099417],
-                [3e-10, 4.50198402401, 23543.23050468179],
-                [3e-10, 5.31355708693, 18319.5365848796],
-                [2.9e-10, 3.47275229977, 13119.72110282519],
-                [2.9e-10, 3.11002782516, 4136.9104335162],
-                [3.2e-10, 5.52273255667, 5753.3848848968],
-                [3.5e-10, 3.7969999668, 143571.32428481648],
-                [2.6e-10, 1.50634201907, 154717.6098876827],
-                [3e-10, 3.53519084118, 6284.0561710596],
-                [2.3e-10, 4.41808025967, 5884.9268465832],
-                [2.5e-10, 1.38477355808, 65147.6197681377],
-                [2.3e-10, 3.49782549797, 7477.522860216],
-                [1.9e-10, 3.14329413716, 6496.3749454294],
-                [1.9e-10, 2.20135125199, 18073.7049386502],
-                [1.9e-10, 4.95020255309, 3930.2096962196],
-                [1.9e-10, 0.57998702747, 31415.379249957],
-                [2.1e-10, 1.75474323399, 12139.5535091068],
-                [1.9e-10, 3.92233070499, 19651.048481098],
-                [1.4e-10, 0.98131213224, 12559.038152982],
-                [1.9e-10, 4.93309333729, 2942.4634232916],
-                [1.6e-10, 5.55997534558, 8827.3902698748],
-                [1.3e-10, 1.68808165516, 4535.0594369244],
-                [1.3e-10, 0.33982116161, 4933.2084403326],
-                [1.2e-10, 1.85426309994, 5856.4776591154],
-                [1e-10, 4.82763996845, 13095.8426650774],
-                [1.1e-10, 5.38005490571, 11790.6290886588],
-                [1e-10, 1.40815507226, 10988.808157535],
-                [1.1e-10, 3.05005267431, 17260.1546546904],
-                [1e-10, 4.93364992366, 12352.8526045448],
-            ],
-            vec![
-                [2.89226e-06, 5.84384198723, 6283.0758499914],
-                [3.4955e-07, 0.0, 0.0],
-                [1.6819e-07, 5.48766912348, 12566.1516999828],
-                [2.962e-08, 5.19577265202, 155.4203994342],
-                [1.288e-08, 4.72200252235, 3.523118349],
-                [6.35e-09, 5.96925937141, 242.728603974],
-                [7.14e-09, 5.30045809128, 18849.2275499742],
-                [4.02e-09, 3.78682982419, 553.5694028424],
-                [7.2e-10, 4.2976812618, 6286.5989683404],
-                [6.7e-10, 0.90721687647, 6127.6554505572],
-                [3.6e-10, 5.24029648014, 6438.4962494256],
-                [2.4e-10, 5.16003960716, 25132.3033999656],
-                [2.3e-10, 3.01921570335, 6309.3741697912],
-                [1.7e-10, 5.82863573502, 6525.8044539654],
-                [1.7e-10, 3.6777286393, 71430.69561812909],
-                [9e-11, 4.58467294499, 1577.3435424478],
-                [8e-11, 1.40626662824, 11856.2186514245],
-                [8e-11, 5.07561257196, 6256.7775301916],
-                [7e-11, 2.82473374405, 83996.84731811189],
-                [5e-11, 2.71488713339, 10977.078804699],
-                [5e-11, 3.76879847273, 12036.4607348882],
-                [5e-11, 4.28412873331, 6275.9623029906],
-            ],
-            vec![
-                [1.14084e-06, 3.14159265359, 0.0],
-                [7.717e-08, 4.13446589358, 6283.0758499914],
-                [7.65e-09, 3.83803776214, 12566.1516999828],
-                [4.2e-09, 0.41925861858, 155.4203994342],
-                [4e-10, 3.5984758584, 18849.2275499742],
-                [4.1e-10, 3.14398414077, 3.523118349],
-                [3.5e-10, 5.00298940826, 5573.1428014331],
-                [1.3e-10, 0.48794833701, 77713.7714681205],
-                [1e-10, 5.6480176635, 6127.6554505572],
-                [8e-11, 2.84160570605, 161000.6857376741],
-                [2e-11, 0.54912904658, 6438.4962494256],
-            ],
-            vec![
-                [8.78e-09, 3.14159265359, 0.0],
-                [1.72e-09, 2.7657906951, 6283.0758499914],
-                [5e-10, 2.01353298182, 155.4203994342],
-                [2.8e-10, 2.21496423926, 12566.1516999828],
-                [5e-11, 1.75600058765, 18849.2275499742],
-            ],
+// Terms of the VSOP87D series of the heliocentric longitude, latitude
+// and radius vector, each a series of terms [A, B, C] in powers of t
+pub const TERMS: [&[&[[f64; 3]]]; 3] = [
+    &[
+        &[
+            [1.75347045673, 0.0, 0.0],
+            [0.03341656456, 4.66925680417, 6283.0758499914],
+            [0.00034894275, 4.62610241759, 12566.1516999828],
+            [3.417571e-05, 2.82886579606, 3.523118349],
+            [3.497056e-05, 2.74411800971, 5753.3848848968],
+            [3.135896e-05, 3.62767041758, 77713.7714681205],
+            [2.676218e-05, 4.41808351397, 7860.4193924392],
+            [2.342687e-05, 6.13516237631, 3930.2096962196],
+            [1.273166e-05, 2.03709655772, 529.6909650946],
+            [1.324292e-05, 0.74246356352, 11506.7697697936],
+            [9.01855e-06, 2.04505443513, 26.2983197998],
+            [1.199167e-05, 1.10962944315, 1577.3435424478],
+            [8.57223e-06, 3.50849156957, 398.1490034082],
+            [7.79786e-06, 1.17882652114, 5223.6939198022],
+            [9.9025e-06, 5.23268129594, 5884.9268465832],
+            [7.53141e-06, 2.53339053818, 5507.5532386674],
+            [5.05264e-06, 4.58292563052, 18849.2275499742],
+            [4.92379e-06, 4.20506639861, 775.522611324],
+            [3.56655e-06, 2.91954116867, 0.0673103028],
+            [2.84125e-06, 1.89869034186, 796.2980068164],
+            [2.4281e-06, 0.34481140906, 5486.777843175],
+            [3.17087e-06, 5.84901952218, 11790.6290886588],
+            [2.71039e-06, 0.31488607649, 10977.078804699],
+            [2.0616e-06, 4.80646606059, 2544.3144198834],
+            [2.05385e-06, 1.86947813692, 5573.1428014331],
+            [2.02261e-06, 2.45767795458, 6069.7767545534],
+            [1.26184e-06, 1.0830263021, 20.7753954924],
+            [1.55516e-06, 0.83306073807, 213.299095438],
+            [1.15132e-06, 0.64544911683, 0.9803210682],
+            [1.02851e-06, 0.63599846727, 4694.0029547076],
+            [1.01724e-06, 4.26679821365, 7.1135470008],
+            [9.9206e-07, 6.20992940258, 2146.1654164752],
+            [1.32212e-06, 3.41118275555, 2942.4634232916],
+            [9.7607e-07, 0.6810127227, 155.4203994342],
+            [8.5128e-07, 1.29870743025, 6275.9623029906],
+            [7.4651e-07, 1.75508916159, 5088.6288397668],
+            [1.01895e-06, 0.97569221824, 15720.8387848784],
+            [8.4711e-07, 3.67080093025, 71430.69561812909],
+            [7.3547e-07, 4.67926565481, 801.8209311238],
+            [7.3874e-07, 3.50319443167, 3154.6870848956],
+            [7.8756e-07, 3.03698313141, 12036.4607348882],
+            [7.9637e-07, 1.807913307, 17260.1546546904],
+            [8.5803e-07, 5.98322631256, 161000.6857376741],
+            [5.6963e-07, 2.78430398043, 6286.5989683404],
+            [6.1148e-07, 1.81839811024, 7084.8967811152],
+            [6.9627e-07, 0.83297596966, 9437.762934887],
+            [5.6116e-07, 4.38694880779, 14143.4952424306],
+            [6.2449e-07, 3.97763880587, 8827.3902698748],
+            [5.1145e-07, 0.28306864501, 5856.4776591154],
+            [5.5577e-07, 3.47006009062, 6279.5527316424],
+            [4.1036e-07, 5.36817351402, 8429.2412664666],
+            [5.1605e-07, 1.33282746983, 1748.016413067],
+            [5.1992e-07, 0.18914945834, 12139.5535091068],
+            [4.9e-07, 0.48735065033, 1194.4470102246],
+            [3.92e-07, 6.16832995016, 10447.3878396044],
+            [3.5566e-07, 1.77597314691, 6812.766815086],
+            [3.677e-07, 6.04133859347, 10213.285546211],
+            [3.6596e-07, 2.56955238628, 1059.3819301892],
+            [3.3291e-07, 0.59309499459, 17789.845619785],
+            [3.5954e-07, 1.70876111898, 2352.8661537718],
+            [4.0938e-07, 2.39850881707, 19651.048481098],
+            [3.0047e-07, 2.73975123935, 1349.8674096588],
+            [3.0412e-07, 0.44294464135, 83996.84731811189],
+            [2.3663e-07, 0.48473567763, 8031.0922630584],
+            [2.3574e-07, 2.06527720049, 3340.6124266998],
+            [2.1089e-07, 4.14825464101, 951.7184062506],
+            [2.4738e-07, 0.21484762138, 3.5904286518],
+            [2.5352e-07, 3.16470953405, 4690.4798363586],
+            [2.282e-07, 5.22197888032, 4705.7323075436],
+            [2.1419e-07, 1.42563735525, 16730.4636895958],
+            [2.1891e-07, 5.55594302562, 553.5694028424],
+            [1.7481e-07, 4.56052900359, 135.0650800354],
+            [1.9925e-07, 5.22208471269, 12168.0026965746],
+            [1.986e-07, 5.77470167653, 6309.3741697912],
+            [2.03e-07, 0.37133792946, 283.8593188652],
+            [1.4421e-07, 4.19315332546, 242.728603974],
+            [1.6225e-07, 5.98837722564, 11769.8536931664],
+            [1.5077e-07, 4.19567181073, 6256.7775301916],
+            [1.9124e-07, 3.82219996949, 23581.2581773176],
+            [1.8888e-07, 5.38626880969, 149854.4001348079],
+            [1.4346e-07, 3.72355084422, 38.0276726358],
+            [1.7898e-07, 2.21490735647, 13367.9726311066],
+            [1.2054e-07, 2.62229588349, 955.5997416086],
+            [1.1287e-07, 0.17739328092, 4164.311989613],
+            [1.3971e-07, 4.40138139996, 6681.2248533996],
+            [1.3621e-07, 1.88934471407, 7632.9432596502],
+            [1.2503e-07, 1.13052412208, 5.5229243074],
+            [1.0498e-07, 5.35909518669, 1592.5960136328],
+            [9.803e-08, 0.99947478995, 11371.7046897582],
+            [9.22e-08, 4.57138609781, 4292.3308329504],
+            [1.0327e-07, 6.19982566125, 6438.4962494256],
+            [1.2003e-07, 1.003514567, 632.7837393132],
+            [1.0827e-07, 0.32734520222, 103.0927742186],
+            [8.356e-08, 4.53902685948, 25132.3033999656],
+            [1.0005e-07, 6.0291496328, 5746.271337896],
+            [8.409e-08, 3.29946744189, 7234.794256242],
+            [8.006e-08, 5.82145271907, 28.4491874678],
+            [1.0523e-07, 0.93871805506, 11926.2544136688],
+            [7.686e-08, 3.12142363172, 7238.6755916],
+            [9.378e-08, 2.62414241032, 5760.4984318976],
+            [8.127e-08, 6.11228001785, 4732.0306273434],
+            [9.232e-08, 0.48343968736, 522.5774180938],
+            [9.802e-08, 5.24413991147, 27511.4678735372],
+            [7.871e-08, 0.99590177926, 5643.1785636774],
+            [8.123e-08, 6.2705301365, 426.598190876],
+            [9.048e-08, 5.33686335897, 6386.16862421],
+            [8.62e-08, 4.16538210888, 7058.5984613154],
+            [6.297e-08, 4.71724819317, 6836.6452528338],
+            [7.575e-08, 3.97382858911, 11499.6562227928],
+            [7.756e-08, 2.95729056763, 23013.5395395872],
+            [7.314e-08, 0.60652505806, 11513.8833167944],
+            [5.955e-08, 2.87641047971, 6283.14316029419],
+            [6.534e-08, 5.79072926033, 18073.7049386502],
+            [7.188e-08, 3.99831508699, 74.7815985673],
+            [7.346e-08, 4.38582365437, 316.3918696566],
+            [5.413e-08, 5.39199024641, 419.4846438752],
+            [5.127e-08, 2.36062848786, 10973.55568635],
+            [7.056e-08, 0.32258441903, 263.0839233728],
+            [6.625e-08, 3.66475158672, 17298.1823273262],
+            [6.762e-08, 5.91132535899, 90955.5516944961],
+            [4.938e-08, 5.73672165674, 9917.6968745098],
+            [5.547e-08, 2.45152597661, 12352.8526045448],
+            [5.958e-08, 3.32051344676, 6283.0085396886],
+            [4.471e-08, 2.06385999536, 7079.3738568078],
+            [6.153e-08, 1.45823331144, 233141.3144043615],
+            [4.348e-08, 4.4234217548, 5216.5803728014],
+            [6.123e-08, 1.07494905258, 19804.8272915828],
+            [4.488e-08, 3.6528503715, 206.1855484372],
+            [4.02e-08, 0.83995823171, 20.3553193988],
+            [5.188e-08, 4.06503864016, 6208.2942514241],
+            [5.307e-08, 0.38217636096, 31441.6775697568],
+            [3.785e-08, 2.34369213733, 3.881335358],
+            [4.497e-08, 3.27230796845, 11015.1064773348],
+            [4.132e-08, 0.92128915753, 3738.761430108],
+            [3.521e-08, 5.97844807108, 3894.1818295422],
+            [4.215e-08, 1.90601120623, 245.8316462294],
+            [3.701e-08, 5.03069397926, 536.8045120954],
+            [3.865e-08, 1.82634360607, 11856.2186514245],
+            [3.652e-08, 1.01838584934, 16200.7727245012],
+            [3.39e-08, 0.97785123922, 8635.9420037632],
+            [3.737e-08, 2.95380107829, 3128.3887650958],
+            [3.507e-08, 3.71291946325, 6290.1893969922],
+            [3.086e-08, 3.64646921512, 10.6366653498],
+            [3.397e-08, 1.10590684017, 14712.317116458],
+            [3.334e-08, 0.83684924911, 6496.3749454294],
+            [2.805e-08, 2.58504514144, 14314.1681130498],
+            [3.65e-08, 1.08344142571, 88860.05707098669],
+            [3.388e-08, 3.20185096055, 5120.6011455836],
+            [3.252e-08, 3.47859752062, 6133.5126528568],
+            [2.553e-08, 3.94869034189, 1990.745017041],
+            [3.52e-08, 2.05559692878, 244287.60000722768],
+            [2.565e-08, 1.560717849, 23543.23050468179],
+            [2.621e-08, 3.85639359951, 266.6070417218],
+            [2.955e-08, 3.39692949667, 9225.539273283],
+            [2.876e-08, 6.02635617464, 154717.6098876827],
+            [2.395e-08, 1.16131956403, 10984.1923516998],
+            [3.161e-08, 1.32798718453, 10873.9860304804],
+            [3.163e-08, 5.08946464629, 21228.3920235458],
+            [2.361e-08, 4.27212906992, 6040.3472460174],
+            [3.03e-08, 1.80209931347, 35371.8872659764],
+            [2.343e-08, 3.576898605, 10969.9652576982],
+            [2.618e-08, 2.57870156528, 22483.84857449259],
+            [2.113e-08, 3.71393780256, 65147.6197681377],
+            [2.019e-08, 0.81393923319, 170.6728706192],
+            [2.003e-08, 0.38091017375, 6172.869528772],
+            [2.506e-08, 3.74379142438, 10575.4066829418],
+            [2.381e-08, 0.10581361289, 7.046236698],
+            [1.949e-08, 4.86892513469, 36.0278666774],
+            [2.074e-08, 4.2279477457, 5650.2921106782],
+            [1.924e-08, 5.5946054986, 6282.0955289232],
+            [1.949e-08, 1.07002512703, 5230.807466803],
+            [1.988e-08, 5.19736046771, 6262.300454499],
+            [1.887e-08, 3.74365662683, 23.8784377478],
+            [1.787e-08, 1.25929682929, 12559.038152982],
+            [1.883e-08, 1.90364058477, 15.252471185],
+            [1.816e-08, 3.68083868442, 15110.4661198662],
+            [1.701e-08, 4.4110589538, 110.2063212194],
+            [1.99e-08, 3.93295788548, 6206.8097787158],
+            [2.103e-08, 0.75354917468, 13521.7514415914],
+            [1.774e-08, 0.48747535361, 1551.045222648],
+            [1.882e-08, 0.86684493432, 22003.9146348698],
+            [1.924e-08, 1.22898324132, 709.9330485583],
+            [2.009e-08, 4.6285092198, 6037.244203762],
+            [1.924e-08, 0.60231842508, 6284.0561710596],
+            [1.596e-08, 3.98332956992, 13916.0191096416],
+            [1.664e-08, 4.41939715469, 8662.240323563],
+            [1.971e-08, 1.04560500503, 18209.33026366019],
+            [1.942e-08, 4.31335979989, 6244.9428143536],
+            [1.476e-08, 0.93271367331, 2379.1644735716],
+            [1.81e-08, 0.49112137707, 1.4844727083],
+            [1.346e-08, 1.51574702235, 4136.9104335162],
+            [1.528e-08, 5.61835711404, 6127.6554505572],
+            [1.791e-08, 3.22187270126, 39302.096962196],
+            [1.747e-08, 3.05638656738, 18319.5365848796],
+            [1.431e-08, 4.51153808594, 20426.571092422],
+            [1.695e-08, 0.22047718414, 25158.6017197654],
+            [1.242e-08, 4.46665769933, 17256.6315363414],
+            [1.463e-08, 4.69242679213, 14945.3161735544],
+            [1.205e-08, 1.86912144659, 4590.910180489],
+            [1.192e-08, 2.74227166898, 12569.6748183318],
+            [1.222e-08, 5.18120087482, 5333.9002410216],
+            [1.39e-08, 5.42894648983, 143571.32428481648],
+            [1.473e-08, 1.70479245805, 11712.9553182308],
+            [1.362e-08, 2.61069503292, 6062.6632075526],
+            [1.148e-08, 6.0300180054, 3634.6210245184],
+            [1.198e-08, 5.15294130422, 10177.2576795336],
+            [1.266e-08, 0.11421493643, 18422.62935909819],
+            [1.411e-08, 1.09908857534, 3496.032826134],
+            [1.349e-08, 2.99805109633, 17654.7805397496],
+            [1.253e-08, 2.79850152848, 167283.7615876655],
+            [1.311e-08, 1.60942984879, 5481.2549188676],
+            [1.079e-08, 6.20304501787, 3.2863574178],
+            [1.181e-08, 1.20653776978, 131.5419616864],
+            [1.254e-08, 5.45103277798, 6076.8903015542],
+            [1.035e-08, 2.32142722747, 7342.4577801806],
+            [1.117e-08, 0.38838354256, 949.1756089698],
+            [9.66e-09, 3.18341890851, 11087.2851259184],
+            [1.171e-08, 3.39635049962, 12562.6285816338],
+            [1.121e-08, 0.72627490378, 220.4126424388],
+            [1.024e-08, 2.19378315386, 11403.676995575],
+            [8.88e-09, 3.91173199285, 4686.8894077068],
+            [9.1e-09, 1.98802695087, 735.8765135318],
+            [8.3e-09, 0.48984915507, 24072.9214697764],
+            [1.096e-08, 6.17377835617, 5436.9930152402],
+            [9.08e-09, 0.44959639433, 7477.522860216],
+            [9.74e-09, 1.52996238356, 9623.6882766912],
+            [8.4e-09, 1.79543266333, 5429.8794682394],
+            [7.78e-09, 6.17699177946, 38.1330356378],
+            [7.76e-09, 4.09855402433, 14.2270940016],
+            [1.068e-08, 4.64200173735, 43232.3066584156],
+            [9.54e-09, 1.49988435748, 1162.4747044078],
+            [9.07e-09, 0.86986870809, 10344.2950653858],
+            [9.31e-09, 4.06044689031, 28766.924424484],
+            [7.39e-09, 5.04368197372, 639.897286314],
+            [9.37e-09, 3.4688469896, 1589.0728952838],
+            [7.63e-09, 5.86304932998, 16858.4825329332],
+            [9.53e-09, 4.20801492835, 11190.377900137],
+            [7.08e-09, 1.7289998894, 13095.8426650774],
+            [9.69e-09, 1.64439522215, 29088.811415985],
+            [7.17e-09, 0.16688678895, 11.729352836],
+            [9.62e-09, 3.53092337542, 12416.5885028482],
+            [7.47e-09, 5.77866940346, 12592.4500197826],
+            [6.72e-09, 1.91095796194, 3.9321532631],
+            [6.71e-09, 5.46240843677, 18052.9295431578],
+            [6.75e-09, 6.28311558823, 4535.0594369244],
+            [6.84e-09, 0.3997501208, 5849.3641121146],
+            [7.99e-09, 0.29851185294, 12132.439962106],
+            [7.58e-09, 0.96370823331, 1052.2683831884],
+            [7.82e-09, 5.33878339919, 13517.8701062334],
+            [7.3e-09, 1.70106160291, 17267.26820169119],
+            [7.49e-09, 2.59599901875, 11609.8625440122],
+            [7.34e-09, 2.78417782952, 640.8776073822],
+            [6.88e-09, 5.15048287468, 16496.3613962024],
+            [7.7e-09, 1.62469589333, 4701.1165017084],
+            [6.33e-09, 2.20587893893, 25934.1243310894],
+            [7.6e-09, 4.21317219403, 377.3736079158],
+            [5.84e-09, 2.13420121623, 10557.5941608238],
+            [5.74e-09, 0.24250054587, 9779.1086761254],
+            [5.73e-09, 3.16435264609, 533.2140834436],
+            [6.85e-09, 3.19344289472, 12146.6670561076],
+            [6.75e-09, 0.96179233959, 10454.5013866052],
+            [6.48e-09, 1.46327342555, 6268.8487559898],
+            [5.89e-09, 2.50543543638, 3097.88382272579],
+            [5.51e-09, 5.28099026956, 9388.0059094152],
+            [6.96e-09, 3.65342150016, 4804.209275927],
+            [6.69e-09, 2.51030077026, 2388.8940204492],
+            [5.5e-09, 0.06883864342, 20199.094959633],
+            [6.29e-09, 4.13350995675, 45892.73043315699],
+            [6.78e-09, 6.09190163533, 135.62532501],
+            [5.93e-09, 1.50136257618, 226858.23855437007],
+            [5.42e-09, 3.58573645173, 6148.010769956],
+            [6.82e-09, 5.02203067788, 17253.04110768959],
+            [5.65e-09, 4.2930923861, 11933.3679606696],
+            [4.86e-09, 0.77746204893, 27.4015560968],
+            [5.03e-09, 0.58963565969, 15671.0817594066],
+            [6.16e-09, 4.06539884128, 227.476132789],
+            [5.83e-09, 6.12695541996, 18875.525869774],
+            [5.37e-09, 2.1505644098, 21954.15760939799],
+            [6.69e-09, 6.06986269566, 47162.5163546352],
+            [4.75e-09, 0.4034384211, 6915.8595893046],
+            [5.4e-09, 2.83444222174, 5326.7866940208],
+            [5.3e-09, 5.26359885263, 10988.808157535],
+            [5.82e-09, 3.24533095664, 153.7788104848],
+            [6.41e-09, 3.24711791371, 2107.0345075424],
+            [6.21e-09, 3.09698523779, 33019.0211122046],
+            [4.66e-09, 3.14982372198, 10440.2742926036],
+            [4.66e-09, 0.90708835657, 5966.6839803348],
+            [5.28e-09, 0.8192645447, 813.5502839598],
+            [6.03e-09, 3.81378921927, 316428.22867391503],
+            [5.59e-09, 1.81894804124, 17996.0311682222],
+            [4.37e-09, 2.28625594435, 6303.8512454838],
+            [5.18e-09, 4.86069178322, 20597.2439630412],
+            [4.24e-09, 6.23520018693, 6489.2613984286],
+            [5.18e-09, 6.17617826756, 0.2438174835],
+            [4.04e-09, 5.72804304258, 5642.1982426092],
+            [4.58e-09, 1.34117773915, 6287.0080032545],
+            [5.48e-09, 5.6845445832, 155427.542936241],
+            [5.47e-09, 1.03391472061, 3646.3503773544],
+            [4.28e-09, 4.69800981138, 846.0828347512],
+            [4.13e-09, 6.02520699406, 6279.4854213396],
+            [5.34e-09, 3.03030638223, 66567.48586525429],
+            [3.83e-09, 1.49056949125, 19800.9459562248],
+            [4.1e-09, 5.28319622279, 18451.07854656599],
+            [3.52e-09, 4.68891600359, 4907.3020501456],
+            [4.8e-09, 5.36572651091, 348.924420448],
+            [3.44e-09, 5.89157452896, 6546.1597733642],
+            [3.4e-09, 0.3755742644, 13119.72110282519],
+            [4.34e-09, 4.98417785901, 6702.5604938666],
+            [3.32e-09, 2.68902519126, 29296.6153895786],
+            [4.48e-09, 2.16478480251, 5905.7022420756],
+            [3.44e-09, 2.06546633735, 49.7570254718],
+            [3.15e-09, 1.24023811803, 4061.2192153944],
+            [3.24e-09, 2.30897526929, 5017.508371365],
+            [4.13e-09, 0.17171692962, 6286.6662786432],
+            [4.31e-09, 3.86601101393, 12489.8856287072],
+            [3.49e-09, 4.55372342974, 4933.2084403326],
+            [3.23e-09, 0.41971136084, 10770.8932562618],
+            [3.41e-09, 2.68612860807, 11.0457002639],
+            [3.16e-09, 3.52936906658, 17782.7320727842],
+            [3.15e-09, 5.63357264999, 568.8218740274],
+            [3.4e-09, 3.83571212349, 10660.6869350424],
+            [2.97e-09, 0.62691416712, 20995.3929664494],
+            [4.05e-09, 1.00085779471, 16460.33352952499],
+            [4.14e-09, 1.21998752076, 51092.7260508548],
+            [3.36e-09, 4.71465945226, 6179.9830757728],
+            [3.61e-09, 3.71227508354, 28237.2334593894],
+            [3.85e-09, 6.21925225757, 24356.7807886416],
+            [3.27e-09, 1.05606504715, 11919.140866668],
+            [3.27e-09, 6.14222420989, 6254.6266625236],
+            [2.68e-09, 2.47224339737, 664.75604513],
+            [2.69e-09, 1.86207884109, 23141.5583829246],
+            [3.45e-09, 0.93461290184, 6058.7310542895],
+            [2.96e-09, 4.5168755718, 6418.1409300268],
+            [3.53e-09, 4.50033653082, 36949.2308084242],
+            [2.6e-09, 4.04963546305, 6525.8044539654],
+            [2.98e-09, 2.20046722622, 156137.47598479927],
+            [2.53e-09, 3.49900838384, 29864.334027309],
+            [2.54e-09, 2.44901693835, 5331.3574437408],
+            [2.96e-09, 0.84347588787, 5729.506447149],
+            [2.98e-09, 1.29194706125, 22805.7355659936],
+            [2.41e-09, 2.00721280805, 16737.5772365966],
+            [3.11e-09, 1.23668016334, 6281.5913772831],
+            [2.4e-09, 2.51650377121, 6245.0481773556],
+            [3.32e-09, 3.55576945724, 7668.6374249425],
+            [2.64e-09, 4.44052061202, 12964.300703391],
+            [2.57e-09, 1.79654471948, 11080.1715789176],
+            [2.6e-09, 3.3307759842, 5888.4499649322],
+            [2.85e-09, 0.3088636143, 11823.1616394502],
+            [2.9e-09, 5.70141882483, 77.673770428],
+            [2.55e-09, 4.0093966444, 5881.4037282342],
+            [2.53e-09, 4.73318493678, 16723.350142595],
+            [2.28e-09, 0.95333661324, 5540.0857894588],
+            [3.19e-09, 1.38633229189, 163096.18036118348],
+            [2.24e-09, 1.65156322696, 10027.9031957292],
+            [2.26e-09, 0.34106460604, 17796.9591667858],
+            [2.36e-09, 4.19817431922, 19.66976089979],
+            [2.8e-09, 4.1408026897, 12539.853380183],
+            [2.75e-09, 5.50306930248, 32.5325507914],
+            [2.23e-09, 5.23334210294, 56.8983749356],
+            [2.17e-09, 6.08587881787, 6805.6532680852],
+            [2.8e-09, 4.52472044653, 6016.4688082696],
+            [2.27e-09, 5.06509843737, 6277.552925684],
+            [2.26e-09, 5.17755154305, 11720.0688652316],
+            [2.45e-09, 3.96486270306, 22.7752014508],
+            [2.2e-09, 4.7207808197, 6.62855890001],
+            [2.07e-09, 5.71701403951, 41.5507909848],
+            [2.04e-09, 3.9122741125, 2699.7348193176],
+            [2.09e-09, 0.86881969011, 6321.1035226272],
+            [2e-09, 2.11984445273, 4274.5183108324],
+            [2e-09, 5.39839888163, 6019.9919266186],
+            [2.09e-09, 5.67606291663, 11293.4706743556],
+            [2.52e-09, 1.64965729351, 9380.9596727172],
+            [2.75e-09, 5.04826903506, 73.297125859],
+            [2.08e-09, 1.88207277133, 11300.5842213564],
+            [2.72e-09, 0.74640926842, 1975.492545856],
+            [1.99e-09, 3.30836672397, 22743.4093795164],
+            [2.69e-09, 4.48560812155, 64471.99124174489],
+            [1.92e-09, 2.17464236325, 5863.5912061162],
+            [2.28e-09, 5.85373115869, 128.0188433374],
+            [2.61e-09, 2.64321183295, 55022.9357470744],
+            [2.2e-09, 5.75012110079, 29.429508536],
+            [1.87e-09, 4.03230554718, 467.9649903544],
+            [2e-09, 5.60556112058, 1066.49547719],
+            [2.31e-09, 1.09802712785, 12341.8069042809],
+            [1.99e-09, 0.295006252, 149.5631971346],
+            [2.49e-09, 5.10473210814, 7875.6718636242],
+            [2.08e-09, 0.93013835019, 14919.0178537546],
+            [1.79e-09, 0.87104393079, 12721.572099417],
+            [2.03e-09, 1.56920753653, 28286.9904848612],
+            [1.79e-09, 2.47036386443, 16062.1845261168],
+            [1.98e-09, 3.54061588502, 30.914125635],
+            [1.71e-09, 3.45356518113, 5327.4761083828],
+            [1.83e-09, 0.72325421604, 6272.0301497275],
+            [2.16e-09, 2.97174580686, 19402.7969528166],
+            [1.68e-09, 2.51550550242, 23937.856389741],
+            [1.95e-09, 0.09045393425, 156.4007205024],
+            [1.79e-09, 4.4947179809, 31415.379249957],
+            [2.16e-09, 0.42177594328, 23539.7073863328],
+            [1.89e-09, 0.37542530191, 9814.6041002912],
+            [2.18e-09, 2.36835880025, 16627.3709153772],
+            [1.66e-09, 4.23182968446, 16840.67001081519],
+            [2e-09, 2.02153258098, 16097.6799502826],
+            [1.69e-09, 0.91318727, 95.9792272178],
+            [2.11e-09, 5.73370637657, 151.8972810852],
+            [2.04e-09, 0.42643085174, 515.463871093],
+            [2.12e-09, 3.00233538977, 12043.574281889],
+            [1.92e-09, 5.46153589821, 6379.0550772092],
+            [1.65e-09, 1.38698167064, 4171.4255366138],
+            [1.6e-09, 6.23798383332, 202.2533951741],
+            [2.15e-09, 0.20889073407, 5621.8429232104],
+            [1.81e-09, 4.12439203622, 13341.6743113068],
+            [1.53e-09, 1.24460848836, 29826.3063546732],
+            [1.5e-09, 3.12999753018, 799.8211251654],
+            [1.75e-09, 4.55671604437, 239424.39025435288],
+            [1.92e-09, 1.33928820063, 394.6258850592],
+            [1.49e-09, 2.65697593276, 21.335640467],
+            [1.46e-09, 5.58021191726, 412.3710968744],
+            [1.56e-09, 3.75650175503, 12323.4230960088],
+            [1.43e-09, 3.75708566606, 58864.5439181463],
+            [1.43e-09, 3.28248547724, 29.8214381488],
+            [1.44e-09, 1.07862546598, 1265.5674786264],
+            [1.48e-09, 0.23389236655, 10021.8372800994],
+            [1.93e-09, 5.92751083086, 40879.4405046438],
+            [1.4e-09, 4.97612440269, 158.9435177832],
+            [1.48e-09, 2.61640453469, 17157.0618804718],
+            [1.41e-09, 3.66871308723, 26084.0218062162],
+            [1.47e-09, 5.09968173403, 661.232926781],
+            [1.46e-09, 4.96885605695, 57375.8019008462],
+            [1.42e-09, 0.78678347839, 12779.4507954208],
+            [1.34e-09, 4.79432636012, 111.1866422876],
+            [1.4e-09, 1.27748013377, 107.6635239386],
+            [1.69e-09, 2.74893543762, 26735.9452622132],
+            [1.65e-09, 3.95288000638, 6357.8574485587],
+            [1.83e-09, 5.43418358741, 369.6998159404],
+            [1.34e-09, 3.09132862833, 17.812522118],
+            [1.32e-09, 3.05633896779, 22490.9621214934],
+            [1.34e-09, 4.09472795832, 6599.467719648],
+            [1.81e-09, 4.22950689891, 966.9708774356],
+            [1.52e-09, 5.28885894415, 12669.2444742014],
+            [1.5e-09, 5.86819430908, 97238.62754448749],
+            [1.42e-09, 5.87266532526, 22476.73502749179],
+            [1.45e-09, 5.07330784304, 87.30820453981],
+            [1.33e-09, 5.65471067133, 31.9723058168],
+            [1.24e-09, 2.83326217072, 12566.2190102856],
+            [1.35e-09, 3.12861731644, 32217.2001810808],
+            [1.37e-09, 0.86487461904, 9924.8104215106],
+            [1.72e-09, 1.98369595114, 174242.4659640497],
+            [1.7e-09, 4.41115280254, 327574.51427678124],
+            [1.51e-09, 0.46542099527, 39609.6545831656],
+            [1.48e-09, 2.13439571118, 491.6632924588],
+            [1.53e-09, 3.78801830344, 17363.24742890899],
+            [1.65e-09, 5.31654110459, 16943.7627850338],
+            [1.65e-09, 4.06747587817, 58953.145443294],
+            [1.18e-09, 0.63846333239, 6.0659156298],
+            [1.59e-09, 0.86086959274, 221995.02880149524],
+            [1.19e-09, 5.96432932413, 1385.8952763362],
+            [1.14e-09, 5.16516114595, 25685.872802808],
+            [1.12e-09, 3.39403722178, 21393.5419698576],
+            [1.12e-09, 4.92889233335, 56.8032621698],
+            [1.19e-09, 2.40637635942, 18635.9284545362],
+            [1.15e-09, 0.23374479051, 418.9243989006],
+            [1.22e-09, 0.93575234049, 24492.40611365159],
+            [1.15e-09, 4.58880032176, 26709.6469424134],
+            [1.3e-09, 4.85539251, 22345.2603761082],
+            [1.4e-09, 1.09413073202, 44809.6502008634],
+            [1.12e-09, 6.05401806281, 433.7117378768],
+            [1.04e-09, 1.54931540602, 127.9515330346],
+            [1.05e-09, 4.82620858888, 33794.5437235286],
+            [1.02e-09, 4.12448497391, 15664.03552270859],
+            [1.07e-09, 4.67919356465, 77690.75950573849],
+            [1.18e-09, 4.5232017012, 19004.6479494084],
+            [1.07e-09, 5.71774478555, 77736.78343050249],
+            [1.43e-09, 1.81201813018, 4214.0690150848],
+            [1.25e-09, 1.14419195615, 625.6701923124],
+            [1.24e-09, 3.27736514057, 12566.08438968],
+            [1.1e-09, 1.08682570828, 2787.0430238574],
+            [1.05e-09, 1.78318141871, 18139.2945014159],
+            [1.02e-09, 4.75119578149, 12242.6462833254],
+            [1.37e-09, 1.43510636754, 86464.6133168312],
+            [1.01e-09, 4.91289409429, 401.6721217572],
+            [1.29e-09, 1.23567904485, 12029.3471878874],
+            [1.38e-09, 2.45654707999, 7576.560073574],
+            [1.03e-09, 0.40004073416, 90279.92316810328],
+            [1.08e-09, 0.9898977494, 5636.0650166766],
+            [1.17e-09, 5.17362872063, 34520.3093093808],
+            [1e-09, 3.95534628189, 5547.1993364596],
+            [9.8e-10, 1.28118280598, 21548.9623692918],
+            [9.7e-10, 3.34717130592, 16310.9790457206],
+            [9.8e-10, 4.37041908717, 34513.2630726828],
+            [1.25e-09, 2.7216443296, 24065.80792277559],
+            [1.02e-09, 0.66938025772, 10239.5838660108],
+            [1.19e-09, 1.21689479331, 1478.8665740644],
+            [9.4e-10, 1.99595224256, 13362.4497067992],
+            [9.4e-10, 4.30965982872, 26880.3198130326],
+            [9.5e-10, 2.89807657534, 34911.412076091],
+            [1.06e-09, 1.0015665359, 16522.6597160022],
+            [9.7e-10, 0.89642320201, 71980.63357473118],
+            [1.16e-09, 4.19967201116, 206.7007372966],
+            [9.9e-10, 1.37437847718, 1039.0266107904],
+            [1.26e-09, 3.21642544972, 305281.9430710488],
+            [9.4e-10, 0.6899787606, 7834.1210726394],
+            [9.4e-10, 5.58132218606, 3104.9300594238],
+            [9.5e-10, 3.0382374111, 8982.810669309],
+            [1.08e-09, 0.52696637156, 276.7457718644],
+            [1.24e-09, 3.43899862683, 172146.9713405403],
+            [1.02e-09, 1.04031728553, 95143.1329209781],
+            [1.04e-09, 3.39218586218, 290.972865866],
+            [1.1e-09, 3.68205877433, 22380.755800274],
+            [1.17e-09, 0.78475956902, 83286.91426955358],
+            [8.3e-10, 0.18241793425, 15141.390794312],
+            [8.9e-10, 4.45371820659, 792.7748884674],
+            [8.2e-10, 4.80703651241, 6819.8803620868],
+            [8.7e-10, 3.43122851097, 27707.5424942948],
+            [1.01e-09, 5.32081603011, 2301.58581590939],
+            [8.2e-10, 0.87060089842, 10241.2022911672],
+            [8.6e-10, 4.61919461931, 36147.4098773004],
+            [9.5e-10, 2.87032884659, 23020.65308658799],
+            [8.8e-10, 3.2113316569, 33326.5787331742],
+            [8e-10, 1.84900424847, 21424.4666443034],
+            [1.01e-09, 4.18796434479, 30666.1549584328],
+            [1.07e-09, 5.77864921649, 34115.1140692746],
+            [1.04e-09, 1.08739495962, 6288.5987742988],
+            [1.1e-09, 3.32898859416, 72140.6286666874],
+            [8.7e-10, 4.40657711727, 142.1786270362],
+            [1.09e-09, 1.94546030825, 24279.10701821359],
+            [8.7e-10, 4.32472045435, 742.9900605326],
+            [1.07e-09, 4.91580912547, 277.0349937414],
+            [8.8e-10, 2.10180220766, 26482.1708096244],
+            [8.6e-10, 4.01887374432, 12491.3701014155],
+            [1.06e-09, 5.49092372854, 62883.3551395136],
+            [8e-10, 6.19781316983, 6709.6740408674],
+            [8.8e-10, 2.09872810657, 238004.5241572363],
+            [8.3e-10, 4.90662164029, 51.28033786241],
+            [9.5e-10, 4.13387406591, 18216.443810661],
+            [7.8e-10, 6.0694939168, 148434.53403769128],
+            [7.9e-10, 3.03048221644, 838.9692877504],
+            [7.4e-10, 5.49813051211, 29026.48522950779],
+            [7.3e-10, 3.05008665738, 567.7186377304],
+            [8.4e-10, 0.46604373274, 45.1412196366],
+            [9.3e-10, 2.52267536308, 48739.859897083],
+            [7.6e-10, 1.76418124905, 41654.9631159678],
+            [6.7e-10, 5.77851227793, 6311.5250374592],
+            [6.2e-10, 3.32967880172, 15508.6151232744],
+            [7.9e-10, 5.59773841328, 71960.38658322369],
+            [5.7e-10, 3.90629505268, 5999.2165311262],
+            [6.1e-10, 0.05695043232, 7856.89627409019],
+            [6.1e-10, 5.63297958433, 7863.9425107882],
+            [6.5e-10, 3.72178394016, 12573.2652469836],
+            [5.7e-10, 4.18217219541, 26087.9031415742],
+            [6.6e-10, 3.92262333487, 69853.3520756813],
+            [5.3e-10, 5.51119362045, 77710.24834977149],
+            [5.3e-10, 4.88573986961, 77717.29458646949],
+            [6.2e-10, 2.88876342225, 9411.4646150872],
+            [5.1e-10, 1.12657183874, 82576.9812209953],
+            [4.5e-10, 2.95671076719, 24602.61243487099],
+            [4e-10, 5.55145719241, 12565.1713789146],
+            [3.9e-10, 1.20838190039, 18842.11400297339],
+            [4.5e-10, 3.18590558749, 45585.1728121874],
+            [4.9e-10, 2.44790934886, 13613.804277336],
         ],
-        vec![
-            vec![
-                [2.7962e-06, 3.19870156017, 84334.66158130829],
-                [1.01643e-06, 5.42248619256, 5507.5532386674],
-                [8.0445e-07, 3.88013204458, 5223.6939198022],
-                [4.3806e-07, 3.70444689758, 2352.8661537718],
-                [3.1933e-07, 4.00026369781, 1577.3435424478],
-                [2.2724e-07, 3.9847383156, 1047.7473117547],
-                [1.6392e-07, 3.56456119782, 5856.4776591154],
-                [1.8141e-07, 4.98367470263, 6283.0758499914],
-                [1.4443e-07, 3.70275614914, 9437.762934887],
-                [1.4304e-07, 3.41117857525, 10213.285546211],
-                [1.1246e-07, 4.8282069053, 14143.4952424306],
-                [1.09e-07, 2.08574562327, 6812.766815086],
-                [9.714e-08, 3.47303947752, 4694.0029547076],
-                [1.0367e-07, 4.05663927946, 71092.88135493269],
-                [8.775e-08, 4.44016515669, 5753.3848848968],
-                [8.366e-08, 4.9925151218, 7084.8967811152],
-                [6.921e-08, 4.32559054073, 6275.9623029906],
-                [9.145e-08, 1.14182646613, 6620.8901131878],
-                [7.194e-08, 3.60193205752, 529.6909650946],
-                [7.698e-08, 5.55425745881, 167621.5758508619],
-                [5.285e-08, 2.48446991566, 4705.7323075436],
-                [5.208e-08, 6.24992674537, 18073.7049386502],
-                [4.529e-08, 2.33827747356, 6309.3741697912],
-                [5.579e-08, 4.41023653738, 7860.4193924392],
-                [4.743e-08, 0.70995680136, 5884.9268465832],
-                [4.301e-08, 1.10255777773, 6681.2248533996],
-                [3.849e-08, 1.82229412531, 5486.777843175],
-                [4.093e-08, 5.11700141207, 13367.9726311066],
-                [3.681e-08, 0.43793170356, 3154.6870848956],
-                [3.42e-08, 5.42034800952, 6069.7767545534],
-                [3.617e-08, 6.04641937526, 3930.2096962196],
-                [3.67e-08, 4.58210192227, 12194.0329146209],
-                [2.918e-08, 1.95463881126, 10977.078804699],
-                [2.797e-08, 5.61259275048, 11790.6290886588],
-                [2.502e-08, 0.60499729367, 6496.3749454294],
-                [2.319e-08, 5.01648216014, 1059.3819301892],
-                [2.684e-08, 1.39470396488, 22003.9146348698],
-                [2.428e-08, 3.24183056052, 78051.5857313169],
-                [2.12e-08, 4.30691000285, 5643.1785636774],
-                [2.257e-08, 3.15557225618, 90617.7374312997],
-                [1.813e-08, 3.75574218285, 3340.6124266998],
-                [2.226e-08, 2.79699346659, 12036.4607348882],
-                [1.888e-08, 0.86991545823, 8635.9420037632],
-                [1.517e-08, 1.95852055701, 398.1490034082],
-                [1.581e-08, 3.19976230948, 5088.6288397668],
-                [1.421e-08, 6.25530883827, 2544.3144198834],
-                [1.595e-08, 0.25619915135, 17298.1823273262],
-                [1.391e-08, 4.69964175561, 7058.5984613154],
-                [1.478e-08, 2.81808207569, 25934.1243310894],
-                [1.481e-08, 3.65823554806, 11506.7697697936],
-                [1.693e-08, 4.95689385293, 156475.2902479957],
-                [1.183e-08, 1.29343061246, 775.522611324],
-                [1.114e-08, 2.37889311846, 3738.761430108],
-                [9.94e-09, 4.30088900425, 9225.539273283],
-                [9.24e-09, 3.06451026812, 4164.311989613],
-                [8.67e-09, 0.55606931068, 8429.2412664666],
-                [9.88e-09, 5.97286104208, 7079.3738568078],
-                [8.24e-09, 1.50984806173, 10447.3878396044],
-                [9.15e-09, 0.12635654592, 11015.1064773348],
-                [7.42e-09, 1.99159139281, 26087.9031415742],
-                [1.039e-08, 3.14159265359, 0.0],
-                [8.5e-09, 4.24120016095, 29864.334027309],
-                [7.55e-09, 2.8963187332, 4732.0306273434],
-                [7.14e-09, 1.37548118603, 2146.1654164752],
-                [7.08e-09, 1.91406542362, 8031.0922630584],
-                [7.46e-09, 0.57893808616, 796.2980068164],
-                [8.02e-09, 5.1233913723, 2942.4634232916],
-                [7.51e-09, 1.67479850166, 21228.3920235458],
-                [6.02e-09, 4.09976538826, 64809.80550494129],
-                [5.94e-09, 3.49580704962, 16496.3613962024],
-                [5.92e-09, 4.59481504319, 4690.4798363586],
-                [5.3e-09, 5.739792952, 8827.3902698748],
-                [5.03e-09, 5.66433137112, 33794.5437235286],
-                [4.83e-09, 1.57106522411, 801.8209311238],
-                [4.38e-09, 0.06707733767, 3128.3887650958],
-                [4.23e-09, 2.86944595927, 12566.1516999828],
-                [5.04e-09, 3.2620766916, 7632.9432596502],
-                [5.52e-09, 1.02926440457, 239762.20451754928],
-                [4.27e-09, 3.6743437821, 213.299095438],
-                [4.04e-09, 1.46193297142, 15720.8387848784],
-                [5.03e-09, 4.85802444134, 6290.1893969922],
-                [4.17e-09, 0.81920713533, 5216.5803728014],
-                [3.65e-09, 0.01002966162, 12168.0026965746],
-                [3.63e-09, 1.28376436579, 6206.8097787158],
-                [3.53e-09, 4.7005913311, 7234.794256242],
-                [4.15e-09, 0.96862624175, 4136.9104335162],
-                [3.87e-09, 3.09145061418, 25158.6017197654],
-                [3.73e-09, 2.65119262792, 7342.4577801806],
-                [3.61e-09, 2.97762937739, 9623.6882766912],
-                [4.18e-09, 3.75759994446, 5230.807466803],
-                [3.96e-09, 1.22507712354, 6438.4962494256],
-                [3.22e-09, 1.21162178805, 8662.240323563],
-                [2.84e-09, 5.64170320068, 1589.0728952838],
-                [3.79e-09, 1.72248432748, 14945.3161735544],
-                [3.2e-09, 3.94161159962, 7330.8231617461],
-                [3.13e-09, 5.47602376446, 1194.4470102246],
-                [2.92e-09, 1.38971327603, 11769.8536931664],
-                [3.05e-09, 0.80429352049, 37724.7534197482],
-                [2.57e-09, 5.81382809757, 426.598190876],
-                [2.65e-09, 6.10358507671, 6836.6452528338],
-                [2.5e-09, 4.56452895547, 7477.522860216],
-                [2.66e-09, 2.62926282354, 7238.6755916],
-                [2.63e-09, 6.22089501237, 6133.5126528568],
-                [3.06e-09, 2.79682380531, 1748.016413067],
-                [2.36e-09, 2.46093023714, 11371.7046897582],
-                [3.16e-09, 1.62662805006, 250908.4901204155],
-                [2.16e-09, 3.68721275185, 5849.3641121146],
-                [2.3e-09, 0.36165162947, 5863.5912061162],
-                [2.33e-09, 5.03509933858, 20426.571092422],
-                [2e-09, 5.86073159059, 4535.0594369244],
-                [2.77e-09, 4.65400292395, 82239.1669577989],
-                [2.09e-09, 3.72323200804, 10973.55568635],
-                [1.99e-09, 5.05186622555, 5429.8794682394],
-                [2.56e-09, 2.4092327977, 19651.048481098],
-                [2.1e-09, 4.50691909144, 29088.811415985],
-                [1.81e-09, 6.00294783127, 4292.3308329504],
-                [2.49e-09, 0.12900984422, 154379.7956244863],
-                [2.09e-09, 3.87759458598, 17789.845619785],
-                [2.25e-09, 3.18339652605, 18875.525869774],
-                [1.91e-09, 4.53897489299, 18477.1087646123],
-                [1.72e-09, 2.09694183014, 13095.8426650774],
-                [1.82e-09, 3.161079435, 16730.4636895958],
-                [1.88e-09, 2.22746128596, 41654.9631159678],
-                [1.64e-09, 5.18686275017, 5481.2549188676],
-                [1.6e-09, 2.49298855159, 12592.4500197826],
-                [1.55e-09, 1.5959543823, 10021.8372800994],
-                [1.35e-09, 0.21349051064, 10988.808157535],
-                [1.78e-09, 3.8037517797, 23581.2581773176],
-                [1.23e-09, 1.66800739151, 15110.4661198662],
-                [1.22e-09, 2.72678272244, 18849.2275499742],
-                [1.26e-09, 1.1767551291, 14919.0178537546],
-                [1.42e-09, 3.95053441332, 337.8142631964],
-                [1.16e-09, 6.06340906229, 6709.6740408674],
-                [1.37e-09, 3.52143246757, 12139.5535091068],
-                [1.36e-09, 2.92179113542, 32217.2001810808],
-                [1.1e-09, 3.51203379263, 18052.9295431578],
-                [1.47e-09, 4.63371971408, 22805.7355659936],
-                [1.08e-09, 5.45280814878, 7.1135470008],
-                [1.48e-09, 0.65447253687, 95480.9471841745],
-                [1.19e-09, 5.92110458985, 33019.0211122046],
-                [1.1e-09, 5.34824206306, 639.897286314],
-                [1.06e-09, 3.71081682629, 14314.1681130498],
-                [1.39e-09, 6.17607198418, 24356.7807886416],
-                [1.18e-09, 5.5973871267, 161338.5000008705],
-                [1.17e-09, 3.6506527164, 45585.1728121874],
-                [1.27e-09, 4.74596574209, 49515.382508407],
-                [1.2e-09, 1.04211499785, 6915.8595893046],
-                [1.2e-09, 5.60638811846, 5650.2921106782],
-                [1.15e-09, 3.10668213289, 14712.317116458],
-                [9.9e-10, 0.69018940049, 12779.4507954208],
-                [9.7e-10, 1.07908724794, 9917.6968745098],
-                [9.3e-10, 2.62295197319, 17260.1546546904],
-                [9.9e-10, 4.45774681732, 4933.2084403326],
-                [1.23e-09, 1.37488922089, 28286.9904848612],
-                [1.21e-09, 5.19767249813, 27511.4678735372],
-                [1.05e-09, 0.87192267806, 77375.95720492408],
-                [8.7e-10, 3.9363781295, 17654.7805397496],
-                [1.22e-09, 2.2395606868, 83997.09113559539],
-                [8.7e-10, 4.18201600952, 22779.4372461938],
-                [1.04e-09, 4.59580877295, 1349.8674096588],
-                [1.02e-09, 2.83545248411, 12352.8526045448],
-                [1.02e-09, 3.97386522171, 10818.1352869158],
-                [1.01e-09, 4.32892825857, 36147.4098773004],
-                [9.4e-10, 5.00001709261, 150192.2143980043],
-                [7.7e-10, 3.97199369296, 1592.5960136328],
-                [1e-09, 6.07733097102, 26735.9452622132],
-                [8.6e-10, 5.2602963825, 28313.288804661],
-                [9.3e-10, 4.31900620254, 44809.6502008634],
-                [7.6e-10, 6.22743405935, 13521.7514415914],
-                [7.2e-10, 1.55820597747, 6256.7775301916],
-                [8.2e-10, 4.95202664555, 10575.4066829418],
-                [8.2e-10, 1.69647647075, 1990.745017041],
-                [7.5e-10, 2.29836095644, 3634.6210245184],
-                [7.5e-10, 2.66367876557, 16200.7727245012],
-                [8.7e-10, 0.26630214764, 31441.6775697568],
-                [7.7e-10, 2.25530954137, 5235.3285382367],
-                [7.6e-10, 1.09869730846, 12903.9659631792],
-                [5.8e-10, 4.28246138307, 12559.038152982],
-                [6.4e-10, 5.51112830114, 173904.65170085328],
-                [5.6e-10, 2.60133794851, 73188.3759784421],
-                [5.5e-10, 5.81483150022, 143233.51002162008],
-                [5.4e-10, 3.38482031504, 323049.11878710287],
-                [3.9e-10, 3.28500401343, 71768.50988132549],
-                [3.9e-10, 3.1123991069, 96900.81328129109],
-            ],
-            vec![
-                [9.03e-08, 3.8972906189, 5507.5532386674],
-                [6.177e-08, 1.73038850355, 5223.6939198022],
-                [3.8e-08, 5.24404145734, 2352.8661537718],
-                [2.834e-08, 2.4734503745, 1577.3435424478],
-                [1.817e-08, 0.41874743765, 6283.0758499914],
-                [1.499e-08, 1.83320979291, 5856.4776591154],
-                [1.466e-08, 5.69401926017, 5753.3848848968],
-                [1.301e-08, 2.18890066314, 9437.762934887],
-                [1.233e-08, 4.95222451476, 10213.285546211],
-                [1.021e-08, 0.12866660208, 7860.4193924392],
-                [9.82e-09, 0.09005453285, 14143.4952424306],
-                [8.65e-09, 1.73949953555, 3930.2096962196],
-                [5.81e-09, 2.26949174067, 5884.9268465832],
-                [5.24e-09, 5.65662503159, 529.6909650946],
-                [4.73e-09, 6.22750969242, 6309.3741697912],
-                [4.51e-09, 1.53288619213, 18073.7049386502],
-                [3.64e-09, 3.61614477374, 13367.9726311066],
-                [3.72e-09, 3.2247072132, 6275.9623029906],
-                [2.68e-09, 2.34341267879, 11790.6290886588],
-                [3.22e-09, 0.94084045832, 6069.7767545534],
-                [2.32e-09, 0.26781182579, 7058.5984613154],
-                [2.16e-09, 6.05952221329, 10977.078804699],
-                [2.32e-09, 2.93325646109, 22003.9146348698],
-                [2.04e-09, 3.86264841382, 6496.3749454294],
-                [2.02e-09, 2.81892511133, 15720.8387848784],
-                [1.85e-09, 4.93512381859, 12036.4607348882],
-                [2.2e-09, 3.99305643742, 6812.766815086],
-                [1.66e-09, 1.74970002999, 11506.7697697936],
-                [2.12e-09, 1.57166285369, 4694.0029547076],
-                [1.57e-09, 1.08259734788, 5643.1785636774],
-                [1.54e-09, 5.99434678412, 5486.777843175],
-                [1.44e-09, 5.23285656085, 78051.5857313169],
-                [1.44e-09, 1.16454655948, 90617.7374312997],
-                [1.37e-09, 2.67760436027, 6290.1893969922],
-                [1.8e-09, 2.06509026215, 7084.8967811152],
-                [1.21e-09, 5.90212574947, 9225.539273283],
-                [1.5e-09, 2.00175038718, 5230.807466803],
-                [1.49e-09, 5.06157254516, 17298.1823273262],
-                [1.18e-09, 5.39979058038, 3340.6124266998],
-                [1.61e-09, 3.32421999691, 6283.3196674749],
-                [1.21e-09, 4.36722193162, 19651.048481098],
-                [1.16e-09, 5.83462858507, 4705.7323075436],
-                [1.28e-09, 4.35489873365, 25934.1243310894],
-                [1.43e-09, 0.0, 0.0],
-                [1.09e-09, 2.52157834166, 6438.4962494256],
-                [9.9e-10, 2.70727488041, 5216.5803728014],
-                [1.03e-09, 0.93782340879, 8827.3902698748],
-                [8.2e-10, 4.2921468039, 8635.9420037632],
-                [7.9e-10, 2.24085737326, 1059.3819301892],
-                [9.7e-10, 5.50959692365, 29864.334027309],
-                [7.2e-10, 0.21891639822, 21228.3920235458],
-                [7.1e-10, 2.86755026812, 6681.2248533996],
-                [7.4e-10, 2.20184828895, 37724.7534197482],
-                [6.3e-10, 4.45586625948, 7079.3738568078],
-                [6.1e-10, 0.63918772258, 33794.5437235286],
-                [4.7e-10, 2.09070235724, 3128.3887650958],
-                [4.7e-10, 3.325438433, 26087.9031415742],
-                [4.9e-10, 1.60680905005, 6702.5604938666],
-                [5.7e-10, 0.11215813438, 29088.811415985],
-                [5.6e-10, 5.47982934911, 775.522611324],
-                [5e-10, 1.89396788463, 12139.5535091068],
-                [4.7e-10, 2.9721490724, 20426.571092422],
-                [4.1e-10, 5.5532939489, 11015.1064773348],
-                [4.1e-10, 5.91861144924, 23581.2581773176],
-                [4.5e-10, 4.95273290181, 5863.5912061162],
-                [5e-10, 3.62740835096, 41654.9631159678],
-                [3.7e-10, 6.09033460601, 64809.80550494129],
-                [3.7e-10, 5.86153655431, 12566.1516999828],
-                [4.6e-10, 1.65798680284, 25158.6017197654],
-                [3.8e-10, 2.00673650251, 426.598190876],
-                [3.6e-10, 6.24373396652, 6283.14316029419],
-                [3.6e-10, 0.40465162918, 6283.0085396886],
-                [3.2e-10, 6.03707103538, 2942.4634232916],
-                [4.1e-10, 4.86809570283, 1592.5960136328],
-                [2.8e-10, 4.38359423735, 7632.9432596502],
-                [2.8e-10, 6.03334294232, 17789.845619785],
-                [2.6e-10, 3.88971333608, 5331.3574437408],
-                [2.6e-10, 5.94932724051, 16496.3613962024],
-                [3.1e-10, 1.44666331503, 16730.4636895958],
-                [2.6e-10, 6.26376705837, 23543.23050468179],
-                [3.3e-10, 0.93797239147, 213.299095438],
-                [2.6e-10, 3.71858432944, 13095.8426650774],
-                [2.7e-10, 0.60565274405, 10988.808157535],
-                [2.3e-10, 4.4438898555, 18849.2275499742],
-                [2.8e-10, 1.53862289477, 6279.4854213396],
-                [2.8e-10, 1.96831814872, 6286.6662786432],
-                [2.8e-10, 5.78094918529, 15110.4661198662],
-                [2.6e-10, 2.48165809843, 5729.506447149],
-                [2e-10, 3.85655029499, 9623.6882766912],
-                [2.1e-10, 5.83006047147, 7234.794256242],
-                [2.1e-10, 0.69628570421, 398.1490034082],
-                [2.2e-10, 5.02222806555, 6127.6554505572],
-                [2e-10, 3.4761126529, 6148.010769956],
-                [2e-10, 0.90769829044, 5481.2549188676],
-                [2e-10, 0.03081589303, 6418.1409300268],
-                [2e-10, 3.74220084927, 1589.0728952838],
-                [2.1e-10, 4.00149269576, 3154.6870848956],
-                [1.8e-10, 1.58348238359, 2118.7638603784],
-                [1.9e-10, 0.85407021371, 14712.317116458],
-            ],
-            vec![
-                [1.662e-08, 1.62703209173, 84334.66158130829],
-                [4.92e-09, 2.41382223971, 1047.7473117547],
-                [3.44e-09, 2.24353004539, 5507.5532386674],
-                [2.58e-09, 6.00906896311, 5223.6939198022],
-                [1.31e-09, 0.9544734524, 6283.0758499914],
-                [8.6e-10, 1.67530247303, 7860.4193924392],
-                [9e-10, 0.97606804452, 1577.3435424478],
-                [9e-10, 0.37899871725, 2352.8661537718],
-                [8.9e-10, 6.25807507963, 10213.285546211],
-                [7.5e-10, 0.84213523741, 167621.5758508619],
-                [5.2e-10, 1.70501566089, 14143.4952424306],
-                [5.7e-10, 6.15295833679, 12194.0329146209],
-                [5.1e-10, 1.2761601674, 5753.3848848968],
-                [5.1e-10, 5.37229738682, 6812.766815086],
-                [3.4e-10, 1.73672994279, 7058.5984613154],
-                [3.8e-10, 2.77761031485, 10988.808157535],
-                [4.6e-10, 3.38617099014, 156475.2902479957],
-                [2.1e-10, 1.95248349228, 8827.3902698748],
-                [1.8e-10, 3.33419222028, 8429.2412664666],
-                [1.9e-10, 4.32945160287, 17789.845619785],
-                [1.7e-10, 0.66191210656, 6283.0085396886],
-                [1.8e-10, 3.74885333072, 11769.8536931664],
-                [1.7e-10, 4.23058370776, 10977.078804699],
-                [1.7e-10, 1.78116162721, 5486.777843175],
-                [2.1e-10, 1.36972913918, 12036.4607348882],
-                [1.7e-10, 2.79601092529, 796.2980068164],
-                [1.5e-10, 0.4308784885, 11790.6290886588],
-                [1.7e-10, 1.35132152761, 78051.5857313169],
-                [1.5e-10, 1.17032155085, 213.299095438],
-                [1.8e-10, 2.85221514199, 5088.6288397668],
-                [1.7e-10, 0.21780913672, 6283.14316029419],
-                [1.3e-10, 1.21201504386, 25132.3033999656],
-                [1.2e-10, 1.12953712197, 90617.7374312997],
-                [1.2e-10, 5.13714452592, 7079.3738568078],
-                [1.3e-10, 3.79842135217, 4933.2084403326],
-                [1.2e-10, 4.89407978213, 3738.761430108],
-                [1.5e-10, 6.05682328852, 398.1490034082],
-                [1.4e-10, 4.81029291856, 4694.0029547076],
-                [1.1e-10, 0.61684523405, 3128.3887650958],
-                [1.1e-10, 5.328765385, 6040.3472460174],
-                [1.4e-10, 5.27227350286, 4535.0594369244],
-                [1.1e-10, 2.39292099451, 5331.3574437408],
-                [1e-10, 4.4529653271, 6525.8044539654],
-                [1.4e-10, 4.66400985037, 8031.0922630584],
-                [1e-10, 3.22472385926, 9437.762934887],
-                [1.1e-10, 3.80913404437, 801.8209311238],
-                [1e-10, 5.15032130575, 11371.7046897582],
-                [1.3e-10, 0.98720797401, 5729.506447149],
-                [9e-11, 5.94191743597, 7632.9432596502],
-            ],
-            vec![
-                [1.1e-10, 0.23877262399, 7860.4193924392],
-                [9e-11, 1.16069982609, 5507.5532386674],
-                [8e-11, 1.65357552925, 5884.9268465832],
-                [8e-11, 2.86720038197, 7058.5984613154],
-                [7e-11, 3.04818741666, 5486.777843175],
-                [7e-11, 2.59437103785, 529.6909650946],
-                [8e-11, 4.02863090524, 6256.7775301916],
-                [8e-11, 2.42003508927, 5753.3848848968],
-                [6e-11, 0.84181087594, 6275.9623029906],
-                [6e-11, 5.40160929468, 1577.3435424478],
-                [7e-11, 2.73399865247, 6309.3741697912],
-            ],
-            vec![
-                [4e-11, 0.79662198849, 6438.4962494256],
-                [5e-11, 0.84308705203, 1047.7473117547],
-                [5e-11, 0.05711572303, 84334.66158130829],
-                [3e-11, 3.46779895686, 6279.5527316424],
-                [3e-11, 2.89822201212, 6127.6554505572],
-            ],
+        &[
+            [6283.31966747491, 0.0, 0.0],
+            [0.00206058863, 2.67823455584, 6283.0758499914],
+            [4.30343e-05, 2.63512650414, 12566.1516999828],
+            [4.25264e-06, 1.59046980729, 3.523118349],
+            [1.08977e-06, 2.96618001993, 1577.3435424478],
+            [9.3478e-07, 2.59212835365, 18849.2275499742],
+            [1.19261e-06, 5.79557487799, 26.2983197998],
+            [7.2122e-07, 1.13846158196, 529.6909650946],
+            [6.7768e-07, 1.87472304791, 398.1490034082],
+            [6.7327e-07, 4.40918235168, 5507.5532386674],
+            [5.9027e-07, 2.8879703846, 5223.6939198022],
+            [5.5976e-07, 2.17471680261, 155.4203994342],
+            [4.5407e-07, 0.39803079805, 796.2980068164],
+            [3.6369e-07, 0.46624739835, 775.522611324],
+            [2.8958e-07, 2.64707383882, 7.1135470008],
+            [1.9097e-07, 1.84628332577, 5486.777843175],
+            [2.0844e-07, 5.34138275149, 0.9803210682],
+            [1.8508e-07, 4.96855124577, 213.299095438],
+            [1.6233e-07, 0.03216483047, 2544.3144198834],
+            [1.7293e-07, 2.99116864949, 6275.9623029906],
+            [1.5832e-07, 1.43049285325, 2146.1654164752],
+            [1.4615e-07, 1.20532366323, 10977.078804699],
+            [1.1877e-07, 3.25804815607, 5088.6288397668],
+            [1.1514e-07, 2.07502418155, 4694.0029547076],
+            [9.721e-08, 4.23925472239, 1349.8674096588],
+            [9.969e-08, 1.30262991097, 6286.5989683404],
+            [9.452e-08, 2.69957062864, 242.728603974],
+            [1.2461e-07, 2.83432285512, 1748.016413067],
+            [1.1808e-07, 5.2737979048, 1194.4470102246],
+            [8.577e-08, 5.64475868067, 951.7184062506],
+            [1.0641e-07, 0.76614199202, 553.5694028424],
+            [7.576e-08, 5.30062664886, 2352.8661537718],
+            [5.834e-08, 1.76649917904, 1059.3819301892],
+            [6.385e-08, 2.65033984967, 9437.762934887],
+            [5.223e-08, 5.66135767624, 71430.69561812909],
+            [5.305e-08, 0.90857521574, 3154.6870848956],
+            [6.101e-08, 4.66632584188, 4690.4798363586],
+            [4.33e-08, 0.24102555403, 6812.766815086],
+            [5.041e-08, 1.42490103709, 6438.4962494256],
+            [4.259e-08, 0.77355900599, 10447.3878396044],
+            [5.198e-08, 1.85353197345, 801.8209311238],
+            [3.744e-08, 2.00119516488, 8031.0922630584],
+            [3.558e-08, 2.42901552681, 14143.4952424306],
+            [3.372e-08, 3.86210700128, 1592.5960136328],
+            [3.374e-08, 0.88776219727, 12036.4607348882],
+            [3.175e-08, 3.18785710594, 4705.7323075436],
+            [3.221e-08, 0.61599835472, 8429.2412664666],
+            [4.132e-08, 5.23992859705, 7084.8967811152],
+            [2.97e-08, 6.07026318493, 4292.3308329504],
+            [2.9e-08, 2.32464208411, 20.3553193988],
+            [3.504e-08, 4.79975694359, 6279.5527316424],
+            [2.95e-08, 1.43108874817, 5746.271337896],
+            [2.697e-08, 4.80368225199, 7234.794256242],
+            [2.531e-08, 6.22290682655, 6836.6452528338],
+            [2.745e-08, 0.93466065396, 5760.4984318976],
+            [3.25e-08, 3.39954640038, 7632.9432596502],
+            [2.277e-08, 5.00277837672, 17789.845619785],
+            [2.075e-08, 3.95534978634, 10213.285546211],
+            [2.061e-08, 2.22411683077, 5856.4776591154],
+            [2.252e-08, 5.67166499885, 11499.6562227928],
+            [2.148e-08, 5.20184578235, 11513.8833167944],
+            [1.886e-08, 0.53198320577, 3340.6124266998],
+            [1.875e-08, 4.73511970207, 83996.84731811189],
+            [2.06e-08, 2.54987293999, 25132.3033999656],
+            [1.794e-08, 1.47435409831, 4164.311989613],
+            [1.778e-08, 3.02473091781, 5.5229243074],
+            [2.029e-08, 0.90960209983, 6256.7775301916],
+            [2.075e-08, 2.26767270157, 522.5774180938],
+            [1.772e-08, 3.02622802353, 5753.3848848968],
+            [1.569e-08, 6.12410242782, 5216.5803728014],
+            [1.59e-08, 4.63713748247, 3.2863574178],
+            [1.542e-08, 4.20004448567, 13367.9726311066],
+            [1.427e-08, 1.19088061711, 3894.1818295422],
+            [1.375e-08, 3.09301252193, 135.0650800354],
+            [1.359e-08, 4.24532506641, 426.598190876],
+            [1.34e-08, 5.76511818622, 6040.3472460174],
+            [1.284e-08, 3.08524663344, 5643.1785636774],
+            [1.25e-08, 3.07748157144, 11926.2544136688],
+            [1.551e-08, 3.07665451458, 6681.2248533996],
+            [1.268e-08, 2.09196018331, 6290.1893969922],
+            [1.144e-08, 3.24444699514, 12168.0026965746],
+            [1.248e-08, 3.44504937285, 536.8045120954],
+            [1.118e-08, 2.31829670425, 16730.4636895958],
+            [1.105e-08, 5.31966001019, 23.8784377478],
+            [1.051e-08, 3.75015946014, 7860.4193924392],
+            [1.025e-08, 2.44688534235, 1990.745017041],
+            [9.62e-09, 0.81771017882, 3.881335358],
+            [9.1e-09, 0.41727865299, 7079.3738568078],
+            [8.83e-09, 5.16833917651, 11790.6290886588],
+            [9.57e-09, 4.07673573735, 6127.6554505572],
+            [1.11e-08, 3.90096793825, 11506.7697697936],
+            [8.02e-09, 3.88778875582, 10973.55568635],
+            [7.8e-09, 2.39934293755, 1589.0728952838],
+            [7.58e-09, 1.30034364248, 103.0927742186],
+            [7.49e-09, 4.962758033, 6496.3749454294],
+            [7.65e-09, 3.36312388424, 36.0278666774],
+            [9.15e-09, 5.41543742089, 206.1855484372],
+            [7.76e-09, 2.57589093871, 11371.7046897582],
+            [7.72e-09, 3.98369209464, 955.5997416086],
+            [7.49e-09, 5.17890001805, 10969.9652576982],
+            [8.06e-09, 0.34218864254, 9917.6968745098],
+            [7.28e-09, 5.20962563787, 38.0276726358],
+            [6.85e-09, 2.77592961854, 20.7753954924],
+            [6.36e-09, 4.28242193632, 28.4491874678],
+            [6.08e-09, 5.63278508906, 10984.1923516998],
+            [7.04e-09, 5.60738823665, 3738.761430108],
+            [6.85e-09, 0.38876148682, 15.252471185],
+            [6.01e-09, 0.73489602442, 419.4846438752],
+            [7.16e-09, 2.65279791438, 6309.3741697912],
+            [5.84e-09, 5.54502568227, 17298.1823273262],
+            [6.5e-09, 1.13379656406, 7058.5984613154],
+            [6.88e-09, 2.59683891779, 3496.032826134],
+            [4.85e-09, 0.44467180946, 12352.8526045448],
+            [5.28e-09, 2.74936967681, 3930.2096962196],
+            [5.97e-09, 5.27668281777, 10575.4066829418],
+            [5.83e-09, 3.1892906781, 4732.0306273434],
+            [5.26e-09, 5.01697321546, 5884.9268465832],
+            [5.4e-09, 1.29175137075, 640.8776073822],
+            [4.73e-09, 5.4995330697, 5230.807466803],
+            [4.06e-09, 5.21248452189, 220.4126424388],
+            [3.95e-09, 1.87474483222, 16200.7727245012],
+            [3.7e-09, 3.84921354713, 18073.7049386502],
+            [3.67e-09, 0.88533542778, 6283.14316029419],
+            [3.79e-09, 0.37983009325, 10177.2576795336],
+            [3.56e-09, 3.84145204913, 11712.9553182308],
+            [3.74e-09, 5.01577520608, 7.046236698],
+            [3.81e-09, 4.30250406634, 6062.6632075526],
+            [4.71e-09, 0.86381834647, 6069.7767545534],
+            [3.67e-09, 1.32943839763, 6283.0085396886],
+            [4.6e-09, 5.19667219575, 6284.0561710596],
+            [3.33e-09, 5.54256205741, 4686.8894077068],
+            [3.41e-09, 4.36522989934, 7238.6755916],
+            [3.36e-09, 4.00205876835, 3097.88382272579],
+            [3.59e-09, 6.22679790284, 245.8316462294],
+            [3.07e-09, 2.35299010924, 170.6728706192],
+            [3.43e-09, 3.77164927143, 6076.8903015542],
+            [2.96e-09, 5.44152227481, 17260.1546546904],
+            [3.28e-09, 0.13837875384, 11015.1064773348],
+            [2.68e-09, 1.1390455063, 12569.6748183318],
+            [2.63e-09, 0.00538633678, 4136.9104335162],
+            [2.82e-09, 5.0439983748, 7477.522860216],
+            [2.88e-09, 3.13401177517, 12559.038152982],
+            [2.59e-09, 0.93882269387, 5642.1982426092],
+            [2.92e-09, 1.98420020514, 12132.439962106],
+            [2.47e-09, 3.84244798532, 5429.8794682394],
+            [2.45e-09, 5.70467521726, 65147.6197681377],
+            [2.41e-09, 0.99480969552, 3634.6210245184],
+            [2.46e-09, 3.06168069935, 110.2063212194],
+            [2.39e-09, 6.11855909114, 11856.2186514245],
+            [2.63e-09, 0.66348415419, 21228.3920235458],
+            [2.62e-09, 1.51070507866, 12146.6670561076],
+            [2.3e-09, 1.75927314884, 9779.1086761254],
+            [2.23e-09, 2.00967043606, 6172.869528772],
+            [2.46e-09, 1.10411690865, 6282.0955289232],
+            [2.21e-09, 3.03945240854, 8635.9420037632],
+            [2.14e-09, 4.03840869663, 14314.1681130498],
+            [2.36e-09, 5.4691507058, 13916.0191096416],
+            [2.24e-09, 4.68408089456, 24072.9214697764],
+            [2.12e-09, 2.13695625494, 5849.3641121146],
+            [2.07e-09, 3.07724246401, 11.729352836],
+            [2.07e-09, 6.10306282747, 23543.23050468179],
+            [2.66e-09, 1.00709566823, 2388.8940204492],
+            [2.17e-09, 6.27837036335, 17267.26820169119],
+            [2.04e-09, 2.34615348695, 266.6070417218],
+            [1.95e-09, 5.55015549753, 6133.5126528568],
+            [1.88e-09, 2.52667166175, 6525.8044539654],
+            [1.85e-09, 0.90960768344, 18319.5365848796],
+            [1.77e-09, 1.73429218289, 154717.6098876827],
+            [1.87e-09, 4.76483647432, 4535.0594369244],
+            [1.86e-09, 4.63080493407, 10440.2742926036],
+            [2.15e-09, 2.8125545456, 7342.4577801806],
+            [1.72e-09, 1.45551888559, 9225.539273283],
+            [1.62e-09, 3.30661909388, 639.897286314],
+            [1.68e-09, 2.17671416605, 27.4015560968],
+            [1.6e-09, 1.68164180475, 15110.4661198662],
+            [1.58e-09, 0.13519771874, 13095.8426650774],
+            [1.83e-09, 0.56281322071, 13517.8701062334],
+            [1.79e-09, 3.58450811616, 87.30820453981],
+            [1.52e-09, 2.84070476818, 5650.2921106782],
+            [1.82e-09, 0.44065530624, 17253.04110768959],
+            [1.6e-09, 5.95767264171, 4701.1165017084],
+            [1.42e-09, 1.4629013752, 11087.2851259184],
+            [1.42e-09, 2.04464036087, 20426.571092422],
+            [1.31e-09, 5.40912137746, 2699.7348193176],
+            [1.44e-09, 2.07312090485, 25158.6017197654],
+            [1.47e-09, 6.15106982168, 9623.6882766912],
+            [1.41e-09, 5.55739979498, 10454.5013866052],
+            [1.35e-09, 0.06098110407, 16723.350142595],
+            [1.24e-09, 5.81218025669, 17256.6315363414],
+            [1.24e-09, 2.36293551623, 4933.2084403326],
+            [1.26e-09, 3.47435905118, 22483.84857449259],
+            [1.59e-09, 5.63954754618, 5729.506447149],
+            [1.23e-09, 3.92815963256, 17996.0311682222],
+            [1.48e-09, 3.02509280598, 1551.045222648],
+            [1.2e-09, 5.91904349732, 6206.8097787158],
+            [1.34e-09, 3.11122937825, 21954.15760939799],
+            [1.19e-09, 5.5214112345, 709.9330485583],
+            [1.22e-09, 3.00813429479, 19800.9459562248],
+            [1.27e-09, 1.37618620001, 14945.3161735544],
+            [1.41e-09, 2.56889468729, 1052.2683831884],
+            [1.23e-09, 2.83671175442, 11919.140866668],
+            [1.18e-09, 0.81934438215, 5331.3574437408],
+            [1.51e-09, 2.68731829165, 11769.8536931664],
+            [1.19e-09, 5.08835797638, 5481.2549188676],
+            [1.53e-09, 2.46021790779, 11933.3679606696],
+            [1.08e-09, 1.04936452145, 11403.676995575],
+            [1.28e-09, 0.99794735107, 8827.3902698748],
+            [1.44e-09, 2.54869747042, 227.476132789],
+            [1.5e-09, 4.50631437136, 2379.1644735716],
+            [1.07e-09, 1.79272017026, 13119.72110282519],
+            [1.07e-09, 4.43556814486, 18422.62935909819],
+            [1.09e-09, 0.29269062317, 16737.5772365966],
+            [1.41e-09, 3.18979826258, 6262.300454499],
+            [1.22e-09, 4.23040027813, 29.429508536],
+            [1.11e-09, 5.16954029551, 17782.7320727842],
+            [1e-09, 3.52213872761, 18052.9295431578],
+            [1.08e-09, 1.08514212991, 16858.4825329332],
+            [1.06e-09, 1.9608524841, 74.7815985673],
+            [1.1e-09, 2.30582372873, 16460.33352952499],
+            [9.7e-10, 3.5091894021, 5333.9002410216],
+            [9.9e-10, 3.56417337974, 735.8765135318],
+            [9.4e-10, 5.01857894228, 3128.3887650958],
+            [9.7e-10, 1.65579893894, 533.2140834436],
+            [9.2e-10, 0.89217162285, 29296.6153895786],
+            [1.23e-09, 3.16062050433, 9380.9596727172],
+            [1.02e-09, 1.20493500565, 23020.65308658799],
+            [8.8e-10, 2.21296088224, 12721.572099417],
+            [8.9e-10, 1.5426472031, 20199.094959633],
+            [1.13e-09, 4.8332070787, 16496.3613962024],
+            [1.21e-09, 6.19860353182, 9388.0059094152],
+            [8.9e-10, 4.08082274765, 22805.7355659936],
+            [9.8e-10, 1.0918183283, 12043.574281889],
+            [8.6e-10, 1.13655027605, 143571.32428481648],
+            [8.8e-10, 5.96980472191, 107.6635239386],
+            [8.2e-10, 5.01340404594, 22003.9146348698],
+            [9.4e-10, 1.69615700473, 23006.42599258639],
+            [8.1e-10, 3.00657814365, 2118.7638603784],
+            [9.8e-10, 1.39215287161, 8662.240323563],
+            [7.7e-10, 3.3355519084, 15720.8387848784],
+            [8.2e-10, 5.86880116464, 2787.0430238574],
+            [7.6e-10, 5.67183650604, 14.2270940016],
+            [8.1e-10, 6.16619455699, 1039.0266107904],
+            [7.6e-10, 3.21449884756, 111.1866422876],
+            [7.8e-10, 1.37531518377, 21947.1113727],
+            [7.4e-10, 3.58814195051, 11609.8625440122],
+            [7.7e-10, 4.84846488388, 22743.4093795164],
+            [9e-10, 1.48869013606, 15671.0817594066],
+            [8.2e-10, 3.48618399109, 29088.811415985],
+            [6.9e-10, 3.55746476593, 4590.910180489],
+            [6.9e-10, 1.93625656075, 135.62532501],
+            [7e-10, 2.66548322237, 18875.525869774],
+            [6.9e-10, 5.41478093731, 26735.9452622132],
+            [7.9e-10, 5.15154513662, 12323.4230960088],
+            [9.4e-10, 3.62899392448, 77713.7714681205],
+            [7.8e-10, 4.17011182047, 1066.49547719],
+            [7.1e-10, 3.89435637865, 22779.4372461938],
+            [6.3e-10, 4.53968787714, 8982.810669309],
+            [6.9e-10, 0.96028230548, 14919.0178537546],
+            [7.6e-10, 3.29092216589, 2942.4634232916],
+            [6.3e-10, 4.09167842893, 16062.1845261168],
+            [6.5e-10, 3.34580407184, 51.28033786241],
+            [6.5e-10, 5.75757544877, 52670.0695933026],
+            [6.8e-10, 5.75884067555, 21424.4666443034],
+            [5.7e-10, 5.4512239985, 12592.4500197826],
+            [5.7e-10, 5.25043362558, 20995.3929664494],
+            [7.3e-10, 0.53299090807, 2301.58581590939],
+            [7e-10, 4.31243357502, 19402.7969528166],
+            [6.7e-10, 2.53852336668, 377.3736079158],
+            [5.6e-10, 3.20816844695, 24889.5747959916],
+            [5.3e-10, 3.17816599142, 18451.07854656599],
+            [5.3e-10, 3.61529270216, 77.673770428],
+            [5.3e-10, 0.45467549335, 30666.1549584328],
+            [6.1e-10, 0.14807288453, 23013.5395395872],
+            [5.1e-10, 3.32803972907, 56.8983749356],
+            [5.2e-10, 3.41177624177, 23141.5583829246],
+            [5.8e-10, 3.13638677202, 309.2783226558],
+            [7e-10, 2.50592323465, 31415.379249957],
+            [5.2e-10, 5.10673376738, 17796.9591667858],
+            [6.7e-10, 6.27917920454, 22345.2603761082],
+            [5e-10, 0.42577644151, 25685.872802808],
+            [4.8e-10, 0.70204553333, 1162.4747044078],
+            [6.6e-10, 3.64350022359, 15265.8865193004],
+            [5e-10, 5.7438291744, 19.66976089979],
+            [5e-10, 4.69825387775, 28237.2334593894],
+            [4.7e-10, 5.74015846442, 12139.5535091068],
+            [5.4e-10, 1.97301333704, 23581.2581773176],
+            [4.9e-10, 4.98223579027, 10021.8372800994],
+            [4.6e-10, 5.41431705539, 33019.0211122046],
+            [5.1e-10, 1.23882053879, 12539.853380183],
+            [4.6e-10, 2.41369976086, 98068.53671630539],
+            [4.4e-10, 0.80750593746, 167283.7615876655],
+            [4.5e-10, 4.39613584445, 433.7117378768],
+            [4.4e-10, 2.57358208785, 12964.300703391],
+            [4.6e-10, 0.26142733448, 11.0457002639],
+            [4.5e-10, 2.46230645202, 51868.2486621788],
+            [4.8e-10, 0.89551707131, 56600.2792895222],
+            [5.7e-10, 1.8641670701, 25287.7237993998],
+            [4.2e-10, 5.26377513431, 26084.0218062162],
+            [4.9e-10, 3.17757670611, 6303.8512454838],
+            [5.2e-10, 3.65266055509, 7872.1487452752],
+            [4e-10, 1.81891629936, 34596.3646546524],
+            [4.3e-10, 1.94164978061, 1903.4368125012],
+            [4.1e-10, 0.74461854136, 23937.856389741],
+            [4.8e-10, 6.26034008181, 28286.9904848612],
+            [4.5e-10, 5.4557501753, 60530.4889857418],
+            [4e-10, 2.92105728682, 21548.9623692918],
+            [4e-10, 0.04502010161, 38526.574350872],
+            [5.3e-10, 3.64791042082, 11925.2740926006],
+            [4.1e-10, 5.04048954693, 27832.0382192832],
+            [4.2e-10, 5.19292937193, 19004.6479494084],
+            [4e-10, 2.57120233428, 24356.7807886416],
+            [3.8e-10, 3.49190341464, 226858.23855437007],
+            [3.9e-10, 4.61184303844, 95.9792272178],
+            [4.3e-10, 2.20648228147, 13521.7514415914],
+            [4e-10, 5.83461945819, 16193.65917750039],
+            [4.5e-10, 3.73714372195, 7875.6718636242],
+            [4.3e-10, 1.14078465002, 49.7570254718],
+            [3.7e-10, 1.29390383811, 310.8407988684],
+            [3.8e-10, 0.9597092595, 664.75604513],
+            [3.7e-10, 4.27532649462, 6709.6740408674],
+            [3.8e-10, 2.20108541046, 28628.3362260996],
+            [3.9e-10, 0.85957361635, 16522.6597160022],
+            [4e-10, 4.35214003837, 48739.859897083],
+            [3.6e-10, 1.68167662194, 10344.2950653858],
+            [4e-10, 5.13217319067, 15664.03552270859],
+            [3.6e-10, 3.72187132496, 30774.5016425748],
+            [3.6e-10, 3.32158458257, 16207.886271502],
+            [4.5e-10, 3.94202418608, 10988.808157535],
+            [3.9e-10, 1.51948786199, 12029.3471878874],
+            [2.6e-10, 3.8768588318, 6262.7205305926],
+            [2.4e-10, 4.91804163466, 19651.048481098],
+            [2.3e-10, 0.29300197709, 13362.4497067992],
+            [2.1e-10, 3.18605672363, 6277.552925684],
+            [2.1e-10, 6.07546891132, 18139.2945014159],
+            [2.2e-10, 2.31199937177, 6303.4311693902],
+            [2.1e-10, 3.58418394393, 18209.33026366019],
+            [2.6e-10, 2.068012969, 12573.2652469836],
+            [2.1e-10, 1.56857722317, 13341.6743113068],
+            [2.4e-10, 5.72605158675, 29864.334027309],
+            [2.4e-10, 1.40237993205, 14712.317116458],
+            [2.5e-10, 5.71466092822, 25934.1243310894],
         ],
-        vec![
-            vec![
-                [1.00013988799, 0.0, 0.0],
-                [0.01670699626, 3.09846350771, 6283.0758499914],
-                [0.00013956023, 3.0552460962, 12566.1516999828],
-                [3.08372e-05, 5.19846674381, 77713.7714681205],
-                [1.628461e-05, 1.17387749012, 5753.3848848968],
-                [1.575568e-05, 2.84685245825, 7860.4193924392],
-                [9.24799e-06, 5.45292234084, 11506.7697697936],
-                [5.42444e-06, 4.56409149777, 3930.2096962196],
-                [4.7211e-06, 3.66100022149, 5884.9268465832],
-                [3.2878e-06, 5.89983646482, 5223.6939198022],
-                [3.45983e-06, 0.96368617687, 5507.5532386674],
-                [3.06784e-06, 0.29867139512, 5573.1428014331],
-                [1.74844e-06, 3.01193636534, 18849.2275499742],
-                [2.43189e-06, 4.27349536153, 11790.6290886588],
-                [2.11829e-06, 5.84714540314, 1577.3435424478],
-                [1.85752e-06, 5.02194447178, 10977.078804699],
-                [1.09835e-06, 5.05510636285, 5486.777843175],
-                [9.8316e-07, 0.88681311277, 6069.7767545534],
-                [8.6499e-07, 5.68959778254, 15720.8387848784],
-                [8.5825e-07, 1.27083733351, 161000.6857376741],
-                [6.2916e-07, 0.92177108832, 529.6909650946],
-                [5.7056e-07, 2.01374292014, 83996.84731811189],
-                [6.4903e-07, 0.27250613787, 17260.1546546904],
-                [4.9384e-07, 3.24501240359, 2544.3144198834],
-                [5.5736e-07, 5.24159798933, 71430.69561812909],
-                [4.2515e-07, 6.01110242003, 6275.9623029906],
-                [4.6963e-07, 2.57805070386, 775.522611324],
-                [3.8968e-07, 5.36071738169, 4694.0029547076],
-                [4.4661e-07, 5.53715807302, 9437.762934887],
-                [3.566e-07, 1.67468058995, 12036.4607348882],
-                [3.1921e-07, 0.18368229781, 5088.6288397668],
-                [3.1846e-07, 1.77775642085, 398.1490034082],
-                [3.3193e-07, 0.24370300098, 7084.8967811152],
-                [3.8245e-07, 2.39255343974, 8827.3902698748],
-                [2.8464e-07, 1.21344868176, 6286.5989683404],
-                [3.749e-07, 0.82952922332, 19651.048481098],
-                [3.6957e-07, 4.90107591914, 12139.5535091068],
-                [3.4537e-07, 1.84270693282, 2942.4634232916],
-                [2.6275e-07, 4.58896850401, 10447.3878396044],
-                [2.4596e-07, 3.78660875483, 8429.2412664666],
-                [2.3587e-07, 0.26866117066, 796.2980068164],
-                [2.7793e-07, 1.89934330904, 6279.5527316424],
-                [2.3927e-07, 4.99598548138, 5856.4776591154],
-                [2.0349e-07, 4.65267995431, 2146.1654164752],
-                [2.3287e-07, 2.80783650928, 14143.4952424306],
-                [2.2103e-07, 1.95004702988, 3154.6870848956],
-                [1.9506e-07, 5.38227371393, 2352.8661537718],
-                [1.7958e-07, 0.19871379385, 6812.766815086],
-                [1.7174e-07, 4.43315560735, 10213.285546211],
-                [1.619e-07, 5.23160507859, 17789.845619785],
-                [1.7314e-07, 6.15200787916, 16730.4636895958],
-                [1.3814e-07, 5.18962074032, 8031.0922630584],
-                [1.8833e-07, 0.67306674027, 149854.4001348079],
-                [1.8331e-07, 2.25348733734, 23581.2581773176],
-                [1.3641e-07, 3.68516118804, 4705.7323075436],
-                [1.3139e-07, 0.65289581324, 13367.9726311066],
-                [1.0414e-07, 4.33285688538, 11769.8536931664],
-                [9.978e-08, 4.20126336355, 6309.3741697912],
-                [1.0169e-07, 1.59390681369, 4690.4798363586],
-                [7.564e-08, 2.6256059739, 6256.7775301916],
-                [9.661e-08, 3.6758679122, 27511.4678735372],
-                [6.743e-08, 0.56270332741, 3340.6124266998],
-                [8.743e-08, 6.06359123461, 1748.016413067],
-                [7.786e-08, 3.67371235637, 12168.0026965746],
-                [6.633e-08, 5.66149277792, 11371.7046897582],
-                [7.712e-08, 0.31242577789, 7632.9432596502],
-                [6.592e-08, 3.13576266188, 801.8209311238],
-                [7.46e-08, 5.64757188143, 11926.2544136688],
-                [6.933e-08, 2.923845864, 6681.2248533996],
-                [6.802e-08, 1.4232980642, 23013.5395395872],
-                [6.115e-08, 5.13393615454, 1194.4470102246],
-                [6.477e-08, 2.64986648492, 19804.8272915828],
-                [5.233e-08, 4.62434053374, 6438.4962494256],
-                [6.147e-08, 3.02863936662, 233141.3144043615],
-                [4.608e-08, 1.72194702724, 7234.794256242],
-                [4.221e-08, 1.55697533729, 7238.6755916],
-                [5.314e-08, 2.40716580847, 11499.6562227928],
-                [5.128e-08, 5.3239896569, 11513.8833167944],
-                [4.77e-08, 0.25554312006, 11856.2186514245],
-                [5.519e-08, 2.09089154502, 17298.1823273262],
-                [5.625e-08, 4.34052903053, 90955.5516944961],
-                [4.578e-08, 4.4656964157, 5746.271337896],
-                [3.788e-08, 4.9072938351, 4164.311989613],
-                [5.337e-08, 5.09957905104, 31441.6775697568],
-                [3.967e-08, 1.20054555174, 1349.8674096588],
-                [4.008e-08, 3.03007204392, 1059.3819301892],
-                [3.476e-08, 0.7608027703, 10973.55568635],
-                [4.232e-08, 1.05485713117, 5760.4984318976],
-                [4.582e-08, 3.76570026763, 6386.16862421],
-                [3.335e-08, 3.13829943354, 6836.6452528338],
-                [3.418e-08, 3.00072390334, 4292.3308329504],
-                [3.598e-08, 5.70718084323, 5643.1785636774],
-                [3.237e-08, 4.16448773994, 9917.6968745098],
-                [4.154e-08, 2.59941292162, 7058.5984613154],
-                [3.362e-08, 4.54577697964, 4732.0306273434],
-                [2.978e-08, 1.3056126882, 6283.14316029419],
-                [2.765e-08, 0.51311975679, 26.2983197998],
-                [2.802e-08, 5.66263240521, 8635.9420037632],
-                [2.927e-08, 5.73787481548, 16200.7727245012],
-                [3.164e-08, 1.69140262657, 11015.1064773348],
-                [2.598e-08, 2.96244118586, 25132.3033999656],
-                [3.519e-08, 3.62639325753, 244287.60000722768],
-                [2.676e-08, 4.2072570085, 18073.7049386502],
-                [2.978e-08, 1.74971565805, 6283.0085396886],
-                [2.287e-08, 1.06975704977, 14314.1681130498],
-                [2.863e-08, 5.92838131397, 14712.317116458],
-                [3.071e-08, 0.23793217002, 35371.8872659764],
-                [2.656e-08, 0.8995930178, 12352.8526045448],
-                [2.415e-08, 2.79975176257, 709.9330485583],
-                [2.814e-08, 3.51488206882, 21228.3920235458],
-                [1.977e-08, 2.6135829755, 951.7184062506],
-                [2.548e-08, 2.47684686575, 6208.2942514241],
-                [1.999e-08, 0.5609038816, 7079.3738568078],
-                [2.305e-08, 1.05376461628, 22483.84857449259],
-                [1.855e-08, 2.86090681163, 5216.5803728014],
-                [2.157e-08, 1.31396741861, 154717.6098876827],
-                [1.97e-08, 4.36929875289, 167283.7615876655],
-                [1.635e-08, 5.85571606764, 10984.1923516998],
-                [1.754e-08, 2.14452408833, 6290.1893969922],
-                [2.154e-08, 6.03828341543, 10873.9860304804],
-                [1.714e-08, 3.70157691113, 1592.5960136328],
-                [1.541e-08, 6.21598380732, 23543.23050468179],
-                [1.611e-08, 1.99824499377, 10969.9652576982],
-                [1.712e-08, 1.34295663542, 3128.3887650958],
-                [1.642e-08, 5.55026665339, 6496.3749454294],
-                [1.502e-08, 5.43948825854, 155.4203994342],
-                [1.827e-08, 5.91227480261, 3738.761430108],
-                [1.726e-08, 2.16764983583, 10575.4066829418],
-                [1.532e-08, 5.3568310707, 13521.7514415914],
-                [1.829e-08, 1.66006148731, 39302.096962196],
-                [1.605e-08, 1.90928637633, 6133.5126528568],
-                [1.282e-08, 2.46014880418, 13916.0191096416],
-                [1.211e-08, 4.4136063155, 3894.1818295422],
-                [1.394e-08, 1.77801929354, 9225.539273283],
-                [1.571e-08, 4.95512957592, 25158.6017197654],
-                [1.205e-08, 1.19212540615, 3.523118349],
-                [1.132e-08, 2.69830084955, 6040.3472460174],
-                [1.504e-08, 5.77002730341, 18209.33026366019],
-                [1.393e-08, 1.62621805428, 5120.6011455836],
-                [1.077e-08, 2.93931554233, 17256.6315363414],
-                [1.232e-08, 0.71655165307, 143571.32428481648],
-                [1.087e-08, 0.99769687939, 955.5997416086],
-                [1.068e-08, 5.28472576231, 65147.6197681377],
-                [9.8e-09, 5.10949204607, 6172.869528772],
-                [1.169e-08, 3.11664290862, 14945.3161735544],
-                [1.202e-08, 4.02992510402, 553.5694028424],
-                [9.79e-09, 2.00000879212, 15110.4661198662],
-                [9.62e-09, 4.023807714, 6282.0955289232],
-                [9.99e-09, 3.6264300279, 6262.300454499],
-                [1.03e-08, 5.84989900289, 213.299095438],
-                [1.014e-08, 2.84221578218, 8662.240323563],
-                [1.185e-08, 1.51330541132, 17654.7805397496],
-                [9.67e-09, 2.67081017562, 5650.2921106782],
-                [1.222e-08, 2.65423784904, 88860.05707098669],
-                [9.81e-09, 2.36370360283, 6206.8097787158],
-                [1.033e-08, 0.13874927606, 11712.9553182308],
-                [1.103e-08, 3.08477302937, 43232.3066584156],
-                [7.81e-09, 2.53372735932, 16496.3613962024],
-                [1.019e-08, 3.04569392376, 6037.244203762],
-                [7.95e-09, 5.80662989111, 5230.807466803],
-                [8.13e-09, 3.57710279439, 10177.2576795336],
-                [9.62e-09, 5.31470594766, 6284.0561710596],
-                [7.21e-09, 5.96264301567, 12559.038152982],
-                [9.66e-09, 2.74714939953, 6244.9428143536],
-                [9.21e-09, 0.10155275926, 29088.811415985],
-                [6.92e-09, 3.89764447548, 1589.0728952838],
-                [7.19e-09, 5.91791450402, 4136.9104335162],
-                [7.72e-09, 4.05505682353, 6127.6554505572],
-                [7.12e-09, 5.49291532439, 22003.9146348698],
-                [6.72e-09, 1.60700490811, 11087.2851259184],
-                [6.9e-09, 4.50539825563, 426.598190876],
-                [8.54e-09, 3.26104981596, 20426.571092422],
-                [6.56e-09, 4.3241018294, 16858.4825329332],
-                [8.4e-09, 2.59572585222, 28766.924424484],
-                [6.92e-09, 0.61650089011, 11403.676995575],
-                [7e-09, 3.40901167143, 7.1135470008],
-                [7.26e-09, 0.04243053594, 5481.2549188676],
-                [5.57e-09, 4.78317696534, 20199.094959633],
-                [6.49e-09, 1.04027912958, 6062.6632075526],
-                [6.33e-09, 5.70229959167, 45892.73043315699],
-                [5.92e-09, 6.11836729658, 9623.6882766912],
-                [5.23e-09, 3.62840021266, 5333.9002410216],
-                [6.04e-09, 5.57734696185, 10344.2950653858],
-                [4.96e-09, 2.21023499449, 1990.745017041],
-                [6.91e-09, 1.96071732602, 12416.5885028482],
-                [6.4e-09, 1.59074172032, 18319.5365848796],
-                [6.25e-09, 3.82362791378, 13517.8701062334],
-                [6.63e-09, 5.08444996779, 283.8593188652],
-                [4.75e-09, 1.17025894287, 12569.6748183318],
-                [6.64e-09, 4.50029469969, 47162.5163546352],
-                [5.69e-09, 0.16310365162, 17267.26820169119],
-                [5.68e-09, 3.86100969474, 6076.8903015542],
-                [5.39e-09, 4.83282276086, 18422.62935909819],
-                [4.66e-09, 0.75872342878, 7342.4577801806],
-                [5.41e-09, 3.07212190507, 226858.23855437007],
-                [4.58e-09, 0.26774483096, 4590.910180489],
-                [6.1e-09, 1.53597051291, 33019.0211122046],
-                [6.17e-09, 2.62356328726, 11190.377900137],
-                [5.48e-09, 4.55798855791, 18875.525869774],
-                [6.33e-09, 4.60110281228, 66567.48586525429],
-                [5.96e-09, 5.78202396722, 632.7837393132],
-                [5.33e-09, 5.01786882904, 12132.439962106],
-                [6.03e-09, 5.38458554802, 316428.22867391503],
-                [4.69e-09, 0.59168241917, 21954.15760939799],
-                [5.48e-09, 3.50613163558, 17253.04110768959],
-                [5.02e-09, 0.98804327589, 11609.8625440122],
-                [5.68e-09, 1.98497313089, 7668.6374249425],
-                [4.82e-09, 1.62141803864, 12146.6670561076],
-                [3.91e-09, 3.68718382989, 18052.9295431578],
-                [4.57e-09, 3.7720573734, 156137.47598479927],
-                [4.01e-09, 5.28260651958, 15671.0817594066],
-                [4.69e-09, 1.80963184268, 12562.6285816338],
-                [5.08e-09, 3.36399024699, 20597.2439630412],
-                [4.5e-09, 5.6605429925, 10454.5013866052],
-                [3.75e-09, 4.98534633105, 9779.1086761254],
-                [5.23e-09, 0.97215560834, 155427.542936241],
-                [4.03e-09, 5.13939866506, 1551.045222648],
-                [3.72e-09, 3.69883738807, 9388.0059094152],
-                [3.67e-09, 4.43875659716, 4535.0594369244],
-                [4.06e-09, 4.208631566, 12592.4500197826],
-                [3.6e-09, 2.53924644657, 242.728603974],
-                [4.71e-09, 4.61907324819, 5436.9930152402],
-                [4.41e-09, 5.83872966262, 3496.032826134],
-                [3.85e-09, 4.94496680973, 24356.7807886416],
-                [3.49e-09, 6.15018231784, 19800.9459562248],
-                [3.55e-09, 0.21895678106, 5429.8794682394],
-                [3.44e-09, 5.62993724928, 2379.1644735716],
-                [3.8e-09, 2.72105213143, 11933.3679606696],
-                [4.32e-09, 0.24221790536, 17996.0311682222],
-                [3.78e-09, 5.22517556974, 7477.522860216],
-                [3.37e-09, 5.10888041439, 5849.3641121146],
-                [3.15e-09, 0.57827745123, 10557.5941608238],
-                [3.18e-09, 4.49953141399, 3634.6210245184],
-                [3.23e-09, 1.54274281393, 10440.2742926036],
-                [3.09e-09, 5.76839284397, 20.7753954924],
-                [3.01e-09, 2.34727604008, 4686.8894077068],
-                [4.14e-09, 5.9323760231, 51092.7260508548],
-                [3.61e-09, 2.1639860955, 28237.2334593894],
-                [2.88e-09, 0.18376252189, 13095.8426650774],
-                [2.77e-09, 5.12952205045, 13119.72110282519],
-                [3.27e-09, 6.19222146204, 6268.8487559898],
-                [2.73e-09, 0.30522428863, 23141.5583829246],
-                [2.67e-09, 5.76152585786, 5966.6839803348],
-                [3.08e-09, 5.99280509979, 22805.7355659936],
-                [3.45e-09, 2.92489919444, 36949.2308084242],
-                [2.53e-09, 5.20995219509, 24072.9214697764],
-                [3.42e-09, 5.72702586209, 16460.33352952499],
-                [2.61e-09, 2.00304796059, 6148.010769956],
-                [2.38e-09, 5.08264392839, 6915.8595893046],
-                [2.49e-09, 2.94762789744, 135.0650800354],
-                [3.06e-09, 3.89764686987, 10988.808157535],
-                [3.05e-09, 0.05827812117, 4701.1165017084],
-                [3.19e-09, 2.95712862064, 163096.18036118348],
-                [2.09e-09, 4.43768461442, 6546.1597733642],
-                [2.7e-09, 2.06643178717, 4804.209275927],
-                [2.17e-09, 0.73691592312, 6303.8512454838],
-                [2.06e-09, 0.32075959415, 25934.1243310894],
-                [2.18e-09, 0.18428135264, 28286.9904848612],
-                [2.05e-09, 5.21312087405, 20995.3929664494],
-                [1.99e-09, 0.44384292491, 16737.5772365966],
-                [2.3e-09, 6.06567392849, 6287.0080032545],
-                [2.19e-09, 1.291942163, 5326.7866940208],
-                [2.01e-09, 1.74700937253, 22743.4093795164],
-                [2.07e-09, 4.45440927276, 6279.4854213396],
-                [2.69e-09, 6.0564044503, 64471.99124174489],
-                [1.9e-09, 0.99256176518, 29296.6153895786],
-                [2.38e-09, 5.42471431221, 39609.6545831656],
-                [2.62e-09, 5.26961924198, 522.5774180938],
-                [2.1e-09, 4.68618183158, 6254.6266625236],
-                [1.97e-09, 2.8062455408, 4933.2084403326],
-                [2.52e-09, 4.36220154608, 40879.4405046438],
-                [2.61e-09, 1.07241516738, 55022.9357470744],
-                [1.89e-09, 3.82966734476, 419.4846438752],
-                [1.85e-09, 4.14324541379, 5642.1982426092],
-                [2.47e-09, 3.44855612987, 6702.5604938666],
-                [2.05e-09, 4.04424043223, 536.8045120954],
-                [1.91e-09, 3.14082686083, 16723.350142595],
-                [2.22e-09, 5.16263907319, 23539.7073863328],
-                [1.8e-09, 4.56214752149, 6489.2613984286],
-                [2.19e-09, 0.80382553358, 16627.3709153772],
-                [2.27e-09, 0.60156339452, 5905.7022420756],
-                [1.68e-09, 0.88753528161, 16062.1845261168],
-                [1.58e-09, 0.92127725775, 23937.856389741],
-                [1.57e-09, 4.69607868164, 6805.6532680852],
-                [2.07e-09, 4.88410451334, 6286.6662786432],
-                [1.6e-09, 4.95943826846, 10021.8372800994],
-                [1.66e-09, 0.97126433565, 3097.88382272579],
-                [2.09e-09, 5.75663411805, 3646.3503773544],
-                [1.75e-09, 6.12762824412, 239424.39025435288],
-                [1.73e-09, 3.13887234973, 6179.9830757728],
-                [1.57e-09, 3.62822058179, 18451.07854656599],
-                [1.57e-09, 4.67695912235, 6709.6740408674],
-                [1.46e-09, 3.09506069735, 4907.3020501456],
-                [1.65e-09, 2.2713912876, 10660.6869350424],
-                [2.01e-09, 1.67701267433, 2107.0345075424],
-                [1.44e-09, 3.96947747592, 6019.9919266186],
-                [1.71e-09, 5.91302216729, 6058.7310542895],
-                [1.44e-09, 2.1315565512, 26084.0218062162],
-                [1.51e-09, 0.67417383554, 2388.8940204492],
-                [1.89e-09, 5.07122281033, 263.0839233728],
-                [1.46e-09, 5.10373877968, 10770.8932562618],
-                [1.87e-09, 1.23915444627, 19402.7969528166],
-                [1.74e-09, 0.08407293391, 9380.9596727172],
-                [1.37e-09, 1.26247412309, 12566.2190102856],
-                [1.37e-09, 3.52826010842, 639.897286314],
-                [1.48e-09, 1.76124372592, 5888.4499649322],
-                [1.64e-09, 2.39195095081, 6357.8574485587],
-                [1.46e-09, 2.43675816553, 5881.4037282342],
-                [1.61e-09, 1.15721259372, 26735.9452622132],
-                [1.31e-09, 2.51859277344, 6599.467719648],
-                [1.53e-09, 5.85203687779, 6281.5913772831],
-                [1.51e-09, 3.72338532649, 12669.2444742014],
-                [1.32e-09, 2.38417741883, 6525.8044539654],
-                [1.29e-09, 0.75556744143, 5017.508371365],
-                [1.27e-09, 0.00254936441, 10027.9031957292],
-                [1.48e-09, 2.85102145528, 6418.1409300268],
-                [1.43e-09, 5.74460279367, 26087.9031415742],
-                [1.72e-09, 0.4128996224, 174242.4659640497],
-                [1.36e-09, 4.15497742275, 6311.5250374592],
-                [1.7e-09, 5.98194913129, 327574.51427678124],
-                [1.24e-09, 1.65497607604, 32217.2001810808],
-                [1.36e-09, 2.48430783417, 13341.6743113068],
-                [1.65e-09, 2.496679246, 58953.145443294],
-                [1.23e-09, 3.45660563754, 6277.552925684],
-                [1.17e-09, 0.86065134175, 6245.0481773556],
-                [1.49e-09, 5.61358280963, 5729.506447149],
-                [1.53e-09, 0.2686002995, 245.8316462294],
-                [1.28e-09, 0.71204006588, 103.0927742186],
-                [1.59e-09, 2.43166592149, 221995.02880149524],
-                [1.3e-09, 2.80707316718, 6016.4688082696],
-                [1.37e-09, 1.70657709294, 12566.08438968],
-                [1.11e-09, 1.56305648432, 17782.7320727842],
-                [1.13e-09, 3.58302904101, 25685.872802808],
-                [1.09e-09, 3.26403795962, 6819.8803620868],
-                [1.22e-09, 0.34120688217, 1162.4747044078],
-                [1.19e-09, 5.84644718278, 12721.572099417],
-                [1.44e-09, 2.28899679126, 12489.8856287072],
-                [1.37e-09, 5.82029768354, 44809.6502008634],
-                [1.07e-09, 2.4281854414, 5547.1993364596],
-                [1.34e-09, 1.26539982939, 5331.3574437408],
-                [1.03e-09, 5.96518130595, 6321.1035226272],
-                [1.09e-09, 0.33808549034, 11300.5842213564],
-                [1.29e-09, 5.89187277327, 12029.3471878874],
-                [1.22e-09, 5.77325634636, 11919.140866668],
-                [1.07e-09, 6.2499898935, 77690.75950573849],
-                [1.07e-09, 1.00535580713, 77736.78343050249],
-                [1.43e-09, 0.24122178432, 4214.0690150848],
-                [1.43e-09, 0.88529649733, 7576.560073574],
-                [1.07e-09, 2.92124030496, 31415.379249957],
-                [9.9e-10, 5.70862227072, 5540.0857894588],
-                [1.1e-09, 0.37528037383, 5863.5912061162],
-                [1.04e-09, 4.44107178366, 2118.7638603784],
-                [9.8e-10, 5.95877916706, 4061.2192153944],
-                [1.13e-09, 1.24206857385, 84672.47584450469],
-                [1.24e-09, 2.55619029867, 12539.853380183],
-                [1.1e-09, 3.66952094329, 238004.5241572363],
-                [1.12e-09, 4.32512422943, 97238.62754448749],
-                [9.7e-10, 3.70151541181, 11720.0688652316],
-                [1.2e-09, 1.26895630252, 12043.574281889],
-                [9.4e-10, 2.56461130309, 19004.6479494084],
-                [1.17e-09, 3.65425622684, 34520.3093093808],
-                [9.8e-10, 0.13589994287, 11080.1715789176],
-                [9.7e-10, 5.38330115253, 7834.1210726394],
-                [9.7e-10, 2.46722096722, 71980.63357473118],
-                [9.5e-10, 5.36958330451, 6288.5987742988],
-                [1.11e-09, 5.01961920313, 11823.1616394502],
-                [9e-10, 2.72299804525, 26880.3198130326],
-                [9.9e-10, 0.90164266377, 18635.9284545362],
-                [1.26e-09, 4.78722177847, 305281.9430710488],
-                [9.3e-10, 0.21240380046, 18139.2945014159],
-                [1.24e-09, 5.00979495566, 172146.9713405403],
-                [9.9e-10, 5.67090026475, 16522.6597160022],
-                [9.2e-10, 2.28180963676, 12491.3701014155],
-                [9e-10, 4.50544881196, 40077.61957352],
-                [1e-09, 2.00639461612, 12323.4230960088],
-                [9.5e-10, 5.68801979087, 14919.0178537546],
-                [8.7e-10, 1.86043406047, 27707.5424942948],
-                [1.05e-09, 3.02903468417, 22345.2603761082],
-                [8.7e-10, 5.43970168638, 6272.0301497275],
-                [8.9e-10, 1.63389387182, 33326.5787331742],
-                [8.2e-10, 5.58298993353, 10241.2022911672],
-                [9.4e-10, 5.47749711149, 9924.8104215106],
-                [8.2e-10, 4.71988314145, 15141.390794312],
-                [9.7e-10, 5.61458778738, 2787.0430238574],
-                [9.6e-10, 3.89073946348, 6379.0550772092],
-                [8.1e-10, 3.13038482444, 36147.4098773004],
-                [1.1e-09, 4.89978492291, 72140.6286666874],
-                [9.7e-10, 5.20764563059, 6303.4311693902],
-                [8.2e-10, 5.26342716139, 9814.6041002912],
-                [1.09e-09, 2.3555558977, 83286.91426955358],
-                [9.7e-10, 2.58492958057, 30666.1549584328],
-                [9.3e-10, 1.32651591333, 23020.65308658799],
-                [7.8e-10, 3.99588630754, 11293.4706743556],
-                [9e-10, 0.57771932738, 26482.1708096244],
-                [1.06e-09, 3.92012705073, 62883.3551395136],
-                [9.8e-10, 2.94397773524, 316.3918696566],
-                [7.6e-10, 3.96310417608, 29026.48522950779],
-                [7.8e-10, 1.97068529306, 90279.92316810328],
-                [7.6e-10, 0.23027966596, 21424.4666443034],
-                [8e-10, 2.23099742212, 266.6070417218],
-                [7.9e-10, 1.46227790922, 8982.810669309],
-                [1.02e-09, 4.92129953565, 5621.8429232104],
-                [1e-09, 0.39243148321, 24279.10701821359],
-                [7.1e-10, 1.52014858474, 33794.5437235286],
-                [7.6e-10, 0.22880641443, 57375.8019008462],
-                [9.1e-10, 0.96515913904, 48739.859897083],
-                [7.5e-10, 2.77638585157, 12964.300703391],
-                [7.7e-10, 5.18846946344, 11520.9968637952],
-                [6.8e-10, 0.50006599129, 4274.5183108324],
-                [7.5e-10, 2.07323762803, 15664.03552270859],
-                [7.4e-10, 1.01884134928, 6393.2821712108],
-                [7.7e-10, 0.4666517878, 16207.886271502],
-                [8.1e-10, 4.10452219483, 161710.6187862324],
-                [6.7e-10, 3.83840630887, 6262.7205305926],
-                [7.1e-10, 3.91415523291, 7875.6718636242],
-                [8.1e-10, 0.91938383237, 74.7815985673],
-                [8.3e-10, 4.69916218791, 23006.42599258639],
-                [6.3e-10, 2.32556465878, 6279.1945146334],
-                [6.5e-10, 5.41938745446, 28628.3362260996],
-                [6.5e-10, 3.02336771694, 5959.570433334],
-                [6.4e-10, 3.3103319837, 2636.725472637],
-                [6.4e-10, 0.18375587519, 1066.49547719],
-                [8e-10, 5.81239171612, 12341.8069042809],
-                [6.6e-10, 2.15105504851, 38.0276726358],
-                [6.2e-10, 2.43313614978, 10138.1095169486],
-                [6e-10, 3.1615390647, 5490.300961524],
-                [6.9e-10, 0.30764736334, 7018.9523635232],
-                [6.8e-10, 2.24442548639, 24383.0791084414],
-                [7.8e-10, 1.39649386463, 9411.4646150872],
-                [6.3e-10, 0.72976362625, 6286.9571853494],
-                [7.3e-10, 4.95125917731, 6453.7487206106],
-                [7.8e-10, 0.32736023459, 6528.9074962208],
-                [5.9e-10, 4.95362151577, 35707.7100829074],
-                [7e-10, 2.37962727525, 15508.6151232744],
-                [7.3e-10, 1.35229143111, 5327.4761083828],
-                [7.2e-10, 5.91833527334, 10881.0995774812],
-                [5.9e-10, 5.36231868425, 10239.5838660108],
-                [5.9e-10, 1.63156134967, 61306.0115970658],
-                [5.4e-10, 4.29491690425, 21947.1113727],
-                [5.7e-10, 5.89190132575, 34513.2630726828],
-                [7.4e-10, 1.38235845304, 9967.4538999816],
-                [5.3e-10, 3.86543309344, 32370.9789915656],
-                [5.5e-10, 4.51794544854, 34911.412076091],
-                [6.3e-10, 5.41479412056, 11502.8376165305],
-                [6.3e-10, 2.34416220742, 11510.7019230567],
-                [6.8e-10, 0.77493931112, 29864.334027309],
-                [6e-10, 5.57024703495, 5756.9080032458],
-                [7.2e-10, 2.80863088166, 10866.8724834796],
-                [6.1e-10, 2.69736991384, 82576.9812209953],
-                [6.3e-10, 5.32068807257, 3116.6594122598],
-                [5.2e-10, 1.02278758099, 6272.4391846416],
-                [6.9e-10, 5.00698550308, 25287.7237993998],
-                [6.6e-10, 6.12047940728, 12074.488407524],
-                [5.1e-10, 2.59519527563, 11396.5634485742],
-                [5.6e-10, 2.57995973521, 17892.93839400359],
-                [5.9e-10, 0.4416723762, 250570.6758572191],
-                [5.9e-10, 3.84070143543, 5483.254724826],
-                [4.9e-10, 0.54704693048, 22594.05489571199],
-                [6.5e-10, 2.38423614501, 52670.0695933026],
-                [6.9e-10, 5.34363738671, 66813.5648357332],
-                [5.7e-10, 5.42770501007, 310145.1528239236],
-                [5.3e-10, 1.17760296075, 149.5631971346],
-                [6.1e-10, 4.02090887211, 34596.3646546524],
-                [4.9e-10, 4.18361320516, 18606.4989460002],
-                [5.5e-10, 0.83886167974, 20452.8694122218],
-                [5e-10, 1.46327331958, 37455.7264959744],
-                [4.8e-10, 4.53854727167, 29822.7832363242],
-                [5.8e-10, 3.34847975377, 33990.6183442862],
-                [6.5e-10, 1.45522693982, 76251.32777062019],
-                [5.6e-10, 2.35650663692, 37724.7534197482],
-                [5.2e-10, 2.61551081496, 5999.2165311262],
-                [5.3e-10, 0.17334326094, 77717.29458646949],
-                [5.3e-10, 0.79879700631, 77710.24834977149],
-                [4.7e-10, 0.43240779709, 735.8765135318],
-                [5.3e-10, 4.58763261686, 11616.976091013],
-                [4.8e-10, 6.20230111054, 4171.4255366138],
-                [5.2e-10, 1.09723616404, 640.8776073822],
-                [5.7e-10, 3.42008310383, 50317.2034395308],
-                [5.3e-10, 1.01528448581, 149144.46708624958],
-                [4.7e-10, 3.00924906195, 52175.8062831484],
-                [5.2e-10, 2.03254070404, 6293.7125153412],
-                [4.8e-10, 0.12356889734, 13362.4497067992],
-                [4.5e-10, 3.37963782356, 10763.779709261],
-                [4.7e-10, 5.50981287869, 12779.4507954208],
-                [6.2e-10, 5.45209070099, 949.1756089698],
-                [6.1e-10, 2.93237974631, 5791.4125575326],
-                [4.4e-10, 2.87440620802, 8584.6616659008],
-                [4.6e-10, 4.0314179656, 10667.8004820432],
-                [4.7e-10, 3.89902931422, 3903.9113764198],
-                [4.6e-10, 2.75700467329, 6993.0088985497],
-                [4.5e-10, 1.933862933, 206.1855484372],
-                [4.7e-10, 2.57670800912, 11492.542675792],
-                [4.4e-10, 3.62570223167, 63658.8777508376],
-                [5.1e-10, 0.84536826273, 12345.739057544],
-                [4.3e-10, 0.01524970172, 37853.8754993826],
-                [4.1e-10, 3.27146326065, 8858.3149443206],
-                [4.5e-10, 3.03765521215, 65236.2212932854],
-                [4.7e-10, 1.44447548944, 21393.5419698576],
-                [5.8e-10, 5.45843180927, 1975.492545856],
-                [5e-10, 2.13285524146, 12573.2652469836],
-                [4.1e-10, 1.32190847146, 2547.8375382324],
-                [4.7e-10, 3.67579608544, 28313.288804661],
-                [4.1e-10, 2.24013475126, 8273.8208670324],
-                [4.7e-10, 6.21438985953, 10991.3058987006],
-                [4.2e-10, 3.0163181735, 853.196381752],
-                [5.6e-10, 1.09773690181, 77376.2010224076],
-                [4e-10, 2.35698541041, 2699.7348193176],
-                [4.3e-10, 5.28030898459, 17796.9591667858],
-                [5.4e-10, 2.59175932091, 22910.44676536859],
-                [5.4e-10, 0.88027764102, 71960.38658322369],
-                [5.5e-10, 0.07988899477, 83467.15635301729],
-                [3.9e-10, 1.12867321442, 9910.583327509],
-                [4e-10, 1.35670430524, 27177.8515292002],
-                [3.9e-10, 4.39624220245, 5618.3198048614],
-                [4.2e-10, 4.78798367468, 7856.89627409019],
-                [4.7e-10, 2.75482175292, 18202.21671665939],
-                [3.9e-10, 1.97008298629, 24491.4257925834],
-                [4.2e-10, 4.04346599946, 7863.9425107882],
-                [3.8e-10, 0.49178679251, 38650.173506199],
-                [3.6e-10, 4.86047906533, 4157.1984426122],
-                [4.3e-10, 5.64354880978, 1062.9050485382],
-                [3.6e-10, 3.98066313627, 12565.1713789146],
-                [4.2e-10, 2.30753932657, 6549.6828917132],
-                [4e-10, 5.3969491832, 9498.2122306346],
-                [4e-10, 3.30603243754, 23536.11695768099],
-                [5e-10, 6.15760345261, 78051.34191383338],
-            ],
-            vec![
-                [0.00103018608, 1.10748969588, 6283.0758499914],
-                [1.721238e-05, 1.06442301418, 12566.1516999828],
-                [7.02215e-06, 3.14159265359, 0.0],
-                [3.2346e-07, 1.02169059149, 18849.2275499742],
-                [3.0799e-07, 2.84353804832, 5507.5532386674],
-                [2.4971e-07, 1.31906709482, 5223.6939198022],
-                [1.8485e-07, 1.42429748614, 1577.3435424478],
-                [1.0078e-07, 5.91378194648, 10977.078804699],
-                [8.634e-08, 0.27146150602, 5486.777843175],
-                [8.654e-08, 1.42046854427, 6275.9623029906],
-                [5.069e-08, 1.68613426734, 5088.6288397668],
-                [4.985e-08, 6.01401770704, 6286.5989683404],
-                [4.669e-08, 5.98724494073, 529.6909650946],
-                [4.395e-08, 0.51800238019, 4694.0029547076],
-                [3.872e-08, 4.74969833437, 2544.3144198834],
-                [3.75e-08, 5.07097685568, 796.2980068164],
-                [4.1e-08, 1.08424786092, 9437.762934887],
-                [3.518e-08, 0.02290216272, 83996.84731811189],
-                [3.436e-08, 0.94937019624, 71430.69561812909],
-                [3.221e-08, 6.15628775313, 2146.1654164752],
-                [3.414e-08, 5.41218322538, 775.522611324],
-                [2.863e-08, 5.48432847146, 10447.3878396044],
-                [2.52e-08, 0.24276941146, 398.1490034082],
-                [2.201e-08, 4.95216196651, 6812.766815086],
-                [2.186e-08, 0.41991743105, 8031.0922630584],
-                [2.838e-08, 3.42034351366, 2352.8661537718],
-                [2.554e-08, 6.13241878525, 6438.4962494256],
-                [1.932e-08, 5.31374608366, 8429.2412664666],
-                [2.429e-08, 3.09164528262, 4690.4798363586],
-                [1.73e-08, 1.5368620855, 4705.7323075436],
-                [2.25e-08, 3.68863633842, 7084.8967811152],
-                [2.093e-08, 1.28191783032, 1748.016413067],
-                [1.441e-08, 0.81656250862, 14143.4952424306],
-                [1.483e-08, 3.22225357771, 7234.794256242],
-                [1.754e-08, 3.22883705112, 6279.5527316424],
-                [1.583e-08, 4.09702349428, 11499.6562227928],
-                [1.575e-08, 5.53890170575, 3154.6870848956],
-                [1.847e-08, 1.82040335363, 7632.9432596502],
-                [1.504e-08, 3.63293385726, 11513.8833167944],
-                [1.337e-08, 4.64440864339, 6836.6452528338],
-                [1.275e-08, 2.69341415363, 1349.8674096588],
-                [1.352e-08, 6.15101580257, 5746.271337896],
-                [1.125e-08, 3.35673439497, 17789.845619785],
-                [1.47e-08, 3.65282991755, 1194.4470102246],
-                [1.177e-08, 2.57676109092, 13367.9726311066],
-                [1.101e-08, 4.49748696552, 4292.3308329504],
-                [1.234e-08, 5.65036509521, 5760.4984318976],
-                [9.84e-09, 0.65517395136, 5856.4776591154],
-                [9.28e-09, 2.32420318751, 10213.285546211],
-                [1.077e-08, 5.82812169132, 12036.4607348882],
-                [9.16e-09, 0.76613009583, 16730.4636895958],
-                [8.77e-09, 1.50137505051, 11926.2544136688],
-                [1.023e-08, 5.62076589825, 6256.7775301916],
-                [8.51e-09, 0.65709335533, 155.4203994342],
-                [8.02e-09, 4.10519132088, 951.7184062506],
-                [8.57e-09, 1.41661697538, 5753.3848848968],
-                [9.94e-09, 1.14418521187, 1059.3819301892],
-                [8.13e-09, 1.63948433322, 6681.2248533996],
-                [6.62e-09, 4.5520045226, 5216.5803728014],
-                [6.44e-09, 4.19478168733, 6040.3472460174],
-                [6.26e-09, 1.50767713598, 5643.1785636774],
-                [5.9e-09, 6.18277145205, 4164.311989613],
-                [6.35e-09, 0.52413263542, 6290.1893969922],
-                [6.5e-09, 0.9793569035, 25132.3033999656],
-                [5.68e-09, 2.30125315873, 10973.55568635],
-                [5.47e-09, 5.27256412213, 3340.6124266998],
-                [5.47e-09, 2.20144422886, 1592.5960136328],
-                [5.26e-09, 0.92464258226, 11371.7046897582],
-                [4.9e-09, 5.90951388655, 3894.1818295422],
-                [4.78e-09, 1.66857963179, 12168.0026965746],
-                [5.16e-09, 3.59803483887, 10969.9652576982],
-                [5.18e-09, 3.97914412373, 17298.1823273262],
-                [5.34e-09, 5.03740926442, 9917.6968745098],
-                [4.87e-09, 2.50545369269, 6127.6554505572],
-                [4.16e-09, 4.04828175503, 10984.1923516998],
-                [5.38e-09, 5.54081539805, 553.5694028424],
-                [4.02e-09, 2.16544019233, 7860.4193924392],
-                [5.53e-09, 2.32177369366, 11506.7697697936],
-                [3.67e-09, 3.3915253225, 6496.3749454294],
-                [3.6e-09, 5.34379853282, 7079.3738568078],
-                [3.37e-09, 3.61563704045, 11790.6290886588],
-                [4.56e-09, 0.30754294809, 801.8209311238],
-                [4.17e-09, 3.70009308674, 10575.4066829418],
-                [3.81e-09, 5.82033971802, 7058.5984613154],
-                [3.21e-09, 0.31988767355, 16200.7727245012],
-                [3.64e-09, 1.08414306177, 6309.3741697912],
-                [2.94e-09, 4.54798604957, 11856.2186514245],
-                [2.9e-09, 1.26473978562, 8635.9420037632],
-                [3.99e-09, 4.16998866302, 26.2983197998],
-                [2.62e-09, 5.08316906342, 10177.2576795336],
-                [2.43e-09, 2.2574609119, 11712.9553182308],
-                [2.37e-09, 1.05070575346, 242.728603974],
-                [2.75e-09, 3.45319481756, 5884.9268465832],
-                [2.55e-09, 5.38496831087, 21228.3920235458],
-                [3.07e-09, 4.24313526604, 3738.761430108],
-                [2.16e-09, 3.46037894728, 213.299095438],
-                [1.96e-09, 0.69029243914, 1990.745017041],
-                [1.98e-09, 5.16301829964, 12352.8526045448],
-                [2.14e-09, 3.91876200279, 13916.0191096416],
-                [2.12e-09, 4.00861198517, 5230.807466803],
-                [1.84e-09, 5.59805976614, 6283.14316029419],
-                [1.84e-09, 2.85275392124, 7238.6755916],
-                [1.79e-09, 2.54259058334, 14314.1681130498],
-                [2.25e-09, 1.64458698399, 4732.0306273434],
-                [2.36e-09, 5.58826125715, 6069.7767545534],
-                [1.87e-09, 2.72805985443, 6062.6632075526],
-                [1.84e-09, 6.04216273598, 6283.0085396886],
-                [2.3e-09, 3.62591335086, 6284.0561710596],
-                [1.63e-09, 2.19117396803, 18073.7049386502],
-                [1.72e-09, 0.9761295074, 3930.2096962196],
-                [2.15e-09, 1.04672844028, 3496.032826134],
-                [1.69e-09, 4.75084479006, 17267.26820169119],
-                [1.52e-09, 0.19390712179, 9779.1086761254],
-                [1.82e-09, 5.16288118255, 17253.04110768959],
-                [1.49e-09, 0.8094418426, 709.9330485583],
-                [1.63e-09, 2.1920957039, 6076.8903015542],
-                [1.86e-09, 5.01159497089, 11015.1064773348],
-                [1.34e-09, 0.97765485759, 65147.6197681377],
-                [1.41e-09, 4.38421981312, 4136.9104335162],
-                [1.58e-09, 4.60974280627, 9623.6882766912],
-                [1.33e-09, 3.30508592837, 154717.6098876827],
-                [1.63e-09, 6.11782626245, 3.523118349],
-                [1.74e-09, 1.58078542187, 7.1135470008],
-                [1.41e-09, 0.49976927274, 25158.6017197654],
-                [1.24e-09, 6.03440460031, 9225.539273283],
-                [1.5e-09, 5.30166336812, 13517.8701062334],
-                [1.27e-09, 1.92389511438, 22483.84857449259],
-                [1.21e-09, 2.37813129011, 167283.7615876655],
-                [1.2e-09, 3.98423684853, 4686.8894077068],
-                [1.17e-09, 5.81072642211, 12569.6748183318],
-                [1.22e-09, 5.60973054224, 5642.1982426092],
-                [1.57e-09, 3.40236426002, 16496.3613962024],
-                [1.29e-09, 2.10705116371, 1589.0728952838],
-                [1.16e-09, 0.55839966736, 5849.3641121146],
-                [1.23e-09, 1.52961392771, 12559.038152982],
-                [1.11e-09, 0.44848279675, 6172.869528772],
-                [1.23e-09, 5.81645568991, 6282.0955289232],
-                [1.5e-09, 4.26278409223, 3128.3887650958],
-                [1.06e-09, 2.27437761356, 5429.8794682394],
-                [1.04e-09, 4.42743707728, 23543.23050468179],
-                [1.21e-09, 0.39459045915, 12132.439962106],
-                [1.04e-09, 2.41842602527, 426.598190876],
-                [1.1e-09, 5.80381480447, 16858.4825329332],
-                [1e-09, 2.93805577485, 4535.0594369244],
-                [9.7e-10, 3.97935904984, 6133.5126528568],
-                [1.1e-09, 6.22339014386, 12146.6670561076],
-                [9.8e-10, 0.87576563709, 6525.8044539654],
-                [9.8e-10, 3.15248421301, 10440.2742926036],
-                [9.5e-10, 2.461684111, 3097.88382272579],
-                [8.8e-10, 0.23371480284, 13119.72110282519],
-                [9.8e-10, 5.77016493489, 7342.4577801806],
-                [9.2e-10, 6.03915555063, 20426.571092422],
-                [9.6e-10, 5.56909292561, 2388.8940204492],
-                [8.1e-10, 1.32131147691, 5650.2921106782],
-                [8.6e-10, 3.94529200528, 10454.5013866052],
-                [7.6e-10, 2.70729716925, 143571.32428481648],
-                [9.1e-10, 5.64100034152, 8827.3902698748],
-                [7.6e-10, 1.80783856698, 28286.9904848612],
-                [8.1e-10, 1.90858992196, 29088.811415985],
-                [7.5e-10, 3.40955892978, 5481.2549188676],
-                [6.9e-10, 4.49936170873, 17256.6315363414],
-                [8.8e-10, 1.10098454357, 11769.8536931664],
-                [6.6e-10, 2.78285801977, 536.8045120954],
-                [6.8e-10, 3.88179770758, 17260.1546546904],
-                [8.4e-10, 1.59303306354, 9380.9596727172],
-                [8.8e-10, 3.88076636762, 7477.522860216],
-                [6.1e-10, 6.17558202197, 11087.2851259184],
-                [6e-10, 4.34824715818, 6206.8097787158],
-                [8.2e-10, 4.59843208943, 9388.0059094152],
-                [7.9e-10, 1.63131230601, 4933.2084403326],
-                [7.8e-10, 4.20905757484, 5729.506447149],
-                [5.7e-10, 5.48157926651, 18319.5365848796],
-                [6e-10, 1.01261781084, 12721.572099417],
-                [5.6e-10, 1.63031935692, 15720.8387848784],
-                [5.5e-10, 0.24926735018, 15110.4661198662],
-                [6.1e-10, 5.93059279661, 12539.853380183],
-                [5.5e-10, 4.84298966314, 13095.8426650774],
-                [6.7e-10, 6.11690589247, 8662.240323563],
-                [5.4e-10, 5.73750638571, 3634.6210245184],
-                [7.4e-10, 1.05466745829, 16460.33352952499],
-                [5.3e-10, 2.29084335688, 16062.1845261168],
-                [6.4e-10, 2.13513767927, 7875.6718636242],
-                [6.7e-10, 0.07096807518, 14945.3161735544],
-                [5.1e-10, 2.31511194429, 6262.7205305926],
-                [5.7e-10, 5.77055471237, 12043.574281889],
-                [5.6e-10, 4.41980790431, 4701.1165017084],
-                [5.9e-10, 5.87963500073, 5331.3574437408],
-                [5.8e-10, 2.30546168628, 955.5997416086],
-                [4.9e-10, 1.93839278478, 5333.9002410216],
-                [4.8e-10, 2.69973662261, 6709.6740408674],
-                [6.4e-10, 1.64379897981, 6262.300454499],
-                [4.6e-10, 3.98449608961, 98068.53671630539],
-                [5e-10, 3.68875893005, 12323.4230960088],
-                [4.5e-10, 3.30068569697, 22003.9146348698],
-                [4.7e-10, 1.26317154881, 11919.140866668],
-                [4.5e-10, 0.89150445122, 51868.2486621788],
-                [4.3e-10, 1.61526242998, 6277.552925684],
-                [4.3e-10, 5.74295325645, 11403.676995575],
-                [4.4e-10, 3.43070646822, 10021.8372800994],
-                [5.6e-10, 0.02481833774, 15671.0817594066],
-                [5.5e-10, 3.14274403422, 33019.0211122046],
-                [4.5e-10, 3.00877289177, 8982.810669309],
-                [4.6e-10, 0.73303568429, 6303.4311693902],
-                [4.9e-10, 1.60455690285, 6303.8512454838],
-                [4.5e-10, 0.40210030323, 6805.6532680852],
-                [5.3e-10, 0.94869680175, 10988.808157535],
-                [4.1e-10, 1.61122384329, 6819.8803620868],
-                [5.5e-10, 0.89439119424, 11933.3679606696],
-                [4.5e-10, 3.88495384656, 60530.4889857418],
-                [4e-10, 4.75740908001, 38526.574350872],
-                [4e-10, 1.49921251887, 18451.07854656599],
-                [4e-10, 3.77498297228, 26087.9031415742],
-                [5.1e-10, 1.70258603562, 1551.045222648],
-                [3.9e-10, 2.97100699926, 2118.7638603784],
-                [5.3e-10, 5.19854123078, 77713.7714681205],
-                [4.7e-10, 4.26356628717, 21424.4666443034],
-                [3.7e-10, 0.62902722802, 24356.7807886416],
-                [3.6e-10, 0.11087914947, 10344.2950653858],
-                [3.6e-10, 0.77037556319, 12029.3471878874],
-                [3.5e-10, 3.30933994515, 24072.9214697764],
-                [3.5e-10, 5.93650887012, 31570.7996493912],
-                [3.6e-10, 2.15108874765, 30774.5016425748],
-                [3.6e-10, 1.75078825382, 16207.886271502],
-                [3.3e-10, 5.06264177921, 226858.23855437007],
-                [3.4e-10, 6.168913788, 24491.4257925834],
-                [3.5e-10, 3.19120695549, 32217.2001810808],
-                [3.4e-10, 2.31528650443, 55798.4583583984],
-                [3.2e-10, 4.21446357042, 15664.03552270859],
-                [3.9e-10, 1.24979117796, 6418.1409300268],
-                [3.7e-10, 4.1194365577, 2787.0430238574],
-                [3.2e-10, 1.6288771089, 639.897286314],
-                [3.8e-10, 5.89832942685, 640.8776073822],
-                [3.2e-10, 1.72442327688, 27433.88921587499],
-                [3.1e-10, 2.78828943753, 12139.5535091068],
-                [3.5e-10, 4.44608896525, 18202.21671665939],
-                [3.4e-10, 3.96287980676, 18216.443810661],
-                [3.3e-10, 4.73611335874, 16723.350142595],
-                [3.4e-10, 1.43910280005, 49515.382508407],
-                [3.1e-10, 0.23302920161, 23581.2581773176],
-                [2.9e-10, 2.0263384022, 11609.8625440122],
-                [3e-10, 2.5492323024, 9924.8104215106],
-                [3.2e-10, 4.91793198558, 11300.5842213564],
-                [2.8e-10, 0.26187189577, 13521.7514415914],
-                [2.8e-10, 3.84568936822, 2699.7348193176],
-                [2.9e-10, 1.83149729794, 29822.7832363242],
-                [3.3e-10, 4.60320094415, 19004.6479494084],
-                [2.7e-10, 4.46183450287, 6702.5604938666],
-                [3e-10, 4.4649407224, 36147.4098773004],
-                [2.7e-10, 0.03211931363, 6279.7894925736],
-                [2.6e-10, 5.46497324333, 6245.0481773556],
-                [3.5e-10, 4.52695674113, 36949.2308084242],
-                [2.7e-10, 3.52528177609, 10770.8932562618],
-                [2.6e-10, 1.48499438453, 11080.1715789176],
-                [3.5e-10, 2.82154380962, 19402.7969528166],
-                [2.5e-10, 2.46339998836, 6279.4854213396],
-                [2.6e-10, 4.97688894643, 16737.5772365966],
-                [2.6e-10, 2.36136541526, 17996.0311682222],
-                [2.9e-10, 4.15148654061, 45892.73043315699],
-                [2.6e-10, 4.50714272714, 17796.9591667858],
-                [2.7e-10, 4.72625223674, 1066.49547719],
-                [2.5e-10, 2.89309528854, 6286.6662786432],
-                [2.7e-10, 0.37462444357, 12964.300703391],
-                [2.9e-10, 4.94860010533, 5863.5912061162],
-                [3.1e-10, 3.93096113577, 29864.334027309],
-                [2.4e-10, 6.14987193584, 18606.4989460002],
-                [2.4e-10, 3.74225964547, 29026.48522950779],
-                [2.5e-10, 5.70460621565, 27707.5424942948],
-                [2.5e-10, 5.33928840652, 15141.390794312],
-                [2.7e-10, 3.0232089714, 6286.3622074092],
-                [2.3e-10, 0.28364955406, 5327.4761083828],
-                [2.6e-10, 1.34240461687, 18875.525869774],
-                [2.4e-10, 1.33998410121, 19800.9459562248],
-                [2.5e-10, 6.00172494004, 6489.2613984286],
-                [2.2e-10, 1.81777974484, 6288.5987742988],
-                [2.2e-10, 3.5860360664, 6915.8595893046],
-                [2.9e-10, 2.09564449439, 15265.8865193004],
-                [2.2e-10, 1.02173599251, 11925.2740926006],
-                [2.2e-10, 4.74660932338, 28230.18722269139],
-                [2.1e-10, 2.30688751432, 5999.2165311262],
-                [2.1e-10, 3.2265494443, 25934.1243310894],
-                [2.1e-10, 3.04956726238, 6566.9351688566],
-                [2.7e-10, 5.35653084499, 33794.5437235286],
-                [2.8e-10, 3.91168324815, 18208.349942592],
-                [2e-10, 1.52296293311, 135.0650800354],
-                [2.2e-10, 4.66462839521, 13362.4497067992],
-                [1.9e-10, 1.78121167862, 156137.47598479927],
-                [1.9e-10, 2.99969102221, 19651.048481098],
-                [1.9e-10, 2.86664273362, 18422.62935909819],
-                [2.5e-10, 0.94995632141, 31415.379249957],
-                [1.9e-10, 4.71432851499, 77690.75950573849],
-                [1.9e-10, 2.54227398241, 77736.78343050249],
-                [2e-10, 5.91915117116, 48739.859897083],
-            ],
-            vec![
-                [4.359385e-05, 5.78455133738, 6283.0758499914],
-                [1.23633e-06, 5.57934722157, 12566.1516999828],
-                [1.2341e-07, 3.14159265359, 0.0],
-                [8.792e-08, 3.62777733395, 77713.7714681205],
-                [5.689e-08, 1.86958905084, 5573.1428014331],
-                [3.301e-08, 5.47027913302, 18849.2275499742],
-                [1.471e-08, 4.48028885617, 5507.5532386674],
-                [1.013e-08, 2.81456417694, 5223.6939198022],
-                [8.54e-09, 3.10878241236, 1577.3435424478],
-                [1.102e-08, 2.84173992403, 161000.6857376741],
-                [6.48e-09, 5.47349498544, 775.522611324],
-                [6.09e-09, 1.37969434104, 6438.4962494256],
-                [4.99e-09, 4.4164924225, 6286.5989683404],
-                [4.17e-09, 0.90242451175, 10977.078804699],
-                [4.02e-09, 3.2037658529, 5088.6288397668],
-                [3.51e-09, 1.8107922777, 5486.777843175],
-                [4.67e-09, 3.65753702738, 7084.8967811152],
-                [4.58e-09, 5.38585314743, 149854.4001348079],
-                [3.04e-09, 3.51701098693, 796.2980068164],
-                [2.66e-09, 6.17413982699, 6836.6452528338],
-                [2.79e-09, 1.84120501086, 4694.0029547076],
-                [2.6e-09, 1.41629543251, 2146.1654164752],
-                [2.66e-09, 3.13832905677, 71430.69561812909],
-                [3.21e-09, 5.35313367048, 3154.6870848956],
-                [2.38e-09, 2.17720020018, 155.4203994342],
-                [2.93e-09, 4.61501268144, 4690.4798363586],
-                [2.29e-09, 4.7596958807, 7234.794256242],
-                [2.11e-09, 0.21868065485, 4705.7323075436],
-                [2.01e-09, 4.21905743357, 1349.8674096588],
-                [1.95e-09, 4.57808285364, 529.6909650946],
-                [2.53e-09, 2.81496293039, 1748.016413067],
-                [1.82e-09, 5.70454011389, 6040.3472460174],
-                [1.79e-09, 6.02897097053, 4292.3308329504],
-                [1.86e-09, 1.58690991244, 6309.3741697912],
-                [1.7e-09, 2.90220009715, 9437.762934887],
-                [1.66e-09, 1.99984925026, 8031.0922630584],
-                [1.58e-09, 0.04783713552, 2544.3144198834],
-                [1.97e-09, 2.01083639502, 1194.4470102246],
-                [1.65e-09, 5.78372596778, 83996.84731811189],
-                [2.14e-09, 3.38285934319, 7632.9432596502],
-                [1.4e-09, 0.36401486094, 10447.3878396044],
-                [1.51e-09, 0.95153163031, 6127.6554505572],
-                [1.36e-09, 1.48426306582, 2352.8661537718],
-                [1.27e-09, 5.48475435134, 951.7184062506],
-                [1.26e-09, 5.26866506592, 6279.5527316424],
-                [1.25e-09, 3.75754889288, 6812.766815086],
-                [1.01e-09, 4.95015746147, 398.1490034082],
-                [1.02e-09, 0.68468295277, 1592.5960136328],
-                [1e-09, 1.14568935785, 3894.1818295422],
-                [1.29e-09, 0.76540016965, 553.5694028424],
-                [1.09e-09, 5.41063597567, 6256.7775301916],
-                [7.5e-10, 5.84804322893, 242.728603974],
-                [9.5e-10, 1.94452244083, 11856.2186514245],
-                [7.7e-10, 0.69373708195, 8429.2412664666],
-                [1e-09, 5.19725292131, 244287.60000722768],
-                [8e-10, 6.18440483705, 1059.3819301892],
-                [6.9e-10, 5.25699888595, 14143.4952424306],
-                [8.5e-10, 5.39484725499, 25132.3033999656],
-                [6.6e-10, 0.51779993906, 801.8209311238],
-                [5.5e-10, 5.16878202461, 7058.5984613154],
-                [5.1e-10, 3.88759155247, 12036.4607348882],
-                [5e-10, 5.57636570536, 6290.1893969922],
-                [6.1e-10, 2.24359003264, 8635.9420037632],
-                [5e-10, 5.54441900966, 1990.745017041],
-                [5.6e-10, 4.0030107804, 13367.9726311066],
-                [5.2e-10, 4.13138898038, 7860.4193924392],
-                [5.2e-10, 3.90943054011, 26.2983197998],
-                [4.1e-10, 3.5712848278, 7079.3738568078],
-                [5.6e-10, 2.76959005761, 90955.5516944961],
-                [4.2e-10, 1.91461189199, 7477.522860216],
-                [4.2e-10, 0.42728171713, 10213.285546211],
-                [4.2e-10, 1.09413724455, 709.9330485583],
-                [3.9e-10, 3.93298068961, 10973.55568635],
-                [3.8e-10, 6.17935925345, 9917.6968745098],
-                [4.9e-10, 0.83021145241, 11506.7697697936],
-                [5.3e-10, 1.45828359397, 233141.3144043615],
-                [4.7e-10, 6.21568666789, 6681.2248533996],
-                [3.7e-10, 0.3635930998, 10177.2576795336],
-                [3.5e-10, 3.33024911524, 5643.1785636774],
-                [3.4e-10, 5.63446915337, 6525.8044539654],
-                [3.5e-10, 5.36033855038, 25158.6017197654],
-                [3.4e-10, 5.36319798321, 4933.2084403326],
-                [3.3e-10, 4.24722336872, 12569.6748183318],
-                [4.3e-10, 5.26370903404, 10575.4066829418],
-                [4.2e-10, 5.08837645072, 11015.1064773348],
-                [4e-10, 1.98334703186, 6284.0561710596],
-                [4.2e-10, 4.22496037505, 88860.05707098669],
-                [2.9e-10, 3.1908862817, 11926.2544136688],
-                [2.9e-10, 0.15217616684, 12168.0026965746],
-                [3e-10, 1.61904744136, 9779.1086761254],
-                [2.7e-10, 0.76388991416, 1589.0728952838],
-                [3.6e-10, 2.74712003443, 3738.761430108],
-                [3.3e-10, 3.08807829566, 3930.2096962196],
-                [3.1e-10, 5.34906619513, 143571.32428481648],
-                [2.5e-10, 0.10240267494, 22483.84857449259],
-                [3e-10, 3.47110495524, 14945.3161735544],
-                [2.4e-10, 1.10425016019, 4535.0594369244],
-                [2.4e-10, 1.5803725978, 6496.3749454294],
-                [2.3e-10, 3.87710321433, 6275.9623029906],
-                [2.5e-10, 3.9452977897, 3128.3887650958],
-                [2.3e-10, 3.44685609601, 4136.9104335162],
-                [2.3e-10, 3.83156029849, 5753.3848848968],
-                [2.2e-10, 1.86956128067, 16730.4636895958],
-                [2.5e-10, 2.42188933855, 5729.506447149],
-                [2e-10, 1.78208352927, 17789.845619785],
-                [2.1e-10, 4.303630874, 16858.4825329332],
-                [2.1e-10, 0.49258939822, 29088.811415985],
-                [2.5e-10, 1.33030250444, 6282.0955289232],
-                [2.7e-10, 2.54785812264, 3496.032826134],
-                [2.2e-10, 1.1123252195, 12721.572099417],
-                [2.1e-10, 5.97759081637, 7.1135470008],
-                [1.9e-10, 0.80292033311, 16062.1845261168],
-                [2.3e-10, 4.12454848769, 2388.8940204492],
-                [2.2e-10, 4.92663152168, 18875.525869774],
-                [2.3e-10, 5.68902059771, 16460.33352952499],
-                [2.3e-10, 4.97346265647, 17260.1546546904],
-                [2.3e-10, 3.03021283729, 66567.48586525429],
-                [1.6e-10, 3.89740925257, 5331.3574437408],
-                [1.7e-10, 3.08268671348, 154717.6098876827],
-                [1.6e-10, 3.95085099736, 3097.88382272579],
-                [1.6e-10, 3.99041783945, 6283.14316029419],
-                [2e-10, 6.10644140189, 167283.7615876655],
-                [1.5e-10, 4.09775914607, 11712.9553182308],
-                [1.6e-10, 5.717699407, 17298.1823273262],
-                [1.6e-10, 3.28894009404, 5884.9268465832],
-                [1.5e-10, 5.64785377164, 12559.038152982],
-                [1.6e-10, 4.4345208093, 6283.0085396886],
-                [1.4e-10, 2.31721603062, 5481.2549188676],
-                [1.4e-10, 4.43479032305, 13517.8701062334],
-                [1.4e-10, 4.73209312936, 7342.4577801806],
-                [1.2e-10, 0.64705975463, 18073.7049386502],
-                [1.1e-10, 1.514433322, 16200.7727245012],
-                [1.1e-10, 0.88708889185, 21228.3920235458],
-                [1.4e-10, 4.50116508534, 640.8776073822],
-                [1.1e-10, 4.64339996198, 11790.6290886588],
-                [1.1e-10, 1.31064298246, 4164.311989613],
-                [9e-11, 3.02238989305, 23543.23050468179],
-                [9e-11, 2.04999402381, 22003.9146348698],
-                [9e-11, 4.91488110218, 213.299095438],
-            ],
-            vec![
-                [1.44595e-06, 4.27319435148, 6283.0758499914],
-                [6.729e-08, 3.91697608662, 12566.1516999828],
-                [7.74e-09, 0.0, 0.0],
-                [2.47e-09, 3.73019298781, 18849.2275499742],
-                [3.6e-10, 2.8008140905, 6286.5989683404],
-                [3.3e-10, 5.62216602775, 6127.6554505572],
-                [1.9e-10, 3.71292621802, 6438.4962494256],
-                [1.6e-10, 4.26011484232, 6525.8044539654],
-                [1.6e-10, 3.50416887054, 6256.7775301916],
-                [1.4e-10, 3.62127621114, 25132.3033999656],
-                [1.1e-10, 4.39200958819, 4705.7323075436],
-                [1.1e-10, 5.22327127059, 6040.3472460174],
-                [1e-10, 4.28045254647, 83996.84731811189],
-                [9e-11, 1.56864096494, 5507.5532386674],
-                [1.1e-10, 1.37795688024, 6309.3741697912],
-                [1e-10, 5.19937959068, 71430.69561812909],
-                [9e-11, 0.4727519993, 6279.5527316424],
-                [9e-11, 0.74642756529, 5729.506447149],
-                [7e-11, 2.9737489156, 775.522611324],
-                [7e-11, 3.28615691021, 7058.5984613154],
-                [7e-11, 2.19184402142, 6812.766815086],
-                [5e-11, 3.15419034438, 529.6909650946],
-                [6e-11, 4.54725567047, 1059.3819301892],
-                [5e-11, 1.51104406936, 7079.3738568078],
-                [7e-11, 2.98052059053, 6681.2248533996],
-                [5e-11, 2.30961231391, 12036.4607348882],
-                [5e-11, 3.71102966917, 6290.1893969922],
-            ],
-            vec![
-                [3.858e-08, 2.56384387339, 6283.0758499914],
-                [3.06e-09, 2.2676950123, 12566.1516999828],
-                [5.3e-10, 3.44031471924, 5573.1428014331],
-                [1.5e-10, 2.04794573436, 18849.2275499742],
-                [1.3e-10, 2.05688873673, 77713.7714681205],
-                [7e-11, 4.4121885448, 161000.6857376741],
-                [5e-11, 5.26154653107, 6438.4962494256],
-                [5e-11, 4.07695126049, 6127.6554505572],
-                [6e-11, 3.81514213664, 149854.4001348079],
-                [3e-11, 1.28175749811, 6286.5989683404],
-            ],
-            vec![
-                [8.6e-10, 1.21579741687, 6283.0758499914],
-                [1.2e-10, 0.65617264033, 12566.1516999828],
-                [1e-11, 0.38068797142, 18849.2275499742],
-            ],
+        &[
+            [0.0005291887, 0.0, 0.0],
+            [8.719837e-05, 1.07209665242, 6283.0758499914],
+            [3.09125e-06, 0.86728818832, 12566.1516999828],
+            [2.7339e-07, 0.05297871691, 3.523118349],
+            [1.6334e-07, 5.18826691036, 26.2983197998],
+            [1.5752e-07, 3.6845788943, 155.4203994342],
+            [9.541e-08, 0.75742297675, 18849.2275499742],
+            [8.937e-08, 2.05705419118, 77713.7714681205],
+            [6.952e-08, 0.8267330541, 775.522611324],
+            [5.064e-08, 4.66284525271, 1577.3435424478],
+            [4.061e-08, 1.03057162962, 7.1135470008],
+            [3.463e-08, 5.14074632811, 796.2980068164],
+            [3.169e-08, 6.05291851171, 5507.5532386674],
+            [3.02e-08, 1.19246506441, 242.728603974],
+            [2.886e-08, 6.11652627155, 529.6909650946],
+            [3.81e-08, 3.4405080349, 5573.1428014331],
+            [2.714e-08, 0.30637881025, 398.1490034082],
+            [2.371e-08, 4.38118838167, 5223.6939198022],
+            [2.538e-08, 2.27992810679, 553.5694028424],
+            [2.079e-08, 3.75435330484, 0.9803210682],
+            [1.675e-08, 0.90216407959, 951.7184062506],
+            [1.534e-08, 5.75900462759, 1349.8674096588],
+            [1.224e-08, 2.97328088405, 2146.1654164752],
+            [1.449e-08, 4.3641591397, 1748.016413067],
+            [1.341e-08, 3.72061130861, 1194.4470102246],
+            [1.254e-08, 2.94846826628, 6438.4962494256],
+            [9.99e-09, 5.98640014468, 6286.5989683404],
+            [9.17e-09, 4.79788687522, 5088.6288397668],
+            [8.28e-09, 3.31321076572, 213.299095438],
+            [1.103e-08, 1.27104454479, 161000.6857376741],
+            [7.62e-09, 3.41582762988, 5486.777843175],
+            [1.044e-08, 0.60409577691, 3154.6870848956],
+            [8.87e-09, 5.23465144638, 7084.8967811152],
+            [6.45e-09, 1.60096192515, 2544.3144198834],
+            [6.81e-09, 3.43155669169, 4694.0029547076],
+            [6.05e-09, 2.47806340546, 10977.078804699],
+            [7.06e-09, 6.19393222575, 4690.4798363586],
+            [6.43e-09, 1.98042503148, 801.8209311238],
+            [5.02e-09, 1.44394375363, 6836.6452528338],
+            [4.9e-09, 2.34129524194, 1592.5960136328],
+            [4.58e-09, 1.30876448575, 4292.3308329504],
+            [4.31e-09, 0.03526421494, 7234.794256242],
+            [3.79e-09, 3.17030522615, 6309.3741697912],
+            [3.48e-09, 0.99049550009, 6040.3472460174],
+            [3.86e-09, 1.57019797263, 71430.69561812909],
+            [3.47e-09, 0.67013291338, 1059.3819301892],
+            [4.58e-09, 3.81499443681, 149854.4001348079],
+            [3.02e-09, 1.91760044838, 10447.3878396044],
+            [3.07e-09, 3.55343347416, 8031.0922630584],
+            [3.95e-09, 4.93701776616, 7632.9432596502],
+            [3.14e-09, 3.18093696547, 2352.8661537718],
+            [2.82e-09, 4.41936437052, 9437.762934887],
+            [2.76e-09, 2.71314254553, 3894.1818295422],
+            [2.98e-09, 2.5203747421, 6127.6554505572],
+            [2.3e-09, 1.37790215549, 4705.7323075436],
+            [2.52e-09, 0.55330133471, 6279.5527316424],
+            [2.55e-09, 5.26570187369, 6812.766815086],
+            [2.75e-09, 0.67264264272, 25132.3033999656],
+            [1.78e-09, 0.92820785174, 1990.745017041],
+            [2.21e-09, 0.63897368842, 6256.7775301916],
+            [1.55e-09, 0.77319790838, 14143.4952424306],
+            [1.5e-09, 2.40470465561, 426.598190876],
+            [1.96e-09, 6.06877865012, 640.8776073822],
+            [1.37e-09, 2.21679460145, 8429.2412664666],
+            [1.27e-09, 3.26094223174, 17789.845619785],
+            [1.28e-09, 5.47237279946, 12036.4607348882],
+            [1.22e-09, 2.16291082757, 10213.285546211],
+            [1.18e-09, 0.45789822268, 7058.5984613154],
+            [1.41e-09, 2.34932647403, 11506.7697697936],
+            [1e-09, 0.85621569847, 6290.1893969922],
+            [9.2e-10, 5.10587476002, 7079.3738568078],
+            [1.26e-09, 2.65428307012, 88860.05707098669],
+            [1.06e-09, 5.85646710022, 7860.4193924392],
+            [8.4e-10, 3.57457554262, 16730.4636895958],
+            [8.9e-10, 4.21433259618, 83996.84731811189],
+            [9.7e-10, 5.57938280855, 13367.9726311066],
+            [1.02e-09, 2.05853060226, 87.30820453981],
+            [8e-10, 4.73792651816, 11926.2544136688],
+            [8e-10, 5.41418965044, 10973.55568635],
+            [1.06e-09, 4.10978997399, 3496.032826134],
+            [1.02e-09, 3.62650006043, 244287.60000722768],
+            [7.5e-10, 4.89483161769, 5643.1785636774],
+            [8.7e-10, 0.42863750683, 11015.1064773348],
+            [6.9e-10, 1.8890876072, 10177.2576795336],
+            [8.9e-10, 1.35567273119, 6681.2248533996],
+            [6.6e-10, 0.99455837265, 6525.8044539654],
+            [6.7e-10, 5.5124099707, 3097.88382272579],
+            [7.6e-10, 2.72016814799, 4164.311989613],
+            [6.3e-10, 1.4434990254, 9917.6968745098],
+            [7.8e-10, 3.51469733747, 11856.2186514245],
+            [8.5e-10, 0.50956043858, 10575.4066829418],
+            [6.7e-10, 3.62043033405, 16496.3613962024],
+            [5.5e-10, 5.24637517308, 3340.6124266998],
+            [4.8e-10, 5.43966777314, 20426.571092422],
+            [6.4e-10, 5.79535817813, 2388.8940204492],
+            [4.6e-10, 5.43499966519, 6275.9623029906],
+            [5e-10, 3.86263598617, 5729.506447149],
+            [4.4e-10, 1.52269529228, 12168.0026965746],
+            [5.7e-10, 4.96352373486, 14945.3161735544],
+            [4.5e-10, 1.0086123016, 8635.9420037632],
+            [4.3e-10, 3.30685683359, 9779.1086761254],
+            [4.2e-10, 0.6348125893, 2699.7348193176],
+            [4.1e-10, 5.67996766641, 11712.9553182308],
+            [5.6e-10, 4.34024451468, 90955.5516944961],
+            [4.1e-10, 5.81722212845, 709.9330485583],
+            [5.3e-10, 6.17052087143, 233141.3144043615],
+            [3.7e-10, 3.12495025087, 16200.7727245012],
+            [3.5e-10, 5.76973458495, 12569.6748183318],
+            [3.7e-10, 0.31656444326, 24356.7807886416],
+            [3.5e-10, 0.96229051027, 17298.1823273262],
+            [3.3e-10, 5.23130355867, 5331.3574437408],
+            [3.5e-10, 0.62517020593, 25158.6017197654],
+            [3.5e-10, 0.80004512129, 13916.0191096416],
+            [3.7e-10, 2.89336088688, 12721.572099417],
+            [3e-10, 4.50198402401, 23543.23050468179],
+            [3e-10, 5.31355708693, 18319.5365848796],
+            [2.9e-10, 3.47275229977, 13119.72110282519],
+            [2.9e-10, 3.11002782516, 4136.9104335162],
+            [3.2e-10, 5.52273255667, 5753.3848848968],
+            [3.5e-10, 3.7969999668, 143571.32428481648],
+            [2.6e-10, 1.50634201907, 154717.6098876827],
+            [3e-10, 3.53519084118, 6284.0561710596],
+            [2.3e-10, 4.41808025967, 5884.9268465832],
+            [2.5e-10, 1.38477355808, 65147.6197681377],
+            [2.3e-10, 3.49782549797, 7477.522860216],
+            [1.9e-10, 3.14329413716, 6496.3749454294],
+            [1.9e-10, 2.20135125199, 18073.7049386502],
+            [1.9e-10, 4.95020255309, 3930.2096962196],
+            [1.9e-10, 0.57998702747, 31415.379249957],
+            [2.1e-10, 1.75474323399, 12139.5535091068],
+            [1.9e-10, 3.92233070499, 19651.048481098],
+            [1.4e-10, 0.98131213224, 12559.038152982],
+            [1.9e-10, 4.93309333729, 2942.4634232916],
+            [1.6e-10, 5.55997534558, 8827.3902698748],
+            [1.3e-10, 1.68808165516, 4535.0594369244],
+            [1.3e-10, 0.33982116161, 4933.2084403326],
+            [1.2e-10, 1.85426309994, 5856.4776591154],
+            [1e-10, 4.82763996845, 13095.8426650774],
+            [1.1e-10, 5.38005490571, 11790.6290886588],
+            [1e-10, 1.40815507226, 10988.808157535],
+            [1.1e-10, 3.05005267431, 17260.1546546904],
+            [1e-10, 4.93364992366, 12352.8526045448],
         ],
-    ]
-}
-
+        &[
+            [2.89226e-06, 5.84384198723, 6283.0758499914],
+            [3.4955e-07, 0.0, 0.0],
+            [1.6819e-07, 5.48766912348, 12566.1516999828],
+            [2.962e-08, 5.19577265202, 155.4203994342],
+            [1.288e-08, 4.72200252235, 3.523118349],
+            [6.35e-09, 5.96925937141, 242.728603974],
+            [7.14e-09, 5.30045809128, 18849.2275499742],
+            [4.02e-09, 3.78682982419, 553.5694028424],
+            [7.2e-10, 4.2976812618, 6286.5989683404],
+            [6.7e-10, 0.90721687647, 6127.6554505572],
+            [3.6e-10, 5.24029648014, 6438.4962494256],
+            [2.4e-10, 5.16003960716, 25132.3033999656],
+            [2.3e-10, 3.01921570335, 6309.3741697912],
+            [1.7e-10, 5.82863573502, 6525.8044539654],
+            [1.7e-10, 3.6777286393, 71430.69561812909],
+            [9e-11, 4.58467294499, 1577.3435424478],
+            [8e-11, 1.40626662824, 11856.2186514245],
+            [8e-11, 5.07561257196, 6256.7775301916],
+            [7e-11, 2.82473374405, 83996.84731811189],
+            [5e-11, 2.71488713339, 10977.078804699],
+            [5e-11, 3.76879847273, 12036.4607348882],
+            [5e-11, 4.28412873331, 6275.9623029906],
+        ],
+        &[
+            [1.14084e-06, 3.14159265359, 0.0],
+            [7.717e-08, 4.13446589358, 6283.0758499914],
+            [7.65e-09, 3.83803776214, 12566.1516999828],
+            [4.2e-09, 0.41925861858, 155.4203994342],
+            [4e-10, 3.5984758584, 18849.2275499742],
+            [4.1e-10, 3.14398414077, 3.523118349],
+            [3.5e-10, 5.00298940826, 5573.1428014331],
+            [1.3e-10, 0.48794833701, 77713.7714681205],
+            [1e-10, 5.6480176635, 6127.6554505572],
+            [8e-11, 2.84160570605, 161000.6857376741],
+            [2e-11, 0.54912904658, 6438.4962494256],
+        ],
+        &[
+            [8.78e-09, 3.14159265359, 0.0],
+            [1.72e-09, 2.7657906951, 6283.0758499914],
+            [5e-10, 2.01353298182, 155.4203994342],
+            [2.8e-10, 2.21496423926, 12566.1516999828],
+            [5e-11, 1.75600058765, 18849.2275499742],
+        ],
+    ],
+    &[
+        &[
+            [2.7962e-06, 3.19870156017, 84334.66158130829],
+            [1.01643e-06, 5.42248619256, 5507.5532386674],
+            [8.0445e-07, 3.88013204458, 5223.6939198022],
+            [4.3806e-07, 3.70444689758, 2352.8661537718],
+            [3.1933e-07, 4.00026369781, 1577.3435424478],
+            [2.2724e-07, 3.9847383156, 1047.7473117547],
+            [1.6392e-07, 3.56456119782, 5856.4776591154],
+            [1.8141e-07, 4.98367470263, 6283.0758499914],
+            [1.4443e-07, 3.70275614914, 9437.762934887],
+            [1.4304e-07, 3.41117857525, 10213.285546211],
+            [1.1246e-07, 4.8282069053, 14143.4952424306],
+            [1.09e-07, 2.08574562327, 6812.766815086],
+            [9.714e-08, 3.47303947752, 4694.0029547076],
+            [1.0367e-07, 4.05663927946, 71092.88135493269],
+            [8.775e-08, 4.44016515669, 5753.3848848968],
+            [8.366e-08, 4.9925151218, 7084.8967811152],
+            [6.921e-08, 4.32559054073, 6275.9623029906],
+            [9.145e-08, 1.14182646613, 6620.8901131878],
+            [7.194e-08, 3.60193205752, 529.6909650946],
+            [7.698e-08, 5.55425745881, 167621.5758508619],
+            [5.285e-08, 2.48446991566, 4705.7323075436],
+            [5.208e-08, 6.24992674537, 18073.7049386502],
+            [4.529e-08, 2.33827747356, 6309.3741697912],
+            [5.579e-08, 4.41023653738, 7860.4193924392],
+            [4.743e-08, 0.70995680136, 5884.9268465832],
+            [4.301e-08, 1.10255777773, 6681.2248533996],
+            [3.849e-08, 1.82229412531, 5486.777843175],
+            [4.093e-08, 5.11700141207, 13367.9726311066],
+            [3.681e-08, 0.43793170356, 3154.6870848956],
+            [3.42e-08, 5.42034800952, 6069.7767545534],
+            [3.617e-08, 6.04641937526, 3930.2096962196],
+            [3.67e-08, 4.58210192227, 12194.0329146209],
+            [2.918e-08, 1.95463881126, 10977.078804699],
+            [2.797e-08, 5.61259275048, 11790.6290886588],
+            [2.502e-08, 0.60499729367, 6496.3749454294],
+            [2.319e-08, 5.01648216014, 1059.3819301892],
+            [2.684e-08, 1.39470396488, 22003.9146348698],
+            [2.428e-08, 3.24183056052, 78051.5857313169],
+            [2.12e-08, 4.30691000285, 5643.1785636774],
+            [2.257e-08, 3.15557225618, 90617.7374312997],
+            [1.813e-08, 3.75574218285, 3340.6124266998],
+            [2.226e-08, 2.79699346659, 12036.4607348882],
+            [1.888e-08, 0.86991545823, 8635.9420037632],
+            [1.517e-08, 1.95852055701, 398.1490034082],
+            [1.581e-08, 3.19976230948, 5088.6288397668],
+            [1.421e-08, 6.25530883827, 2544.3144198834],
+            [1.595e-08, 0.25619915135, 17298.1823273262],
+            [1.391e-08, 4.69964175561, 7058.5984613154],
+            [1.478e-08, 2.81808207569, 25934.1243310894],
+            [1.481e-08, 3.65823554806, 11506.7697697936],
+            [1.693e-08, 4.95689385293, 156475.2902479957],
+            [1.183e-08, 1.29343061246, 775.522611324],
+            [1.114e-08, 2.37889311846, 3738.761430108],
+            [9.94e-09, 4.30088900425, 9225.539273283],
+            [9.24e-09, 3.06451026812, 4164.311989613],
+            [8.67e-09, 0.55606931068, 8429.2412664666],
+            [9.88e-09, 5.97286104208, 7079.3738568078],
+            [8.24e-09, 1.50984806173, 10447.3878396044],
+            [9.15e-09, 0.12635654592, 11015.1064773348],
+            [7.42e-09, 1.99159139281, 26087.9031415742],
+            [1.039e-08, 3.14159265359, 0.0],
+            [8.5e-09, 4.24120016095, 29864.334027309],
+            [7.55e-09, 2.8963187332, 4732.0306273434],
+            [7.14e-09, 1.37548118603, 2146.1654164752],
+            [7.08e-09, 1.91406542362, 8031.0922630584],
+            [7.46e-09, 0.57893808616, 796.2980068164],
+            [8.02e-09, 5.1233913723, 2942.4634232916],
+            [7.51e-09, 1.67479850166, 21228.3920235458],
+            [6.02e-09, 4.09976538826, 64809.80550494129],
+            [5.94e-09, 3.49580704962, 16496.3613962024],
+            [5.92e-09, 4.59481504319, 4690.4798363586],
+            [5.3e-09, 5.739792952, 8827.3902698748],
+            [5.03e-09, 5.66433137112, 33794.5437235286],
+            [4.83e-09, 1.57106522411, 801.8209311238],
+            [4.38e-09, 0.06707733767, 3128.3887650958],
+            [4.23e-09, 2.86944595927, 12566.1516999828],
+            [5.04e-09, 3.2620766916, 7632.9432596502],
+            [5.52e-09, 1.02926440457, 239762.20451754928],
+            [4.27e-09, 3.6743437821, 213.299095438],
+            [4.04e-09, 1.46193297142, 15720.8387848784],
+            [5.03e-09, 4.85802444134, 6290.1893969922],
+            [4.17e-09, 0.81920713533, 5216.5803728014],
+            [3.65e-09, 0.01002966162, 12168.0026965746],
+            [3.63e-09, 1.28376436579, 6206.8097787158],
+            [3.53e-09, 4.7005913311, 7234.794256242],
+            [4.15e-09, 0.96862624175, 4136.9104335162],
+            [3.87e-09, 3.09145061418, 25158.6017197654],
+            [3.73e-09, 2.65119262792, 7342.4577801806],
+            [3.61e-09, 2.97762937739, 9623.6882766912],
+            [4.18e-09, 3.75759994446, 5230.807466803],
+            [3.96e-09, 1.22507712354, 6438.4962494256],
+            [3.22e-09, 1.21162178805, 8662.240323563],
+            [2.84e-09, 5.64170320068, 1589.0728952838],
+            [3.79e-09, 1.72248432748, 14945.3161735544],
+            [3.2e-09, 3.94161159962, 7330.8231617461],
+            [3.13e-09, 5.47602376446, 1194.4470102246],
+            [2.92e-09, 1.38971327603, 11769.8536931664],
+            [3.05e-09, 0.80429352049, 37724.7534197482],
+            [2.57e-09, 5.81382809757, 426.598190876],
+            [2.65e-09, 6.10358507671, 6836.6452528338],
+            [2.5e-09, 4.56452895547, 7477.522860216],
+            [2.66e-09, 2.62926282354, 7238.6755916],
+            [2.63e-09, 6.22089501237, 6133.5126528568],
+            [3.06e-09, 2.79682380531, 1748.016413067],
+            [2.36e-09, 2.46093023714, 11371.7046897582],
+            [3.16e-09, 1.62662805006, 250908.4901204155],
+            [2.16e-09, 3.68721275185, 5849.3641121146],
+            [2.3e-09, 0.36165162947, 5863.5912061162],
+            [2.33e-09, 5.03509933858, 20426.571092422],
+            [2e-09, 5.86073159059, 4535.0594369244],
+            [2.77e-09, 4.65400292395, 82239.1669577989],
+            [2.09e-09, 3.72323200804, 10973.55568635],
+            [1.99e-09, 5.05186622555, 5429.8794682394],
+            [2.56e-09, 2.4092327977, 19651.048481098],
+            [2.1e-09, 4.50691909144, 29088.811415985],
+            [1.81e-09, 6.00294783127, 4292.3308329504],
+            [2.49e-09, 0.12900984422, 154379.7956244863],
+            [2.09e-09, 3.87759458598, 17789.845619785],
+            [2.25e-09, 3.18339652605, 18875.525869774],
+            [1.91e-09, 4.53897489299, 18477.1087646123],
+            [1.72e-09, 2.09694183014, 13095.8426650774],
+            [1.82e-09, 3.161079435, 16730.4636895958],
+            [1.88e-09, 2.22746128596, 41654.9631159678],
+            [1.64e-09, 5.18686275017, 5481.2549188676],
+            [1.6e-09, 2.49298855159, 12592.4500197826],
+            [1.55e-09, 1.5959543823, 10021.8372800994],
+            [1.35e-09, 0.21349051064, 10988.808157535],
+            [1.78e-09, 3.8037517797, 23581.2581773176],
+            [1.23e-09, 1.66800739151, 15110.4661198662],
+            [1.22e-09, 2.72678272244, 18849.2275499742],
+            [1.26e-09, 1.1767551291, 14919.0178537546],
+            [1.42e-09, 3.95053441332, 337.8142631964],
+            [1.16e-09, 6.06340906229, 6709.6740408674],
+            [1.37e-09, 3.52143246757, 12139.5535091068],
+            [1.36e-09, 2.92179113542, 32217.2001810808],
+            [1.1e-09, 3.51203379263, 18052.9295431578],
+            [1.47e-09, 4.63371971408, 22805.7355659936],
+            [1.08e-09, 5.45280814878, 7.1135470008],
+            [1.48e-09, 0.65447253687, 95480.9471841745],
+            [1.19e-09, 5.92110458985, 33019.0211122046],
+            [1.1e-09, 5.34824206306, 639.897286314],
+            [1.06e-09, 3.71081682629, 14314.1681130498],
+            [1.39e-09, 6.17607198418, 24356.7807886416],
+            [1.18e-09, 5.5973871267, 161338.5000008705],
+            [1.17e-09, 3.6506527164, 45585.1728121874],
+            [1.27e-09, 4.74596574209, 49515.382508407],
+            [1.2e-09, 1.04211499785, 6915.8595893046],
+            [1.2e-09, 5.60638811846, 5650.2921106782],
+            [1.15e-09, 3.10668213289, 14712.317116458],
+            [9.9e-10, 0.69018940049, 12779.4507954208],
+            [9.7e-10, 1.07908724794, 9917.6968745098],
+            [9.3e-10, 2.62295197319, 17260.1546546904],
+            [9.9e-10, 4.45774681732, 4933.2084403326],
+            [1.23e-09, 1.37488922089, 28286.9904848612],
+            [1.21e-09, 5.19767249813, 27511.4678735372],
+            [1.05e-09, 0.87192267806, 77375.95720492408],
+            [8.7e-10, 3.9363781295, 17654.7805397496],
+            [1.22e-09, 2.2395606868, 83997.09113559539],
+            [8.7e-10, 4.18201600952, 22779.4372461938],
+            [1.04e-09, 4.59580877295, 1349.8674096588],
+            [1.02e-09, 2.83545248411, 12352.8526045448],
+            [1.02e-09, 3.97386522171, 10818.1352869158],
+            [1.01e-09, 4.32892825857, 36147.4098773004],
+            [9.4e-10, 5.00001709261, 150192.2143980043],
+            [7.7e-10, 3.97199369296, 1592.5960136328],
+            [1e-09, 6.07733097102, 26735.9452622132],
+            [8.6e-10, 5.2602963825, 28313.288804661],
+            [9.3e-10, 4.31900620254, 44809.6502008634],
+            [7.6e-10, 6.22743405935, 13521.7514415914],
+            [7.2e-10, 1.55820597747, 6256.7775301916],
+            [8.2e-10, 4.95202664555, 10575.4066829418],
+            [8.2e-10, 1.69647647075, 1990.745017041],
+            [7.5e-10, 2.29836095644, 3634.6210245184],
+            [7.5e-10, 2.66367876557, 16200.7727245012],
+            [8.7e-10, 0.26630214764, 31441.6775697568],
+            [7.7e-10, 2.25530954137, 5235.3285382367],
+            [7.6e-10, 1.09869730846, 12903.9659631792],
+            [5.8e-10, 4.28246138307, 12559.038152982],
+            [6.4e-10, 5.51112830114, 173904.65170085328],
+            [5.6e-10, 2.60133794851, 73188.3759784421],
+            [5.5e-10, 5.81483150022, 143233.51002162008],
+            [5.4e-10, 3.38482031504, 323049.11878710287],
+            [3.9e-10, 3.28500401343, 71768.50988132549],
+            [3.9e-10, 3.1123991069, 96900.81328129109],
+        ],
+        &[
+            [9.03e-08, 3.8972906189, 5507.5532386674],
+            [6.177e-08, 1.73038850355, 5223.6939198022],
+            [3.8e-08, 5.24404145734, 2352.8661537718],
+            [2.834e-08, 2.4734503745, 1577.3435424478],
+            [1.817e-08, 0.41874743765, 6283.0758499914],
+            [1.499e-08, 1.83320979291, 5856.4776591154],
+            [1.466e-08, 5.69401926017, 5753.3848848968],
+            [1.301e-08, 2.18890066314, 9437.762934887],
+            [1.233e-08, 4.95222451476, 10213.285546211],
+            [1.021e-08, 0.12866660208, 7860.4193924392],
+            [9.82e-09, 0.09005453285, 14143.4952424306],
+            [8.65e-09, 1.73949953555, 3930.2096962196],
+            [5.81e-09, 2.26949174067, 5884.9268465832],
+            [5.24e-09, 5.65662503159, 529.6909650946],
+            [4.73e-09, 6.22750969242, 6309.3741697912],
+            [4.51e-09, 1.53288619213, 18073.7049386502],
+            [3.64e-09, 3.61614477374, 13367.9726311066],
+            [3.72e-09, 3.2247072132, 6275.9623029906],
+            [2.68e-09, 2.34341267879, 11790.6290886588],
+            [3.22e-09, 0.94084045832, 6069.7767545534],
+            [2.32e-09, 0.26781182579, 7058.5984613154],
+            [2.16e-09, 6.05952221329, 10977.078804699],
+            [2.32e-09, 2.93325646109, 22003.9146348698],
+            [2.04e-09, 3.86264841382, 6496.3749454294],
+            [2.02e-09, 2.81892511133, 15720.8387848784],
+            [1.85e-09, 4.93512381859, 12036.4607348882],
+            [2.2e-09, 3.99305643742, 6812.766815086],
+            [1.66e-09, 1.74970002999, 11506.7697697936],
+            [2.12e-09, 1.57166285369, 4694.0029547076],
+            [1.57e-09, 1.08259734788, 5643.1785636774],
+            [1.54e-09, 5.99434678412, 5486.777843175],
+            [1.44e-09, 5.23285656085, 78051.5857313169],
+            [1.44e-09, 1.16454655948, 90617.7374312997],
+            [1.37e-09, 2.67760436027, 6290.1893969922],
+            [1.8e-09, 2.06509026215, 7084.8967811152],
+            [1.21e-09, 5.90212574947, 9225.539273283],
+            [1.5e-09, 2.00175038718, 5230.807466803],
+            [1.49e-09, 5.06157254516, 17298.1823273262],
+            [1.18e-09, 5.39979058038, 3340.6124266998],
+            [1.61e-09, 3.32421999691, 6283.3196674749],
+            [1.21e-09, 4.36722193162, 19651.048481098],
+            [1.16e-09, 5.83462858507, 4705.7323075436],
+            [1.28e-09, 4.35489873365, 25934.1243310894],
+            [1.43e-09, 0.0, 0.0],
+            [1.09e-09, 2.52157834166, 6438.4962494256],
+            [9.9e-10, 2.70727488041, 5216.5803728014],
+            [1.03e-09, 0.93782340879, 8827.3902698748],
+            [8.2e-10, 4.2921468039, 8635.9420037632],
+            [7.9e-10, 2.24085737326, 1059.3819301892],
+            [9.7e-10, 5.50959692365, 29864.334027309],
+            [7.2e-10, 0.21891639822, 21228.3920235458],
+            [7.1e-10, 2.86755026812, 6681.2248533996],
+            [7.4e-10, 2.20184828895, 37724.7534197482],
+            [6.3e-10, 4.45586625948, 7079.3738568078],
+            [6.1e-10, 0.63918772258, 33794.5437235286],
+            [4.7e-10, 2.09070235724, 3128.3887650958],
+            [4.7e-10, 3.325438433, 26087.9031415742],
+            [4.9e-10, 1.60680905005, 6702.5604938666],
+            [5.7e-10, 0.11215813438, 29088.811415985],
+            [5.6e-10, 5.47982934911, 775.522611324],
+            [5e-10, 1.89396788463, 12139.5535091068],
+            [4.7e-10, 2.9721490724, 20426.571092422],
+            [4.1e-10, 5.5532939489, 11015.1064773348],
+            [4.1e-10, 5.91861144924, 23581.2581773176],
+            [4.5e-10, 4.95273290181, 5863.5912061162],
+            [5e-10, 3.62740835096, 41654.9631159678],
+            [3.7e-10, 6.09033460601, 64809.80550494129],
+            [3.7e-10, 5.86153655431, 12566.1516999828],
+            [4.6e-10, 1.65798680284, 25158.6017197654],
+            [3.8e-10, 2.00673650251, 426.598190876],
+            [3.6e-10, 6.24373396652, 6283.14316029419],
+            [3.6e-10, 0.40465162918, 6283.0085396886],
+            [3.2e-10, 6.03707103538, 2942.4634232916],
+            [4.1e-10, 4.86809570283, 1592.5960136328],
+            [2.8e-10, 4.38359423735, 7632.9432596502],
+            [2.8e-10, 6.03334294232, 17789.845619785],
+            [2.6e-10, 3.88971333608, 5331.3574437408],
+            [2.6e-10, 5.94932724051, 16496.3613962024],
+            [3.1e-10, 1.44666331503, 16730.4636895958],
+            [2.6e-10, 6.26376705837, 23543.23050468179],
+            [3.3e-10, 0.93797239147, 213.299095438],
+            [2.6e-10, 3.71858432944, 13095.8426650774],
+            [2.7e-10, 0.60565274405, 10988.808157535],
+            [2.3e-10, 4.4438898555, 18849.2275499742],
+            [2.8e-10, 1.53862289477, 6279.4854213396],
+            [2.8e-10, 1.96831814872, 6286.6662786432],
+            [2.8e-10, 5.78094918529, 15110.4661198662],
+            [2.6e-10, 2.48165809843, 5729.506447149],
+            [2e-10, 3.85655029499, 9623.6882766912],
+            [2.1e-10, 5.83006047147, 7234.794256242],
+            [2.1e-10, 0.69628570421, 398.1490034082],
+            [2.2e-10, 5.02222806555, 6127.6554505572],
+            [2e-10, 3.4761126529, 6148.010769956],
+            [2e-10, 0.90769829044, 5481.2549188676],
+            [2e-10, 0.03081589303, 6418.1409300268],
+            [2e-10, 3.74220084927, 1589.0728952838],
+            [2.1e-10, 4.00149269576, 3154.6870848956],
+            [1.8e-10, 1.58348238359, 2118.7638603784],
+            [1.9e-10, 0.85407021371, 14712.317116458],
+        ],
+        &[
+            [1.662e-08, 1.62703209173, 84334.66158130829],
+            [4.92e-09, 2.41382223971, 1047.7473117547],
+            [3.44e-09, 2.24353004539, 5507.5532386674],
+            [2.58e-09, 6.00906896311, 5223.6939198022],
+            [1.31e-09, 0.9544734524, 6283.0758499914],
+            [8.6e-10, 1.67530247303, 7860.4193924392],
+            [9e-10, 0.97606804452, 1577.3435424478],
+            [9e-10, 0.37899871725, 2352.8661537718],
+            [8.9e-10, 6.25807507963, 10213.285546211],
+            [7.5e-10, 0.84213523741, 167621.5758508619],
+            [5.2e-10, 1.70501566089, 14143.4952424306],
+            [5.7e-10, 6.15295833679, 12194.0329146209],
+            [5.1e-10, 1.2761601674, 5753.3848848968],
+            [5.1e-10, 5.37229738682, 6812.766815086],
+            [3.4e-10, 1.73672994279, 7058.5984613154],
+            [3.8e-10, 2.77761031485, 10988.808157535],
+            [4.6e-10, 3.38617099014, 156475.2902479957],
+            [2.1e-10, 1.95248349228, 8827.3902698748],
+            [1.8e-10, 3.33419222028, 8429.2412664666],
+            [1.9e-10, 4.32945160287, 17789.845619785],
+            [1.7e-10, 0.66191210656, 6283.0085396886],
+            [1.8e-10, 3.74885333072, 11769.8536931664],
+            [1.7e-10, 4.23058370776, 10977.078804699],
+            [1.7e-10, 1.78116162721, 5486.777843175],
+            [2.1e-10, 1.36972913918, 12036.4607348882],
+            [1.7e-10, 2.79601092529, 796.2980068164],
+            [1.5e-10, 0.4308784885, 11790.6290886588],
+            [1.7e-10, 1.35132152761, 78051.5857313169],
+            [1.5e-10, 1.17032155085, 213.299095438],
+            [1.8e-10, 2.85221514199, 5088.6288397668],
+            [1.7e-10, 0.21780913672, 6283.14316029419],
+            [1.3e-10, 1.21201504386, 25132.3033999656],
+            [1.2e-10, 1.12953712197, 90617.7374312997],
+            [1.2e-10, 5.13714452592, 7079.3738568078],
+            [1.3e-10, 3.79842135217, 4933.2084403326],
+            [1.2e-10, 4.89407978213, 3738.761430108],
+            [1.5e-10, 6.05682328852, 398.1490034082],
+            [1.4e-10, 4.81029291856, 4694.0029547076],
+            [1.1e-10, 0.61684523405, 3128.3887650958],
+            [1.1e-10, 5.328765385, 6040.3472460174],
+            [1.4e-10, 5.27227350286, 4535.0594369244],
+            [1.1e-10, 2.39292099451, 5331.3574437408],
+            [1e-10, 4.4529653271, 6525.8044539654],
+            [1.4e-10, 4.66400985037, 8031.0922630584],
+            [1e-10, 3.22472385926, 9437.762934887],
+            [1.1e-10, 3.80913404437, 801.8209311238],
+            [1e-10, 5.15032130575, 11371.7046897582],
+            [1.3e-10, 0.98720797401, 5729.506447149],
+            [9e-11, 5.94191743597, 7632.9432596502],
+        ],
+        &[
+            [1.1e-10, 0.23877262399, 7860.4193924392],
+            [9e-11, 1.16069982609, 5507.5532386674],
+            [8e-11, 1.65357552925, 5884.9268465832],
+            [8e-11, 2.86720038197, 7058.5984613154],
+            [7e-11, 3.04818741666, 5486.777843175],
+            [7e-11, 2.59437103785, 529.6909650946],
+            [8e-11, 4.02863090524, 6256.7775301916],
+            [8e-11, 2.42003508927, 5753.3848848968],
+            [6e-11, 0.84181087594, 6275.9623029906],
+            [6e-11, 5.40160929468, 1577.3435424478],
+            [7e-11, 2.73399865247, 6309.3741697912],
+        ],
+        &[
+            [4e-11, 0.79662198849, 6438.4962494256],
+            [5e-11, 0.84308705203, 1047.7473117547],
+            [5e-11, 0.05711572303, 84334.66158130829],
+            [3e-11, 3.46779895686, 6279.5527316424],
+            [3e-11, 2.89822201212, 6127.6554505572],
+        ],
+    ],
+    &[
+        &[
+            [1.00013988799, 0.0, 0.0],
+            [0.01670699626, 3.09846350771, 6283.0758499914],
+            [0.00013956023, 3.0552460962, 12566.1516999828],
+            [3.08372e-05, 5.19846674381, 77713.7714681205],
+            [1.628461e-05, 1.17387749012, 5753.3848848968],
+            [1.575568e-05, 2.84685245825, 7860.4193924392],
+            [9.24799e-06, 5.45292234084, 11506.7697697936],
+            [5.42444e-06, 4.56409149777, 3930.2096962196],
+            [4.7211e-06, 3.66100022149, 5884.9268465832],
+            [3.2878e-06, 5.89983646482, 5223.6939198022],
+            [3.45983e-06, 0.96368617687, 5507.5532386674],
+            [3.06784e-06, 0.29867139512, 5573.1428014331],
+            [1.74844e-06, 3.01193636534, 18849.2275499742],
+            [2.43189e-06, 4.27349536153, 11790.6290886588],
+            [2.11829e-06, 5.84714540314, 1577.3435424478],
+            [1.85752e-06, 5.02194447178, 10977.078804699],
+            [1.09835e-06, 5.05510636285, 5486.777843175],
+            [9.8316e-07, 0.88681311277, 6069.7767545534],
+            [8.6499e-07, 5.68959778254, 15720.8387848784],
+            [8.5825e-07, 1.27083733351, 161000.6857376741],
+            [6.2916e-07, 0.92177108832, 529.6909650946],
+            [5.7056e-07, 2.01374292014, 83996.84731811189],
+            [6.4903e-07, 0.27250613787, 17260.1546546904],
+            [4.9384e-07, 3.24501240359, 2544.3144198834],
+            [5.5736e-07, 5.24159798933, 71430.69561812909],
+            [4.2515e-07, 6.01110242003, 6275.9623029906],
+            [4.6963e-07, 2.57805070386, 775.522611324],
+            [3.8968e-07, 5.36071738169, 4694.0029547076],
+            [4.4661e-07, 5.53715807302, 9437.762934887],
+            [3.566e-07, 1.67468058995, 12036.4607348882],
+            [3.1921e-07, 0.18368229781, 5088.6288397668],
+            [3.1846e-07, 1.77775642085, 398.1490034082],
+            [3.3193e-07, 0.24370300098, 7084.8967811152],
+            [3.8245e-07, 2.39255343974, 8827.3902698748],
+            [2.8464e-07, 1.21344868176, 6286.5989683404],
+            [3.749e-07, 0.82952922332, 19651.048481098],
+            [3.6957e-07, 4.90107591914, 12139.5535091068],
+            [3.4537e-07, 1.84270693282, 2942.4634232916],
+            [2.6275e-07, 4.58896850401, 10447.3878396044],
+            [2.4596e-07, 3.78660875483, 8429.2412664666],
+            [2.3587e-07, 0.26866117066, 796.2980068164],
+            [2.7793e-07, 1.89934330904, 6279.5527316424],
+            [2.3927e-07, 4.99598548138, 5856.4776591154],
+            [2.0349e-07, 4.65267995431, 2146.1654164752],
+            [2.3287e-07, 2.80783650928, 14143.4952424306],
+            [2.2103e-07, 1.95004702988, 3154.6870848956],
+            [1.9506e-07, 5.38227371393, 2352.8661537718],
+            [1.7958e-07, 0.19871379385, 6812.766815086],
+            [1.7174e-07, 4.43315560735, 10213.285546211],
+            [1.619e-07, 5.23160507859, 17789.845619785],
+            [1.7314e-07, 6.15200787916, 16730.4636895958],
+            [1.3814e-07, 5.18962074032, 8031.0922630584],
+            [1.8833e-07, 0.67306674027, 149854.4001348079],
+            [1.8331e-07, 2.25348733734, 23581.2581773176],
+            [1.3641e-07, 3.68516118804, 4705.7323075436],
+            [1.3139e-07, 0.65289581324, 13367.9726311066],
+            [1.0414e-07, 4.33285688538, 11769.8536931664],
+            [9.978e-08, 4.20126336355, 6309.3741697912],
+            [1.0169e-07, 1.59390681369, 4690.4798363586],
+            [7.564e-08, 2.6256059739, 6256.7775301916],
+            [9.661e-08, 3.6758679122, 27511.4678735372],
+            [6.743e-08, 0.56270332741, 3340.6124266998],
+            [8.743e-08, 6.06359123461, 1748.016413067],
+            [7.786e-08, 3.67371235637, 12168.0026965746],
+            [6.633e-08, 5.66149277792, 11371.7046897582],
+            [7.712e-08, 0.31242577789, 7632.9432596502],
+            [6.592e-08, 3.13576266188, 801.8209311238],
+            [7.46e-08, 5.64757188143, 11926.2544136688],
+            [6.933e-08, 2.923845864, 6681.2248533996],
+            [6.802e-08, 1.4232980642, 23013.5395395872],
+            [6.115e-08, 5.13393615454, 1194.4470102246],
+            [6.477e-08, 2.64986648492, 19804.8272915828],
+            [5.233e-08, 4.62434053374, 6438.4962494256],
+            [6.147e-08, 3.02863936662, 233141.3144043615],
+            [4.608e-08, 1.72194702724, 7234.794256242],
+            [4.221e-08, 1.55697533729, 7238.6755916],
+            [5.314e-08, 2.40716580847, 11499.6562227928],
+            [5.128e-08, 5.3239896569, 11513.8833167944],
+            [4.77e-08, 0.25554312006, 11856.2186514245],
+            [5.519e-08, 2.09089154502, 17298.1823273262],
+            [5.625e-08, 4.34052903053, 90955.5516944961],
+            [4.578e-08, 4.4656964157, 5746.271337896],
+            [3.788e-08, 4.9072938351, 4164.311989613],
+            [5.337e-08, 5.09957905104, 31441.6775697568],
+            [3.967e-08, 1.20054555174, 1349.8674096588],
+            [4.008e-08, 3.03007204392, 1059.3819301892],
+            [3.476e-08, 0.7608027703, 10973.55568635],
+            [4.232e-08, 1.05485713117, 5760.4984318976],
+            [4.582e-08, 3.76570026763, 6386.16862421],
+            [3.335e-08, 3.13829943354, 6836.6452528338],
+            [3.418e-08, 3.00072390334, 4292.3308329504],
+            [3.598e-08, 5.70718084323, 5643.1785636774],
+            [3.237e-08, 4.16448773994, 9917.6968745098],
+            [4.154e-08, 2.59941292162, 7058.5984613154],
+            [3.362e-08, 4.54577697964, 4732.0306273434],
+            [2.978e-08, 1.3056126882, 6283.14316029419],
+            [2.765e-08, 0.51311975679, 26.2983197998],
+            [2.802e-08, 5.66263240521, 8635.9420037632],
+            [2.927e-08, 5.73787481548, 16200.7727245012],
+            [3.164e-08, 1.69140262657, 11015.1064773348],
+            [2.598e-08, 2.96244118586, 25132.3033999656],
+            [3.519e-08, 3.62639325753, 244287.60000722768],
+            [2.676e-08, 4.2072570085, 18073.7049386502],
+            [2.978e-08, 1.74971565805, 6283.0085396886],
+            [2.287e-08, 1.06975704977, 14314.1681130498],
+            [2.863e-08, 5.92838131397, 14712.317116458],
+            [3.071e-08, 0.23793217002, 35371.8872659764],
+            [2.656e-08, 0.8995930178, 12352.8526045448],
+            [2.415e-08, 2.79975176257, 709.9330485583],
+            [2.814e-08, 3.51488206882, 21228.3920235458],
+            [1.977e-08, 2.6135829755, 951.7184062506],
+            [2.548e-08, 2.47684686575, 6208.2942514241],
+            [1.999e-08, 0.5609038816, 7079.3738568078],
+            [2.305e-08, 1.05376461628, 22483.84857449259],
+            [1.855e-08, 2.86090681163, 5216.5803728014],
+            [2.157e-08, 1.31396741861, 154717.6098876827],
+            [1.97e-08, 4.36929875289, 167283.7615876655],
+            [1.635e-08, 5.85571606764, 10984.1923516998],
+            [1.754e-08, 2.14452408833, 6290.1893969922],
+            [2.154e-08, 6.03828341543, 10873.9860304804],
+            [1.714e-08, 3.70157691113, 1592.5960136328],
+            [1.541e-08, 6.21598380732, 23543.23050468179],
+            [1.611e-08, 1.99824499377, 10969.9652576982],
+            [1.712e-08, 1.34295663542, 3128.3887650958],
+            [1.642e-08, 5.55026665339, 6496.3749454294],
+            [1.502e-08, 5.43948825854, 155.4203994342],
+            [1.827e-08, 5.91227480261, 3738.761430108],
+            [1.726e-08, 2.16764983583, 10575.4066829418],
+            [1.532e-08, 5.3568310707, 13521.7514415914],
+            [1.829e-08, 1.66006148731, 39302.096962196],
+            [1.605e-08, 1.90928637633, 6133.5126528568],
+            [1.282e-08, 2.46014880418, 13916.0191096416],
+            [1.211e-08, 4.4136063155, 3894.1818295422],
+            [1.394e-08, 1.77801929354, 9225.539273283],
+            [1.571e-08, 4.95512957592, 25158.6017197654],
+            [1.205e-08, 1.19212540615, 3.523118349],
+            [1.132e-08, 2.69830084955, 6040.3472460174],
+            [1.504e-08, 5.77002730341, 18209.33026366019],
+            [1.393e-08, 1.62621805428, 5120.6011455836],
+            [1.077e-08, 2.93931554233, 17256.6315363414],
+            [1.232e-08, 0.71655165307, 143571.32428481648],
+            [1.087e-08, 0.99769687939, 955.5997416086],
+            [1.068e-08, 5.28472576231, 65147.6197681377],
+            [9.8e-09, 5.10949204607, 6172.869528772],
+            [1.169e-08, 3.11664290862, 14945.3161735544],
+            [1.202e-08, 4.02992510402, 553.5694028424],
+            [9.79e-09, 2.00000879212, 15110.4661198662],
+            [9.62e-09, 4.023807714, 6282.0955289232],
+            [9.99e-09, 3.6264300279, 6262.300454499],
+            [1.03e-08, 5.84989900289, 213.299095438],
+            [1.014e-08, 2.84221578218, 8662.240323563],
+            [1.185e-08, 1.51330541132, 17654.7805397496],
+            [9.67e-09, 2.67081017562, 5650.2921106782],
+            [1.222e-08, 2.65423784904, 88860.05707098669],
+            [9.81e-09, 2.36370360283, 6206.8097787158],
+            [1.033e-08, 0.13874927606, 11712.9553182308],
+            [1.103e-08, 3.08477302937, 43232.3066584156],
+            [7.81e-09, 2.53372735932, 16496.3613962024],
+            [1.019e-08, 3.04569392376, 6037.244203762],
+            [7.95e-09, 5.80662989111, 5230.807466803],
+            [8.13e-09, 3.57710279439, 10177.2576795336],
+            [9.62e-09, 5.31470594766, 6284.0561710596],
+            [7.21e-09, 5.96264301567, 12559.038152982],
+            [9.66e-09, 2.74714939953, 6244.9428143536],
+            [9.21e-09, 0.10155275926, 29088.811415985],
+            [6.92e-09, 3.89764447548, 1589.0728952838],
+            [7.19e-09, 5.91791450402, 4136.9104335162],
+            [7.72e-09, 4.05505682353, 6127.6554505572],
+            [7.12e-09, 5.49291532439, 22003.9146348698],
+            [6.72e-09, 1.60700490811, 11087.2851259184],
+            [6.9e-09, 4.50539825563, 426.598190876],
+            [8.54e-09, 3.26104981596, 20426.571092422],
+            [6.56e-09, 4.3241018294, 16858.4825329332],
+            [8.4e-09, 2.59572585222, 28766.924424484],
+            [6.92e-09, 0.61650089011, 11403.676995575],
+            [7e-09, 3.40901167143, 7.1135470008],
+            [7.26e-09, 0.04243053594, 5481.2549188676],
+            [5.57e-09, 4.78317696534, 20199.094959633],
+            [6.49e-09, 1.04027912958, 6062.6632075526],
+            [6.33e-09, 5.70229959167, 45892.73043315699],
+            [5.92e-09, 6.11836729658, 9623.6882766912],
+            [5.23e-09, 3.62840021266, 5333.9002410216],
+            [6.04e-09, 5.57734696185, 10344.2950653858],
+            [4.96e-09, 2.21023499449, 1990.745017041],
+            [6.91e-09, 1.96071732602, 12416.5885028482],
+            [6.4e-09, 1.59074172032, 18319.5365848796],
+            [6.25e-09, 3.82362791378, 13517.8701062334],
+            [6.63e-09, 5.08444996779, 283.8593188652],
+            [4.75e-09, 1.17025894287, 12569.6748183318],
+            [6.64e-09, 4.50029469969, 47162.5163546352],
+            [5.69e-09, 0.16310365162, 17267.26820169119],
+            [5.68e-09, 3.86100969474, 6076.8903015542],
+            [5.39e-09, 4.83282276086, 18422.62935909819],
+            [4.66e-09, 0.75872342878, 7342.4577801806],
+            [5.41e-09, 3.07212190507, 226858.23855437007],
+            [4.58e-09, 0.26774483096, 4590.910180489],
+            [6.1e-09, 1.53597051291, 33019.0211122046],
+            [6.17e-09, 2.62356328726, 11190.377900137],
+            [5.48e-09, 4.55798855791, 18875.525869774],
+            [6.33e-09, 4.60110281228, 66567.48586525429],
+            [5.96e-09, 5.78202396722, 632.7837393132],
+            [5.33e-09, 5.01786882904, 12132.439962106],
+            [6.03e-09, 5.38458554802, 316428.22867391503],
+            [4.69e-09, 0.59168241917, 21954.15760939799],
+            [5.48e-09, 3.50613163558, 17253.04110768959],
+            [5.02e-09, 0.98804327589, 11609.8625440122],
+            [5.68e-09, 1.98497313089, 7668.6374249425],
+            [4.82e-09, 1.62141803864, 12146.6670561076],
+            [3.91e-09, 3.68718382989, 18052.9295431578],
+            [4.57e-09, 3.7720573734, 156137.47598479927],
+            [4.01e-09, 5.28260651958, 15671.0817594066],
+            [4.69e-09, 1.80963184268, 12562.6285816338],
+            [5.08e-09, 3.36399024699, 20597.2439630412],
+            [4.5e-09, 5.6605429925, 10454.5013866052],
+            [3.75e-09, 4.98534633105, 9779.1086761254],
+            [5.23e-09, 0.97215560834, 155427.542936241],
+            [4.03e-09, 5.13939866506, 1551.045222648],
+            [3.72e-09, 3.69883738807, 9388.0059094152],
+            [3.67e-09, 4.43875659716, 4535.0594369244],
+            [4.06e-09, 4.208631566, 12592.4500197826],
+            [3.6e-09, 2.53924644657, 242.728603974],
+            [4.71e-09, 4.61907324819, 5436.9930152402],
+            [4.41e-09, 5.83872966262, 3496.032826134],
+            [3.85e-09, 4.94496680973, 24356.7807886416],
+            [3.49e-09, 6.15018231784, 19800.9459562248],
+            [3.55e-09, 0.21895678106, 5429.8794682394],
+            [3.44e-09, 5.62993724928, 2379.1644735716],
+            [3.8e-09, 2.72105213143, 11933.3679606696],
+            [4.32e-09, 0.24221790536, 17996.0311682222],
+            [3.78e-09, 5.22517556974, 7477.522860216],
+            [3.37e-09, 5.10888041439, 5849.3641121146],
+            [3.15e-09, 0.57827745123, 10557.5941608238],
+            [3.18e-09, 4.49953141399, 3634.6210245184],
+            [3.23e-09, 1.54274281393, 10440.2742926036],
+            [3.09e-09, 5.76839284397, 20.7753954924],
+            [3.01e-09, 2.34727604008, 4686.8894077068],
+            [4.14e-09, 5.9323760231, 51092.7260508548],
+            [3.61e-09, 2.1639860955, 28237.2334593894],
+            [2.88e-09, 0.18376252189, 13095.8426650774],
+            [2.77e-09, 5.12952205045, 13119.72110282519],
+            [3.27e-09, 6.19222146204, 6268.8487559898],
+            [2.73e-09, 0.30522428863, 23141.5583829246],
+            [2.67e-09, 5.76152585786, 5966.6839803348],
+            [3.08e-09, 5.99280509979, 22805.7355659936],
+            [3.45e-09, 2.92489919444, 36949.2308084242],
+            [2.53e-09, 5.20995219509, 24072.9214697764],
+            [3.42e-09, 5.72702586209, 16460.33352952499],
+            [2.61e-09, 2.00304796059, 6148.010769956],
+            [2.38e-09, 5.08264392839, 6915.8595893046],
+            [2.49e-09, 2.94762789744, 135.0650800354],
+            [3.06e-09, 3.89764686987, 10988.808157535],
+            [3.05e-09, 0.05827812117, 4701.1165017084],
+            [3.19e-09, 2.95712862064, 163096.18036118348],
+            [2.09e-09, 4.43768461442, 6546.1597733642],
+            [2.7e-09, 2.06643178717, 4804.209275927],
+            [2.17e-09, 0.73691592312, 6303.8512454838],
+            [2.06e-09, 0.32075959415, 25934.1243310894],
+            [2.18e-09, 0.18428135264, 28286.9904848612],
+            [2.05e-09, 5.21312087405, 20995.3929664494],
+            [1.99e-09, 0.44384292491, 16737.5772365966],
+            [2.3e-09, 6.06567392849, 6287.0080032545],
+            [2.19e-09, 1.291942163, 5326.7866940208],
+            [2.01e-09, 1.74700937253, 22743.4093795164],
+            [2.07e-09, 4.45440927276, 6279.4854213396],
+            [2.69e-09, 6.0564044503, 64471.99124174489],
+            [1.9e-09, 0.99256176518, 29296.6153895786],
+            [2.38e-09, 5.42471431221, 39609.6545831656],
+            [2.62e-09, 5.26961924198, 522.5774180938],
+            [2.1e-09, 4.68618183158, 6254.6266625236],
+            [1.97e-09, 2.8062455408, 4933.2084403326],
+            [2.52e-09, 4.36220154608, 40879.4405046438],
+            [2.61e-09, 1.07241516738, 55022.9357470744],
+            [1.89e-09, 3.82966734476, 419.4846438752],
+            [1.85e-09, 4.14324541379, 5642.1982426092],
+            [2.47e-09, 3.44855612987, 6702.5604938666],
+            [2.05e-09, 4.04424043223, 536.8045120954],
+            [1.91e-09, 3.14082686083, 16723.350142595],
+            [2.22e-09, 5.16263907319, 23539.7073863328],
+            [1.8e-09, 4.56214752149, 6489.2613984286],
+            [2.19e-09, 0.80382553358, 16627.3709153772],
+            [2.27e-09, 0.60156339452, 5905.7022420756],
+            [1.68e-09, 0.88753528161, 16062.1845261168],
+            [1.58e-09, 0.92127725775, 23937.856389741],
+            [1.57e-09, 4.69607868164, 6805.6532680852],
+            [2.07e-09, 4.88410451334, 6286.6662786432],
+            [1.6e-09, 4.95943826846, 10021.8372800994],
+            [1.66e-09, 0.97126433565, 3097.88382272579],
+            [2.09e-09, 5.75663411805, 3646.3503773544],
+            [1.75e-09, 6.12762824412, 239424.39025435288],
+            [1.73e-09, 3.13887234973, 6179.9830757728],
+            [1.57e-09, 3.62822058179, 18451.07854656599],
+            [1.57e-09, 4.67695912235, 6709.6740408674],
+            [1.46e-09, 3.09506069735, 4907.3020501456],
+            [1.65e-09, 2.2713912876, 10660.6869350424],
+            [2.01e-09, 1.67701267433, 2107.0345075424],
+            [1.44e-09, 3.96947747592, 6019.9919266186],
+            [1.71e-09, 5.91302216729, 6058.7310542895],
+            [1.44e-09, 2.1315565512, 26084.0218062162],
+            [1.51e-09, 0.67417383554, 2388.8940204492],
+            [1.89e-09, 5.07122281033, 263.0839233728],
+            [1.46e-09, 5.10373877968, 10770.8932562618],
+            [1.87e-09, 1.23915444627, 19402.7969528166],
+            [1.74e-09, 0.08407293391, 9380.9596727172],
+            [1.37e-09, 1.26247412309, 12566.2190102856],
+            [1.37e-09, 3.52826010842, 639.897286314],
+            [1.48e-09, 1.76124372592, 5888.4499649322],
+            [1.64e-09, 2.39195095081, 6357.8574485587],
+            [1.46e-09, 2.43675816553, 5881.4037282342],
+            [1.61e-09, 1.15721259372, 26735.9452622132],
+            [1.31e-09, 2.51859277344, 6599.467719648],
+            [1.53e-09, 5.85203687779, 6281.5913772831],
+            [1.51e-09, 3.72338532649, 12669.2444742014],
+            [1.32e-09, 2.38417741883, 6525.8044539654],
+            [1.29e-09, 0.75556744143, 5017.508371365],
+            [1.27e-09, 0.00254936441, 10027.9031957292],
+            [1.48e-09, 2.85102145528, 6418.1409300268],
+            [1.43e-09, 5.74460279367, 26087.9031415742],
+            [1.72e-09, 0.4128996224, 174242.4659640497],
+            [1.36e-09, 4.15497742275, 6311.5250374592],
+            [1.7e-09, 5.98194913129, 327574.51427678124],
+            [1.24e-09, 1.65497607604, 32217.2001810808],
+            [1.36e-09, 2.48430783417, 13341.6743113068],
+            [1.65e-09, 2.496679246, 58953.145443294],
+            [1.23e-09, 3.45660563754, 6277.552925684],
+            [1.17e-09, 0.86065134175, 6245.0481773556],
+            [1.49e-09, 5.61358280963, 5729.506447149],
+            [1.53e-09, 0.2686002995, 245.8316462294],
+            [1.28e-09, 0.71204006588, 103.0927742186],
+            [1.59e-09, 2.43166592149, 221995.02880149524],
+            [1.3e-09, 2.80707316718, 6016.4688082696],
+            [1.37e-09, 1.70657709294, 12566.08438968],
+            [1.11e-09, 1.56305648432, 17782.7320727842],
+            [1.13e-09, 3.58302904101, 25685.872802808],
+            [1.09e-09, 3.26403795962, 6819.8803620868],
+            [1.22e-09, 0.34120688217, 1162.4747044078],
+            [1.19e-09, 5.84644718278, 12721.572099417],
+            [1.44e-09, 2.28899679126, 12489.8856287072],
+            [1.37e-09, 5.82029768354, 44809.6502008634],
+            [1.07e-09, 2.4281854414, 5547.1993364596],
+            [1.34e-09, 1.26539982939, 5331.3574437408],
+            [1.03e-09, 5.96518130595, 6321.1035226272],
+            [1.09e-09, 0.33808549034, 11300.5842213564],
+            [1.29e-09, 5.89187277327, 12029.3471878874],
+            [1.22e-09, 5.77325634636, 11919.140866668],
+            [1.07e-09, 6.2499898935, 77690.75950573849],
+            [1.07e-09, 1.00535580713, 77736.78343050249],
+            [1.43e-09, 0.24122178432, 4214.0690150848],
+            [1.43e-09, 0.88529649733, 7576.560073574],
+            [1.07e-09, 2.92124030496, 31415.379249957],
+            [9.9e-10, 5.70862227072, 5540.0857894588],
+            [1.1e-09, 0.37528037383, 5863.5912061162],
+            [1.04e-09, 4.44107178366, 2118.7638603784],
+            [9.8e-10, 5.95877916706, 4061.2192153944],
+            [1.13e-09, 1.24206857385, 84672.47584450469],
+            [1.24e-09, 2.55619029867, 12539.853380183],
+            [1.1e-09, 3.66952094329, 238004.5241572363],
+            [1.12e-09, 4.32512422943, 97238.62754448749],
+            [9.7e-10, 3.70151541181, 11720.0688652316],
+            [1.2e-09, 1.26895630252, 12043.574281889],
+            [9.4e-10, 2.56461130309, 19004.6479494084],
+            [1.17e-09, 3.65425622684, 34520.3093093808],
+            [9.8e-10, 0.13589994287, 11080.1715789176],
+            [9.7e-10, 5.38330115253, 7834.1210726394],
+            [9.7e-10, 2.46722096722, 71980.63357473118],
+            [9.5e-10, 5.36958330451, 6288.5987742988],
+            [1.11e-09, 5.01961920313, 11823.1616394502],
+            [9e-10, 2.72299804525, 26880.3198130326],
+            [9.9e-10, 0.90164266377, 18635.9284545362],
+            [1.26e-09, 4.78722177847, 305281.9430710488],
+            [9.3e-10, 0.21240380046, 18139.2945014159],
+            [1.24e-09, 5.00979495566, 172146.9713405403],
+            [9.9e-10, 5.67090026475, 16522.6597160022],
+            [9.2e-10, 2.28180963676, 12491.3701014155],
+            [9e-10, 4.50544881196, 40077.61957352],
+            [1e-09, 2.00639461612, 12323.4230960088],
+            [9.5e-10, 5.68801979087, 14919.0178537546],
+            [8.7e-10, 1.86043406047, 27707.5424942948],
+            [1.05e-09, 3.02903468417, 22345.2603761082],
+            [8.7e-10, 5.43970168638, 6272.0301497275],
+            [8.9e-10, 1.63389387182, 33326.5787331742],
+            [8.2e-10, 5.58298993353, 10241.2022911672],
+            [9.4e-10, 5.47749711149, 9924.8104215106],
+            [8.2e-10, 4.71988314145, 15141.390794312],
+            [9.7e-10, 5.61458778738, 2787.0430238574],
+            [9.6e-10, 3.89073946348, 6379.0550772092],
+            [8.1e-10, 3.13038482444, 36147.4098773004],
+            [1.1e-09, 4.89978492291, 72140.6286666874],
+            [9.7e-10, 5.20764563059, 6303.4311693902],
+            [8.2e-10, 5.26342716139, 9814.6041002912],
+            [1.09e-09, 2.3555558977, 83286.91426955358],
+            [9.7e-10, 2.58492958057, 30666.1549584328],
+            [9.3e-10, 1.32651591333, 23020.65308658799],
+            [7.8e-10, 3.99588630754, 11293.4706743556],
+            [9e-10, 0.57771932738, 26482.1708096244],
+            [1.06e-09, 3.92012705073, 62883.3551395136],
+            [9.8e-10, 2.94397773524, 316.3918696566],
+            [7.6e-10, 3.96310417608, 29026.48522950779],
+            [7.8e-10, 1.97068529306, 90279.92316810328],
+            [7.6e-10, 0.23027966596, 21424.4666443034],
+            [8e-10, 2.23099742212, 266.6070417218],
+            [7.9e-10, 1.46227790922, 8982.810669309],
+            [1.02e-09, 4.92129953565, 5621.8429232104],
+            [1e-09, 0.39243148321, 24279.10701821359],
+            [7.1e-10, 1.52014858474, 33794.5437235286],
+            [7.6e-10, 0.22880641443, 57375.8019008462],
+            [9.1e-10, 0.96515913904, 48739.859897083],
+            [7.5e-10, 2.77638585157, 12964.300703391],
+            [7.7e-10, 5.18846946344, 11520.9968637952],
+            [6.8e-10, 0.50006599129, 4274.5183108324],
+            [7.5e-10, 2.07323762803, 15664.03552270859],
+            [7.4e-10, 1.01884134928, 6393.2821712108],
+            [7.7e-10, 0.4666517878, 16207.886271502],
+            [8.1e-10, 4.10452219483, 161710.6187862324],
+            [6.7e-10, 3.83840630887, 6262.7205305926],
+            [7.1e-10, 3.91415523291, 7875.6718636242],
+            [8.1e-10, 0.91938383237, 74.7815985673],
+            [8.3e-10, 4.69916218791, 23006.42599258639],
+            [6.3e-10, 2.32556465878, 6279.1945146334],
+            [6.5e-10, 5.41938745446, 28628.3362260996],
+            [6.5e-10, 3.02336771694, 5959.570433334],
+            [6.4e-10, 3.3103319837, 2636.725472637],
+            [6.4e-10, 0.18375587519, 1066.49547719],
+            [8e-10, 5.81239171612, 12341.8069042809],
+            [6.6e-10, 2.15105504851, 38.0276726358],
+            [6.2e-10, 2.43313614978, 10138.1095169486],
+            [6e-10, 3.1615390647, 5490.300961524],
+            [6.9e-10, 0.30764736334, 7018.9523635232],
+            [6.8e-10, 2.24442548639, 24383.0791084414],
+            [7.8e-10, 1.39649386463, 9411.4646150872],
+            [6.3e-10, 0.72976362625, 6286.9571853494],
+            [7.3e-10, 4.95125917731, 6453.7487206106],
+            [7.8e-10, 0.32736023459, 6528.9074962208],
+            [5.9e-10, 4.95362151577, 35707.7100829074],
+            [7e-10, 2.37962727525, 15508.6151232744],
+            [7.3e-10, 1.35229143111, 5327.4761083828],
+            [7.2e-10, 5.91833527334, 10881.0995774812],
+            [5.9e-10, 5.36231868425, 10239.5838660108],
+            [5.9e-10, 1.63156134967, 61306.0115970658],
+            [5.4e-10, 4.29491690425, 21947.1113727],
+            [5.7e-10, 5.89190132575, 34513.2630726828],
+            [7.4e-10, 1.38235845304, 9967.4538999816],
+            [5.3e-10, 3.86543309344, 32370.9789915656],
+            [5.5e-10, 4.51794544854, 34911.412076091],
+            [6.3e-10, 5.41479412056, 11502.8376165305],
+            [6.3e-10, 2.34416220742, 11510.7019230567],
+            [6.8e-10, 0.77493931112, 29864.334027309],
+            [6e-10, 5.57024703495, 5756.9080032458],
+            [7.2e-10, 2.80863088166, 10866.8724834796],
+            [6.1e-10, 2.69736991384, 82576.9812209953],
+            [6.3e-10, 5.32068807257, 3116.6594122598],
+            [5.2e-10, 1.02278758099, 6272.4391846416],
+            [6.9e-10, 5.00698550308, 25287.7237993998],
+            [6.6e-10, 6.12047940728, 12074.488407524],
+            [5.1e-10, 2.59519527563, 11396.5634485742],
+            [5.6e-10, 2.57995973521, 17892.93839400359],
+            [5.9e-10, 0.4416723762, 250570.6758572191],
+            [5.9e-10, 3.84070143543, 5483.254724826],
+            [4.9e-10, 0.54704693048, 22594.05489571199],
+            [6.5e-10, 2.38423614501, 52670.0695933026],
+            [6.9e-10, 5.34363738671, 66813.5648357332],
+            [5.7e-10, 5.42770501007, 310145.1528239236],
+            [5.3e-10, 1.17760296075, 149.5631971346],
+            [6.1e-10, 4.02090887211, 34596.3646546524],
+            [4.9e-10, 4.18361320516, 18606.4989460002],
+            [5.5e-10, 0.83886167974, 20452.8694122218],
+            [5e-10, 1.46327331958, 37455.7264959744],
+            [4.8e-10, 4.53854727167, 29822.7832363242],
+            [5.8e-10, 3.34847975377, 33990.6183442862],
+            [6.5e-10, 1.45522693982, 76251.32777062019],
+            [5.6e-10, 2.35650663692, 37724.7534197482],
+            [5.2e-10, 2.61551081496, 5999.2165311262],
+            [5.3e-10, 0.17334326094, 77717.29458646949],
+            [5.3e-10, 0.79879700631, 77710.24834977149],
+            [4.7e-10, 0.43240779709, 735.8765135318],
+            [5.3e-10, 4.58763261686, 11616.976091013],
+            [4.8e-10, 6.20230111054, 4171.4255366138],
+            [5.2e-10, 1.09723616404, 640.8776073822],
+            [5.7e-10, 3.42008310383, 50317.2034395308],
+            [5.3e-10, 1.01528448581, 149144.46708624958],
+            [4.7e-10, 3.00924906195, 52175.8062831484],
+            [5.2e-10, 2.03254070404, 6293.7125153412],
+            [4.8e-10, 0.12356889734, 13362.4497067992],
+            [4.5e-10, 3.37963782356, 10763.779709261],
+            [4.7e-10, 5.50981287869, 12779.4507954208],
+            [6.2e-10, 5.45209070099, 949.1756089698],
+            [6.1e-10, 2.93237974631, 5791.4125575326],
+            [4.4e-10, 2.87440620802, 8584.6616659008],
+            [4.6e-10, 4.0314179656, 10667.8004820432],
+            [4.7e-10, 3.89902931422, 3903.9113764198],
+            [4.6e-10, 2.75700467329, 6993.0088985497],
+            [4.5e-10, 1.933862933, 206.1855484372],
+            [4.7e-10, 2.57670800912, 11492.542675792],
+            [4.4e-10, 3.62570223167, 63658.8777508376],
+            [5.1e-10, 0.84536826273, 12345.739057544],
+            [4.3e-10, 0.01524970172, 37853.8754993826],
+            [4.1e-10, 3.27146326065, 8858.3149443206],
+            [4.5e-10, 3.03765521215, 65236.2212932854],
+            [4.7e-10, 1.44447548944, 21393.5419698576],
+            [5.8e-10, 5.45843180927, 1975.492545856],
+            [5e-10, 2.13285524146, 12573.2652469836],
+            [4.1e-10, 1.32190847146, 2547.8375382324],
+            [4.7e-10, 3.67579608544, 28313.288804661],
+            [4.1e-10, 2.24013475126, 8273.8208670324],
+            [4.7e-10, 6.21438985953, 10991.3058987006],
+            [4.2e-10, 3.0163181735, 853.196381752],
+            [5.6e-10, 1.09773690181, 77376.2010224076],
+            [4e-10, 2.35698541041, 2699.7348193176],
+            [4.3e-10, 5.28030898459, 17796.9591667858],
+            [5.4e-10, 2.59175932091, 22910.44676536859],
+            [5.4e-10, 0.88027764102, 71960.38658322369],
+            [5.5e-10, 0.07988899477, 83467.15635301729],
+            [3.9e-10, 1.12867321442, 9910.583327509],
+            [4e-10, 1.35670430524, 27177.8515292002],
+            [3.9e-10, 4.39624220245, 5618.3198048614],
+            [4.2e-10, 4.78798367468, 7856.89627409019],
+            [4.7e-10, 2.75482175292, 18202.21671665939],
+            [3.9e-10, 1.97008298629, 24491.4257925834],
+            [4.2e-10, 4.04346599946, 7863.9425107882],
+            [3.8e-10, 0.49178679251, 38650.173506199],
+            [3.6e-10, 4.86047906533, 4157.1984426122],
+            [4.3e-10, 5.64354880978, 1062.9050485382],
+            [3.6e-10, 3.98066313627, 12565.1713789146],
+            [4.2e-10, 2.30753932657, 6549.6828917132],
+            [4e-10, 5.3969491832, 9498.2122306346],
+            [4e-10, 3.30603243754, 23536.11695768099],
+            [5e-10, 6.15760345261, 78051.34191383338],
+        ],
+        &[
+            [0.00103018608, 1.10748969588, 6283.0758499914],
+            [1.721238e-05, 1.06442301418, 12566.1516999828],
+            [7.02215e-06, 3.14159265359, 0.0],
+            [3.2346e-07, 1.02169059149, 18849.2275499742],
+            [3.0799e-07, 2.84353804832, 5507.5532386674],
+            [2.4971e-07, 1.31906709482, 5223.6939198022],
+            [1.8485e-07, 1.42429748614, 1577.3435424478],
+            [1.0078e-07, 5.91378194648, 10977.078804699],
+            [8.634e-08, 0.27146150602, 5486.777843175],
+            [8.654e-08, 1.42046854427, 6275.9623029906],
+            [5.069e-08, 1.68613426734, 5088.6288397668],
+            [4.985e-08, 6.01401770704, 6286.5989683404],
+            [4.669e-08, 5.98724494073, 529.6909650946],
+            [4.395e-08, 0.51800238019, 4694.0029547076],
+            [3.872e-08, 4.74969833437, 2544.3144198834],
+            [3.75e-08, 5.07097685568, 796.2980068164],
+            [4.1e-08, 1.08424786092, 9437.762934887],
+            [3.518e-08, 0.02290216272, 83996.84731811189],
+            [3.436e-08, 0.94937019624, 71430.69561812909],
+            [3.221e-08, 6.15628775313, 2146.1654164752],
+            [3.414e-08, 5.41218322538, 775.522611324],
+            [2.863e-08, 5.48432847146, 10447.3878396044],
+            [2.52e-08, 0.24276941146, 398.1490034082],
+            [2.201e-08, 4.95216196651, 6812.766815086],
+            [2.186e-08, 0.41991743105, 8031.0922630584],
+            [2.838e-08, 3.42034351366, 2352.8661537718],
+            [2.554e-08, 6.13241878525, 6438.4962494256],
+            [1.932e-08, 5.31374608366, 8429.2412664666],
+            [2.429e-08, 3.09164528262, 4690.4798363586],
+            [1.73e-08, 1.5368620855, 4705.7323075436],
+            [2.25e-08, 3.68863633842, 7084.8967811152],
+            [2.093e-08, 1.28191783032, 1748.016413067],
+            [1.441e-08, 0.81656250862, 14143.4952424306],
+            [1.483e-08, 3.22225357771, 7234.794256242],
+            [1.754e-08, 3.22883705112, 6279.5527316424],
+            [1.583e-08, 4.09702349428, 11499.6562227928],
+            [1.575e-08, 5.53890170575, 3154.6870848956],
+            [1.847e-08, 1.82040335363, 7632.9432596502],
+            [1.504e-08, 3.63293385726, 11513.8833167944],
+            [1.337e-08, 4.64440864339, 6836.6452528338],
+            [1.275e-08, 2.69341415363, 1349.8674096588],
+            [1.352e-08, 6.15101580257, 5746.271337896],
+            [1.125e-08, 3.35673439497, 17789.845619785],
+            [1.47e-08, 3.65282991755, 1194.4470102246],
+            [1.177e-08, 2.57676109092, 13367.9726311066],
+            [1.101e-08, 4.49748696552, 4292.3308329504],
+            [1.234e-08, 5.65036509521, 5760.4984318976],
+            [9.84e-09, 0.65517395136, 5856.4776591154],
+            [9.28e-09, 2.32420318751, 10213.285546211],
+            [1.077e-08, 5.82812169132, 12036.4607348882],
+            [9.16e-09, 0.76613009583, 16730.4636895958],
+            [8.77e-09, 1.50137505051, 11926.2544136688],
+            [1.023e-08, 5.62076589825, 6256.7775301916],
+            [8.51e-09, 0.65709335533, 155.4203994342],
+            [8.02e-09, 4.10519132088, 951.7184062506],
+            [8.57e-09, 1.41661697538, 5753.3848848968],
+            [9.94e-09, 1.14418521187, 1059.3819301892],
+            [8.13e-09, 1.63948433322, 6681.2248533996],
+            [6.62e-09, 4.5520045226, 5216.5803728014],
+            [6.44e-09, 4.19478168733, 6040.3472460174],
+            [6.26e-09, 1.50767713598, 5643.1785636774],
+            [5.9e-09, 6.18277145205, 4164.311989613],
+            [6.35e-09, 0.52413263542, 6290.1893969922],
+            [6.5e-09, 0.9793569035, 25132.3033999656],
+            [5.68e-09, 2.30125315873, 10973.55568635],
+            [5.47e-09, 5.27256412213, 3340.6124266998],
+            [5.47e-09, 2.20144422886, 1592.5960136328],
+            [5.26e-09, 0.92464258226, 11371.7046897582],
+            [4.9e-09, 5.90951388655, 3894.1818295422],
+            [4.78e-09, 1.66857963179, 12168.0026965746],
+            [5.16e-09, 3.59803483887, 10969.9652576982],
+            [5.18e-09, 3.97914412373, 17298.1823273262],
+            [5.34e-09, 5.03740926442, 9917.6968745098],
+            [4.87e-09, 2.50545369269, 6127.6554505572],
+            [4.16e-09, 4.04828175503, 10984.1923516998],
+            [5.38e-09, 5.54081539805, 553.5694028424],
+            [4.02e-09, 2.16544019233, 7860.4193924392],
+            [5.53e-09, 2.32177369366, 11506.7697697936],
+            [3.67e-09, 3.3915253225, 6496.3749454294],
+            [3.6e-09, 5.34379853282, 7079.3738568078],
+            [3.37e-09, 3.61563704045, 11790.6290886588],
+            [4.56e-09, 0.30754294809, 801.8209311238],
+            [4.17e-09, 3.70009308674, 10575.4066829418],
+            [3.81e-09, 5.82033971802, 7058.5984613154],
+            [3.21e-09, 0.31988767355, 16200.7727245012],
+            [3.64e-09, 1.08414306177, 6309.3741697912],
+            [2.94e-09, 4.54798604957, 11856.2186514245],
+            [2.9e-09, 1.26473978562, 8635.9420037632],
+            [3.99e-09, 4.16998866302, 26.2983197998],
+            [2.62e-09, 5.08316906342, 10177.2576795336],
+            [2.43e-09, 2.2574609119, 11712.9553182308],
+            [2.37e-09, 1.05070575346, 242.728603974],
+            [2.75e-09, 3.45319481756, 5884.9268465832],
+            [2.55e-09, 5.38496831087, 21228.3920235458],
+            [3.07e-09, 4.24313526604, 3738.761430108],
+            [2.16e-09, 3.46037894728, 213.299095438],
+            [1.96e-09, 0.69029243914, 1990.745017041],
+            [1.98e-09, 5.16301829964, 12352.8526045448],
+            [2.14e-09, 3.91876200279, 13916.0191096416],
+            [2.12e-09, 4.00861198517, 5230.807466803],
+            [1.84e-09, 5.59805976614, 6283.14316029419],
+            [1.84e-09, 2.85275392124, 7238.6755916],
+            [1.79e-09, 2.54259058334, 14314.1681130498],
+            [2.25e-09, 1.64458698399, 4732.0306273434],
+            [2.36e-09, 5.58826125715, 6069.7767545534],
+            [1.87e-09, 2.72805985443, 6062.6632075526],
+            [1.84e-09, 6.04216273598, 6283.0085396886],
+            [2.3e-09, 3.62591335086, 6284.0561710596],
+            [1.63e-09, 2.19117396803, 18073.7049386502],
+            [1.72e-09, 0.9761295074, 3930.2096962196],
+            [2.15e-09, 1.04672844028, 3496.032826134],
+            [1.69e-09, 4.75084479006, 17267.26820169119],
+            [1.52e-09, 0.19390712179, 9779.1086761254],
+            [1.82e-09, 5.16288118255, 17253.04110768959],
+            [1.49e-09, 0.8094418426, 709.9330485583],
+            [1.63e-09, 2.1920957039, 6076.8903015542],
+            [1.86e-09, 5.01159497089, 11015.1064773348],
+            [1.34e-09, 0.97765485759, 65147.6197681377],
+            [1.41e-09, 4.38421981312, 4136.9104335162],
+            [1.58e-09, 4.60974280627, 9623.6882766912],
+            [1.33e-09, 3.30508592837, 154717.6098876827],
+            [1.63e-09, 6.11782626245, 3.523118349],
+            [1.74e-09, 1.58078542187, 7.1135470008],
+            [1.41e-09, 0.49976927274, 25158.6017197654],
+            [1.24e-09, 6.03440460031, 9225.539273283],
+            [1.5e-09, 5.30166336812, 13517.8701062334],
+            [1.27e-09, 1.92389511438, 22483.84857449259],
+            [1.21e-09, 2.37813129011, 167283.7615876655],
+            [1.2e-09, 3.98423684853, 4686.8894077068],
+            [1.17e-09, 5.81072642211, 12569.6748183318],
+            [1.22e-09, 5.60973054224, 5642.1982426092],
+            [1.57e-09, 3.40236426002, 16496.3613962024],
+            [1.29e-09, 2.10705116371, 1589.0728952838],
+            [1.16e-09, 0.55839966736, 5849.3641121146],
+            [1.23e-09, 1.52961392771, 12559.038152982],
+            [1.11e-09, 0.44848279675, 6172.869528772],
+            [1.23e-09, 5.81645568991, 6282.0955289232],
+            [1.5e-09, 4.26278409223, 3128.3887650958],
+            [1.06e-09, 2.27437761356, 5429.8794682394],
+            [1.04e-09, 4.42743707728, 23543.23050468179],
+            [1.21e-09, 0.39459045915, 12132.439962106],
+            [1.04e-09, 2.41842602527, 426.598190876],
+            [1.1e-09, 5.80381480447, 16858.4825329332],
+            [1e-09, 2.93805577485, 4535.0594369244],
+            [9.7e-10, 3.97935904984, 6133.5126528568],
+            [1.1e-09, 6.22339014386, 12146.6670561076],
+            [9.8e-10, 0.87576563709, 6525.8044539654],
+            [9.8e-10, 3.15248421301, 10440.2742926036],
+            [9.5e-10, 2.461684111, 3097.88382272579],
+            [8.8e-10, 0.23371480284, 13119.72110282519],
+            [9.8e-10, 5.77016493489, 7342.4577801806],
+            [9.2e-10, 6.03915555063, 20426.571092422],
+            [9.6e-10, 5.56909292561, 2388.8940204492],
+            [8.1e-10, 1.32131147691, 5650.2921106782],
+            [8.6e-10, 3.94529200528, 10454.5013866052],
+            [7.6e-10, 2.70729716925, 143571.32428481648],
+            [9.1e-10, 5.64100034152, 8827.3902698748],
+            [7.6e-10, 1.80783856698, 28286.9904848612],
+            [8.1e-10, 1.90858992196, 29088.811415985],
+            [7.5e-10, 3.40955892978, 5481.2549188676],
+            [6.9e-10, 4.49936170873, 17256.6315363414],
+            [8.8e-10, 1.10098454357, 11769.8536931664],
+            [6.6e-10, 2.78285801977, 536.8045120954],
+            [6.8e-10, 3.88179770758, 17260.1546546904],
+            [8.4e-10, 1.59303306354, 9380.9596727172],
+            [8.8e-10, 3.88076636762, 7477.522860216],
+            [6.1e-10, 6.17558202197, 11087.2851259184],
+            [6e-10, 4.34824715818, 6206.8097787158],
+            [8.2e-10, 4.59843208943, 9388.0059094152],
+            [7.9e-10, 1.63131230601, 4933.2084403326],
+            [7.8e-10, 4.20905757484, 5729.506447149],
+            [5.7e-10, 5.48157926651, 18319.5365848796],
+            [6e-10, 1.01261781084, 12721.572099417],
+            [5.6e-10, 1.63031935692, 15720.8387848784],
+            [5.5e-10, 0.24926735018, 15110.4661198662],
+            [6.1e-10, 5.93059279661, 12539.853380183],
+            [5.5e-10, 4.84298966314, 13095.8426650774],
+            [6.7e-10, 6.11690589247, 8662.240323563],
+            [5.4e-10, 5.73750638571, 3634.6210245184],
+            [7.4e-10, 1.05466745829, 16460.33352952499],
+            [5.3e-10, 2.29084335688, 16062.1845261168],
+            [6.4e-10, 2.13513767927, 7875.6718636242],
+            [6.7e-10, 0.07096807518, 14945.3161735544],
+            [5.1e-10, 2.31511194429, 6262.7205305926],
+            [5.7e-10, 5.77055471237, 12043.574281889],
+            [5.6e-10, 4.41980790431, 4701.1165017084],
+            [5.9e-10, 5.87963500073, 5331.3574437408],
+            [5.8e-10, 2.30546168628, 955.5997416086],
+            [4.9e-10, 1.93839278478, 5333.9002410216],
+            [4.8e-10, 2.69973662261, 6709.6740408674],
+            [6.4e-10, 1.64379897981, 6262.300454499],
+            [4.6e-10, 3.98449608961, 98068.53671630539],
+            [5e-10, 3.68875893005, 12323.4230960088],
+            [4.5e-10, 3.30068569697, 22003.9146348698],
+            [4.7e-10, 1.26317154881, 11919.140866668],
+            [4.5e-10, 0.89150445122, 51868.2486621788],
+            [4.3e-10, 1.61526242998, 6277.552925684],
+            [4.3e-10, 5.74295325645, 11403.676995575],
+            [4.4e-10, 3.43070646822, 10021.8372800994],
+            [5.6e-10, 0.02481833774, 15671.0817594066],
+            [5.5e-10, 3.14274403422, 33019.0211122046],
+            [4.5e-10, 3.00877289177, 8982.810669309],
+            [4.6e-10, 0.73303568429, 6303.4311693902],
+            [4.9e-10, 1.60455690285, 6303.8512454838],
+            [4.5e-10, 0.40210030323, 6805.6532680852],
+            [5.3e-10, 0.94869680175, 10988.808157535],
+            [4.1e-10, 1.61122384329, 6819.8803620868],
+            [5.5e-10, 0.89439119424, 11933.3679606696],
+            [4.5e-10, 3.88495384656, 60530.4889857418],
+            [4e-10, 4.75740908001, 38526.574350872],
+            [4e-10, 1.49921251887, 18451.07854656599],
+            [4e-10, 3.77498297228, 26087.9031415742],
+            [5.1e-10, 1.70258603562, 1551.045222648],
+            [3.9e-10, 2.97100699926, 2118.7638603784],
+            [5.3e-10, 5.19854123078, 77713.7714681205],
+            [4.7e-10, 4.26356628717, 21424.4666443034],
+            [3.7e-10, 0.62902722802, 24356.7807886416],
+            [3.6e-10, 0.11087914947, 10344.2950653858],
+            [3.6e-10, 0.77037556319, 12029.3471878874],
+            [3.5e-10, 3.30933994515, 24072.9214697764],
+            [3.5e-10, 5.93650887012, 31570.7996493912],
+            [3.6e-10, 2.15108874765, 30774.5016425748],
+            [3.6e-10, 1.75078825382, 16207.886271502],
+            [3.3e-10, 5.06264177921, 226858.23855437007],
+            [3.4e-10, 6.168913788, 24491.4257925834],
+            [3.5e-10, 3.19120695549, 32217.2001810808],
+            [3.4e-10, 2.31528650443, 55798.4583583984],
+            [3.2e-10, 4.21446357042, 15664.03552270859],
+            [3.9e-10, 1.24979117796, 6418.1409300268],
+            [3.7e-10, 4.1194365577, 2787.0430238574],
+            [3.2e-10, 1.6288771089, 639.897286314],
+            [3.8e-10, 5.89832942685, 640.8776073822],
+            [3.2e-10, 1.72442327688, 27433.88921587499],
+            [3.1e-10, 2.78828943753, 12139.5535091068],
+            [3.5e-10, 4.44608896525, 18202.21671665939],
+            [3.4e-10, 3.96287980676, 18216.443810661],
+            [3.3e-10, 4.73611335874, 16723.350142595],
+            [3.4e-10, 1.43910280005, 49515.382508407],
+            [3.1e-10, 0.23302920161, 23581.2581773176],
+            [2.9e-10, 2.0263384022, 11609.8625440122],
+            [3e-10, 2.5492323024, 9924.8104215106],
+            [3.2e-10, 4.91793198558, 11300.5842213564],
+            [2.8e-10, 0.26187189577, 13521.7514415914],
+            [2.8e-10, 3.84568936822, 2699.7348193176],
+            [2.9e-10, 1.83149729794, 29822.7832363242],
+            [3.3e-10, 4.60320094415, 19004.6479494084],
+            [2.7e-10, 4.46183450287, 6702.5604938666],
+            [3e-10, 4.4649407224, 36147.4098773004],
+            [2.7e-10, 0.03211931363, 6279.7894925736],
+            [2.6e-10, 5.46497324333, 6245.0481773556],
+            [3.5e-10, 4.52695674113, 36949.2308084242],
+            [2.7e-10, 3.52528177609, 10770.8932562618],
+            [2.6e-10, 1.48499438453, 11080.1715789176],
+            [3.5e-10, 2.82154380962, 19402.7969528166],
+            [2.5e-10, 2.46339998836, 6279.4854213396],
+            [2.6e-10, 4.97688894643, 16737.5772365966],
+            [2.6e-10, 2.36136541526, 17996.0311682222],
+            [2.9e-10, 4.15148654061, 45892.73043315699],
+            [2.6e-10, 4.50714272714, 17796.9591667858],
+            [2.7e-10, 4.72625223674, 1066.49547719],
+            [2.5e-10, 2.89309528854, 6286.6662786432],
+            [2.7e-10, 0.37462444357, 12964.300703391],
+            [2.9e-10, 4.94860010533, 5863.5912061162],
+            [3.1e-10, 3.93096113577, 29864.334027309],
+            [2.4e-10, 6.14987193584, 18606.4989460002],
+            [2.4e-10, 3.74225964547, 29026.48522950779],
+            [2.5e-10, 5.70460621565, 27707.5424942948],
+            [2.5e-10, 5.33928840652, 15141.390794312],
+            [2.7e-10, 3.0232089714, 6286.3622074092],
+            [2.3e-10, 0.28364955406, 5327.4761083828],
+            [2.6e-10, 1.34240461687, 18875.525869774],
+            [2.4e-10, 1.33998410121, 19800.9459562248],
+            [2.5e-10, 6.00172494004, 6489.2613984286],
+            [2.2e-10, 1.81777974484, 6288.5987742988],
+            [2.2e-10, 3.5860360664, 6915.8595893046],
+            [2.9e-10, 2.09564449439, 15265.8865193004],
+            [2.2e-10, 1.02173599251, 11925.2740926006],
+            [2.2e-10, 4.74660932338, 28230.18722269139],
+            [2.1e-10, 2.30688751432, 5999.2165311262],
+            [2.1e-10, 3.2265494443, 25934.1243310894],
+            [2.1e-10, 3.04956726238, 6566.9351688566],
+            [2.7e-10, 5.35653084499, 33794.5437235286],
+            [2.8e-10, 3.91168324815, 18208.349942592],
+            [2e-10, 1.52296293311, 135.0650800354],
+            [2.2e-10, 4.66462839521, 13362.4497067992],
+            [1.9e-10, 1.78121167862, 156137.47598479927],
+            [1.9e-10, 2.99969102221, 19651.048481098],
+            [1.9e-10, 2.86664273362, 18422.62935909819],
+            [2.5e-10, 0.94995632141, 31415.379249957],
+            [1.9e-10, 4.71432851499, 77690.75950573849],
+            [1.9e-10, 2.54227398241, 77736.78343050249],
+            [2e-10, 5.91915117116, 48739.859897083],
+        ],
+        &[
+            [4.359385e-05, 5.78455133738, 6283.0758499914],
+            [1.23633e-06, 5.57934722157, 12566.1516999828],
+            [1.2341e-07, 3.14159265359, 0.0],
+            [8.792e-08, 3.62777733395, 77713.7714681205],
+            [5.689e-08, 1.86958905084, 5573.1428014331],
+            [3.301e-08, 5.47027913302, 18849.2275499742],
+            [1.471e-08, 4.48028885617, 5507.5532386674],
+            [1.013e-08, 2.81456417694, 5223.6939198022],
+            [8.54e-09, 3.10878241236, 1577.3435424478],
+            [1.102e-08, 2.84173992403, 161000.6857376741],
+            [6.48e-09, 5.47349498544, 775.522611324],
+            [6.09e-09, 1.37969434104, 6438.4962494256],
+            [4.99e-09, 4.4164924225, 6286.5989683404],
+            [4.17e-09, 0.90242451175, 10977.078804699],
+            [4.02e-09, 3.2037658529, 5088.6288397668],
+            [3.51e-09, 1.8107922777, 5486.777843175],
+            [4.67e-09, 3.65753702738, 7084.8967811152],
+            [4.58e-09, 5.38585314743, 149854.4001348079],
+            [3.04e-09, 3.51701098693, 796.2980068164],
+            [2.66e-09, 6.17413982699, 6836.6452528338],
+            [2.79e-09, 1.84120501086, 4694.0029547076],
+            [2.6e-09, 1.41629543251, 2146.1654164752],
+            [2.66e-09, 3.13832905677, 71430.69561812909],
+            [3.21e-09, 5.35313367048, 3154.6870848956],
+            [2.38e-09, 2.17720020018, 155.4203994342],
+            [2.93e-09, 4.61501268144, 4690.4798363586],
+            [2.29e-09, 4.7596958807, 7234.794256242],
+            [2.11e-09, 0.21868065485, 4705.7323075436],
+            [2.01e-09, 4.21905743357, 1349.8674096588],
+            [1.95e-09, 4.57808285364, 529.6909650946],
+            [2.53e-09, 2.81496293039, 1748.016413067],
+            [1.82e-09, 5.70454011389, 6040.3472460174],
+            [1.79e-09, 6.02897097053, 4292.3308329504],
+            [1.86e-09, 1.58690991244, 6309.3741697912],
+            [1.7e-09, 2.90220009715, 9437.762934887],
+            [1.66e-09, 1.99984925026, 8031.0922630584],
+            [1.58e-09, 0.04783713552, 2544.3144198834],
+            [1.97e-09, 2.01083639502, 1194.4470102246],
+            [1.65e-09, 5.78372596778, 83996.84731811189],
+            [2.14e-09, 3.38285934319, 7632.9432596502],
+            [1.4e-09, 0.36401486094, 10447.3878396044],
+            [1.51e-09, 0.95153163031, 6127.6554505572],
+            [1.36e-09, 1.48426306582, 2352.8661537718],
+            [1.27e-09, 5.48475435134, 951.7184062506],
+            [1.26e-09, 5.26866506592, 6279.5527316424],
+            [1.25e-09, 3.75754889288, 6812.766815086],
+            [1.01e-09, 4.95015746147, 398.1490034082],
+            [1.02e-09, 0.68468295277, 1592.5960136328],
+            [1e-09, 1.14568935785, 3894.1818295422],
+            [1.29e-09, 0.76540016965, 553.5694028424],
+            [1.09e-09, 5.41063597567, 6256.7775301916],
+            [7.5e-10, 5.84804322893, 242.728603974],
+            [9.5e-10, 1.94452244083, 11856.2186514245],
+            [7.7e-10, 0.69373708195, 8429.2412664666],
+            [1e-09, 5.19725292131, 244287.60000722768],
+            [8e-10, 6.18440483705, 1059.3819301892],
+            [6.9e-10, 5.25699888595, 14143.4952424306],
+            [8.5e-10, 5.39484725499, 25132.3033999656],
+            [6.6e-10, 0.51779993906, 801.8209311238],
+            [5.5e-10, 5.16878202461, 7058.5984613154],
+            [5.1e-10, 3.88759155247, 12036.4607348882],
+            [5e-10, 5.57636570536, 6290.1893969922],
+            [6.1e-10, 2.24359003264, 8635.9420037632],
+            [5e-10, 5.54441900966, 1990.745017041],
+            [5.6e-10, 4.0030107804, 13367.9726311066],
+            [5.2e-10, 4.13138898038, 7860.4193924392],
+            [5.2e-10, 3.90943054011, 26.2983197998],
+            [4.1e-10, 3.5712848278, 7079.3738568078],
+            [5.6e-10, 2.76959005761, 90955.5516944961],
+            [4.2e-10, 1.91461189199, 7477.522860216],
+            [4.2e-10, 0.42728171713, 10213.285546211],
+            [4.2e-10, 1.09413724455, 709.9330485583],
+            [3.9e-10, 3.93298068961, 10973.55568635],
+            [3.8e-10, 6.17935925345, 9917.6968745098],
+            [4.9e-10, 0.83021145241, 11506.7697697936],
+            [5.3e-10, 1.45828359397, 233141.3144043615],
+            [4.7e-10, 6.21568666789, 6681.2248533996],
+            [3.7e-10, 0.3635930998, 10177.2576795336],
+            [3.5e-10, 3.33024911524, 5643.1785636774],
+            [3.4e-10, 5.63446915337, 6525.8044539654],
+            [3.5e-10, 5.36033855038, 25158.6017197654],
+            [3.4e-10, 5.36319798321, 4933.2084403326],
+            [3.3e-10, 4.24722336872, 12569.6748183318],
+            [4.3e-10, 5.26370903404, 10575.4066829418],
+            [4.2e-10, 5.08837645072, 11015.1064773348],
+            [4e-10, 1.98334703186, 6284.0561710596],
+            [4.2e-10, 4.22496037505, 88860.05707098669],
+            [2.9e-10, 3.1908862817, 11926.2544136688],
+            [2.9e-10, 0.15217616684, 12168.0026965746],
+            [3e-10, 1.61904744136, 9779.1086761254],
+            [2.7e-10, 0.76388991416, 1589.0728952838],
+            [3.6e-10, 2.74712003443, 3738.761430108],
+            [3.3e-10, 3.08807829566, 3930.2096962196],
+            [3.1e-10, 5.34906619513, 143571.32428481648],
+            [2.5e-10, 0.10240267494, 22483.84857449259],
+            [3e-10, 3.47110495524, 14945.3161735544],
+            [2.4e-10, 1.10425016019, 4535.0594369244],
+            [2.4e-10, 1.5803725978, 6496.3749454294],
+            [2.3e-10, 3.87710321433, 6275.9623029906],
+            [2.5e-10, 3.9452977897, 3128.3887650958],
+            [2.3e-10, 3.44685609601, 4136.9104335162],
+            [2.3e-10, 3.83156029849, 5753.3848848968],
+            [2.2e-10, 1.86956128067, 16730.4636895958],
+            [2.5e-10, 2.42188933855, 5729.506447149],
+            [2e-10, 1.78208352927, 17789.845619785],
+            [2.1e-10, 4.303630874, 16858.4825329332],
+            [2.1e-10, 0.49258939822, 29088.811415985],
+            [2.5e-10, 1.33030250444, 6282.0955289232],
+            [2.7e-10, 2.54785812264, 3496.032826134],
+            [2.2e-10, 1.1123252195, 12721.572099417],
+            [2.1e-10, 5.97759081637, 7.1135470008],
+            [1.9e-10, 0.80292033311, 16062.1845261168],
+            [2.3e-10, 4.12454848769, 2388.8940204492],
+            [2.2e-10, 4.92663152168, 18875.525869774],
+            [2.3e-10, 5.68902059771, 16460.33352952499],
+            [2.3e-10, 4.97346265647, 17260.1546546904],
+            [2.3e-10, 3.03021283729, 66567.48586525429],
+            [1.6e-10, 3.89740925257, 5331.3574437408],
+            [1.7e-10, 3.08268671348, 154717.6098876827],
+            [1.6e-10, 3.95085099736, 3097.88382272579],
+            [1.6e-10, 3.99041783945, 6283.14316029419],
+            [2e-10, 6.10644140189, 167283.7615876655],
+            [1.5e-10, 4.09775914607, 11712.9553182308],
+            [1.6e-10, 5.717699407, 17298.1823273262],
+            [1.6e-10, 3.28894009404, 5884.9268465832],
+            [1.5e-10, 5.64785377164, 12559.038152982],
+            [1.6e-10, 4.4345208093, 6283.0085396886],
+            [1.4e-10, 2.31721603062, 5481.2549188676],
+            [1.4e-10, 4.43479032305, 13517.8701062334],
+            [1.4e-10, 4.73209312936, 7342.4577801806],
+            [1.2e-10, 0.64705975463, 18073.7049386502],
+            [1.1e-10, 1.514433322, 16200.7727245012],
+            [1.1e-10, 0.88708889185, 21228.3920235458],
+            [1.4e-10, 4.50116508534, 640.8776073822],
+            [1.1e-10, 4.64339996198, 11790.6290886588],
+            [1.1e-10, 1.31064298246, 4164.311989613],
+            [9e-11, 3.02238989305, 23543.23050468179],
+            [9e-11, 2.04999402381, 22003.9146348698],
+            [9e-11, 4.91488110218, 213.299095438],
+        ],
+        &[
+            [1.44595e-06, 4.27319435148, 6283.0758499914],
+            [6.729e-08, 3.91697608662, 12566.1516999828],
+            [7.74e-09, 0.0, 0.0],
+            [2.47e-09, 3.73019298781, 18849.2275499742],
+            [3.6e-10, 2.8008140905, 6286.5989683404],
+            [3.3e-10, 5.62216602775, 6127.6554505572],
+            [1.9e-10, 3.71292621802, 6438.4962494256],
+            [1.6e-10, 4.26011484232, 6525.8044539654],
+            [1.6e-10, 3.50416887054, 6256.7775301916],
+            [1.4e-10, 3.62127621114, 25132.3033999656],
+            [1.1e-10, 4.39200958819, 4705.7323075436],
+            [1.1e-10, 5.22327127059, 6040.3472460174],
+            [1e-10, 4.28045254647, 83996.84731811189],
+            [9e-11, 1.56864096494, 5507.5532386674],
+            [1.1e-10, 1.37795688024, 6309.3741697912],
+            [1e-10, 5.19937959068, 71430.69561812909],
+            [9e-11, 0.4727519993, 6279.5527316424],
+            [9e-11, 0.74642756529, 5729.506447149],
+            [7e-11, 2.9737489156, 775.522611324],
+            [7e-11, 3.28615691021, 7058.5984613154],
+            [7e-11, 2.19184402142, 6812.766815086],
+            [5e-11, 3.15419034438, 529.6909650946],
+            [6e-11, 4.54725567047, 1059.3819301892],
+            [5e-11, 1.51104406936, 7079.3738568078],
+            [7e-11, 2.98052059053, 6681.2248533996],
+            [5e-11, 2.30961231391, 12036.4607348882],
+            [5e-11, 3.71102966917, 6290.1893969922],
+        ],
+        &[
+            [3.858e-08, 2.56384387339, 6283.0758499914],
+            [3.06e-09, 2.2676950123, 12566.1516999828],
+            [5.3e-10, 3.44031471924, 5573.1428014331],
+            [1.5e-10, 2.04794573436, 18849.2275499742],
+            [1.3e-10, 2.05688873673, 77713.7714681205],
+            [7e-11, 4.4121885448, 161000.6857376741],
+            [5e-11, 5.26154653107, 6438.4962494256],
+            [5e-11, 4.07695126049, 6127.6554505572],
+            [6e-11, 3.81514213664, 149854.4001348079],
+            [3e-11, 1.28175749811, 6286.5989683404],
+        ],
+        &[
+            [8.6e-10, 1.21579741687, 6283.0758499914],
+            [1.2e-10, 0.65617264033, 12566.1516999828],
+            [1e-11, 0.38068797142, 18849.2275499742],
+        ],
+    ],
+];
@@ -24,47 +24,63 @@ THE SOFTWARE.
 
 use crate::angle;
 use crate::coordinate;
+use crate::math::polynomial;
 use crate::time;
 
-/*
-Returns the flattening factor of the Earth
+/// Flattening factor of the Earth
+///
+/// Reference: [World Geodetic System 1984](https://confluence.qps.nl/pages/viewpage.action?pageId=29855173)
+pub const FLATTENING_FACTOR: f64 = 1.0 / 298.257223563;
 
-Reference: [World Geodetic System 1984](https://confluence.qps.nl/pages/viewpage.action?pageId=29855173)
-*/
+/// Equatorial radius of the Earth | in kilometers
+///
+/// Reference: [World Geodetic System 1984](https://confluence.qps.nl/pages/viewpage.action?pageId=29855173)
+pub const EQUATORIAL_RADIUS: f64 = 6378.137;
+
+/// Polar radius of the Earth | in kilometers
+///
+/// Calculated from [FLATTENING_FACTOR](./constant.FLATTENING_FACTOR.html)
+/// and [EQUATORIAL_RADIUS](./constant.EQUATORIAL_RADIUS.html)
+pub const POLAR_RADIUS: f64 = EQUATORIAL_RADIUS * (1.0 - FLATTENING_FACTOR);
 
+/// Returns the flattening factor of the Earth
+#[deprecated(note = "use the `FLATTENING_FACTOR` constant")]
 #[inline(always)]
 pub fn flattening_factor() -> f64 {
-    1.0 / 298.257223563
+    FLATTENING_FACTOR
 }
 
-/*
-Returns the equatorial radius of the Earth | in kilometers
-
-Reference: [World Geodetic System 1984](https://confluence.qps.nl/pages/viewpage.action?pageId=29855173)
-*/
-
+/// Returns the equatorial radius of the Earth | in kilometers
+#[deprecated(note = "use the `EQUATORIAL_RADIUS` constant")]
 #[inline(always)]
 pub fn equatorial_radius() -> f64 {
-    6378.137
+    EQUATORIAL_RADIUS
 }
 
-/*
-Returns the polar radius of the Earth | in kilometers
-
-Calculated using [flattening_factor()](./fn.flattening_factor.html) and
-[equatorial_radius()](./fn.equatorial_radius.html)
-*/
-#[inline]
+/// Returns the polar radius of the Earth | in kilometers
+#[deprecated(note = "use the `POLAR_RADIUS` constant")]
+#[inline(always)]
 pub fn polar_radius() -> f64 {
-    equatorial_radius() * (1.0 - flattening_factor())
+    POLAR_RADIUS
 }
 
+/// Geocentric gravitational constant, GM of the Earth | in cubic
+/// kilometers per square second
+///
+/// Reference: [World Geodetic System 1984](https://confluence.qps.nl/pages/viewpage.action?pageId=29855173)
+pub const GRAVITATIONAL_PARAMETER: f64 = 398600.4418;
+
+/// Second zonal harmonic of the Earth's gravity field, J₂
+///
+/// Reference: [World Geodetic System 1984](https://confluence.qps.nl/pages/viewpage.action?pageId=29855173)
+pub const J2: f64 = 0.00108262998905;
+
 /*
 Return the eccentricity of the Earth meridian
 */
 #[inline]
 pub fn eccentricity_of_meridian() -> f64 {
-    (flattening_factor() * (2.0 - flattening_factor()).sqrt())
+    FLATTENING_FACTOR * (2.0 - FLATTENING_FACTOR).sqrt()
 }
 
 /*
@@ -84,7 +100,7 @@ pub fn approximate_geodesic_distance(
     p1: &coordinate::GeographPoint,
     p2: &coordinate::GeographPoint,
 ) -> f64 {
-    6371.0 * p1.angular_sep(&p2)
+    6371.0 * p1.angular_sep(p2)
 }
 
 /**
@@ -106,13 +122,13 @@ pub fn geodesic_distance(p1: &coordinate::GeographPoint, p2: &coordinate::Geogra
     let om = ((s / c).sqrt()).atan();
 
     let r = (s * c).sqrt() / om;
-    let d = 2.0 * om * equatorial_radius();
+    let d = 2.0 * om * EQUATORIAL_RADIUS;
 
     let h1 = (3.0 * r - 1.0) / (2.0 * c);
     let h2 = (3.0 * r + 1.0) / (2.0 * s);
 
-    d * (1.0 + flattening_factor() * h1 * (f.sin() * g.cos()).powi(2)
-        - flattening_factor() * h2 * (f.cos() * g.sin()).powi(2))
+    d * (1.0 + FLATTENING_FACTOR * h1 * (f.sin() * g.cos()).powi(2)
+        - FLATTENING_FACTOR * h2 * (f.cos() * g.sin()).powi(2))
 }
 
 /**
@@ -135,10 +151,10 @@ rho_sin_phi, rho_cos_phi
 * height      : Observer's height above sea level (meters)
 **/
 pub fn rho_sin_cos_phi(geograph_lat: f64, height: f64) -> (f64, f64) {
-    let u = (geograph_lat.tan() * polar_radius() / equatorial_radius()).atan();
-    let x = height / (equatorial_radius() * 1000.0);
+    let u = (geograph_lat.tan() * POLAR_RADIUS / EQUATORIAL_RADIUS).atan();
+    let x = height / (EQUATORIAL_RADIUS * 1000.0);
 
-    let rho_sin_phi = (u.sin() * polar_radius() / equatorial_radius()) + (geograph_lat.sin() * x);
+    let rho_sin_phi = (u.sin() * POLAR_RADIUS / EQUATORIAL_RADIUS) + (geograph_lat.sin() * x);
     let rho_cos_phi = u.cos() + (geograph_lat.cos() * x);
 
     (rho_sin_phi, rho_cos_phi)
@@ -162,11 +178,57 @@ pub fn distance_from_center(geograph_lat: f64) -> f64 {
     0.9983271 + 0.0016764 * (2.0 * geograph_lat).cos() - 0.0000035 * (4.0 * geograph_lat).cos()
 }
 
+/**
+Computes the geographical point and height of a point given in
+Earth-fixed rectangular coordinates
+
+# Returns
+
+(geograph_point, height)
+
+* geograph_point: Geographical point on the ellipsoid below the
+                  point | in radians
+* height        : Height above the ellipsoid | in kilometers
+
+The latitude is found by iteration, converging to well below a
+millimeter for points from the Earth's surface out to the Moon.
+
+# Arguments
+
+* rect_point: Geocentric point, with the x axis towards longitude 0
+              and the z axis towards the north pole | in kilometers
+**/
+pub fn geograph_from_rect(rect_point: &coordinate::RectPoint) -> (coordinate::GeographPoint, f64) {
+    let ecc_sqr = FLATTENING_FACTOR * (2.0 - FLATTENING_FACTOR);
+    let dist_from_axis = rect_point.x.hypot(rect_point.y);
+
+    let mut lat = rect_point.z.atan2(dist_from_axis * (1.0 - ecc_sqr));
+    let mut height = 0.0;
+    for _ in 0..5 {
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let prime_vertical = EQUATORIAL_RADIUS / (1.0 - ecc_sqr * sin_lat * sin_lat).sqrt();
+        height = dist_from_axis * cos_lat + rect_point.z * sin_lat
+            - EQUATORIAL_RADIUS * EQUATORIAL_RADIUS / prime_vertical;
+        lat = rect_point
+            .z
+            .atan2(dist_from_axis * (1.0 - ecc_sqr * prime_vertical / (prime_vertical + height)));
+    }
+
+    (
+        coordinate::GeographPoint::new(-rect_point.y.atan2(rect_point.x), lat),
+        height,
+    )
+}
+
+/// Rotational angular velocity of the Earth | in radians per second
+pub const ROTATIONAL_ANGULAR_VELOCITY: f64 = 0.00007292114992;
+
 /// Returns the rotational angular velocity of the Earth
-/// | in radian per second
+/// | in radians per second
+#[deprecated(note = "use the `ROTATIONAL_ANGULAR_VELOCITY` constant")]
 #[inline(always)]
 pub fn rotational_angular_velocity() -> f64 {
-    0.00007292114992
+    ROTATIONAL_ANGULAR_VELOCITY
 }
 
 /**
@@ -185,7 +247,7 @@ Computes the radius of the parallel of a latitude
 pub fn radius_of_parallel(geograph_lat: f64) -> f64 {
     let e = eccentricity_of_meridian();
 
-    equatorial_radius() * geograph_lat.cos() / (1.0 - (e * geograph_lat.sin()).powi(2)).sqrt()
+    EQUATORIAL_RADIUS * geograph_lat.cos() / (1.0 - (e * geograph_lat.sin()).powi(2)).sqrt()
 }
 
 /**
@@ -203,7 +265,7 @@ Computes the linear velocity of a point at a latitude
 **/
 #[inline(always)]
 pub fn linear_velocity_at_lat(geograph_lat: f64) -> f64 {
-    rotational_angular_velocity() * radius_of_parallel(geograph_lat)
+    ROTATIONAL_ANGULAR_VELOCITY * radius_of_parallel(geograph_lat)
 }
 
 /**
@@ -223,7 +285,7 @@ at a latitude
 pub fn radius_of_curvature(geograph_lat: f64) -> f64 {
     let e = eccentricity_of_meridian();
 
-    equatorial_radius() * (1.0 - e * e) / (1.0 - (e * geograph_lat.sin()).powi(2)).powf(1.5)
+    EQUATORIAL_RADIUS * (1.0 - e * e) / (1.0 - (e * geograph_lat.sin()).powi(2)).powf(1.5)
 }
 
 /**
@@ -266,6 +328,92 @@ pub fn equation_of_time(jd: f64, sun_asc: f64, nut_long: f64, true_obliquity: f6
         .to_radians()
 }
 
+/**
+Computes the eccentricity of the Earth's orbit
+
+# Returns
+
+* e: Eccentricity of the orbit
+
+Meeus, Astronomical Algorithms, equation 25.4.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn orbital_eccentricity(jd: f64) -> f64 {
+    polynomial::horner(
+        &[0.016708634, -0.000042037, -0.0000001267],
+        time::julian_century(jd),
+    )
+}
+
+/**
+Computes the longitude of the perihelion of the Earth's orbit
+
+# Returns
+
+* long_of_perihelion: Longitude of the perihelion, referred to the
+                      mean equinox of the date | in radians
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn long_of_perihelion(jd: f64) -> f64 {
+    polynomial::horner(&[102.93735, 1.71946, 0.00046], time::julian_century(jd)).to_radians()
+}
+
+/**
+Computes the true anomaly of the Earth
+
+# Returns
+
+* true_anom: True anomaly of the Earth in its orbit | in radians
+
+The mean anomaly and the equation of the center are those of Meeus,
+Astronomical Algorithms, chapter 25, accurate to about 0.01 degree.
+The true anomaly of the Earth equals that of the Sun in its apparent
+geocentric orbit.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn true_anomaly(jd: f64) -> f64 {
+    let t = time::julian_century(jd);
+    let mean_anom = polynomial::horner(&[357.52911, 35999.05029, -0.0001537], t).to_radians();
+    let center = polynomial::horner(&[1.914602, -0.004817, -0.000014], t) * mean_anom.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * mean_anom).sin()
+        + 0.000289 * (3.0 * mean_anom).sin();
+
+    angle::limit_twoPI(mean_anom + center.to_radians())
+}
+
+/**
+Computes the orbital velocity of the Earth
+
+# Returns
+
+* velocity: Heliocentric velocity of the Earth | in kilometers per
+            second
+
+The velocity follows from the radius vector of the elliptic orbit by
+the vis-viva equation, Meeus, Astronomical Algorithms, equation
+33.7.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn orbital_velocity(jd: f64) -> f64 {
+    let semimajor_axis = 1.000001018;
+    let e = orbital_eccentricity(jd);
+    let rad_vec = semimajor_axis * (1.0 - e * e) / (1.0 + e * true_anomaly(jd).cos());
+
+    42.1219 * (1.0 / rad_vec - 1.0 / (2.0 * semimajor_axis)).sqrt()
+}
+
 /**
 Computes the angle between diurnal path and the horizon
 
@@ -1,2 +1,232 @@
+#[allow(dead_code, clippy::approx_constant)]
 mod VSOPD_87;
 pub mod earth;
+
+use crate::aberration;
+use crate::angle;
+use crate::coordinate;
+use crate::nutation;
+use crate::time;
+use crate::Accuracy;
+use std::f64::consts::PI;
+
+/// Represents a planet with VSOP87 series available in the library
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Planet {
+    /// Earth
+    Earth,
+    /// Mars
+    Mars,
+}
+
+// Amplitude below which VSOP87 terms are skipped with `Accuracy::Low`
+const LOW_ACCURACY_CUTOFF: f64 = 1e-6;
+
+/**
+Computes the heliocentric coordinates of a planet
+
+# Returns
+
+(long, lat, rad_vec)
+
+* long   : Heliocentric longitude | in radians
+* lat    : Heliocentric latitude | in radians
+* rad_vec: Heliocentric radius vector | in AU
+
+The coordinates are referred to the mean ecliptic and equinox of the
+date, as given by the VSOP87D theory. With `Accuracy::Low`, terms
+with amplitudes below 10⁻⁶ are skipped.
+
+# Arguments
+
+* planet  : The planet
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn heliocent_coords(planet: &Planet, jd: f64, accuracy: Accuracy) -> (f64, f64, f64) {
+    let terms = match planet {
+        Planet::Earth => VSOPD_87::earth::TERMS,
+        Planet::Mars => VSOPD_87::mars::TERMS,
+    };
+
+    let t = time::julian_millennium(jd);
+
+    let long = angle::limit_twoPI(eval_series(terms[0], t, accuracy));
+    let lat = eval_series(terms[1], t, accuracy);
+    let rad_vec = eval_series(terms[2], t, accuracy);
+
+    (long, lat, rad_vec)
+}
+
+/**
+Computes the astrometric geocentric ecliptic position of a planet
+
+# Returns
+
+(ecl_point, earth_planet_dist)
+
+* ecl_point        : Astrometric geocentric ecliptic point of the
+                     planet, referred to the mean equinox of the date
+                     | in radians
+* earth_planet_dist: Earth-planet distance | in AU
+
+The position is corrected for light-time only, and converted to the
+FK5 system. This is the place to compare with star catalogs, or with
+the astrometric output of JPL Horizons once both are referred to the
+same equinox.
+
+# Arguments
+
+* planet  : The planet. *Can't be `Planet::Earth`.*
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn geocent_astrometric_ecl_pos(
+    planet: &Planet,
+    jd: f64,
+    accuracy: Accuracy,
+) -> Result<(coordinate::EclPoint, f64), &'static str> {
+    if *planet == Planet::Earth {
+        return Err("Planet::Earth was passed to planet::geocent_astrometric_ecl_pos()");
+    }
+
+    let (ecl_point, dist, _) = astrometric_ecl_pos(planet, jd, accuracy);
+
+    Ok((ecl_point, dist))
+}
+
+// Astrometric geocentric ecliptic point and distance of a planet, and
+// the heliocentric longitude of the Earth
+fn astrometric_ecl_pos(
+    planet: &Planet,
+    jd: f64,
+    accuracy: Accuracy,
+) -> (coordinate::EclPoint, f64, f64) {
+    let (earth_long, earth_lat, earth_rad_vec) = heliocent_coords(&Planet::Earth, jd, accuracy);
+    let (earth_x, earth_y, earth_z) = rect_from_spherical(earth_long, earth_lat, earth_rad_vec);
+
+    let mut light_time = 0.0;
+    let mut geocent = (0.0, 0.0, 0.0);
+    for _ in 0..3 {
+        let (long, lat, rad_vec) = heliocent_coords(planet, jd - light_time, accuracy);
+        let (x, y, z) = rect_from_spherical(long, lat, rad_vec);
+        geocent = (x - earth_x, y - earth_y, z - earth_z);
+
+        let dist = (geocent.0 * geocent.0 + geocent.1 * geocent.1 + geocent.2 * geocent.2).sqrt();
+        light_time = aberration::light_time(dist);
+    }
+
+    let (x, y, z) = geocent;
+    let dist = (x * x + y * y + z * z).sqrt();
+    let long = y.atan2(x);
+    let lat = z.atan2((x * x + y * y).sqrt());
+    let (long, lat) = fk5_correction(long, lat, jd);
+
+    (coordinate::EclPoint { long, lat }, dist, earth_long)
+}
+
+/**
+Computes the apparent geocentric ecliptic position of a planet
+
+# Returns
+
+(ecl_point, earth_planet_dist)
+
+* ecl_point        : Apparent geocentric ecliptic point of the planet,
+                     referred to the true equinox of the date | in radians
+* earth_planet_dist: Earth-planet distance | in AU
+
+The position is corrected for light-time, aberration and nutation,
+and converted to the FK5 system: it is the
+[astrometric position](./fn.geocent_astrometric_ecl_pos.html) with
+the annual aberration and the nutation in longitude added.
+
+# Arguments
+
+* planet  : The planet. *Can't be `Planet::Earth`.*
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn geocent_apparent_ecl_pos(
+    planet: &Planet,
+    jd: f64,
+    accuracy: Accuracy,
+) -> Result<(coordinate::EclPoint, f64), &'static str> {
+    if *planet == Planet::Earth {
+        return Err("Planet::Earth was passed to planet::geocent_apparent_ecl_pos()");
+    }
+
+    let (ecl_point, dist, earth_long) = astrometric_ecl_pos(planet, jd, accuracy);
+
+    let (aberr_long, aberr_lat) =
+        aberration::annual(ecl_point.long, ecl_point.lat, earth_long + PI, jd);
+    let (nut_in_long, _) = nutation::nutation(jd, accuracy);
+
+    Ok((
+        coordinate::EclPoint {
+            long: angle::limit_twoPI(ecl_point.long + aberr_long + nut_in_long),
+            lat: ecl_point.lat + aberr_lat,
+        },
+        dist,
+    ))
+}
+
+/**
+Converts VSOP87 ecliptic coordinates referred to the dynamical equinox
+to the FK5 system
+
+# Returns
+
+(long, lat)
+
+* long: Ecliptic longitude in the FK5 system | in radians
+* lat : Ecliptic latitude in the FK5 system | in radians
+
+# Arguments
+
+* long: Ecliptic longitude | in radians
+* lat : Ecliptic latitude | in radians
+* jd  : Julian (Ephemeris) day
+**/
+pub(crate) fn fk5_correction(long: f64, lat: f64, jd: f64) -> (f64, f64) {
+    let t = time::julian_century(jd);
+    let l1 = (long.to_degrees() - t * (1.397 + t * 0.00031)).to_radians();
+
+    let delta_long = angle::deg_dmas(0, 0, -0.09033)
+        + angle::deg_dmas(0, 0, 0.03916) * (l1.cos() + l1.sin()) * lat.tan();
+    let delta_lat = angle::deg_dmas(0, 0, 0.03916) * (l1.cos() - l1.sin());
+
+    (
+        angle::limit_twoPI(long + delta_long.to_radians()),
+        lat + delta_lat.to_radians(),
+    )
+}
+
+#[inline]
+fn rect_from_spherical(long: f64, lat: f64, rad_vec: f64) -> (f64, f64, f64) {
+    (
+        rad_vec * lat.cos() * long.cos(),
+        rad_vec * lat.cos() * long.sin(),
+        rad_vec * lat.sin(),
+    )
+}
+
+fn eval_series(series: &[&[[f64; 3]]], t: f64, accuracy: Accuracy) -> f64 {
+    let mut result = 0.0;
+    let mut t_pow = 1.0;
+
+    for terms in series.iter() {
+        let mut sum = 0.0;
+        for &[a, b, c] in terms.iter() {
+            if accuracy == Accuracy::Low && a < LOW_ACCURACY_CUTOFF {
+                continue;
+            }
+            sum += a * (b + c * t).cos();
+        }
+
+        result += sum * t_pow;
+        t_pow *= t;
+    }
+
+    result
+}
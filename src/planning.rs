@@ -0,0 +1,147 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------PLANNING-----------!
+
+use crate::angle;
+use crate::atmospheric;
+use crate::coordinate;
+use crate::ephemeris;
+use crate::search;
+use crate::time;
+use crate::Accuracy;
+
+// Fastest apparent motion of the Sun or the Moon across the sky, the
+// diurnal motion plus the Moon's own | in radians per day
+const MAX_SKY_RATE: f64 = 375.0 * std::f64::consts::PI / 180.0;
+
+/// Represents an instant at which a body lines up with a direction
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Alignment {
+    /// Time of the closest approach to the direction
+    pub time: time::Instant,
+    /// Azimuth of the body, measured westwards from the south
+    /// | in radians
+    pub az: f64,
+    /// Apparent altitude of the body, corrected for parallax and
+    /// refraction | in radians
+    pub alt: f64,
+    /// Angular separation of the body from the direction | in radians
+    pub separation: f64,
+}
+
+/**
+Finds the instants at which a body lines up with a direction
+
+# Returns
+
+* alignments: Closest approaches of the body to the direction within
+              `tolerance`, in increasing order of time
+
+This is meant for planning photographs such as the Moon rising
+behind a landmark: the direction is the one in which the landmark is
+seen, and the body is the Sun or the Moon. The center of the body is
+used, in its topocentric position corrected for refraction at
+standard conditions. The sky is sampled at steps short enough for
+passages near the center of the tolerance circle; passages grazing
+its edge may be missed. An error is returned unless `tolerance` is
+positive.
+
+# Arguments
+
+* body      : The body
+* point     : Observer's geographical point | in radians
+* target_az : Azimuth of the direction, measured westwards from the
+              south | in radians
+* target_alt: Apparent altitude of the direction | in radians
+* tolerance : Largest angular separation from the direction
+              | in radians
+* start_jd  : Julian day of the start of the search, UT
+* end_jd    : Julian day of the end of the search, UT
+**/
+pub fn find_alignments(
+    body: &ephemeris::Body,
+    point: &coordinate::GeographPoint,
+    target_az: f64,
+    target_alt: f64,
+    tolerance: f64,
+    start_jd: f64,
+    end_jd: f64,
+) -> Result<Vec<Alignment>, &'static str> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return Err("The tolerance must be positive");
+    }
+
+    let separation_at = |jd: f64| {
+        let (az, alt) = horizontal_pos(body, point, jd);
+        (angle::angular_sep(az, alt, target_az, target_alt), az, alt)
+    };
+
+    let step = tolerance / MAX_SKY_RATE;
+    search::intervals(start_jd, end_jd, step, 1.0 / 86400.0, |jd| {
+        separation_at(jd).0 < tolerance
+    })?
+    .into_iter()
+    .map(|(start, end)| {
+        // The separation has a single minimum within the interval, which
+        // is the interval itself when it is a single instant
+        let jd = if end > start {
+            search::minimize(start, end, end - start, 1.0 / 86400.0, |jd| {
+                separation_at(jd).0
+            })?
+            .0
+        } else {
+            start
+        };
+
+        let (separation, az, alt) = separation_at(jd);
+        Ok(Alignment {
+            time: time::Instant::from_ut(jd),
+            az,
+            alt,
+            separation,
+        })
+    })
+    .collect()
+}
+
+// Topocentric azimuth and refracted altitude of a body
+fn horizontal_pos(
+    body: &ephemeris::Body,
+    point: &coordinate::GeographPoint,
+    jd: f64,
+) -> (f64, f64) {
+    let observer = coordinate::Observer::new(point.long, point.lat, 0.0);
+    let (ha_point, _) =
+        ephemeris::topocent_ha_pos(body, &observer, &time::Instant::from_ut(jd), Accuracy::Low);
+
+    // The refraction formula holds down to about a degree below the
+    // horizon
+    let (az, alt) = ha_point.horizontal(point.lat);
+    let refraction = if alt > -1_f64.to_radians() {
+        atmospheric::refrac_true_alt(alt)
+    } else {
+        0.0
+    };
+
+    (az, alt + refraction)
+}
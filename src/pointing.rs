@@ -0,0 +1,370 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------TELESCOPE POINTING MODEL-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::ephemeris;
+use crate::time;
+use crate::Accuracy;
+
+/// Represents the pointing model of an equatorial mount
+///
+/// Each term is an angle, in radians. The encoder coordinates of a
+/// target are its catalog coordinates plus the sum of the terms
+/// times their geometric factors, following the TPOINT naming.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MountModel {
+    /// Index error in hour angle
+    pub ih: f64,
+    /// Index error in declination
+    pub id: f64,
+    /// Collimation error, the non-perpendicularity of the optical and
+    /// declination axes
+    pub ch: f64,
+    /// Non-perpendicularity of the polar and declination axes
+    pub np: f64,
+    /// Misalignment of the polar axis in azimuth, positive east of
+    /// the pole
+    pub ma: f64,
+    /// Misalignment of the polar axis in elevation, positive above
+    /// the pole
+    pub me: f64,
+    /// Tube flexure, proportional to the sine of the zenith distance
+    pub tf: f64,
+}
+
+/// Represents a pointing observation of a star
+#[derive(Copy, Clone, Debug)]
+pub struct PointingObservation {
+    /// Catalog hour angle, corrected for refraction | in radians
+    pub hour_angle: f64,
+    /// Catalog declination, corrected for refraction | in radians
+    pub dec: f64,
+    /// Hour angle read from the encoders | in radians
+    pub encoder_hour_angle: f64,
+    /// Declination read from the encoders | in radians
+    pub encoder_dec: f64,
+}
+
+impl MountModel {
+    /**
+    Computes the encoder coordinates of catalog coordinates
+
+    # Returns
+
+    (encoder_hour_angle, encoder_dec)
+
+    * encoder_hour_angle: Encoder hour angle | in radians
+    * encoder_dec       : Encoder declination | in radians
+
+    # Arguments
+
+    * hour_angle  : Catalog hour angle | in radians
+    * dec         : Catalog declination | in radians
+    * observer_lat: Observer's geographic latitude | in radians
+    **/
+    pub fn encoder_from_catalog(&self, hour_angle: f64, dec: f64, observer_lat: f64) -> (f64, f64) {
+        let (delta_hour_angle, delta_dec) = self.corrections(hour_angle, dec, observer_lat);
+
+        (hour_angle + delta_hour_angle, dec + delta_dec)
+    }
+
+    /**
+    Computes the catalog coordinates of encoder coordinates
+
+    # Returns
+
+    (hour_angle, dec)
+
+    * hour_angle: Catalog hour angle | in radians
+    * dec       : Catalog declination | in radians
+
+    # Arguments
+
+    * encoder_hour_angle: Encoder hour angle | in radians
+    * encoder_dec       : Encoder declination | in radians
+    * observer_lat      : Observer's geographic latitude | in radians
+    **/
+    pub fn catalog_from_encoder(
+        &self,
+        encoder_hour_angle: f64,
+        encoder_dec: f64,
+        observer_lat: f64,
+    ) -> (f64, f64) {
+        let (mut hour_angle, mut dec) = (encoder_hour_angle, encoder_dec);
+
+        for _ in 0..10 {
+            let (delta_hour_angle, delta_dec) = self.corrections(hour_angle, dec, observer_lat);
+            let next = (
+                encoder_hour_angle - delta_hour_angle,
+                encoder_dec - delta_dec,
+            );
+
+            let converged = (next.0 - hour_angle).abs() < 1e-12 && (next.1 - dec).abs() < 1e-12;
+            (hour_angle, dec) = next;
+            if converged {
+                break;
+            }
+        }
+
+        (hour_angle, dec)
+    }
+
+    fn corrections(&self, hour_angle: f64, dec: f64, observer_lat: f64) -> (f64, f64) {
+        let coeffs = self.coeffs();
+        let (factors_hour_angle, factors_dec) = factors(hour_angle, dec, observer_lat);
+
+        let mut delta_hour_angle = 0.0;
+        let mut delta_dec = 0.0;
+        for i in 0..NUM_TERMS {
+            delta_hour_angle += coeffs[i] * factors_hour_angle[i];
+            delta_dec += coeffs[i] * factors_dec[i];
+        }
+
+        (delta_hour_angle, delta_dec)
+    }
+
+    fn coeffs(&self) -> [f64; NUM_TERMS] {
+        [
+            self.ih, self.id, self.ch, self.np, self.ma, self.me, self.tf,
+        ]
+    }
+}
+
+/**
+Fits a mount model to pointing observations by least squares
+
+# Returns
+
+(mount_model, rms)
+
+* mount_model: Fitted mount model
+* rms        : Root mean square of the residuals on the sky after the
+               fit | in radians
+
+The hour angle residuals are weighted by the cosine of the
+declination. An error is returned if fewer than four observations
+are given, or if they don't constrain all the terms, as happens when
+they all lie on one meridian.
+
+# Arguments
+
+* observations: Pointing observations, spread over the sky
+* observer_lat: Observer's geographic latitude | in radians
+**/
+pub fn fit(
+    observations: &[PointingObservation],
+    observer_lat: f64,
+) -> Result<(MountModel, f64), &'static str> {
+    if observations.len() < 4 {
+        return Err("At least four pointing observations are needed");
+    }
+
+    let mut normal = [[0.0; NUM_TERMS]; NUM_TERMS];
+    let mut rhs = [0.0; NUM_TERMS];
+    for_each_equation(observations, observer_lat, |row, residual| {
+        for i in 0..NUM_TERMS {
+            for j in 0..NUM_TERMS {
+                normal[i][j] += row[i] * row[j];
+            }
+            rhs[i] += row[i] * residual;
+        }
+    });
+
+    let c = solve(normal, rhs)?;
+    let model = MountModel {
+        ih: c[0],
+        id: c[1],
+        ch: c[2],
+        np: c[3],
+        ma: c[4],
+        me: c[5],
+        tf: c[6],
+    };
+
+    let mut sum_sq = 0.0;
+    for_each_equation(observations, observer_lat, |row, residual| {
+        let fitted: f64 = row.iter().zip(c.iter()).map(|(r, c)| r * c).sum();
+        sum_sq += (residual - fitted).powi(2);
+    });
+
+    Ok((model, (sum_sq / observations.len() as f64).sqrt()))
+}
+
+/// Represents the drive rates that keep a body centered
+///
+/// Each rate is in radians per second of time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TrackingRates {
+    /// Rate of the topocentric right ascension, the offset from the
+    /// sidereal rate of an equatorial drive
+    pub asc: f64,
+    /// Rate of the topocentric declination
+    pub dec: f64,
+    /// Rate of the hour angle, the rate of the polar axis of an
+    /// equatorial mount
+    pub hour_angle: f64,
+    /// Rate of the azimuth, measured westward from the south
+    pub az: f64,
+    /// Rate of the altitude, not corrected for refraction
+    pub alt: f64,
+}
+
+/**
+Computes the drive rates to track a body
+
+# Returns
+
+* tracking_rates: Drive rates in equatorial and horizontal
+                  coordinates
+
+The rates combine the diurnal motion with the motion of the body on
+the sky and its change of parallax, and are found by central
+differences over a minute of time on its topocentric apparent place.
+The azimuth rate grows without bound as the body nears the zenith.
+
+# Arguments
+
+* body    : The body
+* observer: The observer
+* jd      : Julian day, UT
+**/
+pub fn tracking_rates(
+    body: &ephemeris::Body,
+    observer: &coordinate::Observer,
+    jd: f64,
+) -> TrackingRates {
+    let half_step = 30.0;
+
+    let place = |jd: f64| {
+        let (ha_point, local_sidereal) =
+            ephemeris::topocent_ha_pos(body, observer, &time::Instant::from_ut(jd), Accuracy::High);
+        let (az, alt) = ha_point.horizontal(observer.lat);
+
+        (ha_point.to_eq(local_sidereal), ha_point, az, alt)
+    };
+
+    let (eq_1, ha_1, az_1, alt_1) = place(jd - half_step / 86400.0);
+    let (eq_2, ha_2, az_2, alt_2) = place(jd + half_step / 86400.0);
+    let rate = |delta: f64| delta / (2.0 * half_step);
+
+    TrackingRates {
+        asc: rate(angle::limit_pi(eq_2.asc - eq_1.asc)),
+        dec: rate(eq_2.dec - eq_1.dec),
+        hour_angle: rate(angle::limit_pi(ha_2.hour_angle - ha_1.hour_angle)),
+        az: rate(angle::limit_pi(az_2 - az_1)),
+        alt: rate(alt_2 - alt_1),
+    }
+}
+
+const NUM_TERMS: usize = 7;
+
+// Geometric factors of the terms, in hour angle and declination
+fn factors(hour_angle: f64, dec: f64, lat: f64) -> ([f64; NUM_TERMS], [f64; NUM_TERMS]) {
+    let (sin_h, cos_h) = hour_angle.sin_cos();
+    let (sin_dec, cos_dec) = dec.sin_cos();
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let tan_dec = sin_dec / cos_dec;
+
+    (
+        [
+            1.0,
+            0.0,
+            1.0 / cos_dec,
+            tan_dec,
+            -cos_h * tan_dec,
+            sin_h * tan_dec,
+            cos_lat * sin_h / cos_dec,
+        ],
+        [
+            0.0,
+            1.0,
+            0.0,
+            0.0,
+            sin_h,
+            cos_h,
+            cos_lat * cos_h * sin_dec - sin_lat * cos_dec,
+        ],
+    )
+}
+
+// Calls `f` with the weighted factors and residual of the two
+// equations of each observation
+fn for_each_equation<F>(observations: &[PointingObservation], lat: f64, mut f: F)
+where
+    F: FnMut(&[f64; NUM_TERMS], f64),
+{
+    for obs in observations {
+        let (mut factors_hour_angle, factors_dec) = factors(obs.hour_angle, obs.dec, lat);
+        let cos_dec = obs.dec.cos();
+        for factor in factors_hour_angle.iter_mut() {
+            *factor *= cos_dec;
+        }
+
+        let delta_hour_angle = angle::limit_pi(obs.encoder_hour_angle - obs.hour_angle);
+
+        f(&factors_hour_angle, delta_hour_angle * cos_dec);
+        f(&factors_dec, obs.encoder_dec - obs.dec);
+    }
+}
+
+// Gaussian elimination with partial pivoting
+fn solve(
+    mut m: [[f64; NUM_TERMS]; NUM_TERMS],
+    mut v: [f64; NUM_TERMS],
+) -> Result<[f64; NUM_TERMS], &'static str> {
+    let scale = m
+        .iter()
+        .enumerate()
+        .map(|(i, row)| row[i].abs())
+        .fold(0.0, f64::max);
+
+    for col in 0..NUM_TERMS {
+        let pivot = (col..NUM_TERMS)
+            .max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))
+            .unwrap_or(col);
+        if m[pivot][col].abs() <= scale * 1e-12 {
+            return Err("The pointing observations don't constrain every term");
+        }
+        m.swap(col, pivot);
+        v.swap(col, pivot);
+
+        let pivot_row = m[col];
+        for row in col + 1..NUM_TERMS {
+            let k = m[row][col] / pivot_row[col];
+            for (x, p) in m[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= k * p;
+            }
+            v[row] -= k * v[col];
+        }
+    }
+
+    let mut x = [0.0; NUM_TERMS];
+    for row in (0..NUM_TERMS).rev() {
+        let sum: f64 = (row + 1..NUM_TERMS).map(|j| m[row][j] * x[j]).sum();
+        x[row] = (v[row] - sum) / m[row][row];
+    }
+
+    Ok(x)
+}
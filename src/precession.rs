@@ -0,0 +1,171 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------PRECESSION-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::math::polynomial;
+use crate::time;
+
+/**
+Precesses an equatorial point from one epoch to another
+
+# Returns
+
+* eq_point: Equatorial point referred to the mean equinox of
+            `jd_to` | in radians
+
+The rigorous method of Meeus, Astronomical Algorithms, chapter 21
+is used, with the IAU 1976 precession angles.
+
+# Arguments
+
+* eq_point: Equatorial point referred to the mean equinox of
+            `jd_from` | in radians
+* jd_from : Julian (Ephemeris) day of the initial epoch
+* jd_to   : Julian (Ephemeris) day of the final epoch
+**/
+pub fn precess_eq(eq_point: &coordinate::EqPoint, jd_from: f64, jd_to: f64) -> coordinate::EqPoint {
+    let (zeta, z, theta) = angles(jd_from, jd_to);
+
+    let (sin_dec, cos_dec) = eq_point.dec.sin_cos();
+    let (sin_asc, cos_asc) = (eq_point.asc + zeta).sin_cos();
+    let (sin_theta, cos_theta) = theta.sin_cos();
+
+    let a = cos_dec * sin_asc;
+    let b = cos_theta * cos_dec * cos_asc - sin_theta * sin_dec;
+    let c = sin_theta * cos_dec * cos_asc + cos_theta * sin_dec;
+
+    coordinate::EqPoint::with_equinox(angle::limit_twoPI(a.atan2(b) + z), c.asin(), jd_to)
+}
+
+/**
+Precesses an ecliptic point from one epoch to another
+
+# Returns
+
+* ecl_point: Ecliptic point referred to the mean ecliptic and
+             equinox of `jd_to` | in radians
+
+The rigorous method of Meeus, Astronomical Algorithms, chapter 21
+is used, with the IAU 1976 precession angles.
+
+# Arguments
+
+* ecl_point: Ecliptic point referred to the mean ecliptic and
+             equinox of `jd_from` | in radians
+* jd_from  : Julian (Ephemeris) day of the initial epoch
+* jd_to    : Julian (Ephemeris) day of the final epoch
+**/
+pub fn precess_ecl(
+    ecl_point: &coordinate::EclPoint,
+    jd_from: f64,
+    jd_to: f64,
+) -> coordinate::EclPoint {
+    let (eta, pi, p) = ecl_angles(jd_from, jd_to);
+
+    let (sin_lat, cos_lat) = ecl_point.lat.sin_cos();
+    let (sin_diff, cos_diff) = (pi - ecl_point.long).sin_cos();
+    let (sin_eta, cos_eta) = eta.sin_cos();
+
+    let a = cos_eta * cos_lat * sin_diff - sin_eta * sin_lat;
+    let b = cos_lat * cos_diff;
+    let c = cos_eta * sin_lat + sin_eta * cos_lat * sin_diff;
+
+    coordinate::EclPoint {
+        long: angle::limit_twoPI(p + pi - a.atan2(b)),
+        lat: c.asin(),
+    }
+}
+
+/**
+Computes the precession matrix from one epoch to another
+
+# Returns
+
+* matrix: Rotation from the mean equator and equinox of `jd_from` to
+          those of `jd_to`
+
+The matrix is R₃(−z) R₂(θ) R₃(−ζ), with the angles of
+[precess_eq()](./fn.precess_eq.html), which it reproduces when
+applied to rectangular equatorial coordinates.
+
+# Arguments
+
+* jd_from: Julian (Ephemeris) day of the initial epoch
+* jd_to  : Julian (Ephemeris) day of the final epoch
+**/
+pub fn matrix(jd_from: f64, jd_to: f64) -> coordinate::RotationMatrix {
+    let (zeta, z, theta) = angles(jd_from, jd_to);
+
+    coordinate::RotationMatrix::about_z(-z)
+        * coordinate::RotationMatrix::about_y(theta)
+        * coordinate::RotationMatrix::about_z(-zeta)
+}
+
+// (ζ, z, θ), Meeus, Astronomical Algorithms, equation 21.3 | in radians
+fn angles(jd_from: f64, jd_to: f64) -> (f64, f64, f64) {
+    let T = time::julian_century(jd_from);
+    let t = (jd_to - jd_from) / 36525.0;
+
+    // Coefficients of t and t² | in arcseconds
+    let x = polynomial::horner(&[2306.2181, 1.39656, -0.000139], T);
+    let zeta_2 = polynomial::horner(&[0.30188, -0.000344], T);
+    let z_2 = polynomial::horner(&[1.09468, 0.000066], T);
+    let theta_1 = polynomial::horner(&[2004.3109, -0.8533, -0.000217], T);
+    let theta_2 = polynomial::horner(&[0.42665, 0.000217], T);
+
+    let zeta = polynomial::horner(&[0.0, x, zeta_2, 0.017998], t);
+    let z = polynomial::horner(&[0.0, x, z_2, 0.018203], t);
+    let theta = polynomial::horner(&[0.0, theta_1, -theta_2, -0.041833], t);
+
+    (
+        angle::deg_dmas(0, 0, zeta).to_radians(),
+        angle::deg_dmas(0, 0, z).to_radians(),
+        angle::deg_dmas(0, 0, theta).to_radians(),
+    )
+}
+
+// (η, Π, p), Meeus, Astronomical Algorithms, equation 21.5 | in radians
+fn ecl_angles(jd_from: f64, jd_to: f64) -> (f64, f64, f64) {
+    let T = time::julian_century(jd_from);
+    let t = (jd_to - jd_from) / 36525.0;
+
+    // Coefficients of t and t² | in arcseconds
+    let eta_1 = polynomial::horner(&[47.0029, -0.06603, 0.000598], T);
+    let eta_2 = polynomial::horner(&[-0.03302, 0.000598], T);
+    let pi_0 = polynomial::horner(&[174.876384 * 3600.0, 3289.4789, 0.60622], T);
+    let pi_1 = polynomial::horner(&[869.8089, 0.50491], T);
+    let p_1 = polynomial::horner(&[5029.0966, 2.22226, -0.000042], T);
+    let p_2 = polynomial::horner(&[1.11113, -0.000042], T);
+
+    let eta = polynomial::horner(&[0.0, eta_1, eta_2, 0.00006], t);
+    let pi = polynomial::horner(&[pi_0, -pi_1, 0.03536], t);
+    let p = polynomial::horner(&[0.0, p_1, p_2, -0.000006], t);
+
+    (
+        angle::deg_dmas(0, 0, eta).to_radians(),
+        angle::deg_dmas(0, 0, pi).to_radians(),
+        angle::deg_dmas(0, 0, p).to_radians(),
+    )
+}
@@ -0,0 +1,46 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------PRELUDE-----------!
+
+//! Re-exports the types, traits and functions most programs need
+//!
+//! ```
+//! use apollo::prelude::*;
+//! ```
+
+pub use crate::coordinate::{
+    EclPoint, EqPoint, GeographPoint, Observer, PrecomputedLatitude, PrecomputedObliquity, SinCos,
+};
+pub use crate::ecliptic::ObliquityModel;
+pub use crate::ephemeris::{apparent_ecl_pos, apparent_eq_pos, Body};
+pub use crate::planet::Planet;
+pub use crate::time::{
+    apparent_sidereal_jd, julian_day, CalType, Date, DateTime, DayOfMonth, Instant, Month, Weekday,
+    ZonedInstant,
+};
+pub use crate::transit::{moon_rise_set, sun_rise_set, RiseTransitSet, ZonedRiseTransitSet};
+pub use crate::{moon_position, observed_position, sun_position, Accuracy, Float, Measurement};
+
+pub use crate::{
+    ecliptic_from_eq, eq_from_ecliptic, eq_from_galactic, galactic_from_eq, horizontal_from_eq,
+};
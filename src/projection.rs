@@ -0,0 +1,507 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------SKY PROJECTIONS-----------!
+
+use crate::angle;
+use crate::coordinate;
+use std::f64::consts::SQRT_2;
+
+/**
+Projects an equatorial point onto the tangent plane with the gnomonic
+projection
+
+# Returns
+
+(xi, eta)
+
+* xi : Standard coordinate ξ, positive towards increasing right
+       ascension | in radians
+* eta: Standard coordinate η, positive towards the north | in radians
+
+This is the FITS WCS `TAN` projection, ξ and η being the intermediate
+world coordinates. An error is returned if the point is 90° or more
+away from the tangent point.
+
+# Arguments
+
+* eq_point: Equatorial point to project | in radians
+* center  : Equatorial point of the tangent point | in radians
+**/
+pub fn gnomonic(
+    eq_point: &coordinate::EqPoint,
+    center: &coordinate::EqPoint,
+) -> Result<(f64, f64), &'static str> {
+    let (cos_c, xi, eta) = tangent_plane(eq_point, center);
+    if cos_c <= 0.0 {
+        return Err("The point is 90° or more away from the tangent point");
+    }
+
+    Ok((xi / cos_c, eta / cos_c))
+}
+
+/**
+Computes the equatorial point of standard coordinates of the gnomonic
+projection
+
+# Returns
+
+* eq_point: Equatorial point | in radians
+
+# Arguments
+
+* xi    : Standard coordinate ξ | in radians
+* eta   : Standard coordinate η | in radians
+* center: Equatorial point of the tangent point | in radians
+**/
+pub fn inverse_gnomonic(xi: f64, eta: f64, center: &coordinate::EqPoint) -> coordinate::EqPoint {
+    let rho = xi.hypot(eta);
+    inverse_azimuthal(xi, eta, rho.atan(), center)
+}
+
+/**
+Projects an equatorial point onto the tangent plane with the
+stereographic projection
+
+# Returns
+
+(xi, eta)
+
+* xi : Standard coordinate ξ, positive towards increasing right
+       ascension | in radians
+* eta: Standard coordinate η, positive towards the north | in radians
+
+This is the FITS WCS `STG` projection. An error is returned for the
+point opposite to the tangent point.
+
+# Arguments
+
+* eq_point: Equatorial point to project | in radians
+* center  : Equatorial point of the tangent point | in radians
+**/
+pub fn stereographic(
+    eq_point: &coordinate::EqPoint,
+    center: &coordinate::EqPoint,
+) -> Result<(f64, f64), &'static str> {
+    let (cos_c, xi, eta) = tangent_plane(eq_point, center);
+    if cos_c <= -1.0 + 1e-12 {
+        return Err("The point is opposite to the tangent point");
+    }
+
+    let k = 2.0 / (1.0 + cos_c);
+    Ok((k * xi, k * eta))
+}
+
+/**
+Computes the equatorial point of standard coordinates of the
+stereographic projection
+
+# Returns
+
+* eq_point: Equatorial point | in radians
+
+# Arguments
+
+* xi    : Standard coordinate ξ | in radians
+* eta   : Standard coordinate η | in radians
+* center: Equatorial point of the tangent point | in radians
+**/
+pub fn inverse_stereographic(
+    xi: f64,
+    eta: f64,
+    center: &coordinate::EqPoint,
+) -> coordinate::EqPoint {
+    let rho = xi.hypot(eta);
+    inverse_azimuthal(xi, eta, 2.0 * (rho / 2.0).atan(), center)
+}
+
+/**
+Projects an equatorial point with the orthographic projection
+
+# Returns
+
+(xi, eta)
+
+* xi : Standard coordinate ξ, positive towards increasing right
+       ascension | in radians
+* eta: Standard coordinate η, positive towards the north | in radians
+
+This is the FITS WCS `SIN` projection, the view of the celestial
+sphere from infinitely far away. An error is returned if the point
+is on the far hemisphere.
+
+# Arguments
+
+* eq_point: Equatorial point to project | in radians
+* center  : Equatorial point of the center of the projection | in radians
+**/
+pub fn orthographic(
+    eq_point: &coordinate::EqPoint,
+    center: &coordinate::EqPoint,
+) -> Result<(f64, f64), &'static str> {
+    let (cos_c, xi, eta) = tangent_plane(eq_point, center);
+    if cos_c < 0.0 {
+        return Err("The point is on the far hemisphere");
+    }
+
+    Ok((xi, eta))
+}
+
+/**
+Computes the equatorial point of standard coordinates of the
+orthographic projection
+
+# Returns
+
+* eq_point: Equatorial point | in radians
+
+An error is returned if (ξ, η) is outside the unit circle.
+
+# Arguments
+
+* xi    : Standard coordinate ξ | in radians
+* eta   : Standard coordinate η | in radians
+* center: Equatorial point of the center of the projection | in radians
+**/
+pub fn inverse_orthographic(
+    xi: f64,
+    eta: f64,
+    center: &coordinate::EqPoint,
+) -> Result<coordinate::EqPoint, &'static str> {
+    let rho = xi.hypot(eta);
+    if rho > 1.0 {
+        return Err("The standard coordinates are outside the projection");
+    }
+
+    Ok(inverse_azimuthal(xi, eta, rho.asin(), center))
+}
+
+/**
+Projects an equatorial point with the Lambert azimuthal equal-area
+projection
+
+# Returns
+
+(xi, eta)
+
+* xi : Standard coordinate ξ, positive towards increasing right
+       ascension | in radians
+* eta: Standard coordinate η, positive towards the north | in radians
+
+This is the FITS WCS `ZEA` projection. An error is returned for the
+point opposite to the center.
+
+# Arguments
+
+* eq_point: Equatorial point to project | in radians
+* center  : Equatorial point of the center of the projection | in radians
+**/
+pub fn lambert_azimuthal(
+    eq_point: &coordinate::EqPoint,
+    center: &coordinate::EqPoint,
+) -> Result<(f64, f64), &'static str> {
+    let (cos_c, xi, eta) = tangent_plane(eq_point, center);
+    if cos_c <= -1.0 + 1e-12 {
+        return Err("The point is opposite to the center");
+    }
+
+    let k = (2.0 / (1.0 + cos_c)).sqrt();
+    Ok((k * xi, k * eta))
+}
+
+/**
+Computes the equatorial point of standard coordinates of the Lambert
+azimuthal equal-area projection
+
+# Returns
+
+* eq_point: Equatorial point | in radians
+
+An error is returned if (ξ, η) is outside the circle of radius 2.
+
+# Arguments
+
+* xi    : Standard coordinate ξ | in radians
+* eta   : Standard coordinate η | in radians
+* center: Equatorial point of the center of the projection | in radians
+**/
+pub fn inverse_lambert_azimuthal(
+    xi: f64,
+    eta: f64,
+    center: &coordinate::EqPoint,
+) -> Result<coordinate::EqPoint, &'static str> {
+    let rho = xi.hypot(eta);
+    if rho > 2.0 {
+        return Err("The standard coordinates are outside the projection");
+    }
+
+    Ok(inverse_azimuthal(xi, eta, 2.0 * (rho / 2.0).asin(), center))
+}
+
+/**
+Projects a point of the sphere with the Hammer-Aitoff projection
+
+# Returns
+
+(x, y)
+
+* x: Abscissa, positive towards increasing longitude | range: -2√2 - 2√2
+* y: Ordinate, positive towards the north | range: -√2 - √2
+
+The whole sphere is mapped to an ellipse with equal-area. Any
+spherical coordinates can be projected, such as equatorial, ecliptic
+or galactic ones. Sky charts conventionally flip the sign of `x`, to
+have the east on the left.
+
+# Arguments
+
+* long       : Longitude of the point | in radians
+* lat        : Latitude of the point | in radians
+* center_long: Longitude of the central meridian | in radians
+**/
+pub fn hammer_aitoff(long: f64, lat: f64, center_long: f64) -> (f64, f64) {
+    let delta_long = angle::limit_pi(long - center_long);
+
+    let (sin_half_long, cos_half_long) = (delta_long / 2.0).sin_cos();
+    let z = (1.0 + lat.cos() * cos_half_long).sqrt();
+
+    (
+        2.0 * SQRT_2 * lat.cos() * sin_half_long / z,
+        SQRT_2 * lat.sin() / z,
+    )
+}
+
+/**
+Computes the point of the sphere of coordinates of the Hammer-Aitoff
+projection
+
+# Returns
+
+(long, lat)
+
+* long: Longitude of the point | in radians
+* lat : Latitude of the point | in radians
+
+An error is returned if (x, y) is outside the ellipse of the
+projection.
+
+# Arguments
+
+* x          : Abscissa
+* y          : Ordinate
+* center_long: Longitude of the central meridian | in radians
+**/
+pub fn inverse_hammer_aitoff(x: f64, y: f64, center_long: f64) -> Result<(f64, f64), &'static str> {
+    if (x * x) / 8.0 + (y * y) / 2.0 > 1.0 {
+        return Err("The coordinates are outside the projection");
+    }
+
+    let z = (1.0 - (x / 4.0).powi(2) - (y / 2.0).powi(2)).sqrt();
+
+    Ok((
+        angle::limit_twoPI(center_long + 2.0 * (z * x).atan2(2.0 * (2.0 * z * z - 1.0))),
+        (z * y).asin(),
+    ))
+}
+
+/// Represents the plate constants relating measured coordinates on a
+/// plate or CCD image to standard coordinates
+///
+/// ξ = a·x + b·y + c and η = d·x + e·y + f
+#[derive(Copy, Clone, Debug)]
+pub struct PlateConstants {
+    /// Coefficient of x in ξ
+    pub a: f64,
+    /// Coefficient of y in ξ
+    pub b: f64,
+    /// Constant term of ξ
+    pub c: f64,
+    /// Coefficient of x in η
+    pub d: f64,
+    /// Coefficient of y in η
+    pub e: f64,
+    /// Constant term of η
+    pub f: f64,
+}
+
+impl PlateConstants {
+    /**
+    Computes the standard coordinates of measured coordinates
+
+    # Returns
+
+    (xi, eta)
+
+    * xi : Standard coordinate ξ | in radians
+    * eta: Standard coordinate η | in radians
+
+    # Arguments
+
+    * x: Measured x coordinate
+    * y: Measured y coordinate
+    **/
+    pub fn standard_from_measured(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.b * y + self.c,
+            self.d * x + self.e * y + self.f,
+        )
+    }
+
+    /**
+    Computes the measured coordinates of standard coordinates
+
+    # Returns
+
+    (x, y)
+
+    * x: Measured x coordinate
+    * y: Measured y coordinate
+
+    # Arguments
+
+    * xi : Standard coordinate ξ | in radians
+    * eta: Standard coordinate η | in radians
+    **/
+    pub fn measured_from_standard(&self, xi: f64, eta: f64) -> (f64, f64) {
+        let det = self.a * self.e - self.b * self.d;
+        let (xi, eta) = (xi - self.c, eta - self.f);
+
+        (
+            (self.e * xi - self.b * eta) / det,
+            (self.a * eta - self.d * xi) / det,
+        )
+    }
+}
+
+/**
+Solves the plate constants from reference stars by least squares
+
+# Returns
+
+* plate_consts: Plate constants
+
+An error is returned if fewer than three reference stars are given,
+or if they are collinear.
+
+# Arguments
+
+* measured: Measured (x, y) coordinates of the reference stars
+* standard: Standard coordinates (ξ, η) of the reference stars, as
+            given by [gnomonic()](./fn.gnomonic.html) | in radians
+**/
+pub fn solve_plate_constants(
+    measured: &[(f64, f64)],
+    standard: &[(f64, f64)],
+) -> Result<PlateConstants, &'static str> {
+    if measured.len() != standard.len() {
+        return Err("Different numbers of measured and standard coordinates");
+    }
+    if measured.len() < 3 {
+        return Err("At least three reference stars are needed");
+    }
+
+    // Normal equations of the least squares fit of a·x + b·y + c
+    let mut normal = [[0.0; 3]; 3];
+    let mut rhs_xi = [0.0; 3];
+    let mut rhs_eta = [0.0; 3];
+    for (&(x, y), &(xi, eta)) in measured.iter().zip(standard) {
+        let row = [x, y, 1.0];
+        for i in 0..3 {
+            for j in 0..3 {
+                normal[i][j] += row[i] * row[j];
+            }
+            rhs_xi[i] += row[i] * xi;
+            rhs_eta[i] += row[i] * eta;
+        }
+    }
+
+    let [a, b, c] = solve_3x3(&normal, &rhs_xi)?;
+    let [d, e, f] = solve_3x3(&normal, &rhs_eta)?;
+
+    Ok(PlateConstants { a, b, c, d, e, f })
+}
+
+// (cos c, cos c·ξ, cos c·η), c being the angular distance to the center
+fn tangent_plane(eq_point: &coordinate::EqPoint, center: &coordinate::EqPoint) -> (f64, f64, f64) {
+    let (sin_dec, cos_dec) = eq_point.dec.sin_cos();
+    let (sin_dec0, cos_dec0) = center.dec.sin_cos();
+    let (sin_delta_asc, cos_delta_asc) = (eq_point.asc - center.asc).sin_cos();
+
+    (
+        sin_dec0 * sin_dec + cos_dec0 * cos_dec * cos_delta_asc,
+        cos_dec * sin_delta_asc,
+        cos_dec0 * sin_dec - sin_dec0 * cos_dec * cos_delta_asc,
+    )
+}
+
+// Inverse of an azimuthal projection, c being the angular distance to
+// the center of the point at (ξ, η)
+fn inverse_azimuthal(
+    xi: f64,
+    eta: f64,
+    c: f64,
+    center: &coordinate::EqPoint,
+) -> coordinate::EqPoint {
+    let rho = xi.hypot(eta);
+    if rho == 0.0 {
+        return *center;
+    }
+
+    let (sin_c, cos_c) = c.sin_cos();
+    let (sin_dec0, cos_dec0) = center.dec.sin_cos();
+
+    coordinate::EqPoint {
+        asc: angle::limit_twoPI(
+            center.asc + (xi * sin_c).atan2(rho * cos_dec0 * cos_c - eta * sin_dec0 * sin_c),
+        ),
+        dec: (cos_c * sin_dec0 + eta * sin_c * cos_dec0 / rho).asin(),
+        equinox: center.equinox,
+    }
+}
+
+fn solve_3x3(m: &[[f64; 3]; 3], v: &[f64; 3]) -> Result<[f64; 3], &'static str> {
+    let det = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+
+    // The determinant scales with the cube of the matrix entries, so
+    // singularity is judged relative to the Frobenius norm
+    let norm = m.iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+    let d = det(m);
+    if d.abs() <= 1e-12 * norm.powi(3) {
+        return Err("The reference stars are collinear");
+    }
+
+    // Cramer's rule
+    let mut solution = [0.0; 3];
+    for (k, s) in solution.iter_mut().enumerate() {
+        let mut mk = *m;
+        for i in 0..3 {
+            mk[i][k] = v[i];
+        }
+        *s = det(&mk) / d;
+    }
+
+    Ok(solution)
+}
@@ -0,0 +1,190 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------REDUCTION OF STAR PLACES-----------!
+
+use crate::aberration;
+use crate::angle;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::nutation;
+use crate::planet;
+use crate::precession;
+use crate::sun;
+use crate::time;
+use crate::Accuracy;
+
+/// Represents a star catalog entry
+///
+/// The position is referred to the mean equinox of J2000.0.
+#[derive(Copy, Clone, Debug)]
+pub struct CatalogEntry {
+    /// Right ascension at the catalog epoch | in radians
+    pub asc: f64,
+    /// Declination at the catalog epoch | in radians
+    pub dec: f64,
+    /// Annual proper motion in right ascension | in radians per year
+    pub pm_asc: f64,
+    /// Annual proper motion in declination | in radians per year
+    pub pm_dec: f64,
+    /// Annual parallax | in radians
+    pub parallax: f64,
+    /// Catalog epoch | as a Julian (Ephemeris) day
+    pub epoch: f64,
+}
+
+/// Represents the apparent place of a star for an observer
+#[derive(Copy, Clone, Debug)]
+pub struct ApparentPlace {
+    /// Topocentric apparent equatorial point, referred to the true
+    /// equinox of the date | in radians
+    pub eq_point: coordinate::EqPoint,
+    /// Local hour angle | in radians
+    pub hour_angle: f64,
+    /// Azimuth, measured westward from the south | in radians
+    pub az: f64,
+    /// Altitude, not corrected for refraction | in radians
+    pub alt: f64,
+}
+
+impl ApparentPlace {
+    /**
+    Computes the altitude corrected for atmospheric refraction
+
+    # Returns
+
+    * refracted_alt: Apparent altitude, as seen through the
+                     atmosphere | in radians
+
+    No correction is applied more than 2° below the horizon.
+
+    # Arguments
+
+    * pressure: Local pressure | in millibars
+    * temp    : Local temperature | in kelvins
+    **/
+    pub fn refracted_alt(&self, pressure: f64, temp: f64) -> f64 {
+        self.observed_point(pressure, temp).refracted_alt
+    }
+
+    /**
+    Returns the horizontal point as observed through the atmosphere
+
+    # Returns
+
+    * observed_point: Observed point, holding both the true and the
+                      refracted altitude
+
+    # Arguments
+
+    * pressure: Local pressure | in millibars
+    * temp    : Local temperature | in kelvins
+    **/
+    pub fn observed_point(&self, pressure: f64, temp: f64) -> coordinate::ObservedPoint {
+        coordinate::ObservedPoint::from_true_alt(self.az, self.alt, pressure, temp)
+    }
+}
+
+/**
+Computes the apparent place of a star for an observer
+
+# Returns
+
+* apparent_place: Topocentric apparent place of the star
+
+The catalog position is corrected, in order, for proper motion,
+precession, annual parallax, annual aberration, nutation and
+diurnal aberration, and converted to horizontal coordinates. Apply
+[ApparentPlace::refracted_alt()](./struct.ApparentPlace.html#method.refracted_alt)
+for refraction.
+
+# Arguments
+
+* catalog_entry: The star's catalog entry
+* observer     : The observer
+* jd           : Julian (Ephemeris) day
+**/
+pub fn apparent_place(
+    catalog_entry: &CatalogEntry,
+    observer: &coordinate::Observer,
+    jd: f64,
+) -> ApparentPlace {
+    let years = (jd - catalog_entry.epoch) / 365.25;
+    let mean = precession::precess_eq(
+        &coordinate::EqPoint::with_equinox(
+            catalog_entry.asc + catalog_entry.pm_asc * years,
+            catalog_entry.dec + catalog_entry.pm_dec * years,
+            time::J2000,
+        ),
+        time::J2000,
+        jd,
+    );
+
+    let mean_oblq = ecliptic::mean_obliquity_laskar(jd);
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(jd, Accuracy::High);
+    let true_oblq = mean_oblq + nut_in_oblq;
+
+    let mut long = coordinate::ecliptic_long_from_eq(mean.asc, mean.dec, mean_oblq);
+    let mut lat = coordinate::ecliptic_lat_from_eq(mean.asc, mean.dec, mean_oblq);
+
+    let (earth_long, earth_lat, earth_rad_vec) =
+        planet::heliocent_coords(&planet::Planet::Earth, jd, Accuracy::High);
+
+    if catalog_entry.parallax != 0.0 {
+        let p = catalog_entry.parallax * earth_rad_vec;
+        let x = lat.cos() * long.cos() - p * earth_lat.cos() * earth_long.cos();
+        let y = lat.cos() * long.sin() - p * earth_lat.cos() * earth_long.sin();
+        let z = lat.sin() - p * earth_lat.sin();
+
+        long = y.atan2(x);
+        lat = z.atan2(x.hypot(y));
+    }
+
+    let (sun, _) = sun::geocent_ecl_pos(jd, Accuracy::High);
+    let (aberr_long, aberr_lat) = aberration::annual(long, lat, sun.long, jd);
+    long += aberr_long + nut_in_long;
+    lat += aberr_lat;
+
+    let mut asc = coordinate::asc_from_ecliptic(long, lat, true_oblq);
+    let mut dec = coordinate::dec_from_ecliptic(long, lat, true_oblq);
+
+    let jd_ut = time::ut_from_tt(jd);
+    let sidereal = time::apparent_sidereal(time::mean_sidereal(jd_ut), nut_in_long, true_oblq);
+
+    // Diurnal aberration, Explanatory Supplement to the Astronomical
+    // Almanac, section 3.27
+    let hour_angle = coordinate::hour_angle_from_long(sidereal, observer.long, asc);
+    let (_, rho_cos_phi) = planet::earth::rho_sin_cos_phi(observer.lat, observer.height);
+    let k = angle::deg_dmas(0, 0, 0.32).to_radians() * rho_cos_phi;
+    asc += k * hour_angle.cos() / dec.cos();
+    dec += k * hour_angle.sin() * dec.sin();
+
+    let asc = angle::limit_twoPI(asc);
+    let hour_angle = coordinate::hour_angle_from_long(sidereal, observer.long, asc);
+
+    ApparentPlace {
+        eq_point: coordinate::EqPoint::with_equinox(asc, dec, jd),
+        hour_angle,
+        az: coordinate::azimuth_from_eq(hour_angle, dec, observer.lat),
+        alt: coordinate::altitude_from_eq(hour_angle, dec, observer.lat),
+    }
+}
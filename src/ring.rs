@@ -0,0 +1,218 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------PLANETARY RINGS-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::planet;
+use crate::precession;
+use crate::search;
+use crate::time;
+use crate::Accuracy;
+
+// Sampling step and tolerance of the searches for crossings | in days
+const SEARCH_STEP: f64 = 10.0;
+const SEARCH_TOLERANCE: f64 = 1e-4;
+
+/// Represents a planet with a ring system in its equatorial plane
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RingedPlanet {
+    /// Saturn
+    Saturn,
+    /// Uranus
+    Uranus,
+}
+
+/// Represents the tilt of a ring plane towards the Earth and the Sun
+#[derive(Copy, Clone, Debug)]
+pub struct RingTilt {
+    /// Planetocentric latitude of the Earth, referred to the ring
+    /// plane, positive on the north side (B) | in radians
+    pub earth: f64,
+    /// Planetocentric latitude of the Sun, referred to the ring
+    /// plane, positive on the north side (B′) | in radians
+    pub sun: f64,
+}
+
+/**
+Computes the north pole of the ring plane of a planet
+
+# Returns
+
+* ecl_point: Ecliptic point of the north pole of the ring plane,
+             referred to the mean ecliptic and equinox of the
+             date | in radians
+
+The ring plane of Saturn has the inclination and ascending node of
+Meeus, Astronomical Algorithms, chapter 45. The rings of Uranus lie
+in its equatorial plane, whose north pole is the IAU one,
+α₀ = 257.311°, δ₀ = −15.175°, referred to J2000.0.
+
+# Arguments
+
+* planet: The planet
+* jd    : Julian (Ephemeris) day
+**/
+pub fn ring_pole(planet: &RingedPlanet, jd: f64) -> coordinate::EclPoint {
+    match planet {
+        RingedPlanet::Saturn => {
+            let t = time::julian_century(jd);
+            let inc = (28.075216 - t * (0.012998 - t * 0.000004)).to_radians();
+            let asc_node = (169.50847 + t * (1.394681 + t * 0.000412)).to_radians();
+
+            coordinate::EclPoint {
+                long: angle::limit_twoPI(asc_node - std::f64::consts::FRAC_PI_2),
+                lat: std::f64::consts::FRAC_PI_2 - inc,
+            }
+        }
+        RingedPlanet::Uranus => {
+            let (asc, dec) = (257.311_f64.to_radians(), -15.175_f64.to_radians());
+            let oblq_eclip = 23.4392911_f64.to_radians();
+            let j2000 = coordinate::EclPoint {
+                long: coordinate::ecliptic_long_from_eq(asc, dec, oblq_eclip),
+                lat: coordinate::ecliptic_lat_from_eq(asc, dec, oblq_eclip),
+            };
+
+            precession::precess_ecl(&j2000, time::J2000, jd)
+        }
+    }
+}
+
+/**
+Computes the tilt of the ring plane of a planet
+
+# Returns
+
+* ring_tilt: Latitudes of the Earth and of the Sun referred to the
+             ring plane
+
+The light-time, under a few hours, is neglected.
+
+# Arguments
+
+* planet : The planet
+* jd     : Julian (Ephemeris) day
+* long   : Heliocentric longitude of the planet, referred to the mean
+           ecliptic and equinox of the date | in radians
+* lat    : Heliocentric latitude of the planet | in radians
+* rad_vec: Heliocentric radius vector of the planet | in AU
+**/
+pub fn ring_tilt(planet: &RingedPlanet, jd: f64, long: f64, lat: f64, rad_vec: f64) -> RingTilt {
+    let pole = ring_pole(planet, jd);
+    let planetocent_lat = |long: f64, lat: f64| {
+        (pole.lat.sin() * lat.sin() + pole.lat.cos() * lat.cos() * (long - pole.long).cos()).asin()
+    };
+
+    let (earth_long, earth_lat, earth_rad_vec) =
+        planet::heliocent_coords(&planet::Planet::Earth, jd, Accuracy::Low);
+    let earth = coordinate::RectPoint::from_spherical(earth_long, earth_lat, earth_rad_vec);
+    let body = coordinate::RectPoint::from_spherical(long, lat, rad_vec);
+    let (to_earth_long, to_earth_lat, _) =
+        coordinate::RectPoint::new(earth.x - body.x, earth.y - body.y, earth.z - body.z)
+            .to_spherical();
+
+    RingTilt {
+        earth: planetocent_lat(to_earth_long, to_earth_lat),
+        sun: planetocent_lat(long + std::f64::consts::PI, -lat),
+    }
+}
+
+/**
+Finds the times at which the Earth crosses the ring plane of a planet
+
+# Returns
+
+* jds: Julian (Ephemeris) days of the crossings, in increasing order,
+       found to about 10 seconds
+
+The rings are seen edge-on at these times. Around an equinox of the
+planet, the Earth crosses the ring plane once or three times.
+Crossings closer together than 10 days may be missed.
+
+apollo has no ephemeris of Saturn and Uranus, so the heliocentric
+coordinates of the planet are given by `heliocent`, for instance from
+a full VSOP87D series.
+
+# Arguments
+
+* planet   : The planet
+* jd_start : Julian (Ephemeris) day of the start of the search
+* jd_end   : Julian (Ephemeris) day of the end of the search
+* heliocent: Heliocentric (long, lat, rad_vec) of the planet, referred
+             to the mean ecliptic and equinox of the date, as a
+             function of the Julian (Ephemeris) day | in radians and AU
+**/
+pub fn earth_crossings<H>(
+    planet: &RingedPlanet,
+    jd_start: f64,
+    jd_end: f64,
+    heliocent: H,
+) -> Vec<f64>
+where
+    H: Fn(f64) -> (f64, f64, f64),
+{
+    crossings(jd_start, jd_end, |jd| {
+        let (long, lat, rad_vec) = heliocent(jd);
+        ring_tilt(planet, jd, long, lat, rad_vec).earth
+    })
+}
+
+/**
+Finds the equinoxes of a planet
+
+# Returns
+
+* jds: Julian (Ephemeris) days at which the Sun crosses the ring plane
+       of the planet, in increasing order, found to about 10 seconds
+
+The equinoxes of Saturn are about 14.7 years apart, those of Uranus
+about 42 years.
+
+# Arguments
+
+* planet   : The planet
+* jd_start : Julian (Ephemeris) day of the start of the search
+* jd_end   : Julian (Ephemeris) day of the end of the search
+* heliocent: Heliocentric (long, lat, rad_vec) of the planet, as in
+             [earth_crossings()](./fn.earth_crossings.html)
+**/
+pub fn sun_crossings<H>(planet: &RingedPlanet, jd_start: f64, jd_end: f64, heliocent: H) -> Vec<f64>
+where
+    H: Fn(f64) -> (f64, f64, f64),
+{
+    crossings(jd_start, jd_end, |jd| {
+        let (long, lat, rad_vec) = heliocent(jd);
+        ring_tilt(planet, jd, long, lat, rad_vec).sun
+    })
+}
+
+// Times at which a latitude referred to the ring plane changes sign
+fn crossings<L: Fn(f64) -> f64>(jd_start: f64, jd_end: f64, lat: L) -> Vec<f64> {
+    search::changes(jd_start, jd_end, SEARCH_STEP, SEARCH_TOLERANCE, |jd| {
+        lat(jd) > 0.0
+    })
+    .expect("Internal error in ring::crossings")
+    .into_iter()
+    .map(|(jd, _)| jd)
+    .collect()
+}
@@ -0,0 +1,697 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------SATELLITE ORBITS-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::planet::earth;
+use crate::time;
+use std::f64::consts::PI;
+
+// Mean tropical year | in seconds
+const TROPICAL_YEAR: f64 = 365.2422 * 86400.0;
+
+/// Represents the secular precession of an Earth satellite's orbit
+/// under the J₂ term of the Earth's gravity field
+#[derive(Copy, Clone, Debug)]
+pub struct J2Precession {
+    /// Rate of change of the longitude of the ascending node,
+    /// negative for the regression of prograde orbits | in radians
+    /// per second
+    pub node_rate: f64,
+    /// Rate of change of the argument of perigee | in radians per
+    /// second
+    pub perigee_rate: f64,
+}
+
+/// Represents a point of a satellite's ground track
+#[derive(Copy, Clone, Debug)]
+pub struct GroundTrackPoint {
+    /// Julian day | in UT
+    pub jd: f64,
+    /// Geographical point below the satellite | in radians
+    pub geograph_point: coordinate::GeographPoint,
+    /// Height of the satellite above the ellipsoid | in kilometers
+    pub height: f64,
+}
+
+/**
+Computes the nodal and apsidal precession of an Earth satellite's
+orbit
+
+# Returns
+
+* j2_precession: Rates of change of the ascending node and of the
+                 argument of perigee
+
+The rates are the first order secular perturbations by J₂. Drag, the
+higher zonal harmonics and lunisolar perturbations are ignored. The
+apsidal rotation vanishes at the critical inclination, about 63.4°.
+
+# Arguments
+
+* a: Semimajor axis of the orbit | in kilometers
+* e: Eccentricity of the orbit
+* i: Inclination of the orbit to the Earth's equator | in radians
+**/
+pub fn j2_precession(a: f64, e: f64, i: f64) -> J2Precession {
+    let k = j2_rate_factor(a, e);
+    let cos_i = i.cos();
+
+    J2Precession {
+        node_rate: -k * cos_i,
+        perigee_rate: k * (2.5 * cos_i * cos_i - 0.5),
+    }
+}
+
+/**
+Computes the inclination of a sun-synchronous orbit
+
+# Returns
+
+* i: Inclination of the orbit to the Earth's equator | in radians
+
+A sun-synchronous orbit's node advances by 360° in a mean tropical
+year, keeping the orbital plane at a fixed angle to the Sun. An
+error is returned if the orbit is too high for J₂ to drive the node
+that fast.
+
+# Arguments
+
+* a: Semimajor axis of the orbit | in kilometers
+* e: Eccentricity of the orbit
+**/
+pub fn sun_synchronous_inclination(a: f64, e: f64) -> Result<f64, &'static str> {
+    let cos_i = -(2.0 * PI / TROPICAL_YEAR) / j2_rate_factor(a, e);
+
+    if cos_i < -1.0 {
+        return Err("No sun-synchronous orbit exists for this semimajor axis");
+    }
+
+    Ok(cos_i.acos())
+}
+
+// 3/2 n J₂ (R / p)², the common factor of the J₂ secular rates
+fn j2_rate_factor(a: f64, e: f64) -> f64 {
+    let mean_motion = (earth::GRAVITATIONAL_PARAMETER / (a * a * a)).sqrt();
+    let semi_latus_rectum = a * (1.0 - e * e);
+    let radius_ratio = earth::EQUATORIAL_RADIUS / semi_latus_rectum;
+
+    1.5 * mean_motion * earth::J2 * radius_ratio * radius_ratio
+}
+
+/**
+Computes the ground track of an Earth satellite from its two-line
+elements
+
+# Returns
+
+* ground_track: Points of the ground track, from `jd_start` up to
+                `jd_end` every `step`
+
+The positions are propagated by SGP4, as in
+[Tle::position()](./struct.Tle.html#method.position), and rotated to
+the Earth-fixed frame as in
+[ground_track_with_propagator()](./fn.ground_track_with_propagator.html).
+An error is returned unless `step` is positive, or if SGP4 fails at
+any point of the track.
+
+# Arguments
+
+* tle     : Two-line elements of the satellite
+* jd_start: Julian day of the first point | in UT
+* jd_end  : Julian day after which the track ends | in UT
+* step    : Interval between the points | in days
+**/
+pub fn ground_track(
+    tle: &Tle,
+    jd_start: f64,
+    jd_end: f64,
+    step: f64,
+) -> Result<Vec<GroundTrackPoint>, &'static str> {
+    let sgp4 = Sgp4::new(tle)?;
+
+    track_times(jd_start, jd_end, step)?
+        .map(|jd| Ok(track_point(jd, sgp4.position((jd - tle.epoch) * 1440.0)?)))
+        .collect()
+}
+
+/**
+Computes the ground track of an Earth satellite from its positions
+
+# Returns
+
+* ground_track: Points of the ground track, from `jd_start` up to
+                `jd_end` every `step`
+
+The positions are rotated to the Earth-fixed frame by the mean
+sidereal time, which is the convention of the TEME frame SGP4
+produces; polar motion is ignored. This suits propagators other than
+the SGP4 of [ground_track()](./fn.ground_track.html), such as SDP4 for
+deep-space orbits. An error is returned unless `step` is positive.
+
+# Arguments
+
+* position: Geocentric position of the satellite referred to the
+            true equator and mean equinox of the date (TEME), as a
+            function of the Julian day in UT | in kilometers
+* jd_start: Julian day of the first point | in UT
+* jd_end  : Julian day after which the track ends | in UT
+* step    : Interval between the points | in days
+**/
+pub fn ground_track_with_propagator<F: Fn(f64) -> coordinate::RectPoint>(
+    position: F,
+    jd_start: f64,
+    jd_end: f64,
+    step: f64,
+) -> Result<Vec<GroundTrackPoint>, &'static str> {
+    Ok(track_times(jd_start, jd_end, step)?
+        .map(|jd| track_point(jd, position(jd)))
+        .collect())
+}
+
+// Julian days of the points of a ground track
+fn track_times(
+    jd_start: f64,
+    jd_end: f64,
+    step: f64,
+) -> Result<impl Iterator<Item = f64>, &'static str> {
+    if step.is_nan() || step <= 0.0 {
+        return Err("The step between the points must be positive");
+    }
+
+    let n_points = ((jd_end - jd_start) / step + 1e-9).floor().max(-1.0) as i64 + 1;
+    Ok((0..n_points).map(move |i| jd_start + i as f64 * step))
+}
+
+// Point of a ground track from the TEME position of the satellite
+fn track_point(jd: f64, position: coordinate::RectPoint) -> GroundTrackPoint {
+    let earth_fixed = coordinate::RotationMatrix::about_z(time::mean_sidereal(jd)) * position;
+    let (mut geograph_point, height) = earth::geograph_from_rect(&earth_fixed);
+    geograph_point.long = angle::limit_pi(geograph_point.long);
+
+    GroundTrackPoint {
+        jd,
+        geograph_point,
+        height,
+    }
+}
+
+/// Represents the mean elements of a two-line element set (TLE)
+#[derive(Copy, Clone, Debug)]
+pub struct Tle {
+    /// Satellite catalog number
+    pub catalog_number: u32,
+    /// Julian day of the epoch of the elements | in UT
+    pub epoch: f64,
+    /// Drag term B* | in inverse Earth radii
+    pub bstar: f64,
+    /// Inclination to the true equator of the epoch | in radians
+    pub inclination: f64,
+    /// Right ascension of the ascending node | in radians
+    pub node: f64,
+    /// Eccentricity
+    pub eccentricity: f64,
+    /// Argument of perigee | in radians
+    pub arg_perigee: f64,
+    /// Mean anomaly | in radians
+    pub mean_anomaly: f64,
+    /// Mean motion | in revolutions per day
+    pub mean_motion: f64,
+}
+
+impl Tle {
+    /**
+    Parses a two-line element set
+
+    # Returns
+
+    * tle: The elements
+
+    The lines follow the fixed-column format of NORAD, with two-digit
+    years from 1957 to 2056. An error is returned if a line is too
+    short, a field can't be read, or a checksum doesn't match.
+
+    # Arguments
+
+    * line_1: First line, starting with `1`
+    * line_2: Second line, starting with `2`
+    **/
+    pub fn parse(line_1: &str, line_2: &str) -> Result<Tle, &'static str> {
+        let (line_1, line_2) = (line_1.trim_end(), line_2.trim_end());
+        if !line_1.starts_with("1 ") || !line_2.starts_with("2 ") {
+            return Err("The lines of a TLE must start with 1 and 2");
+        }
+        if !line_1.is_ascii() || !line_2.is_ascii() {
+            return Err("The lines of a TLE must be ASCII");
+        }
+        if line_1.len() < 69 || line_2.len() < 69 {
+            return Err("The lines of a TLE must be 69 characters long");
+        }
+        if !checksum_matches(line_1) || !checksum_matches(line_2) {
+            return Err("The checksum of a TLE line doesn't match");
+        }
+
+        let catalog_number = tle_field(line_1, 3, 7)? as u32;
+        if tle_field(line_2, 3, 7)? as u32 != catalog_number {
+            return Err("The lines of a TLE are of different satellites");
+        }
+
+        let two_digit_year = tle_field(line_1, 19, 20)? as i16;
+        let year = if two_digit_year < 57 {
+            2000 + two_digit_year
+        } else {
+            1900 + two_digit_year
+        };
+        // Day 1.0 is January 1 at 0h
+        let epoch = time::julian_day(&time::Date {
+            year,
+            month: time::Month::Jan,
+            decimal_day: tle_field(line_1, 21, 32)?,
+            cal_type: time::CalType::Gregorian,
+        });
+
+        Ok(Tle {
+            catalog_number,
+            epoch,
+            bstar: tle_exponent_field(line_1, 54, 61)?,
+            inclination: tle_field(line_2, 9, 16)?.to_radians(),
+            node: tle_field(line_2, 18, 25)?.to_radians(),
+            eccentricity: tle_field(line_2, 27, 33)? * 1e-7,
+            arg_perigee: tle_field(line_2, 35, 42)?.to_radians(),
+            mean_anomaly: tle_field(line_2, 44, 51)?.to_radians(),
+            mean_motion: tle_field(line_2, 53, 63)?,
+        })
+    }
+
+    /**
+    Computes the position of the satellite by SGP4
+
+    # Returns
+
+    * position: Geocentric position of the satellite referred to the
+                true equator and mean equinox of the date (TEME) | in
+                kilometers
+
+    The near-Earth SGP4 model of Hoots and Roehrich (Spacetrack Report
+    No. 3, 1980), as revised by Vallado et al. (AIAA 2006-6753), is
+    used with the WGS-72 constants the elements are fitted with. An
+    error is returned for deep-space orbits, with periods of 225
+    minutes or more, which need the SDP4 model, or if the orbit has
+    decayed at the time.
+
+    # Arguments
+
+    * jd: Julian day | in UT
+    **/
+    pub fn position(&self, jd: f64) -> Result<coordinate::RectPoint, &'static str> {
+        Sgp4::new(self)?.position((jd - self.epoch) * 1440.0)
+    }
+}
+
+// Number in columns `start` to `end` of a TLE line, counted from 1
+fn tle_field(line: &str, start: usize, end: usize) -> Result<f64, &'static str> {
+    let field = line
+        .get(start - 1..end)
+        .ok_or("A TLE field is missing")?
+        .trim();
+
+    field.parse().map_err(|_| "A TLE field isn't a number")
+}
+
+// Number with an assumed decimal point and an exponent, as ` 12345-3`
+// for 0.12345e-3, in columns `start` to `end` of a TLE line
+fn tle_exponent_field(line: &str, start: usize, end: usize) -> Result<f64, &'static str> {
+    let field = line.get(start - 1..end).ok_or("A TLE field is missing")?;
+    let (mantissa, exponent) = field.split_at(field.len() - 2);
+    let mantissa = mantissa.trim();
+    let (sign, digits) = match mantissa.strip_prefix('-') {
+        Some(digits) => (-1.0, digits),
+        None => (1.0, mantissa.trim_start_matches('+')),
+    };
+
+    let digits: f64 = digits.parse().map_err(|_| "A TLE field isn't a number")?;
+    let exponent: i32 = exponent
+        .trim()
+        .parse()
+        .map_err(|_| "A TLE field isn't a number")?;
+
+    Ok(sign * digits * 10_f64.powi(exponent - 5))
+}
+
+// Whether the last digit of a TLE line is the sum modulo 10 of its
+// digits, a minus sign counting as 1
+fn checksum_matches(line: &str) -> bool {
+    let sum: u32 = line[..68]
+        .chars()
+        .map(|c| match c {
+            '-' => 1,
+            _ => c.to_digit(10).unwrap_or(0),
+        })
+        .sum();
+
+    line[68..69].parse() == Ok(sum % 10)
+}
+
+// WGS-72 constants of SGP4: equatorial radius | in kilometers, √(μ)
+// | in Earth radii^1.5 per minute, and the zonal harmonics
+const SGP4_RADIUS: f64 = 6378.135;
+const SGP4_XKE: f64 = 0.07436691613317342;
+const SGP4_J2: f64 = 0.001082616;
+const SGP4_J3: f64 = -0.00000253881;
+const SGP4_J4: f64 = -0.00000165597;
+
+// Near-Earth SGP4 model initialized from mean elements, after the
+// sgp4init() and sgp4() routines of Vallado et al. (2006); lengths
+// in Earth radii and times in minutes
+#[derive(Copy, Clone, Debug)]
+struct Sgp4 {
+    bstar: f64,
+    inclination: f64,
+    node: f64,
+    eccentricity: f64,
+    arg_perigee: f64,
+    mean_anomaly: f64,
+    mean_motion: f64,
+    // Whether the perigee is below 220 km, dropping the higher order
+    // drag terms
+    simple: bool,
+    con41: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    eta: f64,
+    cc1: f64,
+    cc4: f64,
+    cc5: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    delmo: f64,
+    sinmao: f64,
+    mdot: f64,
+    argpdot: f64,
+    nodedot: f64,
+    omgcof: f64,
+    xmcof: f64,
+    nodecf: f64,
+    t2cof: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+    xlcof: f64,
+    aycof: f64,
+}
+
+impl Sgp4 {
+    fn new(tle: &Tle) -> Result<Sgp4, &'static str> {
+        let j3oj2 = SGP4_J3 / SGP4_J2;
+        let ecco = tle.eccentricity;
+        let inclo = tle.inclination;
+        let no_kozai = tle.mean_motion * 2.0 * PI / 1440.0;
+
+        // Recovers the original mean motion from the Kozai one
+        let eccsq = ecco * ecco;
+        let omeosq = 1.0 - eccsq;
+        let rteosq = omeosq.sqrt();
+        let (sinio, cosio) = inclo.sin_cos();
+        let cosio2 = cosio * cosio;
+        let ak = (SGP4_XKE / no_kozai).powf(2.0 / 3.0);
+        let d1 = 0.75 * SGP4_J2 * (3.0 * cosio2 - 1.0) / (rteosq * omeosq);
+        let del = d1 / (ak * ak);
+        let adel = ak * (1.0 - del * del - del * (1.0 / 3.0 + 134.0 * del * del / 81.0));
+        let del = d1 / (adel * adel);
+        let no_unkozai = no_kozai / (1.0 + del);
+
+        if 2.0 * PI / no_unkozai >= 225.0 {
+            return Err("SGP4 doesn't propagate deep-space orbits");
+        }
+
+        let ao = (SGP4_XKE / no_unkozai).powf(2.0 / 3.0);
+        let po = ao * omeosq;
+        let con42 = 1.0 - 5.0 * cosio2;
+        let con41 = -con42 - cosio2 - cosio2;
+        let posq = po * po;
+        let rp = ao * (1.0 - ecco);
+
+        // The atmospheric density parameter s is lowered for perigees
+        // below 156 km
+        let perigee = (rp - 1.0) * SGP4_RADIUS;
+        let (sfour, qzms24) = if perigee < 156.0 {
+            let sfour = if perigee < 98.0 { 20.0 } else { perigee - 78.0 };
+            (
+                sfour / SGP4_RADIUS + 1.0,
+                ((120.0 - sfour) / SGP4_RADIUS).powi(4),
+            )
+        } else {
+            (
+                78.0 / SGP4_RADIUS + 1.0,
+                ((120.0 - 78.0) / SGP4_RADIUS).powi(4),
+            )
+        };
+
+        let pinvsq = 1.0 / posq;
+        let tsi = 1.0 / (ao - sfour);
+        let eta = ao * ecco * tsi;
+        let etasq = eta * eta;
+        let eeta = ecco * eta;
+        let psisq = (1.0 - etasq).abs();
+        let coef = qzms24 * tsi.powi(4);
+        let coef1 = coef / psisq.powf(3.5);
+        let cc2 = coef1
+            * no_unkozai
+            * (ao * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+                + 0.375 * SGP4_J2 * tsi / psisq * con41 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+        let cc1 = tle.bstar * cc2;
+        let cc3 = if ecco > 1e-4 {
+            -2.0 * coef * tsi * j3oj2 * no_unkozai * sinio / ecco
+        } else {
+            0.0
+        };
+        let x1mth2 = 1.0 - cosio2;
+        let cc4 = 2.0
+            * no_unkozai
+            * coef1
+            * ao
+            * omeosq
+            * (eta * (2.0 + 0.5 * etasq) + ecco * (0.5 + 2.0 * etasq)
+                - SGP4_J2 * tsi / (ao * psisq)
+                    * (-3.0 * con41 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                        + 0.75
+                            * x1mth2
+                            * (2.0 * etasq - eeta * (1.0 + etasq))
+                            * (2.0 * tle.arg_perigee).cos()));
+        let cc5 = 2.0 * coef1 * ao * omeosq * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+        // Secular rates of the mean anomaly, argument of perigee and
+        // node
+        let cosio4 = cosio2 * cosio2;
+        let temp1 = 1.5 * SGP4_J2 * pinvsq * no_unkozai;
+        let temp2 = 0.5 * temp1 * SGP4_J2 * pinvsq;
+        let temp3 = -0.46875 * SGP4_J4 * pinvsq * pinvsq * no_unkozai;
+        let mdot = no_unkozai
+            + 0.5 * temp1 * rteosq * con41
+            + 0.0625 * temp2 * rteosq * (13.0 - 78.0 * cosio2 + 137.0 * cosio4);
+        let argpdot = -0.5 * temp1 * con42
+            + 0.0625 * temp2 * (7.0 - 114.0 * cosio2 + 395.0 * cosio4)
+            + temp3 * (3.0 - 36.0 * cosio2 + 49.0 * cosio4);
+        let xhdot1 = -temp1 * cosio;
+        let nodedot = xhdot1
+            + (0.5 * temp2 * (4.0 - 19.0 * cosio2) + 2.0 * temp3 * (3.0 - 7.0 * cosio2)) * cosio;
+
+        // Avoids a division by zero for an inclination of 180°
+        let one_plus_cosio = if (cosio + 1.0).abs() > 1.5e-12 {
+            1.0 + cosio
+        } else {
+            1.5e-12
+        };
+
+        let simple = rp < 220.0 / SGP4_RADIUS + 1.0;
+        let (d2, d3, d4, t3cof, t4cof, t5cof) = if simple {
+            (0.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+        } else {
+            let cc1sq = cc1 * cc1;
+            let d2 = 4.0 * ao * tsi * cc1sq;
+            let temp = d2 * tsi * cc1 / 3.0;
+            let d3 = (17.0 * ao + sfour) * temp;
+            let d4 = 0.5 * temp * ao * tsi * (221.0 * ao + 31.0 * sfour) * cc1;
+            (
+                d2,
+                d3,
+                d4,
+                d2 + 2.0 * cc1sq,
+                0.25 * (3.0 * d3 + cc1 * (12.0 * d2 + 10.0 * cc1sq)),
+                0.2 * (3.0 * d4
+                    + 12.0 * cc1 * d3
+                    + 6.0 * d2 * d2
+                    + 15.0 * cc1sq * (2.0 * d2 + cc1sq)),
+            )
+        };
+
+        Ok(Sgp4 {
+            bstar: tle.bstar,
+            inclination: inclo,
+            node: tle.node,
+            eccentricity: ecco,
+            arg_perigee: tle.arg_perigee,
+            mean_anomaly: tle.mean_anomaly,
+            mean_motion: no_unkozai,
+            simple,
+            con41,
+            x1mth2,
+            x7thm1: 7.0 * cosio2 - 1.0,
+            eta,
+            cc1,
+            cc4,
+            cc5,
+            d2,
+            d3,
+            d4,
+            delmo: (1.0 + eta * tle.mean_anomaly.cos()).powi(3),
+            sinmao: tle.mean_anomaly.sin(),
+            mdot,
+            argpdot,
+            nodedot,
+            omgcof: tle.bstar * cc3 * tle.arg_perigee.cos(),
+            xmcof: if ecco > 1e-4 {
+                -2.0 / 3.0 * coef * tle.bstar / eeta
+            } else {
+                0.0
+            },
+            nodecf: 3.5 * omeosq * xhdot1 * cc1,
+            t2cof: 1.5 * cc1,
+            t3cof,
+            t4cof,
+            t5cof,
+            xlcof: -0.25 * j3oj2 * sinio * (3.0 + 5.0 * cosio) / one_plus_cosio,
+            aycof: -0.5 * j3oj2 * sinio,
+        })
+    }
+
+    // TEME position at a time from the epoch | in kilometers, time in
+    // minutes
+    fn position(&self, t: f64) -> Result<coordinate::RectPoint, &'static str> {
+        let two_pi = 2.0 * PI;
+
+        // Secular gravity and drag
+        let xmdf = self.mean_anomaly + self.mdot * t;
+        let argpdf = self.arg_perigee + self.argpdot * t;
+        let nodedf = self.node + self.nodedot * t;
+        let t2 = t * t;
+        let nodem = nodedf + self.nodecf * t2;
+        let mut argpm = argpdf;
+        let mut mm = xmdf;
+        let mut tempa = 1.0 - self.cc1 * t;
+        let mut tempe = self.bstar * self.cc4 * t;
+        let mut templ = self.t2cof * t2;
+
+        if !self.simple {
+            let delomg = self.omgcof * t;
+            let delm = self.xmcof * ((1.0 + self.eta * xmdf.cos()).powi(3) - self.delmo);
+            mm = xmdf + delomg + delm;
+            argpm = argpdf - delomg - delm;
+            let t3 = t2 * t;
+            let t4 = t3 * t;
+            tempa -= self.d2 * t2 + self.d3 * t3 + self.d4 * t4;
+            tempe += self.bstar * self.cc5 * (mm.sin() - self.sinmao);
+            templ += self.t3cof * t3 + t4 * (self.t4cof + t * self.t5cof);
+        }
+
+        let am = (SGP4_XKE / self.mean_motion).powf(2.0 / 3.0) * tempa * tempa;
+        let em = self.eccentricity - tempe;
+        if !(-0.001..1.0).contains(&em) {
+            return Err("The eccentricity propagated by SGP4 is out of range");
+        }
+        let em = em.max(1e-6);
+
+        mm += self.mean_motion * templ;
+        let xlm = mm + argpm + nodem;
+        let nodem = nodem % two_pi;
+        let argpm = argpm % two_pi;
+        let xlm = xlm % two_pi;
+        let mm = (xlm - argpm - nodem) % two_pi;
+
+        // Long period periodics
+        let (sinip, cosip) = self.inclination.sin_cos();
+        let axnl = em * argpm.cos();
+        let temp = 1.0 / (am * (1.0 - em * em));
+        let aynl = em * argpm.sin() + temp * self.aycof;
+        let xl = mm + argpm + nodem + temp * self.xlcof * axnl;
+
+        // Kepler's equation in the equinoctial elements
+        let u = (xl - nodem) % two_pi;
+        let mut eo1 = u;
+        let (mut sineo1, mut coseo1) = eo1.sin_cos();
+        for _ in 0..10 {
+            (sineo1, coseo1) = eo1.sin_cos();
+            let delta =
+                (u - aynl * coseo1 + axnl * sineo1 - eo1) / (1.0 - coseo1 * axnl - sineo1 * aynl);
+            eo1 += delta.clamp(-0.95, 0.95);
+            if delta.abs() < 1e-12 {
+                break;
+            }
+        }
+
+        // Short period periodics
+        let ecose = axnl * coseo1 + aynl * sineo1;
+        let esine = axnl * sineo1 - aynl * coseo1;
+        let el2 = axnl * axnl + aynl * aynl;
+        let pl = am * (1.0 - el2);
+        if pl < 0.0 {
+            return Err("The semilatus rectum propagated by SGP4 is negative");
+        }
+
+        let rl = am * (1.0 - ecose);
+        let betal = (1.0 - el2).sqrt();
+        let temp = esine / (1.0 + betal);
+        let sinu = am / rl * (sineo1 - aynl - axnl * temp);
+        let cosu = am / rl * (coseo1 - axnl + aynl * temp);
+        let su = sinu.atan2(cosu);
+        let sin2u = (cosu + cosu) * sinu;
+        let cos2u = 1.0 - 2.0 * sinu * sinu;
+        let temp = 1.0 / pl;
+        let temp1 = 0.5 * SGP4_J2 * temp;
+        let temp2 = temp1 * temp;
+
+        let mrt = rl * (1.0 - 1.5 * temp2 * betal * self.con41) + 0.5 * temp1 * self.x1mth2 * cos2u;
+        if mrt < 1.0 {
+            return Err("The satellite has decayed");
+        }
+        let su = su - 0.25 * temp2 * self.x7thm1 * sin2u;
+        let xnode = nodem + 1.5 * temp2 * cosip * sin2u;
+        let xinc = self.inclination + 1.5 * temp2 * cosip * sinip * cos2u;
+
+        // Orientation vectors
+        let (sinsu, cossu) = su.sin_cos();
+        let (snod, cnod) = xnode.sin_cos();
+        let (sini, cosi) = xinc.sin_cos();
+        let xmx = -snod * cosi;
+        let xmy = cnod * cosi;
+        let r = mrt * SGP4_RADIUS;
+
+        Ok(coordinate::RectPoint::new(
+            r * (xmx * sinsu + cnod * cossu),
+            r * (xmy * sinsu + snod * cossu),
+            r * sini * sinsu,
+        ))
+    }
+}
@@ -0,0 +1,242 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------SEARCH-----------!
+
+/**
+Finds the times at which a condition changes
+
+# Returns
+
+* changes: (time, holds) of each change, in increasing order, `holds`
+           being the value of the condition after the change
+
+The condition is sampled at regular steps, and each change of its
+value is refined by bisection. Changes closer together than `step`
+may be missed. An error is returned unless `step` and `tolerance`
+are positive.
+
+# Arguments
+
+* start    : Start of the search
+* end      : End of the search
+* step     : Sampling step
+* tolerance: Tolerance of the times of change
+* condition: The condition, as a function of time
+**/
+pub fn changes<C>(
+    start: f64,
+    end: f64,
+    step: f64,
+    tolerance: f64,
+    mut condition: C,
+) -> Result<Vec<(f64, bool)>, &'static str>
+where
+    C: FnMut(f64) -> bool,
+{
+    check_step(step, tolerance)?;
+
+    let mut changes = Vec::new();
+
+    let mut t = start;
+    let mut holds = condition(t);
+    while t < end {
+        let next_t = (t + step).min(end);
+        let next_holds = condition(next_t);
+
+        if next_holds != holds {
+            changes.push((
+                bisect(t, next_t, holds, tolerance, &mut condition),
+                next_holds,
+            ));
+        }
+
+        t = next_t;
+        holds = next_holds;
+    }
+
+    Ok(changes)
+}
+
+/**
+Finds the intervals of time during which a condition holds
+
+# Returns
+
+* intervals: (start, end) of each interval, in increasing order
+
+The condition is sampled at regular steps, and each change of its
+value is refined by bisection. Intervals shorter than `step` may be
+missed. An interval in progress at `start` or `end` is clipped to
+it. An error is returned unless `step` and `tolerance` are positive.
+
+# Arguments
+
+* start    : Start of the search
+* end      : End of the search
+* step     : Sampling step
+* tolerance: Tolerance of the interval bounds
+* condition: The condition, as a function of time
+**/
+pub fn intervals<C>(
+    start: f64,
+    end: f64,
+    step: f64,
+    tolerance: f64,
+    mut condition: C,
+) -> Result<Vec<(f64, f64)>, &'static str>
+where
+    C: FnMut(f64) -> bool,
+{
+    check_step(step, tolerance)?;
+
+    let mut intervals = Vec::new();
+    let mut interval_start = if condition(start) { Some(start) } else { None };
+
+    for (t, holds) in changes(start, end, step, tolerance, &mut condition)? {
+        match (holds, interval_start.take()) {
+            (false, Some(s)) => intervals.push((s, t)),
+            (true, _) => interval_start = Some(t),
+            (false, None) => {}
+        }
+    }
+
+    if let Some(s) = interval_start {
+        intervals.push((s, end));
+    }
+
+    Ok(intervals)
+}
+
+/**
+Finds the time at which a function is least
+
+# Returns
+
+(time, value)
+
+* time : Time of the least value
+* value: Least value of the function
+
+The function is sampled at regular steps, and the least sample is
+refined by golden-section search between its neighbors. Minima
+narrower than `step` may be missed, and of minima deeper than the
+samples show, the one whose sample is least is taken. An error is
+returned unless `step` and `tolerance` are positive.
+
+# Arguments
+
+* start    : Start of the search
+* end      : End of the search
+* step     : Sampling step
+* tolerance: Tolerance of the time of the least value
+* function : The function, as a function of time
+**/
+pub fn minimize<F>(
+    start: f64,
+    end: f64,
+    step: f64,
+    tolerance: f64,
+    mut function: F,
+) -> Result<(f64, f64), &'static str>
+where
+    F: FnMut(f64) -> f64,
+{
+    check_step(step, tolerance)?;
+
+    let n_steps = ((end - start) / step).ceil().max(1.0) as usize;
+    let time_at = |i: usize| (start + i as f64 * step).min(end);
+
+    let (i_least, least) = (0..=n_steps)
+        .map(|i| (i, function(time_at(i))))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((0, f64::INFINITY));
+
+    let a = time_at(i_least.saturating_sub(1));
+    let b = time_at((i_least + 1).min(n_steps));
+    let (t, value) = golden_section(a, b, tolerance, &mut function);
+
+    if value < least {
+        Ok((t, value))
+    } else {
+        Ok((time_at(i_least), least))
+    }
+}
+
+// Checks the sampling step and tolerance of a search, with which the
+// sampling and refinement loops terminate
+fn check_step(step: f64, tolerance: f64) -> Result<(), &'static str> {
+    if step.is_nan() || step <= 0.0 {
+        Err("The sampling step must be positive")
+    } else if tolerance.is_nan() || tolerance <= 0.0 {
+        Err("The tolerance must be positive")
+    } else {
+        Ok(())
+    }
+}
+
+// Least value of a function between a and b, by golden-section search
+fn golden_section<F>(mut a: f64, mut b: f64, tolerance: f64, function: &mut F) -> (f64, f64)
+where
+    F: FnMut(f64) -> f64,
+{
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let (mut f_c, mut f_d) = (function(c), function(d));
+    while b - a > tolerance {
+        if f_c < f_d {
+            b = d;
+            d = c;
+            f_d = f_c;
+            c = b - ratio * (b - a);
+            f_c = function(c);
+        } else {
+            a = c;
+            c = d;
+            f_c = f_d;
+            d = a + ratio * (b - a);
+            f_d = function(d);
+        }
+    }
+
+    let t = (a + b) / 2.0;
+    (t, function(t))
+}
+
+// Time at which the condition changes from `holds_at_a` between a and b
+fn bisect<C>(mut a: f64, mut b: f64, holds_at_a: bool, tolerance: f64, condition: &mut C) -> f64
+where
+    C: FnMut(f64) -> bool,
+{
+    while b - a > tolerance {
+        let mid = (a + b) / 2.0;
+        if condition(mid) == holds_at_a {
+            a = mid;
+        } else {
+            b = mid;
+        }
+    }
+
+    (a + b) / 2.0
+}
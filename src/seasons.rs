@@ -0,0 +1,127 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------SEASONS-----------!
+
+use crate::angle;
+use crate::sun;
+use crate::time;
+use crate::Accuracy;
+
+/// Represents an astronomical season of the northern hemisphere
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Season {
+    /// From the March equinox to the June solstice
+    Spring,
+    /// From the June solstice to the September equinox
+    Summer,
+    /// From the September equinox to the December solstice
+    Autumn,
+    /// From the December solstice to the March equinox
+    Winter,
+}
+
+/// Represents the lengths of the seasons beginning in a year | in
+/// days
+#[derive(Copy, Clone, Debug)]
+pub struct SeasonLengths {
+    /// Spring
+    pub spring: f64,
+    /// Summer
+    pub summer: f64,
+    /// Autumn
+    pub autumn: f64,
+    /// Winter, which ends in the following year
+    pub winter: f64,
+}
+
+/**
+Computes the time of the equinox or solstice beginning a season
+
+# Returns
+
+* jd: Julian (Ephemeris) day at which the apparent longitude of the
+      Sun is a multiple of 90°
+
+The time is found by iterating on the apparent position of the Sun,
+Meeus, Astronomical Algorithms, chapter 27; the solar theory limits
+its accuracy to about half a minute.
+
+# Arguments
+
+* year  : Gregorian calendar year
+* season: The season
+**/
+pub fn beginning(year: i16, season: Season) -> f64 {
+    let (month, day, solar_long) = match season {
+        Season::Spring => (time::Month::Mar, 20.0, 0.0),
+        Season::Summer => (time::Month::June, 21.0, 90_f64.to_radians()),
+        Season::Autumn => (time::Month::Sept, 23.0, 180_f64.to_radians()),
+        Season::Winter => (time::Month::Dec, 22.0, 270_f64.to_radians()),
+    };
+
+    let mut jd = time::julian_day(&time::Date {
+        year,
+        month,
+        decimal_day: day,
+        cal_type: time::CalType::Gregorian,
+    });
+    for _ in 0..10 {
+        let (sun, _) = sun::apparent_ecl_pos(jd, Accuracy::High);
+        let correction = 58.0 * angle::limit_pi(solar_long - sun.long).sin();
+        jd += correction;
+        if correction.abs() < 1e-6 {
+            break;
+        }
+    }
+
+    jd
+}
+
+/**
+Computes the lengths of the seasons beginning in a year
+
+# Returns
+
+* season_lengths: Lengths of the seasons | in days
+
+The seasons are bounded by the instants of
+[beginning()](./fn.beginning.html); winter runs to the March equinox
+of the following year.
+
+# Arguments
+
+* year: Gregorian calendar year
+**/
+pub fn lengths(year: i16) -> SeasonLengths {
+    let spring = beginning(year, Season::Spring);
+    let summer = beginning(year, Season::Summer);
+    let autumn = beginning(year, Season::Autumn);
+    let winter = beginning(year, Season::Winter);
+
+    SeasonLengths {
+        spring: summer - spring,
+        summer: autumn - summer,
+        autumn: winter - autumn,
+        winter: beginning(year + 1, Season::Spring) - winter,
+    }
+}
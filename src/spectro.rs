@@ -0,0 +1,247 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------SPECTROSCOPY-----------!
+
+// Radial velocities are positive away from the observer.
+
+use crate::au;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::planet;
+use crate::time;
+use crate::Accuracy;
+
+/// Speed of light in vacuum | in kilometers per second
+pub const SPEED_OF_LIGHT: f64 = 299792.458;
+
+/**
+Computes the redshift for a radial velocity
+
+# Returns
+
+* z: Redshift
+
+The relativistic Doppler formula for motion along the line of sight
+is used.
+
+# Arguments
+
+* rv: Radial velocity | in kilometers per second
+**/
+pub fn z_from_velocity(rv: f64) -> f64 {
+    let beta = rv / SPEED_OF_LIGHT;
+
+    ((1.0 + beta) / (1.0 - beta)).sqrt() - 1.0
+}
+
+/**
+Computes the radial velocity for a redshift
+
+# Returns
+
+* rv: Radial velocity | in kilometers per second
+
+This is the inverse of [z_from_velocity()](./fn.z_from_velocity.html).
+
+# Arguments
+
+* z: Redshift
+**/
+pub fn velocity_from_z(z: f64) -> f64 {
+    let s = (1.0 + z) * (1.0 + z);
+
+    SPEED_OF_LIGHT * (s - 1.0) / (s + 1.0)
+}
+
+/**
+Computes the redshift of an observed wavelength
+
+# Returns
+
+* z: Redshift
+
+# Arguments
+
+* observed_wavelength: Observed wavelength
+* rest_wavelength    : Rest wavelength, in the same unit
+**/
+pub fn z_from_wavelength(observed_wavelength: f64, rest_wavelength: f64) -> f64 {
+    observed_wavelength / rest_wavelength - 1.0
+}
+
+/**
+Computes the observed wavelength for a redshift
+
+# Returns
+
+* observed_wavelength: Observed wavelength, in the unit of
+                       `rest_wavelength`
+
+# Arguments
+
+* rest_wavelength: Rest wavelength
+* z              : Redshift
+**/
+pub fn wavelength_from_z(rest_wavelength: f64, z: f64) -> f64 {
+    rest_wavelength * (1.0 + z)
+}
+
+/**
+Computes the Doppler-shifted wavelength of a line
+
+# Returns
+
+* observed_wavelength: Observed wavelength, in the unit of
+                       `rest_wavelength`
+
+# Arguments
+
+* rv             : Radial velocity of the source | in kilometers per
+                   second
+* rest_wavelength: Rest wavelength of the line
+**/
+pub fn doppler_shift(rv: f64, rest_wavelength: f64) -> f64 {
+    wavelength_from_z(rest_wavelength, z_from_velocity(rv))
+}
+
+/**
+Computes the velocity of an observer towards a target due to the
+rotation of the Earth
+
+# Returns
+
+* diurnal_velocity: Component of the observer's velocity towards the
+                    target | in kilometers per second
+
+# Arguments
+
+* hour_angle: Hour angle of the target | in radians
+* dec       : Declination of the target | in radians
+* observer  : The observer
+**/
+pub fn diurnal_velocity(hour_angle: f64, dec: f64, observer: &coordinate::Observer) -> f64 {
+    let (_, rho_cos_phi) = planet::earth::rho_sin_cos_phi(observer.lat, observer.height);
+    let equatorial_speed =
+        planet::earth::ROTATIONAL_ANGULAR_VELOCITY * planet::earth::EQUATORIAL_RADIUS;
+
+    -equatorial_speed * rho_cos_phi * dec.cos() * hour_angle.sin()
+}
+
+/**
+Computes the velocity of the Earth towards a target due to its
+orbital motion
+
+# Returns
+
+* orbital_velocity: Component of the Earth's heliocentric velocity
+                    towards the target | in kilometers per second
+
+The velocity is the derivative of the VSOP87 position of the Earth.
+It is heliocentric, and differs from the barycentric velocity by the
+motion of the Sun about the barycenter, up to about 13 m/s.
+
+# Arguments
+
+* eq_point: Equatorial point of the target, referred to the mean
+            equinox of the date | in radians
+* jd      : Julian (Ephemeris) day
+**/
+pub fn orbital_velocity(eq_point: &coordinate::EqPoint, jd: f64) -> f64 {
+    let oblq_eclip = ecliptic::mean_obliquity_laskar(jd);
+    let long = coordinate::ecliptic_long_from_eq(eq_point.asc, eq_point.dec, oblq_eclip);
+    let lat = coordinate::ecliptic_lat_from_eq(eq_point.asc, eq_point.dec, oblq_eclip);
+    let target = [lat.cos() * long.cos(), lat.cos() * long.sin(), lat.sin()];
+
+    let earth = |jd: f64| {
+        let (long, lat, rad_vec) =
+            planet::heliocent_coords(&planet::Planet::Earth, jd, Accuracy::High);
+        [
+            rad_vec * lat.cos() * long.cos(),
+            rad_vec * lat.cos() * long.sin(),
+            rad_vec * lat.sin(),
+        ]
+    };
+    let h = 0.01;
+    let (before, after) = (earth(jd - h), earth(jd + h));
+
+    let au_per_day = (0..3)
+        .map(|i| (after[i] - before[i]) / (2.0 * h) * target[i])
+        .sum::<f64>();
+
+    au_per_day * au::AU_KM / 86400.0
+}
+
+/**
+Computes the correction of a radial velocity for the motion of the
+observer
+
+# Returns
+
+* velocity_correction: Velocity of the observer towards the target,
+                       orbital and diurnal | in kilometers per second
+
+Pass it to [corrected_velocity()](./fn.corrected_velocity.html) to
+refer a measured radial velocity to the Sun.
+
+# Arguments
+
+* eq_point: Equatorial point of the target, referred to the mean
+            equinox of the date | in radians
+* observer: The observer
+* jd      : Julian (Ephemeris) day
+**/
+pub fn velocity_correction(
+    eq_point: &coordinate::EqPoint,
+    observer: &coordinate::Observer,
+    jd: f64,
+) -> f64 {
+    let sidereal = time::apparent_sidereal_from_jd(time::ut_from_tt(jd));
+    let hour_angle = coordinate::hour_angle_from_long(sidereal, observer.long, eq_point.asc);
+
+    orbital_velocity(eq_point, jd) + diurnal_velocity(hour_angle, eq_point.dec, observer)
+}
+
+/**
+Refers a measured radial velocity to the Sun
+
+# Returns
+
+* rv: Heliocentric radial velocity | in kilometers per second
+
+The velocities are composed relativistically, the redshifts
+multiplying as `1 + z = (1 + z_measured) · (1 + z_correction)`.
+
+# Arguments
+
+* measured_rv        : Radial velocity measured by the observer | in
+                       kilometers per second
+* velocity_correction: Velocity of the observer towards the target,
+                       from [velocity_correction()](./fn.velocity_correction.html)
+                       | in kilometers per second
+**/
+pub fn corrected_velocity(measured_rv: f64, velocity_correction: f64) -> f64 {
+    let z =
+        (1.0 + z_from_velocity(measured_rv)) * (1.0 + z_from_velocity(velocity_correction)) - 1.0;
+
+    velocity_from_z(z)
+}
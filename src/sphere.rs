@@ -0,0 +1,232 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------SPHERICAL TRIGONOMETRY-----------!
+
+// In a spherical triangle, the sides a, b and c are arcs of great
+// circles, and the angles A, B and C are opposite to them. Points on
+// the sphere are given by a longitude increasing from the x axis
+// towards the y axis, and a latitude.
+
+use crate::angle;
+
+/**
+Computes a side of a spherical triangle from the two other sides and
+the angle between them, by the law of cosines for sides
+
+# Returns
+
+* a: Side opposite to the angle | in radians
+
+# Arguments
+
+* b: Side | in radians
+* c: Side | in radians
+* A: Angle between `b` and `c` | in radians
+**/
+pub fn side_from_sides_angle(b: f64, c: f64, A: f64) -> f64 {
+    (b.cos() * c.cos() + b.sin() * c.sin() * A.cos())
+        .clamp(-1.0, 1.0)
+        .acos()
+}
+
+/**
+Computes an angle of a spherical triangle from its three sides, by
+the law of cosines for sides
+
+# Returns
+
+* A: Angle opposite to `a` | in radians
+
+# Arguments
+
+* a: Side | in radians
+* b: Side | in radians
+* c: Side | in radians
+**/
+pub fn angle_from_sides(a: f64, b: f64, c: f64) -> f64 {
+    ((a.cos() - b.cos() * c.cos()) / (b.sin() * c.sin()))
+        .clamp(-1.0, 1.0)
+        .acos()
+}
+
+/**
+Computes an angle of a spherical triangle from the two other angles
+and the side between them, by the law of cosines for angles
+
+# Returns
+
+* A: Angle opposite to the side | in radians
+
+# Arguments
+
+* B: Angle | in radians
+* C: Angle | in radians
+* a: Side between `B` and `C` | in radians
+**/
+pub fn angle_from_angles_side(B: f64, C: f64, a: f64) -> f64 {
+    (-B.cos() * C.cos() + B.sin() * C.sin() * a.cos())
+        .clamp(-1.0, 1.0)
+        .acos()
+}
+
+/**
+Computes a side of a spherical triangle from its three angles, by the
+law of cosines for angles
+
+# Returns
+
+* a: Side opposite to `A` | in radians
+
+# Arguments
+
+* A: Angle | in radians
+* B: Angle | in radians
+* C: Angle | in radians
+**/
+pub fn side_from_angles(A: f64, B: f64, C: f64) -> f64 {
+    ((A.cos() + B.cos() * C.cos()) / (B.sin() * C.sin()))
+        .clamp(-1.0, 1.0)
+        .acos()
+}
+
+/**
+Computes an angle of a spherical triangle from a side, its opposite
+angle and another side, by the law of sines
+
+# Returns
+
+* B: Angle opposite to `b`, in [0, π/2] | in radians
+
+The law of sines gives `B` and `π - B` alike; the other triangle
+parts decide which one is meant.
+
+# Arguments
+
+* a: Side | in radians
+* A: Angle opposite to `a` | in radians
+* b: Side | in radians
+**/
+pub fn angle_from_sines(a: f64, A: f64, b: f64) -> Result<f64, &'static str> {
+    let sin_B = b.sin() * A.sin() / a.sin();
+
+    if sin_B.is_finite() && sin_B.abs() <= 1.0 + 1e-12 {
+        Ok(sin_B.clamp(-1.0, 1.0).asin())
+    } else {
+        Err("No spherical triangle has the given parts")
+    }
+}
+
+/**
+Computes the intersections of two great circles, each given by two
+points on it
+
+# Returns
+
+(point_1, point_2)
+
+* point_1: (long, lat) of one intersection | in radians
+* point_2: (long, lat) of the other, antipodal to `point_1`
+           | in radians
+
+# Arguments
+
+* circle_1: Two distinct, non-antipodal points on the first great
+            circle, as (long, lat) | in radians
+* circle_2: Two distinct, non-antipodal points on the second great
+            circle, as (long, lat) | in radians
+**/
+#[allow(clippy::type_complexity)]
+pub fn great_circle_intersection(
+    circle_1: &[(f64, f64); 2],
+    circle_2: &[(f64, f64); 2],
+) -> Result<((f64, f64), (f64, f64)), &'static str> {
+    let pole = |circle: &[(f64, f64); 2]| {
+        cross(
+            &rectangular(circle[0].0, circle[0].1),
+            &rectangular(circle[1].0, circle[1].1),
+        )
+    };
+    let pole_1 = pole(circle_1);
+    let pole_2 = pole(circle_2);
+
+    let intersection = cross(&pole_1, &pole_2);
+    let norm = dot(&intersection, &intersection).sqrt();
+    if norm < 1e-12 * dot(&pole_1, &pole_1).sqrt() * dot(&pole_2, &pole_2).sqrt() {
+        return Err("The great circles coincide or are not defined by their points");
+    }
+
+    let point = spherical(&intersection);
+    let antipode = spherical(&intersection.map(|x| -x));
+
+    Ok((point, antipode))
+}
+
+/**
+Computes the rectangular unit vector of a point on the sphere
+
+# Returns
+
+* v: (x, y, z) unit vector
+
+# Arguments
+
+* long: Longitude | in radians
+* lat : Latitude | in radians
+**/
+pub fn rectangular(long: f64, lat: f64) -> [f64; 3] {
+    [lat.cos() * long.cos(), lat.cos() * long.sin(), lat.sin()]
+}
+
+/**
+Computes the point on the sphere in the direction of a rectangular
+vector
+
+# Returns
+
+(long, lat)
+
+* long: Longitude, 0 - 2π | in radians
+* lat : Latitude | in radians
+
+# Arguments
+
+* v: (x, y, z) vector, of any non-zero length
+**/
+pub fn spherical(v: &[f64; 3]) -> (f64, f64) {
+    (
+        angle::limit_twoPI(v[1].atan2(v[0])),
+        v[2].atan2(v[0].hypot(v[1])),
+    )
+}
+
+pub(crate) fn cross(u: &[f64; 3], v: &[f64; 3]) -> [f64; 3] {
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+pub(crate) fn dot(u: &[f64; 3], v: &[f64; 3]) -> f64 {
+    u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
+}
@@ -22,6 +22,27 @@ THE SOFTWARE.
 
 // !-------------STAR------------!
 
+use crate::angle;
+use crate::coordinate;
+use crate::reduce;
+use crate::time;
+
+/// Catalog entry of Polaris, α Ursae Minoris, from the new reduction of
+/// the Hipparcos data (van Leeuwen, 2007)
+pub const POLARIS: reduce::CatalogEntry = reduce::CatalogEntry {
+    // 2h31m49.09s
+    asc: 0.662_431_72,
+    // +89°15′50.8″
+    dec: 1.557_952_64,
+    // +44.48 mas/yr on the sky, divided by cos δ
+    pm_asc: 1.679_04e-5,
+    // −11.85 mas/yr
+    pm_dec: -5.745e-8,
+    // 7.54 mas
+    parallax: 3.655_5e-8,
+    epoch: time::J2000,
+};
+
 /*
 Computes the combined magnitude of two stars
 
@@ -100,7 +121,6 @@ Computes the absolute magnitude of a star from its distance from earth
 * `d`: The star's to earth *(parsecs)*
 * `am`: Apparent magnitude of the star
 **/
-
 #[inline]
 pub fn abs_mag_frm_dist(d: f64, am: f64) -> f64 {
     am + 5.0 - 5.0 * d.log10()
@@ -121,7 +141,6 @@ same star to the north pole of the ecliptic
 * `eclip_lat`: The star's ecliptical latitude *| in radians*
 * `oblq_eclip`: Obliquity of the ecliptic *| in radians*
 **/
-
 #[inline]
 pub fn angl_between_north_celes_and_eclip_pole(
     eclip_long: f64,
@@ -216,3 +235,29 @@ pub fn proper_motion_in_eq_coords(
 
     (pmotion_long, pmotion_lat)
 }
+
+/**
+Computes the hour angle of Polaris, for aligning a polar scope
+
+# Returns
+
+* `hour_angle`: Local hour angle of Polaris, in [0, 2π) | in radians
+
+This is the position angle of Polaris about the true celestial pole,
+measured westward from the meridian above the pole. On a polar scope
+reticle drawn as a clock face, 0 is 12 o'clock and each clock hour
+spans 2π/12, before any inversion by the optics. The place of
+[POLARIS](./constant.POLARIS.html) is reduced for proper motion,
+precession, nutation and aberration with
+[reduce::apparent_place()](../reduce/fn.apparent_place.html).
+
+# Arguments
+
+* `jd`      : Julian day, UT
+* `observer`: The observer
+**/
+pub fn polaris_hour_angle(jd: f64, observer: &coordinate::Observer) -> f64 {
+    let place = reduce::apparent_place(&POLARIS, observer, time::tt_from_ut(jd));
+
+    angle::limit_twoPI(place.hour_angle)
+}
@@ -0,0 +1,831 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------SUN-----------!
+
+use crate::angle;
+use crate::atmospheric;
+use crate::config;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::ephemeris;
+use crate::frame;
+use crate::nutation;
+use crate::planet;
+use crate::precession;
+use crate::search;
+use crate::time;
+use crate::transit;
+use crate::Accuracy;
+use num_traits::Float;
+use std::f64::consts::PI;
+
+// Mean tropical year | in days
+const TROPICAL_YEAR: f64 = 365.2422;
+
+// Semidiameter of the Sun at 1 AU | in arcseconds
+const SEMIDIAMETER_1AU: f64 = 959.63;
+
+// Equatorial horizontal parallax of the Sun at 1 AU | in arcseconds
+const PARALLAX_1AU: f64 = 8.794;
+
+/**
+Computes the semidiameter of the Sun
+
+# Returns
+
+* semidiameter: Geocentric semidiameter of the Sun | in radians
+
+# Arguments
+
+* earth_sun_dist: Earth-Sun distance | in AU
+**/
+#[inline]
+pub fn semidiameter(earth_sun_dist: f64) -> f64 {
+    angle::deg_dmas(0, 0, SEMIDIAMETER_1AU / earth_sun_dist).to_radians()
+}
+
+/**
+Computes the equatorial horizontal parallax of the Sun
+
+# Returns
+
+* parallax: Equatorial horizontal parallax of the Sun | in radians
+
+# Arguments
+
+* earth_sun_dist: Earth-Sun distance | in AU
+**/
+#[inline]
+pub fn horizontal_parallax(earth_sun_dist: f64) -> f64 {
+    angle::deg_dmas(0, 0, PARALLAX_1AU / earth_sun_dist).to_radians()
+}
+
+/**
+Computes the geocentric ecliptic position of the Sun
+
+# Returns
+
+(ecl_point, rad_vec)
+
+* ecl_point: Geocentric ecliptic point of the Sun | in radians
+* rad_vec  : Sun-Earth distance | in AU
+
+The position is geometric, referred to the mean equinox of the date
+and converted to the FK5 system. It is not corrected for nutation
+and aberration.
+
+# Arguments
+
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier of the underlying VSOP87 series
+**/
+pub fn geocent_ecl_pos(jd: f64, accuracy: Accuracy) -> (coordinate::EclPoint, f64) {
+    let (long, lat, rad_vec) = planet::heliocent_coords(&planet::Planet::Earth, jd, accuracy);
+
+    let (long, lat) = planet::fk5_correction(long + PI, -lat, jd);
+
+    (coordinate::EclPoint { long, lat }, rad_vec)
+}
+
+/**
+Computes the apparent geocentric ecliptic position of the Sun
+
+# Returns
+
+(ecl_point, rad_vec)
+
+* ecl_point: Apparent geocentric ecliptic point of the Sun,
+             referred to the true equinox of the date | in radians
+* rad_vec  : Sun-Earth distance | in AU
+
+# Arguments
+
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier of the underlying series
+**/
+pub fn apparent_ecl_pos(jd: f64, accuracy: Accuracy) -> (coordinate::EclPoint, f64) {
+    let (ecl_point, rad_vec) = geocent_ecl_pos(jd, accuracy);
+    let (nut_in_long, _) = nutation::nutation(jd, accuracy);
+    let aberration = angle::deg_dmas(0, 0, 20.4898).to_radians() / rad_vec;
+
+    (
+        coordinate::EclPoint {
+            long: angle::limit_twoPI(ecl_point.long + nut_in_long - aberration),
+            lat: ecl_point.lat,
+        },
+        rad_vec,
+    )
+}
+
+/**
+Computes the geocentric rectangular equatorial coordinates of the Sun
+
+# Returns
+
+* rect_point: Geocentric rectangular equatorial point of the Sun, in
+              `frame` | in AU
+
+The position is geometric, as in
+[geocent_ecl_pos()](./fn.geocent_ecl_pos.html), converted to the mean
+equator and equinox of the date and then to `frame` with
+[frame::SkyCoord::transform_to()](../frame/struct.SkyCoord.html#method.transform_to).
+The X axis points to the origin of the longitudes of the frame, the
+Z axis to its pole. In the galactic frame, the X axis points to the
+galactic center.
+
+# Arguments
+
+* jd   : Julian (Ephemeris) day
+* frame: Reference frame of the coordinates
+**/
+pub fn rectangular_coords(jd: f64, frame: frame::Frame) -> coordinate::RectPoint {
+    let (ecl_point, rad_vec) = geocent_ecl_pos(jd, Accuracy::High);
+    let oblq_eclip = ecliptic::mean_obliquity_laskar(jd);
+    let mean = coordinate::EqPoint::new(
+        coordinate::asc_from_ecliptic(ecl_point.long, ecl_point.lat, oblq_eclip),
+        coordinate::dec_from_ecliptic(ecl_point.long, ecl_point.lat, oblq_eclip),
+    );
+    let fk5 = precession::precess_eq(&mean, jd, time::J2000);
+
+    let sky_coord =
+        frame::SkyCoord::from_eq(frame::Frame::Fk5J2000, &fk5).transform_to(frame, Accuracy::High);
+
+    coordinate::RectPoint::from_spherical(sky_coord.long, sky_coord.lat, rad_vec)
+}
+
+/**
+Computes the solar longitude referred to the equinox of J2000.0
+
+# Returns
+
+* solar_long: Geometric geocentric ecliptic longitude of the Sun,
+              referred to the mean ecliptic and equinox of
+              J2000.0 | in radians
+
+This is the solar longitude (λ☉) in which the activity of meteor
+showers is tabulated. It is not corrected for aberration.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn solar_longitude_j2000(jd: f64) -> f64 {
+    let (ecl_point, _) = geocent_ecl_pos(jd, Accuracy::High);
+
+    precession::precess_ecl(&ecl_point, jd, time::J2000).long
+}
+
+/**
+Computes the time at which the Sun reaches a solar longitude
+
+# Returns
+
+* jd: Julian (Ephemeris) day within the Gregorian calendar `year`
+      at which [solar_longitude_j2000()](./fn.solar_longitude_j2000.html)
+      equals `solar_long`
+
+The time is found by iterating over the solar theory, and is
+accurate to well under a second.
+
+# Arguments
+
+* year      : Gregorian calendar year
+* solar_long: Solar longitude, referred to the equinox of
+              J2000.0 | in radians
+**/
+pub fn jd_from_solar_longitude(year: i16, solar_long: f64) -> f64 {
+    let start_jd = time::julian_day(&time::Date {
+        year,
+        month: time::Month::Jan,
+        decimal_day: 1.0,
+        cal_type: time::CalType::Gregorian,
+    });
+
+    let mut jd = start_jd
+        + angle::limit_twoPI(solar_long - solar_longitude_j2000(start_jd)) / (2.0 * PI)
+            * TROPICAL_YEAR;
+    for _ in 0..20 {
+        let correction = 58.0 * (solar_long - solar_longitude_j2000(jd)).sin();
+        jd += correction;
+        if correction.abs() < 1e-7 {
+            break;
+        }
+    }
+
+    jd
+}
+
+/**
+Computes the apparent equatorial position of the Sun with low accuracy
+
+# Returns
+
+(asc, dec)
+
+* asc: Apparent right ascension of the Sun | in radians
+* dec: Apparent declination of the Sun | in radians
+
+The position is computed from the geometric mean longitude and the
+equation of the center instead of the VSOP87 series, and is accurate
+to about 0.01 degree. The time arguments are reduced in `f64`, after
+which the trigonometry is evaluated in `F`, so `f32` can be used on
+targets without a double precision FPU.
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn approx_apparent_eq_pos<F: Float>(jd: f64) -> (F, F) {
+    let t = time::julian_century(jd);
+
+    let mean_long = angle::limit_360(280.46646 + t * (36000.76983 + t * 0.0003032));
+    let mean_anom = angle::limit_360(357.52911 + t * (35999.05029 - t * 0.0001537));
+    let moon_asc_node = angle::limit_360(125.04 - 1934.136 * t);
+
+    let mean_anom: F = cast(mean_anom.to_radians());
+    let moon_asc_node: F = cast(moon_asc_node.to_radians());
+    let two: F = cast(2.0);
+    let three: F = cast(3.0);
+
+    let center = cast::<F>(1.914602 - t * (0.004817 + t * 0.000014)) * mean_anom.sin()
+        + cast::<F>(0.019993 - t * 0.000101) * (two * mean_anom).sin()
+        + cast::<F>(0.000289) * (three * mean_anom).sin();
+
+    let long = cast::<F>(mean_long - 0.00569) + center - cast::<F>(0.00478) * moon_asc_node.sin();
+    let long = long.to_radians();
+
+    let oblq_eclip = cast::<F>(ecliptic::mean_obliquity_IAU(jd))
+        + cast::<F>(0.00256_f64.to_radians()) * moon_asc_node.cos();
+
+    let asc = (oblq_eclip.cos() * long.sin()).atan2(long.cos());
+    let asc = if asc < F::zero() {
+        asc + cast(angle::TWO_PI)
+    } else {
+        asc
+    };
+
+    (asc, (oblq_eclip.sin() * long.sin()).asin())
+}
+
+/**
+Computes the local horizontal position of the Sun with low accuracy
+
+# Returns
+
+(az, alt)
+
+* az : Azimuth of the Sun, measured westwards from the
+       south | in radians
+* alt: Altitude of the Sun, not corrected for atmospheric
+       refraction | in radians
+
+See [approx_apparent_eq_pos()](./fn.approx_apparent_eq_pos.html) for
+the accuracy and the use of `F`.
+
+# Arguments
+
+* jd           : Julian day
+* observer_long: Observer's geographical longitude, positive
+                 westwards | in radians
+* observer_lat : Observer's geographical latitude | in radians
+**/
+pub fn approx_horizontal_pos<F: Float>(jd: f64, observer_long: F, observer_lat: F) -> (F, F) {
+    let (asc, dec) = approx_apparent_eq_pos::<F>(jd);
+    let hour_angle =
+        coordinate::hour_angle_from_long(cast(time::mean_sidereal(jd)), observer_long, asc);
+
+    (
+        coordinate::azimuth_from_eq(hour_angle, dec, observer_lat),
+        coordinate::altitude_from_eq(hour_angle, dec, observer_lat),
+    )
+}
+
+/**
+Computes the local horizontal position of the Sun
+
+# Returns
+
+(az, alt)
+
+* az : Azimuth of the Sun, measured westwards from the
+       south | in radians
+* alt: Altitude of the Sun, not corrected for atmospheric
+       refraction | in radians
+
+# Arguments
+
+* jd      : Julian day, UT
+* observer: The observer
+* accuracy: Accuracy tier of the underlying VSOP87 series
+**/
+pub fn horizontal_pos(jd: f64, observer: &coordinate::Observer, accuracy: Accuracy) -> (f64, f64) {
+    let jde = time::tt_from_ut(jd);
+    let (eq_point, _) = ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jde, accuracy);
+    let hour_angle = coordinate::hour_angle_from_long(
+        time::apparent_sidereal_from_jd(jd),
+        observer.long,
+        eq_point.asc,
+    );
+
+    (
+        coordinate::azimuth_from_eq(hour_angle, eq_point.dec, observer.lat),
+        coordinate::altitude_from_eq(hour_angle, eq_point.dec, observer.lat),
+    )
+}
+
+/**
+Computes the local horizontal positions of the Sun at regular steps
+
+# Returns
+
+* positions: (jd, az, alt) at each step, as returned by
+             [horizontal_pos()](./fn.horizontal_pos.html)
+
+An error is returned unless `step` is positive.
+
+# Arguments
+
+* observer: The observer
+* start_jd: Julian day of the first step, UT
+* end_jd  : Julian day after which the steps stop, UT
+* step    : Step | in days
+**/
+pub fn horizontal_positions(
+    observer: &coordinate::Observer,
+    start_jd: f64,
+    end_jd: f64,
+    step: f64,
+) -> Result<Vec<(f64, f64, f64)>, &'static str> {
+    if step.is_nan() || step <= 0.0 {
+        return Err("The step must be positive");
+    }
+
+    let num_steps = ((end_jd - start_jd) / step + 1e-9).floor() as usize + 1;
+    Ok((0..num_steps)
+        .map(|i| {
+            let jd = start_jd + i as f64 * step;
+            let (az, alt) = horizontal_pos(jd, observer, Accuracy::Low);
+            (jd, az, alt)
+        })
+        .collect())
+}
+
+/// An iterator over the positions of the Sun at the same time of
+/// day on every day of a year
+///
+/// Created by [analemma()](./fn.analemma.html).
+#[derive(Debug)]
+pub struct Analemma {
+    observer: coordinate::Observer,
+    start_jd: f64,
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for Analemma {
+    /// (jd, az, alt), as given by
+    /// [horizontal_pos()](./fn.horizontal_pos.html)
+    type Item = (f64, f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let jd = self.start_jd + self.index as f64;
+        self.index += 1;
+
+        let (az, alt) = horizontal_pos(jd, &self.observer, Accuracy::Low);
+        Some((jd, az, alt))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.len - self.index;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for Analemma {}
+
+/**
+Returns an iterator over the points of the Sun's analemma
+
+The iterator yields `(jd, az, alt)` at `hour_of_day` in the
+observer's time zone on every day of the Gregorian `year`, from
+January 1 to December 31. Plotted, the points trace the analemma,
+its width set by the equation of time and its height by the Sun's
+declination.
+
+# Arguments
+
+* observer   : The observer
+* hour_of_day: Civil time of day, in the observer's time zone | in
+               hours
+* year       : Year, in the Gregorian calendar
+**/
+pub fn analemma(observer: &coordinate::Observer, hour_of_day: f64, year: i16) -> Analemma {
+    let new_year = time::julian_day(&time::Date {
+        year,
+        month: time::Month::Jan,
+        decimal_day: 1.0,
+        cal_type: time::CalType::Gregorian,
+    });
+
+    Analemma {
+        observer: *observer,
+        start_jd: new_year + (hour_of_day - observer.time_zone) / 24.0,
+        index: 0,
+        len: if time::is_leap_year(year, &time::CalType::Gregorian) {
+            366
+        } else {
+            365
+        },
+    }
+}
+
+/**
+Computes the length of the shadow of a vertical object
+
+# Returns
+
+* shadow_length: Length of the shadow on level ground, in the unit of
+                 `object_height`, or `None` if the Sun is below the
+                 horizon
+
+The shadow points towards the azimuth of the Sun plus 180°. The
+altitude of the Sun is corrected for refraction at standard
+conditions.
+
+# Arguments
+
+* object_height: Height of the object
+* jd           : Julian day, UT
+* observer     : The observer
+**/
+pub fn shadow_length(object_height: f64, jd: f64, observer: &coordinate::Observer) -> Option<f64> {
+    let (_, alt) = horizontal_pos(jd, observer, Accuracy::Low);
+    let alt = alt + atmospheric::refrac_true_alt(alt);
+
+    if alt > 0.0 {
+        Some(object_height / alt.tan())
+    } else {
+        None
+    }
+}
+
+/**
+Computes the time of solar noon on a date
+
+# Returns
+
+* solar_noon: Time of the Sun's transit across the local meridian, in
+              the observer's time zone
+
+The date is a calendar date in the observer's time zone, and so is
+the time, as in
+[transit::sun_rise_set()](../transit/fn.sun_rise_set.html).
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn solar_noon(observer: &coordinate::Observer, date: &time::Date) -> time::ZonedInstant {
+    // Start from the local mean noon and correct by the hour angle,
+    // which changes by 2π per solar day
+    let mut jd =
+        time::local_midnight(date, observer.time_zone) + 0.5 + observer.long / angle::TWO_PI;
+    for _ in 0..3 {
+        let jde = time::tt_from_ut(jd);
+        let (eq_point, _) = ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jde, Accuracy::Low);
+        let hour_angle = coordinate::hour_angle_from_long(
+            time::apparent_sidereal_from_jd(jd),
+            observer.long,
+            eq_point.asc,
+        );
+
+        jd -= angle::limit_pi(hour_angle) / angle::TWO_PI;
+    }
+
+    time::Instant::from_ut(jd).to_local(observer.time_zone)
+}
+
+/**
+Computes the length of the day on a date
+
+# Returns
+
+* day_length: Time from sunrise to sunset | in days
+
+The date is a calendar date in the observer's time zone, as in
+[transit::sun_rise_set()](../transit/fn.sun_rise_set.html), with
+the same standard altitude of −50′. The day length is 1.0 when the
+Sun doesn't set and 0.0 when it doesn't rise. If only one of the
+events happens on the date, the day runs from or to the local
+midnight.
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn day_length(observer: &coordinate::Observer, date: &time::Date) -> f64 {
+    let local_midnight = time::local_midnight(date, observer.time_zone);
+    let events = transit::sun_rise_set(observer, date);
+
+    let jd_ut = |event: Option<time::ZonedInstant>| event.map(|zoned| zoned.instant.jd_ut);
+
+    match (jd_ut(events.rise), jd_ut(events.set)) {
+        (Some(rise), Some(set)) if set > rise => set - rise,
+        // Setting before rising, near the end of a polar day
+        (Some(rise), Some(set)) => 1.0 - (rise - set),
+        (Some(rise), None) => local_midnight + 1.0 - rise,
+        (None, Some(set)) => set - local_midnight,
+        (None, None) => {
+            let noon = solar_noon(observer, date);
+            let (_, alt) = horizontal_pos(noon.instant.jd_ut, observer, Accuracy::Low);
+            if alt > transit::StandardAltitude::Sun.alt() {
+                1.0
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+/**
+Computes the apparent declination of the Sun
+
+# Returns
+
+* dec: Apparent declination of the Sun | in radians
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+pub fn declination(jd: f64) -> f64 {
+    let (eq_point, _) = ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jd, Accuracy::High);
+
+    eq_point.dec
+}
+
+/**
+Finds the longest and the shortest day of a year
+
+# Returns
+
+((longest_date, longest_length), (shortest_date, shortest_length))
+
+* longest_date   : Date of the longest day
+* longest_length : Its length, as by [day_length()](./fn.day_length.html)
+                   | in days
+* shortest_date  : Date of the shortest day
+* shortest_length: Its length | in days
+
+The days around the June and the December solstices are compared,
+the extremes of the day length following the extremes of the
+declination of the Sun. Where several days tie, as in the polar day
+and night, the earliest is returned.
+
+# Arguments
+
+* year    : Gregorian calendar year
+* observer: The observer
+**/
+pub fn longest_shortest_day(
+    year: i16,
+    observer: &coordinate::Observer,
+) -> ((time::Date, f64), (time::Date, f64)) {
+    let date = |month: time::Month, day: u8| time::Date {
+        year,
+        month,
+        decimal_day: day as f64,
+        cal_type: time::CalType::Gregorian,
+    };
+
+    let mut longest: Option<(time::Month, u8, f64)> = None;
+    let mut shortest: Option<(time::Month, u8, f64)> = None;
+    for (month, days) in [(time::Month::June, 17..=25), (time::Month::Dec, 18..=26)] {
+        for day in days {
+            let length = day_length(observer, &date(month, day));
+            if longest.is_none_or(|(_, _, l)| length > l) {
+                longest = Some((month, day, length));
+            }
+            if shortest.is_none_or(|(_, _, l)| length < l) {
+                shortest = Some((month, day, length));
+            }
+        }
+    }
+
+    let (longest, shortest) = (longest.unwrap(), shortest.unwrap());
+    (
+        (date(longest.0, longest.1), longest.2),
+        (date(shortest.0, shortest.1), shortest.2),
+    )
+}
+
+/**
+Computes the golden hours on a date
+
+# Returns
+
+* intervals: (start, end) of each golden hour, in increasing order
+
+The golden hour is the time when the apparent altitude of the center
+of the Sun is between −4° and 6°, usually once in the morning and
+once in the evening. The altitude is corrected for refraction with
+the pressure and temperature of the [config](../config/index.html)
+module, which vanishes more than 2° below the horizon. The date is a
+calendar date in the observer's time zone, as in
+[transit::sun_rise_set()](../transit/fn.sun_rise_set.html), and so
+are the times. An interval in progress at a midnight is clipped to
+it.
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn golden_hour(
+    observer: &coordinate::Observer,
+    date: &time::Date,
+) -> Vec<(time::ZonedInstant, time::ZonedInstant)> {
+    altitude_intervals(observer, date, -4_f64.to_radians(), 6_f64.to_radians())
+}
+
+/**
+Computes the blue hours on a date
+
+# Returns
+
+* intervals: (start, end) of each blue hour, in increasing order
+
+The blue hour is the time when the apparent altitude of the center of
+the Sun is between −6° and −4°, in civil twilight before the morning golden
+hour and after the evening one. See
+[golden_hour()](./fn.golden_hour.html) for the conventions.
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn blue_hour(
+    observer: &coordinate::Observer,
+    date: &time::Date,
+) -> Vec<(time::ZonedInstant, time::ZonedInstant)> {
+    altitude_intervals(observer, date, -6_f64.to_radians(), -4_f64.to_radians())
+}
+
+// Intervals of a local date during which the refracted altitude of
+// the Sun lies between two limits
+fn altitude_intervals(
+    observer: &coordinate::Observer,
+    date: &time::Date,
+    low: f64,
+    high: f64,
+) -> Vec<(time::ZonedInstant, time::ZonedInstant)> {
+    let local_midnight = time::local_midnight(date, observer.time_zone);
+    let config = config::get();
+
+    search::intervals(
+        local_midnight,
+        local_midnight + 1.0,
+        5.0 / 1440.0,
+        0.5 / 86400.0,
+        |jd| {
+            let (az, alt) = horizontal_pos(jd, observer, Accuracy::Low);
+            let observed =
+                coordinate::ObservedPoint::from_true_alt(az, alt, config.pressure, config.temp);
+            (low..=high).contains(&observed.refracted_alt)
+        },
+    )
+    .expect("Internal error in sun::altitude_intervals")
+    .into_iter()
+    .map(|(start, end)| {
+        (
+            time::Instant::from_ut(start).to_local(observer.time_zone),
+            time::Instant::from_ut(end).to_local(observer.time_zone),
+        )
+    })
+    .collect()
+}
+
+/// Solar constant, the mean total irradiance at 1 AU | in W/m²
+pub const SOLAR_CONSTANT: f64 = 1361.0;
+
+/// Represents a flat solar panel
+#[derive(Copy, Clone, Debug)]
+pub struct SolarPanel {
+    /// Tilt from the horizontal | in radians
+    pub tilt: f64,
+    /// Azimuth of the direction the panel faces, measured westwards
+    /// from the south | in radians
+    pub az: f64,
+}
+
+impl SolarPanel {
+    /**
+    Returns a solar panel
+
+    # Arguments
+
+    * tilt: Tilt from the horizontal | in radians
+    * az  : Azimuth of the direction the panel faces, measured
+            westwards from the south | in radians
+    **/
+    pub fn new(tilt: f64, az: f64) -> SolarPanel {
+        SolarPanel { tilt, az }
+    }
+
+    /**
+    Computes the angle of incidence of sunlight on the panel
+
+    # Returns
+
+    * incidence_angle: Angle between the Sun and the normal of the
+                       panel | in radians
+
+    # Arguments
+
+    * sun_az : Azimuth of the Sun, measured westwards from the
+               south | in radians
+    * sun_alt: Altitude of the Sun | in radians
+    **/
+    pub fn incidence_angle(&self, sun_az: f64, sun_alt: f64) -> f64 {
+        (sun_alt.sin() * self.tilt.cos()
+            + sun_alt.cos() * self.tilt.sin() * (sun_az - self.az).cos())
+        .clamp(-1.0, 1.0)
+        .acos()
+    }
+
+    /**
+    Computes the daily insolation of the panel at the top of the
+    atmosphere
+
+    # Returns
+
+    * insolation: Solar energy received per unit area of the panel
+                  over the day | in Wh/m²
+
+    The irradiance is the solar constant, scaled by the inverse square
+    of the Sun-Earth distance, when the Sun is above the horizon and
+    in front of the panel. The atmosphere isn't modeled, so this is
+    an upper bound for clear-sky models to scale. The date is a
+    calendar date in the observer's time zone, as in
+    [transit::sun_rise_set()](../transit/fn.sun_rise_set.html).
+
+    # Arguments
+
+    * observer: The observer
+    * date    : The date; its fraction of day is ignored
+    **/
+    pub fn daily_insolation(&self, observer: &coordinate::Observer, date: &time::Date) -> f64 {
+        let start = time::local_midnight(date, observer.time_zone);
+        let (_, rad_vec) = geocent_ecl_pos(start + 0.5, Accuracy::Low);
+        let irradiance = SOLAR_CONSTANT / (rad_vec * rad_vec);
+
+        // Midpoint rule over 2 minute steps
+        let num_steps = 720;
+        let step = 1.0 / num_steps as f64;
+        let energy: f64 = (0..num_steps)
+            .map(|i| {
+                let (az, alt) =
+                    horizontal_pos(start + (i as f64 + 0.5) * step, observer, Accuracy::Low);
+                if alt > 0.0 {
+                    irradiance * self.incidence_angle(az, alt).cos().max(0.0)
+                } else {
+                    0.0
+                }
+            })
+            .sum();
+
+        energy * 24.0 * step
+    }
+}
+
+#[inline(always)]
+fn cast<F: Float>(x: f64) -> F {
+    F::from(x).unwrap()
+}
@@ -0,0 +1,461 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------MEEUS WORKED EXAMPLES-----------!
+
+use crate::coordinate::{EclPoint, EqPoint, GeographPoint};
+use crate::time::{CalType, Date, Month};
+
+// Converts sexagesimal degrees to radians
+const fn dms(deg: i64, min: i64, sec: f64) -> f64 {
+    (deg as f64 + min as f64 / 60.0 + sec / 3600.0).to_radians()
+}
+
+// Converts sexagesimal hours to radians
+const fn hms(hour: i64, min: i64, sec: f64) -> f64 {
+    dms(hour * 15, min * 15, sec * 15.0)
+}
+
+const fn date(year: i16, month: Month, decimal_day: f64, cal_type: CalType) -> Date {
+    Date {
+        year,
+        month,
+        decimal_day,
+        cal_type,
+    }
+}
+
+/// Represents a worked example of the Julian day of a calendar date
+#[derive(Debug)]
+pub struct JulianDayExample {
+    /// Example or chapter of the book
+    pub example: &'static str,
+    /// Calendar date
+    pub date: Date,
+    /// Julian day
+    pub jd: f64,
+}
+
+/// Represents a worked example of the Greenwich sidereal time
+#[derive(Debug)]
+pub struct SiderealExample {
+    /// Example of the book
+    pub example: &'static str,
+    /// Julian day | in UT
+    pub jd_ut: f64,
+    /// Mean sidereal time | in radians
+    pub mean_sidereal: f64,
+    /// Apparent sidereal time, if the example gives it | in radians
+    pub apparent_sidereal: Option<f64>,
+}
+
+/// Represents a worked example of the distance between two places
+#[derive(Debug)]
+pub struct GeodesicExample {
+    /// First place
+    pub p1: GeographPoint,
+    /// Second place
+    pub p2: GeographPoint,
+    /// Distance on the Earth's ellipsoid | in kilometers
+    pub dist: f64,
+    /// Distance on a sphere of radius 6371 km | in kilometers
+    pub approx_dist: f64,
+}
+
+/// Represents a worked example of equatorial to ecliptic coordinates
+#[derive(Debug)]
+pub struct EclipticExample {
+    /// Equatorial point | in radians
+    pub eq_point: EqPoint,
+    /// Obliquity of the ecliptic | in radians
+    pub oblq_eclip: f64,
+    /// Ecliptic point | in radians
+    pub ecl_point: EclPoint,
+}
+
+/// Represents a worked example of equatorial to horizontal coordinates
+#[derive(Debug)]
+pub struct HorizontalExample {
+    /// Local hour angle | in radians
+    pub hour_angle: f64,
+    /// Declination | in radians
+    pub dec: f64,
+    /// Observer's geographical latitude | in radians
+    pub observer_lat: f64,
+    /// Azimuth, measured westward from the south | in radians
+    pub az: f64,
+    /// Altitude | in radians
+    pub alt: f64,
+}
+
+/// Represents a worked example of the angular separation of two points
+#[derive(Debug)]
+pub struct AngularSepExample {
+    /// First point | in radians
+    pub p1: EqPoint,
+    /// Second point | in radians
+    pub p2: EqPoint,
+    /// Angular separation | in radians
+    pub angular_sep: f64,
+}
+
+/// Represents a worked example of the precession of a star
+#[derive(Debug)]
+pub struct PrecessionExample {
+    /// Mean place at the initial equinox | in radians
+    pub eq_point_from: EqPoint,
+    /// Initial equinox | as a Julian (Ephemeris) day
+    pub jd_from: f64,
+    /// Final equinox | as a Julian (Ephemeris) day
+    pub jd_to: f64,
+    /// Mean place at the final equinox | in radians
+    pub eq_point_to: EqPoint,
+}
+
+/// Represents a worked example of the nutation and the obliquity
+#[derive(Debug)]
+pub struct NutationExample {
+    /// Julian (Ephemeris) day
+    pub jd: f64,
+    /// Nutation in longitude | in radians
+    pub nut_in_long: f64,
+    /// Nutation in obliquity | in radians
+    pub nut_in_oblq: f64,
+    /// Mean obliquity of the ecliptic | in radians
+    pub mean_oblq: f64,
+    /// True obliquity of the ecliptic | in radians
+    pub true_oblq: f64,
+}
+
+/// Represents a worked example of the apparent position of the Sun
+#[derive(Debug)]
+pub struct SunExample {
+    /// Julian (Ephemeris) day
+    pub jd: f64,
+    /// Apparent equatorial point | in radians
+    pub eq_point: EqPoint,
+    /// Earth-Sun distance | in AU
+    pub rad_vec: f64,
+}
+
+/// Represents a worked example of the equation of time
+#[derive(Debug)]
+pub struct EquationOfTimeExample {
+    /// Julian (Ephemeris) day
+    pub jd: f64,
+    /// Apparent right ascension of the Sun | in radians
+    pub sun_asc: f64,
+    /// Nutation in longitude | in radians
+    pub nut_in_long: f64,
+    /// True obliquity of the ecliptic | in radians
+    pub true_oblq: f64,
+    /// Equation of time | in radians
+    pub eq_of_time: f64,
+}
+
+/// Represents a worked example of the phase of a planet
+#[derive(Debug)]
+pub struct PlanetPhaseExample {
+    /// Sun-planet distance | in AU
+    pub body_sun_dist: f64,
+    /// Earth-planet distance | in AU
+    pub body_earth_dist: f64,
+    /// Earth-Sun distance | in AU
+    pub earth_sun_dist: f64,
+    /// Illuminated fraction of the disk
+    pub illuminated_fraction: f64,
+}
+
+/// Represents a worked example of the position of the Moon
+#[derive(Debug)]
+pub struct MoonExample {
+    /// Julian (Ephemeris) day
+    pub jd: f64,
+    /// Geocentric ecliptic point, referred to the mean equinox of the
+    /// date | in radians
+    pub ecl_point: EclPoint,
+    /// Earth-Moon distance | in kilometers
+    pub earth_moon_dist: f64,
+    /// Apparent equatorial point | in radians
+    pub eq_point: EqPoint,
+}
+
+/// Represents a worked example of the illuminated fraction of the Moon
+#[derive(Debug)]
+pub struct MoonIlluminationExample {
+    /// Julian (Ephemeris) day
+    pub jd: f64,
+    /// Phase angle | in radians
+    pub phase_angle: f64,
+    /// Illuminated fraction of the disk
+    pub illuminated_fraction: f64,
+}
+
+/// Julian days of calendar dates, Meeus examples 7.a and 7.b and the
+/// table of chapter 7
+///
+/// Dates before 1582 October 15 are in the Julian calendar.
+pub const JULIAN_DAYS: [JulianDayExample; 16] = [
+    JulianDayExample {
+        example: "7.a",
+        date: date(1957, Month::Oct, 4.81, CalType::Gregorian),
+        jd: 2436116.31,
+    },
+    JulianDayExample {
+        example: "7.b",
+        date: date(333, Month::Jan, 27.5, CalType::Julian),
+        jd: 1842713.0,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(2000, Month::Jan, 1.5, CalType::Gregorian),
+        jd: 2451545.0,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(1999, Month::Jan, 1.0, CalType::Gregorian),
+        jd: 2451179.5,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(1987, Month::Jan, 27.0, CalType::Gregorian),
+        jd: 2446822.5,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(1987, Month::June, 19.5, CalType::Gregorian),
+        jd: 2446966.0,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(1988, Month::Jan, 27.0, CalType::Gregorian),
+        jd: 2447187.5,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(1988, Month::June, 19.5, CalType::Gregorian),
+        jd: 2447332.0,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(1900, Month::Jan, 1.0, CalType::Gregorian),
+        jd: 2415020.5,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(1600, Month::Jan, 1.0, CalType::Gregorian),
+        jd: 2305447.5,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(1600, Month::Dec, 31.0, CalType::Gregorian),
+        jd: 2305812.5,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(837, Month::Apr, 10.3, CalType::Julian),
+        jd: 2026871.8,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(-123, Month::Dec, 31.0, CalType::Julian),
+        jd: 1676496.5,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(-1000, Month::July, 12.5, CalType::Julian),
+        jd: 1356001.0,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(-1001, Month::Aug, 17.9, CalType::Julian),
+        jd: 1355671.4,
+    },
+    JulianDayExample {
+        example: "chapter 7",
+        date: date(-4712, Month::Jan, 1.5, CalType::Julian),
+        jd: 0.0,
+    },
+];
+
+/// Sidereal times at Greenwich, Meeus examples 12.a and 12.b
+pub const SIDEREAL_TIMES: [SiderealExample; 2] = [
+    SiderealExample {
+        example: "12.a",
+        jd_ut: 2446895.5,
+        mean_sidereal: hms(13, 10, 46.3668),
+        apparent_sidereal: Some(hms(13, 10, 46.1351)),
+    },
+    SiderealExample {
+        example: "12.b",
+        jd_ut: 2446896.30625,
+        mean_sidereal: 128.7378734_f64.to_radians(),
+        apparent_sidereal: None,
+    },
+];
+
+/// Paris and Washington, Meeus example 11.c
+pub const EXAMPLE_11C: GeodesicExample = GeodesicExample {
+    p1: GeographPoint {
+        long: -dms(2, 20, 14.0),
+        lat: dms(48, 50, 11.0),
+    },
+    p2: GeographPoint {
+        long: dms(77, 3, 56.0),
+        lat: dms(38, 55, 17.0),
+    },
+    dist: 6181.63,
+    approx_dist: 6166.0,
+};
+
+/// Pollux, Meeus example 13.a
+pub const EXAMPLE_13A: EclipticExample = EclipticExample {
+    eq_point: EqPoint {
+        asc: 116.328942_f64.to_radians(),
+        dec: 28.026183_f64.to_radians(),
+        equinox: Some(2451545.0),
+    },
+    oblq_eclip: 23.4392911_f64.to_radians(),
+    ecl_point: EclPoint {
+        long: 113.215630_f64.to_radians(),
+        lat: 6.684170_f64.to_radians(),
+    },
+};
+
+/// Venus from the U.S. Naval Observatory on 1987 April 10, 19h 21m UT,
+/// Meeus example 13.b
+pub const EXAMPLE_13B: HorizontalExample = HorizontalExample {
+    hour_angle: 64.352133_f64.to_radians(),
+    dec: -6.719892_f64.to_radians(),
+    observer_lat: dms(38, 55, 17.0),
+    az: 68.0337_f64.to_radians(),
+    alt: 15.1249_f64.to_radians(),
+};
+
+/// Arcturus and Spica, Meeus example 17.a
+pub const EXAMPLE_17A: AngularSepExample = AngularSepExample {
+    p1: EqPoint {
+        asc: 213.9154_f64.to_radians(),
+        dec: 19.1825_f64.to_radians(),
+        equinox: None,
+    },
+    p2: EqPoint {
+        asc: 201.2983_f64.to_radians(),
+        dec: -11.1614_f64.to_radians(),
+        equinox: None,
+    },
+    angular_sep: 32.7930_f64.to_radians(),
+};
+
+/// θ Persei from J2000.0 to 2028 November 13.19 TD, Meeus example 21.b
+///
+/// The proper motion of the star up to the final date is already
+/// applied to the initial place, as in the book.
+pub const EXAMPLE_21B: PrecessionExample = PrecessionExample {
+    eq_point_from: EqPoint {
+        asc: 41.054063_f64.to_radians(),
+        dec: 49.227750_f64.to_radians(),
+        equinox: Some(2451545.0),
+    },
+    jd_from: 2451545.0,
+    jd_to: 2462088.69,
+    eq_point_to: EqPoint {
+        asc: 41.547214_f64.to_radians(),
+        dec: 49.348483_f64.to_radians(),
+        equinox: Some(2462088.69),
+    },
+};
+
+/// 1987 April 10.0 TD, Meeus example 22.a
+pub const EXAMPLE_22A: NutationExample = NutationExample {
+    jd: 2446895.5,
+    nut_in_long: -dms(0, 0, 3.788),
+    nut_in_oblq: dms(0, 0, 9.443),
+    mean_oblq: dms(23, 26, 27.407),
+    true_oblq: dms(23, 26, 36.850),
+};
+
+/// The Sun on 1992 October 13.0 TD with the low accuracy method,
+/// Meeus example 25.a
+pub const EXAMPLE_25A: SunExample = SunExample {
+    jd: 2448908.5,
+    eq_point: EqPoint {
+        asc: 198.38083_f64.to_radians(),
+        dec: -7.78507_f64.to_radians(),
+        equinox: Some(2448908.5),
+    },
+    rad_vec: 0.99766,
+};
+
+/// The Sun on 1992 October 13.0 TD with VSOP87, Meeus example 25.b
+pub const EXAMPLE_25B: SunExample = SunExample {
+    jd: 2448908.5,
+    eq_point: EqPoint {
+        asc: 198.378178_f64.to_radians(),
+        dec: -7.783871_f64.to_radians(),
+        equinox: Some(2448908.5),
+    },
+    rad_vec: 0.99760775,
+};
+
+/// 1992 October 13.0 TD, Meeus example 28.a
+pub const EXAMPLE_28A: EquationOfTimeExample = EquationOfTimeExample {
+    jd: 2448908.5,
+    sun_asc: 198.378178_f64.to_radians(),
+    nut_in_long: dms(0, 0, 15.908),
+    true_oblq: 23.44023_f64.to_radians(),
+    eq_of_time: 3.427351_f64.to_radians(),
+};
+
+/// Venus on 1992 December 20.0 TD, Meeus example 41.a
+pub const EXAMPLE_41A: PlanetPhaseExample = PlanetPhaseExample {
+    body_sun_dist: 0.724604,
+    body_earth_dist: 0.910947,
+    earth_sun_dist: 0.983824,
+    illuminated_fraction: 0.647,
+};
+
+/// The Moon on 1992 April 12.0 TD, Meeus example 47.a
+pub const EXAMPLE_47A: MoonExample = MoonExample {
+    jd: 2448724.5,
+    ecl_point: EclPoint {
+        long: 133.162655_f64.to_radians(),
+        lat: -3.229126_f64.to_radians(),
+    },
+    earth_moon_dist: 368409.7,
+    eq_point: EqPoint {
+        asc: 134.688470_f64.to_radians(),
+        dec: 13.768368_f64.to_radians(),
+        equinox: Some(2448724.5),
+    },
+};
+
+/// The Moon on 1992 April 12.0 TD, Meeus example 48.a
+pub const EXAMPLE_48A: MoonIlluminationExample = MoonIlluminationExample {
+    jd: 2448724.5,
+    phase_angle: 69.0756_f64.to_radians(),
+    illuminated_fraction: 0.6786,
+};
+
+/// New Moon of 1977 February 18, 3h 37m 42s TD, Meeus example 49.a |
+/// as a Julian (Ephemeris) day
+pub const EXAMPLE_49A: f64 = 2443192.65118;
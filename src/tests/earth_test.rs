@@ -8,23 +8,23 @@ mod tests {
 
     #[test]
     fn test_flattening_factor() {
-        assert!((flattening_factor() - 1.0 / 298.257223563).abs() < 1e-10);
+        assert!((FLATTENING_FACTOR - 1.0 / 298.257223563).abs() < 1e-10);
     }
 
     #[test]
     fn test_equatorial_radius() {
-        assert!((equatorial_radius() - 6378.137).abs() < 1e-3);
+        assert!((EQUATORIAL_RADIUS - 6378.137).abs() < 1e-3);
     }
 
     #[test]
     fn test_polar_radius() {
-        let expected = equatorial_radius() * (1.0 - flattening_factor());
-        assert!((polar_radius() - expected).abs() < 1e-3);
+        let expected = EQUATORIAL_RADIUS * (1.0 - FLATTENING_FACTOR);
+        assert!((POLAR_RADIUS - expected).abs() < 1e-3);
     }
 
     #[test]
     fn test_eccentricity_of_meridian() {
-        let f = flattening_factor();
+        let f = FLATTENING_FACTOR;
         let expected = (f * (2.0 - f).sqrt());
         assert!((eccentricity_of_meridian() - expected).abs() < 1e-10);
     }
@@ -64,13 +64,13 @@ mod tests {
 
     #[test]
     fn test_rotational_angular_velocity() {
-        assert!((rotational_angular_velocity() - 0.00007292114992).abs() < 1e-14);
+        assert!((ROTATIONAL_ANGULAR_VELOCITY - 0.00007292114992).abs() < 1e-14);
     }
 
     #[test]
     fn test_radius_of_parallel() {
         let lat = 0.1; // Example latitude
-        let expected = equatorial_radius() * lat.cos()
+        let expected = EQUATORIAL_RADIUS * lat.cos()
             / (1.0 - (eccentricity_of_meridian() * lat.sin()).powi(2)).sqrt();
         assert!((radius_of_parallel(lat) - expected).abs() < 1e-2);
     }
@@ -78,7 +78,7 @@ mod tests {
     #[test]
     fn test_linear_velocity_at_lat() {
         let lat = 0.1; // Example latitude
-        let expected = rotational_angular_velocity() * radius_of_parallel(lat);
+        let expected = ROTATIONAL_ANGULAR_VELOCITY * radius_of_parallel(lat);
         assert!((linear_velocity_at_lat(lat) - expected).abs() < 1e-2);
     }
 
@@ -87,7 +87,7 @@ mod tests {
         let lat = 0.1; // Example latitude
         let e = eccentricity_of_meridian();
         let expected =
-            equatorial_radius() * (1.0 - e * e) / (1.0 - (e * lat.sin()).powi(2)).powf(1.5);
+            EQUATORIAL_RADIUS * (1.0 - e * e) / (1.0 - (e * lat.sin()).powi(2)).powf(1.5);
         assert!((radius_of_curvature(lat) - expected).abs() < 1e-2);
     }
 
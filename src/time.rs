@@ -23,9 +23,17 @@ THE SOFTWARE.
 // !-----Time For Astonomy------!
 
 use crate::angle;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::ephemeris;
+use crate::math::polynomial;
+use crate::nutation;
+use crate::planet::earth;
+use crate::Accuracy;
+use crate::Measurement;
 
 /// Represents a calendar type
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CalType {
     /// Gregorian calendar
     Gregorian,
@@ -34,7 +42,7 @@ pub enum CalType {
 }
 
 /// Represents a month in the Gregorian and Julian calendars
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Month {
     /// January
     Jan = 1,
@@ -62,6 +70,69 @@ pub enum Month {
     Dec = 12,
 }
 
+impl Month {
+    /// The months of the year, from January to December
+    pub const ALL: [Month; 12] = [
+        Month::Jan,
+        Month::Feb,
+        Month::Mar,
+        Month::Apr,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::Aug,
+        Month::Sept,
+        Month::Oct,
+        Month::Nov,
+        Month::Dec,
+    ];
+
+    /// Returns an iterator over the months of the year, from January
+    /// to December
+    pub fn iter() -> std::array::IntoIter<Month, 12> {
+        Month::ALL.into_iter()
+    }
+
+    /// Returns the following month, January after December
+    pub fn next(self) -> Month {
+        Month::ALL[self as usize % 12]
+    }
+
+    /// Returns the preceding month, December before January
+    pub fn prev(self) -> Month {
+        Month::ALL[(self as usize + 10) % 12]
+    }
+
+    /**
+    Returns the number of days in the month
+
+    # Arguments
+
+    * year    : Year
+    * cal_type: CalType enum
+    **/
+    pub fn days(self, year: i16, cal_type: &CalType) -> u8 {
+        match self {
+            Month::Feb if is_leap_year(year, cal_type) => 29,
+            Month::Feb => 28,
+            Month::Apr | Month::June | Month::Sept | Month::Nov => 30,
+            _ => 31,
+        }
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = &'static str;
+
+    /// Converts a month number, 1 - 12, to a month
+    fn try_from(month: u8) -> Result<Month, Self::Error> {
+        match month {
+            1..=12 => Ok(Month::ALL[month as usize - 1]),
+            _ => Err("The month number isn't in the range 1 - 12"),
+        }
+    }
+}
+
 /// Represents a date with year, month, decimal day and calendar type
 #[derive(Debug)]
 pub struct Date {
@@ -78,7 +149,7 @@ pub struct Date {
 }
 
 /// Represents a day of a month with hours, minutes and seconds
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DayOfMonth {
     /// Day of month
     ///
@@ -102,6 +173,82 @@ pub struct DayOfMonth {
     pub time_zone: f64,
 }
 
+/// Represents a civil date and time, in a time zone
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DateTime {
+    /// Year
+    pub year: i16,
+    /// Month
+    pub month: Month,
+    /// Day of month, time of day and time zone
+    pub day_of_month: DayOfMonth,
+    /// Calendar type
+    pub cal_type: CalType,
+}
+
+impl DateTime {
+    /// Returns the date, with the time of day and time zone folded
+    /// into a decimal day in UT
+    pub fn to_date(&self) -> Date {
+        Date {
+            year: self.year,
+            month: self.month,
+            decimal_day: decimal_day(&self.day_of_month),
+            cal_type: self.cal_type,
+        }
+    }
+
+    /// Returns the Julian day, UT
+    pub fn to_julian_day(&self) -> f64 {
+        julian_day(&self.to_date())
+    }
+
+    /**
+    Computes the civil date and time of a Julian day in a time zone
+
+    # Returns
+
+    * date_time: Civil date and time, with the seconds rounded to the
+                 millisecond
+
+    Dates before 1582 October 15 are in the Julian calendar, and later
+    ones in the Gregorian calendar.
+
+    # Arguments
+
+    * jd       : Julian day, UT. *Can't be a negative value.*
+    * time_zone: Time zone | in decimal hours
+    **/
+    pub fn from_julian_day(jd: f64, time_zone: f64) -> Result<DateTime, &'static str> {
+        let local_jd = jd + time_zone / 24.0;
+
+        // Rounds to the millisecond before splitting, so that 59.9999 s
+        // doesn't carry into a 60th second
+        let millis = (local_jd + 0.5).fract() * 86400000.0;
+        let local_jd = local_jd - millis / 86400000.0 + millis.round() / 86400000.0;
+
+        let (year, month, decimal_day) = date_from_julian_day(local_jd)?;
+        let millis = (decimal_day.fract() * 86400000.0).round() as u64;
+
+        Ok(DateTime {
+            year,
+            month: Month::try_from(month)?,
+            day_of_month: DayOfMonth {
+                day: decimal_day as u8,
+                hr: (millis / 3600000) as u8,
+                min: (millis / 60000 % 60) as u8,
+                sec: (millis % 60000) as f64 / 1000.0,
+                time_zone,
+            },
+            cal_type: if local_jd < 2299160.5 {
+                CalType::Julian
+            } else {
+                CalType::Gregorian
+            },
+        })
+    }
+}
+
 /// Represents a day of the week
 #[derive(Debug)]
 pub enum Weekday {
@@ -125,7 +272,7 @@ pub fn weekday_from_date(date: &Date) -> Weekday {
     let date_0UT = Date {
         year: date.year,
         month: date.month,
-        decimal_day: date.decimal_day.floor() as f64,
+        decimal_day: date.decimal_day.floor(),
         cal_type: CalType::Gregorian,
     };
     let jd = julian_day(&date_0UT);
@@ -143,6 +290,48 @@ pub fn weekday_from_date(date: &Date) -> Weekday {
     }
 }
 
+/**
+Computes the ISO 8601 week of a date
+
+# Returns
+
+(iso_year, week)
+
+* iso_year: ISO week-numbering year, which differs from the calendar
+            year for a few days around January 1
+* week    : Week of the year, 1 - 53
+
+Weeks begin on Monday, and week 1 of a year is the week holding its
+first Thursday. Weeks are counted in the proleptic Gregorian
+calendar, whatever the calendar of the date.
+
+# Arguments
+
+* date: A Date struct
+**/
+pub fn iso_week(date: &Date) -> (i16, u8) {
+    let jdn = julian_day_number(date);
+
+    // The Julian day number 0 was a Monday; weeks are numbered after
+    // their Thursday
+    let thursday = jdn - jdn.rem_euclid(7) + 3;
+
+    let jan_1 = |year: i16| {
+        julian_day_number(&Date {
+            year,
+            month: Month::Jan,
+            decimal_day: 1.0,
+            cal_type: CalType::Gregorian,
+        })
+    };
+    let mut iso_year = date.year + 1;
+    while jan_1(iso_year) > thursday {
+        iso_year -= 1;
+    }
+
+    (iso_year, ((thursday - jan_1(iso_year)) / 7 + 1) as u8)
+}
+
 /**
 Computes decimal day for a DayOfMonth
 
@@ -228,6 +417,9 @@ pub fn is_leap_year(year: i16, cal_type: &CalType) -> bool {
     }
 }
 
+/// Julian (Ephemeris) day of the J2000.0 epoch
+pub const J2000: f64 = 2451545.0;
+
 /**
 Computes Julian century for a Julian day
 
@@ -237,7 +429,7 @@ Computes Julian century for a Julian day
 **/
 #[inline]
 pub fn julian_century(jd: f64) -> f64 {
-    (jd - 2451545.0) / 36525.0
+    (jd - J2000) / 36525.0
 }
 
 /**
@@ -249,7 +441,7 @@ Computes Julian millennium for a Julian day
 **/
 #[inline]
 pub fn julian_millennium(jd: f64) -> f64 {
-    (jd - 2451545.0) / 365250.0
+    (jd - J2000) / 365250.0
 }
 
 /**
@@ -276,6 +468,40 @@ pub fn julian_day(date: &Date) -> f64 {
     (365.25 * (y + 4716.0)).floor() + (30.6001 * (m + 1.0)).floor() + date.decimal_day + b - 1524.5
 }
 
+/**
+Computes the Julian day of the midnight starting a date in a time zone
+
+# Returns
+
+* jd: Julian day of 0h local time on the date, UT
+
+# Arguments
+
+* date     : The date; its fraction of day is ignored
+* time_zone: Time zone | in decimal hours
+**/
+pub fn local_midnight(date: &Date, time_zone: f64) -> f64 {
+    julian_day(date) - date.decimal_day.fract() - time_zone / 24.0
+}
+
+/**
+Computes the Julian day number of a date
+
+# Returns
+
+* jdn: Julian day number, the integer Julian day at noon of the date
+
+The fraction of the day is ignored, so the number is the same for
+every time of the day.
+
+# Arguments
+
+* date: A Date struct
+**/
+pub fn julian_day_number(date: &Date) -> i64 {
+    (julian_day(date) + 0.5).floor() as i64
+}
+
 /**
 Computes the Julian Ephemeris day
 
@@ -366,6 +592,28 @@ pub fn apparent_sidereal(mean_sidereal: f64, nut_in_long: f64, true_obliquity: f
 /**
 Computes apparent sidereal time for a Julian day
 
+# Returns
+
+* apparent_sidereal: Apparent sidereal time | in radians
+
+The nutation is computed with `Accuracy::High`, and the true
+obliquity of the ecliptic from the mean obliquity of `oblq_model`.
+
+# Arguments
+
+* jd        : Julian day
+* oblq_model: Formula for the mean obliquity of the ecliptic
+**/
+pub fn apparent_sidereal_jd(jd: f64, oblq_model: ecliptic::ObliquityModel) -> f64 {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(jd, Accuracy::High);
+    let true_oblq = oblq_model.mean_obliquity(jd) + nut_in_oblq;
+
+    apparent_sidereal(mean_sidereal(jd), nut_in_long, true_oblq)
+}
+
+/**
+Computes apparent sidereal time for a Julian day
+
 This functions uses internally J. Laskar's formula for
 computing the obliquity of the ecliptic.
 
@@ -377,19 +625,167 @@ computing the obliquity of the ecliptic.
 
 * $jd: Julian day
 **/
+#[deprecated(note = "use time::apparent_sidereal_jd() with ObliquityModel::Laskar")]
 #[macro_export]
 macro_rules! apparent_sidereal {
     ($jd: expr) => {{
-        let (nut_in_long, nut_in_obliquity) = apollo::nutation::nutation($jd);
-        let ecliptic_obliquity = apollo::ecliptic::mean_obliquity_laskar($jd);
-        apollo::time::apparent_sidereal(
-            apollo::time::mean_sidereal($jd),
-            nut_in_long,
-            ecliptic_obliquity + nut_in_obliquity,
-        )
+        $crate::time::apparent_sidereal_jd($jd, $crate::ecliptic::ObliquityModel::Laskar)
     }};
 }
 
+// Apparent sidereal time for a Julian day, with J. Laskar's formula
+// for the mean obliquity | in radians
+pub(crate) fn apparent_sidereal_from_jd(jd: f64) -> f64 {
+    apparent_sidereal_jd(jd, ecliptic::ObliquityModel::Laskar)
+}
+
+/// Ratio of a mean solar interval to the sidereal interval it spans
+pub const SIDEREAL_SOLAR_RATIO: f64 = 1.00273790935;
+
+/**
+Converts a mean solar time interval to sidereal time
+
+# Returns
+
+* sidereal_interval: Sidereal time interval | in hours
+
+# Arguments
+
+* solar_interval: Mean solar time interval | in hours
+**/
+pub fn sidereal_from_solar_interval(solar_interval: f64) -> f64 {
+    solar_interval * SIDEREAL_SOLAR_RATIO
+}
+
+/**
+Converts a sidereal time interval to mean solar time
+
+# Returns
+
+* solar_interval: Mean solar time interval | in hours
+
+# Arguments
+
+* sidereal_interval: Sidereal time interval | in hours
+**/
+pub fn solar_from_sidereal_interval(sidereal_interval: f64) -> f64 {
+    sidereal_interval / SIDEREAL_SOLAR_RATIO
+}
+
+/**
+Converts local mean time to local apparent solar time
+
+# Returns
+
+* apparent_solar: Local apparent solar time | in hours
+
+The equation of time is evaluated at `jd` with
+[earth::equation_of_time()](../planet/earth/fn.equation_of_time.html),
+and the result is kept within 0 - 24 hours.
+
+# Arguments
+
+* local_mean: Local mean time | in hours
+* jd        : Julian day of the instant, UT
+**/
+pub fn apparent_solar_from_local_mean(local_mean: f64, jd: f64) -> f64 {
+    angle::limit_24(local_mean + eq_of_time_from_jd(jd).to_degrees() / 15.0)
+}
+
+/**
+Computes local apparent solar time, the time shown by a sundial
+
+# Returns
+
+* apparent_solar: Local apparent solar time | in hours
+
+# Arguments
+
+* jd           : Julian day of the instant, UT
+* observer_long: Observer's geographical longitude, positive
+                 westward | in radians
+**/
+pub fn apparent_solar_time(jd: f64, observer_long: f64) -> f64 {
+    let local_mean = angle::limit_24((jd + 0.5).fract() * 24.0 - observer_long.to_degrees() / 15.0);
+
+    apparent_solar_from_local_mean(local_mean, jd)
+}
+
+/**
+Computes the time of local apparent noon on a date
+
+# Returns
+
+* local_apparent_noon: Time at which local apparent solar time is
+                       12h, the Sun's transit across the meridian
+
+The date is a calendar date in the observer's time zone. Local mean
+noon is corrected by the equation of time at the corrected instant;
+the result agrees with
+[sun::solar_noon()](../sun/fn.solar_noon.html) to about a second.
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn local_apparent_noon(observer: &coordinate::Observer, date: &Date) -> Instant {
+    // Local mean noon falls at 12h + longitude / 15° UT of the date,
+    // whatever the time zone
+    let mean_noon = local_midnight(date, 0.0) + 0.5 + observer.long / angle::TWO_PI;
+
+    let mut jd = mean_noon;
+    for _ in 0..2 {
+        jd = mean_noon - eq_of_time_from_jd(jd) / angle::TWO_PI;
+    }
+
+    Instant::from_ut(jd)
+}
+
+// Equation of time at a Julian day in UT, in the range (-π, π]
+fn eq_of_time_from_jd(jd: f64) -> f64 {
+    let jde = tt_from_ut(jd);
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(jde, Accuracy::High);
+    let true_oblq = ecliptic::mean_obliquity_laskar(jde) + nut_in_oblq;
+    let (sun_eq_point, _) = ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jde, Accuracy::High);
+
+    angle::limit_pi(earth::equation_of_time(
+        jde,
+        sun_eq_point.asc,
+        nut_in_long,
+        true_oblq,
+    ))
+}
+
+/**
+Computes local apparent sidereal time for a date and time of day
+
+# Returns
+
+* local_sidereal: Local apparent sidereal time, 0 - 2π | in radians
+
+The year, month and calendar type are taken from `date`, and the
+day, time of day and time zone from `day_of_month`, which converts
+the local time to UT before the Julian day is computed.
+
+# Arguments
+
+* date          : The date; its decimal day is ignored
+* day_of_month  : The day, time of day and time zone
+* observer_long : Observer's geographical longitude, positive
+                  westward | in radians
+**/
+pub fn local_sidereal_from_date(date: &Date, day_of_month: &DayOfMonth, observer_long: f64) -> f64 {
+    let jd = julian_day(&Date {
+        year: date.year,
+        month: date.month,
+        decimal_day: decimal_day(day_of_month),
+        cal_type: date.cal_type,
+    });
+
+    angle::limit_twoPI(apparent_sidereal_from_jd(jd) - observer_long)
+}
+
 /**
 Computes mean sidereal time for a Julian day
 
@@ -405,13 +801,59 @@ pub fn mean_sidereal(jd: f64) -> f64 {
     let jc = julian_century(jd);
 
     angle::limit_360(
-        280.46061837
-            + 360.98564736629 * (jd - 2451545.0)
-            + jc * jc * (0.000387933 - jc / 38710000.0),
+        280.46061837 + 360.98564736629 * (jd - J2000) + jc * jc * (0.000387933 - jc / 38710000.0),
     )
     .to_radians()
 }
 
+/**
+Computes the rate of change of the mean sidereal time
+
+# Returns
+
+* mean_sidereal_rate: Rate of change of the mean sidereal time | in
+                      radians per day
+
+The rate is the derivative of
+[mean_sidereal()](./fn.mean_sidereal.html), about 360.9856° per day
+of UT.
+
+# Arguments
+
+* jd: Julian day
+**/
+pub fn mean_sidereal_rate(jd: f64) -> f64 {
+    let jc = julian_century(jd);
+
+    (360.98564736629 + jc * (2.0 * 0.000387933 - 3.0 * jc / 38710000.0) / 36525.0).to_radians()
+}
+
+/**
+Computes the rate of change of the apparent sidereal time
+
+# Returns
+
+* apparent_sidereal_rate: Rate of change of the apparent sidereal
+                          time | in radians per day
+
+The rate of the mean sidereal time is corrected by the rate of change
+of the equation of the equinoxes, Δψ cos ε, found from
+[nutation::nutation_rate()](../nutation/fn.nutation_rate.html) and
+[ecliptic::mean_obliquity_rate()](../ecliptic/fn.mean_obliquity_rate.html).
+
+# Arguments
+
+* jd: Julian day
+**/
+pub fn apparent_sidereal_rate(jd: f64) -> f64 {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(jd, Accuracy::High);
+    let (nut_in_long_rate, nut_in_oblq_rate) = nutation::nutation_rate(jd, Accuracy::High);
+    let (sin_oblq, cos_oblq) = (ecliptic::mean_obliquity_laskar(jd) + nut_in_oblq).sin_cos();
+    let oblq_rate = ecliptic::mean_obliquity_rate(jd) + nut_in_oblq_rate;
+
+    mean_sidereal_rate(jd) + nut_in_long_rate * cos_oblq - nut_in_long * sin_oblq * oblq_rate
+}
+
 /**
 Computes an approximate value of ΔT for a given year and month
 
@@ -427,67 +869,466 @@ it covers a far wider time range, and is more accurate.
 **/
 pub fn delta_t(year: i32, month: u8) -> f64 {
     let y = (year as f64) + ((month as f64) - 0.5) / 12.0;
+    let long_term = |y: f64| polynomial::horner(&[-20.0, 0.0, 32.0], (y - 1820.0) / 100.0);
 
     if y < -500.0 {
-        let u = (y - 1820.0) / 100.0;
-        return 32.0 * u * u - 20.0;
+        long_term(y)
     } else if y < 500.0 {
-        let u = y / 100.0;
-        return 10583.6
-            - u * (1014.41
-                + u * (33.78311
-                    + u * (5.952053 - u * (0.1798452 - u * (0.022174192 - u * 0.0090316521)))));
+        polynomial::horner(
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+            y / 100.0,
+        )
     } else if y < 1600.0 {
-        let u = (y - 1000.0) / 100.0;
-        return 1574.2
-            - u * (556.01
-                - u * (71.23472
-                    + u * (0.319781 - u * (0.8503463 + u * (0.005050998 - u * 0.0083572073)))));
+        polynomial::horner(
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+            (y - 1000.0) / 100.0,
+        )
     } else if y < 1700.0 {
-        let u = y - 1600.0;
-        return 120.0 - u * (0.9808 + u * (0.01532 - u / 7129.0));
+        polynomial::horner(&[120.0, -0.9808, -0.01532, 1.0 / 7129.0], y - 1600.0)
     } else if y < 1800.0 {
-        let u = y - 1700.0;
-        return 8.83 + u * (0.1603 - u * (0.0059285 - u * (0.00013336 + u / 1174000.0)));
+        polynomial::horner(
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1174000.0],
+            y - 1700.0,
+        )
     } else if y < 1860.0 {
-        let u = y - 1800.0;
-        return 13.72
-            - u * (0.332447
-                - u * (0.0068612
-                    + u * (0.0041116
-                        - u * (0.00037436
-                            - u * (0.0000121272 + u * (0.0000001699 - u * 0.000000000875))))));
+        polynomial::horner(
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+            y - 1800.0,
+        )
     } else if y < 1900.0 {
-        let u = y - 1860.0;
-        return 7.62
-            + u * (0.5737 - u * (0.251754 - u * (0.01680668 - u * (0.0004473624 + u / 233174.0))));
+        polynomial::horner(
+            &[
+                7.62,
+                0.5737,
+                -0.251754,
+                0.01680668,
+                -0.0004473624,
+                1.0 / 233174.0,
+            ],
+            y - 1860.0,
+        )
     } else if y < 1920.0 {
-        let u = y - 1900.0;
-        return -2.79 + u * (1.494119 - u * (0.0598939 - u * (0.0061966 + u * 0.000197)));
+        polynomial::horner(
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+            y - 1900.0,
+        )
     } else if y < 1941.0 {
-        let u = y - 1920.0;
-        return 21.20 + u * (0.84493 - u * (0.076100 - u * 0.0020936));
+        polynomial::horner(&[21.20, 0.84493, -0.076100, 0.0020936], y - 1920.0)
     } else if y < 1961.0 {
-        let u = y - 1950.0;
-        return 29.07 + u * (0.407 - u * ((1.0 / 233.0) - u / 2547.0));
+        polynomial::horner(&[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0], y - 1950.0)
     } else if y < 1986.0 {
-        let u = y - 1975.0;
-        return 45.45 + u * (1.067 - u * ((1.0 / 260.0) + u / 718.0));
+        polynomial::horner(&[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0], y - 1975.0)
     } else if y < 2005.0 {
-        let u = y - 2000.0;
-        return 63.86
-            + u * (0.3345
-                - u * (0.060374 - u * (0.0017275 + u * (0.000651814 + u * 0.00002373599))));
+        polynomial::horner(
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+            y - 2000.0,
+        )
     } else if y < 2050.0 {
-        let u = y - 2000.0;
-        return 62.92 + u * (0.32217 + u * 0.005589);
+        polynomial::horner(&[62.92, 0.32217, 0.005589], y - 2000.0)
     } else if y <= 2150.0 {
-        let u = (y - 1820.0) / 100.0;
-        return 32.0 * u * u - 20.0 - 0.5628 * (2150.0 - y);
-    } else if y > 2150.0 {
-        let u = (y - 1820.0) / 100.0;
-        return 32.0 * u * u - 20.0;
+        long_term(y) - 0.5628 * (2150.0 - y)
+    } else {
+        long_term(y)
+    }
+}
+
+/**
+Computes an approximate value of ΔT for a given year and month, with
+its uncertainty
+
+# Returns
+
+* delta_t: ΔT and its standard uncertainty | in seconds
+
+Before 1955, the uncertainty of ΔT is that of its reconstruction from
+historical records, about `0.8t²` seconds with `t = (year − 1825)/100`
+after Morrison and Stephenson (2004), and at least one second for the
+fit of the polynomial expressions. From 1955 to 2005, when ΔT is
+measured with atomic clocks, it is the one second of the fit. After
+2005, the expressions are extrapolations whose uncertainty grows by a
+quarter of a second per year.
+
+# Arguments
+
+* year : Year
+* month: Month range: 1 - 12
+**/
+pub fn delta_t_with_uncertainty(year: i32, month: u8) -> Measurement {
+    let y = (year as f64) + ((month as f64) - 0.5) / 12.0;
+
+    let sigma = if y < 1955.0 {
+        let t = (y - 1825.0) / 100.0;
+        (0.8 * t * t).max(1.0)
+    } else if y < 2005.0 {
+        1.0
+    } else {
+        1.0 + 0.25 * (y - 2005.0)
+    };
+
+    Measurement {
+        value: delta_t(year, month),
+        sigma,
+    }
+}
+
+// Span over which a ΔT table is blended into the long-term parabola
+// beyond its ends | in years
+const DELTA_T_BLEND_YEARS: f64 = 100.0;
+
+/// Represents a model of ΔT
+#[derive(Copy, Clone, Debug, Default)]
+pub enum DeltaTModel {
+    /// Polynomial expressions of Espenak and Meeus, as given by
+    /// [delta_t()](./fn.delta_t.html)
+    #[default]
+    EspenakMeeus,
+    /// Long-term parabola of Stephenson, Morrison and Hohenkerk
+    /// (2016), ΔT = −320 + 32.5u² seconds with u = (year − 1825)/100
+    LongTermParabola,
+    /// Table of (year, ΔT) pairs, in increasing order of the decimal
+    /// year | ΔT in seconds
+    ///
+    /// ΔT is interpolated linearly between the entries. Beyond the
+    /// ends of the table, the long-term parabola is used, offset to
+    /// meet the table at its end and blended into the plain parabola
+    /// over a century. Tables such as the Stephenson, Morrison and
+    /// Hohenkerk (2016) splines, sampled yearly, can be used this way.
+    Table(&'static [(f64, f64)]),
+    /// Function taking a year and month and returning ΔT | in seconds
+    Function(fn(i32, u8) -> f64),
+}
+
+impl DeltaTModel {
+    /**
+    Computes ΔT for a given year and month
+
+    # Returns
+
+    * delta_t: ΔT | in seconds
+
+    # Arguments
+
+    * year : Year
+    * month: Month range: 1 - 12
+    **/
+    pub fn delta_t(&self, year: i32, month: u8) -> f64 {
+        let y = (year as f64) + ((month as f64) - 0.5) / 12.0;
+
+        match self {
+            DeltaTModel::EspenakMeeus => delta_t(year, month),
+            DeltaTModel::LongTermParabola => long_term_delta_t(y),
+            DeltaTModel::Table(table) => table_delta_t(table, y),
+            DeltaTModel::Function(f) => f(year, month),
+        }
+    }
+}
+
+// Long-term parabola of Stephenson, Morrison and Hohenkerk (2016) | in
+// seconds
+fn long_term_delta_t(y: f64) -> f64 {
+    let u = (y - 1825.0) / 100.0;
+
+    -320.0 + 32.5 * u * u
+}
+
+// ΔT interpolated in a table, blended into the long-term parabola
+// beyond its ends | in seconds
+fn table_delta_t(table: &[(f64, f64)], y: f64) -> f64 {
+    let (first, last) = match (table.first(), table.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return long_term_delta_t(y),
+    };
+
+    let blend = |(year, delta_t): (f64, f64)| {
+        let weight = (1.0 - (y - year).abs() / DELTA_T_BLEND_YEARS).max(0.0);
+        long_term_delta_t(y) + weight * (delta_t - long_term_delta_t(year))
+    };
+
+    if y <= first.0 {
+        return blend(first);
+    }
+    if y >= last.0 {
+        return blend(last);
+    }
+
+    let i = table.partition_point(|&(year, _)| year <= y);
+    let (y_1, delta_t_1) = table[i - 1];
+    let (y_2, delta_t_2) = table[i];
+
+    delta_t_1 + (delta_t_2 - delta_t_1) * (y - y_1) / (y_2 - y_1)
+}
+
+/// Represents an instant in both the TT and UT time scales
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Instant {
+    /// Julian (Ephemeris) day, TT
+    pub jd_tt: f64,
+    /// Julian day, UT
+    pub jd_ut: f64,
+}
+
+impl Instant {
+    /// Creates an instant from a Julian (Ephemeris) day in TT
+    pub fn from_tt(jd_tt: f64) -> Instant {
+        Instant {
+            jd_tt,
+            jd_ut: ut_from_tt(jd_tt),
+        }
+    }
+
+    /// Creates an instant from a Julian (Ephemeris) day in TT, with
+    /// UT from a given ΔT model
+    pub fn from_tt_with_model(jd_tt: f64, model: &DeltaTModel) -> Instant {
+        Instant {
+            jd_tt,
+            jd_ut: ut_from_tt_with_model(jd_tt, model),
+        }
+    }
+
+    /// Creates an instant from a Julian day in UT
+    pub fn from_ut(jd_ut: f64) -> Instant {
+        Instant {
+            jd_tt: tt_from_ut(jd_ut),
+            jd_ut,
+        }
+    }
+
+    /// Returns ΔT = TT − UT at the instant | in seconds
+    pub fn delta_t(&self) -> f64 {
+        (self.jd_tt - self.jd_ut) * 86400.0
     }
 
-    0.0
+    /// Expresses the instant in a time zone | offset in decimal hours
+    pub fn to_local(&self, offset: f64) -> ZonedInstant {
+        ZonedInstant {
+            instant: *self,
+            offset,
+        }
+    }
+
+    /// Expresses the instant in a named time zone, with the offset in
+    /// force at the instant
+    #[cfg(feature = "chrono-tz")]
+    pub fn to_zone(&self, zone: chrono_tz::Tz) -> ZonedInstant {
+        use chrono::Offset;
+
+        let unix_secs = ((self.jd_ut - 2440587.5) * 86400.0).round() as i64;
+        let offset = chrono::DateTime::from_timestamp(unix_secs, 0)
+            .map(|utc| utc.with_timezone(&zone).offset().fix().local_minus_utc())
+            .unwrap_or(0);
+
+        self.to_local(offset as f64 / 3600.0)
+    }
+}
+
+/// Represents an instant in the local time of a time zone
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ZonedInstant {
+    /// The instant
+    pub instant: Instant,
+    /// Offset of the time zone from UT | in decimal hours
+    ///
+    /// Example: Pacific Standard Time is -8.0
+    pub offset: f64,
+}
+
+impl ZonedInstant {
+    /// Expresses the instant in another time zone | offset in decimal
+    /// hours
+    pub fn to_local(&self, offset: f64) -> ZonedInstant {
+        self.instant.to_local(offset)
+    }
+
+    /// Returns the Julian day of the local time
+    pub fn local_jd(&self) -> f64 {
+        self.instant.jd_ut + self.offset / 24.0
+    }
+
+    /// Returns the local civil date and time, with the seconds rounded
+    /// to the millisecond
+    pub fn local_date_time(&self) -> Result<DateTime, &'static str> {
+        DateTime::from_julian_day(self.instant.jd_ut, self.offset)
+    }
+}
+
+/**
+Converts a Julian day in UT to TT
+
+# Returns
+
+* jd_tt: Julian (Ephemeris) day, TT
+
+ΔT is taken from the model set in the
+[config](../config/index.html) module,
+[delta_t()](./fn.delta_t.html) by default.
+
+# Arguments
+
+* jd_ut: Julian day, UT
+**/
+pub fn tt_from_ut(jd_ut: f64) -> f64 {
+    tt_from_ut_with_model(jd_ut, &crate::config::get().delta_t)
+}
+
+/**
+Converts a Julian day in UT to TT with a given ΔT model
+
+# Returns
+
+* jd_tt: Julian (Ephemeris) day, TT
+
+Results in TT, such as the instants of eclipses and lunar phases,
+can be mapped to UT under several ΔT models this way without
+changing the [config](../config/index.html) defaults.
+
+# Arguments
+
+* jd_ut: Julian day, UT
+* model: ΔT model
+**/
+pub fn tt_from_ut_with_model(jd_ut: f64, model: &DeltaTModel) -> f64 {
+    jd_ut + delta_t_from_jd_with_model(jd_ut, model) / 86400.0
+}
+
+/**
+Converts a Julian (Ephemeris) day in TT to UT
+
+# Returns
+
+* jd_ut: Julian day, UT
+
+This is the inverse of [tt_from_ut()](./fn.tt_from_ut.html); ΔT is
+evaluated at the UT instant.
+
+# Arguments
+
+* jd_tt: Julian (Ephemeris) day, TT
+**/
+pub fn ut_from_tt(jd_tt: f64) -> f64 {
+    ut_from_tt_with_model(jd_tt, &crate::config::get().delta_t)
+}
+
+/**
+Converts a Julian (Ephemeris) day in TT to UT with a given ΔT model
+
+# Returns
+
+* jd_ut: Julian day, UT
+
+This is the inverse of
+[tt_from_ut_with_model()](./fn.tt_from_ut_with_model.html).
+
+# Arguments
+
+* jd_tt: Julian (Ephemeris) day, TT
+* model: ΔT model
+**/
+pub fn ut_from_tt_with_model(jd_tt: f64, model: &DeltaTModel) -> f64 {
+    let mut jd_ut = jd_tt - delta_t_from_jd_with_model(jd_tt, model) / 86400.0;
+    for _ in 0..2 {
+        jd_ut = jd_tt - delta_t_from_jd_with_model(jd_ut, model) / 86400.0;
+    }
+
+    jd_ut
+}
+
+// Largest change of the UT instant of an event between two
+// iterations of refine_ut_event() at convergence | in days
+const EVENT_TOLERANCE: f64 = 1e-3 / 86400.0;
+
+/**
+Finds the instant in UT of an event computed in TT
+
+# Returns
+
+* jd_ut: Julian day of the event, UT
+
+Event finders work in TT, but ΔT, and with it the UT instant of the
+event, depends on the date of the event. Starting from an estimate in
+UT, the estimate is converted to TT, `f_tt` finds the event near it
+and the result is converted back to UT, with ΔT evaluated at the UT
+instant, until the UT instant changes by less than a millisecond.
+
+# Arguments
+
+* f_tt      : Function returning the Julian (Ephemeris) day, TT, of
+              the event nearest to a Julian (Ephemeris) day, TT
+* initial_jd: Estimate of the Julian day of the event, UT
+**/
+pub fn refine_ut_event<F: Fn(f64) -> f64>(f_tt: F, initial_jd: f64) -> f64 {
+    let mut jd_ut = initial_jd;
+    for _ in 0..10 {
+        let jd_tt = f_tt(tt_from_ut(jd_ut));
+        let next_jd_ut = jd_tt - delta_t_from_jd(jd_ut) / 86400.0;
+        let converged = (next_jd_ut - jd_ut).abs() < EVENT_TOLERANCE;
+
+        jd_ut = next_jd_ut;
+        if converged {
+            break;
+        }
+    }
+
+    jd_ut
+}
+
+// ΔT for the year and month of a Julian day, from the configured
+// model | in seconds
+pub(crate) fn delta_t_from_jd(jd: f64) -> f64 {
+    delta_t_from_jd_with_model(jd, &crate::config::get().delta_t)
+}
+
+// ΔT for the year and month of a Julian day, from a given model | in
+// seconds
+fn delta_t_from_jd_with_model(jd: f64, model: &DeltaTModel) -> f64 {
+    match date_from_julian_day(jd) {
+        Ok((year, month, _)) => model.delta_t(year as i32, month),
+        Err(_) => 0.0,
+    }
+}
+
+// ΔT from the configured model and the uncertainty of the built-in
+// one, for the year and month of a Julian day | in seconds
+pub(crate) fn delta_t_from_jd_with_uncertainty(jd: f64) -> Measurement {
+    match date_from_julian_day(jd) {
+        Ok((year, month, _)) => Measurement {
+            value: crate::config::get().delta_t.delta_t(year as i32, month),
+            sigma: delta_t_with_uncertainty(year as i32, month).sigma,
+        },
+        Err(_) => Measurement {
+            value: 0.0,
+            sigma: 0.0,
+        },
+    }
 }
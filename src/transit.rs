@@ -0,0 +1,535 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------RISING, TRANSIT AND SETTING-----------!
+
+use crate::angle;
+use crate::au;
+use crate::coordinate;
+use crate::ephemeris;
+use crate::interpol;
+use crate::lunar;
+use crate::search;
+use crate::time;
+use crate::visibility;
+use crate::Accuracy;
+use crate::Measurement;
+use std::f64::consts::PI;
+
+/// Represents the times of rising, transit and setting of a body on
+/// a day
+///
+/// An event that doesn't happen on the day is `None`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RiseTransitSet {
+    /// Time of rising
+    pub rise: Option<time::Instant>,
+    /// Time of upper transit
+    pub transit: Option<time::Instant>,
+    /// Time of setting
+    pub set: Option<time::Instant>,
+}
+
+/// Represents the times of rising, transit and setting of a body on
+/// a day, in the local time of a time zone
+///
+/// An event that doesn't happen on the day is `None`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ZonedRiseTransitSet {
+    /// Time of rising
+    pub rise: Option<time::ZonedInstant>,
+    /// Time of upper transit
+    pub transit: Option<time::ZonedInstant>,
+    /// Time of setting
+    pub set: Option<time::ZonedInstant>,
+}
+
+impl RiseTransitSet {
+    /// Expresses the times in a time zone | offset in decimal hours
+    pub fn to_local(&self, offset: f64) -> ZonedRiseTransitSet {
+        ZonedRiseTransitSet {
+            rise: self.rise.map(|instant| instant.to_local(offset)),
+            transit: self.transit.map(|instant| instant.to_local(offset)),
+            set: self.set.map(|instant| instant.to_local(offset)),
+        }
+    }
+}
+
+/// Represents a passage of a body across the meridian
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Culmination {
+    /// Time of the passage
+    pub instant: time::Instant,
+    /// Whether the passage is the upper culmination, at the greatest
+    /// altitude, or the lower one
+    pub upper: bool,
+    /// Geometric altitude at the passage | in radians
+    pub alt: f64,
+}
+
+/// Represents the standard altitude of a body, the geometric altitude
+/// of its center at the apparent rising or setting
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StandardAltitude {
+    /// A star or planet, −34′ for the refraction at the horizon
+    Star,
+    /// The Sun, −50′ for the refraction and its semidiameter
+    Sun,
+    /// The Moon, 0.7275π − 34′, holding its horizontal parallax π |
+    /// in radians
+    Moon(f64),
+    /// A custom altitude | in radians
+    Custom(f64),
+}
+
+impl StandardAltitude {
+    /**
+    Returns the standard altitude of a body
+
+    # Returns
+
+    * standard_altitude: Standard altitude for the body | in radians
+
+    # Arguments
+
+    * body           : The body
+    * earth_body_dist: Earth-body distance, used for the Moon | in AU
+    **/
+    pub fn for_body(body: &ephemeris::Body, earth_body_dist: f64) -> Self {
+        match body {
+            ephemeris::Body::Sun => StandardAltitude::Sun,
+            ephemeris::Body::Moon => {
+                StandardAltitude::Moon(lunar::horizontal_parallax(earth_body_dist * au::AU_KM))
+            }
+            _ => StandardAltitude::Star,
+        }
+    }
+
+    /**
+    Returns the altitude
+
+    # Returns
+
+    * altitude: Geometric altitude of the center of the body | in
+                radians
+    **/
+    pub fn alt(&self) -> f64 {
+        let refraction = angle::deg_dmas(0, 34, 0.0).to_radians();
+
+        match *self {
+            StandardAltitude::Star => -refraction,
+            StandardAltitude::Sun => -angle::deg_dmas(0, 50, 0.0).to_radians(),
+            StandardAltitude::Moon(parallax) => 0.7275 * parallax - refraction,
+            StandardAltitude::Custom(alt) => alt,
+        }
+    }
+}
+
+/**
+Computes the times of rising, transit and setting of a body
+
+# Returns
+
+(rise, transit, set)
+
+* rise   : Time of rising | in Julian days, UT
+* transit: Time of transit | in Julian days, UT
+* set    : Time of setting | in Julian days, UT
+
+The three times fall on the day starting at `jd_0h`; Meeus's
+interpolation method from chapter 15 is used. An error is returned
+if the body is circumpolar or never rises on that day.
+
+# Arguments
+
+* eq_points: Apparent equatorial points of the body at 0h TT on the
+             day before, the day of, and the day after `jd_0h`
+* observer : Geographic point of the observer | in radians
+* h0       : Standard altitude of the body
+* jd_0h    : Julian day at 0h UT of the day
+* delta_t  : ΔT | in seconds
+**/
+pub fn rise_transit_set(
+    eq_points: &[coordinate::EqPoint; 3],
+    observer: &coordinate::GeographPoint,
+    h0: StandardAltitude,
+    jd_0h: f64,
+    delta_t: f64,
+) -> Result<(f64, f64, f64), &'static str> {
+    let h0 = h0.alt();
+    let (sin_lat, cos_lat) = observer.lat.sin_cos();
+    let dec = eq_points[1].dec;
+
+    match visibility::classify_at_alt(dec, observer.lat, h0) {
+        visibility::RiseSetClass::Circumpolar => return Err("The body is circumpolar on this day"),
+        visibility::RiseSetClass::NeverRises => return Err("The body never rises on this day"),
+        visibility::RiseSetClass::RisesAndSets => {}
+    }
+    let hour_angle_0 = ((h0.sin() - sin_lat * dec.sin()) / (cos_lat * dec.cos())).acos();
+
+    // Right ascensions unwrapped around the central value
+    let asc_2 = eq_points[1].asc;
+    let asc_1 = asc_2 - angle::limit_pi(asc_2 - eq_points[0].asc);
+    let asc_3 = asc_2 + angle::limit_pi(eq_points[2].asc - asc_2);
+
+    let sidereal_0h = time::apparent_sidereal_from_jd(jd_0h);
+    let transit_m = limit_unit((asc_2 + observer.long - sidereal_0h) / (2.0 * PI));
+
+    let eval = |mut m: f64, is_transit: bool| {
+        for _ in 0..5 {
+            let sidereal = sidereal_0h + (360.985647 * m).to_radians();
+            let n = m + delta_t / 86400.0;
+            let asc = interpol::three_val(asc_1, asc_2, asc_3, n);
+            let dec = interpol::three_val(eq_points[0].dec, eq_points[1].dec, eq_points[2].dec, n);
+            let hour_angle = angle::limit_pi(sidereal - observer.long - asc);
+
+            let delta_m = if is_transit {
+                -hour_angle / (2.0 * PI)
+            } else {
+                let alt = (sin_lat * dec.sin() + cos_lat * dec.cos() * hour_angle.cos()).asin();
+                (alt - h0) / (2.0 * PI * dec.cos() * cos_lat * hour_angle.sin())
+            };
+
+            m += delta_m;
+            if delta_m.abs() < 1e-7 {
+                break;
+            }
+        }
+
+        jd_0h + m
+    };
+
+    let rise_m = limit_unit(transit_m - hour_angle_0 / (2.0 * PI));
+    let set_m = limit_unit(transit_m + hour_angle_0 / (2.0 * PI));
+
+    Ok((
+        eval(rise_m, false),
+        eval(transit_m, true),
+        eval(set_m, false),
+    ))
+}
+
+/**
+Computes the times of rising, transit and setting of a body on a day
+
+# Returns
+
+(rise, transit, set)
+
+* rise   : Time of rising | in Julian days, UT
+* transit: Time of transit | in Julian days, UT
+* set    : Time of setting | in Julian days, UT
+
+The standard altitude is that of
+[StandardAltitude::for_body()](./enum.StandardAltitude.html#method.for_body).
+
+# Arguments
+
+* body    : The body
+* jd_0h   : Julian day at 0h UT of the day
+* observer: Geographic point of the observer | in radians
+* accuracy: Accuracy tier of the positions
+**/
+pub fn body_rise_transit_set(
+    body: &ephemeris::Body,
+    jd_0h: f64,
+    observer: &coordinate::GeographPoint,
+    accuracy: Accuracy,
+) -> Result<(f64, f64, f64), &'static str> {
+    let delta_t = time::delta_t_from_jd(jd_0h);
+    let (eq_points, h0) = positions_and_standard_alt(body, jd_0h, accuracy);
+
+    rise_transit_set(&eq_points, observer, h0, jd_0h, delta_t)
+}
+
+/// Standard deviation of the refraction at the horizon about its
+/// standard value of 34′ | in radians
+///
+/// Refraction at the horizon varies with the temperature gradient of
+/// the air near the ground, typically by about 0.2°.
+pub const HORIZON_REFRACTION_SIGMA: f64 = 0.2 * PI / 180.0;
+
+/**
+Computes the times of rising, transit and setting of a body on a day,
+with their uncertainties
+
+# Returns
+
+(rise, transit, set)
+
+* rise   : Time of rising and its standard uncertainty | in Julian
+           days, UT
+* transit: Time of transit and its standard uncertainty | in Julian
+           days, UT
+* set    : Time of setting and its standard uncertainty | in Julian
+           days, UT
+
+The times are those of
+[body_rise_transit_set()](./fn.body_rise_transit_set.html). The
+uncertainty of ΔT, from
+[delta_t_with_uncertainty()](../time/fn.delta_t_with_uncertainty.html),
+shifts all three events by the motion of the body in right ascension
+over that time. The uncertainty of the refraction at the horizon,
+[HORIZON_REFRACTION_SIGMA](./constant.HORIZON_REFRACTION_SIGMA.html),
+shifts the rising and setting by the time the body takes to climb
+through it, which grows without bound as the body nears being
+circumpolar.
+
+# Arguments
+
+* body    : The body
+* jd_0h   : Julian day at 0h UT of the day
+* observer: Geographic point of the observer | in radians
+* accuracy: Accuracy tier of the positions
+**/
+pub fn body_rise_transit_set_with_uncertainty(
+    body: &ephemeris::Body,
+    jd_0h: f64,
+    observer: &coordinate::GeographPoint,
+    accuracy: Accuracy,
+) -> Result<(Measurement, Measurement, Measurement), &'static str> {
+    let delta_t = time::delta_t_from_jd_with_uncertainty(jd_0h);
+    let (eq_points, h0) = positions_and_standard_alt(body, jd_0h, accuracy);
+
+    let (rise, transit, set) = rise_transit_set(&eq_points, observer, h0, jd_0h, delta_t.value)?;
+    let h0 = h0.alt();
+
+    // Rates of the right ascension and of the hour angle of the body |
+    // in radians per day
+    let asc_rate = angle::limit_pi(eq_points[2].asc - eq_points[0].asc) / 2.0;
+    let hour_angle_rate = 2.0 * PI * time::SIDEREAL_SOLAR_RATIO - asc_rate;
+
+    let sigma_delta_t = (asc_rate * delta_t.sigma / 86400.0 / hour_angle_rate).abs();
+
+    let (sin_lat, cos_lat) = observer.lat.sin_cos();
+    let dec = eq_points[1].dec;
+    let cos_h0 = (h0.sin() - sin_lat * dec.sin()) / (cos_lat * dec.cos());
+    let alt_rate = cos_lat * dec.cos() * (1.0 - cos_h0 * cos_h0).sqrt() * hour_angle_rate;
+    let sigma_refraction = HORIZON_REFRACTION_SIGMA / alt_rate.abs();
+    let sigma_horizon = sigma_delta_t.hypot(sigma_refraction);
+
+    Ok((
+        Measurement {
+            value: rise,
+            sigma: sigma_horizon,
+        },
+        Measurement {
+            value: transit,
+            sigma: sigma_delta_t,
+        },
+        Measurement {
+            value: set,
+            sigma: sigma_horizon,
+        },
+    ))
+}
+
+/**
+Finds the culminations of a fixed point
+
+# Returns
+
+* culminations: Upper and lower culminations between `jd_start` and
+                `jd_end`, in increasing order of time
+
+The upper culmination happens when the local apparent sidereal time
+equals the right ascension, and the lower one twelve sidereal hours
+later. The times are found to about half a second. The altitudes are
+geometric, without refraction; a negative altitude means that the
+culmination happens below the horizon.
+
+# Arguments
+
+* eq_point: Apparent equatorial point, referred to the true equinox
+            of the date | in radians
+* observer: The observer
+* jd_start: Julian day of the start of the search, UT
+* jd_end  : Julian day of the end of the search, UT
+**/
+pub fn culminations(
+    eq_point: &coordinate::EqPoint,
+    observer: &coordinate::Observer,
+    jd_start: f64,
+    jd_end: f64,
+) -> Vec<Culmination> {
+    let step = 10.0 / 1440.0;
+    let tolerance = 0.5 / 86400.0;
+
+    // The hour angle crosses 0 upwards at the upper culmination, and ±π
+    // downwards at the lower one
+    search::changes(jd_start, jd_end, step, tolerance, |jd| {
+        let sidereal = time::apparent_sidereal_from_jd(jd);
+        angle::limit_pi(coordinate::hour_angle_from_long(
+            sidereal,
+            observer.long,
+            eq_point.asc,
+        )) > 0.0
+    })
+    .expect("Internal error in transit::culminations")
+    .into_iter()
+    .map(|(jd, upper)| {
+        let hour_angle = if upper { 0.0 } else { PI };
+        Culmination {
+            instant: time::Instant::from_ut(jd),
+            upper,
+            alt: coordinate::altitude_from_eq(hour_angle, eq_point.dec, observer.lat),
+        }
+    })
+    .collect()
+}
+
+// Apparent equatorial points of a body at 0h TT on the day before, the
+// day of, and the day after jd_0h, and its standard altitude
+fn positions_and_standard_alt(
+    body: &ephemeris::Body,
+    jd_0h: f64,
+    accuracy: Accuracy,
+) -> ([coordinate::EqPoint; 3], StandardAltitude) {
+    let position = |jd: f64| ephemeris::apparent_eq_pos(body, jd, accuracy);
+    let (eq_point_1, _) = position(jd_0h - 1.0);
+    let (eq_point_2, dist) = position(jd_0h);
+    let (eq_point_3, _) = position(jd_0h + 1.0);
+
+    (
+        [eq_point_1, eq_point_2, eq_point_3],
+        StandardAltitude::for_body(body, dist),
+    )
+}
+
+/**
+Computes the times of moonrise, lunar transit and moonset on a date
+
+# Returns
+
+* rise_transit_set: Times of the events on the date, in the
+                    observer's time zone
+
+The date is a calendar date in the observer's time zone, as in
+[sun_rise_set()](./fn.sun_rise_set.html). Each event is found on the
+Moon's position at the time of the event, which follows its fast
+motion better than the three-point method of
+[body_rise_transit_set()](./fn.body_rise_transit_set.html). The
+standard altitude 0.7275π − 34′ is reevaluated with the horizontal
+parallax π at each step. As the Moon rises about 50 minutes later
+each day, one day a month has no moonrise, and one no moonset.
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn moon_rise_set(observer: &coordinate::Observer, date: &time::Date) -> ZonedRiseTransitSet {
+    let local_midnight = time::local_midnight(date, observer.time_zone);
+    let delta_t = time::delta_t_from_jd(local_midnight) / 86400.0;
+
+    events(local_midnight, observer, |jd| {
+        let (eq_point, dist) =
+            ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, jd + delta_t, Accuracy::High);
+        (
+            eq_point,
+            StandardAltitude::for_body(&ephemeris::Body::Moon, dist),
+        )
+    })
+    .to_local(observer.time_zone)
+}
+
+/**
+Computes the times of sunrise, solar transit and sunset on a date
+
+# Returns
+
+* rise_transit_set: Times of the events on the date, in the
+                    observer's time zone
+
+The date is a calendar date in the observer's time zone, set with
+[Observer::time_zone()](../coordinate/struct.Observer.html#method.time_zone),
+and so are the times: `local_jd()` and `local_date_time()` give the
+local Julian days and civil times. The standard altitude of −50′
+accounts for refraction and the semidiameter of the Sun.
+
+# Arguments
+
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+**/
+pub fn sun_rise_set(observer: &coordinate::Observer, date: &time::Date) -> ZonedRiseTransitSet {
+    let local_midnight = time::local_midnight(date, observer.time_zone);
+    let delta_t = time::delta_t_from_jd(local_midnight) / 86400.0;
+    events(local_midnight, observer, |jd| {
+        let (eq_point, _) =
+            ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, jd + delta_t, Accuracy::Low);
+        (eq_point, StandardAltitude::Sun)
+    })
+    .to_local(observer.time_zone)
+}
+
+// Finds the events of a day by sampling the position of the body, a
+// function of the time in UT returning its apparent equatorial point
+// and standard altitude
+fn events<P>(jd_0h: f64, observer: &coordinate::Observer, position: P) -> RiseTransitSet
+where
+    P: Fn(f64) -> (coordinate::EqPoint, StandardAltitude),
+{
+    let step = 10.0 / 1440.0;
+    let tolerance = 0.5 / 86400.0;
+
+    let hour_angle = |jd: f64, eq_point: &coordinate::EqPoint| {
+        let sidereal = time::apparent_sidereal_from_jd(jd);
+        angle::limit_pi(coordinate::hour_angle_from_long(
+            sidereal,
+            observer.long,
+            eq_point.asc,
+        ))
+    };
+
+    let risings = search::changes(jd_0h, jd_0h + 1.0, step, tolerance, |jd| {
+        let (eq_point, h0) = position(jd);
+        coordinate::altitude_from_eq(hour_angle(jd, &eq_point), eq_point.dec, observer.lat)
+            > h0.alt()
+    })
+    .expect("Internal error in transit::events");
+    // The hour angle crosses 0 upwards at the upper transit, and ±π
+    // downwards at the lower one
+    let transits = search::changes(jd_0h, jd_0h + 1.0, step, tolerance, |jd| {
+        let (eq_point, _) = position(jd);
+        hour_angle(jd, &eq_point) > 0.0
+    })
+    .expect("Internal error in transit::events");
+
+    let first = |changes: &[(f64, bool)], holds: bool| {
+        changes
+            .iter()
+            .find(|c| c.1 == holds)
+            .map(|c| time::Instant::from_ut(c.0))
+    };
+
+    RiseTransitSet {
+        rise: first(&risings, true),
+        transit: first(&transits, true),
+        set: first(&risings, false),
+    }
+}
+
+#[inline]
+fn limit_unit(m: f64) -> f64 {
+    m - m.floor()
+}
@@ -0,0 +1,208 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------UNITS-----------!
+
+// Typed counterparts of functions of the library, taking and returning
+// uom quantities instead of plain f64 values in radians, AU or days.
+// Julian days remain f64, as they are instants and not durations.
+
+use crate::au;
+use crate::coordinate;
+use crate::ecliptic;
+use crate::ephemeris;
+use crate::planet::earth;
+use crate::time;
+use crate::Accuracy;
+use uom::si::angle::radian;
+use uom::si::f64::{Angle, Length, Time};
+use uom::si::length::kilometer;
+use uom::si::time::{day, second};
+
+/**
+Converts an angle in radians to an `Angle`
+
+# Arguments
+
+* angl: Angle | in radians
+**/
+#[inline]
+pub fn angle(angl: f64) -> Angle {
+    Angle::new::<radian>(angl)
+}
+
+/**
+Converts a distance in AU to a `Length`
+
+# Arguments
+
+* distance: Distance | in AU
+**/
+#[inline]
+pub fn length_from_au(distance: f64) -> Length {
+    Length::new::<kilometer>(au::km_from_au(distance))
+}
+
+/**
+Converts a `Length` to a distance in AU
+
+# Arguments
+
+* distance: Distance
+**/
+#[inline]
+pub fn au_from_length(distance: Length) -> f64 {
+    au::au_from_km(distance.get::<kilometer>())
+}
+
+/**
+Computes the apparent equatorial position of a body
+
+# Returns
+
+(asc, dec, dist)
+
+* asc : Apparent right ascension, referred to the true equinox of
+        the date
+* dec : Apparent declination
+* dist: Distance from the Earth
+
+See [ephemeris::apparent_eq_pos()](../ephemeris/fn.apparent_eq_pos.html).
+
+# Arguments
+
+* body    : The body
+* jd      : Julian (Ephemeris) day
+* accuracy: Accuracy tier
+**/
+pub fn apparent_eq_pos(
+    body: &ephemeris::Body,
+    jd: f64,
+    accuracy: Accuracy,
+) -> (Angle, Angle, Length) {
+    let (eq_point, dist) = ephemeris::apparent_eq_pos(body, jd, accuracy);
+
+    (
+        angle(eq_point.asc),
+        angle(eq_point.dec),
+        length_from_au(dist),
+    )
+}
+
+/**
+Computes the apparent sidereal time at Greenwich
+
+# Arguments
+
+* jd: Julian day, UT
+**/
+pub fn apparent_sidereal(jd: f64) -> Angle {
+    angle(time::apparent_sidereal_jd(
+        jd,
+        ecliptic::ObliquityModel::Laskar,
+    ))
+}
+
+/**
+Computes the local hour angle
+
+# Arguments
+
+* green_sidereal: Sidereal time at Greenwich
+* observer_long : Observer's geographical longitude, positive westward
+* asc           : Right ascension
+**/
+pub fn hour_angle_from_long(green_sidereal: Angle, observer_long: Angle, asc: Angle) -> Angle {
+    angle(coordinate::hour_angle_from_long(
+        green_sidereal.get::<radian>(),
+        observer_long.get::<radian>(),
+        asc.get::<radian>(),
+    ))
+}
+
+/**
+Computes the horizontal coordinates from equatorial coordinates
+
+# Returns
+
+(az, alt)
+
+* az : Azimuth, measured westwards from the south
+* alt: Altitude
+
+# Arguments
+
+* hour_angle  : Local hour angle
+* dec         : Declination
+* observer_lat: Observer's geographical latitude
+**/
+pub fn horizontal_from_eq(hour_angle: Angle, dec: Angle, observer_lat: Angle) -> (Angle, Angle) {
+    let (hour_angle, dec, observer_lat) = (
+        hour_angle.get::<radian>(),
+        dec.get::<radian>(),
+        observer_lat.get::<radian>(),
+    );
+
+    (
+        angle(coordinate::azimuth_from_eq(hour_angle, dec, observer_lat)),
+        angle(coordinate::altitude_from_eq(hour_angle, dec, observer_lat)),
+    )
+}
+
+/**
+Computes the geodesic distance between two points on the Earth
+
+See [earth::geodesic_distance()](../planet/earth/fn.geodesic_distance.html).
+
+# Arguments
+
+* p1: Geographical point 1
+* p2: Geographical point 2
+**/
+pub fn geodesic_distance(p1: &coordinate::GeographPoint, p2: &coordinate::GeographPoint) -> Length {
+    Length::new::<kilometer>(earth::geodesic_distance(p1, p2))
+}
+
+/**
+Computes the light-time for a distance
+
+# Arguments
+
+* distance: Distance
+**/
+pub fn light_time(distance: Length) -> Time {
+    Time::new::<day>(au::light_time_days(au_from_length(distance)))
+}
+
+/**
+Computes ΔT, the difference TT - UT
+
+See [time::delta_t()](../time/fn.delta_t.html).
+
+# Arguments
+
+* year : Year
+* month: Month, from 1 to 12
+**/
+pub fn delta_t(year: i32, month: u8) -> Time {
+    Time::new::<second>(time::delta_t(year, month))
+}
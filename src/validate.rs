@@ -0,0 +1,357 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------VALIDATION AGAINST JPL HORIZONS-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::ephemeris;
+use crate::time;
+use crate::Accuracy;
+
+/// Represents a row of a JPL Horizons observer table
+#[derive(Debug)]
+pub struct HorizonsRow {
+    /// Julian (Ephemeris) day
+    pub jd: f64,
+    /// Equatorial point | in radians
+    pub eq_point: coordinate::EqPoint,
+    /// Earth-body distance | in AU, if the table has a `delta` column
+    pub dist: Option<f64>,
+}
+
+/// Represents the residuals of apollo's position against a reference
+#[derive(Debug)]
+pub struct Residual {
+    /// Julian (Ephemeris) day
+    pub jd: f64,
+    /// Computed minus reference right ascension, multiplied by the
+    /// cosine of the declination | in radians
+    pub asc_cos_dec: f64,
+    /// Computed minus reference declination | in radians
+    pub dec: f64,
+    /// Computed minus reference distance | in AU, if the reference
+    /// has one
+    pub dist: Option<f64>,
+}
+
+/// Represents summary statistics of a set of residuals
+#[derive(Debug)]
+pub struct Stats {
+    /// Number of residuals
+    pub count: usize,
+    /// Mean
+    pub mean: f64,
+    /// Root mean square
+    pub rms: f64,
+    /// Largest absolute value
+    pub max_abs: f64,
+}
+
+/// Represents summary statistics of the residuals of a position
+#[derive(Debug)]
+pub struct Summary {
+    /// Statistics of the right ascension residuals, multiplied by the
+    /// cosine of the declination | in radians
+    pub asc_cos_dec: Stats,
+    /// Statistics of the declination residuals | in radians
+    pub dec: Stats,
+    /// Statistics of the distance residuals | in AU, if the reference
+    /// has distances
+    pub dist: Option<Stats>,
+}
+
+/**
+Parses a JPL Horizons observer table
+
+The table must have been generated with the CSV format option. The
+rows between the `$$SOE` and `$$EOE` markers are read; the date
+(calendar or Julian day), right ascension, declination and, when
+present, `delta` columns are located from the header line. Angles may
+be sexagesimal or in decimal degrees.
+
+Dates in UT are converted to TT with
+[time::delta_t()](../time/fn.delta_t.html). For a meaningful
+comparison with apollo, request apparent positions (Horizons
+quantity 2).
+
+# Arguments
+
+* text: Horizons output
+**/
+pub fn parse_horizons(text: &str) -> Result<Vec<HorizonsRow>, &'static str> {
+    let mut lines = text.lines();
+
+    let mut header = None;
+    for line in lines.by_ref() {
+        let line = line.trim();
+        if line == "$$SOE" {
+            break;
+        }
+        if line.contains("Date") && line.contains(',') {
+            header = Some(line);
+        }
+    }
+
+    let header = header.ok_or("No CSV header line found in the Horizons output")?;
+    let columns: Vec<&str> = header.split(',').map(|c| c.trim()).collect();
+
+    let date_col = columns
+        .iter()
+        .position(|c| c.starts_with("Date"))
+        .ok_or("No date column in the Horizons output")?;
+    let asc_col = columns
+        .iter()
+        .position(|c| c.starts_with("R.A."))
+        .ok_or("No right ascension column in the Horizons output")?;
+    let dec_col = columns
+        .iter()
+        .position(|c| c.starts_with("DEC"))
+        .ok_or("No declination column in the Horizons output")?;
+    let dist_col = columns.iter().position(|c| *c == "delta");
+
+    let date_header = columns[date_col];
+    let is_julian_day = date_header.contains("JD");
+    let is_ut = date_header.contains("UT");
+
+    let mut rows = Vec::new();
+    for line in lines {
+        let line = line.trim();
+        if line == "$$EOE" {
+            return Ok(rows);
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+        let field = |i: usize| {
+            fields
+                .get(i)
+                .copied()
+                .ok_or("A row of the Horizons output has too few columns")
+        };
+
+        let mut jd = if is_julian_day {
+            field(date_col)?
+                .parse::<f64>()
+                .map_err(|_| "Invalid Julian day in the Horizons output")?
+        } else {
+            parse_date(field(date_col)?)?
+        };
+        if is_ut {
+            let (year, month, _) = time::date_from_julian_day(jd)?;
+            jd = time::julian_ephemeris_day(jd, time::delta_t(year as i32, month));
+        }
+
+        let asc = parse_angle(field(asc_col)?, 15.0)?;
+        let dec = parse_angle(field(dec_col)?, 1.0)?;
+        let dist = match dist_col {
+            Some(i) => Some(
+                field(i)?
+                    .parse::<f64>()
+                    .map_err(|_| "Invalid delta in the Horizons output")?,
+            ),
+            None => None,
+        };
+
+        rows.push(HorizonsRow {
+            jd,
+            eq_point: coordinate::EqPoint::with_equinox(asc.to_radians(), dec.to_radians(), jd),
+            dist,
+        });
+    }
+
+    Err("No $$EOE marker found in the Horizons output")
+}
+
+/**
+Computes the residuals of apollo's apparent position of a body
+against reference positions
+
+# Arguments
+
+* body     : The body
+* reference: Reference positions, such as the rows returned by
+             [parse_horizons()](./fn.parse_horizons.html)
+* accuracy : Accuracy tier of apollo's positions
+**/
+pub fn residuals(
+    body: &ephemeris::Body,
+    reference: &[HorizonsRow],
+    accuracy: Accuracy,
+) -> Vec<Residual> {
+    reference
+        .iter()
+        .map(|row| {
+            let (eq_point, dist) = ephemeris::apparent_eq_pos(body, row.jd, accuracy);
+
+            let delta_asc = angle::limit_pi(eq_point.asc - row.eq_point.asc);
+
+            Residual {
+                jd: row.jd,
+                asc_cos_dec: delta_asc * row.eq_point.dec.cos(),
+                dec: eq_point.dec - row.eq_point.dec,
+                dist: row.dist.map(|d| dist - d),
+            }
+        })
+        .collect()
+}
+
+/**
+Computes summary statistics of residuals
+
+Distance statistics are computed only if every residual has a
+distance.
+
+# Arguments
+
+* residuals: Residuals, such as the ones returned by
+             [residuals()](./fn.residuals.html)
+**/
+pub fn summarize(residuals: &[Residual]) -> Summary {
+    let dists: Option<Vec<f64>> = residuals.iter().map(|r| r.dist).collect();
+
+    Summary {
+        asc_cos_dec: stats(residuals.iter().map(|r| r.asc_cos_dec)),
+        dec: stats(residuals.iter().map(|r| r.dec)),
+        dist: match dists {
+            Some(ref d) if !d.is_empty() => Some(stats(d.iter().copied())),
+            _ => None,
+        },
+    }
+}
+
+/**
+Compares apollo's apparent position of a body against a JPL Horizons
+observer table
+
+# Arguments
+
+* body    : The body
+* text    : Horizons output, see
+            [parse_horizons()](./fn.parse_horizons.html)
+* accuracy: Accuracy tier of apollo's positions
+**/
+pub fn compare(
+    body: &ephemeris::Body,
+    text: &str,
+    accuracy: Accuracy,
+) -> Result<Summary, &'static str> {
+    let reference = parse_horizons(text)?;
+    Ok(summarize(&residuals(body, &reference, accuracy)))
+}
+
+fn stats<I: Iterator<Item = f64>>(values: I) -> Stats {
+    let mut count = 0;
+    let mut sum = 0.0;
+    let mut sum_sq = 0.0;
+    let mut max_abs: f64 = 0.0;
+
+    for v in values {
+        count += 1;
+        sum += v;
+        sum_sq += v * v;
+        max_abs = max_abs.max(v.abs());
+    }
+
+    let n = (count as f64).max(1.0);
+    Stats {
+        count,
+        mean: sum / n,
+        rms: (sum_sq / n).sqrt(),
+        max_abs,
+    }
+}
+
+// Parses `HH MM SS.ff` (scaled by `unit_deg`) or decimal degrees | in degrees
+fn parse_angle(field: &str, unit_deg: f64) -> Result<f64, &'static str> {
+    let parts: Vec<&str> = field.split_whitespace().collect();
+
+    match parts.len() {
+        1 => parts[0]
+            .parse::<f64>()
+            .map_err(|_| "Invalid angle in the Horizons output"),
+        3 => {
+            let invalid = |_| "Invalid sexagesimal angle in the Horizons output";
+            let units: f64 = parts[0].parse().map_err(invalid)?;
+            let minutes: f64 = parts[1].parse().map_err(invalid)?;
+            let seconds: f64 = parts[2].parse().map_err(invalid)?;
+
+            let value = units.abs() + minutes / 60.0 + seconds / 3600.0;
+            if parts[0].starts_with('-') {
+                Ok(-value * unit_deg)
+            } else {
+                Ok(value * unit_deg)
+            }
+        }
+        _ => Err("Invalid angle in the Horizons output"),
+    }
+}
+
+// Parses `[b]YYYY-Mon-DD HH:MN[:SS[.fff]]` | as a Julian day
+fn parse_date(field: &str) -> Result<f64, &'static str> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let invalid = "Invalid date in the Horizons output";
+
+    let (day_part, time_part) = field.split_once(' ').unwrap_or((field, "00:00"));
+
+    let date_fields: Vec<&str> = day_part.split('-').collect();
+    if date_fields.len() != 3 {
+        return Err(invalid);
+    }
+
+    let year: i16 = match date_fields[0].strip_prefix('b') {
+        // Year n BC is the astronomical year 1 - n
+        Some(bc_year) => 1 - bc_year.parse::<i16>().map_err(|_| invalid)?,
+        None => date_fields[0].parse().map_err(|_| invalid)?,
+    };
+    let month = MONTHS
+        .iter()
+        .position(|m| *m == date_fields[1])
+        .ok_or(invalid)? as u8
+        + 1;
+    let day: f64 = date_fields[2].parse().map_err(|_| invalid)?;
+
+    let mut day_fraction = 0.0;
+    for (i, part) in time_part.trim().split(':').enumerate() {
+        let value: f64 = part.parse().map_err(|_| invalid)?;
+        day_fraction += value / [24.0, 1440.0, 86400.0].get(i).ok_or(invalid)?;
+    }
+
+    // Horizons switches from the Julian to the Gregorian calendar
+    // on 1582 October 15
+    let cal_type = if (year, month, day as u8) < (1582, 10, 15) {
+        time::CalType::Julian
+    } else {
+        time::CalType::Gregorian
+    };
+
+    Ok(time::julian_day(&time::Date {
+        year,
+        month: time::Month::try_from(month)?,
+        decimal_day: day + day_fraction,
+        cal_type,
+    }))
+}
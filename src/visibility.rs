@@ -0,0 +1,260 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------VISIBILITY-----------!
+
+use crate::angle;
+use crate::coordinate;
+use crate::ephemeris;
+use crate::lunar;
+use crate::search;
+use crate::sun;
+use crate::time;
+use crate::Accuracy;
+use std::f64::consts::FRAC_PI_2;
+
+/// Represents the interference of the Moon with the observation of
+/// a target
+#[derive(Copy, Clone, Debug)]
+pub struct MoonInterference {
+    /// Interference score, from 0.0 for none to 1.0 for a full Moon
+    /// at the zenith next to the target
+    pub score: f64,
+    /// Illuminated fraction of the Moon's disk | range: 0.0 - 1.0
+    pub illum_frac: f64,
+    /// Altitude of the Moon | in radians
+    pub moon_alt: f64,
+    /// Angular separation of the Moon from the target | in radians
+    pub separation: f64,
+}
+
+/// Represents how a point of the sky moves relative to the horizon
+/// over a sidereal day
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RiseSetClass {
+    /// Always above the horizon
+    Circumpolar,
+    /// Always below the horizon
+    NeverRises,
+    /// Crosses the horizon twice a day
+    RisesAndSets,
+}
+
+/**
+Computes the windows of time during a night when a target is
+observable
+
+# Returns
+
+* windows: (start, end) of each window | in Julian days, UT
+
+A target is observable when it is above `min_alt` while the Sun is
+below `sun_alt`. The night searched is the one following the local
+mean noon of the date; window bounds are accurate to about a second.
+
+# Arguments
+
+* eq_point: Equatorial point of the target, referred to the
+            equinox of the date | in radians
+* observer: The observer
+* date    : The date; its fraction of day is ignored
+* min_alt : Minimum altitude of the target | in radians
+* sun_alt : Altitude of the Sun below which it's night, such as −18°
+            for astronomical darkness | in radians
+**/
+pub fn observable_window(
+    eq_point: &coordinate::EqPoint,
+    observer: &coordinate::Observer,
+    date: &time::Date,
+    min_alt: f64,
+    sun_alt: f64,
+) -> Vec<(f64, f64)> {
+    let jd_0h = time::local_midnight(date, 0.0);
+    let local_noon = jd_0h + 0.5 + observer.long / angle::TWO_PI;
+    let delta_t = time::delta_t_from_jd(jd_0h) / 86400.0;
+
+    let altitude = |jd: f64, asc: f64, dec: f64| {
+        let sidereal = time::apparent_sidereal_from_jd(jd);
+        let hour_angle = coordinate::hour_angle_from_long(sidereal, observer.long, asc);
+        coordinate::altitude_from_eq(hour_angle, dec, observer.lat)
+    };
+
+    search::intervals(
+        local_noon,
+        local_noon + 1.0,
+        5.0 / 1440.0,
+        1.0 / 86400.0,
+        |jd| {
+            let (sun_asc, sun_dec) = sun::approx_apparent_eq_pos(jd + delta_t);
+            altitude(jd, sun_asc, sun_dec) < sun_alt
+                && altitude(jd, eq_point.asc, eq_point.dec) > min_alt
+        },
+    )
+    .expect("Internal error in visibility::observable_window")
+}
+
+/**
+Finds the date in a year when a target transits closest to local
+midnight
+
+# Returns
+
+(midnight, alt)
+
+* midnight: Local midnight at the start of the best date | in Julian
+            days, UT
+* alt     : Altitude of the target at that midnight, its highest
+            midnight altitude of the year | in radians
+
+Midnight is local mean time, from the observer's longitude.
+
+# Arguments
+
+* eq_point: Equatorial point of the target | in radians
+* year    : Year, in the Gregorian calendar
+* observer: The observer
+**/
+pub fn best_opposition_date(
+    eq_point: &coordinate::EqPoint,
+    year: i16,
+    observer: &coordinate::Observer,
+) -> (f64, f64) {
+    let jan_1 = |year| {
+        time::julian_day(&time::Date {
+            year,
+            month: time::Month::Jan,
+            decimal_day: 1.0,
+            cal_type: time::CalType::Gregorian,
+        })
+    };
+    let start = jan_1(year);
+    let num_days = (jan_1(year + 1) - start).round() as i64;
+
+    let hour_angle_at = |midnight: f64| {
+        let sidereal = time::apparent_sidereal_from_jd(midnight);
+        let hour_angle = coordinate::hour_angle_from_long(sidereal, observer.long, eq_point.asc);
+        angle::limit_pi(hour_angle)
+    };
+
+    let midnight = (0..num_days)
+        .map(|day| start + day as f64 + observer.long / angle::TWO_PI)
+        .min_by(|&a, &b| hour_angle_at(a).abs().total_cmp(&hour_angle_at(b).abs()))
+        .unwrap_or(start);
+
+    let alt = coordinate::altitude_from_eq(hour_angle_at(midnight), eq_point.dec, observer.lat);
+
+    (midnight, alt)
+}
+
+/**
+Computes the interference of the Moon with the observation of a
+target
+
+# Returns
+
+* moon_interference: Interference score and the quantities it's
+                     computed from
+
+The score is the product of the illuminated fraction of the Moon,
+the sine of its altitude (zero below the horizon), and
+(1 + cos ρ) / 2, ρ being its separation from the target. It's a
+heuristic meant for ranking targets and nights, not a sky brightness
+model.
+
+# Arguments
+
+* target_eq: Equatorial point of the target, referred to the
+             equinox of the date | in radians
+* jd       : Julian (Ephemeris) day
+* observer : The observer
+**/
+pub fn moon_interference(
+    target_eq: &coordinate::EqPoint,
+    jd: f64,
+    observer: &coordinate::Observer,
+) -> MoonInterference {
+    let (moon, _) = ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, jd, Accuracy::High);
+    let illum_frac = lunar::illuminated_fraction(lunar::phase_angle(jd, Accuracy::High));
+
+    let jd_ut = time::ut_from_tt(jd);
+    let sidereal = time::apparent_sidereal_from_jd(jd_ut);
+    let hour_angle = coordinate::hour_angle_from_long(sidereal, observer.long, moon.asc);
+    let moon_alt = coordinate::altitude_from_eq(hour_angle, moon.dec, observer.lat);
+
+    let separation = angle::angular_sep(moon.asc, moon.dec, target_eq.asc, target_eq.dec);
+
+    MoonInterference {
+        score: illum_frac * moon_alt.sin().max(0.0) * (1.0 + separation.cos()) / 2.0,
+        illum_frac,
+        moon_alt,
+        separation,
+    }
+}
+
+/**
+Classifies a point of the sky as circumpolar, never rising, or rising
+and setting for an observer
+
+# Returns
+
+(class, circumpolar_dec, never_rises_dec)
+
+* class          : Class of the point
+* circumpolar_dec: Limiting declination of circumpolar points | in
+                   radians
+* never_rises_dec: Limiting declination of points that never rise |
+                   in radians
+
+The geometric horizon is used. For an observer north of the equator,
+points with a declination above `circumpolar_dec` are circumpolar and
+points below `never_rises_dec` never rise; south of the equator, the
+inequalities are reversed.
+
+# Arguments
+
+* eq_point    : Equatorial point | in radians
+* observer_lat: Observer's geographical latitude | in radians
+**/
+pub fn classify(eq_point: &coordinate::EqPoint, observer_lat: f64) -> (RiseSetClass, f64, f64) {
+    let circumpolar_dec = (FRAC_PI_2 - observer_lat.abs()).copysign(observer_lat);
+
+    (
+        classify_at_alt(eq_point.dec, observer_lat, 0.0),
+        circumpolar_dec,
+        -circumpolar_dec,
+    )
+}
+
+// Classifies a declination against the almucantar at `alt`, as used
+// for rising and setting at a standard altitude
+pub(crate) fn classify_at_alt(dec: f64, observer_lat: f64, alt: f64) -> RiseSetClass {
+    let cos_hour_angle =
+        (alt.sin() - observer_lat.sin() * dec.sin()) / (observer_lat.cos() * dec.cos());
+
+    if cos_hour_angle < -1.0 {
+        RiseSetClass::Circumpolar
+    } else if cos_hour_angle > 1.0 {
+        RiseSetClass::NeverRises
+    } else {
+        RiseSetClass::RisesAndSets
+    }
+}
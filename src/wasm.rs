@@ -0,0 +1,183 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------WEBASSEMBLY BINDINGS-----------!
+
+//! Flat `wasm_bindgen` wrappers around the most common pipelines
+//!
+//! Angles are in radians and follow the conventions of the rest of
+//! the library; in particular, geographic longitudes are positive
+//! westward. Results are returned as `Float64Array`s on the
+//! JavaScript side.
+
+use crate::angle;
+use crate::coordinate;
+use crate::ephemeris;
+use crate::lunar;
+use crate::sun;
+use crate::transit;
+use crate::Accuracy;
+use wasm_bindgen::prelude::*;
+
+/**
+Computes the apparent geocentric equatorial position of the Sun
+
+# Returns
+
+[asc, dec, dist]
+
+* asc : Apparent right ascension | in radians
+* dec : Apparent declination | in radians
+* dist: Earth-Sun distance | in AU
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+#[wasm_bindgen(js_name = sunPosition)]
+pub fn sun_position(jd: f64) -> Vec<f64> {
+    eq_pos(&ephemeris::Body::Sun, jd)
+}
+
+/**
+Computes the apparent geocentric equatorial position of the Moon
+
+# Returns
+
+[asc, dec, dist]
+
+* asc : Apparent right ascension | in radians
+* dec : Apparent declination | in radians
+* dist: Earth-Moon distance | in AU
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+#[wasm_bindgen(js_name = moonPosition)]
+pub fn moon_position(jd: f64) -> Vec<f64> {
+    eq_pos(&ephemeris::Body::Moon, jd)
+}
+
+/**
+Computes the apparent geocentric equatorial position of a planet
+
+# Returns
+
+[asc, dec, dist]
+
+* asc : Apparent right ascension | in radians
+* dec : Apparent declination | in radians
+* dist: Earth-planet distance | in AU
+
+# Arguments
+
+* planet: Name of the planet, such as `"mars"`
+* jd    : Julian (Ephemeris) day
+**/
+#[wasm_bindgen(js_name = planetPosition)]
+pub fn planet_position(planet: &str, jd: f64) -> Result<Vec<f64>, JsValue> {
+    match body_from_name(planet)? {
+        ephemeris::Body::Sun | ephemeris::Body::Moon => {
+            Err(JsValue::from_str("Not the name of a planet"))
+        }
+        body => Ok(eq_pos(&body, jd)),
+    }
+}
+
+/**
+Computes the times of rising, transit and setting of a body on a day
+
+# Returns
+
+[rise, transit, set]
+
+* rise   : Time of rising | in Julian days, UT
+* transit: Time of transit | in Julian days, UT
+* set    : Time of setting | in Julian days, UT
+
+An error is thrown if the body is circumpolar or never rises.
+
+# Arguments
+
+* body         : Name of the body, such as `"sun"`, `"moon"` or `"mars"`
+* jd_0h        : Julian day at 0h UT of the day
+* observer_long: Observer's geographic longitude | in radians
+* observer_lat : Observer's geographic latitude | in radians
+**/
+#[wasm_bindgen(js_name = riseTransitSet)]
+pub fn rise_transit_set(
+    body: &str,
+    jd_0h: f64,
+    observer_long: f64,
+    observer_lat: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let observer = coordinate::GeographPoint::new(observer_long, observer_lat);
+    let (rise, transit, set) =
+        transit::body_rise_transit_set(&body_from_name(body)?, jd_0h, &observer, Accuracy::High)
+            .map_err(JsValue::from_str)?;
+
+    Ok(vec![rise, transit, set])
+}
+
+/**
+Computes the phase of the Moon
+
+# Returns
+
+[illum_frac, phase_angle, waxing]
+
+* illum_frac : Illuminated fraction of the disk | range: 0.0 - 1.0
+* phase_angle: Phase angle | in radians
+* waxing     : `1.0` if the Moon is waxing, `0.0` if it is waning
+
+# Arguments
+
+* jd: Julian (Ephemeris) day
+**/
+#[wasm_bindgen(js_name = moonPhase)]
+pub fn moon_phase(jd: f64) -> Vec<f64> {
+    let phase_angle = lunar::phase_angle(jd, Accuracy::High);
+    let (moon, _) = lunar::apparent_ecl_pos(jd, Accuracy::High);
+    let (sun, _) = sun::apparent_ecl_pos(jd, Accuracy::High);
+    let waxing = angle::limit_twoPI(moon.long - sun.long) < std::f64::consts::PI;
+
+    vec![
+        lunar::illuminated_fraction(phase_angle),
+        phase_angle,
+        if waxing { 1.0 } else { 0.0 },
+    ]
+}
+
+fn eq_pos(body: &ephemeris::Body, jd: f64) -> Vec<f64> {
+    let (eq_point, dist) = ephemeris::apparent_eq_pos(body, jd, Accuracy::High);
+    vec![eq_point.asc, eq_point.dec, dist]
+}
+
+fn body_from_name(name: &str) -> Result<ephemeris::Body, JsValue> {
+    match name.to_ascii_lowercase().as_str() {
+        "sun" => Ok(ephemeris::Body::Sun),
+        "moon" => Ok(ephemeris::Body::Moon),
+        "mars" => Ok(ephemeris::Body::Mars),
+        _ => Err(JsValue::from_str("Unknown body name")),
+    }
+}
@@ -0,0 +1,199 @@
+/*
+Copyright (c) 2024 Khairandra Muhamad Nandyka
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+// !-----------ZODIAC-----------!
+
+use crate::angle;
+use crate::ephemeris;
+use crate::search;
+use crate::time;
+use crate::Accuracy;
+
+/// Represents a sign of the zodiac, 30° of ecliptic longitude wide
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Sign {
+    /// Aries, from 0°
+    Aries,
+    /// Taurus, from 30°
+    Taurus,
+    /// Gemini, from 60°
+    Gemini,
+    /// Cancer, from 90°
+    Cancer,
+    /// Leo, from 120°
+    Leo,
+    /// Virgo, from 150°
+    Virgo,
+    /// Libra, from 180°
+    Libra,
+    /// Scorpio, from 210°
+    Scorpio,
+    /// Sagittarius, from 240°
+    Sagittarius,
+    /// Capricorn, from 270°
+    Capricorn,
+    /// Aquarius, from 300°
+    Aquarius,
+    /// Pisces, from 330°
+    Pisces,
+}
+
+const SIGNS: [Sign; 12] = [
+    Sign::Aries,
+    Sign::Taurus,
+    Sign::Gemini,
+    Sign::Cancer,
+    Sign::Leo,
+    Sign::Virgo,
+    Sign::Libra,
+    Sign::Scorpio,
+    Sign::Sagittarius,
+    Sign::Capricorn,
+    Sign::Aquarius,
+    Sign::Pisces,
+];
+
+/// Represents an ecliptic longitude as a sign of the zodiac and an
+/// angle within the sign
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ZodiacalLong {
+    /// Sign of the zodiac
+    pub sign: Sign,
+    /// Degrees within the sign, 0 - 29
+    pub deg: i64,
+    /// Minutes of arc
+    pub min: i64,
+    /// Seconds of arc
+    pub sec: f64,
+}
+
+/// Represents the entry of a body into a sign of the zodiac
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ingress {
+    /// Time of the ingress
+    pub time: time::Instant,
+    /// Sign entered
+    pub sign: Sign,
+}
+
+/**
+Computes the sign of the zodiac for an ecliptic longitude
+
+# Returns
+
+* sign: Sign of the zodiac
+
+# Arguments
+
+* ecl_long: Ecliptic longitude | in radians
+**/
+pub fn sign(ecl_long: f64) -> Sign {
+    SIGNS[sign_index(ecl_long)]
+}
+
+/**
+Converts an ecliptic longitude to a sign of the zodiac and an angle
+within the sign
+
+# Returns
+
+* zodiacal_long: Sign of the zodiac, and degrees, minutes and seconds
+                 within it
+
+# Arguments
+
+* ecl_long: Ecliptic longitude | in radians
+**/
+pub fn zodiacal_long(ecl_long: f64) -> ZodiacalLong {
+    let long = angle::limit_360(ecl_long.to_degrees());
+    let index = sign_index(ecl_long);
+    let (deg, min, sec) = angle::dms_deg(long - 30.0 * index as f64);
+
+    ZodiacalLong {
+        sign: SIGNS[index],
+        deg,
+        min,
+        sec,
+    }
+}
+
+/**
+Finds the ingresses of a body into the signs of the zodiac
+
+# Returns
+
+* ingresses: Ingresses of the body, in increasing order of time
+
+The apparent geocentric ecliptic longitude of the body is sampled
+every half day, and each change of sign is refined to about a
+second. A body in retrograde motion re-enters the preceding sign,
+which is reported as an ingress too.
+
+# Arguments
+
+* body     : The body
+* start_jd : Start of the search | in Julian (Ephemeris) days
+* end_jd   : End of the search | in Julian (Ephemeris) days
+* accuracy : Accuracy tier
+**/
+pub fn ingresses(
+    body: &ephemeris::Body,
+    start_jd: f64,
+    end_jd: f64,
+    accuracy: Accuracy,
+) -> Vec<Ingress> {
+    let step = 0.5;
+    let tolerance = 1.0 / 86400.0;
+    let sign_at = |jd: f64| sign_index(ephemeris::apparent_ecl_pos(body, jd, accuracy).0.long);
+
+    let mut ingresses = Vec::new();
+
+    let mut jd = start_jd;
+    let mut index = sign_at(jd);
+    while jd < end_jd {
+        let next_jd = (jd + step).min(end_jd);
+        let next_index = sign_at(next_jd);
+
+        if next_index != index {
+            let changes = search::changes(jd, next_jd, next_jd - jd, tolerance, |t| {
+                sign_at(t) == index
+            })
+            .expect("Internal error in zodiac::ingresses");
+            if let Some(&(time, _)) = changes.first() {
+                ingresses.push(Ingress {
+                    time: time::Instant::from_tt(time),
+                    sign: SIGNS[next_index],
+                });
+            }
+        }
+
+        jd = next_jd;
+        index = next_index;
+    }
+
+    ingresses
+}
+
+// Index of the sign of the zodiac for an ecliptic longitude, 0 - 11
+fn sign_index(ecl_long: f64) -> usize {
+    ((angle::limit_360(ecl_long.to_degrees()) / 30.0) as usize).min(11)
+}
@@ -0,0 +1,93 @@
+// tests/aberration_test.rs
+
+extern crate apollo;
+use apollo::aberration::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apollo::angle;
+    use apollo::nutation;
+    use apollo::planet::{self, Planet};
+    use apollo::Accuracy;
+
+    fn arcsec(x: f64) -> f64 {
+        angle::deg_dmas(0, 0, x).to_radians()
+    }
+
+    fn heliocent_rect(planet: &Planet, jd: f64) -> [f64; 3] {
+        let (long, lat, rad_vec) = planet::heliocent_coords(planet, jd, Accuracy::High);
+        [
+            rad_vec * lat.cos() * long.cos(),
+            rad_vec * lat.cos() * long.sin(),
+            rad_vec * lat.sin(),
+        ]
+    }
+
+    fn geocent_rect(jd_planet: f64, jd_earth: f64) -> [f64; 3] {
+        let mars = heliocent_rect(&Planet::Mars, jd_planet);
+        let earth = heliocent_rect(&Planet::Earth, jd_earth);
+        std::array::from_fn(|i| mars[i] - earth[i])
+    }
+
+    #[test]
+    fn test_astrometric_apparent() {
+        let jd = 2460400.5;
+        let (astrometric, dist) =
+            planet::geocent_astrometric_ecl_pos(&Planet::Mars, jd, Accuracy::High).unwrap();
+        let (apparent, apparent_dist) =
+            planet::geocent_apparent_ecl_pos(&Planet::Mars, jd, Accuracy::High).unwrap();
+        assert_eq!(dist, apparent_dist);
+
+        let (earth_long, _, _) = planet::heliocent_coords(&Planet::Earth, jd, Accuracy::High);
+        let (aberr_long, aberr_lat) = annual(
+            astrometric.long,
+            astrometric.lat,
+            earth_long + std::f64::consts::PI,
+            jd,
+        );
+        let (nut_in_long, _) = nutation::nutation(jd, Accuracy::High);
+
+        let delta_long = angle::limit_pi(apparent.long - astrometric.long);
+        assert!((delta_long - aberr_long - nut_in_long).abs() < 1e-12);
+        assert!((apparent.lat - astrometric.lat - aberr_lat).abs() < 1e-12);
+
+        // The annual aberration reaches about 20.5″
+        assert!(aberr_long.abs() < arcsec(25.0) && aberr_long.abs() > arcsec(5.0));
+        assert!(planet::geocent_astrometric_ecl_pos(&Planet::Earth, jd, Accuracy::Low).is_err());
+    }
+
+    #[test]
+    fn test_planetary() {
+        let jd = 2460400.5;
+
+        // Light-time and annual aberration applied one after the other
+        let geometric = geocent_rect(jd, jd);
+        let dist = geometric.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let mut light_time_corrected = geometric;
+        for _ in 0..3 {
+            let d = light_time_corrected
+                .iter()
+                .map(|x| x * x)
+                .sum::<f64>()
+                .sqrt();
+            light_time_corrected = geocent_rect(jd - light_time(d), jd);
+        }
+        let long = light_time_corrected[1].atan2(light_time_corrected[0]);
+        let lat =
+            light_time_corrected[2].atan2(light_time_corrected[0].hypot(light_time_corrected[1]));
+        let (earth_long, _, _) = planet::heliocent_coords(&Planet::Earth, jd, Accuracy::High);
+        let (aberr_long, aberr_lat) = annual(long, lat, earth_long + std::f64::consts::PI, jd);
+
+        // Planetary aberration from the relative velocity
+        let h = 0.01;
+        let (before, after) = (geocent_rect(jd - h, jd - h), geocent_rect(jd + h, jd + h));
+        let velocity: [f64; 3] = std::array::from_fn(|i| (after[i] - before[i]) / (2.0 * h));
+        let apparent = planetary(&geometric, &velocity, dist);
+        let apparent_long = apparent[1].atan2(apparent[0]);
+        let apparent_lat = apparent[2].atan2(apparent[0].hypot(apparent[1]));
+
+        assert!(angle::limit_pi(apparent_long - long - aberr_long).abs() < arcsec(0.1));
+        assert!((apparent_lat - lat - aberr_lat).abs() < arcsec(0.1));
+    }
+}
@@ -0,0 +1,37 @@
+// tests/angle_test.rs
+
+extern crate apollo;
+use apollo::angle::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_limit_pi() {
+        assert!((limit_pi(3.0 * PI / 2.0) + PI / 2.0).abs() < 1e-12);
+        assert!((limit_pi(-3.0 * PI / 2.0) - PI / 2.0).abs() < 1e-12);
+        assert!((limit_pi(0.5 + 4.0 * TWO_PI) - 0.5).abs() < 1e-12);
+        assert!((limit_pi(-0.5 - 4.0 * TWO_PI) + 0.5).abs() < 1e-12);
+        assert!((limit_pi(PI) - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_limit_half_pi() {
+        assert_eq!(limit_half_pi(0.3), Ok(0.3));
+        assert_eq!(limit_half_pi(FRAC_PI_2 + 1e-12), Ok(FRAC_PI_2));
+        assert_eq!(limit_half_pi(-FRAC_PI_2 - 1e-12), Ok(-FRAC_PI_2));
+        assert!(limit_half_pi(FRAC_PI_2 + 1e-6).is_err());
+        assert!(limit_half_pi(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_limit_24() {
+        assert!((limit_24(25.5) - 1.5).abs() < 1e-12);
+        assert!((limit_24(-1.5) - 22.5).abs() < 1e-12);
+        assert!((limit_24(-48.0)).abs() < 1e-12);
+        assert!((limit_24(12.0) - 12.0).abs() < 1e-12);
+        assert_eq!(limit_24(-1e-17), 0.0);
+    }
+}
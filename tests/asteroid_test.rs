@@ -0,0 +1,42 @@
+// tests/asteroid_test.rs
+
+extern crate apollo;
+use apollo::asteroid::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asteroid_diameter() {
+        // (1) Ceres: H = 3.34 and albedo 0.090 for a diameter of 940 km
+        let diameter = asteroid_diameter(3.34, 0.090);
+        assert!((diameter / 1000.0 - 940.0).abs() < 10.0, "{}", diameter);
+
+        // H = 17.75 and albedo 0.14 for a diameter of 1 km, the size
+        // limit of the NEO surveys
+        let diameter_neo = asteroid_diameter(17.75, 0.14);
+        assert!((diameter_neo - 1000.0).abs() < 10.0, "{}", diameter_neo);
+
+        // A darker asteroid of the same brightness is larger
+        assert!(asteroid_diameter(3.34, 0.05) > diameter);
+    }
+
+    #[test]
+    fn test_asteroid_abs_mag() {
+        let diameter = asteroid_diameter(7.2, 0.15);
+        assert!((asteroid_abs_mag(diameter, 0.15) - 7.2).abs() < 1e-12);
+        assert!((asteroid_albedo(7.2, diameter) - 0.15).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_asteroid_apparent_mag() {
+        // At opposition the phase function is 1
+        let mag = asteroid_apparent_mag(3.34, 0.15, 2.6, 1.6, 0.0);
+        assert!((mag - (3.34 + 5.0 * (2.6_f64 * 1.6).log10())).abs() < 1e-12);
+
+        // 20 degrees from opposition the asteroid is one magnitude fainter
+        let faint = asteroid_apparent_mag(3.34, 0.15, 2.6, 1.6, 20_f64.to_radians());
+        assert!((faint - mag - 1.0).abs() < 0.01, "{}", faint - mag);
+    }
+}
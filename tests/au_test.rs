@@ -0,0 +1,48 @@
+// tests/au_test.rs
+
+extern crate apollo;
+use apollo::au::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constants() {
+        // The light-time constants agree with the speed of light
+        let c = 299792.458;
+        assert!((AU_KM / c / 60.0 - LIGHT_TIME_MINUTES).abs() < 1e-6);
+        assert!((LIGHT_TIME_MINUTES / 1440.0 - LIGHT_TIME_DAYS).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(km_from_au(1.0), AU_KM);
+        assert!((au_from_km(km_from_au(5.2)) - 5.2).abs() < 1e-15);
+        assert!((light_time_days(1.0) * 1440.0 - 8.316746).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parse_distance() {
+        assert_eq!(parse_distance("1.52 AU"), Ok(1.52));
+        assert_eq!(parse_distance("  1.52au "), Ok(1.52));
+        assert_eq!(parse_distance("30"), Ok(30.0));
+        assert_eq!(parse_distance("149597870.7 km"), Ok(1.0));
+        assert!((parse_distance("1.2e9 KM").unwrap() - 1.2e9 / AU_KM).abs() < 1e-12);
+        assert!((parse_distance("8.316746 lmin").unwrap() - 1.0).abs() < 1e-15);
+
+        assert!(parse_distance("1.52 pc").is_err());
+        assert!(parse_distance("AU").is_err());
+        assert!(parse_distance("").is_err());
+    }
+
+    #[test]
+    fn test_format_distance() {
+        assert_eq!(format_distance(1.523679, Unit::AU, 3), "1.524 AU");
+        assert_eq!(format_distance(0.00257, Unit::Km, 0), "384467 km");
+        assert_eq!(format_distance(1.0, Unit::LightMinute, 2), "8.32 lmin");
+
+        let text = format_distance(2.75, Unit::Km, 3);
+        assert!((parse_distance(&text).unwrap() - 2.75).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,64 @@
+// tests/cli_test.rs
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> (bool, String) {
+        let output = Command::new(env!("CARGO_BIN_EXE_apollo-cli"))
+            .args(args)
+            .output()
+            .unwrap();
+
+        (
+            output.status.success(),
+            String::from_utf8(output.stdout).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_jd() {
+        assert_eq!(
+            run(&["jd", "2000-01-01T12:00"]),
+            (true, "2451545.000000\n".to_string())
+        );
+        assert_eq!(run(&["jd", "1957-10-04T19:26:24"]).1, "2436116.310000\n");
+    }
+
+    #[test]
+    fn test_sunrise() {
+        // Boston, 2024 June 20, in EDT
+        let (ok, output) = run(&["sunrise", "42.3333", "71.0833", "2024-06-20", "-4"]);
+        assert!(ok);
+        assert!(output.starts_with("rise    05:07"));
+        assert!(output.contains("set     20:24"));
+    }
+
+    #[test]
+    fn test_moonphase() {
+        // Total solar eclipse of 2024 April 8, at new moon
+        let (ok, output) = run(&["moonphase", "2024-04-08T18:00"]);
+        assert!(ok);
+        assert!(output.starts_with("illuminated 0.0%"));
+    }
+
+    #[test]
+    fn test_planet_position() {
+        // 1992 October 13.0 TD, Meeus example 25.b
+        let (ok, output) = run(&["planet-position", "sun", "1992-10-12T23:59"]);
+        assert!(ok);
+        assert!(output.contains("R.A.  13h13m3"));
+        assert!(output.contains("Dec.  -07°47′"));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(!run(&[]).0);
+        assert!(!run(&["jd", "2024-13-01"]).0);
+        assert!(!run(&["planet-position", "pluto", "2024-01-01"]).0);
+        assert!(!run(&["sunrise", "north", "71", "2024-06-20"]).0);
+    }
+}
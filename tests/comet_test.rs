@@ -0,0 +1,41 @@
+// tests/comet_test.rs
+
+extern crate apollo;
+use apollo::comet::*;
+use apollo::coordinate::EqPoint;
+use std::f64::consts::PI;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apparent_magnitude() {
+        assert_eq!(apparent_magnitude(5.5, 4.0, 1.0, 1.0), 5.5);
+
+        // 5 log 1.55 + 10 log 0.59 = 0.952 - 2.291
+        let mag = apparent_magnitude(5.5, 4.0, 0.59, 1.55);
+        assert!((mag - 4.161).abs() < 0.01, "{}", mag);
+
+        // The same comet farther from the Sun is fainter
+        assert!(apparent_magnitude(5.5, 4.0, 2.0, 1.0) > apparent_magnitude(5.5, 4.0, 1.5, 1.0));
+    }
+
+    #[test]
+    fn test_tail_position_angle() {
+        let comet = EqPoint::new(1.0, 0.2);
+
+        // Sun due east of the comet: the tail points west
+        let east = EqPoint::new(1.3, 0.2);
+        let pa = tail_position_angle(&comet, &east);
+        assert!((pa - 1.5 * PI).abs() < 0.05, "{}", pa);
+
+        // Sun due north of the comet: the tail points south
+        let north = EqPoint::new(1.0, 0.5);
+        assert!((tail_position_angle(&comet, &north) - PI).abs() < 1e-12);
+
+        // Sun due south of the comet: the tail points north
+        let south = EqPoint::new(1.0, -0.3);
+        assert!(tail_position_angle(&comet, &south) < 1e-12);
+    }
+}
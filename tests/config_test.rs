@@ -0,0 +1,58 @@
+// tests/config_test.rs
+
+extern crate apollo;
+use apollo::config::*;
+use apollo::coordinate::Observer;
+use apollo::ephemeris::Body;
+use apollo::time::{self, CalType, DateTime, DayOfMonth, Month};
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The defaults are global, so every check runs in this one test
+    #[test]
+    fn test_config() {
+        let date_time = DateTime {
+            year: 2024,
+            month: Month::Mar,
+            day_of_month: DayOfMonth {
+                day: 20,
+                hr: 16,
+                min: 0,
+                sec: 0.0,
+                time_zone: 0.0,
+            },
+            cal_type: CalType::Gregorian,
+        };
+        assert!(apollo::observed_position(&Body::Sun, &date_time).is_err());
+
+        // Greenwich in the afternoon of the March equinox
+        let greenwich = Observer::new(0.0, 51.4769_f64.to_radians(), 46.0);
+        update(|config| config.observer = Some(greenwich));
+        let point = apollo::observed_position(&Body::Sun, &date_time).unwrap();
+        let (az, alt) = apollo::sun::horizontal_pos(2460390.1666667, &greenwich, Accuracy::High);
+        assert!((point.az - az).abs() < 1e-4);
+        assert!((point.true_alt - alt).abs() < 1e-4);
+        assert!(point.refraction() > 0.0);
+
+        // Colder, denser air refracts more
+        update(|config| config.temp = 253.0);
+        let cold = apollo::observed_position(&Body::Sun, &date_time).unwrap();
+        assert!((cold.refraction() / point.refraction() - 283.0 / 253.0).abs() < 1e-9);
+
+        // A ΔT model is used by every conversion between UT and TT
+        fn no_delta_t(_: i32, _: u8) -> f64 {
+            0.0
+        }
+        assert!(time::tt_from_ut(2460390.0) > 2460390.0);
+        update(|config| config.delta_t = time::DeltaTModel::Function(no_delta_t));
+        assert_eq!(time::tt_from_ut(2460390.0), 2460390.0);
+
+        reset();
+        assert!(get().observer.is_none());
+        assert_eq!(get().accuracy, Accuracy::High);
+        assert!((time::tt_from_ut(2460390.0) - 2460390.0) * 86400.0 > 60.0);
+    }
+}
@@ -0,0 +1,299 @@
+// tests/coordinate_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::*;
+use apollo::planet::earth;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallactic_angle() {
+        // On the meridian, south of the zenith
+        assert!(parallactic_angle(0.0, 0.2, 0.8_f64).abs() < 1e-12);
+        // West of the meridian, the angle is positive
+        assert!(parallactic_angle(0.5, 0.2, 0.8_f64) > 0.0);
+    }
+
+    #[test]
+    fn test_field_rotation_rate() {
+        let (hour_angle, dec, lat): (f64, f64, f64) = (0.7, 0.3, 0.6);
+        let az = azimuth_from_eq(hour_angle, dec, lat);
+        let alt = altitude_from_eq(hour_angle, dec, lat);
+
+        // Numerical derivative of the parallactic angle
+        let dt = 1.0;
+        let dh = earth::ROTATIONAL_ANGULAR_VELOCITY * dt;
+        let expected = (parallactic_angle(hour_angle + dh / 2.0, dec, lat)
+            - parallactic_angle(hour_angle - dh / 2.0, dec, lat))
+            / dt;
+
+        assert!((field_rotation_rate(az, alt, lat) - expected).abs() < 1e-12);
+        assert!(
+            (field_rotation_rate(az as f32, alt as f32, lat as f32) as f64 - expected).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_ecliptic_galactic() {
+        let oblq_eclip = angle::deg_dmas(23, 26, 44.84).to_radians();
+
+        for &(gal_long, gal_lat) in &[(0.0_f64, 0.0_f64), (1.2, -0.4), (4.5, 1.1), (3.0, -1.5)] {
+            let (ecl_long, ecl_lat) = ecliptic_from_galactic(gal_long, gal_lat, oblq_eclip);
+
+            // The same point through B1950.0 equatorial coordinates
+            let asc = asc_from_galactic(gal_long, gal_lat);
+            let dec = dec_from_galactic(gal_long, gal_lat);
+            let expected_long = ecliptic_long_from_eq(asc, dec, oblq_eclip);
+            let expected_lat = ecliptic_lat_from_eq(asc, dec, oblq_eclip);
+            assert!((angle::limit_twoPI(ecl_long - expected_long + 1.0) - 1.0).abs() < 1e-12);
+            assert!((ecl_lat - expected_lat).abs() < 1e-12);
+
+            let (long, lat) = galactic_from_ecliptic(ecl_long, ecl_lat, oblq_eclip);
+            assert!((angle::limit_twoPI(long - gal_long + 1.0) - 1.0).abs() < 1e-12);
+            assert!((lat - gal_lat).abs() < 1e-12);
+        }
+
+        // The north galactic pole
+        let (_, lat) = galactic_from_ecliptic(
+            ecliptic_long_from_eq(192.25_f64.to_radians(), 27.4_f64.to_radians(), oblq_eclip),
+            ecliptic_lat_from_eq(192.25_f64.to_radians(), 27.4_f64.to_radians(), oblq_eclip),
+            oblq_eclip,
+        );
+        assert!((lat - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_azimuth_north() {
+        let lat = 40_f64.to_radians();
+        let quarter = std::f64::consts::FRAC_PI_2;
+
+        // A star on the celestial equator rises due east and sets due west
+        let east = azimuth_north_from_eq(-quarter, 0.0, lat);
+        assert!((east - quarter).abs() < 1e-12);
+        let west = azimuth_north_from_eq(quarter, 0.0, lat);
+        assert!((west - 3.0 * quarter).abs() < 1e-12);
+
+        // Upper culmination south of the zenith
+        let south = azimuth_north_from_eq(0.0, 0.0, lat);
+        assert!((south - 2.0 * quarter).abs() < 1e-12);
+
+        let az = azimuth_from_eq(1.0, 0.3, lat);
+        let az_north = azimuth_north_from_south(az);
+        assert!((0.0..angle::TWO_PI).contains(&az_north));
+        assert!((azimuth_south_from_north(az_north) - angle::limit_twoPI(az)).abs() < 1e-12);
+        assert!((azimuth_north_from_eq(1.0_f32, 0.3, lat as f32) as f64 - az_north).abs() < 1e-6);
+
+        // Azimuths just below 2π stay below it in f32
+        let pi = std::f32::consts::PI;
+        for az in [-3.0 * pi, -pi - 1e-7, pi - 1e-7, 3.0 * pi - 1e-6] {
+            let az_north = azimuth_north_from_south(az);
+            assert!((0.0..2.0 * pi).contains(&az_north), "{}", az_north);
+            let az_south = azimuth_south_from_north(az);
+            assert!((0.0..2.0 * pi).contains(&az_south), "{}", az_south);
+        }
+    }
+
+    #[test]
+    fn test_precomputed_angles() {
+        // Pollux, Meeus example 13.a
+        let asc = 116.328942_f64.to_radians();
+        let dec = 28.026183_f64.to_radians();
+        let oblq_eclip = 23.4392911_f64.to_radians();
+        let oblq = PrecomputedObliquity::new(oblq_eclip);
+
+        assert_eq!(oblq.angle(), oblq_eclip);
+        assert_eq!(
+            ecliptic_long_from_eq(asc, dec, oblq),
+            ecliptic_long_from_eq(asc, dec, oblq_eclip)
+        );
+        assert_eq!(
+            ecliptic_lat_from_eq(asc, dec, oblq),
+            ecliptic_lat_from_eq(asc, dec, oblq_eclip)
+        );
+        let ecl_long = ecliptic_long_from_eq(asc, dec, oblq);
+        let ecl_lat = ecliptic_lat_from_eq(asc, dec, oblq);
+        assert!((asc_from_ecliptic(ecl_long, ecl_lat, oblq) - asc).abs() < 1e-12);
+        assert!((dec_from_ecliptic(ecl_long, ecl_lat, oblq) - dec).abs() < 1e-12);
+
+        // Saturn seen from the U.S. Naval Observatory, Meeus example 13.b
+        let hour_angle = 64.352133_f64.to_radians();
+        let dec = -6.719892_f64.to_radians();
+        let observer_lat = 38.921389_f64.to_radians();
+        let lat = PrecomputedLatitude::new(observer_lat);
+
+        let az = azimuth_from_eq(hour_angle, dec, lat);
+        let alt = altitude_from_eq(hour_angle, dec, lat);
+        assert!((az.to_degrees() - 68.0337).abs() < 1e-4);
+        assert!((alt.to_degrees() - 15.1249).abs() < 1e-4);
+        assert_eq!(az, azimuth_from_eq(hour_angle, dec, observer_lat));
+        assert!((hour_angle_from_horizontal(az, alt, lat) - hour_angle).abs() < 1e-12);
+        assert!((dec_from_horizontal(az, alt, lat) - dec).abs() < 1e-12);
+
+        // Also in single precision
+        let lat_f32 = PrecomputedLatitude::new(observer_lat as f32);
+        let alt_f32 = altitude_from_eq(hour_angle as f32, dec as f32, lat_f32);
+        assert!((alt_f32 as f64 - alt).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_dec_from_horizontal() {
+        // Saturn seen from the U.S. Naval Observatory, Meeus example 13.b
+        let az = 68.0337_f64.to_radians();
+        let alt = 15.1249_f64.to_radians();
+        let lat = 38.921389_f64.to_radians();
+        let dec = dec_from_horizontal(az, alt, lat).to_degrees();
+        assert!((dec - -6.719892).abs() < 1e-4, "{}", dec);
+
+        // The zenith is at the declination of the latitude, whatever
+        // the azimuth
+        for az in [0.0, 1.0, 2.5] {
+            let dec = dec_from_horizontal(az, std::f64::consts::FRAC_PI_2, lat);
+            assert!((dec - lat).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_rect_point() {
+        let p = RectPoint::from_spherical(1.0, -0.4, 2.5);
+        assert!((p.dist() - 2.5).abs() < 1e-15);
+
+        let (long, lat, dist) = p.to_spherical();
+        assert!((long - 1.0).abs() < 1e-15);
+        assert!((lat + 0.4).abs() < 1e-15);
+        assert!((dist - 2.5).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_rotation_matrix() {
+        // Rotating the equatorial frame about the x axis by the obliquity
+        // gives ecliptic coordinates
+        let oblq_eclip = 23.4392911_f64.to_radians();
+        let (asc, dec) = (116.328942_f64.to_radians(), 28.026183_f64.to_radians());
+        let eq = RectPoint::from_spherical(asc, dec, 1.0);
+        let (long, lat, _) = (RotationMatrix::about_x(oblq_eclip) * eq).to_spherical();
+        assert!((long - ecliptic_long_from_eq(asc, dec, oblq_eclip)).abs() < 1e-12);
+        assert!((lat - ecliptic_lat_from_eq(asc, dec, oblq_eclip)).abs() < 1e-12);
+
+        // A rotation of the frame by +90° about z takes the y axis to x
+        let p =
+            RotationMatrix::about_z(std::f64::consts::FRAC_PI_2) * RectPoint::new(0.0, 1.0, 0.0);
+        assert!((p.x - 1.0).abs() < 1e-15 && p.y.abs() < 1e-15);
+
+        let r = RotationMatrix::about_y(0.3) * RotationMatrix::about_z(-1.2);
+        let round_trip = r.transpose() * r;
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = RotationMatrix::identity().m[i][j];
+                assert!((round_trip.m[i][j] - expected).abs() < 1e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hour_angle_at_altitude() {
+        let (dec, lat): (f64, f64) = (0.3, 0.6);
+        let alt = -0.01;
+        let hour_angle = hour_angle_at_altitude(alt, dec, lat).unwrap();
+        assert!((altitude_from_eq(hour_angle, dec, lat) - alt).abs() < 1e-12);
+        assert!((altitude_from_eq(-hour_angle, dec, lat) - alt).abs() < 1e-12);
+
+        // On the equator, a body on the celestial equator is up for
+        // half a day
+        let semi_diurnal_arc = hour_angle_at_altitude(0.0, 0.0, 0.0).unwrap();
+        assert!((semi_diurnal_arc - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+
+        // At 60° N, a body at 40° N is circumpolar, and one at 40° S
+        // never rises
+        let lat = 60_f64.to_radians();
+        assert!(hour_angle_at_altitude(0.0, 40_f64.to_radians(), lat).is_none());
+        assert!(hour_angle_at_altitude(0.0, -40_f64.to_radians(), lat).is_none());
+        assert!(hour_angle_at_altitude(0.0_f32, 0.1, lat as f32).is_some());
+    }
+
+    #[test]
+    fn test_zenith_distance() {
+        let alt = 0.4_f64;
+        assert!((zenith_distance(alt) - (std::f64::consts::FRAC_PI_2 - alt)).abs() < 1e-15);
+        assert!((altitude_from_zenith_distance(zenith_distance(alt)) - alt).abs() < 1e-15);
+        assert!((zenith_distance(0.4_f32) - 1.1707964).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_observed_point() {
+        // A body whose true altitude is 0 is raised by about 29′
+        let point = ObservedPoint::from_true_alt(0.2, 0.0, 1010.0, 283.0);
+        assert_eq!(point.az, 0.2);
+        assert!((point.refraction().to_degrees() * 60.0 - 28.9).abs() < 0.2);
+
+        // Removing the refraction recovers the true altitude within 4″
+        let true_alt = 10_f64.to_radians();
+        let point = ObservedPoint::from_true_alt(0.0, true_alt, 1010.0, 283.0);
+        let back = ObservedPoint::from_refracted_alt(0.0, point.refracted_alt, 1010.0, 283.0);
+        assert!((back.true_alt - true_alt).abs() < angle::deg_dmas(0, 0, 4.0).to_radians());
+        assert!(
+            (point.refracted_zenith_distance() - point.true_zenith_distance() + point.refraction())
+                .abs()
+                < 1e-15
+        );
+
+        // No refraction well below the horizon
+        let point = ObservedPoint::from_true_alt(0.0, -0.1, 1010.0, 283.0);
+        assert_eq!(point.refraction(), 0.0);
+        assert!(point.airmass().is_none());
+
+        // Airmass is 1 at the zenith, about 2 at 30° and 38 at the
+        // horizon
+        let airmass = |alt: f64| {
+            ObservedPoint::from_refracted_alt(0.0, alt.to_radians(), 1010.0, 283.0)
+                .airmass()
+                .unwrap()
+        };
+        assert!((airmass(90.0) - 1.0).abs() < 1e-3);
+        assert!((airmass(30.0) - 1.995).abs() < 1e-2);
+        assert!((airmass(0.0) - 38.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_horizontal_from_ecliptic() {
+        let oblq_eclip = PrecomputedObliquity::new(23.4392911_f64.to_radians());
+        let lat = PrecomputedLatitude::new(38.921389_f64.to_radians());
+        let local_sidereal = 1.3_f64;
+
+        for &(ecl_long, ecl_lat) in &[(0.0, 0.0), (2.0, 0.1), (4.5, -0.08), (5.9, 1.2)] {
+            let (asc, dec) = apollo::eq_from_ecliptic!(ecl_long, ecl_lat, oblq_eclip);
+            let hour_angle = hour_angle_from_sidereal(local_sidereal, asc);
+            let (az, alt) = apollo::horizontal_from_eq!(hour_angle, dec, lat);
+
+            let (az_direct, alt_direct) =
+                horizontal_from_ecliptic(ecl_long, ecl_lat, oblq_eclip, local_sidereal, lat);
+            assert!(angle::limit_pi(az_direct - az).abs() < 1e-12);
+            assert!((alt_direct - alt).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ha_point() {
+        let eq_point = EqPoint::new(0.3, -0.2);
+        let local_sidereal = 6.1;
+        let ha_point = HaPoint::from_eq(&eq_point, local_sidereal);
+        assert!(
+            (ha_point.hour_angle - angle::limit_pi(hour_angle_from_sidereal(local_sidereal, 0.3)))
+                .abs()
+                < 1e-15
+        );
+        assert!(ha_point.hour_angle.abs() <= std::f64::consts::PI);
+        assert_eq!(ha_point.dec, eq_point.dec);
+
+        let back = ha_point.to_eq(local_sidereal);
+        assert!((back.asc - eq_point.asc).abs() < 1e-12);
+        assert_eq!(back.dec, eq_point.dec);
+
+        let lat = 0.7;
+        let (az, alt) = ha_point.horizontal(lat);
+        assert!((az - azimuth_from_eq(ha_point.hour_angle, eq_point.dec, lat)).abs() < 1e-15);
+        assert!((alt - altitude_from_eq(ha_point.hour_angle, eq_point.dec, lat)).abs() < 1e-15);
+    }
+}
@@ -1,53 +1,74 @@
 // tests/earth_test.rs
 
 extern crate apollo;
-use crate::apollo::*;
+use apollo::angle;
+use apollo::planet::earth::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::coordinate::GeographPoint;
-    use crate::time;
+    use apollo::coordinate::GeographPoint;
 
     #[test]
     fn test_flattening_factor() {
-        assert!((flattening_factor() - 1.0 / 298.257223563).abs() < 1e-10);
+        assert!((FLATTENING_FACTOR - 1.0 / 298.257223563).abs() < 1e-10);
     }
 
     #[test]
     fn test_equatorial_radius() {
-        assert!((equatorial_radius() - 6378.137).abs() < 1e-3);
+        assert!((EQUATORIAL_RADIUS - 6378.137).abs() < 1e-3);
     }
 
     #[test]
     fn test_polar_radius() {
-        let expected = equatorial_radius() * (1.0 - flattening_factor());
-        assert!((polar_radius() - expected).abs() < 1e-3);
+        let expected = EQUATORIAL_RADIUS * (1.0 - FLATTENING_FACTOR);
+        assert!((POLAR_RADIUS - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_constant_functions() {
+        assert_eq!(flattening_factor(), FLATTENING_FACTOR);
+        assert_eq!(equatorial_radius(), EQUATORIAL_RADIUS);
+        assert_eq!(polar_radius(), POLAR_RADIUS);
+        assert_eq!(rotational_angular_velocity(), ROTATIONAL_ANGULAR_VELOCITY);
     }
 
     #[test]
     fn test_eccentricity_of_meridian() {
-        let f = flattening_factor();
-        let expected = (f * (2.0 - f).sqrt());
+        let f = FLATTENING_FACTOR;
+        let expected = f * (2.0_f64 - f).sqrt();
         assert!((eccentricity_of_meridian() - expected).abs() < 1e-10);
     }
 
     #[test]
     fn test_approximate_geodesic_distance() {
-        let p1 = GeographPoint::new(0.0, 0.0);
-        let p2 = GeographPoint::new(1.0, 1.0);
-        let distance = approximate_geodesic_distance(&p1, &p2);
-        // Approximate value should be adjusted based on your test points
-        assert!((distance - 111.194926).abs() < 1e-2);
+        // Paris and Washington, Meeus example 11.c
+        let paris = GeographPoint::new(
+            angle::deg_dmas(-2, 20, 14.0).to_radians(),
+            angle::deg_dmas(48, 50, 11.0).to_radians(),
+        );
+        let washington = GeographPoint::new(
+            angle::deg_dmas(77, 3, 56.0).to_radians(),
+            angle::deg_dmas(38, 55, 17.0).to_radians(),
+        );
+        let distance = approximate_geodesic_distance(&paris, &washington);
+        assert!((distance - 6166.0).abs() < 1.0);
     }
 
     #[test]
     fn test_geodesic_distance() {
-        let p1 = GeographPoint::new(0.0, 0.0);
-        let p2 = GeographPoint::new(1.0, 1.0);
-        let distance = geodesic_distance(&p1, &p2);
-        // Adjust expected value based on your specific test
-        assert!((distance - 111.194926).abs() < 1e-2);
+        // Paris and Washington, Meeus example 11.c
+        let paris = GeographPoint::new(
+            angle::deg_dmas(-2, 20, 14.0).to_radians(),
+            angle::deg_dmas(48, 50, 11.0).to_radians(),
+        );
+        let washington = GeographPoint::new(
+            angle::deg_dmas(77, 3, 56.0).to_radians(),
+            angle::deg_dmas(38, 55, 17.0).to_radians(),
+        );
+        let distance = geodesic_distance(&paris, &washington);
+        assert!((distance - 6181.63).abs() < 1e-2);
     }
 
     #[test]
@@ -60,43 +81,43 @@ mod tests {
 
     #[test]
     fn test_distance_from_center() {
-        let lat = 0.0; // Example latitude
+        let lat: f64 = 0.0; // Example latitude
         let expected = 0.9983271 + 0.0016764 * (2.0 * lat).cos() - 0.0000035 * (4.0 * lat).cos();
         assert!((distance_from_center(lat) - expected).abs() < 1e-7);
     }
 
     #[test]
     fn test_rotational_angular_velocity() {
-        assert!((rotational_angular_velocity() - 0.00007292114992).abs() < 1e-14);
+        assert!((ROTATIONAL_ANGULAR_VELOCITY - 0.00007292114992).abs() < 1e-14);
     }
 
     #[test]
     fn test_radius_of_parallel() {
-        let lat = 0.1; // Example latitude
-        let expected = equatorial_radius() * lat.cos()
+        let lat: f64 = 0.1; // Example latitude
+        let expected = EQUATORIAL_RADIUS * lat.cos()
             / (1.0 - (eccentricity_of_meridian() * lat.sin()).powi(2)).sqrt();
         assert!((radius_of_parallel(lat) - expected).abs() < 1e-2);
     }
 
     #[test]
     fn test_linear_velocity_at_lat() {
-        let lat = 0.1; // Example latitude
-        let expected = rotational_angular_velocity() * radius_of_parallel(lat);
+        let lat: f64 = 0.1; // Example latitude
+        let expected = ROTATIONAL_ANGULAR_VELOCITY * radius_of_parallel(lat);
         assert!((linear_velocity_at_lat(lat) - expected).abs() < 1e-2);
     }
 
     #[test]
     fn test_radius_of_curvature() {
-        let lat = 0.1; // Example latitude
+        let lat: f64 = 0.1; // Example latitude
         let e = eccentricity_of_meridian();
         let expected =
-            equatorial_radius() * (1.0 - e * e) / (1.0 - (e * lat.sin()).powi(2)).powf(1.5);
+            EQUATORIAL_RADIUS * (1.0 - e * e) / (1.0 - (e * lat.sin()).powi(2)).powf(1.5);
         assert!((radius_of_curvature(lat) - expected).abs() < 1e-2);
     }
 
     #[test]
     fn test_geograph_geocent_lat_diff() {
-        let lat = 0.1; // Example latitude
+        let lat: f64 = 0.1; // Example latitude
         let expected = angle::deg_dmas(0, 0, 692.73) * (2.0 * lat).sin()
             - angle::deg_dmas(0, 0, 1.16) * (4.0 * lat).sin();
         assert!((geograph_geocent_lat_diff(lat) - expected).abs() < 1e-2);
@@ -104,23 +125,74 @@ mod tests {
 
     #[test]
     fn test_equation_of_time() {
-        let jd = 2451545.0; // Example Julian Day
-        let sun_asc = 0.0; // Example right ascension of the Sun
-        let nut_long = 0.0; // Example nutation correction for longitude
-        let true_obliquity = 0.0; // Example true obliquity of the ecliptic
-        let expected = 0.0; // Example expected result
-        assert!((equation_of_time(jd, sun_asc, nut_long, true_obliquity) - expected).abs() < 1e-2);
+        // 1992 October 13.0 TD, Meeus example 28.a
+        let jd = 2448908.5;
+        let sun_asc = 198.378178_f64.to_radians();
+        let nut_long = angle::deg_dmas(0, 0, 15.908).to_radians();
+        let true_obliquity = 23.44023_f64.to_radians();
+        let expected = 3.427351_f64.to_radians();
+        assert!((equation_of_time(jd, sun_asc, nut_long, true_obliquity) - expected).abs() < 1e-6);
     }
 
     #[test]
     fn test_angle_between_diurnal_path_and_horizon() {
-        let dec = 0.1; // Example declination
-        let observer_lat = 0.1; // Example observer's latitude
-        let expected = (1.0 - (dec.tan() * observer_lat.tan()).powi(2))
-            .sqrt()
-            .atan();
+        let dec: f64 = 0.1; // Example declination
+        let observer_lat: f64 = 0.1; // Example observer's latitude
+        let b = dec.tan() * observer_lat.tan();
+        let expected = ((1.0 - b * b).sqrt() * dec.cos()).atan2(observer_lat.tan());
         assert!(
             (angle_between_diurnal_path_and_horizon(dec, observer_lat) - expected).abs() < 1e-2
         );
     }
+
+    #[test]
+    fn test_orbital_eccentricity() {
+        // 1992 October 13.0 TD, Meeus example 25.a
+        assert!((orbital_eccentricity(2448908.5) - 0.016711668).abs() < 1e-9);
+        assert!((long_of_perihelion(2451545.0).to_degrees() - 102.93735).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_true_anomaly() {
+        // 1992 October 13.0 TD, Meeus example 25.a
+        assert!((true_anomaly(2448908.5).to_degrees() - 277.09665).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_orbital_velocity() {
+        // Fastest near perihelion in early January, slowest near aphelion
+        // in early July, about 30.29 and 29.29 km/s
+        let perihelion = orbital_velocity(2460314.5);
+        let aphelion = orbital_velocity(2460496.5);
+        assert!((perihelion - 30.29).abs() < 0.01, "{}", perihelion);
+        assert!((aphelion - 29.29).abs() < 0.01, "{}", aphelion);
+
+        // 1992 October 13.0 TD, at R = 0.99766 AU
+        assert!((orbital_velocity(2448908.5) - 29.855).abs() < 0.005);
+    }
+
+    #[test]
+    fn test_geograph_from_rect() {
+        use apollo::coordinate::RectPoint;
+
+        // Forward conversion of a point 400 km above 35° N, 60° W
+        let (long, lat, height) = (60_f64.to_radians(), 35_f64.to_radians(), 400.0);
+        let ecc_sqr = FLATTENING_FACTOR * (2.0 - FLATTENING_FACTOR);
+        let prime_vertical = EQUATORIAL_RADIUS / (1.0 - ecc_sqr * lat.sin().powi(2)).sqrt();
+        let rect_point = RectPoint::new(
+            (prime_vertical + height) * lat.cos() * long.cos(),
+            -(prime_vertical + height) * lat.cos() * long.sin(),
+            (prime_vertical * (1.0 - ecc_sqr) + height) * lat.sin(),
+        );
+
+        let (geograph_point, h) = geograph_from_rect(&rect_point);
+        assert!((geograph_point.long - long).abs() < 1e-12);
+        assert!((geograph_point.lat - lat).abs() < 1e-12);
+        assert!((h - height).abs() < 1e-6);
+
+        // Above the north pole
+        let (geograph_point, h) = geograph_from_rect(&RectPoint::new(0.0, 0.0, 7000.0));
+        assert!((geograph_point.lat - 90_f64.to_radians()).abs() < 1e-12);
+        assert!((h - (7000.0 - POLAR_RADIUS)).abs() < 1e-6);
+    }
 }
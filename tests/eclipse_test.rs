@@ -0,0 +1,207 @@
+// tests/eclipse_test.rs
+
+extern crate apollo;
+use apollo::eclipse::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apollo::ephemeris::{self, Body};
+    use apollo::lunar;
+    use apollo::Accuracy;
+
+    #[test]
+    fn test_earth_shadow() {
+        // Greatest eclipse of the total lunar eclipse of 2025 March 14,
+        // 6h 58m 43s TD, of umbral magnitude 1.178
+        let jd = 2460748.79078;
+        let shadow = earth_shadow(jd);
+
+        let (moon, _) = ephemeris::apparent_eq_pos(&Body::Moon, jd, Accuracy::High);
+        let moon_semidiameter = lunar::semidiameter(shadow.earth_moon_dist);
+        let umbral_mag = (shadow.umbra_radius + moon_semidiameter
+            - moon.angular_sep(&shadow.center))
+            / (2.0 * moon_semidiameter);
+        assert!((umbral_mag - 1.178).abs() < 0.01);
+
+        // The penumbra is wider than the umbra by the Sun's diameter
+        let sun_diameter = (shadow.penumbra_radius - shadow.umbra_radius).to_degrees();
+        assert!((sun_diameter - 0.537).abs() < 0.01);
+
+        let (sun, _) = ephemeris::apparent_eq_pos(&Body::Sun, jd, Accuracy::High);
+        assert!((sun.angular_sep(&shadow.center) - std::f64::consts::PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_saros_series() {
+        // Total solar eclipse of 2024 April 8, 30th of 71 in Saros 139
+        let eclipse = saros_series(2460409.262).unwrap();
+        assert_eq!(eclipse.kind, EclipseKind::Solar);
+        assert_eq!((eclipse.saros, eclipse.inex), (139, 50));
+        assert_eq!((eclipse.member, eclipse.members), (30, 71));
+
+        // Total solar eclipse of 2017 August 21, 22nd of 77 in Saros 145
+        let eclipse = saros_series(2457987.268).unwrap();
+        assert_eq!(
+            (eclipse.saros, eclipse.member, eclipse.members),
+            (145, 22, 77)
+        );
+
+        // Solar eclipses one Inex apart, 358 lunations, are in
+        // consecutive Saros series
+        let next = saros_series(2460409.262 + 358.0 * apollo::lunar::SYNODIC_MONTH).unwrap();
+        assert_eq!((next.saros, next.inex), (140, 50));
+
+        // Total lunar eclipse of 2022 November 8, 20th of 72 in Saros 136
+        let eclipse = saros_series(2459891.958).unwrap();
+        assert_eq!(eclipse.kind, EclipseKind::Lunar);
+        assert_eq!(
+            (eclipse.saros, eclipse.member, eclipse.members),
+            (136, 20, 72)
+        );
+
+        // No eclipse at the new Moon of 2000 January 6
+        assert!(saros_series(2451550.26).is_err());
+        assert!(saros_series(2451550.26 + 7.0).is_err());
+    }
+
+    #[test]
+    fn test_earth_shadow_radii_with_uncertainty() {
+        let jd = 2460748.79078;
+        let shadow = earth_shadow(jd);
+        let (umbra, penumbra) = earth_shadow_radii_with_uncertainty(jd);
+
+        assert_eq!(umbra.value, shadow.umbra_radius);
+        assert_eq!(penumbra.value, shadow.penumbra_radius);
+
+        // Half a percent of the Moon's parallax, about 17″
+        let sigma = umbra.sigma.to_degrees() * 3600.0;
+        assert!((sigma - 17.0).abs() < 2.0);
+        assert_eq!(penumbra.sigma, umbra.sigma);
+    }
+
+    #[test]
+    fn test_map_magnitude() {
+        // The total solar eclipse of 2024 April 8, greatest at 18h17m UT
+        let jd_max = 2460409.2623;
+        let city = |long: f64, lat: f64| GridSpec {
+            lat_start: lat.to_radians(),
+            lat_end: lat.to_radians(),
+            n_lat: 1,
+            long_start: long.to_radians(),
+            long_end: long.to_radians(),
+            n_long: 1,
+        };
+
+        // Dallas, within the path of totality
+        let dallas = map_magnitude(jd_max, &city(96.80, 32.78))[0];
+        assert!(dallas.magnitude > 1.0, "{}", dallas.magnitude);
+        assert!((dallas.obscuration - 1.0).abs() < 1e-9);
+
+        // New York, a deep partial eclipse
+        let new_york = map_magnitude(jd_max, &city(74.01, 40.71))[0];
+        assert!(
+            (new_york.magnitude - 0.91).abs() < 0.01,
+            "{}",
+            new_york.magnitude
+        );
+        assert!(
+            (new_york.obscuration - 0.90).abs() < 0.01,
+            "{}",
+            new_york.obscuration
+        );
+
+        // Tokyo, at night
+        let tokyo = map_magnitude(jd_max, &city(-139.69, 35.69))[0];
+        assert_eq!(tokyo.magnitude, 0.0);
+
+        let grid = GridSpec {
+            lat_start: 0.0,
+            lat_end: 0.6,
+            n_lat: 3,
+            long_start: 1.0,
+            long_end: 1.9,
+            n_long: 4,
+        };
+        let map = map_magnitude(jd_max, &grid);
+        assert_eq!(map.len(), 12);
+        assert!((map[5].geograph_point.lat - 0.3).abs() < 1e-12);
+        assert!((map[5].geograph_point.long - 1.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_local_circumstances() {
+        use apollo::coordinate::Observer;
+
+        // Dallas, 2024 April 8: partial from 17h23m to 20h02m UT, total
+        // from 18h40m43s to 18h44m35s UT
+        let jd_max = 2460409.2623;
+        let ut = |h: f64, m: f64, s: f64| 2460408.5 + (h + m / 60.0 + s / 3600.0) / 24.0;
+        let dallas = Observer::new(96.80_f64.to_radians(), 32.78_f64.to_radians(), 130.0);
+
+        let local = local_circumstances(&dallas, jd_max).unwrap();
+        assert!((local.c1.jd_ut - ut(17.0, 23.0, 0.0)).abs() < 2.0 / 1440.0);
+        assert!((local.c4.jd_ut - ut(20.0, 2.0, 0.0)).abs() < 2.0 / 1440.0);
+
+        let (c2, c3) = (local.c2.unwrap(), local.c3.unwrap());
+        assert!((c2.jd_ut - ut(18.0, 40.0, 43.0)).abs() < 20.0 / 86400.0);
+        assert!((c3.jd_ut - ut(18.0, 44.0, 35.0)).abs() < 20.0 / 86400.0);
+        assert!(c2.jd_ut < local.max.jd_ut && local.max.jd_ut < c3.jd_ut);
+
+        assert!(
+            local.magnitude > 1.0 && local.magnitude < 1.02,
+            "{}",
+            local.magnitude
+        );
+        assert_eq!(local.obscuration, 1.0);
+        let sun_alt = local.sun_alt.to_degrees();
+        assert!(sun_alt > 60.0 && sun_alt < 70.0, "{}", sun_alt);
+
+        // A partial eclipse in New York, none in Tokyo
+        let new_york = Observer::new(74.01_f64.to_radians(), 40.71_f64.to_radians(), 0.0);
+        let local = local_circumstances(&new_york, jd_max).unwrap();
+        assert!(local.c2.is_none() && local.c3.is_none());
+        assert!((local.magnitude - 0.91).abs() < 0.01);
+
+        let tokyo = Observer::new(-139.69_f64.to_radians(), 35.69_f64.to_radians(), 0.0);
+        assert!(local_circumstances(&tokyo, jd_max).is_err());
+    }
+
+    #[test]
+    fn test_local_circumstances_with_model() {
+        use apollo::coordinate::Observer;
+        use apollo::time::DeltaTModel;
+
+        let jd_max = 2460409.2623;
+        let dallas = Observer::new(96.80_f64.to_radians(), 32.78_f64.to_radians(), 130.0);
+
+        // The default model agrees with the config default
+        let default = local_circumstances(&dallas, jd_max).unwrap();
+        let espenak_meeus =
+            local_circumstances_with_model(&dallas, jd_max, &DeltaTModel::EspenakMeeus).unwrap();
+        assert_eq!(default.c2, espenak_meeus.c2);
+
+        // A ΔT of 0 instead of about 74 s turns the Earth by about 19′
+        // under the shadow, which moves the contacts in TT, and the UT
+        // of the contacts follows the model
+        let zero = DeltaTModel::Function(|_, _| 0.0);
+        let no_delta_t = local_circumstances_with_model(&dallas, jd_max, &zero).unwrap();
+        let (c2, c2_zero) = (espenak_meeus.c2.unwrap(), no_delta_t.c2.unwrap());
+        assert!((c2.jd_tt - c2_zero.jd_tt).abs() > 1.0 / 86400.0);
+        assert_eq!(c2_zero.jd_ut, c2_zero.jd_tt);
+        let delta_t = DeltaTModel::EspenakMeeus.delta_t(2024, 4);
+        assert!((c2.delta_t() - delta_t).abs() < 1e-3);
+
+        let grid = GridSpec {
+            lat_start: 32.78_f64.to_radians(),
+            lat_end: 32.78_f64.to_radians(),
+            n_lat: 1,
+            long_start: 96.80_f64.to_radians(),
+            long_end: 96.80_f64.to_radians(),
+            n_long: 1,
+        };
+        let magnitude = map_magnitude_with_model(jd_max, &grid, &DeltaTModel::EspenakMeeus)[0];
+        let magnitude_zero = map_magnitude_with_model(jd_max, &grid, &zero)[0];
+        assert_ne!(magnitude.magnitude, magnitude_zero.magnitude);
+    }
+}
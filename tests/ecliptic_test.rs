@@ -0,0 +1,21 @@
+// tests/ecliptic_test.rs
+
+extern crate apollo;
+use apollo::ecliptic::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_obliquity_rate() {
+        // About -46.8″ per Julian century at J2000.0
+        let rate = mean_obliquity_rate(2451545.0).to_degrees() * 3600.0 * 36525.0;
+        assert!((rate + 46.815).abs() < 0.01);
+
+        let jd = 2462088.69;
+        let numerical =
+            (mean_obliquity_laskar(jd + 10.0) - mean_obliquity_laskar(jd - 10.0)) / 20.0;
+        assert!((mean_obliquity_rate(jd) - numerical).abs() < 1e-15);
+    }
+}
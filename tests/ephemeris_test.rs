@@ -0,0 +1,73 @@
+// tests/ephemeris_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::ephemeris::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apparent_eq_pos_sun() {
+        // 1992 October 13.0 TD, Meeus example 25.b
+        let (eq_point, dist) = apparent_eq_pos(&Body::Sun, 2448908.5, apollo::Accuracy::High);
+        assert!((eq_point.asc - angle::deg_hms(13, 13, 30.749).to_radians()).abs() < 5e-6);
+        assert!((eq_point.dec - angle::deg_dmas(-7, 47, 1.74).to_radians()).abs() < 5e-6);
+        assert!((dist - 0.99760775).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_range() {
+        let positions: Vec<_> = range(Body::Moon, 2448724.5, 2448725.5, 0.25)
+            .unwrap()
+            .collect();
+        assert_eq!(positions.len(), 5);
+        assert_eq!(positions[4].0, 2448725.5);
+
+        let (jd, eq_point, _) = &positions[2];
+        let (expected, _) = apparent_eq_pos(&Body::Moon, *jd, apollo::Accuracy::High);
+        assert_eq!(eq_point.asc, expected.asc);
+        assert_eq!(eq_point.dec, expected.dec);
+
+        assert!(range(Body::Moon, 2448724.5, 2448725.5, 0.0).is_err());
+        assert!(range(Body::Moon, 2448724.5, 2448725.5, -0.25).is_err());
+    }
+
+    #[test]
+    fn test_cache() {
+        let arcsec = angle::deg_dmas(0, 0, 1.0).to_radians();
+        let mut cache = Cache::new(Body::Moon, 0.5).unwrap();
+
+        // Nodes are served exactly
+        let (eq_point, dist) = cache.apparent_eq_pos(2448724.5);
+        let (expected, expected_dist) =
+            apparent_eq_pos(&Body::Moon, 2448724.5, apollo::Accuracy::High);
+        assert!((eq_point.asc - expected.asc).abs() < 1e-12);
+        assert!((eq_point.dec - expected.dec).abs() < 1e-12);
+        assert!((dist - expected_dist).abs() < 1e-15);
+
+        // And epochs between them within the error bound, under 0.1″
+        for i in 0..20 {
+            let jd = 2448724.5 + 0.37 * i as f64;
+            let (eq_point, dist) = cache.apparent_eq_pos(jd);
+            let (expected, expected_dist) =
+                apparent_eq_pos(&Body::Moon, jd, apollo::Accuracy::High);
+            let error = eq_point.angular_sep(&expected);
+            assert!(error <= cache.error_bound(jd));
+            assert!(error < 0.1 * arcsec);
+            assert!((dist - expected_dist).abs() < 1e-8);
+        }
+        assert!(cache.error_bound(2448725.0) < 0.5 * arcsec);
+
+        let mut cache = Cache::new(Body::Sun, 4.0)
+            .unwrap()
+            .accuracy(apollo::Accuracy::Low);
+        let (eq_point, _) = cache.apparent_eq_pos(2448908.5);
+        let (expected, _) = apparent_eq_pos(&Body::Sun, 2448908.5, apollo::Accuracy::Low);
+        assert!(eq_point.angular_sep(&expected) < 0.1 * arcsec);
+
+        assert!(Cache::new(Body::Moon, 0.0).is_err());
+        assert!(Cache::new(Body::Moon, f64::NAN).is_err());
+    }
+}
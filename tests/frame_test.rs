@@ -0,0 +1,84 @@
+// tests/frame_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::EqPoint;
+use apollo::frame::*;
+use apollo::precession;
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arcsec(x: f64) -> f64 {
+        angle::deg_dmas(0, 0, x).to_radians()
+    }
+
+    #[test]
+    fn test_frame_bias() {
+        // The ICRS and FK5 J2000.0 frames differ by a few
+        // hundredths of an arcsecond
+        let icrs = SkyCoord::new(Frame::Icrs, 1.2, 0.4);
+        let fk5 = icrs.transform_to(Frame::Fk5J2000, Accuracy::High);
+        let sep = icrs.angular_sep(&fk5, Accuracy::High);
+        assert!(arcsec(0.01) < angle::angular_sep(1.2, 0.4, fk5.long, fk5.lat));
+        assert!(angle::angular_sep(1.2, 0.4, fk5.long, fk5.lat) < arcsec(0.03));
+        assert!(sep < arcsec(0.001));
+    }
+
+    #[test]
+    fn test_equinox_of_date() {
+        // θ Persei on 2028 November 13.19 TD, Meeus example 23.a: the
+        // nutation adds 15.843″ in right ascension and 6.218″ in
+        // declination to the mean place
+        let jd = 2462088.69;
+        let mean = EqPoint::new(41.5472_f64.to_radians(), 49.3485_f64.to_radians());
+        let j2000 = precession::precess_eq(&mean, jd, 2451545.0);
+        let true_of_date = SkyCoord::from_eq(Frame::Fk5J2000, &j2000)
+            .transform_to(Frame::EquinoxOfDate(jd), Accuracy::High);
+
+        assert!((true_of_date.long - mean.asc - arcsec(15.843)).abs() < arcsec(0.05));
+        assert!((true_of_date.lat - mean.dec - arcsec(6.218)).abs() < arcsec(0.05));
+
+        // And back
+        let back = true_of_date.transform_to(Frame::Fk5J2000, Accuracy::High);
+        assert!((back.long - angle::limit_twoPI(j2000.asc)).abs() < arcsec(1e-6));
+        assert!((back.lat - j2000.dec).abs() < arcsec(1e-6));
+    }
+
+    #[test]
+    fn test_galactic() {
+        // The north galactic pole, at 12h49m, +27.4° in B1950.0
+        let pole = SkyCoord::new(
+            Frame::B1950,
+            angle::deg_hms(12, 49, 0.0).to_radians(),
+            27.4_f64.to_radians(),
+        );
+        let galactic = pole.transform_to(Frame::Galactic, Accuracy::Low);
+        assert_eq!(galactic.frame, Frame::Galactic);
+        assert!((galactic.lat - 90_f64.to_radians()).abs() < arcsec(0.01));
+        assert!(galactic.eq_point().is_none());
+
+        // The galactic center in J2000.0 is at 17h45m37s, −28°56′10″
+        let center = SkyCoord::new(Frame::Galactic, 0.0, 0.0)
+            .transform_to(Frame::Fk5J2000, Accuracy::Low)
+            .eq_point()
+            .unwrap();
+        assert!((center.asc - angle::deg_hms(17, 45, 37.2).to_radians()).abs() < arcsec(15.0));
+        assert!((center.dec - angle::deg_dmas(-28, -56, -10.2).to_radians()).abs() < arcsec(2.0));
+    }
+
+    #[test]
+    fn test_angular_sep_across_frames() {
+        // The same direction in two frames is no separation apart
+        let j2000 = SkyCoord::new(Frame::Fk5J2000, 2.1, -0.3);
+        let b1950 = j2000.transform_to(Frame::B1950, Accuracy::High);
+        assert!(j2000.angular_sep(&b1950, Accuracy::High) < arcsec(0.001));
+
+        // Whereas comparing the bare coordinates is off by the
+        // precession over 50 years, near 0.7°
+        let naive = angle::angular_sep(j2000.long, j2000.lat, b1950.long, b1950.lat);
+        assert!(naive > 0.5_f64.to_radians());
+    }
+}
@@ -0,0 +1,120 @@
+// tests/geomag_test.rs
+
+#![cfg(feature = "geomag")]
+
+extern crate apollo;
+use apollo::coordinate::GeographPoint;
+use apollo::geomag::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(east_long: f64, lat: f64) -> GeographPoint {
+        GeographPoint::new(-east_long.to_radians(), lat.to_radians())
+    }
+
+    #[test]
+    fn test_declination() {
+        // Boulder, Colorado; NOAA gives 8.2 degrees east for 2020
+        let boulder = point(-105.27, 40.015);
+        let d = declination(&boulder, 2020.0).unwrap().to_degrees();
+        assert!((d - 8.2).abs() < 0.2, "{}", d);
+
+        // Sydney lies east of magnetic north, London almost on the agonic line
+        assert!(declination(&point(151.2, -33.87), 2020.0).unwrap() > 0.2);
+        let d = declination(&point(-0.13, 51.51), 2020.0)
+            .unwrap()
+            .to_degrees();
+        assert!(d.abs() < 0.5, "{}", d);
+    }
+
+    #[test]
+    fn test_inclination() {
+        let boulder = point(-105.27, 40.015);
+        let i = inclination(&boulder, 2020.0).unwrap().to_degrees();
+        assert!((i - 66.4).abs() < 0.3, "{}", i);
+
+        let sydney = point(151.2, -33.87);
+        assert!(inclination(&sydney, 2020.0).unwrap() < 0.0);
+    }
+
+    #[test]
+    fn test_magnetic_field() {
+        let boulder = point(-105.27, 40.015);
+        let f = magnetic_field(&boulder, 1655.0, 2022.5).unwrap();
+        assert!((f.total_intensity() - 52_000.0).abs() < 500.0);
+        assert!((f.declination() - f.east.atan2(f.north)).abs() < 1e-15);
+
+        // The field weakens with height
+        let high = magnetic_field(&boulder, 400_000.0, 2022.5).unwrap();
+        assert!(high.total_intensity() < f.total_intensity());
+
+        assert!(magnetic_field(&boulder, 0.0, 2019.9).is_err());
+        assert!(magnetic_field(&boulder, 0.0, 2025.1).is_err());
+    }
+
+    #[test]
+    fn test_geomagnetic_north_pole() {
+        // IGRF-13 gives 80.65 N, 72.68 W for 2020
+        let pole = geomagnetic_north_pole(2020.0).unwrap();
+        assert!((pole.lat.to_degrees() - 80.65).abs() < 0.1);
+        assert!((pole.long.to_degrees() - 72.68).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_geomagnetic_coords() {
+        let pole = geomagnetic_north_pole(2021.0).unwrap();
+        let at_pole = geomagnetic_coords(&pole, 2021.0).unwrap();
+        assert!((at_pole.lat.to_degrees() - 90.0).abs() < 1e-9);
+
+        // The geographic south pole lies on the zero geomagnetic meridian
+        let south = geomagnetic_coords(&point(0.0, -90.0), 2021.0).unwrap();
+        assert!(south.long.abs() < 1e-9);
+        assert!((south.lat + pole.lat).abs() < 1e-9);
+
+        // Tromso lies well inside the auroral zone
+        let tromso = geomagnetic_coords(&point(18.96, 69.65), 2021.0).unwrap();
+        assert!(
+            (tromso.lat.to_degrees() - 67.4).abs() < 0.2,
+            "{}",
+            tromso.lat.to_degrees()
+        );
+    }
+
+    #[test]
+    fn test_geomagnetic_lat() {
+        let tromso = point(18.96, 69.65);
+        let coords = geomagnetic_coords(&tromso, 2023.0).unwrap();
+        assert_eq!(geomagnetic_lat(&tromso, 2023.0).unwrap(), coords.lat);
+
+        let hobart = point(147.33, -42.88);
+        assert!(geomagnetic_lat(&hobart, 2023.0).unwrap() < -0.8);
+    }
+
+    #[test]
+    fn test_kp_visibility_lat() {
+        assert!((kp_visibility_lat(0.0).unwrap().to_degrees() - 66.5).abs() < 1e-12);
+        assert!((kp_visibility_lat(9.0).unwrap().to_degrees() - 48.05).abs() < 1e-12);
+        assert!(kp_visibility_lat(5.0).unwrap() < kp_visibility_lat(4.0).unwrap());
+        assert!(kp_visibility_lat(-1.0).is_err());
+        assert!(kp_visibility_lat(9.5).is_err());
+    }
+
+    #[test]
+    fn test_aurora_visible() {
+        let tromso = point(18.96, 69.65);
+        assert!(aurora_visible(&tromso, 2023.0, 1.0).unwrap());
+
+        // Geomagnetic latitude of about 57 degrees needs a strong storm
+        let edinburgh = point(-3.19, 55.95);
+        assert!(!aurora_visible(&edinburgh, 2023.0, 3.0).unwrap());
+        assert!(aurora_visible(&edinburgh, 2023.0, 7.0).unwrap());
+
+        let hobart = point(147.33, -42.88);
+        assert!(!aurora_visible(&hobart, 2023.0, 5.0).unwrap());
+        assert!(aurora_visible(&hobart, 2023.0, 9.0).unwrap());
+
+        assert!(aurora_visible(&tromso, 2026.0, 1.0).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+// tests/lib_test.rs
+
+extern crate apollo;
+use apollo::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apollo::time::{CalType, DateTime, DayOfMonth, Month};
+
+    #[test]
+    fn test_sun_position() {
+        // 1992 October 13, 0h TD, Meeus example 25.a: apparent right
+        // ascension 13h 13m 31.4s and declination -7° 47′ 6″
+        let date_time = DateTime {
+            year: 1992,
+            month: Month::Oct,
+            day_of_month: DayOfMonth {
+                day: 12,
+                hr: 23,
+                min: 59,
+                sec: 0.0,
+                time_zone: 0.0,
+            },
+            cal_type: CalType::Gregorian,
+        };
+        let (eq_point, dist) = sun_position(&date_time, Accuracy::High);
+
+        let asc = angle::deg_hms(13, 13, 31.4).to_radians();
+        let dec = angle::deg_dmas(-7, 47, 6.0).to_radians();
+        assert!((eq_point.asc - asc).abs() < angle::deg_dmas(0, 0, 1.0).to_radians() * 15.0);
+        assert!((eq_point.dec - dec).abs() < angle::deg_dmas(0, 0, 5.0).to_radians());
+        assert!((dist - 0.99766).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_moon_position() {
+        // 1992 April 12, 0h TD, Meeus example 47.a: apparent right
+        // ascension 134.688470°, declination 13.768368° and distance
+        // 368409.7 km
+        let date_time = DateTime {
+            year: 1992,
+            month: Month::Apr,
+            day_of_month: DayOfMonth {
+                day: 11,
+                hr: 23,
+                min: 59,
+                sec: 1.0,
+                time_zone: 0.0,
+            },
+            cal_type: CalType::Gregorian,
+        };
+        let (eq_point, dist) = moon_position(&date_time, Accuracy::High);
+
+        assert!((eq_point.asc.to_degrees() - 134.688470).abs() < 0.005);
+        assert!((eq_point.dec.to_degrees() - 13.768368).abs() < 0.005);
+        assert!((dist - 368409.7).abs() < 5.0);
+    }
+}
@@ -0,0 +1,224 @@
+// tests/lunar_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::{EqPoint, Observer};
+use apollo::lunar::*;
+use apollo::time::{CalType, Date, Month};
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geocent_ecl_pos() {
+        // 1992 April 12.0 TD, Meeus example 47.a
+        let (ecl_point, earth_moon_dist) = geocent_ecl_pos(2448724.5, Accuracy::High);
+        assert!((ecl_point.long.to_degrees() - 133.162655).abs() < 1e-6);
+        assert!((ecl_point.lat.to_degrees() - -3.229126).abs() < 1e-6);
+        assert!((earth_moon_dist - 368409.7).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_illuminated_fraction() {
+        // 1992 April 12.0 TD, Meeus example 48.a
+        let phase_angle = phase_angle(2448724.5, Accuracy::High);
+        assert!((phase_angle.to_degrees() - 69.0756).abs() < 1e-3);
+        assert!((illuminated_fraction(phase_angle) - 0.6786).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_new_moon() {
+        // 1977 February 18, 3h 37m 40s TD, Meeus example 49.a
+        let new_moon = 2443192.65093;
+        let half_minute = 30.0 / 86400.0;
+        assert!((next_new_moon(2443180.0) - new_moon).abs() < half_minute);
+        assert!((previous_new_moon(2443200.0) - new_moon).abs() < half_minute);
+        assert!((age(2443200.0) - (2443200.0 - new_moon)).abs() < half_minute);
+
+        let following = next_new_moon(next_new_moon(2443180.0));
+        assert!((following - new_moon - SYNODIC_MONTH).abs() < 0.6);
+    }
+
+    #[test]
+    fn test_lunation_number() {
+        // The new Moon of 2000 January 6 starts Brown lunation 953 and
+        // the month of Shawwal 1420 AH
+        let jd = 2451560.0;
+        assert_eq!(lunation_number(jd, LunationConvention::Meeus), 0);
+        assert_eq!(lunation_number(jd, LunationConvention::Brown), 953);
+        assert_eq!(lunation_number(jd, LunationConvention::Islamic), 17038);
+
+        assert_eq!(lunation_number(jd - 10.0, LunationConvention::Brown), 952);
+    }
+
+    #[test]
+    fn test_crescent_visibility() {
+        // Mecca, around the new Moon of 2024 March 10, 9h UT
+        let observer =
+            Observer::new(-39.83_f64.to_radians(), 21.42_f64.to_radians(), 0.0).time_zone(3.0);
+        let date = |day: f64| Date {
+            year: 2024,
+            month: Month::Mar,
+            decimal_day: day,
+            cal_type: CalType::Gregorian,
+        };
+
+        assert!(crescent_visibility(&observer, &date(9.0)).is_err());
+
+        let young = crescent_visibility(&observer, &date(10.0)).unwrap();
+        assert_eq!(young.class, YallopClass::F);
+        assert!(young.arcl.to_degrees() < 5.0);
+
+        let old = crescent_visibility(&observer, &date(11.0)).unwrap();
+        assert_eq!(old.class, YallopClass::A);
+        assert!(old.q > young.q);
+        assert!(old.arcl > old.arcv.abs() && old.arcl > old.daz.abs());
+        assert!((old.width.to_degrees() * 60.0 - 0.85).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_next_syzygy() {
+        // The new Moon of 1977 February 18, Meeus example 49.a
+        let (jd, syzygy) = next_syzygy(2443190.0);
+        assert_eq!(syzygy, Syzygy::NewMoon);
+        assert!((jd - 2443192.65093).abs() < 30.0 / 86400.0);
+
+        let (full_moon, syzygy) = next_syzygy(jd);
+        assert_eq!(syzygy, Syzygy::FullMoon);
+        assert!(full_moon - jd > 13.5 && full_moon - jd < 16.0);
+        assert!(phase_angle(full_moon, Accuracy::High).to_degrees() < 6.0);
+
+        assert_eq!(next_syzygy(full_moon).1, Syzygy::NewMoon);
+    }
+
+    #[test]
+    fn test_tidal_force() {
+        // The full Moon of 2024 October 17 came a day after perigee
+        let (full_moon, _) = next_syzygy(2460599.0);
+        assert_eq!(tidal_force(full_moon).class, TideClass::PerigeanSpring);
+
+        // The quarters a week before and after
+        assert_eq!(tidal_force(full_moon - 7.3).class, TideClass::Neap);
+        assert_eq!(tidal_force(full_moon + 7.5).class, TideClass::Neap);
+        assert!(tidal_force(full_moon).index > tidal_force(full_moon - 3.0).index);
+    }
+
+    #[test]
+    fn test_rise_set_azimuth() {
+        let boston =
+            Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0).time_zone(-5.0);
+        let date = Date {
+            year: 2024,
+            month: Month::Jan,
+            decimal_day: 1.0,
+            cal_type: CalType::Gregorian,
+        };
+
+        // Moonrise at 22h EST, about 81° east of north
+        let events = rise_set_azimuth(&boston, &date);
+        let rise = events.rise.unwrap();
+        let times = apollo::transit::moon_rise_set(&boston, &date);
+        assert_eq!(Some(rise.time), times.rise.map(|zoned| zoned.instant));
+        assert!((rise.time.jd_ut - 2460311.625).abs() < 10.0 / 1440.0);
+        assert!(
+            (rise.az.to_degrees() + 99.0).abs() < 1.0,
+            "{}",
+            rise.az.to_degrees()
+        );
+
+        // The parallax acts almost wholly in altitude
+        let (moon, _) = apollo::ephemeris::apparent_eq_pos(
+            &apollo::ephemeris::Body::Moon,
+            rise.time.jd_tt,
+            Accuracy::High,
+        );
+        let hour_angle = apollo::coordinate::hour_angle_from_long(
+            apollo::time::apparent_sidereal_jd(
+                rise.time.jd_ut,
+                apollo::ecliptic::ObliquityModel::Laskar,
+            ),
+            boston.long,
+            moon.asc,
+        );
+        let geocent_az = apollo::coordinate::azimuth_from_eq(hour_angle, moon.dec, boston.lat);
+        let shift = (rise.az - geocent_az).abs().to_degrees();
+        assert!(shift < 0.01, "{}", shift);
+
+        let set = events.set.unwrap();
+        assert!(set.az > 0.0);
+
+        // No moonrise on January 3 in EST
+        let date = Date {
+            decimal_day: 3.0,
+            ..date
+        };
+        assert_eq!(rise_set_azimuth(&boston, &date).rise, None);
+    }
+
+    #[test]
+    fn test_fundamental_args() {
+        // 1992 April 12.0 TD, Meeus example 47.a
+        let jd = 2448724.5;
+        let degrees = |angl: f64| angl.to_degrees();
+        assert!((degrees(mean_long(jd)) - 134.290182).abs() < 1e-6);
+        assert!((degrees(mean_elong(jd)) - 113.842304).abs() < 1e-6);
+        assert!((degrees(sun_mean_anom(jd)) - 97.643514).abs() < 1e-6);
+        assert!((degrees(mean_anom(jd)) - 5.150833).abs() < 1e-6);
+        assert!((degrees(arg_of_lat(jd)) - 219.889721).abs() < 1e-6);
+
+        // 1987 April 10.0 TD, Meeus example 22.a
+        assert!((degrees(mean_asc_node(2446895.5)) - 11.2531).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_asc_node_and_perigee() {
+        // The node regresses once in 18.6 years and the perigee
+        // advances once in 8.85 years
+        let jd = 2451545.0;
+        let year = 365.25;
+        let node_motion = angle::limit_pi(mean_asc_node(jd + year) - mean_asc_node(jd));
+        assert!((node_motion.to_degrees() + 360.0 / 18.6).abs() < 0.1);
+        let perigee_motion = angle::limit_pi(mean_perigee(jd + year) - mean_perigee(jd));
+        assert!((perigee_motion.to_degrees() - 360.0 / 8.85).abs() < 0.1);
+
+        for i in 0..100 {
+            let jd = jd + 7.3 * i as f64;
+            let diff = angle::limit_pi(true_asc_node(jd) - mean_asc_node(jd));
+            assert!(diff.to_degrees().abs() < 1.9);
+        }
+    }
+
+    #[test]
+    fn test_bright_limb_and_crescent_tilt() {
+        // The Moon, 1992 April 12.0 TD, Meeus example 48.a
+        let moon = EqPoint::new(134.6885_f64.to_radians(), 13.7684_f64.to_radians());
+        let sun = EqPoint::new(20.6579_f64.to_radians(), 8.6964_f64.to_radians());
+        let pos_angle = bright_limb_position_angle(&moon, &sun).to_degrees();
+        assert!((pos_angle - 285.0).abs() < 0.1, "{}", pos_angle);
+
+        // On the meridian the zenith is towards the north
+        let tilt = crescent_tilt(&moon, &sun, 0.0, 0.8).to_degrees();
+        assert!((tilt - pos_angle).abs() < 1e-9, "{}", tilt);
+
+        // An evening crescent setting in the west, from the equator,
+        // has its bright limb facing the horizon
+        let moon = EqPoint::new(0.3, 0.0);
+        let sun = EqPoint::new(0.0, 0.0);
+        let tilt = crescent_tilt(&moon, &sun, 85_f64.to_radians(), 0.0).to_degrees();
+        assert!((tilt - 180.0).abs() < 1.0, "{}", tilt);
+    }
+
+    #[test]
+    fn test_earthshine() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        assert!((earthshine(PI) - 1.0).abs() < 1e-12);
+        assert!(earthshine(0.0).abs() < 1e-12);
+        assert!((earthshine(FRAC_PI_2) - 1.0 / PI).abs() < 1e-12);
+
+        // A thin crescent shows far more earthshine than a quarter
+        assert!(earthshine(150_f64.to_radians()) > 2.0 * earthshine(FRAC_PI_2));
+    }
+}
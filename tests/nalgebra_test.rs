@@ -0,0 +1,52 @@
+// tests/nalgebra_test.rs
+
+#![cfg(feature = "nalgebra")]
+
+extern crate apollo;
+use apollo::coordinate::*;
+use nalgebra::{Rotation3, Vector3};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_point() {
+        let v: Vector3<f64> = RectPoint::new(1.0, -2.0, 3.0).into();
+        assert_eq!(v, Vector3::new(1.0, -2.0, 3.0));
+        assert_eq!(RectPoint::from(v), RectPoint::new(1.0, -2.0, 3.0));
+    }
+
+    #[test]
+    fn test_rotation_matrix() {
+        let r = RotationMatrix::about_x(0.4);
+        let rotation: Rotation3<f64> = r.into();
+        let p = RectPoint::new(0.2, 0.5, -0.7);
+
+        let expected = r * p;
+        let v = rotation * Vector3::from(p);
+        assert!((v - Vector3::from(expected)).norm() < 1e-15);
+        assert_eq!(RotationMatrix::from(rotation), r);
+
+        // A rotation of the frame is the inverse of nalgebra's rotation
+        // of vectors about the same axis
+        let about_x = Rotation3::from_axis_angle(&Vector3::x_axis(), -0.4);
+        assert!((about_x.matrix() - rotation.matrix()).norm() < 1e-15);
+    }
+
+    #[test]
+    fn test_composition() {
+        let r = RotationMatrix::about_z(0.7);
+        let n = Rotation3::from_axis_angle(&Vector3::y_axis(), 0.3);
+        let p = RectPoint::new(1.0, 2.0, 3.0);
+
+        let composed = r * n;
+        let expected = r * (RotationMatrix::from(n) * p);
+        let q = composed * p;
+        assert!((Vector3::from(q) - Vector3::from(expected)).norm() < 1e-14);
+
+        let composed = n * r;
+        let v = n * Vector3::from(r * p);
+        assert!((Vector3::from(composed * p) - v).norm() < 1e-14);
+    }
+}
@@ -0,0 +1,136 @@
+// tests/navigation_test.rs
+
+extern crate apollo;
+use apollo::navigation::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apollo::coordinate::{self, GeographPoint};
+    use apollo::ephemeris::Body;
+
+    fn arcmin(x: f64) -> f64 {
+        (x / 60.0).to_radians()
+    }
+
+    // Observed altitude of a body from a position
+    fn alt_from(position: &GeographPoint, gha: f64, dec: f64) -> f64 {
+        coordinate::altitude_from_eq(gha - position.long, dec, position.lat)
+    }
+
+    #[test]
+    fn test_observed_alt() {
+        // Dip for 9 m of eye height is 5.3′, refraction at 30° is 1.7′
+        let sextant_alt = 30_f64.to_radians() + arcmin(2.0);
+        let observed_alt = observed_alt(sextant_alt, arcmin(2.0), 9.0);
+        assert!((observed_alt - 30_f64.to_radians() + arcmin(5.28 + 1.68)).abs() < arcmin(0.05));
+
+        // Parallax of the Moon near the horizon
+        let hp = arcmin(57.0);
+        assert!((parallax_in_alt(0.0, hp) - hp).abs() < 1e-12);
+        assert!(parallax_in_alt(60_f64.to_radians(), hp) < hp);
+    }
+
+    #[test]
+    fn test_sight_reduction() {
+        let assumed = GeographPoint::new(60_f64.to_radians(), 40_f64.to_radians());
+
+        // A body on the meridian, north of the zenith
+        let gha = assumed.long;
+        let dec = 55_f64.to_radians();
+        let lop = sight_reduction(gha, dec, &assumed, 75_f64.to_radians() + arcmin(3.0));
+        assert!((lop.computed_alt - 75_f64.to_radians()).abs() < 1e-12);
+        assert!(lop.az.abs() < 1e-12 || (lop.az - apollo::angle::TWO_PI).abs() < 1e-12);
+        assert!((lop.intercept - arcmin(3.0)).abs() < 1e-12);
+
+        // A body west of the meridian has an azimuth between 180° and 360°
+        let lop = sight_reduction(gha + 0.5, 10_f64.to_radians(), &assumed, 0.5);
+        assert!(lop.az > std::f64::consts::PI && lop.az < apollo::angle::TWO_PI);
+    }
+
+    #[test]
+    fn test_destination() {
+        let start = GeographPoint::new(0.3, 0.0);
+        let east = destination(&start, std::f64::consts::FRAC_PI_2, 0.1);
+        assert!((east.long - 0.2).abs() < 1e-12 && east.lat.abs() < 1e-12);
+
+        let north = destination(&start, 0.0, 0.1);
+        assert!((north.long - 0.3).abs() < 1e-12 && (north.lat - 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fix() {
+        let position = GeographPoint::new(-0.6, 0.7);
+        let dead_reckoning = GeographPoint::new(-0.61, 0.69);
+
+        let bodies = [(-0.2, 0.3), (-1.3, -0.1)];
+        let sights =
+            bodies.map(|(gha, dec)| (geograph_pos(gha, dec), alt_from(&position, gha, dec)));
+
+        let fix = fix(&sights, &dead_reckoning).unwrap();
+        assert!(fix.angular_sep(&position) < 1e-9);
+
+        let same_body = [sights[0], sights[0]];
+        assert!(apollo::navigation::fix(&same_body, &dead_reckoning).is_err());
+    }
+
+    #[test]
+    fn test_running_fix() {
+        // Run 20 nautical miles on a course of 045° between the sights
+        let course = 45_f64.to_radians();
+        let distance = arcmin(20.0);
+        let earlier_position = GeographPoint::new(0.9, -0.5);
+        let later_position = destination(&earlier_position, course, distance);
+
+        let earlier = (
+            geograph_pos(0.5, 0.2),
+            alt_from(&earlier_position, 0.5, 0.2),
+        );
+        let later = (
+            geograph_pos(1.8, -0.6),
+            alt_from(&later_position, 1.8, -0.6),
+        );
+
+        let fix = running_fix(&earlier, &later, &earlier_position, course, distance).unwrap();
+        assert!(fix.angular_sep(&later_position) < 1e-9);
+
+        // An error in the dead reckoning barely moves the fix
+        let earlier_dead_reckoning = GeographPoint::new(0.9 + arcmin(6.0), -0.5 - arcmin(4.0));
+        let fix = running_fix(&earlier, &later, &earlier_dead_reckoning, course, distance).unwrap();
+        assert!(fix.angular_sep(&later_position) < arcmin(0.1));
+    }
+
+    #[test]
+    fn test_gha_aries_sha() {
+        // GMST at 2000 January 1, 0h UT is 6h 39m 52.3s; the equation of
+        // the equinoxes was -0.9s
+        let gha_aries = gha_aries(2451544.5);
+        assert!((gha_aries.to_degrees() - 99.9640).abs() < 1e-3);
+
+        let asc = 1.2;
+        assert!((sha(asc) + asc - apollo::angle::TWO_PI).abs() < 1e-12);
+        let gha = gha_star(sha(asc), 2451544.5);
+        assert!((gha - apollo::angle::limit_twoPI(gha_aries - asc)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gha() {
+        // At the March equinox of 2024, 0h UT on March 20, the equation
+        // of time is -7m 28s and the Sun's declination grows by 1′ an hour
+        let jd = 2460389.5;
+        let sun = gha(&Body::Sun, jd);
+        assert!((sun.gha.to_degrees() - (180.0 - 7.47 / 4.0)).abs() < 0.01);
+        assert!(sun.dec.abs() < arcmin(4.0));
+        assert!((sun.d - arcmin(0.99)).abs() < arcmin(0.02));
+        assert!(sun.v.abs() < arcmin(0.5));
+
+        // The v of the Moon lies between about 2′ and 17′
+        let moon = gha(&Body::Moon, jd);
+        assert!(moon.v > arcmin(2.0) && moon.v < arcmin(17.0));
+
+        let next_moon = gha(&Body::Moon, jd + 1.0 / 24.0);
+        let rate = apollo::angle::deg_dmas(14, 19, 0.0).to_radians() + moon.v;
+        assert!((apollo::angle::limit_pi(next_moon.gha - moon.gha - rate)).abs() < 1e-9);
+        assert!((next_moon.dec - moon.dec - moon.d).abs() < 1e-9);
+    }
+}
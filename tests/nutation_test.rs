@@ -0,0 +1,83 @@
+// tests/nutation_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::RectPoint;
+use apollo::frame::{Frame, SkyCoord};
+use apollo::nutation::*;
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nutation() {
+        // 1987 April 10.0 TD, Meeus example 22.a
+        let (nut_in_long, nut_in_oblq) = nutation(2446895.5, Accuracy::High);
+        assert!((nut_in_long - angle::deg_dmas(0, 0, -3.788).to_radians()).abs() < 1e-8);
+        assert!((nut_in_oblq - angle::deg_dmas(0, 0, 9.443).to_radians()).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_nutation_low_accuracy() {
+        let (nut_in_long, nut_in_oblq) = nutation(2446895.5, Accuracy::Low);
+        assert!((nut_in_long - angle::deg_dmas(0, 0, -3.788).to_radians()).abs() < 0.5e-5);
+        assert!((nut_in_oblq - angle::deg_dmas(0, 0, 9.443).to_radians()).abs() < 0.1e-5);
+    }
+
+    #[test]
+    fn test_nutation_in_eq_coords() {
+        // θ Persei on 2028 November 13.19 TD, Meeus example 23.a
+        let asc = 41.5472_f64.to_radians();
+        let dec = 49.3485_f64.to_radians();
+        let (nut_in_asc, nut_in_dec) = nutation_in_eq_coords(asc, dec, 2462088.69);
+
+        let arcsec = |angl: f64| angl.to_degrees() * 3600.0;
+        assert!((arcsec(nut_in_asc) - 15.843).abs() < 0.01);
+        assert!((arcsec(nut_in_dec) - 6.218).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_nutation_rate() {
+        let jd = 2446895.5;
+        let step = 0.01;
+
+        for &accuracy in &[Accuracy::Low, Accuracy::High] {
+            let (long_before, oblq_before) = nutation(jd - step, accuracy);
+            let (long_after, oblq_after) = nutation(jd + step, accuracy);
+            let (long_rate, oblq_rate) = nutation_rate(jd, accuracy);
+
+            let long_numerical = (long_after - long_before) / (2.0 * step);
+            let oblq_numerical = (oblq_after - oblq_before) / (2.0 * step);
+            assert!((long_rate - long_numerical).abs() < 1e-5 * long_rate.abs());
+            assert!((oblq_rate - oblq_numerical).abs() < 1e-5 * oblq_rate.abs());
+        }
+    }
+
+    #[test]
+    fn test_matrix() {
+        // θ Persei on 2028 November 13.19 TD, Meeus example 23.a
+        let jd = 2462088.69;
+        let (asc, dec) = (41.5472_f64.to_radians(), 49.3485_f64.to_radians());
+        let p = RectPoint::from_spherical(asc, dec, 1.0);
+        let (true_asc, true_dec, _) = (matrix(jd) * p).to_spherical();
+
+        let arcsec = |angl: f64| angl.to_degrees() * 3600.0;
+        assert!((arcsec(true_asc - asc) - 15.843).abs() < 0.01);
+        assert!((arcsec(true_dec - dec) - 6.218).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_precession_nutation_matrix() {
+        let jd = 2462088.69;
+        let (asc, dec) = (41.054063_f64.to_radians(), 49.227750_f64.to_radians());
+        let p = RectPoint::from_spherical(asc, dec, 1.0);
+        let (true_asc, true_dec, _) = (precession_nutation_matrix(jd) * p).to_spherical();
+
+        let expected = SkyCoord::new(Frame::Fk5J2000, asc, dec)
+            .transform_to(Frame::EquinoxOfDate(jd), Accuracy::High);
+        assert!((true_asc - expected.long).abs() < 1e-10);
+        assert!((true_dec - expected.lat).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,142 @@
+// tests/orbit_test.rs
+
+extern crate apollo;
+use apollo::orbit::*;
+use apollo::sun;
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(perih_dist: f64, ecc: f64) -> OrbitalElements {
+        OrbitalElements {
+            perih_dist,
+            ecc,
+            incl: 0.2,
+            arg_perih: 1.1,
+            asc_node: 2.3,
+            perih_jd: 2460000.5,
+        }
+    }
+
+    #[test]
+    fn test_heliocent_rect() {
+        // At the perihelion, whatever the eccentricity
+        for ecc in [0.2, 0.97, 1.0, 1.5] {
+            let rad_vec = heliocent_rect(&elements(0.8, ecc), 2460000.5).dist();
+            assert!((rad_vec - 0.8).abs() < 1e-12, "{}", ecc);
+        }
+
+        // Continuity across the parabolic orbit
+        let parabolic = heliocent_rect(&elements(0.8, 1.0), 2460030.5);
+        for ecc in [1.0 - 1e-6, 1.0 + 1e-6] {
+            let near = heliocent_rect(&elements(0.8, ecc), 2460030.5);
+            assert!((near.x - parabolic.x).abs() < 1e-6);
+            assert!((near.y - parabolic.y).abs() < 1e-6);
+            assert!((near.z - parabolic.z).abs() < 1e-6);
+        }
+
+        // An ellipse closes after one period
+        let asteroid = OrbitalElements::from_mean_anom(2.5, 0.1, 0.1, 0.5, 1.5, 2.0, 2460000.5);
+        let period = 2.5_f64.powf(1.5) * 365.2568984;
+        let p1 = heliocent_rect(&asteroid, 2460100.5);
+        let p2 = heliocent_rect(&asteroid, 2460100.5 + period);
+        assert!((p1.x - p2.x).abs() < 1e-6 && (p1.y - p2.y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_close_approach() {
+        // A body on a hyperbolic orbit in the ecliptic, passing its
+        // perihelion 0.01 AU outside the Earth's orbit, in line with
+        // the Sun and the Earth
+        let perih_jd = 2460500.5;
+        let (sun, rad_vec) = sun::geocent_ecl_pos(perih_jd, Accuracy::High);
+        let flyby = OrbitalElements {
+            perih_dist: rad_vec + 0.01,
+            ecc: 1.2,
+            incl: 0.0,
+            arg_perih: sun::solar_longitude_j2000(perih_jd) + std::f64::consts::PI,
+            asc_node: 0.0,
+            perih_jd,
+        };
+        assert!(sun.lat.abs() < 1e-5);
+
+        let (jd, dist) = close_approach(&flyby, perih_jd - 30.0, perih_jd + 30.0);
+        assert!((jd - perih_jd).abs() < 1.0, "{}", jd);
+        assert!((dist - 0.01).abs() < 1e-4, "{}", dist);
+        assert!((geocent_dist(&flyby, jd) - dist).abs() < 1e-12);
+        assert!(geocent_dist(&flyby, jd + 0.1) > dist);
+        assert!(geocent_dist(&flyby, jd - 0.1) > dist);
+    }
+
+    #[test]
+    fn test_moid() {
+        let circle = |radius: f64, incl: f64| OrbitalElements {
+            perih_dist: radius,
+            ecc: 0.0,
+            incl,
+            arg_perih: 0.0,
+            asc_node: 0.0,
+            perih_jd: 2460000.5,
+        };
+
+        // Coplanar and concentric
+        assert!((moid(&circle(1.0, 0.0), &circle(1.3, 0.0)) - 0.3).abs() < 1e-9);
+        // Crossing at the nodes
+        assert!(moid(&circle(1.0, 0.0), &circle(1.0, 0.4)) < 1e-9);
+        // Perpendicular, closest at the nodes
+        let moid_perp = moid(&circle(1.0, 0.0), &circle(1.2, std::f64::consts::FRAC_PI_2));
+        assert!((moid_perp - 0.2).abs() < 1e-9, "{}", moid_perp);
+
+        // An ellipse within a circle, touching it at the aphelion
+        let ellipse = OrbitalElements {
+            perih_dist: 0.5,
+            ecc: 1.0 / 3.0,
+            ..circle(0.0, 0.0)
+        };
+        assert!(moid(&ellipse, &circle(1.0, 0.0)) < 1e-9);
+        let moid_ab = moid(&ellipse, &circle(1.5, 0.0));
+        assert!((moid_ab - moid(&circle(1.5, 0.0), &ellipse)).abs() < 1e-12);
+        assert!((moid(&ellipse, &circle(1.5, 0.0)) - 0.5).abs() < 1e-9);
+
+        // A hyperbola
+        let hyperbola = OrbitalElements {
+            perih_dist: 1.1,
+            ecc: 1.5,
+            ..circle(0.0, 0.0)
+        };
+        assert!((moid(&hyperbola, &circle(1.0, 0.0)) - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_state_conversion() {
+        for &(q, ecc) in &[(0.8, 0.3), (0.5, 1.0), (1.2, 1.5)] {
+            let orbit = elements(q, ecc);
+            let jd = orbit.perih_jd + 40.0;
+            let (pos, vel) = state_from_elements(&orbit, SUN_GRAV_PARAM, jd);
+            let rect = heliocent_rect(&orbit, jd);
+            assert!((pos.x - rect.x).abs() < 1e-12 && (pos.z - rect.z).abs() < 1e-12);
+
+            let back = elements_from_state(&pos, &vel, SUN_GRAV_PARAM, jd);
+            assert!((back.perih_dist - q).abs() < 1e-9, "{}", ecc);
+            assert!((back.ecc - ecc).abs() < 1e-9, "{}", ecc);
+            assert!((back.incl - orbit.incl).abs() < 1e-9);
+            assert!((back.arg_perih - orbit.arg_perih).abs() < 1e-9);
+            assert!((back.asc_node - orbit.asc_node).abs() < 1e-9);
+            assert!((back.perih_jd - orbit.perih_jd).abs() < 1e-6, "{}", ecc);
+        }
+
+        // Circular orbit in the plane of the frame
+        let (pos, vel) = (
+            apollo::coordinate::RectPoint::new(0.0, 1.0, 0.0),
+            apollo::coordinate::RectPoint::new(-0.01720209895, 0.0, 0.0),
+        );
+        let circle = elements_from_state(&pos, &vel, SUN_GRAV_PARAM, 2451545.0);
+        assert!(circle.ecc < 1e-9 && circle.incl.abs() < 1e-12);
+        assert!((circle.perih_dist - 1.0).abs() < 1e-9);
+        let (pos_back, vel_back) = state_from_elements(&circle, SUN_GRAV_PARAM, 2451545.0);
+        assert!((pos_back.y - 1.0).abs() < 1e-9 && pos_back.x.abs() < 1e-9);
+        assert!((vel_back.x - vel.x).abs() < 1e-12 && vel_back.y.abs() < 1e-12);
+    }
+}
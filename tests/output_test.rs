@@ -0,0 +1,100 @@
+// tests/output_test.rs
+
+#![cfg(feature = "output")]
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::EqPoint;
+use apollo::output::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<(f64, EqPoint, f64)> {
+        vec![(
+            2448908.5,
+            EqPoint::new(
+                angle::deg_hms(13, 13, 30.749).to_radians(),
+                angle::deg_dmas(-7, 47, 1.74).to_radians(),
+            ),
+            0.99760775,
+        )]
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut csv = Vec::new();
+        write_csv(&mut csv, rows(), &Options::default()).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "Date__(TT)__HR:MN:SS,R.A._(a-app),DEC_(a-app),delta\n\
+             1992-Oct-13 00:00:00,13 13 30.75,-07 47 01.7,0.99760775000000\n"
+        );
+    }
+
+    #[test]
+    fn test_write_invalid_date() {
+        let rows = vec![(-1.0, EqPoint::new(0.0, 0.0), 1.0)];
+        let mut csv = Vec::new();
+        let error = write_csv(&mut csv, rows.clone(), &Options::default()).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+
+        let mut json = Vec::new();
+        assert!(write_json(&mut json, rows, &Options::default()).is_err());
+    }
+
+    #[test]
+    fn test_write_csv_rounding() {
+        let options = Options {
+            columns: vec![Column::Asc, Column::Dec],
+            angle_format: AngleFormat::Sexagesimal,
+        };
+        let row = |asc: f64, dec: f64| {
+            let mut csv = Vec::new();
+            let eq_point = EqPoint::new(asc.to_radians(), dec.to_radians());
+            write_csv(&mut csv, vec![(2448908.5, eq_point, 1.0)], &options).unwrap();
+            String::from_utf8(csv)
+                .unwrap()
+                .lines()
+                .nth(1)
+                .unwrap()
+                .to_string()
+        };
+
+        // The seconds carry into the minutes and hours, which wrap
+        assert_eq!(
+            row(angle::deg_hms(23, 59, 59.996), 0.0),
+            "00 00 00.00,+00 00 00.0"
+        );
+        assert_eq!(
+            row(angle::deg_hms(1, 59, 59.996), 0.0),
+            "02 00 00.00,+00 00 00.0"
+        );
+
+        // A small negative value rounding to zero has no minus sign
+        assert_eq!(
+            row(0.0, -angle::deg_dmas(0, 0, 0.04)),
+            "00 00 00.00,+00 00 00.0"
+        );
+        assert_eq!(
+            row(0.0, -angle::deg_dmas(0, 0, 0.06)),
+            "00 00 00.00,-00 00 00.1"
+        );
+    }
+
+    #[test]
+    fn test_write_json() {
+        let options = Options {
+            columns: vec![Column::JulianDay, Column::Asc, Column::Dec],
+            angle_format: AngleFormat::Decimal,
+        };
+        let mut json = Vec::new();
+        write_json(&mut json, rows(), &options).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            "[{\"Date_________JDTT\":\"2448908.500000000\",\
+             \"R.A._(a-app)\":\"198.37812\",\"DEC_(a-app)\":\"-7.78382\"}]\n"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// tests/phase_test.rs
+
+extern crate apollo;
+use apollo::coordinate::EqPoint;
+use apollo::phase::*;
+use std::f64::consts::PI;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_angle() {
+        // Venus, 1992 December 20.0 TD, Meeus example 41.a
+        let i = phase_angle(0.724604, 0.910947, 0.983824);
+        assert!((illuminated_fraction(i) - 0.647).abs() < 1e-3);
+
+        // A body at opposition is fully illuminated
+        assert!(phase_angle(2.0, 1.0, 1.0).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_elongation() {
+        assert!((elongation(2.0, 1.0, 1.0) - PI).abs() < 1e-7);
+
+        // The sum of the angles of the Sun-Earth-body triangle
+        let (r, delta, big_r): (f64, f64, f64) = (0.724604, 0.910947, 0.983824);
+        let sun_angle = ((r * r + big_r * big_r - delta * delta) / (2.0 * r * big_r)).acos();
+        let sum = phase_angle(r, delta, big_r) + elongation(r, delta, big_r) + sun_angle;
+        assert!((sum - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_illuminated_fraction() {
+        assert_eq!(illuminated_fraction(0.0), 1.0);
+        assert!((illuminated_fraction(PI / 2.0) - 0.5).abs() < 1e-15);
+        assert!(illuminated_fraction(PI) < 1e-15);
+    }
+
+    #[test]
+    fn test_bright_limb_pos_angle() {
+        // The Moon, 1992 April 12.0 TD, Meeus example 48.a
+        let moon = EqPoint::new(134.6885_f64.to_radians(), 13.7684_f64.to_radians());
+        let sun = EqPoint::new(20.6579_f64.to_radians(), 8.6964_f64.to_radians());
+        let pos_angle = bright_limb_pos_angle(&moon, &sun).to_degrees();
+        assert!((pos_angle - 285.0).abs() < 0.1, "{}", pos_angle);
+    }
+}
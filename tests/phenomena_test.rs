@@ -0,0 +1,160 @@
+// tests/phenomena_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::Observer;
+use apollo::ephemeris::Body;
+use apollo::phenomena::*;
+use apollo::sun;
+use apollo::Accuracy;
+use std::f64::consts::PI;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_antisolar_point() {
+        // 1992 October 13.0 TD, Meeus example 25.b
+        let jd = 2448908.5;
+        let (sun_point, _) = sun::apparent_ecl_pos(jd, Accuracy::Low);
+        let point = antisolar_point(jd);
+        assert!((angle::limit_pi(point.long - sun_point.long).abs() - PI).abs() < 1e-12);
+        assert!((point.lat + sun_point.lat).abs() < 1e-15);
+        assert!((point.long.to_degrees() - 19.906).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_gegenschein_horizontal_pos() {
+        // Near midnight at Greenwich the gegenschein transits close to
+        // the south; 2024 October 1 it lies at a declination of about +3°
+        let greenwich = Observer::new(0.0, 51.48_f64.to_radians(), 0.0);
+        let (az, alt) = gegenschein_horizontal_pos(2460584.5, &greenwich);
+        assert!(az.abs() < 5_f64.to_radians(), "{}", az.to_degrees());
+        assert!(
+            (alt.to_degrees() - 41.5).abs() < 2.0,
+            "{}",
+            alt.to_degrees()
+        );
+    }
+
+    #[test]
+    fn test_zodiacal_light_cone() {
+        let observer = Observer::new(0.0, 40_f64.to_radians(), 0.0);
+
+        // Spring evening: the ecliptic stands steeply over the west
+        let spring = zodiacal_light_cone(2460390.5 + 19.5 / 24.0, &observer);
+        assert!((spring.az - PI / 2.0).abs() < 30_f64.to_radians());
+        assert!(spring.inclination > 60_f64.to_radians());
+
+        // Autumn evening: it lies low along the western horizon
+        let autumn = zodiacal_light_cone(2460576.5 + 19.5 / 24.0, &observer);
+        assert!((autumn.az - PI / 2.0).abs() < 30_f64.to_radians());
+        assert!(autumn.inclination < 35_f64.to_radians());
+
+        // Autumn morning: steep over the east
+        let morning = zodiacal_light_cone(2460576.5 + 4.5 / 24.0, &observer);
+        assert!((morning.az + PI / 2.0).abs() < 30_f64.to_radians());
+        assert!(morning.inclination > 60_f64.to_radians());
+    }
+
+    #[test]
+    fn test_subsolar_point() {
+        // 2024 June 20, 20h51m UT, June solstice
+        let point = subsolar_point(2460482.369);
+        assert!((point.lat.to_degrees() - 23.44).abs() < 0.01);
+
+        // The Sun is at the zenith of the subsolar point
+        let jd = 2460589.8;
+        let point = subsolar_point(jd);
+        let observer = Observer::new(point.long, point.lat, 0.0);
+        let (_, alt) = sun::horizontal_pos(jd, &observer, Accuracy::High);
+        assert!((alt.to_degrees() - 90.0).abs() < 0.01);
+
+        // Near 12h UT the Sun is near the Greenwich meridian, off by
+        // the equation of time
+        let point = subsolar_point(2460389.0);
+        assert!(point.long.to_degrees().abs() < 2.0);
+        assert!(point.lat.abs() < 0.5_f64.to_radians());
+    }
+
+    #[test]
+    fn test_sublunar_point() {
+        let jd = 2460589.8;
+        let point = sublunar_point(jd);
+        let (eq_point, _) = apollo::ephemeris::apparent_eq_pos(
+            &apollo::ephemeris::Body::Moon,
+            apollo::time::tt_from_ut(jd),
+            Accuracy::High,
+        );
+        assert!((point.lat - eq_point.dec).abs() < 1e-12);
+        assert!(point.long.abs() <= PI);
+        assert!(point.lat.abs() < 29_f64.to_radians());
+    }
+
+    #[test]
+    fn test_terminator() {
+        let jd = 2460589.8;
+        let points = terminator(jd, 36);
+        assert_eq!(points.len(), 36);
+        assert!(terminator(jd, 0).is_empty());
+
+        // The Sun is at its standard altitude of −50′ along the
+        // terminator
+        for point in &points {
+            assert!(point.long.abs() <= PI);
+            let observer = Observer::new(point.long, point.lat, 0.0);
+            let (_, alt) = sun::horizontal_pos(jd, &observer, Accuracy::High);
+            assert!((alt.to_degrees() * 60.0 + 50.0).abs() < 0.5);
+        }
+
+        // And 18° below the horizon at the end of astronomical twilight
+        for point in &terminator_at_depression(jd, 8, 18_f64.to_radians()) {
+            let observer = Observer::new(point.long, point.lat, 0.0);
+            let (_, alt) = sun::horizontal_pos(jd, &observer, Accuracy::High);
+            assert!((alt.to_degrees() + 18.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_appulses() {
+        // The total solar eclipse of 2024 April 8, greatest near 18h 17m
+        // UT
+        let appulses = appulses(
+            &Body::Sun,
+            &Body::Moon,
+            2460405.5,
+            2460415.5,
+            1_f64.to_radians(),
+        );
+        assert_eq!(appulses.len(), 1);
+        assert_eq!(
+            (appulses[0].near_body, appulses[0].far_body),
+            (Body::Moon, Body::Sun)
+        );
+        assert!(
+            (appulses[0].jd - 2460409.263).abs() < 0.02,
+            "{}",
+            appulses[0].jd
+        );
+        assert!(appulses[0].occultation);
+    }
+
+    #[test]
+    fn test_planetary_occultations() {
+        // The Moon occulted Mars on 2025 January 14 near 4h TT, and passed
+        // 0.8° from it, as seen from the center of the Earth, on
+        // February 9
+        let appulses = planetary_occultations(2460676.5, 2460740.5, 1_f64.to_radians());
+        assert_eq!(appulses.len(), 2);
+        assert!(
+            (appulses[0].jd - 2460689.66).abs() < 0.01,
+            "{}",
+            appulses[0].jd
+        );
+        assert!(appulses[0].occultation);
+        assert_eq!(appulses[1].near_body, Body::Moon);
+        assert!(!appulses[1].occultation);
+        assert!(appulses[1].angular_sep.to_degrees() > 0.5);
+    }
+}
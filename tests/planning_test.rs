@@ -0,0 +1,86 @@
+// tests/planning_test.rs
+
+extern crate apollo;
+use apollo::coordinate::{GeographPoint, Observer};
+use apollo::ephemeris::Body;
+use apollo::planning::*;
+use apollo::sun;
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_alignments_sun() {
+        let boston = GeographPoint::new(71.06_f64.to_radians(), 42.36_f64.to_radians());
+        let observer = Observer::new(boston.long, boston.lat, 0.0);
+
+        // The direction of the Sun at 2024 June 1, 20h UT
+        let jd = 2460463.5 + 20.0 / 24.0;
+        let (az, alt) = sun::horizontal_pos(jd, &observer, Accuracy::Low);
+        let alt = alt + apollo::atmospheric::refrac_true_alt(alt);
+
+        let tolerance = 0.5_f64.to_radians();
+        let alignments =
+            find_alignments(&Body::Sun, &boston, az, alt, tolerance, jd - 0.5, jd + 0.5).unwrap();
+        assert_eq!(alignments.len(), 1);
+        let alignment = alignments[0];
+        assert!((alignment.time.jd_ut - jd).abs() < 1.0 / 1440.0);
+        assert!(alignment.separation < 0.01_f64.to_radians());
+        assert!((alignment.az - az).abs() < 0.01_f64.to_radians());
+
+        // The Sun passes close to the same direction on the following days
+        let alignments =
+            find_alignments(&Body::Sun, &boston, az, alt, tolerance, jd - 0.5, jd + 3.5).unwrap();
+        assert!(alignments.len() > 1);
+        for pair in alignments.windows(2) {
+            assert!(pair[1].time.jd_ut - pair[0].time.jd_ut > 0.9);
+        }
+        assert!(alignments.iter().all(|a| a.separation < tolerance));
+
+        assert!(find_alignments(&Body::Sun, &boston, az, alt, 0.0, jd - 0.5, jd + 0.5).is_err());
+
+        // A search over a single instant gives that instant
+        let alignments = find_alignments(&Body::Sun, &boston, az, alt, tolerance, jd, jd).unwrap();
+        assert_eq!(alignments.len(), 1);
+        assert_eq!(alignments[0].time.jd_ut, jd);
+    }
+
+    #[test]
+    fn test_find_alignments_moon() {
+        // The full Moon rises in the east-northeast of Boston, 60° from
+        // the north, on the evenings of 2024 January 25 and 26
+        let boston = GeographPoint::new(71.06_f64.to_radians(), 42.36_f64.to_radians());
+        let target_az = (-120.0_f64).to_radians();
+        let target_alt = 0.0;
+        let tolerance = 5_f64.to_radians();
+
+        let alignments = find_alignments(
+            &Body::Moon,
+            &boston,
+            target_az,
+            target_alt,
+            tolerance,
+            2460334.5,
+            2460336.5,
+        )
+        .unwrap();
+        assert!(!alignments.is_empty());
+        for alignment in &alignments {
+            assert!(alignment.separation < tolerance);
+            assert!(alignment.alt.abs() < tolerance);
+        }
+        assert!(find_alignments(
+            &Body::Moon,
+            &boston,
+            0.0,
+            -1.5,
+            tolerance,
+            2460334.5,
+            2460336.5
+        )
+        .unwrap()
+        .is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+// tests/pointing_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::Observer;
+use apollo::ephemeris::Body;
+use apollo::planet::earth;
+use apollo::pointing::*;
+use apollo::sun;
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arcsec(x: f64) -> f64 {
+        angle::deg_dmas(0, 0, x).to_radians()
+    }
+
+    fn model() -> MountModel {
+        MountModel {
+            ih: arcsec(120.0),
+            id: arcsec(-45.0),
+            ch: arcsec(30.0),
+            np: arcsec(-12.0),
+            ma: arcsec(60.0),
+            me: arcsec(-90.0),
+            tf: arcsec(20.0),
+        }
+    }
+
+    #[test]
+    fn test_catalog_from_encoder() {
+        let lat = 35_f64.to_radians();
+        let (hour_angle, dec) = (-1.1, 0.4);
+
+        let (encoder_hour_angle, encoder_dec) = model().encoder_from_catalog(hour_angle, dec, lat);
+        assert!((encoder_hour_angle - hour_angle).abs() > arcsec(100.0));
+
+        let (h, d) = model().catalog_from_encoder(encoder_hour_angle, encoder_dec, lat);
+        assert!((h - hour_angle).abs() < 1e-10);
+        assert!((d - dec).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_fit() {
+        let lat = 35_f64.to_radians();
+        let mut observations = Vec::new();
+        for i in 0..6 {
+            for j in 0..5 {
+                let hour_angle = (-75.0 + 30.0 * i as f64).to_radians();
+                let dec = (-20.0 + 20.0 * j as f64).to_radians();
+                let (encoder_hour_angle, encoder_dec) =
+                    model().encoder_from_catalog(hour_angle, dec, lat);
+                observations.push(PointingObservation {
+                    hour_angle,
+                    dec,
+                    encoder_hour_angle,
+                    encoder_dec,
+                });
+            }
+        }
+
+        let (fitted, rms) = fit(&observations, lat).unwrap();
+        assert!(rms < 1e-12);
+        assert!((fitted.ih - model().ih).abs() < 1e-12);
+        assert!((fitted.ma - model().ma).abs() < 1e-12);
+        assert!((fitted.tf - model().tf).abs() < 1e-12);
+
+        assert!(fit(&observations[..3], lat).is_err());
+        let one_meridian: Vec<_> = observations
+            .iter()
+            .filter(|o| o.hour_angle == observations[0].hour_angle)
+            .copied()
+            .collect();
+        assert!(fit(&one_meridian, lat).is_err());
+    }
+
+    #[test]
+    fn test_tracking_rates() {
+        let observer = Observer::new(71.0833_f64.to_radians(), 42.3333_f64.to_radians(), 0.0);
+        let jd = 2460400.1;
+        let omega = earth::ROTATIONAL_ANGULAR_VELOCITY;
+
+        // Mars moves under a degree a day on the sky, so it nearly
+        // follows the stars
+        let rates = tracking_rates(&Body::Mars, &observer, jd);
+        assert!(rates.asc.abs() < 2e-7);
+        assert!((rates.hour_angle + rates.asc - omega).abs() < 1e-9);
+
+        // The altitude changes as dh/dt = −cos φ sin A dH/dt
+        let rates = tracking_rates(&Body::Sun, &observer, jd);
+        let (az, alt) = sun::horizontal_pos(jd, &observer, Accuracy::High);
+        assert!(alt > 0.0);
+        let alt_rate = -observer.lat.cos() * az.sin() * rates.hour_angle;
+        assert!((rates.alt - alt_rate).abs() < 2e-7);
+
+        // The Moon drifts eastward by about 13° a day, more or less
+        // its diurnal parallax
+        let rates = tracking_rates(&Body::Moon, &observer, jd);
+        let daily_drift = rates.asc.to_degrees() * 86400.0;
+        assert!(daily_drift > 6.0 && daily_drift < 20.0);
+        assert!((rates.hour_angle + rates.asc - omega).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,35 @@
+// tests/polynomial_test.rs
+
+extern crate apollo;
+use apollo::math::polynomial::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_horner() {
+        // 2 - 3x + x³ at x = 2
+        assert_eq!(horner(&[2.0, -3.0, 0.0, 1.0], 2.0), 4.0);
+        assert_eq!(horner(&[5.0], 10.0), 5.0);
+        assert_eq!(horner(&[], 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_polynomial() {
+        const CUBIC: Polynomial<'static> = Polynomial::new(&[2.0, -3.0, 0.0, 1.0]);
+
+        assert_eq!(CUBIC.eval(2.0), 4.0);
+        assert_eq!(CUBIC.coeffs(), &[2.0, -3.0, 0.0, 1.0]);
+        assert_eq!(CUBIC.degree(), Some(3));
+
+        // The derivative -3 + 3x² vanishes at x = ±1
+        assert_eq!(CUBIC.eval_derivative(1.0), 0.0);
+        assert_eq!(CUBIC.eval_derivative(-1.0), 0.0);
+        assert_eq!(CUBIC.eval_with_derivative(2.0), (4.0, 9.0));
+
+        assert_eq!(Polynomial::new(&[1.0, 0.0]).degree(), Some(0));
+        assert_eq!(Polynomial::new(&[0.0]).degree(), None);
+        assert_eq!(Polynomial::new(&[7.0]).eval_derivative(3.0), 0.0);
+    }
+}
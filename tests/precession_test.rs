@@ -0,0 +1,83 @@
+// tests/precession_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::{EclPoint, EqPoint, RectPoint};
+use apollo::precession::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precess_eq() {
+        // θ Persei from J2000.0 to 2028 November 13.19 TD, Meeus example 21.b
+        let years = (2462088.69 - 2451545.0) / 365.25;
+        let eq_point = EqPoint::new(
+            (angle::deg_hms(2, 44, 11.986) + angle::deg_hms(0, 0, 0.03425) * years).to_radians(),
+            (angle::deg_dmas(49, 13, 42.48) + angle::deg_dmas(0, 0, -0.0895) * years).to_radians(),
+        );
+
+        let precessed = precess_eq(&eq_point, 2451545.0, 2462088.69);
+        assert!((precessed.asc - angle::deg_hms(2, 46, 11.331).to_radians()).abs() < 1e-7);
+        assert!((precessed.dec - angle::deg_dmas(49, 20, 54.54).to_radians()).abs() < 1e-7);
+
+        let back = precess_eq(&precessed, 2462088.69, 2451545.0);
+        assert!((back.asc - eq_point.asc).abs() < 1e-9);
+        assert!((back.dec - eq_point.dec).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_precess_ecl() {
+        // Venus from J2000.0 to -214 June 30.0 TD, Meeus example 21.c
+        let ecl_point = EclPoint {
+            long: 149.48194_f64.to_radians(),
+            lat: 1.76549_f64.to_radians(),
+        };
+
+        let precessed = precess_ecl(&ecl_point, 2451545.0, 1643074.5);
+        assert!((precessed.long.to_degrees() - 118.70416).abs() < 1e-4);
+        assert!((precessed.lat.to_degrees() - 1.61533).abs() < 1e-4);
+
+        // Over a few decades the precession is reversible
+        let forward = precess_ecl(&ecl_point, 2451545.0, 2469807.5);
+        let back = precess_ecl(&forward, 2469807.5, 2451545.0);
+        assert!((back.long - ecl_point.long).abs() < 1e-9);
+        assert!((back.lat - ecl_point.lat).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_angular_sep_across_equinoxes() {
+        // A point of B1950.0 and the same point precessed to J2000.0
+        let b1950 = EqPoint::with_equinox(1.9, 0.6, 2433282.4235);
+        let j2000 = precess_eq(&b1950, 2433282.4235, 2451545.0);
+        assert_eq!(j2000.equinox, Some(2451545.0));
+
+        // are the same direction, once the equinoxes are matched
+        assert!(j2000.angular_sep(&b1950) < 1e-7);
+        assert!(b1950.angular_sep(&j2000) < 1e-7);
+
+        // Points of unknown equinox are compared as they are, 0.6°
+        // apart here
+        let bare = EqPoint::new(b1950.asc, b1950.dec);
+        assert!((j2000.angular_sep(&bare).to_degrees() - 0.6).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_matrix() {
+        // θ Persei from J2000.0 to 2028 November 13.19 TD, Meeus example
+        // 21.b
+        let (jd_from, jd_to) = (2451545.0, 2462088.69);
+        let eq_point = EqPoint::new(41.054063_f64.to_radians(), 49.227750_f64.to_radians());
+        let expected = precess_eq(&eq_point, jd_from, jd_to);
+
+        let p = RectPoint::from_spherical(eq_point.asc, eq_point.dec, 1.0);
+        let (asc, dec, _) = (matrix(jd_from, jd_to) * p).to_spherical();
+        assert!((asc - expected.asc).abs() < 1e-12);
+        assert!((dec - expected.dec).abs() < 1e-12);
+
+        let (asc, dec, _) = (matrix(jd_to, jd_from) * matrix(jd_from, jd_to) * p).to_spherical();
+        assert!((asc - eq_point.asc).abs() < 1e-12);
+        assert!((dec - eq_point.dec).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,31 @@
+// tests/prelude_test.rs
+
+extern crate apollo;
+use apollo::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prelude() {
+        let date = Date {
+            year: 2000,
+            month: Month::Jan,
+            decimal_day: 1.5,
+            cal_type: CalType::Gregorian,
+        };
+        let jd = julian_day(&date);
+        assert_eq!(jd, 2451545.0);
+
+        let (sun, _) = apparent_eq_pos(&Body::Sun, jd, Accuracy::Low);
+        let oblq = PrecomputedObliquity::new(23.4392911_f64.to_radians());
+        let (ecl_long, ecl_lat) = ecliptic_from_eq!(sun.asc, sun.dec, oblq);
+        let (asc, dec) = eq_from_ecliptic!(ecl_long, ecl_lat, oblq);
+        assert!(apollo::angle::limit_pi(asc - sun.asc).abs() < 1e-9);
+        assert!((dec - sun.dec).abs() < 1e-9);
+
+        let sidereal = apparent_sidereal_jd(jd, ObliquityModel::Laskar);
+        assert!((0.0..std::f64::consts::TAU).contains(&sidereal));
+    }
+}
@@ -0,0 +1,134 @@
+// tests/projection_test.rs
+
+extern crate apollo;
+use apollo::coordinate::EqPoint;
+use apollo::projection::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eq_point(asc: f64, dec: f64) -> EqPoint {
+        EqPoint::new(asc.to_radians(), dec.to_radians())
+    }
+
+    #[test]
+    fn test_gnomonic() {
+        let center = eq_point(0.0, 0.0);
+        let (xi, eta) = gnomonic(&eq_point(10.0, 0.0), &center).unwrap();
+        assert!((xi - 10.0_f64.to_radians().tan()).abs() < 1e-12);
+        assert!(eta.abs() < 1e-12);
+
+        assert!(gnomonic(&eq_point(180.0, 0.0), &center).is_err());
+
+        let center = eq_point(201.3, -43.0);
+        let star = eq_point(202.1, -41.7);
+        let (xi, eta) = gnomonic(&star, &center).unwrap();
+        let inverse = inverse_gnomonic(xi, eta, &center);
+        assert!((inverse.asc - star.asc).abs() < 1e-12);
+        assert!((inverse.dec - star.dec).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_stereographic() {
+        let center = eq_point(83.8, 22.0);
+        let star = eq_point(150.0, -30.0);
+        let (xi, eta) = stereographic(&star, &center).unwrap();
+        let inverse = inverse_stereographic(xi, eta, &center);
+        assert!((inverse.asc - star.asc).abs() < 1e-12);
+        assert!((inverse.dec - star.dec).abs() < 1e-12);
+
+        assert!(stereographic(&eq_point(263.8, -22.0), &center).is_err());
+    }
+
+    #[test]
+    fn test_solve_plate_constants() {
+        let expected = PlateConstants {
+            a: 1e-5,
+            b: 2e-7,
+            c: -0.01,
+            d: -3e-7,
+            e: 1.1e-5,
+            f: 0.02,
+        };
+        let measured = [
+            (10.0, 20.0),
+            (1500.0, 80.0),
+            (700.0, 1000.0),
+            (1900.0, 1800.0),
+        ];
+        let standard: Vec<_> = measured
+            .iter()
+            .map(|&(x, y)| expected.standard_from_measured(x, y))
+            .collect();
+
+        let plate_consts = solve_plate_constants(&measured, &standard).unwrap();
+        let (xi, eta) = plate_consts.standard_from_measured(1234.0, 567.0);
+        let (x, y) = plate_consts.measured_from_standard(xi, eta);
+        assert!((x - 1234.0).abs() < 1e-6);
+        assert!((y - 567.0).abs() < 1e-6);
+        assert!((plate_consts.a - expected.a).abs() < 1e-15);
+        assert!((plate_consts.f - expected.f).abs() < 1e-12);
+
+        assert!(solve_plate_constants(&measured[..2], &standard[..2]).is_err());
+        let collinear = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)];
+        assert!(solve_plate_constants(&collinear, &standard[..3]).is_err());
+        let nearly_collinear = [
+            (10.3, 0.3 * 10.3 + 7.1),
+            (1500.7, 0.3 * 1500.7 + 7.1),
+            (912.9, 0.3 * 912.9 + 7.1),
+            (1873.1, 0.3 * 1873.1 + 7.1),
+        ];
+        assert!(solve_plate_constants(&nearly_collinear, &standard).is_err());
+    }
+
+    #[test]
+    fn test_orthographic() {
+        let center = eq_point(0.0, 90.0);
+        let (xi, eta) = orthographic(&eq_point(90.0, 60.0), &center).unwrap();
+        assert!((xi.hypot(eta) - 0.5).abs() < 1e-12);
+        assert!(orthographic(&eq_point(90.0, -10.0), &center).is_err());
+
+        let center = eq_point(120.0, 15.0);
+        let star = eq_point(150.0, 40.0);
+        let (xi, eta) = orthographic(&star, &center).unwrap();
+        let inverse = inverse_orthographic(xi, eta, &center).unwrap();
+        assert!((inverse.asc - star.asc).abs() < 1e-12);
+        assert!((inverse.dec - star.dec).abs() < 1e-12);
+        assert!(inverse_orthographic(1.0, 0.5, &center).is_err());
+    }
+
+    #[test]
+    fn test_lambert_azimuthal() {
+        // The area of a spherical cap of radius c is preserved
+        let center = eq_point(0.0, 90.0);
+        let (xi, eta) = lambert_azimuthal(&eq_point(0.0, 0.0), &center).unwrap();
+        assert!(
+            (std::f64::consts::PI * (xi * xi + eta * eta) - 2.0 * std::f64::consts::PI).abs()
+                < 1e-12
+        );
+
+        let center = eq_point(300.0, -50.0);
+        let star = eq_point(10.0, 20.0);
+        let (xi, eta) = lambert_azimuthal(&star, &center).unwrap();
+        let inverse = inverse_lambert_azimuthal(xi, eta, &center).unwrap();
+        assert!((inverse.asc - star.asc).abs() < 1e-12);
+        assert!((inverse.dec - star.dec).abs() < 1e-12);
+        assert!(inverse_lambert_azimuthal(2.0, 0.1, &center).is_err());
+    }
+
+    #[test]
+    fn test_hammer_aitoff() {
+        let (x, y) = hammer_aitoff(std::f64::consts::PI, 0.0, 0.0);
+        assert!((x - 2.0 * std::f64::consts::SQRT_2).abs() < 1e-12);
+        assert!(y.abs() < 1e-12);
+
+        let (long, lat) = (200.0_f64.to_radians(), -35.0_f64.to_radians());
+        let (x, y) = hammer_aitoff(long, lat, 266.4_f64.to_radians());
+        let (inverse_long, inverse_lat) =
+            inverse_hammer_aitoff(x, y, 266.4_f64.to_radians()).unwrap();
+        assert!((inverse_long - long).abs() < 1e-12);
+        assert!((inverse_lat - lat).abs() < 1e-12);
+        assert!(inverse_hammer_aitoff(2.9, 0.0, 0.0).is_err());
+    }
+}
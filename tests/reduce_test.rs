@@ -0,0 +1,62 @@
+// tests/reduce_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::Observer;
+use apollo::reduce::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn theta_persei() -> CatalogEntry {
+        CatalogEntry {
+            asc: angle::deg_hms(2, 44, 11.986).to_radians(),
+            dec: angle::deg_dmas(49, 13, 42.48).to_radians(),
+            pm_asc: angle::deg_hms(0, 0, 0.03425).to_radians(),
+            pm_dec: angle::deg_dmas(0, 0, -0.0895).to_radians(),
+            parallax: 0.0,
+            epoch: 2451545.0,
+        }
+    }
+
+    #[test]
+    fn test_apparent_place() {
+        // θ Persei on 2028 November 13.19 TD, Meeus example 23.a. At
+        // the pole the diurnal aberration vanishes.
+        let pole = Observer::new(0.0, 90_f64.to_radians(), 0.0);
+        let place = apparent_place(&theta_persei(), &pole, 2462088.69);
+
+        let arcsec = angle::deg_dmas(0, 0, 1.0).to_radians();
+        let asc = angle::deg_hms(2, 46, 14.39).to_radians();
+        let dec = angle::deg_dmas(49, 21, 7.45).to_radians();
+        assert!((place.eq_point.asc - asc).abs() < 0.05 * arcsec);
+        assert!((place.eq_point.dec - dec).abs() < 0.05 * arcsec);
+        assert!((place.alt - place.eq_point.dec).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_refracted_alt() {
+        let observer = Observer::new(
+            angle::deg_dmas(71, 5, 0.0).to_radians(),
+            angle::deg_dmas(42, 20, 0.0).to_radians(),
+            50.0,
+        );
+        let place = apparent_place(&theta_persei(), &observer, 2462088.69);
+
+        // θ Persei is circumpolar at Boston
+        assert!(place.alt > 0.0);
+        assert!(place.refracted_alt(1010.0, 283.0) > place.alt);
+
+        let mut parallax = theta_persei();
+        parallax.parallax = angle::deg_dmas(0, 0, 1.0).to_radians();
+        let shifted = apparent_place(&parallax, &observer, 2462088.69);
+        let sep = angle::angular_sep(
+            place.eq_point.asc,
+            place.eq_point.dec,
+            shifted.eq_point.asc,
+            shifted.eq_point.dec,
+        );
+        assert!(sep > 0.0 && sep < angle::deg_dmas(0, 0, 1.0).to_radians());
+    }
+}
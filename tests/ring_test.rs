@@ -0,0 +1,90 @@
+// tests/ring_test.rs
+
+extern crate apollo;
+use apollo::coordinate::EclPoint;
+use apollo::ring::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Heliocentric coordinates of Saturn from its mean orbital elements,
+    // referred to the mean equinox of the date (Meeus, Astronomical
+    // Algorithms, table 31.A). The perturbations by Jupiter, up to about
+    // 1°, are neglected.
+    fn saturn(jd: f64) -> (f64, f64, f64) {
+        let t = (jd - 2451545.0) / 36525.0;
+        let mean_long = (50.077471 + t * (1223.5110141 + t * 0.00051952)).to_radians();
+        let semimaj_axis = 9.554909081 - t * 0.0000021389;
+        let eccen = 0.05550862 - t * (0.000346818 + t * 0.0000006456);
+        let inc = (2.488878 - t * (0.0037363 + t * 0.00001516)).to_radians();
+        let asc_node = (113.665524 + t * (0.8770979 - t * 0.00012067)).to_radians();
+        let long_perih = (93.056787 + t * (1.9637694 + t * 0.00083757)).to_radians();
+
+        let mean_anom = mean_long - long_perih;
+        let mut eccen_anom = mean_anom;
+        for _ in 0..20 {
+            eccen_anom = mean_anom + eccen * eccen_anom.sin();
+        }
+        let true_anom =
+            2.0 * (((1.0 + eccen) / (1.0 - eccen)).sqrt() * (eccen_anom / 2.0).tan()).atan();
+        let rad_vec = semimaj_axis * (1.0 - eccen * eccen_anom.cos());
+        let arg_lat = true_anom + long_perih - asc_node;
+
+        (
+            asc_node + (inc.cos() * arg_lat.sin()).atan2(arg_lat.cos()),
+            (inc.sin() * arg_lat.sin()).asin(),
+            rad_vec,
+        )
+    }
+
+    #[test]
+    fn test_ring_tilt() {
+        // 1992 December 16.0 TD, Meeus example 45.a: B = 16.442° and
+        // B′ = 14.679°
+        let jd = 2448972.5;
+        let (long, lat, rad_vec) = saturn(jd);
+        let ring_tilt = ring_tilt(&RingedPlanet::Saturn, jd, long, lat, rad_vec);
+        assert!((ring_tilt.earth.to_degrees() - 16.442).abs() < 0.03);
+        assert!((ring_tilt.sun.to_degrees() - 14.679).abs() < 0.03);
+    }
+
+    #[test]
+    fn test_ring_pole() {
+        // The obliquity of Uranus is 97.77°, measured from the pole about
+        // which it rotates counterclockwise, opposite to the IAU north pole
+        let jd = 2451545.0;
+        let pole = ring_pole(&RingedPlanet::Uranus, jd);
+        let orbit_pole = EclPoint {
+            long: (74.005957_f64 - 90.0).to_radians(),
+            lat: (90.0 - 0.773197_f64).to_radians(),
+        };
+        let obliquity = pole.angular_sep(&orbit_pole).to_degrees();
+        assert!((obliquity - (180.0 - 97.77)).abs() < 0.1, "{}", obliquity);
+    }
+
+    #[test]
+    fn test_sun_crossings() {
+        // Equinoxes of Saturn on 1995 November 19, 2009 August 11 and
+        // 2025 May 6
+        let equinoxes = sun_crossings(&RingedPlanet::Saturn, 2449000.0, 2461500.0, saturn);
+        assert_eq!(equinoxes.len(), 3);
+        for (jd, expected) in equinoxes.iter().zip([2450040.5, 2455054.5, 2460801.5]) {
+            assert!((jd - expected).abs() < 7.0, "{}", jd);
+        }
+    }
+
+    #[test]
+    fn test_earth_crossings() {
+        // The rings were edge-on on 2009 September 4 and 2025 March 23
+        let crossings = earth_crossings(&RingedPlanet::Saturn, 2454500.0, 2461500.0, saturn);
+        assert_eq!(crossings.len(), 2);
+        for (jd, expected) in crossings.iter().zip([2455078.5, 2460757.5]) {
+            assert!((jd - expected).abs() < 3.0, "{}", jd);
+        }
+
+        let (long, lat, rad_vec) = saturn(crossings[0]);
+        let ring_tilt = ring_tilt(&RingedPlanet::Saturn, crossings[0], long, lat, rad_vec);
+        assert!(ring_tilt.earth.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,144 @@
+// tests/satellite_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::RectPoint;
+use apollo::planet::earth;
+use apollo::satellite::*;
+use apollo::time;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_j2_precession() {
+        // A circular orbit at 800 km, inclined by 98.6°
+        let a = earth::EQUATORIAL_RADIUS + 800.0;
+        let precession = j2_precession(a, 0.0, 98.6_f64.to_radians());
+        let node_rate = precession.node_rate.to_degrees() * 86400.0;
+        assert!((node_rate - 0.9856).abs() < 0.005, "{}", node_rate);
+
+        // No apsidal rotation at the critical inclination
+        let critical = (1.0_f64 / 5.0).sqrt().acos();
+        assert!(j2_precession(a, 0.1, critical).perigee_rate.abs() < 1e-15);
+
+        // Nodal regression of prograde orbits
+        assert!(j2_precession(a, 0.0, 0.9).node_rate < 0.0);
+    }
+
+    #[test]
+    fn test_sun_synchronous_inclination() {
+        let i = sun_synchronous_inclination(earth::EQUATORIAL_RADIUS + 800.0, 0.0)
+            .unwrap()
+            .to_degrees();
+        assert!((i - 98.6).abs() < 0.05, "{}", i);
+
+        assert!(sun_synchronous_inclination(earth::EQUATORIAL_RADIUS + 8000.0, 0.0).is_err());
+    }
+
+    const LINE_1: &str = "1 88888U          80275.98708465  .00073094  13844-3  66816-4 0    87";
+    const LINE_2: &str = "2 88888  72.8435 115.9689 0086731  52.6988 110.5714 16.05824518  1058";
+
+    #[test]
+    fn test_tle_parse() {
+        let tle = Tle::parse(LINE_1, LINE_2).unwrap();
+        assert_eq!(tle.catalog_number, 88888);
+        // 1980 October 1, 23h41m24s UT
+        assert!((tle.epoch - 2444514.48708465).abs() < 1e-8);
+        assert!((tle.bstar - 0.66816e-4).abs() < 1e-15);
+        assert!((tle.inclination.to_degrees() - 72.8435).abs() < 1e-12);
+        assert!((tle.eccentricity - 0.0086731).abs() < 1e-15);
+        assert_eq!(tle.mean_motion, 16.05824518);
+
+        let corrupted = LINE_2.replace("72.8435", "72.8436");
+        assert!(Tle::parse(LINE_1, &corrupted).is_err());
+        assert!(Tle::parse(LINE_2, LINE_1).is_err());
+        assert!(Tle::parse(LINE_1, &LINE_2[..60]).is_err());
+    }
+
+    #[test]
+    fn test_tle_position() {
+        // Test case of Spacetrack Report No. 3, with the results of
+        // Vallado et al. (2006)
+        let tle = Tle::parse(LINE_1, LINE_2).unwrap();
+        let expected = [
+            (0.0, [2328.96975262, -5995.22051338, 1719.97297192]),
+            (360.0, [2456.10706533, -6071.93855503, 1222.89768554]),
+            (1440.0, [2742.55398832, -6079.67009123, -326.39012649]),
+        ];
+        for (minutes, [x, y, z]) in expected {
+            let position = tle.position(tle.epoch + minutes / 1440.0).unwrap();
+            assert!((position.x - x).abs() < 1e-3, "{}", position.x);
+            assert!((position.y - y).abs() < 1e-3, "{}", position.y);
+            assert!((position.z - z).abs() < 1e-3, "{}", position.z);
+        }
+
+        // A 12 hour orbit needs SDP4
+        let deep_space = Tle {
+            mean_motion: 2.0,
+            ..tle
+        };
+        assert!(deep_space.position(tle.epoch).is_err());
+    }
+
+    #[test]
+    fn test_ground_track() {
+        let tle = Tle::parse(LINE_1, LINE_2).unwrap();
+        let track = ground_track(&tle, tle.epoch, tle.epoch + 0.5, 1.0 / 1440.0).unwrap();
+        assert_eq!(track.len(), 721);
+
+        // The latitude reaches the inclination, and the height stays
+        // within the perigee and apogee
+        let max_lat = track
+            .iter()
+            .map(|point| point.geograph_point.lat.to_degrees())
+            .fold(f64::MIN, f64::max);
+        assert!((max_lat - 72.8).abs() < 0.5, "{}", max_lat);
+        for point in &track {
+            assert!(
+                point.height > 100.0 && point.height < 400.0,
+                "{}",
+                point.height
+            );
+        }
+
+        let position = track[0];
+        let expected =
+            ground_track_with_propagator(|jd| tle.position(jd).unwrap(), tle.epoch, tle.epoch, 1.0)
+                .unwrap();
+        assert_eq!(position.height, expected[0].height);
+
+        assert!(ground_track(&tle, tle.epoch, tle.epoch + 1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_ground_track_with_propagator() {
+        // A satellite fixed over the vernal equinox, 7000 km from the
+        // Earth's center
+        let jd_start = 2460400.5;
+        let track = ground_track_with_propagator(
+            |_| RectPoint::new(7000.0, 0.0, 0.0),
+            jd_start,
+            jd_start + 1.0,
+            1.0 / 24.0,
+        )
+        .unwrap();
+        assert_eq!(track.len(), 25);
+
+        for point in &track {
+            let expected = angle::limit_pi(time::mean_sidereal(point.jd));
+            assert!((angle::limit_pi(point.geograph_point.long - expected)).abs() < 1e-12);
+            assert!(point.geograph_point.lat.abs() < 1e-12);
+            assert!((point.height - (7000.0 - earth::EQUATORIAL_RADIUS)).abs() < 1e-6);
+        }
+        assert!((track[24].jd - (jd_start + 1.0)).abs() < 1e-9);
+
+        let position = |_| RectPoint::new(7000.0, 0.0, 0.0);
+        assert!(ground_track_with_propagator(position, jd_start, jd_start + 1.0, 0.0).is_err());
+        assert!(ground_track_with_propagator(position, jd_start, jd_start + 1.0, -1.0).is_err());
+        assert!(
+            ground_track_with_propagator(position, jd_start, jd_start + 1.0, f64::NAN).is_err()
+        );
+    }
+}
@@ -0,0 +1,49 @@
+// tests/search_test.rs
+
+extern crate apollo;
+use apollo::search::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_intervals() {
+        let found = intervals(0.0, 4.0 * PI, 0.1, 1e-9, |t| t.sin() > 0.5).unwrap();
+        assert_eq!(found.len(), 2);
+        assert!((found[0].0 - PI / 6.0).abs() < 1e-8);
+        assert!((found[0].1 - 5.0 * PI / 6.0).abs() < 1e-8);
+        assert!((found[1].0 - 13.0 * PI / 6.0).abs() < 1e-8);
+
+        let clipped = intervals(1.0, 2.0, 0.1, 1e-9, |t| t.sin() > 0.5).unwrap();
+        assert_eq!(clipped, vec![(1.0, 2.0)]);
+
+        assert!(intervals(0.0, 1.0, 0.0, 1e-9, |t| t > 0.5).is_err());
+        assert!(changes(0.0, 1.0, f64::NAN, 1e-9, |t| t > 0.5).is_err());
+        assert!(changes(0.0, 1.0, 0.1, 0.0, |t| t > 0.5).is_err());
+    }
+
+    #[test]
+    fn test_minimize() {
+        let (t, value) = minimize(0.0, 10.0, 1.0, 1e-9, |t| (t - 3.3) * (t - 3.3) + 1.0).unwrap();
+        assert!((t - 3.3).abs() < 1e-6);
+        assert!((value - 1.0).abs() < 1e-12);
+
+        // The deeper of two minima
+        let (t, _) = minimize(0.0, 10.0, 0.5, 1e-9, |t| {
+            (t - 2.0).powi(2) * (t - 7.0).powi(2) - t
+        })
+        .unwrap();
+        assert!((t - 7.0).abs() < 0.05, "{}", t);
+
+        // A least value at the end of the range
+        let (t, value) = minimize(0.0, 10.0, 3.0, 1e-9, |t| -t).unwrap();
+        assert!((t - 10.0).abs() < 1e-6);
+        assert!((value + 10.0).abs() < 1e-6);
+
+        // A zero step would never advance
+        assert!(minimize(0.0, 10.0, 0.0, 1e-9, |t| t).is_err());
+        assert!(minimize(0.0, 10.0, -1.0, 1e-9, |t| t).is_err());
+    }
+}
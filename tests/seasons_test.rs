@@ -0,0 +1,26 @@
+// tests/seasons_test.rs
+
+extern crate apollo;
+use apollo::seasons::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beginning() {
+        // June solstice of 1962, Meeus example 27.a
+        let jd = beginning(1962, Season::Summer);
+        assert!((jd - 2437837.39245).abs() < 5e-4, "{}", jd);
+    }
+
+    #[test]
+    fn test_lengths() {
+        // Meeus, Astronomical Algorithms, table 27.D, for 2000
+        let lengths = lengths(2000);
+        assert!((lengths.spring - 92.76).abs() < 0.01, "{}", lengths.spring);
+        assert!((lengths.summer - 93.65).abs() < 0.01, "{}", lengths.summer);
+        assert!((lengths.autumn - 89.84).abs() < 0.01, "{}", lengths.autumn);
+        assert!((lengths.winter - 88.99).abs() < 0.01, "{}", lengths.winter);
+    }
+}
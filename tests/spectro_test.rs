@@ -0,0 +1,68 @@
+// tests/spectro_test.rs
+
+extern crate apollo;
+use apollo::spectro::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apollo::coordinate::{self, EqPoint, Observer};
+    use apollo::ecliptic;
+    use apollo::sun;
+    use apollo::Accuracy;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_doppler_shift() {
+        // Hα receding at 100 km/s
+        let observed = doppler_shift(100.0, 656.28);
+        assert!((observed - 656.4990).abs() < 1e-3);
+        assert!((velocity_from_z(z_from_wavelength(observed, 656.28)) - 100.0).abs() < 1e-9);
+
+        for &rv in &[-250000.0, -30.0, 0.0, 12.5, 150000.0] {
+            assert!((velocity_from_z(z_from_velocity(rv)) - rv).abs() < 1e-6);
+        }
+        assert!((wavelength_from_z(500.0, 0.1) - 550.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_diurnal_velocity() {
+        // A star rising due east, seen from the equator
+        let observer = Observer::new(0.0, 0.0, 0.0);
+        let v = diurnal_velocity(-FRAC_PI_2, 0.0, &observer);
+        assert!((v - 0.4651).abs() < 1e-4);
+        assert!((diurnal_velocity(FRAC_PI_2, 0.0, &observer) + v).abs() < 1e-12);
+        assert!(diurnal_velocity(0.0, 0.3, &observer).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_orbital_velocity() {
+        // The apex of the Earth's motion is 90° west of the Sun
+        let jd = 2460400.5;
+        let (sun, _) = sun::geocent_ecl_pos(jd, Accuracy::High);
+        let oblq_eclip = ecliptic::mean_obliquity_laskar(jd);
+        let apex_long = sun.long - FRAC_PI_2;
+        let apex = EqPoint::new(
+            coordinate::asc_from_ecliptic(apex_long, 0.0, oblq_eclip),
+            coordinate::dec_from_ecliptic(apex_long, 0.0, oblq_eclip),
+        );
+
+        let v = orbital_velocity(&apex, jd);
+        assert!(v > 29.2 && v < 30.4);
+
+        let antapex = EqPoint::new(apex.asc + std::f64::consts::PI, -apex.dec);
+        assert!((orbital_velocity(&antapex, jd) + v).abs() < 1e-9);
+
+        let observer = Observer::new(1.0, 0.5, 100.0);
+        let correction = velocity_correction(&apex, &observer, jd);
+        assert!((correction - v).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_corrected_velocity() {
+        assert!((corrected_velocity(20.0, 0.0) - 20.0).abs() < 1e-9);
+        // To first order, the correction adds to the measured velocity
+        assert!((corrected_velocity(20.0, 29.0) - 49.0).abs() < 0.01);
+        assert!((corrected_velocity(-29.0, 29.0)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,71 @@
+// tests/sphere_test.rs
+
+extern crate apollo;
+use apollo::sphere::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apollo::angle;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_triangle() {
+        // The octant triangle has right angles and quarter-circle sides
+        assert!((angle_from_sides(FRAC_PI_2, FRAC_PI_2, FRAC_PI_2) - FRAC_PI_2).abs() < 1e-12);
+        assert!((side_from_angles(FRAC_PI_2, FRAC_PI_2, FRAC_PI_2) - FRAC_PI_2).abs() < 1e-12);
+
+        // angle_a general triangle, solved back and forth
+        let (b, c, angle_a) = (0.7, 1.1, 0.9);
+        let a = side_from_sides_angle(b, c, angle_a);
+        assert!((angle_from_sides(a, b, c) - angle_a).abs() < 1e-12);
+
+        let angle_b = angle_from_sides(b, c, a);
+        let angle_c = angle_from_sides(c, a, b);
+        assert!((angle_from_angles_side(angle_b, angle_c, a) - angle_a).abs() < 1e-12);
+        assert!((side_from_angles(angle_a, angle_b, angle_c) - a).abs() < 1e-12);
+        assert!((angle_from_sines(a, angle_a, b).unwrap() - angle_b).abs() < 1e-12);
+
+        assert!(angle_from_sines(0.1, 1.2, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_great_circle_intersection() {
+        let equator = [(0.0, 0.0), (1.0, 0.0)];
+        let meridian = [(0.3, 0.2), (0.3, -1.0)];
+        let (p, q) = great_circle_intersection(&equator, &meridian).unwrap();
+
+        let (p, q) = if p.0 < PI { (p, q) } else { (q, p) };
+        assert!((p.0 - 0.3).abs() < 1e-12 && p.1.abs() < 1e-12);
+        assert!((q.0 - (0.3 + PI)).abs() < 1e-12 && q.1.abs() < 1e-12);
+
+        // Two inclined circles: the intersection lies on both
+        let circle_1 = [(0.1, 0.4), (1.3, -0.2)];
+        let circle_2 = [(2.0, 0.5), (0.4, -0.6)];
+        let (p, _) = great_circle_intersection(&circle_1, &circle_2).unwrap();
+        for circle in [circle_1, circle_2] {
+            let pole = cross_pole(&circle);
+            let v = rectangular(p.0, p.1);
+            assert!((pole[0] * v[0] + pole[1] * v[1] + pole[2] * v[2]).abs() < 1e-12);
+        }
+
+        assert!(great_circle_intersection(&equator, &[(2.0, 0.0), (3.0, 0.0)]).is_err());
+    }
+
+    #[test]
+    fn test_rectangular_spherical() {
+        let (long, lat) = spherical(&rectangular(-0.5, 0.8).map(|x| 3.0 * x));
+        assert!((long - angle::limit_twoPI(-0.5)).abs() < 1e-12);
+        assert!((lat - 0.8).abs() < 1e-12);
+    }
+
+    fn cross_pole(circle: &[(f64, f64); 2]) -> [f64; 3] {
+        let u = rectangular(circle[0].0, circle[0].1);
+        let v = rectangular(circle[1].0, circle[1].1);
+        [
+            u[1] * v[2] - u[2] * v[1],
+            u[2] * v[0] - u[0] * v[2],
+            u[0] * v[1] - u[1] * v[0],
+        ]
+    }
+}
@@ -0,0 +1,38 @@
+// tests/star_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::Observer;
+use apollo::reduce;
+use apollo::star::*;
+use std::f64::consts::PI;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polaris_hour_angle() {
+        let observer = Observer::new(
+            -angle::deg_dmas(2, 20, 14.0).to_radians(),
+            angle::deg_dmas(48, 51, 24.0).to_radians(),
+            35.0,
+        );
+        let jd = 2460400.75;
+        let hour_angle = polaris_hour_angle(jd, &observer);
+        assert!((0.0..2.0 * PI).contains(&hour_angle));
+
+        let place = reduce::apparent_place(&POLARIS, &observer, apollo::time::tt_from_ut(jd));
+        assert!(angle::limit_pi(hour_angle - place.hour_angle).abs() < 1e-12);
+
+        // In 2024 Polaris is about 38′ from the pole, near 3h of right
+        // ascension
+        assert!((90.0 - place.eq_point.dec.to_degrees() - 0.63).abs() < 0.01);
+        assert!((place.eq_point.asc.to_degrees() / 15.0 - 3.0).abs() < 0.1);
+
+        // Polaris turns once about the pole in a sidereal day, give or
+        // take its diurnal aberration, magnified near the pole
+        let later = polaris_hour_angle(jd + 0.25 / 1.0027379, &observer);
+        assert!((angle::limit_pi(later - hour_angle) - PI / 2.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,333 @@
+// tests/sun_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::{EqPoint, Observer};
+use apollo::frame::Frame;
+use apollo::sun::*;
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geocent_ecl_pos() {
+        // 1992 October 13.0 TD, Meeus example 25.b
+        //
+        // Meeus uses an abridged VSOP87, so the full series differs from
+        // the book by a few tenths of an arcsecond.
+        let (ecl_point, rad_vec) = geocent_ecl_pos(2448908.5, Accuracy::High);
+        assert!((ecl_point.long.to_degrees() - 199.907347).abs() < 1e-4);
+        assert!((ecl_point.lat.to_degrees() * 3600.0 - 0.62).abs() < 0.15);
+        assert!((rad_vec - 0.99760775).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_geocent_ecl_pos_low_accuracy() {
+        let (high, _) = geocent_ecl_pos(2448908.5, Accuracy::High);
+        let (low, _) = geocent_ecl_pos(2448908.5, Accuracy::Low);
+        assert!((high.long - low.long).abs().to_degrees() * 3600.0 < 5.0);
+    }
+
+    #[test]
+    fn test_approx_apparent_eq_pos() {
+        // 1992 October 13.0 TD, Meeus example 25.a
+        let (asc, dec) = approx_apparent_eq_pos::<f64>(2448908.5);
+        assert!((asc.to_degrees() - 198.38083).abs() < 1e-4);
+        assert!((dec.to_degrees() - -7.78507).abs() < 1e-4);
+
+        let (asc, dec) = approx_apparent_eq_pos::<f32>(2448908.5);
+        assert!((asc.to_degrees() - 198.38083).abs() < 1e-3);
+        assert!((dec.to_degrees() - -7.78507).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_horizontal_positions() {
+        let equator = Observer::new(0.0, 0.0, 0.0);
+        let positions = horizontal_positions(&equator, 2460389.5, 2460390.5, 1.0 / 24.0).unwrap();
+        assert_eq!(positions.len(), 25);
+
+        // Near the equinox, the Sun passes close to the zenith at noon
+        let (_, _, max_alt) = positions
+            .iter()
+            .copied()
+            .max_by(|a, b| a.2.total_cmp(&b.2))
+            .unwrap();
+        assert!((max_alt.to_degrees() - 90.0).abs() < 4.0);
+
+        assert!(horizontal_positions(&equator, 2460389.5, 2460390.5, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_solar_panel() {
+        // A panel facing south, tilted by the latitude, faces the Sun
+        // at noon on the equinox
+        let lat = 40_f64.to_radians();
+        let panel = SolarPanel::new(lat, 0.0);
+        let sun_alt = std::f64::consts::FRAC_PI_2 - lat;
+        assert!(panel.incidence_angle(0.0, sun_alt).abs() < 1e-6);
+
+        // Horizontal panel on the equator near the equinox:
+        // 24/π · S / R² for a 12 hour day
+        let equator = Observer::new(0.0, 0.0, 0.0);
+        let flat = SolarPanel::new(0.0, 0.0);
+        let date = apollo::time::Date {
+            year: 2024,
+            month: apollo::time::Month::Mar,
+            decimal_day: 20.0,
+            cal_type: apollo::time::CalType::Gregorian,
+        };
+        let insolation = flat.daily_insolation(&equator, &date);
+        let (_, rad_vec) = geocent_ecl_pos(2460390.0, Accuracy::Low);
+        let expected = 24.0 / std::f64::consts::PI * SOLAR_CONSTANT / (rad_vec * rad_vec);
+        assert!((insolation - expected).abs() / expected < 0.005);
+    }
+
+    #[test]
+    fn test_shadow_length() {
+        let equator = Observer::new(0.0, 0.0, 0.0);
+        // Near the equinox, at 3h and 9h UT the Sun is below and at
+        // about 45° altitude
+        assert_eq!(shadow_length(2.0, 2460389.625, &equator), None);
+        let length = shadow_length(2.0, 2460389.875, &equator).unwrap();
+        assert!((length - 2.0).abs() < 0.15);
+    }
+
+    #[test]
+    fn test_solar_noon() {
+        let boston =
+            Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0).time_zone(-4.0);
+        let date = apollo::time::Date {
+            year: 2024,
+            month: apollo::time::Month::Mar,
+            decimal_day: 20.0,
+            cal_type: apollo::time::CalType::Gregorian,
+        };
+
+        // 12h51m EDT, as transit::sun_rise_set()
+        let noon = solar_noon(&boston, &date);
+        let transit = apollo::transit::sun_rise_set(&boston, &date)
+            .transit
+            .unwrap();
+        assert!((noon.instant.jd_ut - transit.instant.jd_ut).abs() < 1.0 / 86400.0);
+        assert!(((noon.local_jd() - 2460389.5) * 24.0 - 12.858).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_solar_longitude_j2000() {
+        // At the March equinox of 2024 the apparent longitude of date is
+        // zero; referred to J2000.0 it is smaller by 24 years of precession
+        let solar_long = solar_longitude_j2000(2460389.6301).to_degrees();
+        assert!((solar_long - 359.665).abs() < 0.01, "{}", solar_long);
+    }
+
+    #[test]
+    fn test_jd_from_solar_longitude() {
+        // Maxima of the 2024 Perseids (140.0°), 2024 August 12 14h TT, and
+        // of the 2024 Quadrantids (283.15°), 2024 January 4 9h TT
+        let perseids = jd_from_solar_longitude(2024, 140_f64.to_radians());
+        assert!((perseids - 2460535.08).abs() < 0.02, "{}", perseids);
+        let quadrantids = jd_from_solar_longitude(2024, 283.15_f64.to_radians());
+        assert!((quadrantids - 2460313.87).abs() < 0.02, "{}", quadrantids);
+
+        for deg in [0.0_f64, 45.0, 90.0, 180.0, 279.0, 359.9] {
+            let solar_long = deg.to_radians();
+            let jd = jd_from_solar_longitude(2024, solar_long);
+            assert!((2460310.5..2460676.5).contains(&jd), "{}", jd);
+            assert!(angle::limit_pi(solar_longitude_j2000(jd) - solar_long).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_golden_hour() {
+        let boston =
+            Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0).time_zone(-4.0);
+        let date = apollo::time::Date {
+            year: 2024,
+            month: apollo::time::Month::Mar,
+            decimal_day: 20.0,
+            cal_type: apollo::time::CalType::Gregorian,
+        };
+
+        // About 54 minutes each, around sunrise and sunset
+        let golden = golden_hour(&boston, &date);
+        assert_eq!(golden.len(), 2);
+        let events = apollo::transit::sun_rise_set(&boston, &date);
+        let (rise, set) = (
+            events.rise.unwrap().instant.jd_ut,
+            events.set.unwrap().instant.jd_ut,
+        );
+        assert!(golden[0].0.instant.jd_ut < rise && rise < golden[0].1.instant.jd_ut);
+        assert!(golden[1].0.instant.jd_ut < set && set < golden[1].1.instant.jd_ut);
+        for (start, end) in &golden {
+            assert!(((end.instant.jd_ut - start.instant.jd_ut) * 1440.0 - 54.0).abs() < 3.0);
+            assert_eq!(start.offset, -4.0);
+        }
+
+        // Refraction raises the Sun, which reaches the upper limit of
+        // 6° earlier in the morning than without it
+        let refracted_end = golden[0].1.instant.jd_ut;
+        let (_, alt) = horizontal_pos(refracted_end, &boston, Accuracy::Low);
+        assert!(alt < 6_f64.to_radians() - angle::deg_dmas(0, 7, 0.0).to_radians());
+
+        // Tromsø at the summer solstice: the Sun stays above 3°, so the
+        // golden hour spans local midnight and there is no blue hour
+        let tromso = Observer::new(-18.96_f64.to_radians(), 69.65_f64.to_radians(), 0.0);
+        let solstice = apollo::time::Date {
+            month: apollo::time::Month::June,
+            decimal_day: 21.0,
+            ..date
+        };
+        assert!(!golden_hour(&tromso, &solstice).is_empty());
+        assert!(blue_hour(&tromso, &solstice).is_empty());
+    }
+
+    #[test]
+    fn test_blue_hour() {
+        let boston =
+            Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0).time_zone(-4.0);
+        let date = apollo::time::Date {
+            year: 2024,
+            month: apollo::time::Month::Mar,
+            decimal_day: 20.0,
+            cal_type: apollo::time::CalType::Gregorian,
+        };
+
+        // The blue hours adjoin the golden hours, about 11 minutes each
+        let golden = golden_hour(&boston, &date);
+        let blue = blue_hour(&boston, &date);
+        assert_eq!(blue.len(), 2);
+        assert!((blue[0].1.instant.jd_ut - golden[0].0.instant.jd_ut).abs() < 1.0 / 86400.0);
+        assert!((blue[1].0.instant.jd_ut - golden[1].1.instant.jd_ut).abs() < 1.0 / 86400.0);
+        for (start, end) in &blue {
+            assert!(((end.instant.jd_ut - start.instant.jd_ut) * 1440.0 - 11.0).abs() < 2.0);
+        }
+    }
+
+    #[test]
+    fn test_day_length() {
+        let boston =
+            Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0).time_zone(-4.0);
+        let date = apollo::time::Date {
+            year: 2024,
+            month: apollo::time::Month::Mar,
+            decimal_day: 20.0,
+            cal_type: apollo::time::CalType::Gregorian,
+        };
+
+        // A little over 12 hours at the equinox, because of refraction and
+        // the semidiameter of the Sun
+        let events = apollo::transit::sun_rise_set(&boston, &date);
+        let length = day_length(&boston, &date);
+        assert_eq!(
+            length,
+            events.set.unwrap().instant.jd_ut - events.rise.unwrap().instant.jd_ut
+        );
+        assert!((length * 24.0 - 12.18).abs() < 0.05, "{}", length * 24.0);
+
+        // Polar day and polar night at Tromsø
+        let tromso = Observer::new(-18.96_f64.to_radians(), 69.65_f64.to_radians(), 0.0);
+        let june = apollo::time::Date {
+            month: apollo::time::Month::June,
+            decimal_day: 21.0,
+            ..date
+        };
+        assert_eq!(day_length(&tromso, &june), 1.0);
+        let december = apollo::time::Date {
+            month: apollo::time::Month::Dec,
+            decimal_day: 21.0,
+            ..june
+        };
+        assert_eq!(day_length(&tromso, &december), 0.0);
+    }
+
+    #[test]
+    fn test_declination() {
+        // 1992 October 13.0 TD, Meeus example 25.b
+        assert!((declination(2448908.5).to_degrees() + 7.783872).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_longest_shortest_day() {
+        // 2024: 15h17m on June 20 and 9h05m on December 21 in Boston
+        let boston =
+            Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0).time_zone(-5.0);
+        let ((longest, longest_length), (shortest, shortest_length)) =
+            longest_shortest_day(2024, &boston);
+        assert_eq!(
+            (longest.month, longest.decimal_day),
+            (apollo::time::Month::June, 20.0)
+        );
+        assert_eq!(
+            (shortest.month, shortest.decimal_day),
+            (apollo::time::Month::Dec, 21.0)
+        );
+        assert!((longest_length * 24.0 - 15.28).abs() < 0.02);
+        assert!((shortest_length * 24.0 - 9.08).abs() < 0.02);
+
+        // The seasons are reversed in Sydney
+        let sydney =
+            Observer::new(-151.21_f64.to_radians(), -33.87_f64.to_radians(), 0.0).time_zone(10.0);
+        let ((longest, _), (shortest, _)) = longest_shortest_day(2024, &sydney);
+        assert_eq!(longest.month, apollo::time::Month::Dec);
+        assert_eq!(shortest.month, apollo::time::Month::June);
+    }
+
+    #[test]
+    fn test_semidiameter_and_parallax() {
+        assert!((semidiameter(1.0).to_degrees() * 3600.0 - 959.63).abs() < 1e-9);
+        assert!((horizontal_parallax(1.0).to_degrees() * 3600.0 - 8.794).abs() < 1e-9);
+
+        // Near perihelion the Sun appears larger
+        assert!((semidiameter(0.983).to_degrees() * 3600.0 - 976.23).abs() < 0.01);
+        assert!(horizontal_parallax(1.017) < horizontal_parallax(0.983));
+    }
+
+    #[test]
+    fn test_rectangular_coords() {
+        // 1992 October 13.0 TD, Meeus example 26.a, referred to the mean
+        // equinox of the date and precessed to J2000.0
+        let jd = 2448908.5;
+        let meeus = apollo::coordinate::RectPoint::new(-0.9379952, -0.3116544, -0.1351215);
+        let (asc, dec, dist) = meeus.to_spherical();
+        let j2000 = apollo::precession::precess_eq(&EqPoint::new(asc, dec), jd, 2451545.0);
+        let expected = apollo::coordinate::RectPoint::from_spherical(j2000.asc, j2000.dec, dist);
+
+        let rect_point = rectangular_coords(jd, Frame::Fk5J2000);
+        assert!((rect_point.x - expected.x).abs() < 2e-6, "{}", rect_point.x);
+        assert!((rect_point.y - expected.y).abs() < 2e-6, "{}", rect_point.y);
+        assert!((rect_point.z - expected.z).abs() < 2e-6, "{}", rect_point.z);
+
+        // The frame bias is a few milliarcseconds
+        let icrs = rectangular_coords(jd, Frame::Icrs);
+        assert!((icrs.x - rect_point.x).abs() < 1e-7);
+        assert!((icrs.y - rect_point.y).abs() < 1e-7);
+        assert!((icrs.z - rect_point.z).abs() < 1e-7);
+
+        let (_, rad_vec) = geocent_ecl_pos(jd, Accuracy::High);
+        let of_date = rectangular_coords(jd, Frame::EquinoxOfDate(jd));
+        assert!((of_date.dist() - rad_vec).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_analemma() {
+        let greenwich = Observer::new(0.0, 51.48_f64.to_radians(), 0.0);
+        let points: Vec<_> = analemma(&greenwich, 12.0, 2023).collect();
+        assert_eq!(points.len(), 365);
+        assert_eq!(analemma(&greenwich, 12.0, 2024).len(), 366);
+
+        // Noon altitudes between the solstices, 90° - φ ± ε
+        let alts = points.iter().map(|point| point.2.to_degrees());
+        let max_alt = alts.clone().fold(f64::MIN, f64::max);
+        let min_alt = alts.fold(f64::MAX, f64::min);
+        assert!((max_alt - 61.96).abs() < 0.1, "{}", max_alt);
+        assert!((min_alt - 15.08).abs() < 0.1, "{}", min_alt);
+
+        // The equation of time swings the Sun a few degrees either
+        // side of the meridian
+        for &(_, az, _) in &points {
+            assert!(angle::limit_pi(az).to_degrees().abs() < 6.0);
+        }
+    }
+}
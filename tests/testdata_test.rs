@@ -0,0 +1,153 @@
+// tests/testdata_test.rs
+#![cfg(feature = "testdata")]
+
+extern crate apollo;
+use apollo::testdata::*;
+use apollo::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // One arcsecond | in radians
+    const ARCSEC: f64 = std::f64::consts::PI / 648000.0;
+
+    #[test]
+    fn test_julian_days() {
+        for example in &JULIAN_DAYS {
+            let jd = time::julian_day(&example.date);
+            assert!(
+                (jd - example.jd).abs() < 1e-6,
+                "{}: {}",
+                example.example,
+                jd
+            );
+        }
+    }
+
+    #[test]
+    fn test_sidereal_times() {
+        let millisecond = angle::deg_hms(0, 0, 0.001).to_radians();
+        for example in &SIDEREAL_TIMES {
+            let mean = time::mean_sidereal(example.jd_ut);
+            assert!(
+                (mean - example.mean_sidereal).abs() < millisecond,
+                "{}",
+                example.example
+            );
+
+            if let Some(apparent) = example.apparent_sidereal {
+                let sidereal =
+                    time::apparent_sidereal_jd(example.jd_ut, ecliptic::ObliquityModel::IAU);
+                assert!(
+                    (sidereal - apparent).abs() < millisecond,
+                    "{}",
+                    example.example
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_geodesic() {
+        let dist = planet::earth::geodesic_distance(&EXAMPLE_11C.p1, &EXAMPLE_11C.p2);
+        assert!((dist - EXAMPLE_11C.dist).abs() < 0.01);
+        let dist = planet::earth::approximate_geodesic_distance(&EXAMPLE_11C.p1, &EXAMPLE_11C.p2);
+        assert!((dist - EXAMPLE_11C.approx_dist).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let (asc, dec) = (EXAMPLE_13A.eq_point.asc, EXAMPLE_13A.eq_point.dec);
+        let long = coordinate::ecliptic_long_from_eq(asc, dec, EXAMPLE_13A.oblq_eclip);
+        let lat = coordinate::ecliptic_lat_from_eq(asc, dec, EXAMPLE_13A.oblq_eclip);
+        assert!((long - EXAMPLE_13A.ecl_point.long).abs() < 1e-8);
+        assert!((lat - EXAMPLE_13A.ecl_point.lat).abs() < 1e-8);
+
+        let (hour_angle, dec) = (EXAMPLE_13B.hour_angle, EXAMPLE_13B.dec);
+        let az = coordinate::azimuth_from_eq(hour_angle, dec, EXAMPLE_13B.observer_lat);
+        let alt = coordinate::altitude_from_eq(hour_angle, dec, EXAMPLE_13B.observer_lat);
+        assert!((az - EXAMPLE_13B.az).abs() < 2e-6);
+        assert!((alt - EXAMPLE_13B.alt).abs() < 2e-6);
+
+        let angular_sep = EXAMPLE_17A.p1.angular_sep(&EXAMPLE_17A.p2);
+        assert!((angular_sep - EXAMPLE_17A.angular_sep).abs() < 2e-6);
+    }
+
+    #[test]
+    fn test_precession() {
+        let eq_point = precession::precess_eq(
+            &EXAMPLE_21B.eq_point_from,
+            EXAMPLE_21B.jd_from,
+            EXAMPLE_21B.jd_to,
+        );
+        assert!((eq_point.asc - EXAMPLE_21B.eq_point_to.asc).abs() < 0.01 * ARCSEC);
+        assert!((eq_point.dec - EXAMPLE_21B.eq_point_to.dec).abs() < 0.01 * ARCSEC);
+    }
+
+    #[test]
+    fn test_nutation() {
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(EXAMPLE_22A.jd, Accuracy::High);
+        assert!((nut_in_long - EXAMPLE_22A.nut_in_long).abs() < 0.001 * ARCSEC);
+        assert!((nut_in_oblq - EXAMPLE_22A.nut_in_oblq).abs() < 0.001 * ARCSEC);
+
+        let mean_oblq = ecliptic::mean_obliquity_IAU(EXAMPLE_22A.jd);
+        assert!((mean_oblq - EXAMPLE_22A.mean_oblq).abs() < 0.001 * ARCSEC);
+        assert!((mean_oblq + nut_in_oblq - EXAMPLE_22A.true_oblq).abs() < 0.002 * ARCSEC);
+    }
+
+    #[test]
+    fn test_sun() {
+        let (asc, dec) = sun::approx_apparent_eq_pos::<f64>(EXAMPLE_25A.jd);
+        assert!((asc - EXAMPLE_25A.eq_point.asc).abs() < 2e-6);
+        assert!((dec - EXAMPLE_25A.eq_point.dec).abs() < 2e-6);
+
+        // Meeus uses an abridged VSOP87, so the full series differs from
+        // the book by a few tenths of an arcsecond
+        let (eq_point, dist) =
+            ephemeris::apparent_eq_pos(&ephemeris::Body::Sun, EXAMPLE_25B.jd, Accuracy::High);
+        assert!((eq_point.asc - EXAMPLE_25B.eq_point.asc).abs() < ARCSEC);
+        assert!((eq_point.dec - EXAMPLE_25B.eq_point.dec).abs() < ARCSEC);
+        assert!((dist - EXAMPLE_25B.rad_vec).abs() < 1e-6);
+
+        let eq_of_time = planet::earth::equation_of_time(
+            EXAMPLE_28A.jd,
+            EXAMPLE_28A.sun_asc,
+            EXAMPLE_28A.nut_in_long,
+            EXAMPLE_28A.true_oblq,
+        );
+        assert!((eq_of_time - EXAMPLE_28A.eq_of_time).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_planet_phase() {
+        let phase_angle = phase::phase_angle(
+            EXAMPLE_41A.body_sun_dist,
+            EXAMPLE_41A.body_earth_dist,
+            EXAMPLE_41A.earth_sun_dist,
+        );
+        let illuminated_fraction = phase::illuminated_fraction(phase_angle);
+        assert!((illuminated_fraction - EXAMPLE_41A.illuminated_fraction).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_moon() {
+        let (ecl_point, dist) = lunar::geocent_ecl_pos(EXAMPLE_47A.jd, Accuracy::High);
+        assert!((ecl_point.long - EXAMPLE_47A.ecl_point.long).abs() < 1e-8);
+        assert!((ecl_point.lat - EXAMPLE_47A.ecl_point.lat).abs() < 1e-8);
+        assert!((dist - EXAMPLE_47A.earth_moon_dist).abs() < 0.1);
+
+        let (eq_point, _) =
+            ephemeris::apparent_eq_pos(&ephemeris::Body::Moon, EXAMPLE_47A.jd, Accuracy::High);
+        assert!((eq_point.asc - EXAMPLE_47A.eq_point.asc).abs() < ARCSEC);
+        assert!((eq_point.dec - EXAMPLE_47A.eq_point.dec).abs() < ARCSEC);
+
+        let phase_angle = lunar::phase_angle(EXAMPLE_48A.jd, Accuracy::High);
+        assert!((phase_angle - EXAMPLE_48A.phase_angle).abs() < 2e-5);
+        let illuminated_fraction = lunar::illuminated_fraction(phase_angle);
+        assert!((illuminated_fraction - EXAMPLE_48A.illuminated_fraction).abs() < 1e-4);
+
+        let new_moon = lunar::next_new_moon(EXAMPLE_49A - 10.0);
+        assert!((new_moon - EXAMPLE_49A).abs() < 30.0 / 86400.0);
+    }
+}
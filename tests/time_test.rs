@@ -0,0 +1,384 @@
+// tests/time_test.rs
+
+extern crate apollo;
+use apollo::ecliptic::ObliquityModel;
+use apollo::time::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instant() {
+        // 2024 March 20, 0h UT
+        let jd_ut = 2460389.5;
+
+        let instant = Instant::from_ut(jd_ut);
+        assert_eq!(instant.jd_ut, jd_ut);
+        assert!((instant.delta_t() - delta_t(2024, 3)).abs() < 1e-3);
+
+        let instant = Instant::from_tt(instant.jd_tt);
+        assert!((instant.jd_ut - jd_ut).abs() < 1e-3 / 86400.0);
+    }
+
+    #[test]
+    fn test_ut_from_tt() {
+        for &jd_ut in &[2415020.5, 2451545.0, 2460389.5] {
+            let jd_tt = tt_from_ut(jd_ut);
+            assert!((ut_from_tt(jd_tt) - jd_ut).abs() < 1e-3 / 86400.0);
+        }
+    }
+
+    #[test]
+    fn test_local_midnight() {
+        let date = Date {
+            year: 2024,
+            month: Month::Mar,
+            decimal_day: 20.75,
+            cal_type: CalType::Gregorian,
+        };
+        assert_eq!(local_midnight(&date, 0.0), 2460389.5);
+        assert_eq!(local_midnight(&date, -4.0), 2460389.5 + 4.0 / 24.0);
+    }
+
+    #[test]
+    fn test_sidereal_solar_interval() {
+        let sidereal = sidereal_from_solar_interval(24.0);
+        assert!((sidereal - 24.0657098244).abs() < 1e-9);
+        assert!((solar_from_sidereal_interval(sidereal) - 24.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_apparent_solar_from_local_mean() {
+        // Meeus example 28.a: E = +13m 42.6s on 1992 October 13.0 TD
+        let jd = ut_from_tt(2448908.5);
+        let apparent_solar = apparent_solar_from_local_mean(0.0, jd);
+        assert!((apparent_solar * 60.0 - 13.710).abs() < 0.01);
+
+        let apparent_solar = apparent_solar_from_local_mean(23.9, jd);
+        assert!((apparent_solar - (23.9 + 13.710 / 60.0 - 24.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_local_sidereal_from_date() {
+        // 1987 April 10, 0h UT, Meeus example 12.a: apparent sidereal
+        // time 13h 10m 46.1351s, given as 19h local time on April 9 in
+        // a zone 5 hours behind UT
+        let date = Date {
+            year: 1987,
+            month: Month::Apr,
+            decimal_day: 0.0,
+            cal_type: CalType::Gregorian,
+        };
+        let day_of_month = DayOfMonth {
+            day: 9,
+            hr: 19,
+            min: 0,
+            sec: 0.0,
+            time_zone: -5.0,
+        };
+        let expected = apollo::angle::deg_hms(13, 10, 46.1351).to_radians();
+
+        let sidereal = local_sidereal_from_date(&date, &day_of_month, 0.0);
+        assert!((sidereal - expected).abs() < apollo::angle::deg_hms(0, 0, 0.001).to_radians());
+
+        let observer_long = 77_f64.to_radians();
+        let local = local_sidereal_from_date(&date, &day_of_month, observer_long);
+        assert!((local - (expected - observer_long)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_zoned_instant() {
+        // 2000 January 1, 18h UT is 10h on the same day in PST
+        let instant = Instant::from_ut(2451545.25);
+        let local = instant.to_local(-8.0);
+
+        assert!((local.local_jd() - 2451544.916666667).abs() < 1e-9);
+
+        let date_time = local.local_date_time().unwrap();
+        let day_of_month = date_time.day_of_month;
+        assert_eq!((date_time.year, date_time.month), (2000, Month::Jan));
+        assert_eq!(
+            (day_of_month.day, day_of_month.hr, day_of_month.min),
+            (1, 10, 0)
+        );
+        assert_eq!(day_of_month.sec, 0.0);
+        assert_eq!(day_of_month.time_zone, -8.0);
+
+        // and 3h on January 2 at UTC+9
+        let day_of_month = local.to_local(9.0).local_date_time().unwrap().day_of_month;
+        assert_eq!((day_of_month.day, day_of_month.hr), (2, 3));
+        assert_eq!(local.to_local(9.0).instant, instant);
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_zoned_instant_named_zone() {
+        // Daylight saving time is in force in New York on 2024 July 1,
+        // and not on 2024 January 1
+        let summer = Instant::from_ut(julian_day(&Date {
+            year: 2024,
+            month: Month::July,
+            decimal_day: 1.5,
+            cal_type: CalType::Gregorian,
+        }));
+        let winter = Instant::from_ut(julian_day(&Date {
+            year: 2024,
+            month: Month::Jan,
+            decimal_day: 1.5,
+            cal_type: CalType::Gregorian,
+        }));
+
+        assert_eq!(summer.to_zone(chrono_tz::America::New_York).offset, -4.0);
+        assert_eq!(winter.to_zone(chrono_tz::America::New_York).offset, -5.0);
+    }
+
+    #[test]
+    fn test_delta_t_with_uncertainty() {
+        let measured = delta_t_with_uncertainty(2000, 1);
+        assert_eq!(measured.value, delta_t(2000, 1));
+        assert_eq!(measured.sigma, 1.0);
+
+        // About 4 minutes of uncertainty in the first century
+        assert!((delta_t_with_uncertainty(0, 1).sigma - 266.0).abs() < 1.0);
+
+        // The extrapolation grows more uncertain with time
+        assert!(delta_t_with_uncertainty(2100, 1).sigma > delta_t_with_uncertainty(2030, 1).sigma);
+        assert!(delta_t_with_uncertainty(2030, 1).sigma > 1.0);
+    }
+
+    #[test]
+    fn test_delta_t() {
+        // Values tabulated with the polynomial expressions by Espenak
+        // and Meeus, at least one in each expression whose nested form
+        // had sign errors | in seconds
+        for &(year, expected, tolerance) in &[
+            (-500, 17190.0, 20.0),
+            (-200, 12790.0, 20.0),
+            (0, 10580.0, 10.0),
+            (400, 6700.0, 10.0),
+            (500, 5710.0, 10.0),
+            (1000, 1570.0, 5.0),
+            (1750, 13.4, 0.5),
+            (1850, 7.1, 0.5),
+            (1880, -5.4, 0.5),
+            (1890, -5.9, 0.5),
+            (1900, -2.8, 0.5),
+            (1910, 10.4, 0.5),
+            (1950, 29.1, 0.5),
+            (2000, 63.8, 0.5),
+        ] {
+            assert!((delta_t(year, 1) - expected).abs() < tolerance, "{}", year);
+        }
+    }
+
+    #[test]
+    fn test_month() {
+        assert_eq!(Month::try_from(1), Ok(Month::Jan));
+        assert_eq!(Month::try_from(12), Ok(Month::Dec));
+        assert!(Month::try_from(0).is_err());
+        assert!(Month::try_from(13).is_err());
+
+        assert_eq!(Month::Dec.next(), Month::Jan);
+        assert_eq!(Month::Jan.prev(), Month::Dec);
+        assert_eq!(Month::June.next(), Month::July);
+        assert_eq!(Month::June.prev(), Month::May);
+
+        let months: Vec<Month> = Month::iter().collect();
+        assert_eq!(months.len(), 12);
+        for (i, month) in months.iter().enumerate() {
+            assert_eq!(*month as usize, i + 1);
+        }
+
+        assert_eq!(Month::Feb.days(2024, &CalType::Gregorian), 29);
+        assert_eq!(Month::Feb.days(1900, &CalType::Gregorian), 28);
+        assert_eq!(Month::Feb.days(1900, &CalType::Julian), 29);
+        assert_eq!(Month::Sept.days(2024, &CalType::Gregorian), 30);
+        let year_days: u16 = Month::iter()
+            .map(|month| month.days(2023, &CalType::Gregorian) as u16)
+            .sum();
+        assert_eq!(year_days, 365);
+    }
+
+    #[test]
+    fn test_date_time() {
+        // 1957 October 4, 19h 26m 24s UT, Meeus example 7.a, at 0h 56m
+        // 24s on October 5 in India Standard Time
+        let date_time = DateTime {
+            year: 1957,
+            month: Month::Oct,
+            day_of_month: DayOfMonth {
+                day: 5,
+                hr: 0,
+                min: 56,
+                sec: 24.0,
+                time_zone: 5.5,
+            },
+            cal_type: CalType::Gregorian,
+        };
+        assert!((date_time.to_julian_day() - 2436116.31).abs() < 1e-9);
+        assert_eq!(
+            DateTime::from_julian_day(date_time.to_julian_day(), 5.5).unwrap(),
+            date_time
+        );
+
+        let utc = DateTime::from_julian_day(2436116.31, 0.0).unwrap();
+        assert_eq!(
+            (utc.year, utc.month, utc.day_of_month.day),
+            (1957, Month::Oct, 4)
+        );
+        assert_eq!((utc.day_of_month.hr, utc.day_of_month.min), (19, 26));
+        assert_eq!(utc.day_of_month.sec, 24.0);
+
+        // 333 January 27, 12h, Meeus example 7.b, in the Julian calendar
+        let julian = DateTime::from_julian_day(1842713.0, 0.0).unwrap();
+        assert_eq!(
+            (julian.year, julian.month, julian.day_of_month.day),
+            (333, Month::Jan, 27)
+        );
+        assert_eq!(julian.cal_type, CalType::Julian);
+        assert_eq!(julian.to_julian_day(), 1842713.0);
+    }
+
+    #[test]
+    fn test_julian_day_number() {
+        let date = |year, month, decimal_day| Date {
+            year,
+            month,
+            decimal_day,
+            cal_type: CalType::Gregorian,
+        };
+
+        assert_eq!(julian_day_number(&date(2000, Month::Jan, 1.5)), 2451545);
+        assert_eq!(julian_day_number(&date(2000, Month::Jan, 1.0)), 2451545);
+        assert_eq!(julian_day_number(&date(2000, Month::Jan, 1.99)), 2451545);
+        assert_eq!(julian_day_number(&date(1858, Month::Nov, 17.0)), 2400001);
+    }
+
+    #[test]
+    fn test_iso_week() {
+        let date = |year, month, decimal_day| Date {
+            year,
+            month,
+            decimal_day,
+            cal_type: CalType::Gregorian,
+        };
+
+        assert_eq!(iso_week(&date(2024, Month::Jan, 1.0)), (2024, 1));
+        assert_eq!(iso_week(&date(2024, Month::Dec, 30.0)), (2025, 1));
+        assert_eq!(iso_week(&date(2021, Month::Jan, 3.0)), (2020, 53));
+        assert_eq!(iso_week(&date(2021, Month::Jan, 4.0)), (2021, 1));
+        assert_eq!(iso_week(&date(2026, Month::Oct, 16.5)), (2026, 42));
+    }
+
+    #[test]
+    fn test_sidereal_rate() {
+        // A step exact in binary, so that the Julian days are exact
+        let jd = 2446895.5;
+        let step = 0.125;
+
+        let mean_rate = mean_sidereal_rate(jd);
+        assert!((mean_rate.to_degrees() - 360.9856474).abs() < 1e-6);
+        let numerical =
+            apollo::angle::limit_pi(mean_sidereal(jd + step) - mean_sidereal(jd - step))
+                / (2.0 * step);
+        assert!((mean_rate - numerical).abs() < 1e-9);
+
+        let apparent = |jd: f64| apparent_sidereal_jd(jd, ObliquityModel::Laskar);
+        let numerical =
+            apollo::angle::limit_pi(apparent(jd + step) - apparent(jd - step)) / (2.0 * step);
+        assert!((apparent_sidereal_rate(jd) - numerical).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apparent_sidereal_jd() {
+        // 1987 April 10, 0h UT, Meeus example 12.a
+        let expected = apollo::angle::deg_hms(13, 10, 46.1351).to_radians();
+        let sidereal = apparent_sidereal_jd(2446895.5, ObliquityModel::Laskar);
+        assert!((sidereal - expected).abs() < apollo::angle::deg_hms(0, 0, 0.001).to_radians());
+
+        // The two models differ by a fraction of a milliarcsecond in
+        // the equation of the equinoxes
+        let iau = apparent_sidereal_jd(2446895.5, ObliquityModel::IAU);
+        assert!((iau - sidereal).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_refine_ut_event() {
+        // The new Moon of 1977 February 18, Meeus example 49.a, from an
+        // estimate a day early
+        let new_moon = |jd_tt: f64| apollo::lunar::next_new_moon(jd_tt - 2.0);
+        let jd_ut = refine_ut_event(new_moon, 2443191.6);
+        let jd_tt = apollo::lunar::next_new_moon(2443190.0);
+        assert!((jd_ut - ut_from_tt(jd_tt)).abs() < 1e-3 / 86400.0);
+        assert!((tt_from_ut(jd_ut) - jd_tt).abs() < 1e-3 / 86400.0);
+    }
+
+    #[test]
+    fn test_apparent_solar_time_and_noon() {
+        use apollo::coordinate::Observer;
+
+        let boston =
+            Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0).time_zone(-4.0);
+        let date = Date {
+            year: 2024,
+            month: Month::Mar,
+            decimal_day: 20.0,
+            cal_type: CalType::Gregorian,
+        };
+
+        let noon = local_apparent_noon(&boston, &date);
+        let solar_noon = apollo::sun::solar_noon(&boston, &date);
+        assert!((noon.jd_ut - solar_noon.instant.jd_ut).abs() < 2.0 / 86400.0);
+
+        let apparent_solar = apparent_solar_time(noon.jd_ut, boston.long);
+        assert!((apparent_solar - 12.0).abs() < 1e-4, "{}", apparent_solar);
+
+        // The equation of time is about -7.5 minutes on the equinox
+        let apparent_solar = apparent_solar_time(2460390.0, 0.0);
+        assert!(
+            (apparent_solar - (12.0 - 7.5 / 60.0)).abs() < 0.01,
+            "{}",
+            apparent_solar
+        );
+    }
+
+    #[test]
+    fn test_delta_t_model() {
+        assert_eq!(DeltaTModel::EspenakMeeus.delta_t(2000, 1), delta_t(2000, 1));
+        assert!((DeltaTModel::LongTermParabola.delta_t(1825, 1) + 320.0).abs() < 0.01);
+        assert!((DeltaTModel::LongTermParabola.delta_t(-500, 7) - 17240.1).abs() < 0.1);
+
+        static TABLE: [(f64, f64); 3] = [(1900.0, -2.7), (1950.0, 29.1), (2000.0, 63.8)];
+        let table = DeltaTModel::Table(&TABLE);
+        let residual =
+            table.delta_t(1925, 1) - (-2.7 + 31.8 * (1925.0 + 1.0 / 24.0 - 1900.0) / 50.0);
+        assert!(residual.abs() < 1e-9);
+
+        // Beyond the ends, the table is blended into the parabola
+        let end = DeltaTModel::Table(&TABLE[2..]);
+        assert!((end.delta_t(2000, 1) - 63.8).abs() < 0.1);
+        let far = DeltaTModel::LongTermParabola.delta_t(2300, 1);
+        assert!((table.delta_t(2300, 1) - far).abs() < 1e-9);
+        assert!((DeltaTModel::Table(&[]).delta_t(2300, 1) - far).abs() < 1e-9);
+
+        fn no_delta_t(_: i32, _: u8) -> f64 {
+            0.0
+        }
+        assert_eq!(DeltaTModel::Function(no_delta_t).delta_t(2000, 1), 0.0);
+    }
+
+    #[test]
+    fn test_conversions_with_model() {
+        // An eclipse in antiquity, mapped to UT under two models
+        let jd_tt = 1_500_000.5;
+        for model in [DeltaTModel::EspenakMeeus, DeltaTModel::LongTermParabola] {
+            let jd_ut = ut_from_tt_with_model(jd_tt, &model);
+            assert!((tt_from_ut_with_model(jd_ut, &model) - jd_tt).abs() < 1e-6);
+        }
+
+        let difference = ut_from_tt_with_model(jd_tt, &DeltaTModel::EspenakMeeus)
+            - ut_from_tt_with_model(jd_tt, &DeltaTModel::LongTermParabola);
+        assert!(difference.abs() * 86400.0 > 60.0);
+    }
+}
@@ -0,0 +1,190 @@
+// tests/transit_test.rs
+
+extern crate apollo;
+use apollo::coordinate::{EqPoint, GeographPoint, Observer};
+use apollo::ecliptic::ObliquityModel;
+use apollo::ephemeris::Body;
+use apollo::time::ZonedInstant;
+use apollo::transit::*;
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rise_transit_set() {
+        // Venus at Boston on 1988 March 20, Meeus example 15.a
+        let eq_point = |asc: f64, dec: f64| EqPoint::new(asc.to_radians(), dec.to_radians());
+        let eq_points = [
+            eq_point(40.68021, 18.04761),
+            eq_point(41.73129, 18.44092),
+            eq_point(42.78204, 18.82742),
+        ];
+        let boston = GeographPoint::new(71.0833_f64.to_radians(), 42.3333_f64.to_radians());
+        let h0 = StandardAltitude::Star;
+
+        let (rise, transit, set) =
+            rise_transit_set(&eq_points, &boston, h0, 2447240.5, 56.0).unwrap();
+        assert!((rise - 2447241.01766).abs() < 1e-4);
+        assert!((transit - 2447241.31980).abs() < 1e-4);
+        assert!((set - 2447240.62130).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_standard_altitude() {
+        let arcmin = |alt: f64| alt.to_degrees() * 60.0;
+        assert!((arcmin(StandardAltitude::Star.alt()) + 34.0).abs() < 1e-9);
+        assert!((arcmin(StandardAltitude::Sun.alt()) + 50.0).abs() < 1e-9);
+        assert_eq!(StandardAltitude::Custom(-0.1).alt(), -0.1);
+
+        // 0.7275 × 57′ − 34′ at a horizontal parallax of 57′
+        let moon = StandardAltitude::Moon(57.0_f64.to_radians() / 60.0);
+        assert!((arcmin(moon.alt()) - 7.4675).abs() < 1e-9);
+
+        assert_eq!(
+            StandardAltitude::for_body(&Body::Sun, 1.0),
+            StandardAltitude::Sun
+        );
+        assert_eq!(
+            StandardAltitude::for_body(&Body::Mars, 1.0),
+            StandardAltitude::Star
+        );
+        match StandardAltitude::for_body(&Body::Moon, 384400.0 / 149597870.7) {
+            StandardAltitude::Moon(parallax) => assert!((arcmin(parallax) - 57.04).abs() < 0.01),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_body_rise_transit_set() {
+        let tromso = GeographPoint::new(-18.95_f64.to_radians(), 69.65_f64.to_radians());
+        // Midnight sun on 2024 June 21
+        assert!(body_rise_transit_set(&Body::Sun, 2460482.5, &tromso, Accuracy::Low).is_err());
+
+        // Polar night on 2023 December 21
+        assert!(body_rise_transit_set(&Body::Sun, 2460299.5, &tromso, Accuracy::Low).is_err());
+
+        // 2024 March 20
+        let (rise, transit, set) =
+            body_rise_transit_set(&Body::Sun, 2460389.5, &tromso, Accuracy::Low).unwrap();
+        assert!(rise < transit && transit < set);
+        assert!((set - rise - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_moon_rise_set() {
+        let boston = Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0);
+
+        let date = |day: f64| apollo::time::Date {
+            year: 2024,
+            month: apollo::time::Month::Jan,
+            decimal_day: day,
+            cal_type: apollo::time::CalType::Gregorian,
+        };
+
+        // 2024 January 1, agrees with the three-point method within a minute
+
+        let events = moon_rise_set(&boston, &date(1.0));
+        let (rise, transit, set) = body_rise_transit_set(
+            &Body::Moon,
+            2460310.5,
+            &boston.geograph_point(),
+            Accuracy::High,
+        )
+        .unwrap();
+        let minute = 1.0 / 1440.0;
+        assert!((events.rise.unwrap().instant.jd_ut - rise).abs() < minute);
+        assert!((events.transit.unwrap().instant.jd_ut - transit).abs() < minute);
+        assert!((events.set.unwrap().instant.jd_ut - set).abs() < minute);
+
+        // No moonset in UT on 2024 January 13
+        let events = moon_rise_set(&boston, &date(13.0));
+        assert!(events.rise.is_some() && events.transit.is_some());
+        assert_eq!(events.set, None);
+    }
+
+    #[test]
+    fn test_sun_rise_set() {
+        let boston =
+            Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0).time_zone(-4.0);
+        let date = apollo::time::Date {
+            year: 2024,
+            month: apollo::time::Month::Mar,
+            decimal_day: 20.0,
+            cal_type: apollo::time::CalType::Gregorian,
+        };
+
+        // 6h47m, 12h51m and 18h57m EDT
+        let events = sun_rise_set(&boston, &date);
+        let local_hours =
+            |zoned: Option<ZonedInstant>| (zoned.unwrap().local_jd() - 2460389.5) * 24.0;
+        assert!((local_hours(events.rise) - 6.777).abs() < 0.02);
+        assert!((local_hours(events.transit) - 12.858).abs() < 0.02);
+        assert!((local_hours(events.set) - 18.952).abs() < 0.02);
+
+        let (rise, transit, set) = body_rise_transit_set(
+            &Body::Sun,
+            2460389.5,
+            &boston.geograph_point(),
+            Accuracy::High,
+        )
+        .unwrap();
+        let minute = 1.0 / 1440.0;
+        assert!((events.rise.unwrap().instant.jd_ut - rise).abs() < minute);
+        assert!((events.transit.unwrap().instant.jd_ut - transit).abs() < minute);
+        assert!((events.set.unwrap().instant.jd_ut - set).abs() < minute);
+    }
+
+    #[test]
+    fn test_body_rise_transit_set_with_uncertainty() {
+        let tromso = GeographPoint::new(-18.95_f64.to_radians(), 69.65_f64.to_radians());
+        let (rise, transit, set) =
+            body_rise_transit_set(&Body::Sun, 2460389.5, &tromso, Accuracy::Low).unwrap();
+        let (rise_u, transit_u, set_u) =
+            body_rise_transit_set_with_uncertainty(&Body::Sun, 2460389.5, &tromso, Accuracy::Low)
+                .unwrap();
+        assert_eq!(
+            (rise_u.value, transit_u.value, set_u.value),
+            (rise, transit, set)
+        );
+
+        // At the equinox, the Sun climbs through 0.2° of refraction in
+        // 0.2° / (360° cos φ) of a day at latitude φ
+        let minute = 1.0 / 1440.0;
+        let expected = 0.2 / (360.0 * 69.65_f64.to_radians().cos());
+        assert!((rise_u.sigma - expected).abs() < 0.05 * minute);
+        assert!((set_u.sigma - expected).abs() < 0.05 * minute);
+
+        // The Sun moves too slowly for ΔT to matter at the transit
+        assert!(transit_u.sigma < 1.0 / 86400.0);
+
+        assert!(body_rise_transit_set_with_uncertainty(
+            &Body::Sun,
+            2460482.5,
+            &tromso,
+            Accuracy::Low
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_culminations() {
+        // A star on the meridian of Greenwich at 2024 March 20, 0h UT
+        let jd = 2460389.5;
+        let sidereal = apollo::time::apparent_sidereal_jd(jd, ObliquityModel::Laskar);
+        let star = EqPoint::new(sidereal, 20_f64.to_radians());
+        let greenwich = Observer::new(0.0, 51.48_f64.to_radians(), 0.0);
+
+        let culminations = culminations(&star, &greenwich, jd - 0.1, jd + 1.9);
+        assert_eq!(culminations.len(), 4);
+        let sidereal_day = 0.99726957;
+        for (i, culmination) in culminations.iter().enumerate() {
+            assert_eq!(culmination.upper, i % 2 == 0);
+            let expected = jd + sidereal_day * i as f64 / 2.0;
+            assert!((culmination.instant.jd_ut - expected).abs() < 1.0 / 86400.0);
+        }
+        assert!((culminations[0].alt.to_degrees() - 58.52).abs() < 1e-9);
+        assert!((culminations[1].alt.to_degrees() + 18.52).abs() < 1e-9);
+    }
+}
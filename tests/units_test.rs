@@ -0,0 +1,79 @@
+// tests/units_test.rs
+
+#![cfg(feature = "uom")]
+
+extern crate apollo;
+use apollo::coordinate::GeographPoint;
+use apollo::ephemeris::Body;
+use apollo::units::*;
+use apollo::Accuracy;
+use uom::si::angle::{degree, radian};
+use uom::si::f64::{Angle, Length};
+use uom::si::length::{kilometer, meter};
+use uom::si::time::{minute, second};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_from_au() {
+        let one_au = length_from_au(1.0);
+        assert!((one_au.get::<meter>() - 149597870700.0).abs() < 1e-3);
+        assert!((au_from_length(Length::new::<kilometer>(748989460.0)) - 5.0066).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_apparent_eq_pos() {
+        // The Sun, 1992 October 13.0 TD, Meeus example 25.b
+        let (asc, dec, dist) = apparent_eq_pos(&Body::Sun, 2448908.5, Accuracy::High);
+        assert!((asc.get::<degree>() - 198.378178).abs() < 1e-4);
+        assert!((dec.get::<degree>() + 7.783871).abs() < 1e-4);
+        assert!((au_from_length(dist) - 0.99760775).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_horizontal_from_eq() {
+        // Saturn from Washington, 1987 April 10, Meeus example 13.b
+        let sidereal = Angle::new::<degree>(128.7378734);
+        let long = Angle::new::<degree>(77.0656);
+        let asc = Angle::new::<degree>(347.3193375);
+        let hour_angle = hour_angle_from_long(sidereal, long, asc);
+        assert!((hour_angle.get::<degree>().rem_euclid(360.0) - 64.35294).abs() < 1e-4);
+
+        let dec = Angle::new::<degree>(-6.7198889);
+        let lat = Angle::new::<degree>(38.9213889);
+        let (az, alt) = horizontal_from_eq(hour_angle, dec, lat);
+        assert!((az.get::<degree>() - 68.0337).abs() < 1e-3);
+        assert!((alt.get::<degree>() - 15.1249).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_apparent_sidereal() {
+        // 1987 April 10, 0h UT, Meeus example 12.a
+        let sidereal = apparent_sidereal(2446895.5);
+        assert!((sidereal.get::<degree>() - 197.69223).abs() < 1e-4);
+        assert_eq!(angle(1.0).get::<radian>(), 1.0);
+    }
+
+    #[test]
+    fn test_light_time() {
+        let light_time = light_time(length_from_au(1.0));
+        assert!((light_time.get::<minute>() - 8.316746).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_geodesic_distance() {
+        // Paris to Washington, Meeus example 11.c
+        let paris = GeographPoint::new(-2.33722_f64.to_radians(), 48.83639_f64.to_radians());
+        let washington = GeographPoint::new(77.06556_f64.to_radians(), 38.92139_f64.to_radians());
+        let distance = geodesic_distance(&paris, &washington);
+        assert!((distance.get::<kilometer>() - 6181.63).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_delta_t() {
+        let delta_t = delta_t(2000, 1);
+        assert!((delta_t.get::<second>() - 63.8).abs() < 0.5);
+    }
+}
@@ -0,0 +1,52 @@
+// tests/validate_test.rs
+
+extern crate apollo;
+use apollo::ephemeris::Body;
+use apollo::validate::*;
+use apollo::Accuracy;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Horizons-style table for the Sun, Meeus example 25.b
+    const HORIZONS: &str = "\
+*******************************************************************************
+ Date__(TT)__HR:MN, , , R.A._(a-app), DEC_(a-app),             delta,     deldot,
+*******************************************************************************
+$$SOE
+ 1992-Oct-13 00:00, , , 13 13 30.763, -07 47 01.94, 0.99760775, 0.0,
+ 1992-Oct-13 00:00, , , 198.378178, -7.783871, 0.99760775, 0.0,
+$$EOE
+*******************************************************************************
+";
+
+    #[test]
+    fn test_parse_horizons() {
+        let rows = parse_horizons(HORIZONS).unwrap();
+        assert_eq!(rows.len(), 2);
+
+        for row in &rows {
+            assert!((row.jd - 2448908.5).abs() < 1e-9);
+            assert!((row.eq_point.asc - 198.378178_f64.to_radians()).abs() < 1e-7);
+            assert!((row.eq_point.dec - (-7.783871_f64).to_radians()).abs() < 1e-7);
+            assert_eq!(row.dist, Some(0.99760775));
+        }
+    }
+
+    #[test]
+    fn test_parse_horizons_errors() {
+        assert!(parse_horizons("no table here").is_err());
+        assert!(parse_horizons(&HORIZONS.replace("$$EOE", "")).is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let summary = compare(&Body::Sun, HORIZONS, Accuracy::High).unwrap();
+
+        assert_eq!(summary.asc_cos_dec.count, 2);
+        assert!(summary.asc_cos_dec.max_abs < 5e-6);
+        assert!(summary.dec.max_abs < 5e-6);
+        assert!(summary.dist.unwrap().max_abs < 1e-6);
+    }
+}
@@ -0,0 +1,109 @@
+// tests/visibility_test.rs
+
+extern crate apollo;
+use apollo::angle;
+use apollo::coordinate::*;
+use apollo::ephemeris::{apparent_eq_pos, Body};
+use apollo::time::{CalType, Date, Month};
+use apollo::visibility::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observable_window() {
+        let sirius = EqPoint::new(
+            angle::deg_hms(6, 45, 9.0).to_radians(),
+            angle::deg_dmas(-16, 43, 0.0).to_radians(),
+        );
+        let boston = Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0);
+
+        let date = Date {
+            year: 2024,
+            month: Month::Jan,
+            decimal_day: 15.0,
+            cal_type: CalType::Gregorian,
+        };
+
+        // 2024 January 15, Sirius is above 20° from about 1h04m to 6h30m UT,
+        // symmetric about its transit
+        let windows = observable_window(
+            &sirius,
+            &boston,
+            &date,
+            20_f64.to_radians(),
+            -18_f64.to_radians(),
+        );
+        assert_eq!(windows.len(), 1);
+        let (start, end) = windows[0];
+        assert!((start - 2460325.5446).abs() < 0.003);
+        assert!((end - 2460325.7712).abs() < 0.003);
+
+        // Never above 40° from Boston
+        let windows = observable_window(
+            &sirius,
+            &boston,
+            &date,
+            40_f64.to_radians(),
+            -18_f64.to_radians(),
+        );
+        assert!(windows.is_empty());
+    }
+
+    #[test]
+    fn test_best_opposition_date() {
+        let sirius = EqPoint::new(
+            angle::deg_hms(6, 45, 9.0).to_radians(),
+            angle::deg_dmas(-16, 43, 0.0).to_radians(),
+        );
+        let boston = Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0);
+
+        // Sirius culminates at midnight around January 1
+        let (midnight, alt) = best_opposition_date(&sirius, 2024, &boston);
+        let (year, month, day) = apollo::time::date_from_julian_day(midnight).unwrap();
+        assert_eq!((year, month), (2024, 1));
+        assert!(day < 4.0);
+        let max_alt = std::f64::consts::FRAC_PI_2 - boston.lat + sirius.dec;
+        assert!((alt - max_alt).abs() < 0.5_f64.to_radians());
+    }
+
+    #[test]
+    fn test_moon_interference() {
+        // Full Moon of 2024 January 25, seen from Boston at 3h UT on the 26th
+        let jd = 2460335.625;
+        let boston = Observer::new(71.06_f64.to_radians(), 42.36_f64.to_radians(), 0.0);
+        let (moon, _) = apparent_eq_pos(&Body::Moon, jd, apollo::Accuracy::High);
+
+        let interference = moon_interference(&moon, jd, &boston);
+        assert!(interference.illum_frac > 0.99);
+        assert!(interference.moon_alt > 0.0);
+        assert!(interference.separation < 1e-6);
+        assert!(
+            (interference.score - interference.illum_frac * interference.moon_alt.sin()).abs()
+                < 1e-9
+        );
+
+        let opposite = EqPoint::new(moon.asc + std::f64::consts::PI, -moon.dec);
+        assert!(moon_interference(&opposite, jd, &boston).score < 1e-9);
+    }
+
+    #[test]
+    fn test_classify() {
+        let lat = angle::deg_dmas(51, 28, 38.0).to_radians();
+        let eq_point = |dec: f64| EqPoint::new(0.0, dec.to_radians());
+
+        let (class, circumpolar_dec, never_rises_dec) = classify(&eq_point(89.26), lat);
+        assert_eq!(class, RiseSetClass::Circumpolar);
+        assert!((circumpolar_dec - (90.0_f64.to_radians() - lat)).abs() < 1e-12);
+        assert!((never_rises_dec + circumpolar_dec).abs() < 1e-12);
+
+        assert_eq!(classify(&eq_point(-60.0), lat).0, RiseSetClass::NeverRises);
+        assert_eq!(classify(&eq_point(20.0), lat).0, RiseSetClass::RisesAndSets);
+
+        let (class, circumpolar_dec, _) = classify(&eq_point(-60.0), -lat);
+        assert_eq!(class, RiseSetClass::Circumpolar);
+        assert!(circumpolar_dec < 0.0);
+        assert_eq!(classify(&eq_point(89.26), -lat).0, RiseSetClass::NeverRises);
+    }
+}
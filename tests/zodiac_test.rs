@@ -0,0 +1,49 @@
+// tests/zodiac_test.rs
+
+extern crate apollo;
+use apollo::zodiac::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apollo::ephemeris::Body;
+    use apollo::time;
+    use apollo::Accuracy;
+
+    #[test]
+    fn test_zodiacal_long() {
+        let pos = zodiacal_long(123.5_f64.to_radians());
+        assert_eq!(pos.sign, Sign::Leo);
+        assert_eq!((pos.deg, pos.min), (3, 30));
+        assert!(pos.sec.abs() < 1e-6);
+
+        assert_eq!(sign(-1.0_f64.to_radians()), Sign::Pisces);
+        assert_eq!(sign(0.0), Sign::Aries);
+        assert_eq!(sign(359.999_f64.to_radians()), Sign::Pisces);
+    }
+
+    #[test]
+    fn test_sun_ingresses() {
+        // The Sun enters Aries at the March equinox, 2024 March 20,
+        // 3h 06m UT, and Cancer at the June solstice, June 20, 20h 51m UT
+        let start = time::julian_day(&time::Date {
+            year: 2024,
+            month: time::Month::Mar,
+            decimal_day: 1.0,
+            cal_type: time::CalType::Gregorian,
+        });
+        let ingresses = ingresses(&Body::Sun, start, start + 120.0, Accuracy::High);
+
+        let signs: Vec<Sign> = ingresses.iter().map(|i| i.sign).collect();
+        assert_eq!(
+            signs,
+            vec![Sign::Aries, Sign::Taurus, Sign::Gemini, Sign::Cancer]
+        );
+
+        let minute = 1.0 / 1440.0;
+        let equinox = start + 19.0 + (3.0 + 6.0 / 60.0) / 24.0;
+        assert!((ingresses[0].time.jd_ut - equinox).abs() < 2.0 * minute);
+        let solstice = start + 111.0 + (20.0 + 51.0 / 60.0) / 24.0;
+        assert!((ingresses[3].time.jd_ut - solstice).abs() < 2.0 * minute);
+    }
+}